@@ -3,8 +3,8 @@ use std::path::Path;
 use colored::Colorize;
 use ww_core::EntityKind;
 
-pub fn run(dir: &Path) -> Result<(), String> {
-    let world = super::compile_dir(dir)?;
+pub fn run(dir: &Path, timings: bool) -> Result<(), String> {
+    let (world, phases) = super::compile_dir_timed(dir)?;
 
     // Print summary
     let counts = world.entity_counts_by_kind();
@@ -24,6 +24,24 @@ pub fn run(dir: &Path) -> Result<(), String> {
         }
     }
 
+    if timings {
+        println!();
+        println!("  {}", "Timings:".dimmed());
+        let ran = [
+            ("lex", Some(phases.lex)),
+            ("parse", Some(phases.parse)),
+            ("resolve", phases.resolve),
+            ("compile", phases.compile),
+        ];
+        for (phase, took) in ran
+            .into_iter()
+            .filter_map(|(phase, took)| Some((phase, took?)))
+        {
+            println!("    {phase:<8} {took:>10.2?}");
+        }
+        println!("    {:<8} {:>10.2?}", "total".bold(), phases.total());
+    }
+
     // Validate mechanics configuration
     let issues = ww_mechanics::validate_world(&world);
     if !issues.is_empty() {
@@ -1,7 +1,39 @@
 use std::path::Path;
 
-pub fn run(dir: &Path) -> Result<(), String> {
-    let world = super::compile_dir(dir)?;
+use colored::Colorize;
+use ww_core::validate::ValidateOptions;
+use ww_dsl::diagnostics::{Diagnostic, DiagnosticCode, Severity, apply_lint_levels};
+
+pub fn run(
+    dir: &Path,
+    cycles: bool,
+    strict: bool,
+    allow: &[String],
+    deny: &[String],
+) -> Result<(), String> {
+    let allow = parse_codes(allow)?;
+    let deny = parse_codes(deny)?;
+    let levels = |diagnostics: Vec<Diagnostic>| apply_lint_levels(diagnostics, &allow, &deny);
+
+    let mut result = ww_dsl::compile_dir(dir);
+    let compiled = levels(std::mem::take(&mut result.diagnostics));
+    super::print_diagnostics(&compiled, dir);
+    if compiled.iter().any(|d| d.severity == Severity::Error) {
+        return Err("compilation failed with errors".into());
+    }
+
+    let options = if strict {
+        ValidateOptions::all()
+    } else {
+        ValidateOptions::default()
+    };
+    let issues = levels(result.validate(options));
+    super::print_diagnostics(&issues, dir);
+    if issues.iter().any(|d| d.severity == Severity::Error) {
+        return Err("validation failed with errors".into());
+    }
+
+    let world = result.world;
 
     println!("  All checks passed for '{}'.", world.meta.name);
     println!(
@@ -10,5 +42,33 @@ pub fn run(dir: &Path) -> Result<(), String> {
         world.relationship_count()
     );
 
+    if cycles {
+        let found = world.reference_cycles();
+        println!();
+        if found.is_empty() {
+            println!("  No reference cycles.");
+        } else {
+            println!(
+                "  {} {}",
+                found.len(),
+                if found.len() == 1 {
+                    "reference cycle:"
+                } else {
+                    "reference cycles:"
+                }
+                .dimmed()
+            );
+            for cycle in &found {
+                let mut names: Vec<&str> = cycle.iter().map(|id| world.entity_name(*id)).collect();
+                names.push(world.entity_name(cycle[0]));
+                println!("  - {}", names.join(" -> "));
+            }
+        }
+    }
+
     Ok(())
 }
+
+fn parse_codes(codes: &[String]) -> Result<Vec<DiagnosticCode>, String> {
+    codes.iter().map(|code| code.parse()).collect()
+}
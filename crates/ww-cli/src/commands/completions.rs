@@ -0,0 +1,65 @@
+use std::path::Path;
+
+use clap::ValueEnum;
+use clap_complete::Shell;
+use ww_dsl::CompileOptions;
+
+/// What `ww completions` prints.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum Target {
+    /// Completion script for bash.
+    Bash,
+    /// Completion script for zsh.
+    Zsh,
+    /// Completion script for fish.
+    Fish,
+    /// Entity names of the world, one per line, for dynamic completion.
+    Entities,
+}
+
+pub fn run(target: Target, cmd: &mut clap::Command, dir: &Path) -> Result<(), String> {
+    let shell = match target {
+        Target::Bash => Shell::Bash,
+        Target::Zsh => Shell::Zsh,
+        Target::Fish => Shell::Fish,
+        Target::Entities => {
+            print_entities(dir);
+            return Ok(());
+        }
+    };
+    let name = cmd.get_name().to_string();
+    clap_complete::generate(shell, cmd, name, &mut std::io::stdout());
+    Ok(())
+}
+
+/// Print entity names, sorted. Completion scripts call this on every tab
+/// press, so diagnostics are never printed. The world is compiled leniently,
+/// and when it has errors each file is also compiled on its own, so a broken
+/// declaration (even an unclosed one) does not take away the other names.
+fn print_entities(dir: &Path) {
+    let result = ww_dsl::compile_dir_with_options(dir, CompileOptions::lenient());
+    let mut names: Vec<String> = result
+        .world
+        .all_entities()
+        .map(|e| e.name.clone())
+        .collect();
+    if result.has_errors() {
+        let files = std::fs::read_dir(dir)
+            .into_iter()
+            .flatten()
+            .filter_map(|e| e.ok())
+            .filter(|e| e.path().extension().is_some_and(|ext| ext == "ww"));
+        for file in files {
+            let Ok(text) = std::fs::read_to_string(file.path()) else {
+                continue;
+            };
+            let world = ww_dsl::compile_source_with_options(&text, CompileOptions::lenient()).world;
+            names.extend(world.all_entities().map(|e| e.name.clone()));
+        }
+    }
+    names.sort_by(|a, b| a.to_lowercase().cmp(&b.to_lowercase()).then(a.cmp(b)));
+    names.dedup();
+    for name in names {
+        println!("{name}");
+    }
+}
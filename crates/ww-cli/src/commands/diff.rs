@@ -0,0 +1,280 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+use ww_core::entity::{Entity, EntityKind};
+use ww_core::{Relationship, World};
+
+pub fn run(old_dir: &Path, new_dir: &Path, format: &str) -> Result<(), String> {
+    let old = super::compile_dir(old_dir)?;
+    let new = super::compile_dir(new_dir)?;
+    let diff = WorldDiff::between(&old, &new);
+
+    match format {
+        "text" => print_text(&diff),
+        "json" => {
+            let json = serde_json::to_string_pretty(&diff_json(&diff))
+                .map_err(|e| format!("JSON serialization error: {e}"))?;
+            println!("{json}");
+        }
+        _ => {
+            return Err(format!("unsupported format: \"{format}\". Use: text, json"));
+        }
+    }
+
+    Ok(())
+}
+
+/// Differences between two compiled worlds.
+///
+/// Entities are matched by their exact name, not by alias, since IDs are
+/// assigned fresh on every compile. Relationships are compared by their
+/// sentence together with their `since`, `until`, and `strength`.
+struct WorldDiff<'a> {
+    added: Vec<&'a Entity>,
+    removed: Vec<&'a Entity>,
+    modified: Vec<(&'a Entity, Vec<Change>)>,
+    added_relationships: Vec<String>,
+    removed_relationships: Vec<String>,
+}
+
+/// A changed field of an entity, with its old and new rendering.
+struct Change {
+    key: String,
+    old: Option<String>,
+    new: Option<String>,
+}
+
+impl<'a> WorldDiff<'a> {
+    fn between(old: &'a World, new: &'a World) -> Self {
+        let mut added = Vec::new();
+        let mut modified = Vec::new();
+        for entity in new.all_entities() {
+            match same_entity(old, entity) {
+                None => added.push(entity),
+                Some(before) => {
+                    let changes = entity_changes(before, entity);
+                    if !changes.is_empty() {
+                        modified.push((entity, changes));
+                    }
+                }
+            }
+        }
+        let mut removed: Vec<_> = old
+            .all_entities()
+            .filter(|e| same_entity(new, e).is_none())
+            .collect();
+
+        added.sort_by_key(|e| e.name.to_lowercase());
+        removed.sort_by_key(|e| e.name.to_lowercase());
+        modified.sort_by_key(|(e, _)| e.name.to_lowercase());
+
+        let sentences = |world: &World| -> BTreeSet<String> {
+            world
+                .all_relationships()
+                .map(|r| relationship_line(r, world))
+                .collect()
+        };
+        let (before, after) = (sentences(old), sentences(new));
+
+        Self {
+            added,
+            removed,
+            modified,
+            added_relationships: after.difference(&before).cloned().collect(),
+            removed_relationships: before.difference(&after).cloned().collect(),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.added.is_empty()
+            && self.removed.is_empty()
+            && self.modified.is_empty()
+            && self.added_relationships.is_empty()
+            && self.removed_relationships.is_empty()
+    }
+}
+
+/// The entity in `world` with exactly the same name as `entity`.
+fn same_entity<'w>(world: &'w World, entity: &Entity) -> Option<&'w Entity> {
+    world
+        .find_by_name(&entity.name)
+        .filter(|e| e.name == entity.name)
+}
+
+/// A relationship's sentence with its attributes, e.g.
+/// "Kael is allied with Elara (since 1203, strength 3)."
+fn relationship_line(relationship: &Relationship, world: &World) -> String {
+    let sentence = relationship.describe(world);
+    let attributes: Vec<String> = Relationship::ATTRIBUTES
+        .iter()
+        .filter_map(|key| relationship.attribute(key).map(|v| format!("{key} {v}")))
+        .collect();
+    if attributes.is_empty() {
+        return sentence;
+    }
+    format!(
+        "{} ({}).",
+        sentence.trim_end_matches('.'),
+        attributes.join(", ")
+    )
+}
+
+/// Changed kind, description, and properties, with properties in key order.
+fn entity_changes(old: &Entity, new: &Entity) -> Vec<Change> {
+    let mut changes = Vec::new();
+    if old.kind != new.kind {
+        changes.push(Change {
+            key: "kind".to_string(),
+            old: Some(old.kind.to_string()),
+            new: Some(new.kind.to_string()),
+        });
+    }
+    if old.description.trim() != new.description.trim() {
+        let text = |s: &str| (!s.trim().is_empty()).then(|| s.trim().to_string());
+        changes.push(Change {
+            key: "description".to_string(),
+            old: text(&old.description),
+            new: text(&new.description),
+        });
+    }
+
+    let keys: BTreeSet<&String> = old.properties.keys().chain(new.properties.keys()).collect();
+    for key in keys {
+        let (before, after) = (old.properties.get(key), new.properties.get(key));
+        if before != after {
+            changes.push(Change {
+                key: key.clone(),
+                old: before.map(ToString::to_string),
+                new: after.map(ToString::to_string),
+            });
+        }
+    }
+    changes
+}
+
+/// Group items by their entity kind's name.
+fn by_kind<'a, T>(
+    items: impl IntoIterator<Item = T>,
+    kind: impl Fn(&T) -> &'a EntityKind,
+) -> BTreeMap<String, Vec<T>> {
+    let mut groups: BTreeMap<String, Vec<T>> = BTreeMap::new();
+    for item in items {
+        groups
+            .entry(kind(&item).to_string())
+            .or_default()
+            .push(item);
+    }
+    groups
+}
+
+fn print_text(diff: &WorldDiff) {
+    if diff.is_empty() {
+        println!("  No differences");
+        return;
+    }
+
+    let added = by_kind(diff.added.iter(), |e| &e.kind);
+    let removed = by_kind(diff.removed.iter(), |e| &e.kind);
+    let modified = by_kind(diff.modified.iter(), |(e, _)| &e.kind);
+    let kinds: BTreeSet<&String> = added
+        .keys()
+        .chain(removed.keys())
+        .chain(modified.keys())
+        .collect();
+
+    for kind in kinds {
+        println!("  {kind}");
+        for entity in added.get(kind).into_iter().flatten() {
+            println!("    + {}", entity.name);
+        }
+        for entity in removed.get(kind).into_iter().flatten() {
+            println!("    - {}", entity.name);
+        }
+        for (entity, changes) in modified.get(kind).into_iter().flatten() {
+            println!("    ~ {}", entity.name);
+            for change in changes {
+                let show = |v: &Option<String>| v.as_deref().unwrap_or("(none)").to_string();
+                if change.key == "description" {
+                    println!("        description changed");
+                } else {
+                    println!(
+                        "        {}: {} -> {}",
+                        change.key,
+                        show(&change.old),
+                        show(&change.new)
+                    );
+                }
+            }
+        }
+        println!();
+    }
+
+    if !diff.added_relationships.is_empty() || !diff.removed_relationships.is_empty() {
+        println!("  relationships");
+        for sentence in &diff.added_relationships {
+            println!("    + {sentence}");
+        }
+        for sentence in &diff.removed_relationships {
+            println!("    - {sentence}");
+        }
+        println!();
+    }
+
+    println!(
+        "  {} added, {} removed, {} modified, {} relationships added, {} removed",
+        diff.added.len(),
+        diff.removed.len(),
+        diff.modified.len(),
+        diff.added_relationships.len(),
+        diff.removed_relationships.len()
+    );
+}
+
+/// The diff as JSON, with added and removed entities in export form.
+fn diff_json(diff: &WorldDiff) -> serde_json::Value {
+    let entities = |list: &[&Entity]| -> BTreeMap<String, Vec<serde_json::Value>> {
+        by_kind(list.iter(), |e| &e.kind)
+            .into_iter()
+            .map(|(kind, group)| {
+                let group = group
+                    .into_iter()
+                    .map(|e| super::export::entity_json(e, false))
+                    .collect();
+                (kind, group)
+            })
+            .collect()
+    };
+    let modified: BTreeMap<String, Vec<serde_json::Value>> =
+        by_kind(diff.modified.iter(), |(e, _)| &e.kind)
+            .into_iter()
+            .map(|(kind, group)| {
+                let group = group
+                    .into_iter()
+                    .map(|(entity, changes)| {
+                        let changes: Vec<_> = changes
+                            .iter()
+                            .map(|c| {
+                                serde_json::json!({
+                                    "key": c.key,
+                                    "old": c.old,
+                                    "new": c.new,
+                                })
+                            })
+                            .collect();
+                        serde_json::json!({ "name": entity.name, "changes": changes })
+                    })
+                    .collect();
+                (kind, group)
+            })
+            .collect();
+
+    serde_json::json!({
+        "added": entities(&diff.added),
+        "removed": entities(&diff.removed),
+        "modified": modified,
+        "relationships": {
+            "added": diff.added_relationships,
+            "removed": diff.removed_relationships,
+        },
+    })
+}
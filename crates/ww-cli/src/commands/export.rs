@@ -1,18 +1,40 @@
 use std::path::Path;
 
 use ww_core::World;
-use ww_core::entity::EntityKind;
+use ww_core::entity::{Entity, EntityId, EntityKind};
 
-pub fn run(dir: &Path, format: &str, output: Option<&Path>) -> Result<(), String> {
+pub fn run(
+    dir: &Path,
+    format: &str,
+    output: Option<&Path>,
+    include_gm_notes: bool,
+    focus: Option<&str>,
+    depth: usize,
+) -> Result<(), String> {
     let world = super::compile_dir(dir)?;
 
+    if focus.is_some() && format != "dot" {
+        return Err("--focus is only supported by the dot format".to_string());
+    }
+
     let content = match format {
-        "json" => export_json(&world)?,
-        "markdown" | "md" => export_markdown(&world),
-        "html" => export_html(&world),
+        "json" => export_json(&world, include_gm_notes),
+        "markdown" | "md" => export_markdown(&world, include_gm_notes),
+        "html" => export_html(&world, include_gm_notes),
+        "svg" => export_svg(&world),
+        "dot" => {
+            let focus = focus
+                .map(|name| {
+                    world
+                        .find_id_by_name(name)
+                        .ok_or_else(|| format!("entity not found: \"{name}\""))
+                })
+                .transpose()?;
+            export_dot(&world, focus, depth)
+        }
         _ => {
             return Err(format!(
-                "unsupported format: \"{format}\". Use: json, markdown, html"
+                "unsupported format: \"{format}\". Use: json, markdown, html, svg, dot"
             ));
         }
     };
@@ -28,26 +50,48 @@ pub fn run(dir: &Path, format: &str, output: Option<&Path>) -> Result<(), String
     Ok(())
 }
 
-fn export_json(world: &World) -> Result<String, String> {
-    // Build a serializable structure
-    let entities: Vec<_> = world.all_entities().collect();
-    let relationships: Vec<_> = world.all_relationships().collect();
+fn export_json(world: &World, include_gm_notes: bool) -> String {
+    with_gm_notes(world, include_gm_notes).to_json()
+}
 
-    let export = serde_json::json!({
-        "world": {
-            "name": world.meta.name,
-            "description": world.meta.description,
-            "genre": world.meta.genre,
-            "setting": world.meta.setting,
-        },
-        "entities": entities,
-        "relationships": relationships,
-    });
+/// Versioned JSON snapshot of a world, as written by `World::to_json`.
+///
+/// GM notes are stripped from the entities unless `include_gm_notes` is set.
+pub(crate) fn world_json(world: &World, include_gm_notes: bool) -> serde_json::Value {
+    with_gm_notes(world, include_gm_notes).to_json_value()
+}
 
-    serde_json::to_string_pretty(&export).map_err(|e| format!("JSON serialization error: {e}"))
+/// A copy of the world, with GM notes cleared unless `include_gm_notes` is set.
+fn with_gm_notes(world: &World, include_gm_notes: bool) -> World {
+    let mut world = world.clone();
+    if !include_gm_notes {
+        let ids: Vec<_> = world.all_entities().map(|e| e.id).collect();
+        for id in ids {
+            if let Some(entity) = world.get_entity_mut(id) {
+                entity.gm_notes.clear();
+            }
+        }
+    }
+    world
+}
+
+/// Serializable snapshot of one entity, without GM notes unless
+/// `include_gm_notes` is set.
+pub(crate) fn entity_json(entity: &Entity, include_gm_notes: bool) -> serde_json::Value {
+    let mut entity = entity.clone();
+    if !include_gm_notes {
+        entity.gm_notes.clear();
+    }
+    serde_json::to_value(entity).unwrap_or_default()
 }
 
-fn export_markdown(world: &World) -> String {
+fn export_markdown(world: &World, include_gm_notes: bool) -> String {
+    render_markdown(world, include_gm_notes, false)
+}
+
+/// Markdown document for the world. With `html`, entity headings get
+/// anchors and description mentions link to them.
+fn render_markdown(world: &World, include_gm_notes: bool, html: bool) -> String {
     let mut out = String::new();
 
     out.push_str(&format!("# {}\n\n", world.meta.name));
@@ -96,10 +140,29 @@ fn export_markdown(world: &World) -> String {
         sorted.sort_by(|a, b| a.name.cmp(&b.name));
 
         for entity in sorted {
-            out.push_str(&format!("### {}\n\n", entity.name));
+            if html {
+                out.push_str(&format!(
+                    "### <a id=\"{}\">{}</a>\n\n",
+                    slugify(&entity.name),
+                    xml_escape(&entity.name)
+                ));
+            } else {
+                out.push_str(&format!("### {}\n\n", entity.name));
+            }
 
             if !entity.description.is_empty() {
-                out.push_str(&format!("{}\n\n", entity.description.trim()));
+                let description = if html {
+                    linked_description(world, entity)
+                } else {
+                    entity.description.clone()
+                };
+                out.push_str(&format!("{}\n\n", description.trim()));
+            }
+
+            if include_gm_notes {
+                for note in &entity.gm_notes {
+                    out.push_str(&format!("> **GM note:** {}\n\n", note.trim()));
+                }
             }
 
             // Properties
@@ -117,14 +180,7 @@ fn export_markdown(world: &World) -> String {
             if !rels.is_empty() {
                 out.push_str("**Relationships:**\n\n");
                 for rel in &rels {
-                    let other_id = if rel.source == entity.id {
-                        rel.target
-                    } else {
-                        rel.source
-                    };
-                    let other_name = world.entity_name(other_id);
-                    let phrase = rel.kind.as_phrase();
-                    out.push_str(&format!("- {phrase} {other_name}\n"));
+                    out.push_str(&format!("- {}\n", rel.describe(world)));
                 }
                 out.push('\n');
             }
@@ -134,9 +190,9 @@ fn export_markdown(world: &World) -> String {
     out
 }
 
-fn export_html(world: &World) -> String {
+fn export_html(world: &World, include_gm_notes: bool) -> String {
     // Wrap the markdown in a basic HTML template
-    let md = export_markdown(world);
+    let md = render_markdown(world, include_gm_notes, true);
 
     let mut html = String::new();
     html.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n");
@@ -151,9 +207,336 @@ fn export_html(world: &World) -> String {
     html.push_str("    h3 { color: #444; }\n");
     html.push_str("    hr { border: none; border-top: 1px solid #ccc; margin: 2em 0; }\n");
     html.push_str("    pre { background: #f5f5f5; padding: 1em; overflow-x: auto; }\n");
+    html.push_str("    a { color: #2a5d8f; }\n");
     html.push_str("  </style>\n</head>\n<body>\n<pre>\n");
     html.push_str(&md);
     html.push_str("</pre>\n</body>\n</html>\n");
 
     html
 }
+
+/// An entity's description as escaped HTML, with each recorded mention
+/// linked to the mentioned entity's heading.
+fn linked_description(world: &World, entity: &ww_core::Entity) -> String {
+    let text = &entity.description;
+    let mut out = String::new();
+    let mut pos = 0;
+    for mention in world.mentions(entity.id) {
+        out.push_str(&xml_escape(&text[pos..mention.range.start]));
+        out.push_str(&format!(
+            "<a href=\"#{}\">{}</a>",
+            slugify(world.entity_name(mention.entity)),
+            xml_escape(&text[mention.range.clone()])
+        ));
+        pos = mention.range.end;
+    }
+    out.push_str(&xml_escape(&text[pos..]));
+    out
+}
+
+// -- SVG export --
+
+const SVG_WIDTH: f64 = 960.0;
+const SVG_HEIGHT: f64 = 720.0;
+const SVG_MARGIN: f64 = 60.0;
+const NODE_RADIUS: f64 = 10.0;
+const LAYOUT_ITERATIONS: usize = 300;
+const GRAVITY: f64 = 3.0;
+
+/// Fill color for an entity kind, shared by nodes and the legend.
+fn kind_color(kind: &EntityKind) -> &'static str {
+    match kind {
+        EntityKind::Location => "#4caf50",
+        EntityKind::Character => "#2196f3",
+        EntityKind::Faction => "#f44336",
+        EntityKind::Event => "#ff9800",
+        EntityKind::Item => "#9c27b0",
+        EntityKind::Lore => "#795548",
+        EntityKind::Custom(_) => "#607d8b",
+    }
+}
+
+/// Render the relationship graph as a standalone, interactive SVG.
+///
+/// Nodes carry `<title>` tooltips (name, kind, description), `data-*`
+/// attributes, and a `node-<slug>` id for styling or scripting.
+fn export_svg(world: &World) -> String {
+    let mut entities: Vec<_> = world.all_entities().collect();
+    entities.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let index: std::collections::HashMap<_, _> = entities
+        .iter()
+        .enumerate()
+        .map(|(i, e)| (e.id, i))
+        .collect();
+    let mut relationships: Vec<_> = world
+        .all_relationships()
+        .filter(|r| index.contains_key(&r.source) && index.contains_key(&r.target))
+        .collect();
+    relationships.sort_by_key(|r| (index[&r.source], index[&r.target], r.kind.as_phrase()));
+
+    let edges: Vec<(usize, usize)> = relationships
+        .iter()
+        .map(|r| (index[&r.source], index[&r.target]))
+        .collect();
+    let positions = force_layout(entities.len(), &edges);
+
+    let mut svg = String::new();
+    svg.push_str(&format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{SVG_WIDTH}\" height=\"{SVG_HEIGHT}\" \
+         viewBox=\"0 0 {SVG_WIDTH} {SVG_HEIGHT}\" font-family=\"sans-serif\" font-size=\"12\">\n"
+    ));
+    svg.push_str(&format!(
+        "  <title>{}</title>\n",
+        xml_escape(&world.meta.name)
+    ));
+    svg.push_str("  <style>\n");
+    svg.push_str("    .edge { stroke: #999; stroke-width: 1.5; }\n");
+    svg.push_str("    .edge:hover { stroke: #333; stroke-width: 3; }\n");
+    svg.push_str("    .node circle { stroke: #fff; stroke-width: 2; }\n");
+    svg.push_str("    .node:hover circle { stroke: #333; }\n");
+    svg.push_str("    .node text { fill: #333; pointer-events: none; }\n");
+    svg.push_str("  </style>\n");
+
+    svg.push_str("  <g class=\"edges\">\n");
+    for (rel, &(s, t)) in relationships.iter().zip(&edges) {
+        let (x1, y1) = positions[s];
+        let (x2, y2) = positions[t];
+        let mut tooltip = format!(
+            "{} {} {}",
+            entities[s].name,
+            rel.kind.as_phrase(),
+            entities[t].name
+        );
+        if let Some(label) = &rel.label {
+            tooltip.push_str(&format!(" ({label})"));
+        }
+        svg.push_str(&format!(
+            "    <line class=\"edge\" x1=\"{x1:.1}\" y1=\"{y1:.1}\" x2=\"{x2:.1}\" y2=\"{y2:.1}\" \
+             data-kind=\"{}\"><title>{}</title></line>\n",
+            rel.kind.as_phrase().replace(' ', "_"),
+            xml_escape(&tooltip),
+        ));
+    }
+    svg.push_str("  </g>\n");
+
+    svg.push_str("  <g class=\"nodes\">\n");
+    for (entity, &(x, y)) in entities.iter().zip(&positions) {
+        let slug = slugify(&entity.name);
+        let mut tooltip = format!("{} ({})", entity.name, entity.kind);
+        if !entity.description.is_empty() {
+            tooltip.push_str(&format!("\n{}", entity.description.trim()));
+        }
+        svg.push_str(&format!(
+            "    <g class=\"node\" id=\"node-{slug}\" data-id=\"{}\" \
+             data-kind=\"{}\" data-name=\"{}\">\n",
+            entity.id.0,
+            xml_escape(&entity.kind.to_string()),
+            xml_escape(&entity.name),
+        ));
+        svg.push_str(&format!("      <title>{}</title>\n", xml_escape(&tooltip)));
+        svg.push_str(&format!(
+            "      <circle cx=\"{x:.1}\" cy=\"{y:.1}\" r=\"{NODE_RADIUS}\" fill=\"{}\"/>\n",
+            kind_color(&entity.kind)
+        ));
+        svg.push_str(&format!(
+            "      <text x=\"{x:.1}\" y=\"{:.1}\" text-anchor=\"middle\">{}</text>\n",
+            y + NODE_RADIUS + 14.0,
+            xml_escape(&entity.name)
+        ));
+        svg.push_str("    </g>\n");
+    }
+    svg.push_str("  </g>\n");
+
+    // Legend for the kinds actually present
+    let mut kinds: Vec<&EntityKind> = Vec::new();
+    for entity in &entities {
+        if !kinds.contains(&&entity.kind) {
+            kinds.push(&entity.kind);
+        }
+    }
+    kinds.sort_by_key(|k| k.to_string());
+    svg.push_str("  <g class=\"legend\">\n");
+    for (i, kind) in kinds.iter().enumerate() {
+        let y = 20.0 + i as f64 * 18.0;
+        svg.push_str(&format!(
+            "    <circle cx=\"20\" cy=\"{y}\" r=\"6\" fill=\"{}\"/>\
+             <text x=\"32\" y=\"{}\">{}</text>\n",
+            kind_color(kind),
+            y + 4.0,
+            xml_escape(&kind.to_string())
+        ));
+    }
+    svg.push_str("  </g>\n");
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// Render the relationship graph as a Graphviz DOT digraph.
+///
+/// Nodes are filled with their kind's color and edges are labeled with the
+/// relationship phrase. With `focus`, only entities within `depth` hops of
+/// it are included.
+fn export_dot(world: &World, focus: Option<EntityId>, depth: usize) -> String {
+    let included = focus.map(|id| world.neighborhood(id, depth));
+    let mut entities: Vec<_> = world
+        .all_entities()
+        .filter(|e| included.as_ref().is_none_or(|set| set.contains(&e.id)))
+        .collect();
+    entities.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let index: std::collections::HashMap<_, _> = entities
+        .iter()
+        .enumerate()
+        .map(|(i, e)| (e.id, i))
+        .collect();
+    let mut relationships: Vec<_> = world
+        .all_relationships()
+        .filter(|r| index.contains_key(&r.source) && index.contains_key(&r.target))
+        .collect();
+    relationships.sort_by_key(|r| (index[&r.source], index[&r.target], r.kind.as_phrase()));
+
+    let mut dot = String::new();
+    dot.push_str(&format!(
+        "digraph \"{}\" {{\n",
+        dot_escape(&world.meta.name)
+    ));
+    dot.push_str("  graph [overlap=false, fontname=\"sans-serif\"];\n");
+    dot.push_str(
+        "  node [shape=ellipse, style=filled, fontname=\"sans-serif\", fontcolor=\"#ffffff\"];\n",
+    );
+    dot.push_str("  edge [fontname=\"sans-serif\", fontsize=10, color=\"#999999\"];\n");
+
+    for entity in &entities {
+        let mut attrs = format!(
+            "label=\"{}\", fillcolor=\"{}\", tooltip=\"{}\"",
+            dot_escape(&entity.name),
+            kind_color(&entity.kind),
+            dot_escape(&entity.kind.to_string()),
+        );
+        if Some(entity.id) == focus {
+            attrs.push_str(", penwidth=3");
+        }
+        dot.push_str(&format!("  \"{}\" [{attrs}];\n", dot_escape(&entity.name)));
+    }
+
+    for rel in &relationships {
+        let mut label = rel.kind.as_phrase().to_string();
+        if let Some(custom) = &rel.label {
+            label.push_str(&format!(" ({custom})"));
+        }
+        let dir = if rel.bidirectional { ", dir=both" } else { "" };
+        dot.push_str(&format!(
+            "  \"{}\" -> \"{}\" [label=\"{}\"{dir}];\n",
+            dot_escape(world.entity_name(rel.source)),
+            dot_escape(world.entity_name(rel.target)),
+            dot_escape(&label),
+        ));
+    }
+
+    dot.push_str("}\n");
+    dot
+}
+
+/// Escape a string for use inside a double-quoted DOT identifier.
+fn dot_escape(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// Deterministic Fruchterman–Reingold layout inside the SVG canvas.
+///
+/// Nodes start evenly spaced on a circle so the same world always produces
+/// the same picture.
+fn force_layout(count: usize, edges: &[(usize, usize)]) -> Vec<(f64, f64)> {
+    let (cx, cy) = (SVG_WIDTH / 2.0, SVG_HEIGHT / 2.0);
+    if count == 0 {
+        return Vec::new();
+    }
+    if count == 1 {
+        return vec![(cx, cy)];
+    }
+
+    let usable_w = SVG_WIDTH - 2.0 * SVG_MARGIN;
+    let usable_h = SVG_HEIGHT - 2.0 * SVG_MARGIN;
+    let radius = usable_w.min(usable_h) / 2.0;
+    let mut pos: Vec<(f64, f64)> = (0..count)
+        .map(|i| {
+            let angle = i as f64 / count as f64 * std::f64::consts::TAU;
+            (cx + radius * angle.cos(), cy + radius * angle.sin())
+        })
+        .collect();
+
+    let k = (usable_w * usable_h / count as f64).sqrt();
+    let mut temperature = usable_w / 10.0;
+    let cooling = temperature / LAYOUT_ITERATIONS as f64;
+
+    for _ in 0..LAYOUT_ITERATIONS {
+        let mut disp = vec![(0.0f64, 0.0f64); count];
+
+        for i in 0..count {
+            for j in (i + 1)..count {
+                let (dx, dy) = (pos[i].0 - pos[j].0, pos[i].1 - pos[j].1);
+                let dist = (dx * dx + dy * dy).sqrt().max(0.01);
+                let force = k * k / dist;
+                let (fx, fy) = (dx / dist * force, dy / dist * force);
+                disp[i].0 += fx;
+                disp[i].1 += fy;
+                disp[j].0 -= fx;
+                disp[j].1 -= fy;
+            }
+        }
+
+        for &(s, t) in edges {
+            if s == t {
+                continue;
+            }
+            let (dx, dy) = (pos[s].0 - pos[t].0, pos[s].1 - pos[t].1);
+            let dist = (dx * dx + dy * dy).sqrt().max(0.01);
+            let force = dist * dist / k;
+            let (fx, fy) = (dx / dist * force, dy / dist * force);
+            disp[s].0 -= fx;
+            disp[s].1 -= fy;
+            disp[t].0 += fx;
+            disp[t].1 += fy;
+        }
+
+        // Pull toward the center keeps nodes off the canvas edges
+        for (p, d) in pos.iter().zip(disp.iter_mut()) {
+            let (dx, dy) = (p.0 - cx, p.1 - cy);
+            let dist = (dx * dx + dy * dy).sqrt().max(0.01);
+            let force = GRAVITY * dist * dist / k;
+            d.0 -= dx / dist * force;
+            d.1 -= dy / dist * force;
+        }
+
+        for (p, d) in pos.iter_mut().zip(&disp) {
+            let len = (d.0 * d.0 + d.1 * d.1).sqrt().max(0.01);
+            let step = len.min(temperature);
+            p.0 = (p.0 + d.0 / len * step).clamp(SVG_MARGIN, SVG_WIDTH - SVG_MARGIN);
+            p.1 = (p.1 + d.1 / len * step).clamp(SVG_MARGIN, SVG_HEIGHT - SVG_MARGIN);
+        }
+
+        temperature = (temperature - cooling).max(1.0);
+    }
+
+    pos
+}
+
+/// Lowercase, hyphen-separated anchor for an entity name.
+fn slugify(name: &str) -> String {
+    name.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -0,0 +1,74 @@
+use std::fs;
+use std::path::Path;
+
+use ww_dsl::InputFile;
+use ww_dsl::diagnostics::render_diagnostics;
+
+pub fn run(dir: &Path, fix_relationships: bool) -> Result<(), String> {
+    if !fix_relationships {
+        return Err("nothing to do: pass --fix-relationships".into());
+    }
+
+    let files = read_ww_files(dir)?;
+    if files.is_empty() {
+        return Err(format!("no .ww files found in {}", dir.display()));
+    }
+
+    let fixes = ww_dsl::fix::fix_relationships(dir, &files).map_err(|diagnostics| {
+        let source = ww_dsl::read_dir_sources(dir);
+        eprint!(
+            "{}",
+            render_diagnostics(&source, &dir.display().to_string(), &diagnostics)
+        );
+        "cannot fix relationships: source has errors".to_string()
+    })?;
+
+    if fixes.is_empty() {
+        println!("  All relationships are already reciprocal.");
+        return Ok(());
+    }
+
+    let mut total = 0;
+    for fix in &fixes {
+        let path = dir.join(&fix.name);
+        fs::write(&path, &fix.text).map_err(|e| format!("cannot write {}: {e}", path.display()))?;
+        println!("  {}:", fix.name);
+        for line in &fix.added {
+            println!("    + {line}");
+        }
+        total += fix.added.len();
+    }
+
+    println!(
+        "  Added {} reciprocal statement{} in {} file{}.",
+        total,
+        if total == 1 { "" } else { "s" },
+        fixes.len(),
+        if fixes.len() == 1 { "" } else { "s" },
+    );
+
+    Ok(())
+}
+
+/// Read all `.ww` files in `dir`, sorted by path like the compiler does.
+fn read_ww_files(dir: &Path) -> Result<Vec<InputFile>, String> {
+    let mut entries: Vec<_> = fs::read_dir(dir)
+        .map_err(|e| format!("cannot read directory: {e}"))?
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().extension().is_some_and(|ext| ext == "ww"))
+        .collect();
+    entries.sort_by_key(|e| e.path());
+
+    entries
+        .into_iter()
+        .map(|entry| {
+            let path = entry.path();
+            let text = fs::read_to_string(&path)
+                .map_err(|e| format!("cannot read {}: {e}", path.display()))?;
+            Ok(InputFile {
+                name: entry.file_name().to_string_lossy().to_string(),
+                text,
+            })
+        })
+        .collect()
+}
@@ -24,6 +24,52 @@ pub fn run(dir: &Path, focus: Option<&str>) -> Result<(), String> {
     Ok(())
 }
 
+pub fn run_path(dir: &Path, from: &str, to: &str, directed: bool) -> Result<(), String> {
+    let world = super::compile_dir(dir)?;
+
+    let find = |name: &str| {
+        world
+            .find_id_by_name(name)
+            .ok_or_else(|| format!("entity not found: \"{}\"", name))
+    };
+    let (start, end) = (find(from)?, find(to)?);
+
+    let Some((entities, hops)) = world.shortest_path(start, end, directed) else {
+        println!(
+            "  No path from {} to {}",
+            world.entity_name(start),
+            world.entity_name(end)
+        );
+        return Ok(());
+    };
+
+    println!(
+        "  Path from {} to {} ({} hop{})",
+        world.entity_name(start),
+        world.entity_name(end),
+        hops.len(),
+        if hops.len() == 1 { "" } else { "s" }
+    );
+    println!();
+    println!("  [{}]", world.entity_name(start));
+    for (rel, &next) in hops.iter().zip(&entities[1..]) {
+        let arrow = if rel.bidirectional {
+            "<-->"
+        } else if rel.target == next {
+            " -->"
+        } else {
+            "<-- "
+        };
+        println!(
+            "    {arrow} {} {arrow} [{}]",
+            rel.kind.as_phrase(),
+            world.entity_name(next)
+        );
+    }
+
+    Ok(())
+}
+
 fn render_focused_graph(world: &World, center: EntityId) {
     let center_name = world.entity_name(center);
 
@@ -0,0 +1,188 @@
+//! `ww import`: bulk-load entities from spreadsheet exports.
+
+use std::fs;
+use std::path::Path;
+
+use ww_dsl::render::{declaration_name, quote};
+
+/// Creature fields the solo `encounter` command reads, in output order.
+const CREATURE_FIELDS: &[&str] = &[
+    "name", "type", "combat", "instinct", "hits", "weapon", "traits",
+];
+
+pub fn run(
+    kind: &str,
+    input: &Path,
+    file: Option<&Path>,
+    columns: &[String],
+) -> Result<(), String> {
+    match kind {
+        "creatures" => creatures(input, file, columns),
+        other => Err(format!("cannot import {other}: expected creatures")),
+    }
+}
+
+/// Import a CSV of creature stats as `creature` entities.
+///
+/// The header row names the columns. By default a column feeds the field of
+/// the same name (`name`, `type`, `combat`, `instinct`, `hits`, `weapon`,
+/// `traits`, case-insensitive); `--column HEADER=FIELD` maps any other
+/// header. Traits are `;`-separated `name: description` pairs. Remaining
+/// columns become plain properties.
+fn creatures(input: &Path, file: Option<&Path>, columns: &[String]) -> Result<(), String> {
+    let text =
+        fs::read_to_string(input).map_err(|e| format!("cannot read {}: {e}", input.display()))?;
+    let blocks = creatures_to_dsl(&text, columns)?;
+
+    let target = file.unwrap_or(Path::new("creatures.ww"));
+    super::new::append(target, &blocks.join("\n\n"))?;
+
+    println!(
+        "  Imported {} creature(s) to {}",
+        blocks.len(),
+        target.display()
+    );
+    Ok(())
+}
+
+/// Convert creature CSV text into one `.ww` block per data row.
+fn creatures_to_dsl(text: &str, columns: &[String]) -> Result<Vec<String>, String> {
+    let mut rows = parse_csv(text).into_iter();
+    let header = rows.next().ok_or("CSV file is empty")?;
+    let fields = map_columns(&header, columns)?;
+    if !fields.iter().any(|f| f == "name") {
+        return Err("no name column: add one or map it with --column HEADER=name".into());
+    }
+
+    let mut blocks = Vec::new();
+    for (line, row) in rows.enumerate() {
+        if row.iter().all(|cell| cell.trim().is_empty()) {
+            continue;
+        }
+        let cell = |field: &str| {
+            fields
+                .iter()
+                .position(|f| f == field)
+                .and_then(|i| row.get(i))
+                .map(|c| c.trim())
+                .filter(|c| !c.is_empty())
+        };
+        let name = cell("name").ok_or_else(|| format!("row {}: missing name", line + 2))?;
+
+        let mut body = Vec::new();
+        for field in &CREATURE_FIELDS[1..6] {
+            if let Some(value) = cell(field) {
+                body.push(format!("    {field} {}", dsl_value(value)));
+            }
+        }
+        for (i, field) in fields.iter().enumerate() {
+            if CREATURE_FIELDS.contains(&field.as_str()) {
+                continue;
+            }
+            if let Some(value) = row.get(i).map(|c| c.trim()).filter(|c| !c.is_empty()) {
+                body.push(format!("    {field} {}", dsl_value(value)));
+            }
+        }
+        for entry in cell("traits").into_iter().flat_map(|t| t.split(';')) {
+            let (trait_name, description) = match entry.split_once(':') {
+                Some((n, d)) => (n.trim(), d.trim()),
+                None => (entry.trim(), entry.trim()),
+            };
+            if !trait_name.is_empty() {
+                body.push(format!(
+                    "    trait-{} {}",
+                    slug(trait_name),
+                    quote(description)
+                ));
+            }
+        }
+
+        blocks.push(format!(
+            "{} is a creature {{\n{}\n}}",
+            declaration_name(name),
+            body.join("\n")
+        ));
+    }
+    Ok(blocks)
+}
+
+/// Resolve the field each header column feeds.
+fn map_columns(header: &[String], columns: &[String]) -> Result<Vec<String>, String> {
+    let mut overrides = Vec::new();
+    for mapping in columns {
+        let (from, to) = mapping
+            .split_once('=')
+            .ok_or_else(|| format!("invalid column mapping '{mapping}': expected HEADER=FIELD"))?;
+        let to = to.trim().to_lowercase();
+        if !CREATURE_FIELDS.contains(&to.as_str()) {
+            return Err(format!(
+                "unknown creature field '{to}': expected one of {}",
+                CREATURE_FIELDS.join(", ")
+            ));
+        }
+        overrides.push((from.trim().to_lowercase(), to));
+    }
+
+    Ok(header
+        .iter()
+        .map(|h| {
+            let h = h.trim().to_lowercase();
+            overrides
+                .iter()
+                .find(|(from, _)| *from == h)
+                .map(|(_, to)| to.clone())
+                .unwrap_or_else(|| slug(&h))
+        })
+        .collect())
+}
+
+/// Split CSV text into rows of cells.
+///
+/// Handles quoted cells with embedded commas, newlines, and `""` escapes.
+fn parse_csv(text: &str) -> Vec<Vec<String>> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut cell = String::new();
+    let mut quoted = false;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                cell.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => row.push(std::mem::take(&mut cell)),
+            '\n' if !quoted => {
+                row.push(std::mem::take(&mut cell));
+                rows.push(std::mem::take(&mut row));
+            }
+            '\r' if !quoted => {}
+            _ => cell.push(c),
+        }
+    }
+    if !cell.is_empty() || !row.is_empty() {
+        row.push(cell);
+        rows.push(row);
+    }
+    rows
+}
+
+/// Render a cell as a DSL value: integers bare, everything else quoted.
+fn dsl_value(value: &str) -> String {
+    if value.parse::<i64>().is_ok() {
+        value.to_string()
+    } else {
+        quote(value)
+    }
+}
+
+/// Lowercase a header or trait name into a property key.
+fn slug(s: &str) -> String {
+    s.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+        .to_lowercase()
+}
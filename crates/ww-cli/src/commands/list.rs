@@ -2,14 +2,22 @@ use std::path::Path;
 
 use comfy_table::{ContentArrangement, Table};
 use ww_core::entity::EntityKind;
+use ww_core::grammar::Grammar;
 
-pub fn run(dir: &Path, kind: Option<&str>, tag: Option<&str>) -> Result<(), String> {
+pub fn run(
+    dir: &Path,
+    kind: Option<&str>,
+    tag: Option<&str>,
+    related_to: Option<&str>,
+) -> Result<(), String> {
     let world = super::compile_dir(dir)?;
 
     let mut query = world.query();
+    let mut noun = "entity".to_string();
 
     if let Some(kind_str) = kind {
         let (entity_kind, _) = EntityKind::parse(kind_str);
+        noun = entity_kind.to_string();
         query = query.kind(entity_kind);
     }
 
@@ -17,6 +25,13 @@ pub fn run(dir: &Path, kind: Option<&str>, tag: Option<&str>) -> Result<(), Stri
         query = query.tag(tag_str);
     }
 
+    if let Some(name) = related_to {
+        let anchor = world
+            .find_by_name(name)
+            .ok_or_else(|| format!("entity not found: \"{name}\""))?;
+        query = query.related_to(anchor.id, None);
+    }
+
     let results = query.execute();
 
     if results.is_empty() {
@@ -26,6 +41,9 @@ pub fn run(dir: &Path, kind: Option<&str>, tag: Option<&str>) -> Result<(), Stri
 
     let mut table = Table::new();
     table.set_content_arrangement(ContentArrangement::Dynamic);
+    if let Some(width) = super::output_width() {
+        table.set_width(width as u16);
+    }
     table.set_header(vec!["Name", "Kind", "Description"]);
 
     for entity in &results {
@@ -48,7 +66,8 @@ pub fn run(dir: &Path, kind: Option<&str>, tag: Option<&str>) -> Result<(), Stri
 
     println!("{table}");
     println!();
-    println!("  {} entities", results.len());
+    let grammar = Grammar::from_meta(&world.meta);
+    println!("  {}", grammar.count(results.len(), &noun));
 
     Ok(())
 }
@@ -1,58 +1,101 @@
 pub mod build;
 pub mod check;
+pub mod completions;
+pub mod diff;
 pub mod export;
+pub mod fmt;
 pub mod graph;
+pub mod import;
 pub mod init;
 pub mod list;
 pub mod new;
 pub mod play;
 pub mod search;
+pub mod sheet;
 pub mod show;
 pub mod simulate;
 pub mod solo;
+pub mod suggest;
 pub mod timeline;
 pub mod tui;
+pub mod watch;
 
+use std::io::IsTerminal;
 use std::path::Path;
+use std::sync::OnceLock;
 
 use ww_core::World;
-use ww_dsl::CompileResult;
-use ww_dsl::diagnostics::{Severity, render_diagnostics};
+use ww_dsl::diagnostics::{Diagnostic, Severity, render_diagnostics};
+use ww_dsl::{CompileResult, CompileTimings};
+
+static OUTPUT_WIDTH: OnceLock<Option<usize>> = OnceLock::new();
+
+/// Fix the column width used to wrap text output.
+///
+/// An explicit `--width` wins; otherwise the terminal's width is used when
+/// stdout is a terminal. Piped output is left unwrapped.
+pub fn init_output_width(width: Option<usize>) {
+    let width = width.or_else(|| {
+        std::io::stdout()
+            .is_terminal()
+            .then(|| crossterm::terminal::size().ok())
+            .flatten()
+            .map(|(cols, _)| cols as usize)
+    });
+    let _ = OUTPUT_WIDTH.set(width);
+}
+
+/// Word-wrap text to the output width, indenting each line by `indent`.
+pub fn wrap(text: &str, indent: &str) -> String {
+    ww_core::text::wrap_indented(text, output_width().unwrap_or(0), indent)
+}
+
+/// The output width, if text output is wrapped at all.
+pub fn output_width() -> Option<usize> {
+    OUTPUT_WIDTH.get().copied().flatten()
+}
 
 /// Compile a directory of .ww files and print diagnostics.
 /// Returns the compiled world if there are no errors.
 pub fn compile_dir(dir: &Path) -> Result<World, String> {
+    compile_dir_timed(dir).map(|(world, _)| world)
+}
+
+/// Like [`compile_dir`], but also returns how long each compile phase took.
+pub fn compile_dir_timed(dir: &Path) -> Result<(World, CompileTimings), String> {
+    compile_dir_result(dir).map(|result| (result.world, result.timings))
+}
+
+/// Like [`compile_dir`], but returns the full compile result.
+pub fn compile_dir_result(dir: &Path) -> Result<CompileResult, String> {
     let result = ww_dsl::compile_dir(dir);
-    print_diagnostics(&result, dir);
+    print_diagnostics(&result.diagnostics, dir);
 
     if result.has_errors() {
         Err("compilation failed with errors".into())
     } else {
-        Ok(result.world)
+        Ok(result)
     }
 }
 
 /// Print diagnostics to stderr using ariadne.
-fn print_diagnostics(result: &CompileResult, dir: &Path) {
-    let has_diags = !result.diagnostics.is_empty();
-    if !has_diags {
+pub fn print_diagnostics(diagnostics: &[Diagnostic], dir: &Path) {
+    if diagnostics.is_empty() {
         return;
     }
 
     // Read all source files to provide context for diagnostics
-    let source = read_all_sources(dir);
+    let source = ww_dsl::read_dir_sources(dir);
     let filename = dir.display().to_string();
 
-    let rendered = render_diagnostics(&source, &filename, &result.diagnostics);
+    let rendered = render_diagnostics(&source, &filename, diagnostics);
     eprint!("{rendered}");
 
-    let errors = result
-        .diagnostics
+    let errors = diagnostics
         .iter()
         .filter(|d| d.severity == Severity::Error)
         .count();
-    let warnings = result
-        .diagnostics
+    let warnings = diagnostics
         .iter()
         .filter(|d| d.severity == Severity::Warning)
         .count();
@@ -73,24 +116,3 @@ fn print_diagnostics(result: &CompileResult, dir: &Path) {
         );
     }
 }
-
-/// Read and concatenate all .ww source files (for diagnostic rendering).
-fn read_all_sources(dir: &Path) -> String {
-    let mut sources = String::new();
-    if let Ok(entries) = std::fs::read_dir(dir) {
-        let mut files: Vec<_> = entries
-            .filter_map(|e| e.ok())
-            .filter(|e| e.path().extension().is_some_and(|ext| ext == "ww"))
-            .collect();
-        files.sort_by_key(|e| e.path());
-        for entry in files {
-            if let Ok(content) = std::fs::read_to_string(entry.path()) {
-                if !sources.is_empty() {
-                    sources.push('\n');
-                }
-                sources.push_str(&content);
-            }
-        }
-    }
-    sources
-}
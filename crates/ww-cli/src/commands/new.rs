@@ -12,28 +12,32 @@ pub fn run(kind: &str, name: &str, file: Option<&Path>) -> Result<(), String> {
         Path::new(&filename).to_path_buf()
     };
 
-    // Append to existing file or create new
+    append(&target, &stub)?;
+
+    println!("  Added {} '{}' to {}", kind, name, target.display());
+
+    Ok(())
+}
+
+/// Append DSL text to a `.ww` file, creating it if needed.
+pub fn append(target: &Path, text: &str) -> Result<(), String> {
     let mut content = if target.exists() {
-        let existing = fs::read_to_string(&target)
+        let existing = fs::read_to_string(target)
             .map_err(|e| format!("cannot read {}: {e}", target.display()))?;
         if existing.ends_with('\n') {
-            format!("{existing}\n{stub}")
+            format!("{existing}\n{text}")
         } else {
-            format!("{existing}\n\n{stub}")
+            format!("{existing}\n\n{text}")
         }
     } else {
-        stub.clone()
+        text.to_string()
     };
 
     if !content.ends_with('\n') {
         content.push('\n');
     }
 
-    fs::write(&target, content).map_err(|e| format!("cannot write {}: {e}", target.display()))?;
-
-    println!("  Added {} '{}' to {}", kind, name, target.display());
-
-    Ok(())
+    fs::write(target, content).map_err(|e| format!("cannot write {}: {e}", target.display()))
 }
 
 fn generate_stub(kind: &str, name: &str) -> String {
@@ -99,6 +103,19 @@ fn generate_stub(kind: &str, name: &str) -> String {
     type article
     source "Unknown"
 
+    """
+    Description of {name}.
+    """
+}}"#
+        ),
+        "creature" => format!(
+            r#"{name} is {article} {kind} {{
+    type beast
+    combat 0
+    instinct 0
+    hits 1
+    weapon "Claws"
+
     """
     Description of {name}.
     """
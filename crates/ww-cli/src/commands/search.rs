@@ -1,9 +1,22 @@
 use std::path::Path;
 
-pub fn run(dir: &Path, query: &str) -> Result<(), String> {
+/// Minimum name similarity for `--fuzzy` matches (0.0-1.0).
+const FUZZY_THRESHOLD: f64 = 0.8;
+
+pub fn run(dir: &Path, query: &str, regex: bool, fuzzy: bool) -> Result<(), String> {
     let world = super::compile_dir(dir)?;
 
-    let results = world.search(query);
+    let results = if regex {
+        world
+            .query()
+            .matches_regex(query)
+            .map_err(|e| e.to_string())?
+            .execute()
+    } else if fuzzy {
+        world.query().fuzzy(query, FUZZY_THRESHOLD).execute()
+    } else {
+        world.search(query)
+    };
 
     if results.is_empty() {
         println!("  No results for \"{}\".", query);
@@ -28,7 +41,7 @@ pub fn run(dir: &Path, query: &str) -> Result<(), String> {
             } else {
                 entity.description.clone()
             };
-            println!("    {}", preview.trim());
+            println!("{}", super::wrap(preview.trim(), "    "));
         }
     }
 
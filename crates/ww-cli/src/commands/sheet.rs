@@ -0,0 +1,23 @@
+use std::path::Path;
+
+use ww_mechanics::{CharacterSheet, RuleSet};
+
+pub fn run(dir: &Path, name: &str, format: &str) -> Result<(), String> {
+    let world = super::compile_dir(dir)?;
+    let ruleset =
+        RuleSet::from_world(&world).map_err(|e| format!("no game mechanics in this world: {e}"))?;
+    let entity = world
+        .find_by_name(name)
+        .ok_or_else(|| format!("entity not found: \"{name}\""))?;
+    let sheet = CharacterSheet::from_entity(entity, &ruleset).map_err(|e| e.to_string())?;
+
+    match format {
+        "md" | "markdown" => print!("{}", sheet.to_markdown(&ruleset)),
+        "json" => println!("{}", sheet.to_json()),
+        _ => {
+            return Err(format!("unsupported format: \"{format}\". Use: md, json"));
+        }
+    }
+
+    Ok(())
+}
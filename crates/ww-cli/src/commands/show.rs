@@ -1,9 +1,20 @@
 use std::path::Path;
 
 use colored::Colorize;
-
-pub fn run(dir: &Path, name: &str, show_relationships: bool) -> Result<(), String> {
-    let world = super::compile_dir(dir)?;
+use ww_core::display::DetailTemplate;
+use ww_core::timeline::Timeline;
+
+pub fn run(
+    dir: &Path,
+    name: &str,
+    show_relationships: bool,
+    show_timeline: bool,
+) -> Result<(), String> {
+    let mut world = super::compile_dir(dir)?;
+    if show_relationships {
+        // List each relationship from both ends
+        world.add_inverse_relationships();
+    }
 
     let entity = world
         .find_by_name(name)
@@ -20,9 +31,21 @@ pub fn run(dir: &Path, name: &str, show_relationships: bool) -> Result<(), Strin
 
     // Description
     if !entity.description.is_empty() {
-        for line in entity.description.lines() {
-            println!("  {}", line.trim());
-        }
+        let lines: Vec<&str> = entity.description.lines().map(str::trim).collect();
+        println!("{}", super::wrap(&lines.join("\n"), "  "));
+        println!();
+    }
+
+    // GM notes
+    for note in &entity.gm_notes {
+        let wrapped = super::wrap(note.trim(), "      ");
+        println!(
+            "  {} {}",
+            "GM:".magenta().bold(),
+            wrapped.trim_start().italic()
+        );
+    }
+    if !entity.gm_notes.is_empty() {
         println!();
     }
 
@@ -100,13 +123,18 @@ pub fn run(dir: &Path, name: &str, show_relationships: bool) -> Result<(), Strin
         }
     }
 
-    // Generic properties
-    if !entity.properties.is_empty() {
-        let mut props: Vec<_> = entity.properties.iter().collect();
-        props.sort_by_key(|(k, _)| (*k).clone());
-        for (key, value) in props {
-            println!("  {key}: {value}");
-        }
+    // Generic properties, templated ones first in the world's order
+    let template = DetailTemplate::for_entity(&world.meta, entity).unwrap_or_default();
+    let layout = template.layout(entity);
+    for (label, value) in &layout.fields {
+        println!("  {label}: {value}");
+    }
+    if !layout.fields.is_empty() && !layout.other.is_empty() {
+        println!();
+        println!("  {}", "Other:".dimmed());
+    }
+    for (key, value) in &layout.other {
+        println!("  {key}: {value}");
     }
 
     // Tags
@@ -124,84 +152,25 @@ pub fn run(dir: &Path, name: &str, show_relationships: bool) -> Result<(), Strin
         } else {
             println!("  {}", "Relationships:".dimmed());
             for rel in &rels {
-                let other_id = if rel.source == entity.id {
-                    rel.target
-                } else {
-                    rel.source
-                };
-                let other_name = world.entity_name(other_id);
-
-                let phrase = if rel.source == entity.id {
-                    // Outgoing: "self <phrase> target"
-                    format_outgoing_rel(&rel.kind, other_name, rel.label.as_deref())
-                } else {
-                    // Incoming: "source <phrase> self"
-                    format_incoming_rel(&rel.kind, other_name, rel.label.as_deref())
-                };
-                println!("    {phrase}");
+                println!("    {}", rel.describe(&world));
             }
         }
     }
 
-    Ok(())
-}
-
-fn format_outgoing_rel(
-    kind: &ww_core::RelationshipKind,
-    target: &str,
-    label: Option<&str>,
-) -> String {
-    use ww_core::RelationshipKind::*;
-    match kind {
-        ContainedIn => format!("in {target}"),
-        ConnectedTo => {
-            if let Some(dir) = label {
-                format!("{dir} to {target}")
-            } else {
-                format!("connected to {target}")
+    // Personal timeline
+    if show_timeline {
+        println!();
+        let timeline = Timeline::from_world(&world);
+        let events = timeline.events_involving(&world, entity.id);
+        if events.is_empty() {
+            println!("  {} (none)", "Timeline:".dimmed());
+        } else {
+            println!("  {}", "Timeline:".dimmed());
+            for entry in &events {
+                println!("    {:>30}  {}", entry.date.to_string(), entry.entity.name);
             }
         }
-        LocatedAt => format!("located at {target}"),
-        BasedAt => format!("based at {target}"),
-        MemberOf => format!("member of {target}"),
-        LeaderOf => format!("leads {target}"),
-        AlliedWith => format!("allied with {target}"),
-        RivalOf => format!("rival of {target}"),
-        RelatedTo => format!("related to {target}"),
-        OwnedBy => format!("owns {target}"),
-        ParticipatedIn => format!("participated in {target}"),
-        CausedBy => format!("caused by {target}"),
-        References => format!("references {target}"),
-        Custom(s) => format!("{s} {target}"),
     }
-}
 
-fn format_incoming_rel(
-    kind: &ww_core::RelationshipKind,
-    source: &str,
-    label: Option<&str>,
-) -> String {
-    use ww_core::RelationshipKind::*;
-    match kind {
-        ContainedIn => format!("contains {source}"),
-        ConnectedTo => {
-            if let Some(dir) = label {
-                format!("{dir} from {source}")
-            } else {
-                format!("connected from {source}")
-            }
-        }
-        LocatedAt => format!("{source} is located here"),
-        BasedAt => format!("{source} is based here"),
-        MemberOf => format!("{source} is a member"),
-        LeaderOf => format!("led by {source}"),
-        AlliedWith => format!("allied with {source}"),
-        RivalOf => format!("rival of {source}"),
-        RelatedTo => format!("related to {source}"),
-        OwnedBy => format!("owned by {source}"),
-        ParticipatedIn => format!("{source} participated"),
-        CausedBy => format!("caused {source}"),
-        References => format!("referenced by {source}"),
-        Custom(s) => format!("{s} from {source}"),
-    }
+    Ok(())
 }
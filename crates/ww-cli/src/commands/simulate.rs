@@ -6,16 +6,73 @@ use comfy_table::{ContentArrangement, Table};
 use ww_core::component::CharacterStatus;
 use ww_core::entity::EntityKind;
 use ww_simulation::SimEventKind;
+use ww_simulation::economy::EconomySystem;
+use ww_simulation::narrate::narrate_event;
 use ww_simulation::needs::{NeedKind, NeedsSystem};
 use ww_simulation::schedule::ScheduleSystem;
 use ww_simulation::spatial::SpatialSystem;
-use ww_simulation::{SimConfig, Simulation};
+use ww_simulation::weather::WeatherSystem;
+use ww_simulation::{SimConfig, SimSnapshot, Simulation};
 
-pub fn run(dir: &Path, ticks: u64, seed: u64, speed: f64, verbose: bool) -> Result<(), String> {
-    let world = super::compile_dir(dir)?;
+/// How events are reported while and after the simulation runs.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Feed<'a> {
+    /// Only notable events (deaths, critical needs) after the run.
+    Summary,
+    /// The full event log after the run, grouped by tick.
+    Verbose,
+    /// Each event narrated as prose as its tick unfolds.
+    Narrate,
+    /// A per-tick diary of the named character after the run.
+    Focus(&'a str),
+}
+
+/// Files the simulation reads from and writes to.
+#[derive(Clone, Copy)]
+pub struct Files<'a> {
+    /// Write the final world and event log here.
+    pub out: Option<&'a Path>,
+    /// Format for `out`; inferred from its extension when unset.
+    pub format: Option<&'a str>,
+    /// Save a snapshot of the finished run here.
+    pub save: Option<&'a Path>,
+    /// Resume from a snapshot saved earlier instead of compiling the world.
+    pub load: Option<&'a Path>,
+}
+
+pub fn run(
+    dir: &Path,
+    ticks: u64,
+    seed: Option<u64>,
+    speed: f64,
+    feed: Feed<'_>,
+    files: Files<'_>,
+) -> Result<(), String> {
+    let snapshot = match files.load {
+        Some(path) => {
+            let json = std::fs::read_to_string(path)
+                .map_err(|e| format!("cannot read {}: {e}", path.display()))?;
+            Some(SimSnapshot::from_json(&json).map_err(|e| format!("{}: {e}", path.display()))?)
+        }
+        None => None,
+    };
+    let world = match &snapshot {
+        Some(snapshot) => snapshot.world().map_err(|e| e.to_string())?,
+        None => super::compile_dir(dir)?,
+    };
+    let seed = world
+        .meta
+        .rng_seed(seed)
+        .unwrap_or(SimConfig::default().seed);
+
+    // Validate the output format before spending time on the run
+    let out = match files.out {
+        Some(path) => Some((path, output_format(path, files.format)?)),
+        None => None,
+    };
 
     // Collect living characters
-    let char_info: Vec<_> = world
+    let mut char_info: Vec<_> = world
         .entities_by_kind(&EntityKind::Character)
         .iter()
         .filter(|e| {
@@ -32,6 +89,19 @@ pub fn run(dir: &Path, ticks: u64, seed: u64, speed: f64, verbose: bool) -> Resu
         return Ok(());
     }
 
+    // A focused run reports on that character alone
+    let focus = match feed {
+        Feed::Focus(name) => {
+            let lower = name.to_lowercase();
+            char_info.retain(|(_, n)| n.to_lowercase() == lower);
+            let (id, _) = char_info
+                .first()
+                .ok_or_else(|| format!("no living character named \"{name}\""))?;
+            Some(*id)
+        }
+        _ => None,
+    };
+
     // Build and run simulation
     let config = SimConfig::default()
         .with_seed(seed)
@@ -42,11 +112,53 @@ pub fn run(dir: &Path, ticks: u64, seed: u64, speed: f64, verbose: bool) -> Resu
     sim.add_system(NeedsSystem::with_default_config());
     sim.add_system(ScheduleSystem::new());
     sim.add_system(SpatialSystem::new());
+    sim.add_system(WeatherSystem::new());
+    sim.add_system(EconomySystem::with_default_config());
 
-    sim.init()
-        .map_err(|e| format!("simulation init failed: {e}"))?;
-    sim.run(ticks)
-        .map_err(|e| format!("simulation error: {e}"))?;
+    if let Some(snapshot) = snapshot {
+        sim.restore(snapshot)
+            .map_err(|e| format!("cannot resume simulation: {e}"))?;
+        println!("  Resumed at tick {}", sim.current_tick());
+    } else {
+        sim.init()
+            .map_err(|e| format!("simulation init failed: {e}"))?;
+    }
+    let start_tick = sim.current_tick();
+    if let Some(id) = focus {
+        sim.focus(id);
+    }
+    if feed == Feed::Narrate {
+        println!("  {}", "Chronicle".bold().underline());
+        println!();
+        for _ in 0..ticks {
+            sim.tick().map_err(|e| format!("simulation error: {e}"))?;
+            let tick = sim.current_tick();
+            let clock = sim.clock();
+            let stamp = format!(
+                "[day {} {:02}:00]",
+                clock.day_index_at_tick(tick) + 1,
+                clock.hour_at_tick(tick).floor() as u32
+            );
+            // Wrapped lines continue under the text, past the stamp
+            let indent = " ".repeat(stamp.len() + 3);
+            for event in sim.events().events_at_tick(tick) {
+                let line = narrate_event(event, sim.world());
+                let wrapped = super::wrap(&line, &indent);
+                for (i, part) in wrapped.lines().enumerate() {
+                    let part = colorize_event(&event.kind, part.trim_start());
+                    if i == 0 {
+                        println!("  {} {part}", stamp.dimmed());
+                    } else {
+                        println!("{indent}{part}");
+                    }
+                }
+            }
+        }
+        println!();
+    } else {
+        sim.run(ticks)
+            .map_err(|e| format!("simulation error: {e}"))?;
+    }
 
     // Header
     let date = sim.clock().current_date();
@@ -65,19 +177,51 @@ pub fn run(dir: &Path, ticks: u64, seed: u64, speed: f64, verbose: bool) -> Resu
     println!();
 
     // Events
-    if verbose {
+    if let Some(diary) = sim.render_diary() {
+        for line in diary.lines() {
+            if line.is_empty() {
+                println!();
+            } else if line.starts_with("Diary of") || line.starts_with("Day ") {
+                println!("  {}", line.bold());
+            } else {
+                println!("{}", super::wrap(line, "  "));
+            }
+        }
+        println!();
+    } else if feed == Feed::Verbose {
         println!("  {}", "Event Log".bold().underline());
         println!();
+        // Group by tick, leading each group with what changed in it
+        let mut current_tick = None;
         for event in sim.events().events() {
-            let tick_label = format!("[tick {:>3}]", event.tick).dimmed();
+            if current_tick != Some(event.tick) {
+                current_tick = Some(event.tick);
+                let changed: Vec<String> = sim
+                    .events()
+                    .changes_at_tick(event.tick)
+                    .iter()
+                    .map(|c| {
+                        let aspects: Vec<String> =
+                            c.aspects.iter().map(|a| a.to_string()).collect();
+                        format!(
+                            "{} ({})",
+                            sim.world().entity_name(c.entity),
+                            aspects.join(", ")
+                        )
+                    })
+                    .collect();
+                let tick_label = format!("[tick {:>3}]", event.tick).dimmed();
+                println!("  {tick_label} {}", changed.join(", ").bold());
+            }
             let desc = colorize_event(&event.kind, &event.description);
-            println!("  {tick_label} {desc}");
+            println!("             {desc}");
         }
         if sim.events().is_empty() {
             println!("  {}", "(no events)".dimmed());
         }
         println!();
-    } else {
+        print_resource_changes(&sim, start_tick);
+    } else if feed == Feed::Summary {
         // Notable events only
         let deaths: Vec<_> = sim
             .events()
@@ -203,9 +347,76 @@ pub fn run(dir: &Path, ticks: u64, seed: u64, speed: f64, verbose: bool) -> Resu
         println!();
     }
 
+    if let Some((path, format)) = out {
+        let content = match format {
+            OutputFormat::Json => serde_json::to_string_pretty(&simulation_json(&sim))
+                .map_err(|e| format!("JSON serialization error: {e}"))?,
+            OutputFormat::Markdown => sim.chronicle(),
+        };
+        std::fs::write(path, content)
+            .map_err(|e| format!("cannot write to {}: {e}", path.display()))?;
+        println!("  Wrote {}", path.display());
+    }
+
+    if let Some(path) = files.save {
+        std::fs::write(path, sim.snapshot().to_json())
+            .map_err(|e| format!("cannot write to {}: {e}", path.display()))?;
+        println!("  Saved snapshot to {}", path.display());
+    }
+
     Ok(())
 }
 
+#[derive(Clone, Copy)]
+enum OutputFormat {
+    Json,
+    Markdown,
+}
+
+/// Pick the output format from `--format`, falling back to the file extension.
+fn output_format(path: &Path, format: Option<&str>) -> Result<OutputFormat, String> {
+    let format = format.map(str::to_string).unwrap_or_else(|| {
+        path.extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or("json")
+            .to_lowercase()
+    });
+    match format.as_str() {
+        "json" => Ok(OutputFormat::Json),
+        "markdown" | "md" => Ok(OutputFormat::Markdown),
+        other => Err(format!(
+            "unsupported format: \"{other}\". Use: json, markdown"
+        )),
+    }
+}
+
+/// The final world plus the event log, with in-world dates for each event.
+fn simulation_json(sim: &Simulation) -> serde_json::Value {
+    let clock = sim.clock();
+    let events: Vec<_> = sim
+        .events()
+        .events()
+        .iter()
+        .map(|e| {
+            serde_json::json!({
+                "tick": e.tick,
+                "date": clock.date_at_tick(e.tick).to_string(),
+                "kind": e.kind.label(),
+                "description": e.description,
+            })
+        })
+        .collect();
+
+    let mut json = super::export::world_json(sim.world(), false);
+    json["simulation"] = serde_json::json!({
+        "ticks": clock.tick(),
+        "hours_per_tick": clock.hours_per_tick(),
+        "date": clock.current_date().to_string(),
+    });
+    json["events"] = serde_json::Value::Array(events);
+    json
+}
+
 fn colorize_event(kind: &SimEventKind, description: &str) -> colored::ColoredString {
     match kind {
         SimEventKind::EntityDied { .. } => description.red().bold(),
@@ -214,10 +425,49 @@ fn colorize_event(kind: &SimEventKind, description: &str) -> colored::ColoredStr
         SimEventKind::NeedSatisfied { .. } => description.green(),
         SimEventKind::ActivityChanged { .. } => description.cyan(),
         SimEventKind::Departed { .. } | SimEventKind::Arrived { .. } => description.blue(),
+        SimEventKind::WeatherChanged { .. } => description.magenta(),
+        SimEventKind::ResourceSurplus { .. } => description.green(),
+        SimEventKind::ResourceShortage { .. } => description.yellow(),
         SimEventKind::Custom { .. } => description.normal(),
     }
 }
 
+/// Each faction's wealth at the start and end of this run.
+fn print_resource_changes(sim: &Simulation, start_tick: u64) {
+    let Some(economy) = sim.get_system::<EconomySystem>() else {
+        return;
+    };
+    let mut rows: Vec<_> = economy
+        .all_economies()
+        .keys()
+        .map(|&id| {
+            let before = economy.wealth_at(id, start_tick).unwrap_or(0.0);
+            let after = economy.wealth(id).unwrap_or(0.0);
+            (sim.world().entity_name(id).to_string(), before, after)
+        })
+        .collect();
+    if rows.is_empty() {
+        return;
+    }
+    rows.sort_by_key(|(name, _, _)| name.to_lowercase());
+
+    println!("  {}", "Resources".bold().underline());
+    println!();
+    let mut table = Table::new();
+    table.set_content_arrangement(ContentArrangement::Dynamic);
+    table.set_header(vec!["Faction", "Start", "End", "Change"]);
+    for (name, before, after) in rows {
+        table.add_row(vec![
+            name,
+            format!("{before:.1}"),
+            format!("{after:.1}"),
+            format!("{:+.1}", after - before),
+        ]);
+    }
+    println!("{table}");
+    println!();
+}
+
 fn format_need_bar(val: f64) -> String {
     let pct = (val * 100.0) as u32;
     let filled = (val * 10.0).round() as usize;
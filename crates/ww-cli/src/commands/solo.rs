@@ -3,17 +3,16 @@
 use std::path::Path;
 
 /// Launch the ww-tui standalone binary for solo TTRPG sessions.
-pub fn run(dir: &Path, seed: u64, chaos: u32) -> Result<(), String> {
-    let status = std::process::Command::new("ww-tui")
-        .arg("--world")
-        .arg(dir)
-        .arg("--tab")
-        .arg("solo")
-        .arg("--seed")
-        .arg(seed.to_string())
-        .arg("--chaos")
-        .arg(chaos.to_string())
-        .status();
+pub fn run(dir: &Path, seed: Option<u64>, chaos: u32, width: Option<usize>) -> Result<(), String> {
+    let mut command = std::process::Command::new("ww-tui");
+    command.arg("--world").arg(dir).arg("--tab").arg("solo");
+    if let Some(seed) = seed {
+        command.arg("--seed").arg(seed.to_string());
+    }
+    if let Some(width) = width {
+        command.arg("--width").arg(width.to_string());
+    }
+    let status = command.arg("--chaos").arg(chaos.to_string()).status();
 
     match status {
         Ok(s) if s.success() => Ok(()),
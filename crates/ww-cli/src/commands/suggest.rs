@@ -0,0 +1,45 @@
+use std::path::Path;
+
+use ww_core::grammar::Grammar;
+use ww_core::suggest::suggest;
+
+pub fn run(dir: &Path, limit: usize) -> Result<(), String> {
+    let world = super::compile_dir(dir)?;
+    let grammar = Grammar::from_meta(&world.meta);
+
+    let suggestions = suggest(&world);
+    if suggestions.is_empty() {
+        println!("  Nothing to suggest: every referenced entity is fleshed out.");
+        return Ok(());
+    }
+
+    println!("  Entities to flesh out in '{}':", world.meta.name);
+    println!();
+
+    for (rank, s) in suggestions.iter().take(limit).enumerate() {
+        let kind_str = if let Some(subtype) = s.entity.location_subtype() {
+            format!("{} ({})", s.entity.kind, subtype)
+        } else {
+            s.entity.kind.to_string()
+        };
+        let description = if s.description_words == 0 {
+            "no description".to_string()
+        } else {
+            format!("{}-word description", s.description_words)
+        };
+        println!("  {:>2}. {} [{}]", rank + 1, s.entity.name, kind_str);
+        println!(
+            "      referenced by {}; {}, {}",
+            grammar.count(s.references, "entity"),
+            description,
+            grammar.count(s.properties, "property")
+        );
+    }
+
+    if suggestions.len() > limit {
+        println!();
+        println!("  ... and {} more", suggestions.len() - limit);
+    }
+
+    Ok(())
+}
@@ -1,11 +1,15 @@
 use std::path::Path;
 
+use ww_core::WorldMeta;
+use ww_core::grammar::Grammar;
 use ww_core::timeline::Timeline;
 
-pub fn run(dir: &Path, from: Option<i64>, to: Option<i64>) -> Result<(), String> {
+pub fn run(dir: &Path, from: Option<&str>, to: Option<&str>) -> Result<(), String> {
     let world = super::compile_dir(dir)?;
 
-    let timeline = Timeline::from_world(&world).range(from, to);
+    let from_year = from.map(|b| parse_bound(&world.meta, b)).transpose()?;
+    let to_year = to.map(|b| parse_bound(&world.meta, b)).transpose()?;
+    let timeline = Timeline::from_world(&world).range(from_year, to_year);
 
     if timeline.is_empty() {
         println!("  No events found in the timeline.");
@@ -17,9 +21,11 @@ pub fn run(dir: &Path, from: Option<i64>, to: Option<i64>) -> Result<(), String>
 
     println!("  Timeline for '{}'", world.meta.name);
     if from.is_some() || to.is_some() {
-        let from_str = from.map(|y| y.to_string()).unwrap_or_else(|| "...".into());
-        let to_str = to.map(|y| y.to_string()).unwrap_or_else(|| "...".into());
-        println!("  Range: {from_str} to {to_str}");
+        println!(
+            "  Range: {} to {}",
+            from.unwrap_or("..."),
+            to.unwrap_or("...")
+        );
     }
     println!();
 
@@ -56,7 +62,25 @@ pub fn run(dir: &Path, from: Option<i64>, to: Option<i64>) -> Result<(), String>
     }
 
     println!();
-    println!("  {} events", timeline.len());
+    let grammar = Grammar::from_meta(&world.meta);
+    println!("  {}", grammar.count(timeline.len(), "event"));
 
     Ok(())
 }
+
+/// Parse a `--from`/`--to` bound: an absolute year, or `era:year` counted
+/// from the start of a declared era.
+fn parse_bound(meta: &WorldMeta, bound: &str) -> Result<i64, String> {
+    let invalid = || format!("invalid year '{bound}' (expected N or era:N)");
+    match bound.rsplit_once(':') {
+        Some((era, year)) => {
+            let year: i64 = year.trim().parse().map_err(|_| invalid())?;
+            let era = era.trim();
+            let start = meta
+                .era_start(era)
+                .ok_or_else(|| format!("unknown era '{era}'"))?;
+            Ok(start + year)
+        }
+        None => bound.trim().parse().map_err(|_| invalid()),
+    }
+}
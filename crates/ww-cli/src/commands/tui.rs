@@ -3,13 +3,13 @@
 use std::path::Path;
 
 /// Launch the ww-tui standalone binary.
-pub fn run(dir: &Path) -> Result<(), String> {
-    let status = std::process::Command::new("ww-tui")
-        .arg("--world")
-        .arg(dir)
-        .arg("--tab")
-        .arg("explorer")
-        .status();
+pub fn run(dir: &Path, width: Option<usize>) -> Result<(), String> {
+    let mut command = std::process::Command::new("ww-tui");
+    command.arg("--world").arg(dir).arg("--tab").arg("explorer");
+    if let Some(width) = width {
+        command.arg("--width").arg(width.to_string());
+    }
+    let status = command.status();
 
     match status {
         Ok(s) if s.success() => Ok(()),
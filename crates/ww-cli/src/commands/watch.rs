@@ -0,0 +1,84 @@
+use std::io::Write;
+use std::path::Path;
+use std::sync::mpsc;
+use std::time::Duration;
+
+use colored::Colorize;
+use crossterm::cursor::MoveTo;
+use crossterm::terminal::{Clear, ClearType};
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+
+/// How long a burst of file events must settle before recompiling, so a
+/// single editor save does not trigger several compiles.
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+enum Signal {
+    Changed,
+    Stop,
+}
+
+pub fn run(dir: &Path) -> Result<(), String> {
+    if !dir.is_dir() {
+        return Err(format!("not a directory: {}", dir.display()));
+    }
+
+    let (tx, rx) = mpsc::channel();
+    let stop = tx.clone();
+    ctrlc::set_handler(move || {
+        let _ = stop.send(Signal::Stop);
+    })
+    .map_err(|e| format!("cannot handle Ctrl+C: {e}"))?;
+
+    // Only writes matter: compiling reads the files, and those access events
+    // must not trigger another compile.
+    let mut watcher = notify::recommended_watcher(move |result: notify::Result<Event>| {
+        let changed = result.is_ok_and(|event| {
+            matches!(
+                event.kind,
+                EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+            ) && event
+                .paths
+                .iter()
+                .any(|path| path.extension().is_some_and(|ext| ext == "ww"))
+        });
+        if changed {
+            let _ = tx.send(Signal::Changed);
+        }
+    })
+    .map_err(|e| format!("cannot start file watcher: {e}"))?;
+    watcher
+        .watch(dir, RecursiveMode::Recursive)
+        .map_err(|e| format!("cannot watch {}: {e}", dir.display()))?;
+
+    check(dir);
+    'watch: while let Ok(Signal::Changed) = rx.recv() {
+        loop {
+            match rx.recv_timeout(DEBOUNCE) {
+                Ok(Signal::Changed) => {}
+                Ok(Signal::Stop) => break 'watch,
+                Err(mpsc::RecvTimeoutError::Timeout) => break,
+                Err(mpsc::RecvTimeoutError::Disconnected) => break 'watch,
+            }
+        }
+        check(dir);
+    }
+
+    println!();
+    println!("  Stopped watching.");
+    Ok(())
+}
+
+/// Clear the screen and run `ww check` once, reporting failures inline.
+fn check(dir: &Path) {
+    let mut stdout = std::io::stdout();
+    let _ = crossterm::execute!(stdout, Clear(ClearType::All), MoveTo(0, 0));
+    if let Err(e) = super::check::run(dir, false, false, &[], &[]) {
+        eprintln!("error: {e}");
+    }
+    println!();
+    println!(
+        "  {}",
+        format!("Watching {} for changes (Ctrl+C to stop)", dir.display()).dimmed()
+    );
+    let _ = stdout.flush();
+}
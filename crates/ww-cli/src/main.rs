@@ -5,7 +5,7 @@ mod commands;
 use std::path::PathBuf;
 use std::process;
 
-use clap::{Parser, Subcommand};
+use clap::{CommandFactory, Parser, Subcommand};
 
 #[derive(Parser)]
 #[command(
@@ -15,6 +15,10 @@ use clap::{Parser, Subcommand};
     propagate_version = true
 )]
 struct Cli {
+    /// Wrap text output to this many columns (default: terminal width)
+    #[arg(long, global = true)]
+    width: Option<usize>,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -29,6 +33,10 @@ enum Commands {
 
     /// Compile all .ww files and report diagnostics
     Build {
+        /// Print how long each compile phase took
+        #[arg(long)]
+        timings: bool,
+
         /// Directory containing .ww files (default: current directory)
         #[arg(short, long, default_value = ".")]
         dir: PathBuf,
@@ -36,6 +44,40 @@ enum Commands {
 
     /// Validate .ww files without full compilation output
     Check {
+        /// Also list directed reference cycles (informational)
+        #[arg(long)]
+        cycles: bool,
+
+        /// Also warn about unreachable locations
+        #[arg(long)]
+        strict: bool,
+
+        /// Silence warnings with this diagnostic code (e.g. WW0303)
+        #[arg(long, value_name = "CODE")]
+        allow: Vec<String>,
+
+        /// Treat warnings with this diagnostic code as errors
+        #[arg(long, value_name = "CODE")]
+        deny: Vec<String>,
+
+        /// Directory containing .ww files (default: current directory)
+        #[arg(short, long, default_value = ".")]
+        dir: PathBuf,
+    },
+
+    /// Re-run checks whenever a .ww file changes
+    Watch {
+        /// Directory containing .ww files (default: current directory)
+        #[arg(short, long, default_value = ".")]
+        dir: PathBuf,
+    },
+
+    /// Rewrite .ww files in place with automatic fixes
+    Fmt {
+        /// Insert missing reciprocal exits and symmetric relationships
+        #[arg(long)]
+        fix_relationships: bool,
+
         /// Directory containing .ww files (default: current directory)
         #[arg(short, long, default_value = ".")]
         dir: PathBuf,
@@ -50,6 +92,10 @@ enum Commands {
         #[arg(short, long)]
         tag: Option<String>,
 
+        /// Only list entities with a relationship to this entity
+        #[arg(long, value_name = "NAME")]
+        related_to: Option<String>,
+
         /// Directory containing .ww files
         #[arg(short, long, default_value = ".")]
         dir: PathBuf,
@@ -64,6 +110,24 @@ enum Commands {
         #[arg(short, long)]
         relationships: bool,
 
+        /// Also show the dated events the entity took part in or caused
+        #[arg(short, long)]
+        timeline: bool,
+
+        /// Directory containing .ww files
+        #[arg(short, long, default_value = ".")]
+        dir: PathBuf,
+    },
+
+    /// Print a character's sheet under the world's game mechanics
+    Sheet {
+        /// Character name (case-insensitive)
+        name: String,
+
+        /// Output format: md, json
+        #[arg(short, long, default_value = "md")]
+        format: String,
+
         /// Directory containing .ww files
         #[arg(short, long, default_value = ".")]
         dir: PathBuf,
@@ -74,6 +138,25 @@ enum Commands {
         /// Search query
         query: String,
 
+        /// Treat the query as a regular expression
+        #[arg(long, conflicts_with = "fuzzy")]
+        regex: bool,
+
+        /// Match entity names approximately, tolerating typos
+        #[arg(long)]
+        fuzzy: bool,
+
+        /// Directory containing .ww files
+        #[arg(short, long, default_value = ".")]
+        dir: PathBuf,
+    },
+
+    /// Suggest referenced but thinly described entities to flesh out next
+    Suggest {
+        /// Show at most this many suggestions
+        #[arg(short = 'n', long, default_value_t = 10)]
+        limit: usize,
+
         /// Directory containing .ww files
         #[arg(short, long, default_value = ".")]
         dir: PathBuf,
@@ -82,9 +165,17 @@ enum Commands {
     /// Display an ASCII relationship graph
     Graph {
         /// Focus on a specific entity
-        #[arg(short, long)]
+        #[arg(short, long, conflicts_with = "path")]
         focus: Option<String>,
 
+        /// Show the shortest chain of relationships between two entities
+        #[arg(long, num_args = 2, value_names = ["FROM", "TO"])]
+        path: Option<Vec<String>>,
+
+        /// Only follow relationships in their stated direction (with --path)
+        #[arg(long, requires = "path")]
+        directed: bool,
+
         /// Directory containing .ww files
         #[arg(short, long, default_value = ".")]
         dir: PathBuf,
@@ -92,28 +183,53 @@ enum Commands {
 
     /// Display events in chronological order
     Timeline {
-        /// Start year (inclusive)
-        #[arg(long)]
-        from: Option<i64>,
+        /// Start year (inclusive), absolute or as era:year
+        #[arg(long, allow_hyphen_values = true)]
+        from: Option<String>,
 
-        /// End year (inclusive)
-        #[arg(long)]
-        to: Option<i64>,
+        /// End year (inclusive), absolute or as era:year
+        #[arg(long, allow_hyphen_values = true)]
+        to: Option<String>,
 
         /// Directory containing .ww files
         #[arg(short, long, default_value = ".")]
         dir: PathBuf,
     },
 
+    /// Compare two world directories entity by entity
+    Diff {
+        /// Directory with the original world
+        old: PathBuf,
+
+        /// Directory with the changed world
+        new: PathBuf,
+
+        /// Output format: text, json
+        #[arg(short, long, default_value = "text")]
+        format: String,
+    },
+
     /// Export the world to a different format
     Export {
-        /// Output format: json, markdown, html
+        /// Output format: json, markdown, html, svg, dot
         format: String,
 
         /// Output file path (default: stdout)
         #[arg(short, long)]
         output: Option<PathBuf>,
 
+        /// Include GM-only notes (excluded by default)
+        #[arg(long)]
+        include_gm_notes: bool,
+
+        /// Only export entities near this one (dot format)
+        #[arg(long)]
+        focus: Option<String>,
+
+        /// Number of relationship hops to include around --focus
+        #[arg(long, default_value = "1", requires = "focus")]
+        depth: usize,
+
         /// Directory containing .ww files
         #[arg(short, long, default_value = ".")]
         dir: PathBuf,
@@ -125,9 +241,9 @@ enum Commands {
         #[arg(short, long, default_value = "24")]
         ticks: u64,
 
-        /// RNG seed for deterministic simulation
-        #[arg(short, long, default_value = "42")]
-        seed: u64,
+        /// RNG seed for deterministic simulation (default: the world's rng.seed, else 42)
+        #[arg(short, long)]
+        seed: Option<u64>,
 
         /// In-world hours per tick
         #[arg(long, default_value = "1.0")]
@@ -137,6 +253,30 @@ enum Commands {
         #[arg(short, long)]
         verbose: bool,
 
+        /// Narrate events as prose while the simulation runs
+        #[arg(short, long, conflicts_with = "verbose")]
+        narrate: bool,
+
+        /// Follow one character, printing a per-tick diary of their day
+        #[arg(long, conflicts_with_all = ["verbose", "narrate"])]
+        focus: Option<String>,
+
+        /// Write the final world and event log to this file
+        #[arg(short, long)]
+        out: Option<PathBuf>,
+
+        /// Output file format: json, markdown (default: from the file extension)
+        #[arg(short, long, requires = "out")]
+        format: Option<String>,
+
+        /// Save a snapshot of the run to this file, to continue it later
+        #[arg(long)]
+        save: Option<PathBuf>,
+
+        /// Continue a run from a snapshot saved with --save (ignores --dir and --speed)
+        #[arg(long)]
+        load: Option<PathBuf>,
+
         /// Directory containing .ww files
         #[arg(short, long, default_value = ".")]
         dir: PathBuf,
@@ -155,9 +295,9 @@ enum Commands {
         #[arg(short, long, default_value = ".")]
         dir: PathBuf,
 
-        /// RNG seed for oracle rolls
-        #[arg(short, long, default_value = "42")]
-        seed: u64,
+        /// RNG seed for oracle rolls (default: the world's rng.seed, else 42)
+        #[arg(short, long)]
+        seed: Option<u64>,
 
         /// Initial chaos factor (1-9, default: 5)
         #[arg(short, long, default_value = "5")]
@@ -186,41 +326,139 @@ enum Commands {
         #[arg(short, long)]
         file: Option<PathBuf>,
     },
+
+    /// Import entities from a spreadsheet export
+    Import {
+        /// What to import
+        #[arg(value_parser = ["creatures"])]
+        kind: String,
+
+        /// CSV file with a header row (name, type, combat, instinct, hits, weapon, traits)
+        input: PathBuf,
+
+        /// File to append to (default: `<kind>.ww`)
+        #[arg(short, long)]
+        file: Option<PathBuf>,
+
+        /// Read a differently named column as a field (e.g. `--column HP=hits`)
+        #[arg(long = "column", value_name = "HEADER=FIELD")]
+        columns: Vec<String>,
+    },
+
+    /// Print a shell completion script, or the world's entity names
+    Completions {
+        /// Shell to generate a script for, or `entities` to list entity names
+        target: commands::completions::Target,
+
+        /// Directory containing .ww files (for `entities`)
+        #[arg(short, long, default_value = ".")]
+        dir: PathBuf,
+    },
 }
 
 fn main() {
     let cli = Cli::parse();
+    commands::init_output_width(cli.width);
 
     let result = match cli.command {
         Commands::Init { name } => commands::init::run(&name),
-        Commands::Build { dir } => commands::build::run(&dir),
-        Commands::Check { dir } => commands::check::run(&dir),
-        Commands::List { kind, tag, dir } => {
-            commands::list::run(&dir, kind.as_deref(), tag.as_deref())
-        }
+        Commands::Build { timings, dir } => commands::build::run(&dir, timings),
+        Commands::Check {
+            cycles,
+            strict,
+            allow,
+            deny,
+            dir,
+        } => commands::check::run(&dir, cycles, strict, &allow, &deny),
+        Commands::Watch { dir } => commands::watch::run(&dir),
+        Commands::Fmt {
+            fix_relationships,
+            dir,
+        } => commands::fmt::run(&dir, fix_relationships),
+        Commands::List {
+            kind,
+            tag,
+            related_to,
+            dir,
+        } => commands::list::run(&dir, kind.as_deref(), tag.as_deref(), related_to.as_deref()),
         Commands::Show {
             name,
             relationships,
+            timeline,
             dir,
-        } => commands::show::run(&dir, &name, relationships),
-        Commands::Search { query, dir } => commands::search::run(&dir, &query),
-        Commands::Graph { focus, dir } => commands::graph::run(&dir, focus.as_deref()),
-        Commands::Timeline { from, to, dir } => commands::timeline::run(&dir, from, to),
+        } => commands::show::run(&dir, &name, relationships, timeline),
+        Commands::Sheet { name, format, dir } => commands::sheet::run(&dir, &name, &format),
+        Commands::Search {
+            query,
+            regex,
+            fuzzy,
+            dir,
+        } => commands::search::run(&dir, &query, regex, fuzzy),
+        Commands::Suggest { limit, dir } => commands::suggest::run(&dir, limit),
+        Commands::Graph {
+            focus,
+            path,
+            directed,
+            dir,
+        } => match path.as_deref() {
+            Some([from, to]) => commands::graph::run_path(&dir, from, to, directed),
+            _ => commands::graph::run(&dir, focus.as_deref()),
+        },
+        Commands::Timeline { from, to, dir } => {
+            commands::timeline::run(&dir, from.as_deref(), to.as_deref())
+        }
+        Commands::Diff { old, new, format } => commands::diff::run(&old, &new, &format),
         Commands::Export {
             format,
             output,
+            include_gm_notes,
+            focus,
+            depth,
             dir,
-        } => commands::export::run(&dir, &format, output.as_deref()),
+        } => commands::export::run(
+            &dir,
+            &format,
+            output.as_deref(),
+            include_gm_notes,
+            focus.as_deref(),
+            depth,
+        ),
         Commands::Simulate {
             ticks,
             seed,
             speed,
             verbose,
+            narrate,
+            focus,
+            out,
+            format,
+            save,
+            load,
             dir,
-        } => commands::simulate::run(&dir, ticks, seed, speed, verbose),
-        Commands::Play { dir } => commands::play::run(&dir),
-        Commands::Solo { dir, seed, chaos } => commands::solo::run(&dir, seed, chaos),
-        Commands::Tui { dir } => commands::tui::run(&dir),
+        } => commands::simulate::run(
+            &dir,
+            ticks,
+            seed,
+            speed,
+            if let Some(name) = focus.as_deref() {
+                commands::simulate::Feed::Focus(name)
+            } else if narrate {
+                commands::simulate::Feed::Narrate
+            } else if verbose {
+                commands::simulate::Feed::Verbose
+            } else {
+                commands::simulate::Feed::Summary
+            },
+            commands::simulate::Files {
+                out: out.as_deref(),
+                format: format.as_deref(),
+                save: save.as_deref(),
+                load: load.as_deref(),
+            },
+        ),
+        Commands::Play { dir } => commands::play::run(&dir, cli.width),
+        Commands::Solo { dir, seed, chaos } => commands::solo::run(&dir, seed, chaos, cli.width),
+        Commands::Tui { dir } => commands::tui::run(&dir, cli.width),
         Commands::Lsp => {
             // Exec the separate ww-lsp binary
             let status = std::process::Command::new("ww-lsp")
@@ -238,6 +476,15 @@ fn main() {
             }
         }
         Commands::New { kind, name, file } => commands::new::run(&kind, &name, file.as_deref()),
+        Commands::Import {
+            kind,
+            input,
+            file,
+            columns,
+        } => commands::import::run(&kind, &input, file.as_deref(), &columns),
+        Commands::Completions { target, dir } => {
+            commands::completions::run(target, &mut Cli::command(), &dir)
+        }
     };
 
     if let Err(e) = result {
@@ -120,6 +120,19 @@ fn build_succeeds_with_valid_world() {
         );
 }
 
+#[test]
+fn build_prints_phase_timings() {
+    let dir = test_world();
+    ww().args(["build", "--timings", "-d", dir.path().to_str().unwrap()])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("Timings:")
+                .and(predicate::str::contains("resolve"))
+                .and(predicate::str::contains("total")),
+        );
+}
+
 #[test]
 fn build_fails_with_invalid_syntax() {
     let dir = TempDir::new().unwrap();
@@ -161,6 +174,219 @@ fn check_fails_invalid_world() {
         .failure();
 }
 
+#[test]
+fn check_reports_model_issues() {
+    let dir = TempDir::new().unwrap();
+    fs::write(
+        dir.path().join("world.ww"),
+        "Kael is a character {\n    member of the Keep\n}\n\nthe Keep is a fortress {}\n\nthe Lonely Tower is a location {}\n\nthe Hermitage is a location {\n    standalone\n}\n",
+    )
+    .unwrap();
+    let d = dir.path().to_str().unwrap();
+
+    ww().args(["check", "-d", d]).assert().success().stderr(
+        predicate::str::contains("which is a location (expected faction)")
+            .and(predicate::str::contains(
+                "\"the Lonely Tower\" has no relationships",
+            ))
+            .and(predicate::str::contains("the Hermitage").not()),
+    );
+}
+
+#[test]
+fn check_allow_and_deny_diagnostic_codes() {
+    let dir = TempDir::new().unwrap();
+    fs::write(
+        dir.path().join("world.ww"),
+        "Kael is a character {\n    member of the Keep\n}\n\nthe Keep is a fortress {}\n\nthe Lonely Tower is a location {}\n",
+    )
+    .unwrap();
+    let d = dir.path().to_str().unwrap();
+
+    ww().args(["check", "--strict", "--allow", "WW0303", "-d", d])
+        .assert()
+        .success()
+        .stderr(
+            predicate::str::contains("WW0302")
+                .and(predicate::str::contains("has no relationships").not()),
+        );
+    ww().args(["check", "--deny", "ww0302", "-d", d])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("validation failed"));
+    ww().args(["check", "--allow", "WW9999", "-d", d])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("unknown diagnostic code"));
+}
+
+#[test]
+fn check_lists_reference_cycles() {
+    let dir = TempDir::new().unwrap();
+    fs::write(
+        dir.path().join("lore.ww"),
+        "the Prophecy is a lore {\n    references [the Chronicle]\n}\n\nthe Chronicle is a lore {\n    references [the Prophecy]\n}\n",
+    )
+    .unwrap();
+    let d = dir.path().to_str().unwrap();
+
+    ww().args(["check", "--cycles", "-d", d])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "- the Chronicle -> the Prophecy -> the Chronicle",
+        ));
+    ww().args(["check", "-d", d])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("reference cycle").not());
+}
+
+#[test]
+fn check_follows_includes() {
+    let dir = TempDir::new().unwrap();
+    fs::create_dir(dir.path().join("shared")).unwrap();
+    fs::write(
+        dir.path().join("main.ww"),
+        "include \"shared/factions.ww\"\n\nKael is a character {\n    member of the Order of Dawn\n}\n",
+    )
+    .unwrap();
+    fs::write(
+        dir.path().join("shared/factions.ww"),
+        "the Order of Dawn is a faction {\n    led by Kael\n}\n",
+    )
+    .unwrap();
+    let d = dir.path().to_str().unwrap();
+
+    ww().args(["check", "-d", d])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("2 entities"));
+
+    fs::write(
+        dir.path().join("shared/factions.ww"),
+        "include \"../main.ww\"\n\nthe Order of Dawn is a faction {}\n",
+    )
+    .unwrap();
+    ww().args(["check", "-d", d]).assert().failure().stderr(
+        predicate::str::contains("WW0004").and(predicate::str::contains(
+            "circular include: main.ww -> shared/factions.ww -> main.ww",
+        )),
+    );
+}
+
+#[test]
+fn check_warns_on_misspelled_mentions() {
+    let dir = TempDir::new().unwrap();
+    fs::write(
+        dir.path().join("world.ww"),
+        "the Citadel is a fortress {}\n\nKael is a character {\n    located at the Citadel\n\n    \"\"\"\n    Kael guards the Citadel, not the Citdel.\n    \"\"\"\n}\n",
+    )
+    .unwrap();
+    let d = dir.path().to_str().unwrap();
+
+    ww().args(["check", "-d", d]).assert().success().stderr(
+        predicate::str::contains("WW0306").and(predicate::str::contains(
+            "\"Kael\" mentions \"Citdel\" in its description; did you mean \"the Citadel\"?",
+        )),
+    );
+    ww().args(["export", "html", "-d", d])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("<a id=\"the-citadel\">the Citadel</a>").and(
+                predicate::str::contains(
+                    "Kael guards <a href=\"#the-citadel\">the Citadel</a>, not the Citdel.",
+                ),
+            ),
+        );
+}
+
+// ---------------------------------------------------------------------------
+// watch
+// ---------------------------------------------------------------------------
+
+#[test]
+fn watch_rejects_missing_directory() {
+    let dir = TempDir::new().unwrap();
+    let missing = dir.path().join("nowhere");
+    ww().args(["watch", "-d", missing.to_str().unwrap()])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("not a directory"));
+}
+
+// ---------------------------------------------------------------------------
+// fmt
+// ---------------------------------------------------------------------------
+
+#[test]
+fn fmt_fix_relationships_inserts_reciprocals() {
+    let dir = TempDir::new().unwrap();
+    fs::write(
+        dir.path().join("places.ww"),
+        "the Hall is a room {\n    north to the Tower\n}\n\nthe Tower is a room {\n    climate cold\n}\n",
+    )
+    .unwrap();
+    let d = dir.path().to_str().unwrap();
+
+    ww().args(["fmt", "--fix-relationships", "-d", d])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("+ the Tower: south to the Hall"));
+
+    let content = fs::read_to_string(dir.path().join("places.ww")).unwrap();
+    assert!(content.contains("    climate cold\n    south to the Hall\n}"));
+
+    // Second run changes nothing
+    ww().args(["fmt", "--fix-relationships", "-d", d])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("already reciprocal"));
+    assert_eq!(
+        fs::read_to_string(dir.path().join("places.ww")).unwrap(),
+        content
+    );
+}
+
+#[test]
+fn fmt_fix_relationships_follows_includes() {
+    let dir = TempDir::new().unwrap();
+    fs::create_dir(dir.path().join("regions")).unwrap();
+    fs::write(
+        dir.path().join("main.ww"),
+        "include \"regions/north.ww\"\n\nthe Hall is a room {\n    climate mild\n    east to the Yard\n}\n",
+    )
+    .unwrap();
+    fs::write(
+        dir.path().join("regions/north.ww"),
+        "the Gate is a room {\n    south to the Hall\n}\n\nthe Yard is a room {}\n",
+    )
+    .unwrap();
+    let d = dir.path().to_str().unwrap();
+
+    ww().args(["fmt", "--fix-relationships", "-d", d])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("+ the Hall: north to the Gate"))
+        .stdout(predicate::str::contains("regions/north.ww:"))
+        .stdout(predicate::str::contains("+ the Yard: west to the Hall"));
+
+    let content = fs::read_to_string(dir.path().join("main.ww")).unwrap();
+    assert!(content.contains("    east to the Yard\n    north to the Gate\n}"));
+    let content = fs::read_to_string(dir.path().join("regions/north.ww")).unwrap();
+    assert!(content.contains("the Yard is a room {\n    west to the Hall\n}"));
+}
+
+#[test]
+fn fmt_requires_a_pass() {
+    let dir = test_world();
+    ww().args(["fmt", "-d", dir.path().to_str().unwrap()])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--fix-relationships"));
+}
+
 // ---------------------------------------------------------------------------
 // list
 // ---------------------------------------------------------------------------
@@ -191,6 +417,52 @@ fn list_filters_by_kind() {
         );
 }
 
+#[test]
+fn list_counts_in_kind_plural() {
+    let dir = test_world();
+    ww().args(["list", "character", "-d", dir.path().to_str().unwrap()])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("  1 character\n"));
+
+    let dir = TempDir::new().unwrap();
+    fs::write(
+        dir.path().join("world.ww"),
+        "world \"Desert\" {\n    grammar {\n        plural { cactus \"cacti\" }\n    }\n}\n\n\
+         Old Spike is a cactus {}\n\nthe Twins is a cactus {}\n",
+    )
+    .unwrap();
+    ww().args(["list", "cactus", "-d", dir.path().to_str().unwrap()])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("  2 cacti\n"));
+}
+
+#[test]
+fn list_filters_by_related_entity() {
+    let dir = test_world();
+    let path = dir.path().to_str().unwrap();
+    ww().args(["list", "--related-to", "the Order of Dawn", "-d", path])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("Kael Stormborn")
+                .and(predicate::str::contains("Iron Citadel"))
+                .and(predicate::str::contains("Great Sundering").not())
+                .and(predicate::str::contains("  2 entities")),
+        );
+
+    ww().args(["list", "--related-to", "the Great Sundering", "-d", path])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No entities found"));
+
+    ww().args(["list", "--related-to", "Nobody", "-d", path])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("entity not found"));
+}
+
 #[test]
 fn list_no_matches() {
     let dir = test_world();
@@ -238,83 +510,482 @@ fn show_with_relationships() {
 }
 
 #[test]
-fn show_fails_unknown_entity() {
+fn show_timeline_lists_involved_events() {
     let dir = test_world();
-    ww().args(["show", "Nobody", "-d", dir.path().to_str().unwrap()])
+    fs::write(
+        dir.path().join("battles.ww"),
+        "the Siege of Dawn is an event {\n    date year 3\n    involving [Kael Stormborn]\n}\n",
+    )
+    .unwrap();
+    ww().args([
+        "show",
+        "Kael Stormborn",
+        "--timeline",
+        "-d",
+        dir.path().to_str().unwrap(),
+    ])
+    .assert()
+    .success()
+    .stdout(
+        predicate::str::contains("Timeline:")
+            .and(predicate::str::contains("the Siege of Dawn"))
+            .and(predicate::str::contains("Great Sundering").not()),
+    );
+}
+
+#[test]
+fn show_relationships_read_as_sentences() {
+    let dir = test_world();
+    ww().args(["show", "the Order of Dawn", "-r"])
+        .args(["-d", dir.path().to_str().unwrap()])
         .assert()
-        .failure()
-        .stderr(predicate::str::contains("entity not found"));
+        .success()
+        .stdout(
+            predicate::str::contains("Kael Stormborn is a member of the Order of Dawn.").and(
+                predicate::str::contains("The Order of Dawn has member Kael Stormborn."),
+            ),
+        );
 }
 
-// ---------------------------------------------------------------------------
-// search
-// ---------------------------------------------------------------------------
+#[test]
+fn show_relationships_lists_each_sentence_once() {
+    let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/../../iron-kingdoms");
+    let output = ww()
+        .args(["show", "Kael Stormborn", "-r", "-d", dir])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let sentences: Vec<&str> = stdout
+        .lines()
+        .skip_while(|line| !line.contains("Relationships:"))
+        .skip(1)
+        .map(str::trim)
+        .collect();
+    assert!(sentences.contains(&"Kael Stormborn leads the Order of Dawn."));
+    assert!(sentences.contains(&"The Order of Dawn is led by Kael Stormborn."));
+    let mut unique = sentences.clone();
+    unique.sort_unstable();
+    unique.dedup();
+    assert_eq!(unique.len(), sentences.len(), "{stdout}");
+}
 
 #[test]
-fn search_finds_matching_entities() {
+fn show_wraps_to_width() {
     let dir = test_world();
-    ww().args(["search", "knight", "-d", dir.path().to_str().unwrap()])
+    ww().args(["show", "the Iron Citadel", "--width", "20"])
+        .args(["-d", dir.path().to_str().unwrap()])
         .assert()
         .success()
-        .stdout(predicate::str::contains("Kael Stormborn"));
+        .stdout(predicate::str::contains(
+            "  An ancient\n  fortress carved\n  from iron ore.\n",
+        ));
 }
 
 #[test]
-fn search_no_results() {
-    let dir = test_world();
-    ww().args(["search", "zzzznothing", "-d", dir.path().to_str().unwrap()])
+fn show_follows_detail_template() {
+    let dir = TempDir::new().unwrap();
+    fs::write(
+        dir.path().join("world.ww"),
+        r#"world "Mystery" {
+    display {
+        template {
+            character ["motive: Motive", "alibi: Alibi"]
+        }
+    }
+}
+
+Ada is a character {
+    alibi "the library"
+    eyes grey
+    motive greed
+}
+"#,
+    )
+    .unwrap();
+    ww().args(["show", "Ada", "-d", dir.path().to_str().unwrap()])
         .assert()
         .success()
-        .stdout(predicate::str::contains("No results"));
+        .stdout(predicate::str::contains(
+            "  Motive: greed\n  Alibi: the library\n\n  Other:\n  eyes: grey\n",
+        ));
+}
+
+#[test]
+fn show_fails_unknown_entity() {
+    let dir = test_world();
+    ww().args(["show", "Nobody", "-d", dir.path().to_str().unwrap()])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("entity not found"));
 }
 
 // ---------------------------------------------------------------------------
-// graph
+// sheet
 // ---------------------------------------------------------------------------
 
+/// Create a temp directory with a ruleset and a character that uses it.
+fn mechanics_world() -> TempDir {
+    let dir = TempDir::new().unwrap();
+    fs::write(
+        dir.path().join("world.ww"),
+        r#"the Table Rules is a ruleset {
+    mechanics {
+        system "pbta"
+        check_die "d6"
+        pool_size 2
+        attributes ["Cool", "Hard"]
+        tracks ["Harm:6:0"]
+    }
+}
+
+Dremmer is a character {
+    mechanics {
+        cool 1
+        hard 2
+        harm 2
+        focuses ["Brawling"]
+    }
+}
+"#,
+    )
+    .unwrap();
+    dir
+}
+
 #[test]
-fn graph_shows_relationships() {
-    let dir = test_world();
-    ww().args(["graph", "-d", dir.path().to_str().unwrap()])
+fn sheet_prints_markdown_by_default() {
+    let dir = mechanics_world();
+    ww().args(["sheet", "dremmer", "-d", dir.path().to_str().unwrap()])
         .assert()
         .success()
-        .stdout(predicate::str::contains("Relationship graph"));
+        .stdout(
+            predicate::str::contains("# Dremmer")
+                .and(predicate::str::contains("| Hard | 2 |"))
+                .and(predicate::str::contains("- **Harm**: `[##----] 2/6`"))
+                .and(predicate::str::contains("- Brawling")),
+        );
 }
 
 #[test]
-fn graph_focused_entity() {
-    let dir = test_world();
-    ww().args([
-        "graph",
-        "--focus",
-        "Kael Stormborn",
-        "-d",
-        dir.path().to_str().unwrap(),
-    ])
-    .assert()
-    .success()
-    .stdout(
-        predicate::str::contains("Kael Stormborn").and(predicate::str::contains("Iron Citadel")),
-    );
+fn sheet_prints_json() {
+    let dir = mechanics_world();
+    let output = ww()
+        .args(["sheet", "Dremmer", "--format", "json"])
+        .args(["-d", dir.path().to_str().unwrap()])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["attributes"]["Cool"], 1);
+    assert_eq!(json["tracks"]["Harm"]["current"], 2);
 }
 
 #[test]
-fn graph_focused_unknown_entity() {
+fn sheet_without_mechanics_fails() {
     let dir = test_world();
     ww().args([
-        "graph",
-        "--focus",
-        "Nobody",
+        "sheet",
+        "Kael Stormborn",
         "-d",
         dir.path().to_str().unwrap(),
     ])
     .assert()
     .failure()
-    .stderr(predicate::str::contains("entity not found"));
+    .stderr(predicate::str::contains("no game mechanics"));
 }
 
 // ---------------------------------------------------------------------------
-// timeline
+// search
+// ---------------------------------------------------------------------------
+
+#[test]
+fn search_finds_matching_entities() {
+    let dir = test_world();
+    ww().args(["search", "knight", "-d", dir.path().to_str().unwrap()])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Kael Stormborn"));
+}
+
+#[test]
+fn search_with_regex_and_fuzzy() {
+    let dir = test_world();
+    let path = dir.path().to_str().unwrap();
+    ww().args(["search", "--regex", "^Kael|Dawn$", "-d", path])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("Kael Stormborn")
+                .and(predicate::str::contains("Order of Dawn"))
+                .and(predicate::str::contains("Iron Citadel").not()),
+        );
+
+    ww().args(["search", "--fuzzy", "Kael Stormbon", "-d", path])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Kael Stormborn"));
+
+    ww().args(["search", "--regex", "(unclosed", "-d", path])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid pattern"));
+}
+
+#[test]
+fn search_no_results() {
+    let dir = test_world();
+    ww().args(["search", "zzzznothing", "-d", dir.path().to_str().unwrap()])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No results"));
+}
+
+// ---------------------------------------------------------------------------
+// suggest
+// ---------------------------------------------------------------------------
+
+#[test]
+fn suggest_ranks_referenced_thin_entities() {
+    let dir = test_world();
+    ww().args(["suggest", "-d", dir.path().to_str().unwrap()])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains(" 1. the Iron Citadel")
+                .and(predicate::str::contains("referenced by 2 entities"))
+                .and(predicate::str::contains(" 2. the Order of Dawn"))
+                .and(predicate::str::contains(
+                    "referenced by 1 entity; no description",
+                ))
+                .and(predicate::str::contains("Kael Stormborn").not()),
+        );
+}
+
+#[test]
+fn suggest_respects_limit() {
+    let dir = test_world();
+    ww().args(["suggest", "-n", "1", "-d", dir.path().to_str().unwrap()])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("the Order of Dawn")
+                .not()
+                .and(predicate::str::contains("... and 1 more")),
+        );
+}
+
+// ---------------------------------------------------------------------------
+// graph
+// ---------------------------------------------------------------------------
+
+#[test]
+fn graph_shows_relationships() {
+    let dir = test_world();
+    ww().args(["graph", "-d", dir.path().to_str().unwrap()])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Relationship graph"));
+}
+
+#[test]
+fn graph_focused_entity() {
+    let dir = test_world();
+    ww().args([
+        "graph",
+        "--focus",
+        "Kael Stormborn",
+        "-d",
+        dir.path().to_str().unwrap(),
+    ])
+    .assert()
+    .success()
+    .stdout(
+        predicate::str::contains("Kael Stormborn").and(predicate::str::contains("Iron Citadel")),
+    );
+}
+
+#[test]
+fn graph_focused_unknown_entity() {
+    let dir = test_world();
+    ww().args([
+        "graph",
+        "--focus",
+        "Nobody",
+        "-d",
+        dir.path().to_str().unwrap(),
+    ])
+    .assert()
+    .failure()
+    .stderr(predicate::str::contains("entity not found"));
+}
+
+#[test]
+fn graph_path_between_entities() {
+    let dir = test_world();
+    ww().args([
+        "graph",
+        "--path",
+        "the Order of Dawn",
+        "Kael Stormborn",
+        "-d",
+        dir.path().to_str().unwrap(),
+    ])
+    .assert()
+    .success()
+    .stdout(
+        predicate::str::contains("(1 hop)").and(predicate::str::contains(
+            "<--  member of <--  [Kael Stormborn]",
+        )),
+    );
+}
+
+#[test]
+fn graph_path_directed_unreachable() {
+    let dir = test_world();
+    ww().args([
+        "graph",
+        "--path",
+        "the Order of Dawn",
+        "Kael Stormborn",
+        "--directed",
+        "-d",
+        dir.path().to_str().unwrap(),
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains(
+        "No path from the Order of Dawn to Kael Stormborn",
+    ));
+}
+
+// ---------------------------------------------------------------------------
+// diff
+// ---------------------------------------------------------------------------
+
+/// A copy of the test world with one entity changed, one removed, and one added.
+fn changed_world() -> TempDir {
+    let dir = test_world();
+    fs::write(
+        dir.path().join("factions.ww"),
+        r#"the Order of Dawn is a faction {
+    motto "Light endures"
+    based at the Iron Citadel
+}
+
+the Ashen Court is a faction {
+    rival of the Order of Dawn
+}
+"#,
+    )
+    .unwrap();
+    fs::remove_file(dir.path().join("events.ww")).unwrap();
+    dir
+}
+
+#[test]
+fn diff_reports_entity_and_relationship_changes() {
+    let (old, new) = (test_world(), changed_world());
+    ww().args([
+        "diff",
+        old.path().to_str().unwrap(),
+        new.path().to_str().unwrap(),
+    ])
+    .assert()
+    .success()
+    .stdout(
+        predicate::str::contains("+ the Ashen Court")
+            .and(predicate::str::contains("- the Great Sundering"))
+            .and(predicate::str::contains("~ the Order of Dawn"))
+            .and(predicate::str::contains("motto: (none) -> Light endures"))
+            .and(predicate::str::contains(
+                "+ The Ashen Court is a rival of the Order of Dawn.",
+            )),
+    );
+}
+
+#[test]
+fn diff_matches_exact_names_and_relationship_attributes() {
+    let (old, new) = (test_world(), test_world());
+    fs::write(
+        new.path().join("characters.ww"),
+        r#"Kael the Bold is a character {
+    alias Kael Stormborn
+    member of the Order of Dawn
+}
+"#,
+    )
+    .unwrap();
+    fs::write(
+        new.path().join("factions.ww"),
+        r#"the Order of Dawn is a faction {
+    type military_order
+    values [honor, duty]
+    based at the Iron Citadel since year 1100
+}
+"#,
+    )
+    .unwrap();
+    ww().args([
+        "diff",
+        old.path().to_str().unwrap(),
+        new.path().to_str().unwrap(),
+    ])
+    .assert()
+    .success()
+    .stdout(
+        predicate::str::contains("+ Kael the Bold")
+            .and(predicate::str::contains("- Kael Stormborn"))
+            .and(predicate::str::contains(
+                "- The Order of Dawn is based at the Iron Citadel.",
+            ))
+            .and(predicate::str::contains(
+                "+ The Order of Dawn is based at the Iron Citadel (since 1100).",
+            )),
+    );
+}
+
+#[test]
+fn diff_identical_worlds() {
+    let (old, new) = (test_world(), test_world());
+    ww().args([
+        "diff",
+        old.path().to_str().unwrap(),
+        new.path().to_str().unwrap(),
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains("No differences"));
+}
+
+#[test]
+fn diff_json_format() {
+    let (old, new) = (test_world(), changed_world());
+    let output = ww()
+        .args([
+            "diff",
+            old.path().to_str().unwrap(),
+            new.path().to_str().unwrap(),
+            "--format",
+            "json",
+        ])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["added"]["faction"][0]["name"], "the Ashen Court");
+    assert_eq!(json["removed"]["event"][0]["name"], "the Great Sundering");
+    assert_eq!(json["modified"]["faction"][0]["changes"][0]["key"], "motto");
+    assert_eq!(
+        json["relationships"]["added"][0],
+        "The Ashen Court is a rival of the Order of Dawn."
+    );
+}
+
+// ---------------------------------------------------------------------------
+// timeline
 // ---------------------------------------------------------------------------
 
 #[test]
@@ -343,6 +1014,64 @@ fn timeline_no_events_in_range() {
     .stdout(predicate::str::contains("No events"));
 }
 
+#[test]
+fn timeline_orders_and_filters_by_era() {
+    let dir = test_world();
+    fs::write(
+        dir.path().join("world.ww"),
+        r#"world "Test World" {
+    era "Age of Ruin" starts year -2000
+    era "Age of Crowns" starts year 0
+}
+
+the Iron Citadel is a fortress {}
+"#,
+    )
+    .unwrap();
+    fs::write(
+        dir.path().join("eras.ww"),
+        r#"the Fall of Vyr is an event {
+    date year 900, era "Age of Ruin"
+}
+
+the Coronation is an event {
+    date year 5, era "Age of Crowns"
+}
+"#,
+    )
+    .unwrap();
+    let path = dir.path().to_str().unwrap();
+
+    let output = ww().args(["timeline", "-d", path]).assert().success();
+    let stdout = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+    let sundering = stdout.find("Great Sundering").unwrap();
+    let fall = stdout.find("Fall of Vyr").unwrap();
+    let coronation = stdout.find("Coronation").unwrap();
+    assert!(sundering < fall && fall < coronation, "{stdout}");
+
+    ww().args([
+        "timeline",
+        "--from",
+        "Age of Ruin:800",
+        "--to",
+        "-1",
+        "-d",
+        path,
+    ])
+    .assert()
+    .success()
+    .stdout(
+        predicate::str::contains("Fall of Vyr")
+            .and(predicate::str::contains("Great Sundering").not())
+            .and(predicate::str::contains("Coronation").not()),
+    );
+
+    ww().args(["timeline", "--from", "Third Age:1", "-d", path])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("unknown era 'Third Age'"));
+}
+
 // ---------------------------------------------------------------------------
 // export
 // ---------------------------------------------------------------------------
@@ -359,8 +1088,15 @@ fn export_json_valid_output() {
         .clone();
 
     let json: serde_json::Value = serde_json::from_slice(&output).expect("valid JSON output");
+    assert_eq!(json["schema_version"], ww_core::world::SCHEMA_VERSION);
     assert_eq!(json["world"]["name"], "Test World");
     assert!(json["entities"].as_array().unwrap().len() >= 4);
+
+    let world = ww_core::World::from_json(std::str::from_utf8(&output).unwrap()).unwrap();
+    assert_eq!(
+        world.entity_count(),
+        json["entities"].as_array().unwrap().len()
+    );
 }
 
 #[test]
@@ -388,6 +1124,79 @@ fn export_html() {
         );
 }
 
+#[test]
+fn export_svg() {
+    let dir = test_world();
+    ww().args(["export", "svg", "-d", dir.path().to_str().unwrap()])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\"")
+                .and(predicate::str::contains("data-kind=\"character\""))
+                .and(predicate::str::contains("id=\"node-kael-stormborn\""))
+                .and(predicate::str::contains("<a href").not())
+                .and(predicate::str::contains(
+                    "<title>Kael Stormborn member of the Order of Dawn</title>",
+                ))
+                .and(predicate::str::contains(
+                    "A brave knight sworn to protect the realm.",
+                ))
+                .and(predicate::str::ends_with("</svg>\n")),
+        );
+}
+
+#[test]
+fn export_dot() {
+    let dir = test_world();
+    ww().args(["export", "dot", "-d", dir.path().to_str().unwrap()])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::starts_with("digraph \"Test World\" {")
+                .and(predicate::str::contains(
+                    "\"Kael Stormborn\" [label=\"Kael Stormborn\", fillcolor=\"#2196f3\"",
+                ))
+                .and(predicate::str::contains(
+                    "\"Kael Stormborn\" -> \"the Order of Dawn\" [label=\"member of\"];",
+                ))
+                .and(predicate::str::contains("\"the Great Sundering\""))
+                .and(predicate::str::ends_with("}\n")),
+        );
+
+    ww().args([
+        "export",
+        "dot",
+        "--focus",
+        "the Order of Dawn",
+        "--depth",
+        "1",
+        "-d",
+        dir.path().to_str().unwrap(),
+    ])
+    .assert()
+    .success()
+    .stdout(
+        predicate::str::contains("\"the Order of Dawn\" [label=\"the Order of Dawn\"")
+            .and(predicate::str::contains("penwidth=3"))
+            .and(predicate::str::contains(
+                "\"Kael Stormborn\" -> \"the Order of Dawn\"",
+            ))
+            .and(predicate::str::contains("the Great Sundering").not()),
+    );
+
+    ww().args([
+        "export",
+        "svg",
+        "--focus",
+        "Kael Stormborn",
+        "-d",
+        dir.path().to_str().unwrap(),
+    ])
+    .assert()
+    .failure()
+    .stderr(predicate::str::contains("only supported by the dot format"));
+}
+
 #[test]
 fn export_to_file() {
     let dir = test_world();
@@ -418,6 +1227,34 @@ fn export_unsupported_format() {
         .stderr(predicate::str::contains("unsupported format"));
 }
 
+#[test]
+fn export_hides_gm_notes_unless_requested() {
+    let dir = TempDir::new().unwrap();
+    fs::write(
+        dir.path().join("world.ww"),
+        "Mira is a character {\n    gm_note \"secretly the heir\"\n}\n",
+    )
+    .unwrap();
+    let d = dir.path().to_str().unwrap();
+
+    ww().args(["export", "markdown", "-d", d])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("secretly the heir").not());
+    ww().args(["export", "json", "-d", d])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("gm_notes").not());
+    ww().args(["export", "markdown", "--include-gm-notes", "-d", d])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("**GM note:** secretly the heir"));
+    ww().args(["show", "Mira", "-d", d])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("secretly the heir"));
+}
+
 // ---------------------------------------------------------------------------
 // simulate
 // ---------------------------------------------------------------------------
@@ -452,6 +1289,211 @@ fn simulate_verbose() {
         .stdout(predicate::str::contains("Event Log"));
 }
 
+#[test]
+fn simulate_verbose_lists_resource_changes() {
+    let dir = test_world();
+    ww().args(["simulate", "-v", "-d", dir.path().to_str().unwrap()])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("Resources")
+                .and(predicate::str::contains("the Order of Dawn")),
+        );
+}
+
+#[test]
+fn simulate_narrate() {
+    let dir = test_world();
+    ww().args(["simulate", "-n", "-d", dir.path().to_str().unwrap()])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("Chronicle")
+                .and(predicate::str::contains("[day 1 "))
+                .and(predicate::str::contains("Character Status")),
+        );
+}
+
+#[test]
+fn simulate_narrate_wraps_to_width() {
+    let dir = test_world();
+    let output = ww()
+        .args(["simulate", "-n", "--width", "40"])
+        .args(["-d", dir.path().to_str().unwrap()])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let (chronicle, _) = stdout.split_once("Simulation").unwrap();
+    assert!(chronicle.contains("[day 1 "));
+    for line in chronicle.lines() {
+        assert!(line.chars().count() <= 40, "{line:?}");
+    }
+}
+
+#[test]
+fn simulate_narrate_conflicts_with_verbose() {
+    let dir = test_world();
+    ww().args(["simulate", "-n", "-v", "-d", dir.path().to_str().unwrap()])
+        .assert()
+        .failure();
+}
+
+#[test]
+fn simulate_focus_prints_diary() {
+    let dir = test_world();
+    ww().args([
+        "simulate",
+        "--focus",
+        "kael stormborn",
+        "-t",
+        "3",
+        "-d",
+        dir.path().to_str().unwrap(),
+    ])
+    .assert()
+    .success()
+    .stdout(
+        predicate::str::contains("Diary of Kael Stormborn")
+            .and(predicate::str::contains("Day 1"))
+            .and(predicate::str::contains("hunger")),
+    );
+}
+
+#[test]
+fn simulate_focus_unknown_character() {
+    let dir = test_world();
+    ww().args([
+        "simulate",
+        "--focus",
+        "Nobody",
+        "-d",
+        dir.path().to_str().unwrap(),
+    ])
+    .assert()
+    .failure()
+    .stderr(predicate::str::contains(
+        "no living character named \"Nobody\"",
+    ));
+}
+
+#[test]
+fn simulate_uses_world_seed() {
+    let dir = test_world();
+    let world_file = dir.path().join("world.ww");
+    let source = fs::read_to_string(&world_file).unwrap().replacen(
+        "genre \"fantasy\"",
+        "genre \"fantasy\"\n    rng {\n        seed 7\n    }",
+        1,
+    );
+    fs::write(&world_file, &source).unwrap();
+    let path = dir.path().to_str().unwrap();
+
+    ww().args(["simulate", "-d", path])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("seed=7"));
+    ww().args(["simulate", "-s", "99", "-d", path])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("seed=99"));
+
+    // A locked seed ignores the override
+    fs::write(
+        &world_file,
+        source.replace("seed 7", "seed 7\n        lock true"),
+    )
+    .unwrap();
+    ww().args(["simulate", "-s", "99", "-d", path])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("seed=7"));
+}
+
+#[test]
+fn simulate_writes_json_output() {
+    let dir = test_world();
+    let out = dir.path().join("run.json");
+    ww().args(["simulate", "-t", "12", "-d", dir.path().to_str().unwrap()])
+        .args(["--out", out.to_str().unwrap()])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Wrote"));
+
+    let json: serde_json::Value = serde_json::from_str(&fs::read_to_string(&out).unwrap()).unwrap();
+    assert_eq!(json["simulation"]["ticks"], 12);
+    assert!(json["entities"].as_array().is_some_and(|e| !e.is_empty()));
+    assert!(json["events"].is_array());
+}
+
+#[test]
+fn simulate_writes_markdown_chronicle() {
+    let dir = test_world();
+    let out = dir.path().join("chronicle.txt");
+    ww().args(["simulate", "-t", "48", "-d", dir.path().to_str().unwrap()])
+        .args(["--out", out.to_str().unwrap(), "--format", "markdown"])
+        .assert()
+        .success();
+
+    let text = fs::read_to_string(&out).unwrap();
+    assert!(text.starts_with("# Chronicle of "));
+    assert!(text.contains("48 ticks"));
+}
+
+#[test]
+fn simulate_save_and_load_continues_run() {
+    let dir = test_world();
+    let path = |name: &str| dir.path().join(name).to_str().unwrap().to_string();
+    let events = |name: &str| -> serde_json::Value {
+        let json: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(dir.path().join(name)).unwrap()).unwrap();
+        json["events"].clone()
+    };
+
+    ww().args(["simulate", "-t", "48", "-d", dir.path().to_str().unwrap()])
+        .args(["--out", &path("straight.json")])
+        .assert()
+        .success();
+    ww().args(["simulate", "-t", "20", "-d", dir.path().to_str().unwrap()])
+        .args(["--save", &path("snapshot.json")])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Saved snapshot"));
+    ww().args(["simulate", "-t", "28", "--load", &path("snapshot.json")])
+        .args(["--out", &path("resumed.json")])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Resumed at tick 20"));
+
+    assert!(
+        events("straight.json")
+            .as_array()
+            .is_some_and(|e| !e.is_empty())
+    );
+    assert_eq!(events("resumed.json"), events("straight.json"));
+}
+
+#[test]
+fn simulate_load_rejects_invalid_snapshot() {
+    let dir = test_world();
+    let snapshot = dir.path().join("bad.json");
+    fs::write(&snapshot, "{}").unwrap();
+    ww().args(["simulate", "--load", snapshot.to_str().unwrap()])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid snapshot"));
+}
+
+#[test]
+fn simulate_rejects_unknown_output_format() {
+    let dir = test_world();
+    ww().args(["simulate", "-d", dir.path().to_str().unwrap()])
+        .args(["--out", "run.csv"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("unsupported format"));
+}
+
 // ---------------------------------------------------------------------------
 // new
 // ---------------------------------------------------------------------------
@@ -502,3 +1544,195 @@ fn new_custom_file() {
     let content = fs::read_to_string(dir.path().join("places.ww")).unwrap();
     assert!(content.contains("The Ashlands is a location"));
 }
+
+// ---------------------------------------------------------------------------
+// import
+// ---------------------------------------------------------------------------
+
+#[test]
+fn import_creatures_from_csv() {
+    let dir = TempDir::new().unwrap();
+    fs::write(
+        dir.path().join("bestiary.csv"),
+        "Name,Type,Combat,Instinct,HP,Weapon,Traits,Habitat\n\
+         Dock Guard,human,45,30,2,\"Baton, 1d5\",Alert: notices sneaking,docks\n\
+         Cave Wyrm,beast,60,70,5,Bite,\"Burrow: hides underground; Armored\",\n",
+    )
+    .unwrap();
+
+    ww().args(["import", "creatures", "bestiary.csv", "--column", "HP=hits"])
+        .current_dir(dir.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Imported 2 creature(s) to creatures.ww",
+        ));
+
+    let content = fs::read_to_string(dir.path().join("creatures.ww")).unwrap();
+    assert!(content.contains(
+        "Dock Guard is a creature {\n    type \"human\"\n    combat 45\n    instinct 30\n    hits 2\n    weapon \"Baton, 1d5\"\n    habitat \"docks\"\n    trait-alert \"notices sneaking\"\n}"
+    ));
+    assert!(
+        content.contains("    trait-burrow \"hides underground\"\n    trait-armored \"Armored\"\n")
+    );
+
+    ww().args(["show", "Cave Wyrm", "-d", dir.path().to_str().unwrap()])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("combat: 60"));
+}
+
+#[test]
+fn imported_creatures_compile() {
+    let dir = TempDir::new().unwrap();
+    fs::write(
+        dir.path().join("bestiary.csv"),
+        "Name,Combat,Traits\n\
+         Giant Rat (Alpha),20,\"Pack: calls C:\\rats\"\n\
+         Rat is Legion,10,\n",
+    )
+    .unwrap();
+
+    ww().args(["import", "creatures", "bestiary.csv"])
+        .current_dir(dir.path())
+        .assert()
+        .success();
+    let content = fs::read_to_string(dir.path().join("creatures.ww")).unwrap();
+    assert!(content.contains("\"Giant Rat (Alpha)\" is a creature {"));
+
+    ww().args(["check", "-d", dir.path().to_str().unwrap()])
+        .assert()
+        .success();
+    ww().args([
+        "show",
+        "Giant Rat (Alpha)",
+        "-d",
+        dir.path().to_str().unwrap(),
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains("trait-pack: calls C:\\rats"));
+    ww().args(["show", "Rat is Legion", "-d", dir.path().to_str().unwrap()])
+        .assert()
+        .success();
+}
+
+#[test]
+fn import_creatures_requires_a_name_column() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("bad.csv"), "Creature,Combat\nRat,10\n").unwrap();
+
+    ww().args(["import", "creatures", "bad.csv"])
+        .current_dir(dir.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("no name column"));
+    ww().args([
+        "import",
+        "creatures",
+        "bad.csv",
+        "--column",
+        "Creature=size",
+    ])
+    .current_dir(dir.path())
+    .assert()
+    .failure()
+    .stderr(predicate::str::contains("unknown creature field 'size'"));
+    ww().args([
+        "import",
+        "creatures",
+        "bad.csv",
+        "--column",
+        "Creature=name",
+    ])
+    .current_dir(dir.path())
+    .assert()
+    .success();
+}
+
+// ---------------------------------------------------------------------------
+// completions
+// ---------------------------------------------------------------------------
+
+#[test]
+fn completions_generate_shell_scripts() {
+    for shell in ["bash", "zsh", "fish"] {
+        ww().args(["completions", shell])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("timeline"));
+    }
+    ww().args(["completions", "powershell"]).assert().failure();
+}
+
+#[test]
+fn completions_list_entity_names() {
+    let dir = test_world();
+    let output = ww()
+        .args([
+            "completions",
+            "entities",
+            "-d",
+            dir.path().to_str().unwrap(),
+        ])
+        .assert()
+        .success();
+    let stdout = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+    assert!(
+        stdout.lines().any(|line| line == "Kael Stormborn"),
+        "{stdout}"
+    );
+
+    let broken = TempDir::new().unwrap();
+    fs::write(
+        broken.path().join("world.ww"),
+        "Kael is a {
+",
+    )
+    .unwrap();
+    ww().args([
+        "completions",
+        "entities",
+        "-d",
+        broken.path().to_str().unwrap(),
+    ])
+    .assert()
+    .success()
+    .stdout("")
+    .stderr("");
+
+    // Broken declarations leave the names of the others, even when one is
+    // left open and swallows the rest of the world
+    fs::write(
+        dir.path().join("broken.ww"),
+        "Thorn is a character {}\n\nBroken is {\n}\n\nElder Moss is a character {}\n\nKael is a {\n",
+    )
+    .unwrap();
+    ww().args([
+        "completions",
+        "entities",
+        "-d",
+        dir.path().to_str().unwrap(),
+    ])
+    .assert()
+    .success()
+    .stdout(
+        predicate::str::contains("Kael Stormborn\n")
+            .and(predicate::str::contains("the Iron Citadel\n"))
+            .and(predicate::str::contains("Thorn\n"))
+            .and(predicate::str::contains("Elder Moss\n")),
+    )
+    .stderr("");
+}
+
+#[test]
+fn example_worlds_have_no_mention_warnings() {
+    let root = concat!(env!("CARGO_MANIFEST_DIR"), "/../..");
+    for world in ["iron-kingdoms", "stellar-drift", "blood-and-honor"] {
+        let dir = format!("{root}/{world}");
+        ww().args(["check", "-d", &dir])
+            .assert()
+            .success()
+            .stderr(predicate::str::contains("WW0306").not());
+    }
+}
@@ -0,0 +1,201 @@
+//! Author-configurable layouts for entity detail views.
+//!
+//! A world can declare which properties a detail view shows, in which order,
+//! and under which labels, per entity kind:
+//!
+//! ```text
+//! display {
+//!     template {
+//!         character ["motive: Motive", "alibi: Alibi", "suspicion"]
+//!     }
+//! }
+//! ```
+//!
+//! Each entry is a property key, optionally followed by `: Label`.
+//! Properties the template does not list are still shown, in a separate
+//! "other" section, so nothing is hidden by accident.
+
+use crate::entity::{Entity, MetadataValue};
+use crate::world::WorldMeta;
+
+/// One property slot in a [`DetailTemplate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DetailField {
+    /// Property key looked up on the entity.
+    pub key: String,
+    /// Label shown in place of the key.
+    pub label: String,
+}
+
+/// Ordered, labeled property layout for one entity kind.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DetailTemplate {
+    /// Fields in display order.
+    pub fields: Vec<DetailField>,
+}
+
+/// An entity's properties split according to a [`DetailTemplate`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DetailLayout<'a> {
+    /// Templated properties as `(label, value)`, in template order.
+    /// Fields the entity does not have are skipped.
+    pub fields: Vec<(&'a str, &'a MetadataValue)>,
+    /// Remaining properties as `(key, value)`, sorted by key.
+    pub other: Vec<(&'a str, &'a MetadataValue)>,
+}
+
+impl DetailTemplate {
+    /// Parse a template from a list of `"key"` or `"key: Label"` entries.
+    ///
+    /// Returns `None` for anything other than a non-empty list.
+    pub fn parse(value: &MetadataValue) -> Option<Self> {
+        let MetadataValue::List(items) = value else {
+            return None;
+        };
+        let fields: Vec<DetailField> = items
+            .iter()
+            .filter_map(|item| match item {
+                MetadataValue::String(s) => parse_field(s),
+                _ => None,
+            })
+            .collect();
+        (!fields.is_empty()).then_some(Self { fields })
+    }
+
+    /// The template declared for `entity` under `display.template.<kind>`.
+    ///
+    /// A location subtype (e.g. `display.template.fortress`) takes precedence
+    /// over its kind.
+    pub fn for_entity(meta: &WorldMeta, entity: &Entity) -> Option<Self> {
+        let lookup = |name: &str| {
+            meta.properties
+                .get(&format!("display.template.{}", name.to_lowercase()))
+                .and_then(Self::parse)
+        };
+        entity
+            .location_subtype()
+            .and_then(lookup)
+            .or_else(|| lookup(&entity.kind.to_string()))
+    }
+
+    /// Split `entity`'s properties into templated fields and the rest.
+    pub fn layout<'a>(&'a self, entity: &'a Entity) -> DetailLayout<'a> {
+        let fields = self
+            .fields
+            .iter()
+            .filter_map(|f| entity.properties.get(&f.key).map(|v| (f.label.as_str(), v)))
+            .collect();
+        let mut other: Vec<_> = entity
+            .properties
+            .iter()
+            .filter(|(k, _)| !self.fields.iter().any(|f| &f.key == *k))
+            .map(|(k, v)| (k.as_str(), v))
+            .collect();
+        other.sort_by_key(|(k, _)| *k);
+        DetailLayout { fields, other }
+    }
+}
+
+fn parse_field(entry: &str) -> Option<DetailField> {
+    let (key, label) = match entry.split_once(':') {
+        Some((key, label)) => (key.trim(), label.trim()),
+        None => (entry.trim(), entry.trim()),
+    };
+    if key.is_empty() {
+        return None;
+    }
+    let label = if label.is_empty() { key } else { label };
+    Some(DetailField {
+        key: key.to_string(),
+        label: label.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::EntityKind;
+
+    fn template_meta(kind: &str, entries: &[&str]) -> WorldMeta {
+        let mut meta = WorldMeta::new("Test");
+        meta.properties.insert(
+            format!("display.template.{kind}"),
+            MetadataValue::List(
+                entries
+                    .iter()
+                    .map(|e| MetadataValue::String(e.to_string()))
+                    .collect(),
+            ),
+        );
+        meta
+    }
+
+    #[test]
+    fn parse_keys_and_labels() {
+        let meta = template_meta("character", &["motive: Motive", "alibi", " : nope"]);
+        let suspect = Entity::new(EntityKind::Character, "Ada");
+        let template = DetailTemplate::for_entity(&meta, &suspect).unwrap();
+        assert_eq!(
+            template.fields,
+            vec![
+                DetailField {
+                    key: "motive".into(),
+                    label: "Motive".into()
+                },
+                DetailField {
+                    key: "alibi".into(),
+                    label: "alibi".into()
+                },
+            ]
+        );
+        assert!(DetailTemplate::parse(&MetadataValue::String("motive".into())).is_none());
+        assert!(DetailTemplate::parse(&MetadataValue::List(vec![])).is_none());
+    }
+
+    #[test]
+    fn layout_orders_fields_and_collects_the_rest() {
+        let meta = template_meta("character", &["motive: Motive", "alibi: Alibi", "height"]);
+        let mut suspect = Entity::new(EntityKind::Character, "Ada");
+        for (k, v) in [("alibi", "library"), ("motive", "greed"), ("eyes", "grey")] {
+            suspect
+                .properties
+                .insert(k.into(), MetadataValue::String(v.into()));
+        }
+        suspect
+            .properties
+            .insert("age".into(), MetadataValue::Integer(41));
+
+        let template = DetailTemplate::for_entity(&meta, &suspect).unwrap();
+        let layout = template.layout(&suspect);
+        let fields: Vec<_> = layout
+            .fields
+            .iter()
+            .map(|(l, v)| (*l, v.to_string()))
+            .collect();
+        assert_eq!(
+            fields,
+            vec![("Motive", "greed".into()), ("Alibi", "library".into())]
+        );
+        let other: Vec<_> = layout.other.iter().map(|(k, _)| *k).collect();
+        assert_eq!(other, vec!["age", "eyes"]);
+    }
+
+    #[test]
+    fn location_subtype_template_wins() {
+        let mut meta = template_meta("location", &["climate"]);
+        meta.properties.insert(
+            "display.template.fortress".into(),
+            MetadataValue::List(vec![MetadataValue::String("garrison".into())]),
+        );
+        let mut keep = Entity::new(EntityKind::Location, "the Keep");
+        keep.components.location = Some(crate::component::LocationComponent {
+            location_type: "fortress".into(),
+            ..Default::default()
+        });
+        let template = DetailTemplate::for_entity(&meta, &keep).unwrap();
+        assert_eq!(template.fields[0].key, "garrison");
+
+        let item = Entity::new(EntityKind::Item, "Sword");
+        assert!(DetailTemplate::for_entity(&meta, &item).is_none());
+    }
+}
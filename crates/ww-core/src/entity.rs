@@ -144,12 +144,24 @@ pub struct Entity {
     pub kind: EntityKind,
     /// Display name of the entity.
     pub name: String,
+    /// Alternate names that also find this entity (e.g. "Kael" for
+    /// "Kael Stormborn").
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<String>,
     /// Free-text description of the entity.
     pub description: String,
+    /// Authoring notes from the `--` comment block above the declaration.
+    ///
+    /// Unlike `description`, this is never shown to players.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub doc: Option<String>,
     /// User-defined tags for categorization and filtering.
     pub tags: Vec<String>,
     /// Arbitrary key-value metadata properties.
     pub properties: HashMap<String, MetadataValue>,
+    /// GM-only notes, kept out of player-facing exports by default.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub gm_notes: Vec<String>,
     /// Typed component data attached to this entity.
     pub components: ComponentSet,
     /// Timestamp when the entity was created.
@@ -174,9 +186,12 @@ impl Entity {
             id,
             kind,
             name: name.into(),
+            aliases: Vec::new(),
             description: String::new(),
+            doc: None,
             tags: Vec::new(),
             properties: HashMap::new(),
+            gm_notes: Vec::new(),
             components: ComponentSet::default(),
             created_at: now,
             updated_at: now,
@@ -190,6 +205,33 @@ impl Entity {
             .as_ref()
             .map(|l| l.location_type.as_str())
     }
+
+    /// Whether the entity carries a tag (case-insensitive).
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t.eq_ignore_ascii_case(tag))
+    }
+
+    /// Add a tag unless it is already present. Returns true if it was added.
+    pub fn add_tag(&mut self, tag: impl Into<String>) -> bool {
+        let tag = tag.into();
+        if self.has_tag(&tag) {
+            return false;
+        }
+        self.tags.push(tag);
+        self.updated_at = Utc::now();
+        true
+    }
+
+    /// Remove a tag (case-insensitive). Returns true if it was present.
+    pub fn remove_tag(&mut self, tag: &str) -> bool {
+        let before = self.tags.len();
+        self.tags.retain(|t| !t.eq_ignore_ascii_case(tag));
+        let removed = self.tags.len() != before;
+        if removed {
+            self.updated_at = Utc::now();
+        }
+        removed
+    }
 }
 
 #[cfg(test)]
@@ -223,6 +265,17 @@ mod tests {
         assert!(subtype.is_none());
     }
 
+    #[test]
+    fn tags_are_added_once_and_removed_case_insensitively() {
+        let mut entity = Entity::new(EntityKind::Character, "Kael");
+        assert!(entity.add_tag("north-arc"));
+        assert!(!entity.add_tag("North-Arc"));
+        assert!(entity.has_tag("NORTH-ARC"));
+        assert!(entity.remove_tag("north-ARC"));
+        assert!(!entity.remove_tag("north-arc"));
+        assert!(entity.tags.is_empty());
+    }
+
     #[test]
     fn new_entity_has_timestamps() {
         let entity = Entity::new(EntityKind::Character, "Kael");
@@ -31,4 +31,12 @@ pub enum WwError {
     /// A generic validation error with a descriptive message.
     #[error("validation error: {0}")]
     Validation(String),
+
+    /// A search pattern is not a valid regular expression.
+    #[error("invalid pattern: {0}")]
+    InvalidPattern(String),
+
+    /// A serialized world could not be read.
+    #[error("invalid world JSON: {0}")]
+    InvalidJson(String),
 }
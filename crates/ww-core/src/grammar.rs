@@ -0,0 +1,183 @@
+//! English pluralization and article selection for display text.
+//!
+//! Frontends show counts and kinds everywhere ("3 locations", "an event").
+//! [`Grammar`] applies simple English rules to any word, including custom
+//! entity kinds, and lets a world override words the rules get wrong:
+//!
+//! ```text
+//! grammar {
+//!     plural { cactus "cacti" }
+//!     article { historian "an" }
+//! }
+//! ```
+
+use std::collections::HashMap;
+
+use crate::entity::MetadataValue;
+use crate::world::WorldMeta;
+
+/// Pluralization and article rules, with per-world overrides.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Grammar {
+    plurals: HashMap<String, String>,
+    articles: HashMap<String, String>,
+}
+
+impl Grammar {
+    /// Rules with the overrides declared under `grammar.plural.<word>` and
+    /// `grammar.article.<word>` in the world metadata.
+    pub fn from_meta(meta: &WorldMeta) -> Self {
+        let mut grammar = Self::default();
+        for (key, value) in &meta.properties {
+            let MetadataValue::String(value) = value else {
+                continue;
+            };
+            if let Some(word) = key.strip_prefix("grammar.plural.") {
+                grammar
+                    .plurals
+                    .insert(word.to_lowercase(), value.trim().to_string());
+            } else if let Some(word) = key.strip_prefix("grammar.article.") {
+                grammar
+                    .articles
+                    .insert(word.to_lowercase(), value.trim().to_string());
+            }
+        }
+        grammar
+    }
+
+    /// The plural form of `word`.
+    pub fn plural(&self, word: &str) -> String {
+        match self.plurals.get(&word.to_lowercase()) {
+            Some(plural) => plural.clone(),
+            None => plural(word),
+        }
+    }
+
+    /// The indefinite article (`"a"` or `"an"`) for `word`.
+    pub fn article<'a>(&'a self, word: &str) -> &'a str {
+        let first = word.split_whitespace().next().unwrap_or("").to_lowercase();
+        self.articles
+            .get(&word.to_lowercase())
+            .or_else(|| self.articles.get(&first))
+            .map(String::as_str)
+            .unwrap_or_else(|| article(word))
+    }
+
+    /// `word` preceded by its indefinite article, e.g. `"an event"`.
+    pub fn with_article(&self, word: &str) -> String {
+        format!("{} {word}", self.article(word))
+    }
+
+    /// `n` followed by `word`, pluralized unless `n` is one.
+    pub fn count(&self, n: usize, word: &str) -> String {
+        if n == 1 {
+            format!("{n} {word}")
+        } else {
+            format!("{n} {}", self.plural(word))
+        }
+    }
+}
+
+/// The plural of `word` by simple English rules.
+///
+/// Consonant + `y` becomes `ies`, sibilant endings take `es`, and everything
+/// else takes `s`. For multi-word phrases the last word is pluralized.
+pub fn plural(word: &str) -> String {
+    let lower = word.to_lowercase();
+    if lower.is_empty() {
+        return String::new();
+    }
+    if let Some(stem) = lower.strip_suffix('y')
+        && stem.chars().last().is_some_and(|c| !is_vowel(c))
+    {
+        return format!("{}ies", &word[..word.len() - 1]);
+    }
+    if ["s", "x", "z", "ch", "sh"]
+        .iter()
+        .any(|suffix| lower.ends_with(suffix))
+    {
+        return format!("{word}es");
+    }
+    format!("{word}s")
+}
+
+/// The indefinite article for `word` by simple English rules.
+///
+/// Words starting with a vowel sound take `"an"`. Common exceptions are
+/// covered: "a unicorn", "a one-eyed", "an hour", "an heir".
+pub fn article(word: &str) -> &'static str {
+    let lower = word.trim().to_lowercase();
+    const VOWEL_SOUNDING_H: [&str; 4] = ["hour", "honest", "honor", "heir"];
+    const CONSONANT_SOUNDING: [&str; 7] = ["uni", "use", "usu", "uti", "eu", "one", "once"];
+    if VOWEL_SOUNDING_H.iter().any(|p| lower.starts_with(p)) {
+        return "an";
+    }
+    if CONSONANT_SOUNDING.iter().any(|p| lower.starts_with(p)) {
+        return "a";
+    }
+    match lower.chars().next() {
+        Some(c) if is_vowel(c) => "an",
+        _ => "a",
+    }
+}
+
+fn is_vowel(c: char) -> bool {
+    matches!(c, 'a' | 'e' | 'i' | 'o' | 'u')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plural_rules() {
+        assert_eq!(plural("location"), "locations");
+        assert_eq!(plural("entity"), "entities");
+        assert_eq!(plural("day"), "days");
+        assert_eq!(plural("box"), "boxes");
+        assert_eq!(plural("witch"), "witches");
+        assert_eq!(plural("secret society"), "secret societies");
+        assert_eq!(plural("Faction"), "Factions");
+    }
+
+    #[test]
+    fn article_rules() {
+        assert_eq!(article("event"), "an");
+        assert_eq!(article("location"), "a");
+        assert_eq!(article("item"), "an");
+        assert_eq!(article("unicorn"), "a");
+        assert_eq!(article("hour"), "an");
+        assert_eq!(article("Orc"), "an");
+        assert_eq!(article(""), "a");
+    }
+
+    #[test]
+    fn overrides_from_meta() {
+        let mut meta = WorldMeta::new("Test");
+        meta.properties.insert(
+            "grammar.plural.cactus".to_string(),
+            MetadataValue::String("cacti".to_string()),
+        );
+        meta.properties.insert(
+            "grammar.article.historian".to_string(),
+            MetadataValue::String("an".to_string()),
+        );
+        let grammar = Grammar::from_meta(&meta);
+        assert_eq!(grammar.plural("Cactus"), "cacti");
+        assert_eq!(grammar.plural("cave"), "caves");
+        assert_eq!(grammar.with_article("historian"), "an historian");
+        assert_eq!(
+            grammar.with_article("historian of the deep"),
+            "an historian of the deep"
+        );
+        assert_eq!(grammar.with_article("event"), "an event");
+    }
+
+    #[test]
+    fn count_pluralizes_except_one() {
+        let grammar = Grammar::default();
+        assert_eq!(grammar.count(0, "location"), "0 locations");
+        assert_eq!(grammar.count(1, "location"), "1 location");
+        assert_eq!(grammar.count(2, "entity"), "2 entities");
+    }
+}
@@ -6,16 +6,30 @@
 
 /// Typed component data (location, character stats, event details, etc.).
 pub mod component;
+/// Author-configurable layouts for entity detail views.
+pub mod display;
 /// Entity types, identifiers, and metadata values.
 pub mod entity;
 /// Error types used throughout the crate.
 pub mod error;
+/// English pluralization and article selection for display text.
+pub mod grammar;
+/// Entity names mentioned in description prose.
+pub mod mention;
 /// Query builder for filtering and searching entities.
 pub mod query;
 /// Relationship types and identifiers connecting entities.
 pub mod relationship;
+/// Dialogue conditions and effects as written in the DSL.
+pub mod script;
+/// Worldbuilding suggestions for referenced but thin entities.
+pub mod suggest;
+/// Markdown-aware word wrapping for text output.
+pub mod text;
 /// Chronological timeline built from event entities.
 pub mod timeline;
+/// Model-level validation checks aggregated by `World::validate`.
+pub mod validate;
 /// The central world model that owns entities and relationships.
 pub mod world;
 
@@ -0,0 +1,341 @@
+//! Entity names mentioned in description prose.
+//!
+//! Descriptions often name other entities in passing ("Kael fought at the
+//! Iron Citadel"). [`find_mentions`] locates those names so exports can
+//! link them, and [`near_misses`] flags capitalized phrases that look like
+//! a misspelled entity name.
+
+use std::ops::Range;
+
+use crate::entity::EntityId;
+use crate::world::World;
+
+/// Short names are too easily confused with ordinary words to guess at.
+const MIN_TYPO_NAME_LEN: usize = 5;
+/// Largest edit distance still reported as a likely typo.
+const MAX_TYPO_DISTANCE: usize = 2;
+
+/// An entity name found in a piece of text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mention {
+    /// The entity that is mentioned.
+    pub entity: EntityId,
+    /// Byte range of the mention within the text.
+    pub range: Range<usize>,
+}
+
+/// A capitalized phrase that closely resembles an entity name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NearMiss {
+    /// The phrase as written.
+    pub text: String,
+    /// Byte range of the phrase within the text.
+    pub range: Range<usize>,
+    /// The entity it most likely meant.
+    pub entity: EntityId,
+}
+
+/// Byte ranges of every case-insensitive occurrence of any of `names`.
+///
+/// Matches may overlap and are not limited to word boundaries.
+pub fn name_spans(text: &str, names: &[String]) -> Vec<Range<usize>> {
+    let (text_lower, offsets) = lowercase_with_offsets(text);
+    let mut spans = Vec::new();
+    for name in names {
+        let name_lower = name.to_lowercase();
+        if name_lower.is_empty() {
+            continue;
+        }
+        let mut search_from = 0;
+        while let Some(found) = text_lower[search_from..].find(&name_lower) {
+            let start = search_from + found;
+            spans.push(offsets[start]..offsets[start + name_lower.len()]);
+            search_from = start + text_lower[start..].chars().next().map_or(1, char::len_utf8);
+        }
+    }
+    spans
+}
+
+/// Whole-word mentions of entity names in `text`, in text order.
+///
+/// Matching ignores case. Where names overlap the longest one wins, so
+/// "the Iron Citadel" is not also reported as "Iron".
+pub fn find_mentions(world: &World, text: &str) -> Vec<Mention> {
+    let (text_lower, offsets) = lowercase_with_offsets(text);
+
+    let mut candidates: Vec<Mention> = Vec::new();
+    for entity in world.all_entities() {
+        let name = entity.name.to_lowercase();
+        if name.is_empty() {
+            continue;
+        }
+        let mut search_from = 0;
+        while let Some(found) = text_lower[search_from..].find(&name) {
+            let lower_end = search_from + found + name.len();
+            let (start, end) = (offsets[search_from + found], offsets[lower_end]);
+            if is_word_boundary(text, start) && is_word_boundary(text, end) {
+                candidates.push(Mention {
+                    entity: entity.id,
+                    range: start..end,
+                });
+            }
+            search_from = lower_end;
+        }
+    }
+
+    candidates.sort_by_key(|m| (m.range.start, std::cmp::Reverse(m.range.end)));
+    let mut mentions: Vec<Mention> = Vec::new();
+    for candidate in candidates {
+        if mentions
+            .last()
+            .is_none_or(|last| candidate.range.start >= last.range.end)
+        {
+            mentions.push(candidate);
+        }
+    }
+    mentions
+}
+
+/// Capitalized phrases in `text` that are within a couple of typos of an
+/// entity name but are not an exact mention.
+///
+/// A leading "the" on entity names is ignored when comparing, so
+/// "Iron Citdel" is matched against "the Iron Citadel".
+pub fn near_misses(world: &World, text: &str) -> Vec<NearMiss> {
+    let mentions = find_mentions(world, text);
+    let names: Vec<(EntityId, String)> = world
+        .all_entities()
+        .map(|e| (e.id, bare_name(&e.name)))
+        .filter(|(_, name)| name.len() >= MIN_TYPO_NAME_LEN)
+        .collect();
+
+    let mut misses = Vec::new();
+    for range in capitalized_phrases(text) {
+        if mentions
+            .iter()
+            .any(|m| m.range.start <= range.start && range.end <= m.range.end)
+        {
+            continue;
+        }
+        let phrase = &text[range.clone()];
+        let lower = bare_name(phrase);
+        let best = names
+            .iter()
+            .map(|(id, name)| (*id, edit_distance(&lower, name)))
+            .filter(|(_, d)| (1..=MAX_TYPO_DISTANCE).contains(d))
+            .min_by_key(|(_, d)| *d);
+        if let Some((entity, _)) = best {
+            misses.push(NearMiss {
+                text: phrase.to_string(),
+                range,
+                entity,
+            });
+        }
+    }
+    misses
+}
+
+/// Lowercased name without a leading article or a trailing possessive,
+/// so "the Undercrypt", "Undercrypt's" and "an Undercrypt" compare equal.
+fn bare_name(name: &str) -> String {
+    let lower = name.trim().to_lowercase();
+    let lower = ["'s", "\u{2019}s"]
+        .iter()
+        .find_map(|suffix| lower.strip_suffix(suffix))
+        .unwrap_or(&lower);
+    ["the ", "an ", "a "]
+        .iter()
+        .find_map(|article| lower.strip_prefix(article))
+        .unwrap_or(lower)
+        .to_string()
+}
+
+/// `text` lowercased, with the byte offset in `text` of every byte offset
+/// in the lowercased copy, plus one past the end.
+///
+/// Lowercasing can change a character's byte length ("İ" becomes two
+/// characters), so positions found in the copy are mapped back through this.
+fn lowercase_with_offsets(text: &str) -> (String, Vec<usize>) {
+    let mut lower = String::with_capacity(text.len());
+    let mut offsets = Vec::with_capacity(text.len() + 1);
+    for (i, c) in text.char_indices() {
+        for lc in c.to_lowercase() {
+            lower.push(lc);
+            offsets.extend(std::iter::repeat_n(i, lc.len_utf8()));
+        }
+    }
+    offsets.push(text.len());
+    (lower, offsets)
+}
+
+fn is_word_boundary(text: &str, index: usize) -> bool {
+    let before = text[..index].chars().next_back();
+    let after = text[index..].chars().next();
+    !(before.is_some_and(char::is_alphanumeric) && after.is_some_and(char::is_alphanumeric))
+}
+
+/// Runs of capitalized words, e.g. "Iron Citdel" or "Order of Dwn".
+///
+/// Lowercase "of" is allowed between capitalized words.
+fn capitalized_phrases(text: &str) -> Vec<Range<usize>> {
+    let mut words = Vec::new();
+    let mut start = None;
+    for (i, c) in text.char_indices() {
+        match (c.is_alphanumeric() || c == '\'', start) {
+            (true, None) => start = Some(i),
+            (false, Some(s)) => {
+                words.push(s..i);
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(s) = start {
+        words.push(s..text.len());
+    }
+
+    let is_capitalized = |r: &Range<usize>| text[r.clone()].starts_with(char::is_uppercase);
+    let only_spaces = |a: usize, b: usize| text[a..b].chars().all(|c| c == ' ');
+
+    let mut phrases = Vec::new();
+    let mut i = 0;
+    while i < words.len() {
+        if !is_capitalized(&words[i]) {
+            i += 1;
+            continue;
+        }
+        let start = words[i].start;
+        let mut end = words[i].end;
+        let mut j = i + 1;
+        while j < words.len() && only_spaces(end, words[j].start) {
+            if is_capitalized(&words[j]) {
+                end = words[j].end;
+                j += 1;
+            } else if &text[words[j].clone()] == "of"
+                && j + 1 < words.len()
+                && only_spaces(words[j].end, words[j + 1].start)
+                && is_capitalized(&words[j + 1])
+            {
+                end = words[j + 1].end;
+                j += 2;
+            } else {
+                break;
+            }
+        }
+        phrases.push(start..end);
+        i = j;
+    }
+    phrases
+}
+
+/// Levenshtein distance between two strings, by characters.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut prev = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let next = (prev + usize::from(ca != *cb))
+                .min(row[j] + 1)
+                .min(row[j + 1] + 1);
+            prev = row[j + 1];
+            row[j + 1] = next;
+        }
+    }
+    row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::{Entity, EntityKind};
+    use crate::world::WorldMeta;
+
+    fn world() -> World {
+        let mut world = World::new(WorldMeta::new("Test"));
+        for (kind, name) in [
+            (EntityKind::Character, "Kael"),
+            (EntityKind::Location, "the Iron Citadel"),
+            (EntityKind::Location, "Iron"),
+            (EntityKind::Faction, "the Order of Dawn"),
+        ] {
+            world.add_entity(Entity::new(kind, name)).unwrap();
+        }
+        world
+    }
+
+    #[test]
+    fn mentions_prefer_longest_whole_word_names() {
+        let world = world();
+        let text = "Kael fought at The Iron Citadel; Kaelin did not.";
+        let found: Vec<&str> = find_mentions(&world, text)
+            .iter()
+            .map(|m| &text[m.range.clone()])
+            .collect();
+        assert_eq!(found, ["Kael", "The Iron Citadel"]);
+    }
+
+    #[test]
+    fn near_misses_flag_likely_typos() {
+        let world = world();
+        let text = "Kael rode from the Iron Citdel to meet the Order of Dwn. Nobody else came.";
+        let misses = near_misses(&world, text);
+        let found: Vec<(&str, &str)> = misses
+            .iter()
+            .map(|m| (m.text.as_str(), world.entity_name(m.entity)))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                ("Iron Citdel", "the Iron Citadel"),
+                ("Order of Dwn", "the Order of Dawn")
+            ]
+        );
+    }
+
+    #[test]
+    fn near_misses_ignore_possessives_and_articles() {
+        let world = world();
+        let text = "The Iron Citadel's gate shut. An Order of Dawn man rode in.";
+        assert!(near_misses(&world, text).is_empty());
+    }
+
+    #[test]
+    fn mentions_survive_lowercasing_that_changes_length() {
+        let world = world();
+        // "İ" lowercases to two characters, three bytes instead of two
+        let text = "İstanbul greets Kael at the Iron Citadel.";
+        let found: Vec<&str> = find_mentions(&world, text)
+            .iter()
+            .map(|m| &text[m.range.clone()])
+            .collect();
+        assert_eq!(found, ["Kael", "the Iron Citadel"]);
+        let spans = name_spans(text, &["kael".to_string()]);
+        assert_eq!(spans, [found_range(text, "Kael")]);
+    }
+
+    fn found_range(text: &str, needle: &str) -> Range<usize> {
+        let start = text.find(needle).unwrap();
+        start..start + needle.len()
+    }
+
+    #[test]
+    fn index_skips_self_mentions() {
+        let mut world = world();
+        let kael = world.find_id_by_name("Kael").unwrap();
+        world.get_entity_mut(kael).unwrap().description =
+            "Kael guards the Iron Citadel.".to_string();
+        world.index_mentions();
+        let mentions = world.mentions(kael);
+        assert_eq!(mentions.len(), 1);
+        assert_eq!(world.entity_name(mentions[0].entity), "the Iron Citadel");
+    }
+
+    #[test]
+    fn edit_distance_counts_edits() {
+        assert_eq!(edit_distance("citadel", "citadel"), 0);
+        assert_eq!(edit_distance("citdel", "citadel"), 1);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+    }
+}
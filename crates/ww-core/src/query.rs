@@ -1,13 +1,64 @@
+use regex::Regex;
+
 use crate::entity::{Entity, EntityId, EntityKind};
+use crate::error::{WwError, WwResult};
+use crate::relationship::RelationshipKind;
+use crate::text::similarity;
 use crate::world::World;
 
+/// The part of an entity a ranked search matched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatchField {
+    /// The entity's name.
+    Name,
+    /// One of the entity's tags.
+    Tag,
+    /// The entity's description.
+    Description,
+}
+
+/// Which way a relationship must point for [`QueryBuilder::related_to`].
+///
+/// Directions are seen from the anchor entity. Bidirectional relationship
+/// kinds match in either direction regardless.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RelationDirection {
+    /// The relationship may point either way.
+    #[default]
+    Either,
+    /// The anchor is the source, e.g. the Order for `the Order based at the Citadel`.
+    Outgoing,
+    /// The anchor is the target, e.g. the Order for `Kael member of the Order`.
+    Incoming,
+}
+
+/// The `related_to` filter of a [`QueryBuilder`].
+struct RelatedFilter {
+    anchor: EntityId,
+    kind: Option<RelationshipKind>,
+    direction: RelationDirection,
+}
+
+/// An entity found by [`QueryBuilder::ranked`], with its relevance.
+#[derive(Debug, Clone)]
+pub struct SearchHit<'w> {
+    /// The matching entity.
+    pub entity: &'w Entity,
+    /// The best-scoring field that matched.
+    pub field: MatchField,
+    /// Relevance; higher is better.
+    pub score: u32,
+}
+
 /// A builder for filtering and searching entities in a world.
 pub struct QueryBuilder<'w> {
     world: &'w World,
     kind_filter: Option<EntityKind>,
     tag_filters: Vec<String>,
     name_contains: Option<String>,
-    related_to: Option<EntityId>,
+    pattern: Option<Regex>,
+    fuzzy: Option<(String, f64)>,
+    related_to: Option<RelatedFilter>,
     has_property: Option<String>,
     limit: Option<usize>,
     offset: usize,
@@ -21,6 +72,8 @@ impl<'w> QueryBuilder<'w> {
             kind_filter: None,
             tag_filters: Vec::new(),
             name_contains: None,
+            pattern: None,
+            fuzzy: None,
             related_to: None,
             has_property: None,
             limit: None,
@@ -46,9 +99,46 @@ impl<'w> QueryBuilder<'w> {
         self
     }
 
-    /// Filter to entities that have a relationship with the given entity.
-    pub fn related_to(mut self, id: EntityId) -> Self {
-        self.related_to = Some(id);
+    /// Filter to entities whose name, tags, or description match a regular
+    /// expression. Prefix the pattern with `(?i)` to ignore case.
+    ///
+    /// Fails with [`WwError::InvalidPattern`] if the pattern does not compile.
+    pub fn matches_regex(mut self, pattern: &str) -> WwResult<Self> {
+        let regex = Regex::new(pattern).map_err(|e| WwError::InvalidPattern(e.to_string()))?;
+        self.pattern = Some(regex);
+        Ok(self)
+    }
+
+    /// Filter to entities whose name or an alias is similar to `term`, with a
+    /// [`similarity`] score of at least `threshold` (0.0 to 1.0).
+    ///
+    /// Tolerates typos, e.g. `Kael Stormbon` finds `Kael Stormborn`. Results
+    /// of [`execute`](Self::execute) come best match first.
+    pub fn fuzzy(mut self, term: impl Into<String>, threshold: f64) -> Self {
+        self.fuzzy = Some((term.into(), threshold));
+        self
+    }
+
+    /// Filter to entities that have a relationship with the given entity,
+    /// optionally only relationships of the given kind.
+    ///
+    /// The anchor entity itself is never part of the results. An anchor
+    /// without relationships simply matches nothing.
+    pub fn related_to(mut self, id: EntityId, kind: Option<RelationshipKind>) -> Self {
+        self.related_to = Some(RelatedFilter {
+            anchor: id,
+            kind,
+            direction: RelationDirection::default(),
+        });
+        self
+    }
+
+    /// Restrict the [`related_to`](Self::related_to) filter to relationships
+    /// pointing in one direction. Has no effect without `related_to`.
+    pub fn related_direction(mut self, direction: RelationDirection) -> Self {
+        if let Some(ref mut filter) = self.related_to {
+            filter.direction = direction;
+        }
         self
     }
 
@@ -78,8 +168,11 @@ impl<'w> QueryBuilder<'w> {
             .filter(|e| self.matches(e))
             .collect();
 
-        // Sort by name for deterministic output
-        results.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
+        // Sort by name for deterministic output, best fuzzy matches first
+        results.sort_by_key(|a| a.name.to_lowercase());
+        if self.fuzzy.is_some() {
+            results.sort_by(|a, b| self.fuzzy_score(b).total_cmp(&self.fuzzy_score(a)));
+        }
 
         // Apply offset and limit
         let results: Vec<&Entity> = results.into_iter().skip(self.offset).collect();
@@ -90,6 +183,39 @@ impl<'w> QueryBuilder<'w> {
         }
     }
 
+    /// Execute the query, keeping entities whose name, tags, or description
+    /// contain `text` (case-insensitive), best matches first.
+    ///
+    /// Name matches outrank tag matches, which outrank description matches;
+    /// within a field, exact and prefix matches rank highest. Ties are
+    /// broken by name.
+    pub fn ranked(self, text: &str) -> Vec<SearchHit<'w>> {
+        let needle = text.trim().to_lowercase();
+        if needle.is_empty() {
+            return Vec::new();
+        }
+        let mut hits: Vec<SearchHit<'w>> = self
+            .world
+            .all_entities()
+            .filter(|e| self.matches(e))
+            .filter_map(|e| rank(e, &needle))
+            .collect();
+        hits.sort_by(|a, b| {
+            b.score.cmp(&a.score).then_with(|| {
+                a.entity
+                    .name
+                    .to_lowercase()
+                    .cmp(&b.entity.name.to_lowercase())
+            })
+        });
+
+        let hits = hits.into_iter().skip(self.offset);
+        match self.limit {
+            Some(limit) => hits.take(limit).collect(),
+            None => hits.collect(),
+        }
+    }
+
     /// Count matching entities without collecting them.
     pub fn count(self) -> usize {
         self.world
@@ -121,17 +247,27 @@ impl<'w> QueryBuilder<'w> {
             return false;
         }
 
+        // Regular expression
+        if let Some(ref regex) = self.pattern
+            && !regex.is_match(&entity.name)
+            && !entity.tags.iter().any(|t| regex.is_match(t))
+            && !regex.is_match(&entity.description)
+        {
+            return false;
+        }
+
+        // Fuzzy name
+        if let Some((_, threshold)) = self.fuzzy
+            && self.fuzzy_score(entity) < threshold
+        {
+            return false;
+        }
+
         // Related to
-        if let Some(related_id) = self.related_to {
-            let neighbors: Vec<_> = self
-                .world
-                .neighbors(entity.id)
-                .into_iter()
-                .map(|(id, _)| id)
-                .collect();
-            if !neighbors.contains(&related_id) {
-                return false;
-            }
+        if let Some(ref filter) = self.related_to
+            && !filter.matches(self.world, entity.id)
+        {
+            return false;
         }
 
         // Has property
@@ -143,12 +279,96 @@ impl<'w> QueryBuilder<'w> {
 
         true
     }
+
+    /// Best similarity of the fuzzy term to the entity's name or aliases.
+    fn fuzzy_score(&self, entity: &Entity) -> f64 {
+        let Some((ref term, _)) = self.fuzzy else {
+            return 0.0;
+        };
+        std::iter::once(&entity.name)
+            .chain(&entity.aliases)
+            .map(|name| similarity(term, name))
+            .fold(0.0, f64::max)
+    }
+}
+
+impl RelatedFilter {
+    fn matches(&self, world: &World, entity: EntityId) -> bool {
+        if entity == self.anchor {
+            return false;
+        }
+        world
+            .relationships_of(self.anchor)
+            .into_iter()
+            .filter(|rel| self.kind.as_ref().is_none_or(|kind| rel.kind == *kind))
+            .any(|rel| {
+                let outgoing = rel.source == self.anchor && rel.target == entity;
+                let incoming = rel.target == self.anchor && rel.source == entity;
+                match self.direction {
+                    _ if rel.kind.is_bidirectional() => outgoing || incoming,
+                    RelationDirection::Either => outgoing || incoming,
+                    RelationDirection::Outgoing => outgoing,
+                    RelationDirection::Incoming => incoming,
+                }
+            })
+    }
+}
+
+/// Score how well an entity matches a lowercase search term.
+fn rank<'w>(entity: &'w Entity, needle: &str) -> Option<SearchHit<'w>> {
+    let name = entity.name.to_lowercase();
+    let name_score = if name == needle {
+        100
+    } else if name.starts_with(needle) {
+        80
+    } else if name.split_whitespace().any(|w| w.starts_with(needle)) {
+        60
+    } else if name.contains(needle) {
+        50
+    } else {
+        0
+    };
+    let tag_score = entity
+        .tags
+        .iter()
+        .map(|t| t.to_lowercase())
+        .map(|t| {
+            if t == needle {
+                40
+            } else if t.contains(needle) {
+                30
+            } else {
+                0
+            }
+        })
+        .max()
+        .unwrap_or(0);
+    let occurrences = entity.description.to_lowercase().matches(needle).count() as u32;
+    let description_score = match occurrences {
+        0 => 0,
+        n => 10 + n.min(9),
+    };
+
+    [
+        (name_score, MatchField::Name),
+        (tag_score, MatchField::Tag),
+        (description_score, MatchField::Description),
+    ]
+    .into_iter()
+    .filter(|(score, _)| *score > 0)
+    .max_by_key(|(score, _)| *score)
+    .map(|(score, field)| SearchHit {
+        entity,
+        field,
+        score,
+    })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::entity::MetadataValue;
+    use crate::relationship::Relationship;
     use crate::world::WorldMeta;
 
     fn test_world() -> World {
@@ -222,4 +442,122 @@ mod tests {
         let world = test_world();
         assert_eq!(world.query().kind(EntityKind::Character).count(), 2);
     }
+
+    #[test]
+    fn ranked_orders_by_field_and_match_quality() {
+        let mut world = test_world();
+        let mut tower = Entity::new(EntityKind::Location, "Mage Tower");
+        tower.description = "Where every mage of the realm trains.".to_string();
+        world.add_entity(tower).unwrap();
+        let mut sage = Entity::new(EntityKind::Character, "Old Sage");
+        sage.description = "Once a court mage.".to_string();
+        world.add_entity(sage).unwrap();
+
+        let hits = world.query().ranked("Mage");
+        let names: Vec<_> = hits.iter().map(|h| h.entity.name.as_str()).collect();
+        assert_eq!(names, ["Mage Tower", "Elara Nightwhisper", "Old Sage"]);
+        assert_eq!(hits[1].field, MatchField::Tag);
+        assert_eq!(hits[2].field, MatchField::Description);
+
+        let characters = world.query().kind(EntityKind::Character).ranked("mage");
+        assert_eq!(characters.len(), 2);
+        assert!(world.query().ranked("  ").is_empty());
+    }
+
+    #[test]
+    fn query_related_to_by_kind_and_direction() {
+        let mut world = test_world();
+        let order = world
+            .add_entity(Entity::new(EntityKind::Faction, "The Order"))
+            .unwrap();
+        let kael = world.find_id_by_name("Kael Stormborn").unwrap();
+        let elara = world.find_id_by_name("Elara Nightwhisper").unwrap();
+        let citadel = world.find_id_by_name("The Iron Citadel").unwrap();
+        for (source, kind, target) in [
+            (kael, RelationshipKind::MemberOf, order),
+            (elara, RelationshipKind::RivalOf, order),
+            (order, RelationshipKind::BasedAt, citadel),
+        ] {
+            world
+                .add_relationship(Relationship::new(source, kind, target))
+                .unwrap();
+        }
+
+        let names = |query: QueryBuilder| -> Vec<String> {
+            query
+                .execute()
+                .into_iter()
+                .map(|e| e.name.clone())
+                .collect()
+        };
+        assert_eq!(
+            names(world.query().related_to(order, None)),
+            ["Elara Nightwhisper", "Kael Stormborn", "The Iron Citadel"]
+        );
+        assert_eq!(
+            names(
+                world
+                    .query()
+                    .related_to(order, Some(RelationshipKind::MemberOf))
+            ),
+            ["Kael Stormborn"]
+        );
+        assert_eq!(
+            names(
+                world
+                    .query()
+                    .related_to(order, None)
+                    .related_direction(RelationDirection::Incoming)
+            ),
+            ["Elara Nightwhisper", "Kael Stormborn"]
+        );
+        assert_eq!(
+            names(
+                world
+                    .query()
+                    .related_to(order, None)
+                    .related_direction(RelationDirection::Outgoing)
+            ),
+            ["Elara Nightwhisper", "The Iron Citadel"]
+        );
+
+        let loner = world
+            .add_entity(Entity::new(EntityKind::Character, "Hermit"))
+            .unwrap();
+        assert_eq!(world.query().related_to(loner, None).count(), 0);
+    }
+
+    #[test]
+    fn query_matches_regex() {
+        let world = test_world();
+        let results = world
+            .query()
+            .matches_regex(r"^(Kael|Elara) ")
+            .unwrap()
+            .execute();
+        assert_eq!(results.len(), 2);
+
+        let results = world
+            .query()
+            .matches_regex("(?i)CITADEL$")
+            .unwrap()
+            .execute();
+        assert_eq!(results[0].name, "The Iron Citadel");
+
+        let err = world.query().matches_regex("(unclosed").err().unwrap();
+        assert!(matches!(err, WwError::InvalidPattern(_)));
+    }
+
+    #[test]
+    fn query_fuzzy_tolerates_typos() {
+        let mut world = test_world();
+        let mut kaela = Entity::new(EntityKind::Character, "Kaela");
+        kaela.aliases = vec!["Kael Stormbringer".to_string()];
+        world.add_entity(kaela).unwrap();
+
+        let results = world.query().fuzzy("kael stormbon", 0.8).execute();
+        let names: Vec<_> = results.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, ["Kael Stormborn", "Kaela"]);
+        assert_eq!(world.query().fuzzy("Citadel of Glass", 0.95).count(), 0);
+    }
 }
@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::entity::{EntityId, MetadataValue};
+use crate::world::World;
 
 /// Unique identifier for a relationship edge.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -46,6 +47,15 @@ pub struct Relationship {
     pub metadata: HashMap<String, MetadataValue>,
     /// Whether the relationship applies in both directions.
     pub bidirectional: bool,
+    /// Year the relationship began, e.g. `since year 1203`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub since: Option<i64>,
+    /// Year the relationship ended, e.g. `until year 1250`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub until: Option<i64>,
+    /// How strong the bond or enmity is, e.g. `strength 3`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub strength: Option<i64>,
 }
 
 impl Relationship {
@@ -60,6 +70,23 @@ impl Relationship {
             label: None,
             metadata: HashMap::new(),
             bidirectional,
+            since: None,
+            until: None,
+            strength: None,
+        }
+    }
+
+    /// Keys accepted by [`attribute`](Self::attribute), in display order.
+    pub const ATTRIBUTES: [&'static str; 3] = ["since", "until", "strength"];
+
+    /// Look up a typed attribute by its DSL keyword: `since`, `until`, or
+    /// `strength`. Returns `None` for unset attributes and unknown keys.
+    pub fn attribute(&self, key: &str) -> Option<i64> {
+        match key {
+            "since" => self.since,
+            "until" => self.until,
+            "strength" => self.strength,
+            _ => None,
         }
     }
 
@@ -68,6 +95,35 @@ impl Relationship {
         self.label = Some(label.into());
         self
     }
+
+    /// Phrase this relationship as a sentence from source to target, e.g.
+    /// "Kael is allied with Elara." or "Kael leads the Order of Dawn."
+    ///
+    /// Endpoint names are resolved in `world`. A label is appended in
+    /// parentheses, except on exits where it names the direction.
+    pub fn describe(&self, world: &World) -> String {
+        let source = world.entity_name(self.source);
+        let target = world.entity_name(self.target);
+        let mut label = self.label.as_deref();
+        let sentence = match (&self.kind, label) {
+            (RelationshipKind::ConnectedTo, Some(dir)) => {
+                label = None;
+                format!("{source} leads {dir} to {target}")
+            }
+            (kind, _) => format!("{source} {} {target}", kind.verb_phrase()),
+        };
+
+        let mut chars = sentence.chars();
+        let mut out: String = match chars.next() {
+            Some(first) => first.to_uppercase().chain(chars).collect(),
+            None => String::new(),
+        };
+        if let Some(label) = label {
+            out.push_str(&format!(" ({label})"));
+        }
+        out.push('.');
+        out
+    }
 }
 
 /// The kind of relationship between two entities.
@@ -143,8 +199,76 @@ impl RelationshipKind {
             Self::Custom(s) => s.as_str(),
         }
     }
+
+    /// Returns the kind of the same relationship with source and target
+    /// swapped, e.g. the inverse of `Kael member of the Order` is
+    /// `the Order has_member Kael`.
+    ///
+    /// Bidirectional kinds are their own inverse. One-way kinds map to the
+    /// custom kinds in [`INVERSE_KINDS`] and back. Other custom kinds have no
+    /// known inverse.
+    pub fn inverse(&self) -> Option<RelationshipKind> {
+        if self.is_bidirectional() {
+            return Some(self.clone());
+        }
+        if let Self::Custom(name) = self {
+            return INVERSE_KINDS
+                .iter()
+                .find(|(inverse, _)| inverse == name)
+                .map(|(_, kind)| kind.clone());
+        }
+        INVERSE_KINDS
+            .iter()
+            .find(|(_, kind)| kind == self)
+            .map(|(inverse, _)| Self::Custom((*inverse).to_string()))
+    }
+
+    /// Returns the verb phrase that reads `<source> <phrase> <target>` as a
+    /// sentence, e.g. `is a member of` or `leads`. Custom kinds use their name
+    /// with underscores as spaces.
+    pub fn verb_phrase(&self) -> String {
+        match self {
+            Self::ContainedIn => "is in",
+            Self::ConnectedTo => "is connected to",
+            Self::LocatedAt => "is located at",
+            Self::BasedAt => "is based at",
+            Self::MemberOf => "is a member of",
+            Self::LeaderOf => "leads",
+            Self::AlliedWith => "is allied with",
+            Self::RivalOf => "is a rival of",
+            Self::RelatedTo => "is related to",
+            Self::OwnedBy => "owns",
+            Self::ParticipatedIn => "took part in",
+            Self::CausedBy => "was caused by",
+            Self::References => "references",
+            Self::Custom(kind) => return kind.replace('_', " "),
+        }
+        .to_string()
+    }
+
+    /// Returns the verb phrase for this relationship read from target to
+    /// source, i.e. the [`verb_phrase`](Self::verb_phrase) of its
+    /// [`inverse`](Self::inverse): `<target> <phrase> <source>`, e.g.
+    /// `has member` or `is led by`.
+    pub fn inverse_phrase(&self) -> Option<String> {
+        self.inverse().map(|kind| kind.verb_phrase())
+    }
 }
 
+/// Custom kind names for the inverses of one-way relationship kinds, named
+/// so that `<source> <name> <target>` reads as a sentence.
+pub const INVERSE_KINDS: [(&str, RelationshipKind); 9] = [
+    ("contains", RelationshipKind::ContainedIn),
+    ("hosts", RelationshipKind::LocatedAt),
+    ("is_home_to", RelationshipKind::BasedAt),
+    ("has_member", RelationshipKind::MemberOf),
+    ("is_led_by", RelationshipKind::LeaderOf),
+    ("is_owned_by", RelationshipKind::OwnedBy),
+    ("involves", RelationshipKind::ParticipatedIn),
+    ("caused", RelationshipKind::CausedBy),
+    ("is_referenced_by", RelationshipKind::References),
+];
+
 impl fmt::Display for RelationshipKind {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.as_phrase())
@@ -155,6 +279,22 @@ impl fmt::Display for RelationshipKind {
 mod tests {
     use super::*;
 
+    #[test]
+    fn attributes_by_key() {
+        let mut rel = Relationship::new(
+            EntityId::new(),
+            RelationshipKind::AlliedWith,
+            EntityId::new(),
+        );
+        rel.since = Some(1203);
+        rel.strength = Some(3);
+
+        assert_eq!(rel.attribute("since"), Some(1203));
+        assert_eq!(rel.attribute("until"), None);
+        assert_eq!(rel.attribute("strength"), Some(3));
+        assert_eq!(rel.attribute("color"), None);
+    }
+
     #[test]
     fn bidirectional_kinds() {
         assert!(RelationshipKind::AlliedWith.is_bidirectional());
@@ -163,6 +303,88 @@ mod tests {
         assert!(!RelationshipKind::OwnedBy.is_bidirectional());
     }
 
+    #[test]
+    fn inverse_kinds_round_trip() {
+        assert_eq!(
+            RelationshipKind::MemberOf.inverse(),
+            Some(RelationshipKind::Custom("has_member".to_string()))
+        );
+        assert_eq!(
+            RelationshipKind::AlliedWith.inverse(),
+            Some(RelationshipKind::AlliedWith)
+        );
+        for (_, kind) in &INVERSE_KINDS {
+            assert_eq!(
+                kind.inverse().and_then(|k| k.inverse()).as_ref(),
+                Some(kind)
+            );
+        }
+        assert_eq!(
+            RelationshipKind::Custom("mentors".to_string()).inverse(),
+            None
+        );
+    }
+
+    #[test]
+    fn inverse_phrases() {
+        let phrase = |kind: RelationshipKind| kind.inverse_phrase();
+        assert_eq!(
+            phrase(RelationshipKind::LeaderOf).as_deref(),
+            Some("is led by")
+        );
+        assert_eq!(
+            phrase(RelationshipKind::MemberOf).as_deref(),
+            Some("has member")
+        );
+        assert_eq!(
+            phrase(RelationshipKind::AlliedWith).as_deref(),
+            Some("is allied with")
+        );
+        assert_eq!(
+            phrase(RelationshipKind::Custom("mentors".to_string())),
+            None
+        );
+        for (name, kind) in &INVERSE_KINDS {
+            assert_eq!(kind.inverse_phrase(), Some(name.replace('_', " ")));
+        }
+    }
+
+    #[test]
+    fn describe_phrases_each_direction_naturally() {
+        use crate::entity::{Entity, EntityKind};
+        use crate::world::WorldMeta;
+
+        let mut world = World::new(WorldMeta::new("Test"));
+        let kael = world
+            .add_entity(Entity::new(EntityKind::Character, "Kael"))
+            .unwrap();
+        let order = world
+            .add_entity(Entity::new(EntityKind::Faction, "the Order of Dawn"))
+            .unwrap();
+        let gate = world
+            .add_entity(Entity::new(EntityKind::Location, "the Gate"))
+            .unwrap();
+
+        let led = Relationship::new(kael, RelationshipKind::LeaderOf, order);
+        assert_eq!(led.describe(&world), "Kael leads the Order of Dawn.");
+
+        let member = Relationship::new(kael, RelationshipKind::MemberOf, order).with_label("sworn");
+        assert_eq!(
+            member.describe(&world),
+            "Kael is a member of the Order of Dawn (sworn)."
+        );
+
+        let exit =
+            Relationship::new(gate, RelationshipKind::ConnectedTo, order).with_label("north");
+        assert_eq!(
+            exit.describe(&world),
+            "The Gate leads north to the Order of Dawn."
+        );
+
+        let custom = Relationship::new(kael, RelationshipKind::Custom("guards".to_string()), gate);
+        assert_eq!(custom.describe(&world), "Kael guards the Gate.");
+    }
+
     #[test]
     fn relationship_builder() {
         let src = EntityId::new();
@@ -0,0 +1,227 @@
+//! Dialogue conditions and effects as written in the DSL.
+//!
+//! `requires` and `when` lines hold a condition, effect lines an effect.
+//! Both are stored as text in [`DialogueData`](crate::component::DialogueData);
+//! the compiler parses them here to report text it does not understand, and
+//! the fiction engine turns the parsed form into something it can evaluate.
+
+use crate::entity::MetadataValue;
+
+/// A dialogue condition, e.g. `has_item "Key"` or `not visited Crypt`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConditionScript {
+    /// `has_item <item>` or `has <item>`: the player carries the item.
+    HasItem(String),
+    /// `visited <location>`: the player has been there.
+    Visited(String),
+    /// `at <location>`: the player is there now.
+    At(String),
+    /// `knows <key>`: a knowledge flag is set.
+    Knows(String),
+    /// `flag <key> [value]`: a flag is set, or set to the value.
+    Flag {
+        /// Flag key.
+        key: String,
+        /// Required value, if any.
+        value: Option<MetadataValue>,
+    },
+    /// `player has <key>`: a flag is set or an item is carried.
+    ///
+    /// The older form; `player has knowledge.<key>` reads as [`Self::Knows`].
+    PlayerHas(String),
+    /// `not <condition>`.
+    Not(Box<ConditionScript>),
+}
+
+impl ConditionScript {
+    /// Parse a condition, or `None` if the text is not one.
+    pub fn parse(text: &str) -> Option<Self> {
+        let words = split_words(text);
+        let (verb, args) = words.split_first()?;
+        let name = || match args {
+            [name] => Some(name.clone()),
+            _ => None,
+        };
+        match verb.as_str() {
+            "not" => {
+                Self::parse(text.trim_start().strip_prefix("not")?).map(|c| Self::Not(Box::new(c)))
+            }
+            "has_item" | "has" => name().map(Self::HasItem),
+            "visited" => name().map(Self::Visited),
+            "at" => name().map(Self::At),
+            "knows" => name().map(Self::Knows),
+            "flag" => match args {
+                [key] => Some(Self::Flag {
+                    key: key.clone(),
+                    value: None,
+                }),
+                [key, value] => Some(Self::Flag {
+                    key: key.clone(),
+                    value: Some(parse_value(value)),
+                }),
+                _ => None,
+            },
+            "player" => match args {
+                [has, key] if has == "has" => Some(match key.strip_prefix("knowledge.") {
+                    Some(key) => Self::Knows(key.to_string()),
+                    None => Self::PlayerHas(key.clone()),
+                }),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+/// A dialogue effect, e.g. `gives "Torch"` or `sets flag met_king`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum EffectScript {
+    /// `gives <item>`: the player receives the item.
+    Give(String),
+    /// `takes <item>`: the player hands the item over.
+    Take(String),
+    /// `sets flag <key> [value]`, or the older `set <key> <value>`.
+    SetFlag {
+        /// Flag key.
+        key: String,
+        /// Value to set; `true` when omitted.
+        value: MetadataValue,
+    },
+    /// `clears flag <key>`.
+    ClearFlag(String),
+    /// `sets|clears knowledge <key>`, or the older `set knowledge.<key> <bool>`.
+    SetKnowledge {
+        /// Knowledge key.
+        key: String,
+        /// Whether the knowledge is gained or lost.
+        value: bool,
+    },
+}
+
+impl EffectScript {
+    /// Parse an effect, or `None` if the text is not one.
+    pub fn parse(text: &str) -> Option<Self> {
+        let words = split_words(text);
+        let words: Vec<&str> = words.iter().map(String::as_str).collect();
+        let effect = match words.as_slice() {
+            ["gives", item] => Self::Give(item.to_string()),
+            ["takes", item] => Self::Take(item.to_string()),
+            ["sets", "flag", key] => Self::SetFlag {
+                key: key.to_string(),
+                value: MetadataValue::Boolean(true),
+            },
+            ["sets", "flag", key, value] => Self::SetFlag {
+                key: key.to_string(),
+                value: parse_value(value),
+            },
+            ["clears", "flag", key] => Self::ClearFlag(key.to_string()),
+            [verb @ ("sets" | "clears"), "knowledge", key] => Self::SetKnowledge {
+                key: key.to_string(),
+                value: *verb == "sets",
+            },
+            ["set", key, value] => match key.strip_prefix("knowledge.") {
+                Some(key) => Self::SetKnowledge {
+                    key: key.to_string(),
+                    value: *value != "false",
+                },
+                None => Self::SetFlag {
+                    key: key.to_string(),
+                    value: parse_value(value),
+                },
+            },
+            _ => return None,
+        };
+        Some(effect)
+    }
+}
+
+/// Split script text into words, keeping `"quoted names"` together.
+fn split_words(text: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut rest = text.trim();
+    while !rest.is_empty() {
+        let (word, tail) = match rest.strip_prefix('"') {
+            Some(quoted) => quoted.split_once('"').unwrap_or((quoted, "")),
+            None => rest.split_once(char::is_whitespace).unwrap_or((rest, "")),
+        };
+        words.push(word.to_string());
+        rest = tail.trim_start();
+    }
+    words
+}
+
+/// Read a flag value: a boolean, a number, or else a string.
+fn parse_value(text: &str) -> MetadataValue {
+    match text {
+        "true" => MetadataValue::Boolean(true),
+        "false" => MetadataValue::Boolean(false),
+        _ => text
+            .parse()
+            .map(MetadataValue::Integer)
+            .unwrap_or_else(|_| MetadataValue::String(text.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_conditions() {
+        assert_eq!(
+            ConditionScript::parse("has_item \"Golden Key\""),
+            Some(ConditionScript::HasItem("Golden Key".to_string()))
+        );
+        assert_eq!(
+            ConditionScript::parse("flag \"mood\" 3"),
+            Some(ConditionScript::Flag {
+                key: "mood".to_string(),
+                value: Some(MetadataValue::Integer(3)),
+            })
+        );
+        assert_eq!(
+            ConditionScript::parse("not knows secret"),
+            Some(ConditionScript::Not(Box::new(ConditionScript::Knows(
+                "secret".to_string()
+            ))))
+        );
+        assert_eq!(ConditionScript::parse("has_item"), None);
+        assert_eq!(ConditionScript::parse("the moon is full"), None);
+    }
+
+    #[test]
+    fn parse_player_has() {
+        assert_eq!(
+            ConditionScript::parse("player has quest.join_order"),
+            Some(ConditionScript::PlayerHas("quest.join_order".to_string()))
+        );
+        assert_eq!(
+            ConditionScript::parse("player has knowledge.ashlands"),
+            Some(ConditionScript::Knows("ashlands".to_string()))
+        );
+        assert_eq!(ConditionScript::parse("player wants gold"), None);
+    }
+
+    #[test]
+    fn parse_effects() {
+        assert_eq!(
+            EffectScript::parse("gives \"Iron Torch\""),
+            Some(EffectScript::Give("Iron Torch".to_string()))
+        );
+        assert_eq!(
+            EffectScript::parse("set knowledge.ashlands true"),
+            Some(EffectScript::SetKnowledge {
+                key: "ashlands".to_string(),
+                value: true,
+            })
+        );
+        assert_eq!(
+            EffectScript::parse("set quest.armed true"),
+            Some(EffectScript::SetFlag {
+                key: "quest.armed".to_string(),
+                value: MetadataValue::Boolean(true),
+            })
+        );
+        assert_eq!(EffectScript::parse("summons a dragon"), None);
+    }
+}
@@ -0,0 +1,149 @@
+//! Worldbuilding suggestions: entities that are referenced a lot but
+//! described very little.
+//!
+//! An entity's priority grows with the number of other entities that point
+//! at it and shrinks as it gains description text and properties, so the
+//! faction everyone mentions but nobody wrote up floats to the top.
+
+use std::collections::HashSet;
+
+use crate::entity::{Entity, EntityId};
+use crate::world::World;
+
+/// Description length, in words, at which an entity counts as described.
+const FULL_DESCRIPTION_WORDS: usize = 40;
+/// Property count at which an entity counts as detailed.
+const FULL_PROPERTIES: usize = 4;
+
+/// One entity worth fleshing out, with the numbers behind its score.
+#[derive(Debug, Clone, Copy)]
+pub struct Suggestion<'w> {
+    /// The under-developed entity.
+    pub entity: &'w Entity,
+    /// Number of distinct entities with a relationship pointing at it.
+    pub references: usize,
+    /// Words in its description.
+    pub description_words: usize,
+    /// Number of properties it defines.
+    pub properties: usize,
+    /// Priority; higher means more worth working on.
+    pub score: f64,
+}
+
+/// How developed an entity is, from 0.0 (bare) to 1.0 (fully written up).
+///
+/// Description counts for 60%, properties for 40%, each capped once it
+/// reaches a reasonable size.
+pub fn development(description_words: usize, properties: usize) -> f64 {
+    let description =
+        description_words.min(FULL_DESCRIPTION_WORDS) as f64 / FULL_DESCRIPTION_WORDS as f64;
+    let properties = properties.min(FULL_PROPERTIES) as f64 / FULL_PROPERTIES as f64;
+    0.6 * description + 0.4 * properties
+}
+
+/// Priority of an entity: its reference count scaled by how undeveloped it is.
+pub fn score(references: usize, description_words: usize, properties: usize) -> f64 {
+    references as f64 * (1.0 - development(description_words, properties))
+}
+
+/// Score a single entity using the world's inbound relationship index.
+pub fn suggestion_for<'w>(world: &World, entity: &'w Entity) -> Suggestion<'w> {
+    let referrers: HashSet<EntityId> = world
+        .relationships_to(entity.id)
+        .into_iter()
+        .map(|rel| {
+            if rel.source == entity.id {
+                rel.target
+            } else {
+                rel.source
+            }
+        })
+        .filter(|id| *id != entity.id)
+        .collect();
+    let references = referrers.len();
+    let description_words = entity.description.split_whitespace().count();
+    let properties = entity.properties.len();
+    Suggestion {
+        entity,
+        references,
+        description_words,
+        properties,
+        score: score(references, description_words, properties),
+    }
+}
+
+/// All referenced, not fully developed entities, highest priority first.
+///
+/// Ties are broken by name so the output is stable.
+pub fn suggest(world: &World) -> Vec<Suggestion<'_>> {
+    let mut suggestions: Vec<Suggestion<'_>> = world
+        .all_entities()
+        .map(|entity| suggestion_for(world, entity))
+        .filter(|s| s.score > 0.0)
+        .collect();
+    suggestions.sort_by(|a, b| {
+        b.score
+            .total_cmp(&a.score)
+            .then_with(|| a.entity.name.cmp(&b.entity.name))
+    });
+    suggestions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::{EntityKind, MetadataValue};
+    use crate::relationship::{Relationship, RelationshipKind};
+    use crate::world::WorldMeta;
+
+    #[test]
+    fn development_caps_at_one() {
+        assert_eq!(development(0, 0), 0.0);
+        assert_eq!(development(100, 10), 1.0);
+        assert_eq!(score(3, 100, 10), 0.0);
+        assert_eq!(score(3, 0, 0), 3.0);
+    }
+
+    #[test]
+    fn referenced_thin_entities_rank_first() {
+        let mut world = World::new(WorldMeta::new("Test"));
+        let order = world
+            .add_entity(Entity::new(EntityKind::Faction, "the Order"))
+            .unwrap();
+        let mut keep = Entity::new(EntityKind::Location, "the Keep");
+        keep.description = "A keep.".to_string();
+        keep.properties.insert(
+            "climate".to_string(),
+            MetadataValue::String("cold".to_string()),
+        );
+        let keep = world.add_entity(keep).unwrap();
+        let mut members = Vec::new();
+        for name in ["Kael", "Mira", "Tom"] {
+            let mut e = Entity::new(EntityKind::Character, name);
+            e.description = "word ".repeat(FULL_DESCRIPTION_WORDS);
+            for key in ["a", "b", "c", "d"] {
+                e.properties
+                    .insert(key.to_string(), MetadataValue::Integer(1));
+            }
+            members.push(world.add_entity(e).unwrap());
+        }
+        for &member in &members {
+            world
+                .add_relationship(Relationship::new(member, RelationshipKind::MemberOf, order))
+                .unwrap();
+        }
+        world
+            .add_relationship(Relationship::new(
+                members[0],
+                RelationshipKind::LocatedAt,
+                keep,
+            ))
+            .unwrap();
+
+        let suggestions = suggest(&world);
+        let names: Vec<&str> = suggestions.iter().map(|s| s.entity.name.as_str()).collect();
+        assert_eq!(names, ["the Order", "the Keep"]);
+        assert_eq!(suggestions[0].references, 3);
+        assert_eq!(suggestions[0].score, 3.0);
+    }
+}
@@ -0,0 +1,169 @@
+//! Plain-text helpers shared by the CLI, TUI, and narration engines.
+
+/// Case-insensitive similarity of two names, from 0.0 (unrelated) to 1.0
+/// (identical), using Jaro-Winkler distance.
+///
+/// Tolerant of typos and favours shared prefixes, so `Kael Stormbon` scores
+/// close to `Kael Stormborn`. Used for fuzzy entity lookup.
+pub fn similarity(a: &str, b: &str) -> f64 {
+    strsim::jaro_winkler(&a.to_lowercase(), &b.to_lowercase())
+}
+
+/// Uppercase the first character of `s`, as at the start of a sentence.
+pub fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Word-wrap text to `width` columns, keeping Markdown structure intact.
+///
+/// Each input line is wrapped on its own, so paragraph breaks, hard line
+/// breaks, and trailing newlines survive. List items (`- `, `* `, `1. `)
+/// continue with a hanging indent under their text, and block quotes (`> `)
+/// repeat their marker. Headings, table rows, fenced code blocks, and lines
+/// indented by a tab or four spaces (preformatted text) are left untouched,
+/// and words longer than the width are never split. A width of 0 disables
+/// wrapping.
+pub fn wrap(text: &str, width: usize) -> String {
+    wrap_indented(text, width, "")
+}
+
+/// Like [`wrap`], but prefixes every non-blank output line with `indent`.
+///
+/// The indent counts toward the width, matching how the CLI indents its
+/// output by two spaces.
+pub fn wrap_indented(text: &str, width: usize, indent: &str) -> String {
+    let mut out = String::new();
+    let mut in_fence = false;
+
+    for line in text.lines() {
+        if !out.is_empty() {
+            out.push('\n');
+        }
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_fence = !in_fence;
+        }
+        let verbatim = in_fence
+            || trimmed.starts_with("```")
+            || trimmed.starts_with("~~~")
+            || trimmed.starts_with('#')
+            || trimmed.starts_with('|')
+            || line.starts_with('\t')
+            || line.starts_with("    ");
+        if trimmed.is_empty() {
+            continue;
+        }
+        if width == 0 || verbatim {
+            out.push_str(indent);
+            out.push_str(line);
+            continue;
+        }
+
+        let lead = &line[..line.len() - trimmed.len()];
+        let marker = list_marker(trimmed);
+        let first = format!("{indent}{lead}{marker}");
+        let hanging = if marker == "> " {
+            first.clone()
+        } else {
+            format!("{indent}{lead}{}", " ".repeat(marker.chars().count()))
+        };
+        wrap_words(&trimmed[marker.len()..], width, &first, &hanging, &mut out);
+    }
+
+    if text.ends_with('\n') {
+        out.push('\n');
+    }
+    out
+}
+
+/// The list or quote marker at the start of a line, including its trailing space.
+fn list_marker(line: &str) -> &str {
+    for marker in ["- ", "* ", "+ ", "> "] {
+        if line.starts_with(marker) {
+            return marker;
+        }
+    }
+    let digits = line.chars().take_while(|c| c.is_ascii_digit()).count();
+    if digits > 0 && line[digits..].starts_with(". ") {
+        return &line[..digits + 2];
+    }
+    ""
+}
+
+fn wrap_words(text: &str, width: usize, first: &str, hanging: &str, out: &mut String) {
+    out.push_str(first);
+    let mut col = first.chars().count();
+    let mut at_line_start = true;
+
+    for word in text.split_whitespace() {
+        let len = word.chars().count();
+        if !at_line_start && col + 1 + len > width {
+            out.push('\n');
+            out.push_str(hanging);
+            col = hanging.chars().count();
+            at_line_start = true;
+        }
+        if !at_line_start {
+            out.push(' ');
+            col += 1;
+        }
+        out.push_str(word);
+        col += len;
+        at_line_start = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn capitalizes_the_first_character() {
+        assert_eq!(capitalize("the Order rises"), "The Order rises");
+        assert_eq!(capitalize("ärger"), "Ärger");
+        assert_eq!(capitalize(""), "");
+    }
+
+    #[test]
+    fn wraps_at_word_boundaries() {
+        let text = "An ancient fortress carved from iron ore by the first smiths.";
+        assert_eq!(
+            wrap(text, 24),
+            "An ancient fortress\ncarved from iron ore by\nthe first smiths."
+        );
+        assert_eq!(wrap(text, 0), text);
+        assert_eq!(
+            wrap("tiny supercalifragilistic", 5),
+            "tiny\nsupercalifragilistic"
+        );
+    }
+
+    #[test]
+    fn keeps_markdown_structure() {
+        let text = "# A heading that is far too long to fit\n\n- first item wraps here\n12. numbered item wraps\n> quoted text wraps too\n```\ncode stays exactly as it is\n```";
+        assert_eq!(
+            wrap(text, 16),
+            "# A heading that is far too long to fit\n\n- first item\n  wraps here\n12. numbered\n    item wraps\n> quoted text\n> wraps too\n```\ncode stays exactly as it is\n```"
+        );
+    }
+
+    #[test]
+    fn keeps_trailing_newlines_and_preformatted_lines() {
+        assert_eq!(wrap("one two three\n", 9), "one two\nthree\n");
+        assert_eq!(wrap("one two\n\n", 9), "one two\n\n");
+        let text = "Stats:\n    Strength  12   Speed  9\n\tHP 10 of 10 and counting\n";
+        assert_eq!(wrap(text, 10), text);
+    }
+
+    #[test]
+    fn indents_every_line() {
+        assert_eq!(
+            wrap_indented("one two three\n\nfour", 9, "  "),
+            "  one two\n  three\n\n  four"
+        );
+    }
+}
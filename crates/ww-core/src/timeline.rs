@@ -1,14 +1,22 @@
+use std::collections::{HashMap, HashSet};
+
 use crate::component::WorldDate;
 use crate::entity::{Entity, EntityId, EntityKind};
+use crate::relationship::RelationshipKind;
 use crate::world::World;
 
 /// A timeline entry: an event entity with its resolved date.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub struct TimelineEntry<'w> {
     /// The event entity this entry represents.
     pub entity: &'w Entity,
     /// The resolved in-world date of the event.
     pub date: &'w WorldDate,
+    /// The event's year on the world's absolute scale, after applying the
+    /// start offset of its era (see [`WorldMeta::era_start`]).
+    ///
+    /// [`WorldMeta::era_start`]: crate::world::WorldMeta::era_start
+    pub year: i64,
 }
 
 /// Query and iterate events in chronological order.
@@ -18,6 +26,9 @@ pub struct Timeline<'w> {
 
 impl<'w> Timeline<'w> {
     /// Build a timeline from all event entities in the world that have a date.
+    ///
+    /// Events are ordered by absolute year, so dates in declared eras sort
+    /// correctly even when each era counts its years from zero.
     pub fn from_world(world: &'w World) -> Self {
         let mut entries: Vec<TimelineEntry<'w>> = world
             .all_entities()
@@ -27,11 +38,15 @@ impl<'w> Timeline<'w> {
                     .event
                     .as_ref()
                     .and_then(|ec| ec.date.as_ref())
-                    .map(|date| TimelineEntry { entity: e, date })
+                    .map(|date| TimelineEntry {
+                        entity: e,
+                        date,
+                        year: world.meta.absolute_year(date.era.as_deref(), date.year),
+                    })
             })
             .collect();
 
-        entries.sort_by_key(|entry| entry.date.sort_key());
+        entries.sort_by_key(|entry| (entry.year, entry.date.sort_key()));
         Self { entries }
     }
 
@@ -40,19 +55,19 @@ impl<'w> Timeline<'w> {
         &self.entries
     }
 
-    /// Filter entries to a year range (inclusive).
+    /// Filter entries to an absolute year range (inclusive).
     pub fn range(self, from: Option<i64>, to: Option<i64>) -> Self {
         let entries = self
             .entries
             .into_iter()
             .filter(|entry| {
                 if let Some(from_year) = from
-                    && entry.date.year < from_year
+                    && entry.year < from_year
                 {
                     return false;
                 }
                 if let Some(to_year) = to
-                    && entry.date.year > to_year
+                    && entry.year > to_year
                 {
                     return false;
                 }
@@ -78,6 +93,79 @@ impl<'w> Timeline<'w> {
         Self { entries }
     }
 
+    /// Events an entity took part in or caused, in chronological order.
+    ///
+    /// Only `involving` ([`RelationshipKind::ParticipatedIn`]) and
+    /// `caused by` ([`RelationshipKind::CausedBy`]) links count, in either
+    /// direction; being merely located at or referenced by an event does not.
+    pub fn events_involving(&self, world: &World, entity_id: EntityId) -> Vec<TimelineEntry<'w>> {
+        let linked: HashSet<EntityId> = world
+            .neighbors(entity_id)
+            .into_iter()
+            .filter(|(_, rel)| {
+                matches!(
+                    rel.kind,
+                    RelationshipKind::ParticipatedIn | RelationshipKind::CausedBy
+                )
+            })
+            .map(|(id, _)| id)
+            .collect();
+        self.entries
+            .iter()
+            .filter(|entry| linked.contains(&entry.entity.id))
+            .copied()
+            .collect()
+    }
+
+    /// Year in which each character first appears on the timeline.
+    ///
+    /// A character appears in an event through an `involving` relationship
+    /// ([`RelationshipKind::ParticipatedIn`]). Results are ordered by debut,
+    /// chronologically; characters never involved in a dated event are omitted.
+    pub fn character_introductions(&self, world: &World) -> Vec<(EntityId, i64)> {
+        self.debuts(world)
+            .into_iter()
+            .map(|(character, index)| (character, self.entries[index].year))
+            .collect()
+    }
+
+    /// Characters debuting at each event, keyed by event entity ID.
+    ///
+    /// Convenience for views that interleave "X debuts" markers with events.
+    pub fn introductions_by_event(&self, world: &World) -> HashMap<EntityId, Vec<EntityId>> {
+        let mut by_event: HashMap<EntityId, Vec<EntityId>> = HashMap::new();
+        for (character, index) in self.debuts(world) {
+            by_event
+                .entry(self.entries[index].entity.id)
+                .or_default()
+                .push(character);
+        }
+        by_event
+    }
+
+    /// Each character paired with the index of the first entry involving it.
+    fn debuts(&self, world: &World) -> Vec<(EntityId, usize)> {
+        let mut seen = HashSet::new();
+        let mut debuts = Vec::new();
+        for (index, entry) in self.entries.iter().enumerate() {
+            let mut cast: Vec<(EntityId, &str)> = world
+                .neighbors(entry.entity.id)
+                .into_iter()
+                .filter(|(_, rel)| rel.kind == RelationshipKind::ParticipatedIn)
+                .filter_map(|(id, _)| world.get_entity(id))
+                .filter(|e| e.kind == EntityKind::Character)
+                .map(|e| (e.id, e.name.as_str()))
+                .collect();
+            cast.sort_by(|a, b| a.1.cmp(b.1));
+            for (id, _) in cast {
+                if seen.insert(id) {
+                    debuts.push((id, index));
+                }
+            }
+        }
+        debuts
+    }
+
     /// Number of entries.
     pub fn len(&self) -> usize {
         self.entries.len()
@@ -93,6 +181,7 @@ impl<'w> Timeline<'w> {
 mod tests {
     use super::*;
     use crate::component::{EventComponent, WorldDate};
+    use crate::entity::MetadataValue;
     use crate::relationship::{Relationship, RelationshipKind};
     use crate::world::WorldMeta;
 
@@ -179,6 +268,55 @@ mod tests {
         assert_eq!(tl.entries()[0].entity.name, "The Founding of the Order");
     }
 
+    #[test]
+    fn timeline_orders_across_eras() {
+        let mut world = world_with_events();
+        world
+            .meta
+            .properties
+            .insert("era.Age of Ruin".to_string(), MetadataValue::Integer(-2000));
+        world
+            .meta
+            .properties
+            .insert("era.Age of Crowns".to_string(), MetadataValue::Integer(100));
+
+        for (name, year, era) in [
+            ("The Coronation", 5, "Age of Crowns"),
+            ("The Fall of Vyr", 900, "Age of Ruin"),
+        ] {
+            let mut event = Entity::new(EntityKind::Event, name);
+            event.components.event = Some(EventComponent {
+                date: Some(WorldDate {
+                    era: Some(era.to_string()),
+                    ..WorldDate::new(year)
+                }),
+                ..Default::default()
+            });
+            world.add_entity(event).unwrap();
+        }
+
+        let tl = Timeline::from_world(&world);
+        let names: Vec<(&str, i64)> = tl
+            .entries()
+            .iter()
+            .map(|e| (e.entity.name.as_str(), e.year))
+            .collect();
+        assert_eq!(
+            names,
+            [
+                ("The Great Sundering", -1247),
+                ("The Fall of Vyr", -1100),
+                ("The Founding of the Order", -500),
+                ("The Battle of Ashfields", 12),
+                ("The Coronation", 105),
+            ]
+        );
+
+        let tl = Timeline::from_world(&world).range(Some(-1200), Some(0));
+        assert_eq!(tl.len(), 2);
+        assert_eq!(tl.entries()[0].entity.name, "The Fall of Vyr");
+    }
+
     #[test]
     fn timeline_involving_entity() {
         let mut world = world_with_events();
@@ -199,6 +337,87 @@ mod tests {
         assert_eq!(tl.entries()[0].entity.name, "The Battle of Ashfields");
     }
 
+    #[test]
+    fn events_involving_follows_participation_and_cause() {
+        let mut world = world_with_events();
+        let kael = world.find_id_by_name("Kael").unwrap();
+        let sundering = world.find_id_by_name("The Great Sundering").unwrap();
+        let founding = world.find_id_by_name("The Founding of the Order").unwrap();
+        let battle = world.find_id_by_name("The Battle of Ashfields").unwrap();
+
+        world
+            .add_relationship(Relationship::new(
+                kael,
+                RelationshipKind::ParticipatedIn,
+                battle,
+            ))
+            .unwrap();
+        world
+            .add_relationship(Relationship::new(
+                sundering,
+                RelationshipKind::CausedBy,
+                kael,
+            ))
+            .unwrap();
+        world
+            .add_relationship(Relationship::new(
+                founding,
+                RelationshipKind::References,
+                kael,
+            ))
+            .unwrap();
+
+        let timeline = Timeline::from_world(&world);
+        let names: Vec<&str> = timeline
+            .events_involving(&world, kael)
+            .iter()
+            .map(|e| e.entity.name.as_str())
+            .collect();
+        assert_eq!(names, ["The Great Sundering", "The Battle of Ashfields"]);
+    }
+
+    #[test]
+    fn character_introductions_use_earliest_event() {
+        let mut world = world_with_events();
+        world
+            .add_entity(Entity::new(EntityKind::Character, "Elara"))
+            .unwrap();
+        world
+            .add_entity(Entity::new(EntityKind::Faction, "The Order"))
+            .unwrap();
+
+        let kael = world.find_id_by_name("Kael").unwrap();
+        let elara = world.find_id_by_name("Elara").unwrap();
+        let order = world.find_id_by_name("The Order").unwrap();
+        let founding = world.find_id_by_name("The Founding of the Order").unwrap();
+        let battle = world.find_id_by_name("The Battle of Ashfields").unwrap();
+
+        for (event, target) in [
+            (battle, kael),
+            (battle, elara),
+            (founding, elara),
+            (founding, order),
+        ] {
+            world
+                .add_relationship(Relationship::new(
+                    event,
+                    RelationshipKind::ParticipatedIn,
+                    target,
+                ))
+                .unwrap();
+        }
+
+        let tl = Timeline::from_world(&world);
+        assert_eq!(
+            tl.character_introductions(&world),
+            vec![(elara, -500), (kael, 12)]
+        );
+
+        let by_event = tl.introductions_by_event(&world);
+        assert_eq!(by_event[&founding], vec![elara]);
+        assert_eq!(by_event[&battle], vec![kael]);
+    }
+
     #[test]
     fn timeline_empty_world() {
         let world = World::new(WorldMeta::new("Empty"));
@@ -0,0 +1,487 @@
+//! Model-level validation of a [`World`].
+//!
+//! [`World::validate`] is the single entry point frontends use to check a
+//! world beyond what compilation already enforces. Each check reports
+//! [`ValidationIssue`]s tied to the entity they concern, so a frontend can
+//! map them back to source spans.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt;
+
+use crate::entity::{Entity, EntityId, EntityKind, MetadataValue};
+use crate::grammar::Grammar;
+use crate::mention::near_misses;
+use crate::relationship::RelationshipKind;
+use crate::world::World;
+
+/// Which optional checks [`World::validate`] runs.
+///
+/// Reference integrity, relationship-kind mismatches, casing, and misspelled
+/// mentions are always checked; the noisier structural checks are opt-in.
+/// Orphans are reported by the compiler, see [`World::is_orphan`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ValidateOptions {
+    /// Report connected locations that cannot be reached from the start location.
+    pub reachability: bool,
+}
+
+impl ValidateOptions {
+    /// Options with every optional check enabled.
+    pub fn all() -> Self {
+        Self { reachability: true }
+    }
+}
+
+/// How serious a validation issue is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IssueSeverity {
+    /// The world is inconsistent.
+    Error,
+    /// The world is valid but probably not what the author intended.
+    Warning,
+}
+
+/// The check that produced a validation issue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IssueKind {
+    /// A reference names an entity or dialogue that does not exist.
+    DanglingReference,
+    /// A relationship points at an entity of the wrong kind.
+    KindMismatch,
+    /// A location cannot be reached through exits from the start location.
+    Unreachable,
+    /// Names that differ only by letter case.
+    Casing,
+    /// A description names something that closely resembles an entity.
+    Mention,
+}
+
+impl fmt::Display for IssueKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DanglingReference => write!(f, "dangling-reference"),
+            Self::KindMismatch => write!(f, "kind-mismatch"),
+            Self::Unreachable => write!(f, "unreachable"),
+            Self::Casing => write!(f, "casing"),
+            Self::Mention => write!(f, "mention"),
+        }
+    }
+}
+
+/// A single problem found by [`World::validate`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationIssue {
+    /// The check that found the issue.
+    pub kind: IssueKind,
+    /// How serious the issue is.
+    pub severity: IssueSeverity,
+    /// The entity the issue is reported against, if any.
+    pub entity: Option<EntityId>,
+    /// Human-readable description.
+    pub message: String,
+}
+
+impl ValidationIssue {
+    fn warning(kind: IssueKind, entity: Option<EntityId>, message: String) -> Self {
+        Self {
+            kind,
+            severity: IssueSeverity::Warning,
+            entity,
+            message,
+        }
+    }
+
+    fn error(kind: IssueKind, entity: Option<EntityId>, message: String) -> Self {
+        Self {
+            kind,
+            severity: IssueSeverity::Error,
+            entity,
+            message,
+        }
+    }
+
+    /// Returns `true` if the issue has error severity.
+    pub fn is_error(&self) -> bool {
+        self.severity == IssueSeverity::Error
+    }
+}
+
+impl World {
+    /// Run model-level checks and return every issue found.
+    ///
+    /// Issues are ordered by check, then by entity name, so output is stable.
+    pub fn validate(&self, options: ValidateOptions) -> Vec<ValidationIssue> {
+        let mut entities: Vec<&Entity> = self.all_entities().collect();
+        entities.sort_by_key(|e| e.name.to_lowercase());
+
+        let mut issues = Vec::new();
+        check_references(self, &entities, &mut issues);
+        check_kinds(self, &entities, &mut issues);
+        check_casing(&entities, &mut issues);
+        check_mentions(self, &entities, &mut issues);
+        if options.reachability {
+            check_reachability(self, &entities, &mut issues);
+        }
+        issues
+    }
+
+    /// Whether the entity has no relationships or exits at all and is not
+    /// marked `standalone`.
+    pub fn is_orphan(&self, id: EntityId) -> bool {
+        let standalone = self.get_entity(id).is_some_and(|e| {
+            matches!(
+                e.properties.get("standalone"),
+                Some(MetadataValue::Boolean(true))
+            )
+        });
+        !standalone && self.relationships_of(id).is_empty()
+    }
+}
+
+fn check_references(world: &World, entities: &[&Entity], issues: &mut Vec<ValidationIssue>) {
+    if let Some(MetadataValue::String(start)) = world.meta.properties.get("fiction.start") {
+        match world.find_by_name(start) {
+            None => issues.push(ValidationIssue::error(
+                IssueKind::DanglingReference,
+                None,
+                format!("fiction.start names unknown entity \"{start}\""),
+            )),
+            Some(e) if e.kind != EntityKind::Location => issues.push(ValidationIssue::error(
+                IssueKind::KindMismatch,
+                Some(e.id),
+                format!(
+                    "fiction.start names \"{}\", which is {}",
+                    e.name,
+                    Grammar::from_meta(&world.meta).with_article(&e.kind.to_string())
+                ),
+            )),
+            Some(_) => {}
+        }
+    }
+
+    for entity in entities {
+        if let Some(parent) = entity
+            .components
+            .location
+            .as_ref()
+            .and_then(|l| l.parent_location)
+            && world.get_entity(parent).is_none()
+        {
+            issues.push(ValidationIssue::error(
+                IssueKind::DanglingReference,
+                Some(entity.id),
+                format!(
+                    "\"{}\" has a parent location that does not exist",
+                    entity.name
+                ),
+            ));
+        }
+
+        let Some(fiction) = &entity.components.fiction else {
+            continue;
+        };
+        let ids: HashSet<&str> = fiction.dialogues.iter().map(|d| d.id.as_str()).collect();
+        for dialogue in &fiction.dialogues {
+            for goto in dialogue.choices.iter().filter_map(|c| c.goto.as_deref()) {
+                if !ids.contains(goto) {
+                    issues.push(ValidationIssue::error(
+                        IssueKind::DanglingReference,
+                        Some(entity.id),
+                        format!(
+                            "dialogue \"{}\" of \"{}\" goes to unknown dialogue \"{goto}\"",
+                            dialogue.id, entity.name
+                        ),
+                    ));
+                }
+            }
+        }
+    }
+}
+
+/// The built-in kinds a relationship's target may have, if constrained.
+fn expected_target_kinds(kind: &RelationshipKind) -> Option<&'static [EntityKind]> {
+    match kind {
+        RelationshipKind::LocatedAt | RelationshipKind::BasedAt | RelationshipKind::ConnectedTo => {
+            Some(&[EntityKind::Location])
+        }
+        RelationshipKind::ContainedIn => Some(&[EntityKind::Location, EntityKind::Item]),
+        RelationshipKind::MemberOf | RelationshipKind::LeaderOf => Some(&[EntityKind::Faction]),
+        RelationshipKind::ParticipatedIn => Some(&[EntityKind::Event]),
+        _ => None,
+    }
+}
+
+fn check_kinds(world: &World, entities: &[&Entity], issues: &mut Vec<ValidationIssue>) {
+    let grammar = Grammar::from_meta(&world.meta);
+    for entity in entities {
+        let mut rels = world.relationships_from(entity.id);
+        rels.retain(|r| r.source == entity.id);
+        rels.sort_by_key(|r| world.entity_name(r.target).to_lowercase());
+        for rel in rels {
+            let Some(expected) = expected_target_kinds(&rel.kind) else {
+                continue;
+            };
+            let Some(target) = world.get_entity(rel.target) else {
+                continue;
+            };
+            // Custom kinds are user-defined, so there is nothing to compare against.
+            if matches!(target.kind, EntityKind::Custom(_)) || expected.contains(&target.kind) {
+                continue;
+            }
+            let wanted: Vec<String> = expected.iter().map(|k| k.to_string()).collect();
+            issues.push(ValidationIssue::warning(
+                IssueKind::KindMismatch,
+                Some(entity.id),
+                format!(
+                    "\"{}\" {} \"{}\", which is {} (expected {})",
+                    entity.name,
+                    rel.kind,
+                    target.name,
+                    grammar.with_article(&target.kind.to_string()),
+                    wanted.join(" or ")
+                ),
+            ));
+        }
+    }
+}
+
+fn check_casing(entities: &[&Entity], issues: &mut Vec<ValidationIssue>) {
+    let mut kinds: HashMap<String, &str> = HashMap::new();
+    for entity in entities {
+        let EntityKind::Custom(kind) = &entity.kind else {
+            continue;
+        };
+        match kinds.get(&kind.to_lowercase()) {
+            Some(first) if *first != kind => issues.push(ValidationIssue::warning(
+                IssueKind::Casing,
+                Some(entity.id),
+                format!(
+                    "\"{}\" is a \"{kind}\", but other entities use \"{first}\"",
+                    entity.name
+                ),
+            )),
+            Some(_) => {}
+            None => {
+                kinds.insert(kind.to_lowercase(), kind);
+            }
+        }
+    }
+
+    for entity in entities {
+        let mut keys: Vec<&String> = entity.properties.keys().collect();
+        keys.sort();
+        let mut seen: HashMap<String, &str> = HashMap::new();
+        for key in keys {
+            if let Some(first) = seen.insert(key.to_lowercase(), key) {
+                issues.push(ValidationIssue::warning(
+                    IssueKind::Casing,
+                    Some(entity.id),
+                    format!(
+                        "\"{}\" has properties \"{first}\" and \"{key}\" that differ only by case",
+                        entity.name
+                    ),
+                ));
+            }
+        }
+    }
+}
+
+fn check_mentions(world: &World, entities: &[&Entity], issues: &mut Vec<ValidationIssue>) {
+    for entity in entities {
+        for miss in near_misses(world, &entity.description) {
+            issues.push(ValidationIssue::warning(
+                IssueKind::Mention,
+                Some(entity.id),
+                format!(
+                    "\"{}\" mentions \"{}\" in its description; did you mean \"{}\"?",
+                    entity.name,
+                    miss.text,
+                    world.entity_name(miss.entity)
+                ),
+            ));
+        }
+    }
+}
+
+fn check_reachability(world: &World, entities: &[&Entity], issues: &mut Vec<ValidationIssue>) {
+    let connected: Vec<&Entity> = entities
+        .iter()
+        .copied()
+        .filter(|e| e.kind == EntityKind::Location)
+        .filter(|e| {
+            world
+                .relationships_of(e.id)
+                .iter()
+                .any(|r| r.kind == RelationshipKind::ConnectedTo)
+        })
+        .collect();
+
+    let start = match world.meta.properties.get("fiction.start") {
+        Some(MetadataValue::String(name)) => world.find_by_name(name),
+        _ => None,
+    }
+    .or_else(|| connected.first().copied());
+    let Some(start) = start else {
+        return;
+    };
+
+    let mut reached = HashSet::from([start.id]);
+    let mut queue = VecDeque::from([start.id]);
+    while let Some(id) = queue.pop_front() {
+        for (next, rel) in world.neighbors(id) {
+            let forward = rel.source == id || rel.bidirectional;
+            if rel.kind == RelationshipKind::ConnectedTo && forward && reached.insert(next) {
+                queue.push_back(next);
+            }
+        }
+    }
+
+    for location in connected {
+        if !reached.contains(&location.id) {
+            issues.push(ValidationIssue::warning(
+                IssueKind::Unreachable,
+                Some(location.id),
+                format!(
+                    "\"{}\" cannot be reached from \"{}\"",
+                    location.name, start.name
+                ),
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::relationship::Relationship;
+    use crate::world::WorldMeta;
+
+    fn world() -> (World, [EntityId; 4]) {
+        let mut world = World::new(WorldMeta::new("Test"));
+        let hall = world
+            .add_entity(Entity::new(EntityKind::Location, "the Hall"))
+            .unwrap();
+        let tower = world
+            .add_entity(Entity::new(EntityKind::Location, "the Tower"))
+            .unwrap();
+        let cellar = world
+            .add_entity(Entity::new(EntityKind::Location, "the Cellar"))
+            .unwrap();
+        let crypt = world
+            .add_entity(Entity::new(EntityKind::Location, "the Crypt"))
+            .unwrap();
+        let kael = world
+            .add_entity(Entity::new(EntityKind::Character, "Kael"))
+            .unwrap();
+        world
+            .add_relationship(
+                Relationship::new(hall, RelationshipKind::ConnectedTo, tower).with_label("north"),
+            )
+            .unwrap();
+        // The cellar and crypt connect only to each other.
+        world
+            .add_relationship(
+                Relationship::new(cellar, RelationshipKind::ConnectedTo, crypt).with_label("down"),
+            )
+            .unwrap();
+        (world, [hall, cellar, crypt, kael])
+    }
+
+    #[test]
+    fn clean_world_has_no_default_issues() {
+        let (world, _) = world();
+        assert!(world.validate(ValidateOptions::default()).is_empty());
+    }
+
+    #[test]
+    fn reports_misspelled_mentions() {
+        let (mut world, [_, _, _, kael]) = world();
+        world.get_entity_mut(kael).unwrap().description =
+            "Kael keeps watch from the Tower above the Celler.".to_string();
+
+        let issues = world.validate(ValidateOptions::default());
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].kind, IssueKind::Mention);
+        assert_eq!(issues[0].entity, Some(kael));
+        assert_eq!(
+            issues[0].message,
+            "\"Kael\" mentions \"Celler\" in its description; did you mean \"the Cellar\"?"
+        );
+    }
+
+    #[test]
+    fn reports_kind_mismatches_and_casing() {
+        let (mut world, [hall, _, _, kael]) = world();
+        world
+            .add_relationship(Relationship::new(hall, RelationshipKind::LocatedAt, kael))
+            .unwrap();
+        let mut ship = Entity::new(EntityKind::Custom("Vessel".to_string()), "the Gull");
+        ship.properties
+            .insert("Speed".to_string(), MetadataValue::Integer(3));
+        ship.properties
+            .insert("speed".to_string(), MetadataValue::Integer(4));
+        world.add_entity(ship).unwrap();
+        world
+            .add_entity(Entity::new(
+                EntityKind::Custom("vessel".to_string()),
+                "the Wren",
+            ))
+            .unwrap();
+
+        let issues = world.validate(ValidateOptions::default());
+        let kinds: Vec<IssueKind> = issues.iter().map(|i| i.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                IssueKind::KindMismatch,
+                IssueKind::Casing,
+                IssueKind::Casing
+            ]
+        );
+        assert_eq!(issues[0].entity, Some(hall));
+        assert!(issues[0].message.contains("expected location"));
+        assert!(issues.iter().all(|i| !i.is_error()));
+    }
+
+    #[test]
+    fn optional_checks_and_dangling_start() {
+        let (mut world, [_, cellar, crypt, _]) = world();
+        world.meta.properties.insert(
+            "fiction.start".to_string(),
+            MetadataValue::String("the Hall".to_string()),
+        );
+
+        let issues = world.validate(ValidateOptions::all());
+        let found: Vec<(IssueKind, Option<EntityId>)> =
+            issues.iter().map(|i| (i.kind, i.entity)).collect();
+        assert_eq!(
+            found,
+            vec![
+                (IssueKind::Unreachable, Some(cellar)),
+                (IssueKind::Unreachable, Some(crypt)),
+            ]
+        );
+
+        world.meta.properties.insert(
+            "fiction.start".to_string(),
+            MetadataValue::String("Nowhere".to_string()),
+        );
+        let issues = world.validate(ValidateOptions::default());
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].is_error());
+        assert_eq!(issues[0].kind, IssueKind::DanglingReference);
+    }
+
+    #[test]
+    fn standalone_entities_are_not_orphans() {
+        let (mut world, [hall, _, _, kael]) = world();
+        assert!(world.is_orphan(kael));
+        assert!(!world.is_orphan(hall));
+        world
+            .get_entity_mut(kael)
+            .unwrap()
+            .properties
+            .insert("standalone".to_string(), MetadataValue::Boolean(true));
+        assert!(!world.is_orphan(kael));
+    }
+}
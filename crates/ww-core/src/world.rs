@@ -5,8 +5,15 @@ use serde::{Deserialize, Serialize};
 
 use crate::entity::{Entity, EntityId, EntityKind, MetadataValue};
 use crate::error::{WwError, WwResult};
+use crate::mention::{Mention, find_mentions};
 use crate::query::QueryBuilder;
-use crate::relationship::{Relationship, RelationshipId};
+use crate::relationship::{Relationship, RelationshipId, RelationshipKind};
+
+/// Schema version written by [`World::to_json`].
+///
+/// Bump this when the serialized form changes, and teach [`migrate`] how to
+/// upgrade documents from the previous version.
+pub const SCHEMA_VERSION: u32 = 1;
 
 /// Metadata about the world itself.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -41,12 +48,50 @@ impl WorldMeta {
             genre: None,
             setting: None,
             authors: Vec::new(),
-            schema_version: 1,
+            schema_version: SCHEMA_VERSION,
             properties: HashMap::new(),
             created_at: now,
             updated_at: now,
         }
     }
+
+    /// The RNG seed a session should use, given an explicit (e.g. CLI) seed.
+    ///
+    /// Falls back to the world's `rng.seed` when no explicit seed is given.
+    /// With `rng.lock true`, the world's seed always wins so that everyone
+    /// running the world gets the same rolls.
+    pub fn rng_seed(&self, explicit: Option<u64>) -> Option<u64> {
+        let world_seed = match self.properties.get("rng.seed") {
+            Some(MetadataValue::Integer(n)) if *n >= 0 => Some(*n as u64),
+            _ => None,
+        };
+        let locked = matches!(
+            self.properties.get("rng.lock"),
+            Some(MetadataValue::Boolean(true))
+        );
+        if locked && world_seed.is_some() {
+            world_seed
+        } else {
+            explicit.or(world_seed)
+        }
+    }
+
+    /// Absolute year at which a named era starts, from `era.<name>`.
+    ///
+    /// Declared in the DSL as `era "Age of Ruin" starts year -2000`.
+    pub fn era_start(&self, era: &str) -> Option<i64> {
+        match self.properties.get(&format!("era.{era}")) {
+            Some(MetadataValue::Integer(n)) => Some(*n),
+            _ => None,
+        }
+    }
+
+    /// Convert a year counted within `era` to an absolute year.
+    ///
+    /// Years without an era, or in an undeclared era, are already absolute.
+    pub fn absolute_year(&self, era: Option<&str>, year: i64) -> i64 {
+        era.and_then(|e| self.era_start(e)).unwrap_or(0) + year
+    }
 }
 
 /// The central world model. Owns all entities and relationships.
@@ -60,8 +105,10 @@ pub struct World {
     // Indexes
     by_kind: HashMap<EntityKind, Vec<EntityId>>,
     by_name_lower: HashMap<String, EntityId>,
+    by_alias_lower: HashMap<String, EntityId>,
     edges_from: HashMap<EntityId, Vec<RelationshipId>>,
     edges_to: HashMap<EntityId, Vec<RelationshipId>>,
+    mentions: HashMap<EntityId, Vec<Mention>>,
 }
 
 impl World {
@@ -73,8 +120,10 @@ impl World {
             relationships: HashMap::new(),
             by_kind: HashMap::new(),
             by_name_lower: HashMap::new(),
+            by_alias_lower: HashMap::new(),
             edges_from: HashMap::new(),
             edges_to: HashMap::new(),
+            mentions: HashMap::new(),
         }
     }
 
@@ -83,11 +132,28 @@ impl World {
     // -----------------------------------------------------------------------
 
     /// Add an entity to the world. Returns the entity's ID.
+    ///
+    /// Fails if the entity's name or one of its aliases is already taken by
+    /// another entity's name or alias.
     pub fn add_entity(&mut self, entity: Entity) -> WwResult<EntityId> {
         let name_lower = entity.name.to_lowercase();
-        if self.by_name_lower.contains_key(&name_lower) {
+        if self.name_taken(&name_lower) {
             return Err(WwError::DuplicateName(entity.name.clone()));
         }
+        let aliases: Vec<String> = entity
+            .aliases
+            .iter()
+            .map(|a| a.to_lowercase())
+            .filter(|a| *a != name_lower)
+            .collect();
+        if let Some(taken) = entity
+            .aliases
+            .iter()
+            .zip(&aliases)
+            .find(|(_, lower)| self.name_taken(lower))
+        {
+            return Err(WwError::DuplicateName(taken.0.clone()));
+        }
 
         let id = entity.id;
         self.by_kind
@@ -95,6 +161,9 @@ impl World {
             .or_default()
             .push(id);
         self.by_name_lower.insert(name_lower, id);
+        for alias in aliases {
+            self.by_alias_lower.insert(alias, id);
+        }
         self.entities.insert(id, entity);
         Ok(id)
     }
@@ -117,16 +186,26 @@ impl World {
         self.entities.get_mut(&id)
     }
 
-    /// Find an entity by name (case-insensitive).
+    /// Find an entity by name or alias (case-insensitive).
     pub fn find_by_name(&self, name: &str) -> Option<&Entity> {
-        self.by_name_lower
-            .get(&name.to_lowercase())
-            .and_then(|id| self.entities.get(id))
+        self.find_id_by_name(name)
+            .and_then(|id| self.entities.get(&id))
     }
 
-    /// Find an entity ID by name (case-insensitive).
+    /// Find an entity ID by name or alias (case-insensitive).
+    ///
+    /// Names take precedence over aliases.
     pub fn find_id_by_name(&self, name: &str) -> Option<EntityId> {
-        self.by_name_lower.get(&name.to_lowercase()).copied()
+        let lower = name.to_lowercase();
+        self.by_name_lower
+            .get(&lower)
+            .or_else(|| self.by_alias_lower.get(&lower))
+            .copied()
+    }
+
+    /// Whether a lowercased name is already used as a name or alias.
+    fn name_taken(&self, lower: &str) -> bool {
+        self.by_name_lower.contains_key(lower) || self.by_alias_lower.contains_key(lower)
     }
 
     /// Remove an entity and all its relationships.
@@ -139,9 +218,11 @@ impl World {
         // Remove from indexes
         let name_lower = entity.name.to_lowercase();
         self.by_name_lower.remove(&name_lower);
+        self.by_alias_lower.retain(|_, eid| *eid != id);
         if let Some(ids) = self.by_kind.get_mut(&entity.kind) {
             ids.retain(|eid| *eid != id);
         }
+        self.mentions.remove(&id);
 
         // Remove all relationships involving this entity
         let rel_ids: Vec<RelationshipId> = self
@@ -184,6 +265,49 @@ impl World {
         Ok(id)
     }
 
+    /// Materialize the inverse of every one-way relationship, so it can be
+    /// found from either end with [`relationships_from`](Self::relationships_from).
+    ///
+    /// Inverses use [`RelationshipKind::inverse`] and copy the label,
+    /// metadata, and attributes. Bidirectional and self-referential
+    /// relationships are skipped, as are custom kinds without a known inverse
+    /// and inverses that are already present, so running this twice adds
+    /// nothing. Returns the number of relationships added.
+    pub fn add_inverse_relationships(&mut self) -> usize {
+        let mut present: std::collections::HashSet<(EntityId, RelationshipKind, EntityId)> = self
+            .relationships
+            .values()
+            .map(|r| (r.source, r.kind.clone(), r.target))
+            .collect();
+
+        let mut inverses = Vec::new();
+        for rel in self.relationships.values() {
+            if rel.bidirectional || rel.source == rel.target {
+                continue;
+            }
+            let Some(kind) = rel.kind.inverse() else {
+                continue;
+            };
+            if !present.insert((rel.target, kind.clone(), rel.source)) {
+                continue;
+            }
+            let mut inverse = Relationship::new(rel.target, kind, rel.source);
+            inverse.label = rel.label.clone();
+            inverse.metadata = rel.metadata.clone();
+            inverse.since = rel.since;
+            inverse.until = rel.until;
+            inverse.strength = rel.strength;
+            inverses.push(inverse);
+        }
+
+        let added = inverses.len();
+        for inverse in inverses {
+            // Both endpoints come from an existing relationship
+            let _ = self.add_relationship(inverse);
+        }
+        added
+    }
+
     /// Remove a relationship by ID.
     pub fn remove_relationship(&mut self, id: RelationshipId) -> WwResult<Relationship> {
         let rel = self
@@ -317,6 +441,132 @@ impl World {
             .collect()
     }
 
+    /// Find directed reference cycles (A → B → … → A) over all relationships.
+    ///
+    /// Bidirectional relationships are symmetric by nature and are ignored.
+    /// Each elementary cycle is listed once, starting at the entity whose name
+    /// sorts first; the cycle closes back on that entity implicitly.
+    pub fn reference_cycles(&self) -> Vec<Vec<EntityId>> {
+        let mut order: Vec<EntityId> = self.entities.keys().copied().collect();
+        order.sort_by_key(|id| (self.entities[id].name.to_lowercase(), id.0));
+        let index: HashMap<EntityId, usize> =
+            order.iter().enumerate().map(|(i, id)| (*id, i)).collect();
+
+        let mut adjacency: Vec<Vec<usize>> = vec![Vec::new(); order.len()];
+        for rel in self.relationships.values().filter(|r| !r.bidirectional) {
+            if let (Some(&a), Some(&b)) = (index.get(&rel.source), index.get(&rel.target))
+                && !adjacency[a].contains(&b)
+            {
+                adjacency[a].push(b);
+            }
+        }
+        for targets in &mut adjacency {
+            targets.sort_unstable();
+        }
+
+        CycleFinder::new(&adjacency)
+            .find_all()
+            .into_iter()
+            .map(|cycle| cycle.into_iter().map(|i| order[i]).collect())
+            .collect()
+    }
+
+    /// Find the shortest chain of relationships from `from` to `to`.
+    ///
+    /// Returns the entities along the path (both ends included) and the
+    /// relationship traversed on each hop, or `None` if `to` is unreachable.
+    /// Relationships are followed in either direction unless `directed` is
+    /// set, in which case only bidirectional ones may be walked backwards.
+    /// Among equally short paths, neighbors are explored in name order so
+    /// the result is stable.
+    pub fn shortest_path(
+        &self,
+        from: EntityId,
+        to: EntityId,
+        directed: bool,
+    ) -> Option<(Vec<EntityId>, Vec<&Relationship>)> {
+        if !self.entities.contains_key(&from) || !self.entities.contains_key(&to) {
+            return None;
+        }
+
+        let mut parent: HashMap<EntityId, (EntityId, &Relationship)> = HashMap::new();
+        let mut queue = std::collections::VecDeque::from([from]);
+        let mut visited = std::collections::HashSet::from([from]);
+
+        while let Some(current) = queue.pop_front() {
+            if current == to {
+                break;
+            }
+            let mut next: Vec<(EntityId, &Relationship)> = self
+                .neighbors(current)
+                .into_iter()
+                .filter(|(_, rel)| !directed || rel.source == current || rel.bidirectional)
+                .collect();
+            next.sort_by_key(|(id, rel)| {
+                (
+                    self.entity_name(*id).to_lowercase(),
+                    id.0,
+                    rel.kind.to_string(),
+                    rel.id.0,
+                )
+            });
+            for (other, rel) in next {
+                if visited.insert(other) {
+                    parent.insert(other, (current, rel));
+                    queue.push_back(other);
+                }
+            }
+        }
+
+        if !visited.contains(&to) {
+            return None;
+        }
+
+        let mut entities = vec![to];
+        let mut hops = Vec::new();
+        let mut current = to;
+        while let Some(&(prev, rel)) = parent.get(&current) {
+            entities.push(prev);
+            hops.push(rel);
+            current = prev;
+        }
+        entities.reverse();
+        hops.reverse();
+        Some((entities, hops))
+    }
+
+    /// Collect the entities within `hops` relationships of `center`,
+    /// following relationships in either direction. The center itself is
+    /// included; an unknown center yields an empty set.
+    pub fn neighborhood(
+        &self,
+        center: EntityId,
+        hops: usize,
+    ) -> std::collections::HashSet<EntityId> {
+        let mut visited = std::collections::HashSet::new();
+        if !self.entities.contains_key(&center) {
+            return visited;
+        }
+
+        visited.insert(center);
+        let mut frontier = vec![center];
+        for _ in 0..hops {
+            let mut next = Vec::new();
+            for current in frontier {
+                for (other, _) in self.neighbors(current) {
+                    if visited.insert(other) {
+                        next.push(other);
+                    }
+                }
+            }
+            if next.is_empty() {
+                break;
+            }
+            frontier = next;
+        }
+        visited
+    }
+
     // -----------------------------------------------------------------------
     // Statistics
     // -----------------------------------------------------------------------
@@ -338,6 +588,259 @@ impl World {
             .map(|(k, ids)| (k.clone(), ids.len()))
             .collect()
     }
+
+    // -----------------------------------------------------------------------
+    // Description mentions
+    // -----------------------------------------------------------------------
+
+    /// Record which entities each description mentions by name.
+    ///
+    /// The compiler calls this once the world is built; call it again after
+    /// editing descriptions. Entities mentioning themselves are skipped.
+    pub fn index_mentions(&mut self) {
+        let index = self
+            .entities
+            .values()
+            .filter_map(|e| {
+                let mut found = find_mentions(self, &e.description);
+                found.retain(|m| m.entity != e.id);
+                (!found.is_empty()).then_some((e.id, found))
+            })
+            .collect();
+        self.mentions = index;
+    }
+
+    /// Entity mentions in an entity's description, in text order, as of
+    /// the last [`World::index_mentions`].
+    pub fn mentions(&self, entity: EntityId) -> &[Mention] {
+        self.mentions.get(&entity).map_or(&[], Vec::as_slice)
+    }
+
+    // -----------------------------------------------------------------------
+    // JSON serialization
+    // -----------------------------------------------------------------------
+
+    /// The world as a versioned JSON value: `schema_version`, the `world`
+    /// metadata, and all `entities` and `relationships`.
+    ///
+    /// Entities are ordered by name and relationships by ID, so the same
+    /// world always serializes the same way.
+    pub fn to_json_value(&self) -> serde_json::Value {
+        let mut entities: Vec<&Entity> = self.entities.values().collect();
+        entities.sort_by_key(|e| (e.name.to_lowercase(), e.id.0));
+        let mut relationships: Vec<&Relationship> = self.relationships.values().collect();
+        relationships.sort_by_key(|r| r.id.0);
+
+        let mut meta = self.meta.clone();
+        meta.schema_version = SCHEMA_VERSION;
+        serde_json::json!({
+            "schema_version": SCHEMA_VERSION,
+            "world": meta,
+            "entities": entities,
+            "relationships": relationships,
+        })
+    }
+
+    /// Serialize the world to pretty-printed, versioned JSON.
+    ///
+    /// [`World::from_json`] reads it back into an identical set of entities
+    /// and relationships.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(&self.to_json_value()).unwrap_or_default()
+    }
+
+    /// Load a world from JSON written by [`World::to_json`].
+    ///
+    /// Documents from older schema versions are migrated first; documents
+    /// from newer versions are rejected.
+    pub fn from_json(s: &str) -> WwResult<World> {
+        let value: serde_json::Value =
+            serde_json::from_str(s).map_err(|e| WwError::InvalidJson(e.to_string()))?;
+        let version = match value.get("schema_version") {
+            None => 0,
+            Some(v) => v
+                .as_u64()
+                .and_then(|v| u32::try_from(v).ok())
+                .ok_or_else(|| WwError::InvalidJson("schema_version must be a number".into()))?,
+        };
+        if version > SCHEMA_VERSION {
+            return Err(WwError::InvalidJson(format!(
+                "schema version {version} is newer than supported version {SCHEMA_VERSION}"
+            )));
+        }
+        let value = migrate(value, version)?;
+
+        let snapshot: Snapshot =
+            serde_json::from_value(value).map_err(|e| WwError::InvalidJson(e.to_string()))?;
+        let mut meta = snapshot.world;
+        meta.schema_version = SCHEMA_VERSION;
+        let mut world = World::new(meta);
+        for entity in snapshot.entities {
+            world.add_entity(entity)?;
+        }
+        for rel in snapshot.relationships {
+            world.add_relationship(rel)?;
+        }
+        world.index_mentions();
+        Ok(world)
+    }
+}
+
+/// The serialized form of a world at the current [`SCHEMA_VERSION`].
+#[derive(Deserialize)]
+struct Snapshot {
+    world: WorldMeta,
+    #[serde(default)]
+    entities: Vec<Entity>,
+    #[serde(default)]
+    relationships: Vec<Relationship>,
+}
+
+/// Upgrade a serialized world from schema version `from` to
+/// [`SCHEMA_VERSION`], one version at a time.
+fn migrate(mut value: serde_json::Value, from: u32) -> WwResult<serde_json::Value> {
+    for version in from..SCHEMA_VERSION {
+        match version {
+            // Version 0: unversioned `ww export json` output, whose `world`
+            // object only carries name, description, genre, and setting.
+            0 => {
+                let world = value
+                    .get_mut("world")
+                    .and_then(serde_json::Value::as_object_mut)
+                    .ok_or_else(|| WwError::InvalidJson("missing \"world\" object".into()))?;
+                let now = serde_json::to_value(Utc::now()).unwrap_or_default();
+                let defaults = [
+                    ("description", serde_json::json!("")),
+                    ("genre", serde_json::Value::Null),
+                    ("setting", serde_json::Value::Null),
+                    ("authors", serde_json::json!([])),
+                    ("properties", serde_json::json!({})),
+                    ("created_at", now.clone()),
+                    ("updated_at", now),
+                ];
+                for (key, default) in defaults {
+                    world.entry(key).or_insert(default);
+                }
+                world.insert("schema_version".into(), serde_json::json!(1));
+            }
+            _ => unreachable!("no migration from schema version {version}"),
+        }
+    }
+    Ok(value)
+}
+
+/// Johnson's algorithm for the elementary cycles of a directed graph.
+///
+/// Cycles are found from their lowest-ordered node, searching only the
+/// strongly connected part of the graph above it, and nodes that cannot lead
+/// back stay blocked until a cycle through them is found. The time spent is
+/// bounded by the number of cycles times the size of the graph, instead of
+/// growing with every path as a plain depth-first search does.
+struct CycleFinder<'a> {
+    adjacency: &'a [Vec<usize>],
+    /// Whether a node is in the component the current search is confined to.
+    in_component: Vec<bool>,
+    blocked: Vec<bool>,
+    /// Nodes to unblock once a node gets unblocked.
+    blocked_by: Vec<Vec<usize>>,
+    path: Vec<usize>,
+    cycles: Vec<Vec<usize>>,
+}
+
+impl<'a> CycleFinder<'a> {
+    fn new(adjacency: &'a [Vec<usize>]) -> Self {
+        let n = adjacency.len();
+        Self {
+            adjacency,
+            in_component: vec![false; n],
+            blocked: vec![false; n],
+            blocked_by: vec![Vec::new(); n],
+            path: Vec::new(),
+            cycles: Vec::new(),
+        }
+    }
+
+    /// Every elementary cycle, grouped by start node in ascending order.
+    fn find_all(mut self) -> Vec<Vec<usize>> {
+        let n = self.adjacency.len();
+        let mut reverse: Vec<Vec<usize>> = vec![Vec::new(); n];
+        for (from, targets) in self.adjacency.iter().enumerate() {
+            for &to in targets {
+                reverse[to].push(from);
+            }
+        }
+        for start in 0..n {
+            // The strongly connected component of `start` among nodes from
+            // `start` on: what it reaches and what reaches it
+            let forward = reachable(start, self.adjacency);
+            let backward = reachable(start, &reverse);
+            for node in start..n {
+                self.in_component[node] = forward[node] && backward[node];
+                self.blocked[node] = false;
+                self.blocked_by[node].clear();
+            }
+            self.circuit(start, start);
+        }
+        self.cycles
+    }
+
+    /// Search for cycles back to `start` through `node`; true if one was found.
+    fn circuit(&mut self, start: usize, node: usize) -> bool {
+        let mut found = false;
+        self.path.push(node);
+        self.blocked[node] = true;
+        for &next in &self.adjacency[node] {
+            if next < start || !self.in_component[next] {
+                continue;
+            }
+            if next == start {
+                self.cycles.push(self.path.clone());
+                found = true;
+            } else if !self.blocked[next] && self.circuit(start, next) {
+                found = true;
+            }
+        }
+        if found {
+            self.unblock(node);
+        } else {
+            for &next in &self.adjacency[node] {
+                if next >= start
+                    && self.in_component[next]
+                    && !self.blocked_by[next].contains(&node)
+                {
+                    self.blocked_by[next].push(node);
+                }
+            }
+        }
+        self.path.pop();
+        found
+    }
+
+    fn unblock(&mut self, node: usize) {
+        self.blocked[node] = false;
+        for other in std::mem::take(&mut self.blocked_by[node]) {
+            if self.blocked[other] {
+                self.unblock(other);
+            }
+        }
+    }
+}
+
+/// Which nodes from `start` on can be reached from `start` along `edges`,
+/// passing only through nodes from `start` on.
+fn reachable(start: usize, edges: &[Vec<usize>]) -> Vec<bool> {
+    let mut seen = vec![false; edges.len()];
+    seen[start] = true;
+    let mut stack = vec![start];
+    while let Some(node) = stack.pop() {
+        for &next in &edges[node] {
+            if next >= start && !seen[next] {
+                seen[next] = true;
+                stack.push(next);
+            }
+        }
+    }
+    seen
 }
 
 #[cfg(test)]
@@ -349,6 +852,22 @@ mod tests {
         World::new(WorldMeta::new("Test World"))
     }
 
+    #[test]
+    fn rng_seed_defaults_and_lock() {
+        let mut meta = WorldMeta::new("Seeded");
+        assert_eq!(meta.rng_seed(None), None);
+        assert_eq!(meta.rng_seed(Some(7)), Some(7));
+
+        meta.properties
+            .insert("rng.seed".to_string(), MetadataValue::Integer(1234));
+        assert_eq!(meta.rng_seed(None), Some(1234));
+        assert_eq!(meta.rng_seed(Some(7)), Some(7));
+
+        meta.properties
+            .insert("rng.lock".to_string(), MetadataValue::Boolean(true));
+        assert_eq!(meta.rng_seed(Some(7)), Some(1234));
+    }
+
     #[test]
     fn add_and_get_entity() {
         let mut world = test_world();
@@ -379,6 +898,31 @@ mod tests {
         assert!(world.find_by_name("nobody").is_none());
     }
 
+    #[test]
+    fn find_by_alias() {
+        let mut world = test_world();
+        let mut kael = Entity::new(EntityKind::Character, "Kael Stormborn");
+        kael.aliases = vec!["Kael".to_string(), "the Stormborn".to_string()];
+        let id = world.add_entity(kael).unwrap();
+        assert_eq!(world.find_id_by_name("kael"), Some(id));
+        assert_eq!(world.find_by_name("The Stormborn").unwrap().id, id);
+
+        let mut other = Entity::new(EntityKind::Character, "Kael the Younger");
+        other.aliases = vec!["KAEL".to_string()];
+        assert!(matches!(
+            world.add_entity(other),
+            Err(WwError::DuplicateName(alias)) if alias == "KAEL"
+        ));
+        assert!(
+            world
+                .add_entity(Entity::new(EntityKind::Location, "the Stormborn"))
+                .is_err()
+        );
+
+        world.remove_entity(id).unwrap();
+        assert!(world.find_by_name("kael").is_none());
+    }
+
     #[test]
     fn add_and_query_relationships() {
         let mut world = test_world();
@@ -576,6 +1120,179 @@ mod tests {
         }
     }
 
+    #[test]
+    fn reference_cycles_lists_each_cycle_once() {
+        let mut world = test_world();
+        let mut add = |name: &str| {
+            world
+                .add_entity(Entity::new(EntityKind::Lore, name))
+                .unwrap()
+        };
+        let (a, b, c, d) = (add("A"), add("B"), add("C"), add("D"));
+        let refs = RelationshipKind::References;
+        for (from, to) in [(b, c), (c, a), (a, b), (a, c), (c, c), (d, a)] {
+            world
+                .add_relationship(Relationship::new(from, refs.clone(), to))
+                .unwrap();
+        }
+        // Symmetric relationships never count as cycles
+        world
+            .add_relationship(Relationship::new(d, RelationshipKind::AlliedWith, b))
+            .unwrap();
+
+        assert_eq!(
+            world.reference_cycles(),
+            vec![vec![a, b, c], vec![a, c], vec![c]]
+        );
+    }
+
+    #[test]
+    fn reference_cycles_stay_fast_on_dense_acyclic_graphs() {
+        // A plain depth-first search walks every one of the 2^n paths here
+        let mut world = test_world();
+        let ids: Vec<_> = (0..40)
+            .map(|i| {
+                world
+                    .add_entity(Entity::new(EntityKind::Lore, format!("Lore {i:02}")))
+                    .unwrap()
+            })
+            .collect();
+        for (i, &from) in ids.iter().enumerate() {
+            for &to in &ids[i + 1..] {
+                world
+                    .add_relationship(Relationship::new(from, RelationshipKind::References, to))
+                    .unwrap();
+            }
+        }
+        assert!(world.reference_cycles().is_empty());
+
+        world
+            .add_relationship(Relationship::new(
+                ids[1],
+                RelationshipKind::References,
+                ids[0],
+            ))
+            .unwrap();
+        assert_eq!(world.reference_cycles(), vec![vec![ids[0], ids[1]]]);
+    }
+
+    #[test]
+    fn shortest_path_prefers_names_and_respects_direction() {
+        let mut world = test_world();
+        let mut add = |name: &str| {
+            world
+                .add_entity(Entity::new(EntityKind::Character, name))
+                .unwrap()
+        };
+        let (kael, aldric, brynn, order, prophecy) = (
+            add("Kael"),
+            add("Aldric"),
+            add("Brynn"),
+            add("the Order"),
+            add("the Prophecy"),
+        );
+        let lonely = add("Lonely");
+        for (from, kind, to) in [
+            (kael, RelationshipKind::MemberOf, order),
+            (brynn, RelationshipKind::MemberOf, order),
+            (aldric, RelationshipKind::LeaderOf, order),
+            (brynn, RelationshipKind::References, prophecy),
+            (aldric, RelationshipKind::References, prophecy),
+        ] {
+            world
+                .add_relationship(Relationship::new(from, kind, to))
+                .unwrap();
+        }
+
+        let (entities, hops) = world.shortest_path(kael, prophecy, false).unwrap();
+        assert_eq!(entities, vec![kael, order, aldric, prophecy]);
+        let kinds: Vec<_> = hops.iter().map(|r| r.kind.clone()).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                RelationshipKind::MemberOf,
+                RelationshipKind::LeaderOf,
+                RelationshipKind::References
+            ]
+        );
+
+        let (entities, hops) = world.shortest_path(kael, kael, false).unwrap();
+        assert_eq!(entities, vec![kael]);
+        assert!(hops.is_empty());
+        assert!(world.shortest_path(kael, lonely, false).is_none());
+        assert!(world.shortest_path(kael, prophecy, true).is_none());
+        assert_eq!(
+            world.shortest_path(aldric, order, true).unwrap().0,
+            vec![aldric, order]
+        );
+    }
+
+    #[test]
+    fn add_inverse_relationships_is_idempotent() {
+        let mut world = test_world();
+        let mut add = |name: &str| {
+            world
+                .add_entity(Entity::new(EntityKind::Character, name))
+                .unwrap()
+        };
+        let (kael, order, elara) = (add("Kael"), add("the Order"), add("Elara"));
+        let mut membership = Relationship::new(kael, RelationshipKind::MemberOf, order);
+        membership.since = Some(1203);
+        for rel in [
+            membership,
+            Relationship::new(kael, RelationshipKind::AlliedWith, elara),
+            Relationship::new(kael, RelationshipKind::RivalOf, kael),
+            Relationship::new(elara, RelationshipKind::References, kael),
+            Relationship::new(
+                kael,
+                RelationshipKind::Custom("is_referenced_by".to_string()),
+                elara,
+            ),
+        ] {
+            world.add_relationship(rel).unwrap();
+        }
+
+        assert_eq!(world.add_inverse_relationships(), 1);
+        assert_eq!(world.add_inverse_relationships(), 0);
+        assert_eq!(world.relationship_count(), 6);
+
+        let from_order = world.relationships_from(order);
+        assert_eq!(from_order.len(), 1);
+        assert_eq!(
+            from_order[0].kind,
+            RelationshipKind::Custom("has_member".to_string())
+        );
+        assert_eq!(from_order[0].target, kael);
+        assert_eq!(from_order[0].since, Some(1203));
+    }
+
+    #[test]
+    fn neighborhood_grows_by_hops() {
+        let mut world = test_world();
+        let mut add = |name: &str| {
+            world
+                .add_entity(Entity::new(EntityKind::Character, name))
+                .unwrap()
+        };
+        let (a, b, c, d) = (add("A"), add("B"), add("C"), add("D"));
+        for (from, to) in [(a, b), (c, b), (c, d)] {
+            world
+                .add_relationship(Relationship::new(from, RelationshipKind::AlliedWith, to))
+                .unwrap();
+        }
+
+        let set = |ids: &[EntityId]| {
+            ids.iter()
+                .copied()
+                .collect::<std::collections::HashSet<_>>()
+        };
+        assert_eq!(world.neighborhood(a, 0), set(&[a]));
+        assert_eq!(world.neighborhood(a, 1), set(&[a, b]));
+        assert_eq!(world.neighborhood(a, 2), set(&[a, b, c]));
+        assert_eq!(world.neighborhood(a, 10), set(&[a, b, c, d]));
+        assert!(world.neighborhood(EntityId::new(), 1).is_empty());
+    }
+
     #[test]
     fn stress_hub_entity_many_connections() {
         let (mut world, ids) = large_world(500);
@@ -699,4 +1416,61 @@ mod tests {
         assert_eq!(world.neighbors(ids[0]).len(), 1);
         assert_eq!(world.neighbors(ids[ids.len() - 1]).len(), 1);
     }
+
+    #[test]
+    fn json_round_trip() {
+        let mut world = test_world();
+        world.meta.genre = Some("fantasy".to_string());
+        let mut kael = Entity::new(EntityKind::Character, "Kael Stormborn");
+        kael.description = "Sworn to the Iron Citadel.".to_string();
+        kael.aliases.push("Kael".to_string());
+        let kael = world.add_entity(kael).unwrap();
+        let citadel = world
+            .add_entity(Entity::new(EntityKind::Location, "Iron Citadel"))
+            .unwrap();
+        let mut rel = Relationship::new(kael, RelationshipKind::LocatedAt, citadel);
+        rel.label = Some("garrisoned".to_string());
+        world.add_relationship(rel).unwrap();
+        world.index_mentions();
+
+        let json = world.to_json();
+        let loaded = World::from_json(&json).unwrap();
+
+        assert_eq!(loaded.meta.name, "Test World");
+        assert_eq!(loaded.meta.genre.as_deref(), Some("fantasy"));
+        assert_eq!(loaded.entity_count(), 2);
+        assert_eq!(
+            serde_json::to_value(loaded.get_entity(kael)).unwrap(),
+            serde_json::to_value(world.get_entity(kael)).unwrap()
+        );
+        assert_eq!(loaded.find_by_name("kael").unwrap().id, kael);
+        assert_eq!(loaded.relationships_from(kael).len(), 1);
+        assert_eq!(loaded.mentions(kael).len(), 1);
+        assert_eq!(loaded.to_json(), json);
+    }
+
+    #[test]
+    fn json_migrates_unversioned_export() {
+        let json = r#"{
+            "world": { "name": "Old World", "description": "", "genre": null, "setting": null },
+            "entities": [],
+            "relationships": []
+        }"#;
+        let world = World::from_json(json).unwrap();
+        assert_eq!(world.meta.name, "Old World");
+        assert_eq!(world.meta.schema_version, SCHEMA_VERSION);
+    }
+
+    #[test]
+    fn json_rejects_newer_schema_and_garbage() {
+        let newer = format!(
+            r#"{{"schema_version": {}, "world": {{}}}}"#,
+            SCHEMA_VERSION + 1
+        );
+        assert!(matches!(
+            World::from_json(&newer),
+            Err(WwError::InvalidJson(_))
+        ));
+        assert!(World::from_json("not json").is_err());
+    }
 }
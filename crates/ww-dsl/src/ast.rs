@@ -1,3 +1,5 @@
+use ww_core::RelationshipKind;
+
 /// Source span as a byte range.
 pub type Span = std::ops::Range<usize>;
 
@@ -46,6 +48,10 @@ pub struct EntityDecl {
     pub kind: Spanned<String>,
     /// The statements contained in the entity body.
     pub body: Vec<Spanned<Statement>>,
+    /// Authoring doc comment: the `--` lines directly above the declaration.
+    ///
+    /// Filled in by [`crate::parser::attach_doc_comments`], not by the parser.
+    pub doc: Option<String>,
 }
 
 /// A statement within a declaration body.
@@ -63,6 +69,17 @@ pub enum Statement {
     Date(DateLiteral),
     /// A named block grouping related statements.
     Block(BlockStmt),
+    /// Alternate names for the entity, e.g. `alias [Kael, the Stormborn]`.
+    Alias(Vec<Spanned<String>>),
+    /// A named era in the world block, e.g. `era "Age of Ruin" starts year -2000`.
+    Era(EraDecl),
+    /// A dialogue choice line, e.g. `choice "Ask about the road" -> road`.
+    Choice(ChoiceStmt),
+    /// A dialogue condition line, e.g. `requires has_item "Lantern"`.
+    Requires(String),
+    /// A dialogue effect line, e.g. `gives "Torch"`, applied when the
+    /// choice above it is picked.
+    Effect(String),
 }
 
 /// A key-value property, e.g. `population: 15000`.
@@ -98,6 +115,19 @@ pub struct RelationshipStmt {
     pub keyword: RelationshipKeyword,
     /// The target entities of the relationship.
     pub targets: Vec<Spanned<String>>,
+    /// Optional clauses after the target, e.g. `since year 1203`.
+    pub attributes: Vec<RelationshipAttribute>,
+}
+
+/// An optional clause on a relationship statement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RelationshipAttribute {
+    /// `since year N`: the year the relationship began.
+    Since(i64),
+    /// `until year N`: the year the relationship ended.
+    Until(i64),
+    /// `strength N`: how strong the relationship is.
+    Strength(i64),
 }
 
 /// A keyword identifying the type of relationship.
@@ -127,6 +157,35 @@ pub enum RelationshipKeyword {
     CausedBy,
 }
 
+impl RelationshipKeyword {
+    /// The relationship kind this keyword compiles to.
+    ///
+    /// `led by`, `owned by`, and `involving` produce relationships whose
+    /// source is the target entity, not the one declaring the statement;
+    /// see [`is_reversed`](Self::is_reversed).
+    pub fn kind(&self) -> RelationshipKind {
+        match self {
+            Self::In => RelationshipKind::ContainedIn,
+            Self::MemberOf => RelationshipKind::MemberOf,
+            Self::LocatedAt => RelationshipKind::LocatedAt,
+            Self::AlliedWith => RelationshipKind::AlliedWith,
+            Self::RivalOf => RelationshipKind::RivalOf,
+            Self::OwnedBy => RelationshipKind::OwnedBy,
+            Self::LedBy => RelationshipKind::LeaderOf,
+            Self::BasedAt => RelationshipKind::BasedAt,
+            Self::Involving => RelationshipKind::ParticipatedIn,
+            Self::References => RelationshipKind::References,
+            Self::CausedBy => RelationshipKind::CausedBy,
+        }
+    }
+
+    /// Whether the entity named after the keyword becomes the relationship's
+    /// source, e.g. `led by Kael` makes Kael the leader.
+    pub fn is_reversed(&self) -> bool {
+        matches!(self, Self::LedBy | Self::OwnedBy | Self::Involving)
+    }
+}
+
 /// A directional exit statement, e.g. `exit north to "The Citadel"`.
 #[derive(Debug, Clone)]
 pub struct ExitStmt {
@@ -136,6 +195,17 @@ pub struct ExitStmt {
     pub target: Spanned<String>,
 }
 
+/// A choice line in a `dialogue` block.
+#[derive(Debug, Clone)]
+pub struct ChoiceStmt {
+    /// The text shown to the player.
+    pub text: String,
+    /// The node the choice leads to; `None` ends the conversation.
+    pub target: Option<Spanned<String>>,
+    /// A trailing `requires` condition for showing the choice.
+    pub requires: Option<String>,
+}
+
 /// A named block grouping properties under a namespace prefix.
 #[derive(Debug, Clone)]
 pub struct BlockStmt {
@@ -158,6 +228,15 @@ pub struct InlineAnnotation {
     pub targets: Vec<Spanned<String>>,
 }
 
+/// A named era and the absolute year it starts in.
+#[derive(Debug, Clone)]
+pub struct EraDecl {
+    /// The era's name, matched against the `era` field of dates.
+    pub name: String,
+    /// The absolute year in which year 0 of the era falls.
+    pub start: i64,
+}
+
 /// A date literal, e.g. `1247-03-15 Third Age`.
 #[derive(Debug, Clone, Default)]
 pub struct DateLiteral {
@@ -0,0 +1,238 @@
+//! Stateful, filesystem-free compilation.
+//!
+//! [`WorldBuilder`] holds a set of named in-memory files and recompiles them
+//! on demand. It is meant for editors, web playgrounds, and other embedders
+//! that receive file contents directly instead of reading a directory.
+//!
+//! Each file is lexed once and its tokens are cached until the file changes;
+//! parsing, resolution, and compilation run over the whole set whenever
+//! anything changed, since names resolve across files. Building without any
+//! change returns the cached result. Includes are looked up among the
+//! builder's own files, never on disk.
+
+use std::collections::HashMap;
+use std::time::Instant;
+
+use crate::diagnostics::{Diagnostic, DiagnosticCode};
+use crate::include::{self, LexedFile};
+use crate::{CompileOptions, CompileResult, CompileTimings};
+
+/// Incrementally compiles a set of in-memory `.ww` files into a World.
+///
+/// ```
+/// use ww_dsl::WorldBuilder;
+///
+/// let mut builder = WorldBuilder::new();
+/// builder.add_or_update_file("people.ww", "Kael is a character {\n    located at the Keep\n}");
+/// builder.add_or_update_file("places.ww", "the Keep is a fortress {}");
+/// assert!(builder.diagnostics().is_empty());
+///
+/// builder.remove_file("places.ww");
+/// assert!(!builder.diagnostics().is_empty());
+/// ```
+#[derive(Default)]
+pub struct WorldBuilder {
+    /// Files in insertion order (which is also compilation order).
+    files: Vec<LexedFile>,
+    options: CompileOptions,
+    /// Result of the last build; `None` when files changed since.
+    result: Option<CompileResult>,
+}
+
+impl WorldBuilder {
+    /// Create an empty builder with default options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create an empty builder with explicit [`CompileOptions`].
+    pub fn with_options(options: CompileOptions) -> Self {
+        Self {
+            options,
+            ..Self::default()
+        }
+    }
+
+    /// Add a new file or replace the contents of an existing one.
+    ///
+    /// Returns `false` if the file already had exactly this content, in which
+    /// case nothing is invalidated.
+    pub fn add_or_update_file(&mut self, name: impl Into<String>, text: impl Into<String>) -> bool {
+        let name = name.into();
+        let text = text.into();
+
+        match self.files.iter_mut().find(|f| f.name == name) {
+            Some(file) if file.text == text => return false,
+            Some(file) => *file = LexedFile::new(name, text),
+            None => self.files.push(LexedFile::new(name, text)),
+        }
+        self.result = None;
+        true
+    }
+
+    /// Remove a file. Returns `false` if no file had that name.
+    pub fn remove_file(&mut self, name: &str) -> bool {
+        let before = self.files.len();
+        self.files.retain(|f| f.name != name);
+        let removed = self.files.len() != before;
+        if removed {
+            self.result = None;
+        }
+        removed
+    }
+
+    /// Names of the tracked files, in compilation order.
+    pub fn file_names(&self) -> impl Iterator<Item = &str> {
+        self.files.iter().map(|f| f.name.as_str())
+    }
+
+    /// Whether the next [`build`](Self::build) has to recompile.
+    pub fn is_dirty(&self) -> bool {
+        self.result.is_none()
+    }
+
+    /// Compile the current files, reusing the previous result if nothing changed.
+    ///
+    /// Produces the same result as [`compile_files_with_options`](crate::compile_files_with_options)
+    /// on the same files in the same order.
+    pub fn build(&mut self) -> &CompileResult {
+        let (files, options) = (&self.files, self.options);
+        self.result
+            .get_or_insert_with(|| compile_cached(files, options))
+    }
+
+    /// The current world, recompiling first if needed.
+    pub fn world(&mut self) -> &ww_core::World {
+        &self.build().world
+    }
+
+    /// The current diagnostics, recompiling first if needed.
+    pub fn diagnostics(&mut self) -> &[Diagnostic] {
+        &self.build().diagnostics
+    }
+}
+
+/// Stitch the cached per-file tokens together and run the rest of the pipeline.
+fn compile_cached(files: &[LexedFile], options: CompileOptions) -> CompileResult {
+    let started = Instant::now();
+    let sources = include::link(files, None);
+
+    if sources.text.is_empty() {
+        return CompileResult {
+            world: ww_core::World::new(ww_core::WorldMeta::new("Empty")),
+            diagnostics: vec![
+                Diagnostic::error(0..0, "no source files provided")
+                    .with_code(DiagnosticCode::Source),
+            ],
+            source_map: sources.source_map,
+            timings: CompileTimings::default(),
+            entity_spans: HashMap::new(),
+        };
+    }
+    let lex = started.elapsed();
+
+    let mut result = crate::compile_tokens(
+        &sources.tokens,
+        &sources.comments,
+        sources.diagnostics,
+        sources.source_map,
+        options,
+    );
+    result.timings.lex = lex;
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::InputFile;
+
+    #[test]
+    fn matches_compile_files() {
+        let files = [
+            (
+                "a.ww",
+                "Kael is a character {\n    member of the Order\n}\n",
+            ),
+            ("b.ww", "the Order is a faction {\n    based at Nowhere\n}"),
+        ];
+        let mut builder = WorldBuilder::new();
+        for (name, text) in files {
+            builder.add_or_update_file(name, text);
+        }
+        let inputs: Vec<InputFile> = files
+            .iter()
+            .map(|(name, text)| InputFile {
+                name: name.to_string(),
+                text: text.to_string(),
+            })
+            .collect();
+        let expected = crate::compile_files(&inputs);
+
+        let result = builder.build();
+        assert_eq!(result.world.entity_count(), expected.world.entity_count());
+        let spans = |r: &CompileResult| -> Vec<_> {
+            r.diagnostics
+                .iter()
+                .map(|d| (d.span.clone(), d.message.clone()))
+                .collect()
+        };
+        assert_eq!(spans(result), spans(&expected));
+        assert!(!result.diagnostics.is_empty());
+    }
+
+    #[test]
+    fn update_and_remove_invalidate() {
+        let mut builder = WorldBuilder::new();
+        assert!(builder.add_or_update_file("a.ww", "Kael is a character {}"));
+        assert_eq!(builder.world().entity_count(), 1);
+        assert!(!builder.is_dirty());
+
+        assert!(!builder.add_or_update_file("a.ww", "Kael is a character {}"));
+        assert!(!builder.is_dirty());
+
+        assert!(builder.add_or_update_file("a.ww", "Elara is a character {}"));
+        assert!(builder.is_dirty());
+        assert!(builder.world().find_by_name("Elara").is_some());
+        assert!(builder.world().find_by_name("Kael").is_none());
+
+        assert!(builder.remove_file("a.ww"));
+        assert!(!builder.remove_file("a.ww"));
+        assert_eq!(builder.diagnostics().len(), 1);
+        assert_eq!(builder.file_names().count(), 0);
+    }
+
+    #[test]
+    fn lex_errors_map_to_owning_file() {
+        let mut builder = WorldBuilder::new();
+        builder.add_or_update_file("a.ww", "Kael is a character {}");
+        builder.add_or_update_file("b.ww", "Elara is a character {\n    mood !\n}");
+        let result = builder.build();
+        let error = &result.diagnostics[0];
+        let (file, local) = result.source_map.to_local_span(&error.span).unwrap();
+        assert_eq!(result.source_map.files()[file].name, "b.ww");
+        assert_eq!(local.start, "Elara is a character {\n    mood ".len());
+    }
+
+    #[test]
+    fn includes_come_from_the_builder_files() {
+        let mut builder = WorldBuilder::new();
+        builder.add_or_update_file(
+            "main.ww",
+            "include \"shared/order.ww\"\n\nKael is a character {\n    member of the Order\n}\n",
+        );
+        builder.add_or_update_file("shared/order.ww", "the Order is a faction {}\n");
+        assert!(
+            builder.diagnostics().is_empty(),
+            "{:?}",
+            builder.diagnostics()
+        );
+
+        builder.remove_file("shared/order.ww");
+        let messages: Vec<_> = builder.diagnostics().iter().map(|d| &d.message).collect();
+        assert!(
+            messages[0].starts_with("cannot include shared/order.ww"),
+            "{messages:?}"
+        );
+    }
+}
@@ -1,10 +1,15 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
 use ww_core::component::*;
 use ww_core::entity::{Entity, EntityId, EntityKind, MetadataValue};
 use ww_core::relationship::{Relationship, RelationshipKind};
+use ww_core::script::{ConditionScript, EffectScript};
+use ww_core::validate::{IssueSeverity, ValidateOptions};
 use ww_core::world::{World, WorldMeta};
 
 use crate::ast::*;
-use crate::diagnostics::{Diagnostic, Severity};
+use crate::diagnostics::{Diagnostic, DiagnosticCode, Severity};
 use crate::resolver::{Resolver, SourceMap};
 
 /// Result of compiling DSL source into a World.
@@ -15,6 +20,34 @@ pub struct CompileResult {
     pub diagnostics: Vec<Diagnostic>,
     /// Maps byte offsets back to individual source files.
     pub source_map: SourceMap,
+    /// Wall-clock time spent in each pipeline phase.
+    pub timings: CompileTimings,
+    /// Span of each entity's declared name, for reporting model-level issues.
+    pub entity_spans: HashMap<EntityId, Span>,
+}
+
+/// Wall-clock durations of the compilation phases.
+///
+/// Phases after a failed strict parse do not run and are `None`.
+/// [`WorldBuilder`](crate::WorldBuilder) reuses cached tokens,
+/// so its `lex` time only covers stitching the files together.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CompileTimings {
+    /// Tokenizing the source text.
+    pub lex: Duration,
+    /// Parsing tokens into an AST.
+    pub parse: Duration,
+    /// Resolving entity names across files.
+    pub resolve: Option<Duration>,
+    /// Building the world from the AST.
+    pub compile: Option<Duration>,
+}
+
+impl CompileTimings {
+    /// Sum of the durations of the phases that ran.
+    pub fn total(&self) -> Duration {
+        self.lex + self.parse + self.resolve.unwrap_or_default() + self.compile.unwrap_or_default()
+    }
 }
 
 impl CompileResult {
@@ -24,23 +57,56 @@ impl CompileResult {
             .iter()
             .any(|d| d.severity == Severity::Error)
     }
+
+    /// Run [`World::validate`] and turn its issues into diagnostics.
+    ///
+    /// Each diagnostic points at the declaration of the entity it concerns;
+    /// world-level issues point at the start of the source.
+    pub fn validate(&self, options: ValidateOptions) -> Vec<Diagnostic> {
+        self.world
+            .validate(options)
+            .into_iter()
+            .map(|issue| {
+                let span = issue
+                    .entity
+                    .and_then(|id| self.entity_spans.get(&id).cloned())
+                    .unwrap_or(0..0);
+                let diagnostic = match issue.severity {
+                    IssueSeverity::Error => Diagnostic::error(span, issue.message),
+                    IssueSeverity::Warning => Diagnostic::warning(span, issue.message),
+                };
+                diagnostic
+                    .with_code(issue.kind.into())
+                    .with_label(issue.kind.to_string())
+            })
+            .collect()
+    }
 }
 
 /// Compile a parsed AST into a ww-core World.
 ///
-/// The compilation happens in two passes:
+/// The compilation happens in four passes:
 /// 1. **Entity pass**: create all entities using IDs pre-assigned by the resolver
 /// 2. **Relationship pass**: resolve name references via the resolver and create relationships
+/// 3. **Mention pass**: record entity names mentioned in descriptions
+/// 4. **Orphan pass**: warn about entities without any relationship, unless `standalone`
 pub fn compile(ast: &SourceFile, resolver: &Resolver, source_map: SourceMap) -> CompileResult {
     let mut compiler = Compiler::new(resolver, &source_map);
     compiler.compile(ast);
     // Merge resolver diagnostics (duplicates) first, then compiler diagnostics
     let mut diagnostics = resolver.diagnostics.clone();
     diagnostics.append(&mut compiler.diagnostics);
+    let entity_spans = compiler
+        .world
+        .all_entities()
+        .filter_map(|e| resolver.get(&e.name).map(|r| (e.id, r.name_span.clone())))
+        .collect();
     CompileResult {
         world: compiler.world,
         diagnostics,
         source_map,
+        timings: CompileTimings::default(),
+        entity_spans,
     }
 }
 
@@ -80,6 +146,39 @@ impl<'a> Compiler<'a> {
                 self.compile_entity_pass2(e);
             }
         }
+
+        // Pass 3: record entity names mentioned in descriptions
+        self.world.index_mentions();
+
+        // Pass 4: warn about entities nothing connects to
+        for decl in &ast.declarations {
+            if let Declaration::Entity(e) = &decl.node {
+                self.check_orphan(e);
+            }
+        }
+    }
+
+    /// Warn at the name of an entity with no relationships or exits, unless
+    /// its body marks it `standalone`.
+    fn check_orphan(&mut self, decl: &EntityDecl) {
+        // Duplicate declarations are reported by the resolver already
+        let Some(resolved) = self.resolver.get(&decl.name.node) else {
+            return;
+        };
+        if resolved.name_span != decl.name.span {
+            return;
+        }
+        if !self.world.is_orphan(resolved.id) {
+            return;
+        }
+        self.diagnostics.push(
+            Diagnostic::warning(
+                decl.name.span.clone(),
+                format!("\"{}\" has no relationships", decl.name.node),
+            )
+            .with_code(DiagnosticCode::Orphan)
+            .with_label("mark it `standalone` if it is meant to stand alone"),
+        );
     }
 
     // -- Pass 1: World metadata and entity creation --
@@ -110,14 +209,30 @@ impl<'a> Compiler<'a> {
                         self.world.meta.properties.insert(other.to_string(), mv);
                     }
                 },
+                Statement::Block(block) => self.apply_world_block(&block.name, &block.body),
+                Statement::Era(era) => {
+                    self.world.meta.properties.insert(
+                        format!("era.{}", era.name),
+                        MetadataValue::Integer(era.start),
+                    );
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Flatten a world block into dotted meta properties, recursing into
+    /// nested blocks (e.g. `display { template { ... } }`).
+    fn apply_world_block(&mut self, prefix: &str, body: &[Spanned<Statement>]) {
+        for inner in body {
+            match &inner.node {
+                Statement::Property(prop) => {
+                    let key = format!("{prefix}.{}", prop.key);
+                    let mv = self.value_to_metadata(&prop.value);
+                    self.world.meta.properties.insert(key, mv);
+                }
                 Statement::Block(block) => {
-                    for inner in &block.body {
-                        if let Statement::Property(prop) = &inner.node {
-                            let key = format!("{}.{}", block.name, prop.key);
-                            let mv = self.value_to_metadata(&prop.value);
-                            self.world.meta.properties.insert(key, mv);
-                        }
-                    }
+                    self.apply_world_block(&format!("{prefix}.{}", block.name), &block.body);
                 }
                 _ => {}
             }
@@ -136,6 +251,7 @@ impl<'a> Compiler<'a> {
         // Resolve entity kind — may traverse inheritance chain
         let (kind, location_subtype) = self.resolve_entity_kind(&name_lower, &decl.kind.node);
         let mut entity = Entity::with_id(resolved.id, kind, &decl.name.node);
+        entity.doc.clone_from(&decl.doc);
 
         // Set location subtype if applicable
         if let Some(subtype) = location_subtype {
@@ -150,6 +266,8 @@ impl<'a> Compiler<'a> {
             self.apply_inherited_properties(&mut entity, &parent_lower, &mut Vec::new());
         }
 
+        self.check_duplicate_properties("", &decl.body, &mut HashMap::new());
+
         // Process properties, component fields, descriptions, and dates
         for stmt in &decl.body {
             match &stmt.node {
@@ -170,14 +288,96 @@ impl<'a> Compiler<'a> {
                         &block.body,
                     );
                 }
+                Statement::Alias(names) => {
+                    // Aliases the resolver rejected would clash in the world
+                    for alias in names {
+                        if self.resolver.get(&alias.node).map(|r| r.id) == Some(entity.id)
+                            && !alias.node.eq_ignore_ascii_case(&entity.name)
+                        {
+                            entity.aliases.push(alias.node.clone());
+                        }
+                    }
+                }
+                Statement::Era(_) => {
+                    self.diagnostics.push(
+                        Diagnostic::warning(
+                            stmt.span.clone(),
+                            "eras can only be declared in the world block",
+                        )
+                        .with_code(DiagnosticCode::MisplacedStatement),
+                    );
+                }
+                Statement::Choice(_) | Statement::Requires(_) | Statement::Effect(_) => {
+                    self.diagnostics.push(
+                        Diagnostic::warning(
+                            stmt.span.clone(),
+                            "choice, requires and effect lines belong inside a 'dialogue' block",
+                        )
+                        .with_code(DiagnosticCode::MisplacedStatement),
+                    );
+                }
                 // Relationships handled in pass 2
                 Statement::Relationship(_) | Statement::Exit(_) => {}
             }
         }
 
+        self.check_dialogue_targets(&entity, &decl.body);
+
         if let Err(e) = self.world.add_entity(entity) {
-            self.diagnostics
-                .push(Diagnostic::error(decl.name.span.clone(), e.to_string()));
+            self.diagnostics.push(
+                Diagnostic::error(decl.name.span.clone(), e.to_string())
+                    .with_code(DiagnosticCode::InvalidEntity),
+            );
+        }
+    }
+
+    /// Warn about property keys set more than once in an entity body.
+    ///
+    /// Keys inside blocks are checked under their flattened name, so
+    /// `combat { hits 3 }` and `combat { hits 4 }` collide but sibling
+    /// instances such as `participant "A"` and `participant "B"` do not.
+    fn check_duplicate_properties<'b>(
+        &mut self,
+        prefix: &str,
+        body: &'b [Spanned<Statement>],
+        seen: &mut HashMap<String, (&'b Value, Span)>,
+    ) {
+        for stmt in body {
+            match &stmt.node {
+                // Repeatable keys accumulate rather than override
+                Statement::Property(prop) if prop.key == "gm_note" || prop.key == "tags" => {}
+                Statement::Property(prop) => {
+                    let key = format!("{prefix}{}", prop.key);
+                    let Some((first, first_span)) = seen.get(&key) else {
+                        seen.insert(key, (&prop.value, stmt.span.clone()));
+                        continue;
+                    };
+                    let message = if matches!(
+                        (first, &prop.value),
+                        (Value::List(_), Value::List(_))
+                    ) {
+                        format!(
+                            "list property '{key}' is set twice; only the later list is kept, should they be merged?"
+                        )
+                    } else {
+                        format!("property '{key}' is set twice; the later value wins")
+                    };
+                    self.diagnostics.push(
+                        Diagnostic::warning(stmt.span.clone(), message)
+                            .with_code(DiagnosticCode::DuplicateProperty)
+                            .with_label("set again here")
+                            .with_related(first_span.clone(), "first set here"),
+                    );
+                }
+                Statement::Block(block) if block.name != "dialogue" => {
+                    let nested = match &block.arg {
+                        Some(arg) => format!("{prefix}{}.{arg}.", block.name),
+                        None => format!("{prefix}{}.", block.name),
+                    };
+                    self.check_duplicate_properties(&nested, &block.body, seen);
+                }
+                _ => {}
+            }
         }
     }
 
@@ -194,6 +394,7 @@ impl<'a> Compiler<'a> {
             let rel = RelationshipStmt {
                 keyword: ann.node.keyword.clone(),
                 targets: ann.node.targets.clone(),
+                attributes: Vec::new(),
             };
             self.compile_relationship(source_id, &rel);
         }
@@ -213,19 +414,7 @@ impl<'a> Compiler<'a> {
     }
 
     fn compile_relationship(&mut self, source_id: EntityId, rel: &RelationshipStmt) {
-        let kind = match rel.keyword {
-            RelationshipKeyword::In => RelationshipKind::ContainedIn,
-            RelationshipKeyword::MemberOf => RelationshipKind::MemberOf,
-            RelationshipKeyword::LocatedAt => RelationshipKind::LocatedAt,
-            RelationshipKeyword::AlliedWith => RelationshipKind::AlliedWith,
-            RelationshipKeyword::RivalOf => RelationshipKind::RivalOf,
-            RelationshipKeyword::OwnedBy => RelationshipKind::OwnedBy,
-            RelationshipKeyword::LedBy => RelationshipKind::LeaderOf,
-            RelationshipKeyword::BasedAt => RelationshipKind::BasedAt,
-            RelationshipKeyword::Involving => RelationshipKind::ParticipatedIn,
-            RelationshipKeyword::References => RelationshipKind::References,
-            RelationshipKeyword::CausedBy => RelationshipKind::CausedBy,
-        };
+        let kind = rel.keyword.kind();
 
         for target in &rel.targets {
             let target_id = match self.resolve_name(&target.node, &target.span) {
@@ -237,18 +426,28 @@ impl<'a> Compiler<'a> {
             // "led by X" means X leads self, so X is source
             // "owned by X" means X owns self, so X is source
             // "involving [X, Y]" means X/Y participated in self
-            let (src, tgt) = match rel.keyword {
-                RelationshipKeyword::LedBy | RelationshipKeyword::OwnedBy => (target_id, source_id),
-                RelationshipKeyword::Involving => (target_id, source_id),
-                _ => (source_id, target_id),
+            let (src, tgt) = if rel.keyword.is_reversed() {
+                (target_id, source_id)
+            } else {
+                (source_id, target_id)
             };
 
-            let relationship = Relationship::new(src, kind.clone(), tgt);
+            let mut relationship = Relationship::new(src, kind.clone(), tgt);
+            for attribute in &rel.attributes {
+                match *attribute {
+                    RelationshipAttribute::Since(year) => relationship.since = Some(year),
+                    RelationshipAttribute::Until(year) => relationship.until = Some(year),
+                    RelationshipAttribute::Strength(n) => relationship.strength = Some(n),
+                }
+            }
             if let Err(e) = self.world.add_relationship(relationship) {
-                self.diagnostics.push(Diagnostic::error(
-                    target.span.clone(),
-                    format!("failed to add relationship: {e}"),
-                ));
+                self.diagnostics.push(
+                    Diagnostic::error(
+                        target.span.clone(),
+                        format!("failed to add relationship: {e}"),
+                    )
+                    .with_code(DiagnosticCode::InvalidRelationship),
+                );
             }
         }
     }
@@ -263,10 +462,10 @@ impl<'a> Compiler<'a> {
             .with_label(&exit.direction);
 
         if let Err(e) = self.world.add_relationship(relationship) {
-            self.diagnostics.push(Diagnostic::error(
-                exit.target.span.clone(),
-                format!("failed to add exit: {e}"),
-            ));
+            self.diagnostics.push(
+                Diagnostic::error(exit.target.span.clone(), format!("failed to add exit: {e}"))
+                    .with_code(DiagnosticCode::InvalidRelationship),
+            );
         }
     }
 
@@ -324,10 +523,13 @@ impl<'a> Compiler<'a> {
     ) {
         if visited.contains(&parent_lower.to_string()) {
             // Cycle detected
-            self.diagnostics.push(Diagnostic::error(
-                0..0,
-                format!("inheritance cycle detected involving \"{parent_lower}\""),
-            ));
+            self.diagnostics.push(
+                Diagnostic::error(
+                    0..0,
+                    format!("inheritance cycle detected involving \"{parent_lower}\""),
+                )
+                .with_code(DiagnosticCode::InheritanceCycle),
+            );
             return;
         }
         visited.push(parent_lower.to_string());
@@ -364,8 +566,15 @@ impl<'a> Compiler<'a> {
                                 &block.body,
                             );
                         }
-                        // Don't inherit relationships or exits
-                        Statement::Relationship(_) | Statement::Exit(_) => {}
+                        // Don't inherit relationships, exits, aliases, eras or
+                        // stray dialogue lines
+                        Statement::Relationship(_)
+                        | Statement::Exit(_)
+                        | Statement::Alias(_)
+                        | Statement::Era(_)
+                        | Statement::Choice(_)
+                        | Statement::Requires(_)
+                        | Statement::Effect(_) => {}
                     }
                 }
                 break;
@@ -408,6 +617,13 @@ impl<'a> Compiler<'a> {
             return;
         }
 
+        // A block argument names an instance: `participant "Kael" { .. }`
+        // flattens to `participant.Kael.*`, keeping sibling instances apart.
+        let prefix = match arg {
+            Some(arg) => format!("{prefix}.{arg}"),
+            None => prefix.to_string(),
+        };
+
         for stmt in body {
             match &stmt.node {
                 Statement::Property(prop) => {
@@ -431,72 +647,212 @@ impl<'a> Compiler<'a> {
                         .insert(key, MetadataValue::String(text.clone()));
                 }
                 Statement::Relationship(_) | Statement::Exit(_) => {
-                    self.diagnostics.push(Diagnostic::warning(
-                        stmt.span.clone(),
-                        format!("relationships and exits are not allowed inside '{prefix}' block"),
-                    ));
+                    self.diagnostics.push(
+                        Diagnostic::warning(
+                            stmt.span.clone(),
+                            format!(
+                                "relationships and exits are not allowed inside '{prefix}' block"
+                            ),
+                        )
+                        .with_code(DiagnosticCode::MisplacedStatement),
+                    );
                 }
                 Statement::Date(_) => {
-                    self.diagnostics.push(Diagnostic::warning(
-                        stmt.span.clone(),
-                        format!("dates are not allowed inside '{prefix}' block"),
-                    ));
+                    self.diagnostics.push(
+                        Diagnostic::warning(
+                            stmt.span.clone(),
+                            format!("dates are not allowed inside '{prefix}' block"),
+                        )
+                        .with_code(DiagnosticCode::MisplacedStatement),
+                    );
+                }
+                Statement::Alias(_) => {
+                    self.diagnostics.push(
+                        Diagnostic::warning(
+                            stmt.span.clone(),
+                            format!("aliases are not allowed inside '{prefix}' block"),
+                        )
+                        .with_code(DiagnosticCode::MisplacedStatement),
+                    );
+                }
+                Statement::Era(_) => {
+                    self.diagnostics.push(
+                        Diagnostic::warning(
+                            stmt.span.clone(),
+                            format!("eras are not allowed inside '{prefix}' block"),
+                        )
+                        .with_code(DiagnosticCode::MisplacedStatement),
+                    );
+                }
+                Statement::Choice(_) | Statement::Requires(_) | Statement::Effect(_) => {
+                    self.diagnostics.push(
+                        Diagnostic::warning(
+                            stmt.span.clone(),
+                            format!(
+                                "choice, requires and effect lines are not allowed inside '{prefix}' block"
+                            ),
+                        )
+                        .with_code(DiagnosticCode::MisplacedStatement),
+                    );
                 }
             }
         }
     }
 
+    /// Compile a `dialogue` block into one or more dialogue nodes.
+    ///
+    /// Each `node <id>` line starts a new node; lines before the first one
+    /// belong to a node named after the block argument (or `default`).
     fn apply_dialogue_block(
         &mut self,
         entity: &mut Entity,
         arg: Option<&str>,
         body: &[Spanned<Statement>],
     ) {
-        let id = arg.unwrap_or("default").to_string();
-        let mut text = String::new();
-        let mut conditions = Vec::new();
-        let mut choices = Vec::new();
+        let mut node = new_dialogue_node(arg.unwrap_or("default"));
+        let mut nodes = Vec::new();
 
         for stmt in body {
             match &stmt.node {
                 Statement::Property(prop) => match prop.key.as_str() {
+                    "node" => {
+                        if let Some(id) = self.value_as_string(&prop.value) {
+                            let previous = std::mem::replace(&mut node, new_dialogue_node(&id));
+                            // An unnamed lead-in with nothing in it is no node
+                            if !nodes.is_empty() || !is_empty_node(&previous) {
+                                nodes.push(previous);
+                            }
+                        }
+                    }
                     "text" => {
                         if let Some(s) = self.value_as_string(&prop.value) {
-                            text = s;
+                            node.text = s;
+                        }
+                    }
+                    "say" => {
+                        if let Some(s) = self.value_as_string(&prop.value) {
+                            if !node.text.is_empty() {
+                                node.text.push('\n');
+                            }
+                            node.text.push_str(&s);
                         }
                     }
                     "when" => {
                         if let Some(s) = self.value_as_string(&prop.value) {
-                            conditions.push(s);
+                            self.check_condition(&stmt.span, &s);
+                            node.conditions.push(s);
                         }
                     }
                     _ => {}
                 },
                 Statement::Description(t) => {
-                    text.clone_from(t);
+                    node.text.clone_from(t);
                 }
+                Statement::Requires(condition) => {
+                    self.check_condition(&stmt.span, condition);
+                    node.conditions.push(condition.clone());
+                }
+                Statement::Choice(choice) => {
+                    if let Some(condition) = &choice.requires {
+                        self.check_condition(&stmt.span, condition);
+                    }
+                    node.choices.push(ChoiceData {
+                        text: choice.text.clone(),
+                        response: String::new(),
+                        effects: Vec::new(),
+                        conditions: choice.requires.iter().cloned().collect(),
+                        goto: choice.target.as_ref().map(|t| t.node.clone()),
+                    });
+                }
+                Statement::Effect(effect) => match node.choices.last_mut() {
+                    Some(choice) => {
+                        choice.effects.push(effect.clone());
+                        self.check_effect(&stmt.span, effect);
+                    }
+                    None => self.diagnostics.push(
+                        Diagnostic::warning(
+                            stmt.span.clone(),
+                            "effect lines follow the choice they belong to",
+                        )
+                        .with_code(DiagnosticCode::MisplacedStatement),
+                    ),
+                },
                 Statement::Block(choice_block) if choice_block.name == "choice" => {
-                    choices.push(self.compile_choice_block(choice_block));
+                    node.choices.push(self.compile_choice_block(choice_block));
                 }
                 _ => {}
             }
         }
-
-        let dialogue = DialogueData {
-            id,
-            text,
-            conditions,
-            choices,
-        };
+        nodes.push(node);
 
         let comp = entity
             .components
             .fiction
             .get_or_insert_with(Default::default);
-        comp.dialogues.push(dialogue);
+        comp.dialogues.extend(nodes);
     }
 
-    fn compile_choice_block(&self, block: &BlockStmt) -> ChoiceData {
+    /// Report `choice ... -> node` lines whose node the entity does not have.
+    fn check_dialogue_targets(&mut self, entity: &Entity, body: &[Spanned<Statement>]) {
+        let ids: Vec<&str> = entity
+            .components
+            .fiction
+            .iter()
+            .flat_map(|f| f.dialogues.iter().map(|d| d.id.as_str()))
+            .collect();
+        let blocks = body.iter().filter_map(|stmt| match &stmt.node {
+            Statement::Block(block) if block.name == "dialogue" => Some(block),
+            _ => None,
+        });
+        for block in blocks {
+            for stmt in &block.body {
+                if let Statement::Choice(choice) = &stmt.node
+                    && let Some(target) = &choice.target
+                    && !ids.contains(&target.node.as_str())
+                {
+                    self.diagnostics.push(
+                        Diagnostic::error(
+                            target.span.clone(),
+                            format!(
+                                "choice \"{}\" goes to unknown dialogue node \"{}\"",
+                                choice.text, target.node
+                            ),
+                        )
+                        .with_code(DiagnosticCode::DanglingReference),
+                    );
+                }
+            }
+        }
+    }
+
+    /// Warn about a `requires`/`when` condition the fiction engine cannot
+    /// read; such a condition never holds during play.
+    fn check_condition(&mut self, span: &crate::ast::Span, text: &str) {
+        if ConditionScript::parse(text).is_none() {
+            self.diagnostics.push(
+                Diagnostic::warning(
+                    span.clone(),
+                    format!("unknown dialogue condition \"{text}\"; it will never hold"),
+                )
+                .with_code(DiagnosticCode::UnknownScript),
+            );
+        }
+    }
+
+    /// Warn about an effect the fiction engine cannot apply.
+    fn check_effect(&mut self, span: &crate::ast::Span, text: &str) {
+        if EffectScript::parse(text).is_none() {
+            self.diagnostics.push(
+                Diagnostic::warning(
+                    span.clone(),
+                    format!("unknown dialogue effect \"{text}\"; it will be ignored"),
+                )
+                .with_code(DiagnosticCode::UnknownScript),
+            );
+        }
+    }
+
+    fn compile_choice_block(&mut self, block: &BlockStmt) -> ChoiceData {
         let choice_text = block.arg.clone().unwrap_or_default();
         let mut response = String::new();
         let mut effects = Vec::new();
@@ -513,11 +869,13 @@ impl<'a> Compiler<'a> {
                     }
                     "effect" => {
                         if let Some(s) = self.value_as_string(&prop.value) {
+                            self.check_effect(&stmt.span, &s);
                             effects.push(s);
                         }
                     }
                     "when" => {
                         if let Some(s) = self.value_as_string(&prop.value) {
+                            self.check_condition(&stmt.span, &s);
                             conditions.push(s);
                         }
                     }
@@ -547,6 +905,32 @@ impl<'a> Compiler<'a> {
     // -- Property application --
 
     fn apply_property(&mut self, entity: &mut Entity, prop: &Property, span: &crate::ast::Span) {
+        // GM notes live outside the canonical properties
+        if prop.key == "gm_note" {
+            match &prop.value {
+                Value::String(note) => entity.gm_notes.push(note.clone()),
+                _ => self.diagnostics.push(
+                    Diagnostic::warning(span.clone(), "gm_note expects a quoted string")
+                        .with_code(DiagnosticCode::InvalidProperty),
+                ),
+            }
+            return;
+        }
+
+        if prop.key == "tags" {
+            let tags: Vec<String> = match &prop.value {
+                Value::List(items) => items
+                    .iter()
+                    .filter_map(|v| self.value_as_string(&v.node))
+                    .collect(),
+                other => self.value_as_string(other).into_iter().collect(),
+            };
+            for tag in tags {
+                entity.add_tag(tag);
+            }
+            return;
+        }
+
         // Try to apply as a component field first
         if self.apply_component_property(entity, prop) {
             return;
@@ -556,8 +940,6 @@ impl<'a> Compiler<'a> {
         let key = prop.key.clone();
         let mv = self.value_to_metadata(&prop.value);
         entity.properties.insert(key, mv);
-
-        let _ = span; // span available for future diagnostics
     }
 
     /// Try to apply a property as a typed component field. Returns true if handled.
@@ -804,12 +1186,36 @@ impl<'a> Compiler<'a> {
     }
 }
 
+/// An empty dialogue node with the given id.
+fn new_dialogue_node(id: &str) -> DialogueData {
+    DialogueData {
+        id: id.to_string(),
+        text: String::new(),
+        conditions: Vec::new(),
+        choices: Vec::new(),
+    }
+}
+
+fn is_empty_node(node: &DialogueData) -> bool {
+    node.text.is_empty() && node.conditions.is_empty() && node.choices.is_empty()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::lexer;
     use crate::parser;
 
+    /// Diagnostics other than orphan warnings, which every entity in a
+    /// single-entity source gets.
+    fn non_orphan(result: &CompileResult) -> Vec<&Diagnostic> {
+        result
+            .diagnostics
+            .iter()
+            .filter(|d| d.code != Some(DiagnosticCode::Orphan))
+            .collect()
+    }
+
     fn compile_source(source: &str) -> CompileResult {
         let (tokens, lex_errors) = lexer::lex(source);
         assert!(lex_errors.is_empty(), "lex errors: {lex_errors:?}");
@@ -832,6 +1238,54 @@ mod tests {
         assert_eq!(result.world.meta.genre.as_deref(), Some("high fantasy"));
     }
 
+    #[test]
+    fn compile_world_eras() {
+        let result = compile_source(
+            r#"world "Aldoria" {
+    era "Age of Ruin" starts year -2000
+}
+
+the Sundering is an event {
+    date year 753, era "Age of Ruin"
+    era "Age of Crowns" starts year 0
+}"#,
+        );
+        assert_eq!(result.world.meta.era_start("Age of Ruin"), Some(-2000));
+        assert_eq!(result.world.meta.era_start("Age of Crowns"), None);
+        assert_eq!(
+            result.world.meta.absolute_year(Some("Age of Ruin"), 753),
+            -1247
+        );
+        assert!(result.diagnostics.iter().any(|d| {
+            d.code == Some(DiagnosticCode::MisplacedStatement) && d.message.contains("world block")
+        }));
+    }
+
+    #[test]
+    fn compile_nested_world_blocks() {
+        let result = compile_source(
+            r#"world "Mystery" {
+    display {
+        template {
+            character ["motive: Motive", "alibi"]
+        }
+    }
+}"#,
+        );
+        assert!(!result.has_errors(), "errors: {:?}", result.diagnostics);
+        assert_eq!(
+            result
+                .world
+                .meta
+                .properties
+                .get("display.template.character"),
+            Some(&MetadataValue::List(vec![
+                MetadataValue::String("motive: Motive".into()),
+                MetadataValue::String("alibi".into()),
+            ]))
+        );
+    }
+
     #[test]
     fn compile_character_entity() {
         let result = compile_source(
@@ -873,6 +1327,31 @@ mod tests {
         assert_eq!(loc.population, Some(45000));
     }
 
+    #[test]
+    fn compile_relationship_attributes() {
+        let result = compile_source(
+            "Kael is a character {\n    allied with Elara since year 1203 strength 3\n    member of the Order until year 1250\n}\n\nElara is a character {}\n\nthe Order is a faction {}",
+        );
+        assert!(result.diagnostics.is_empty(), "{:?}", result.diagnostics);
+
+        let world = &result.world;
+        let kael = world.find_by_name("Kael").unwrap().id;
+        let rels = world.relationships_from(kael);
+        let allied = rels
+            .iter()
+            .find(|r| r.kind == RelationshipKind::AlliedWith)
+            .unwrap();
+        assert_eq!(allied.since, Some(1203));
+        assert_eq!(allied.until, None);
+        assert_eq!(allied.attribute("strength"), Some(3));
+        let member = rels
+            .iter()
+            .find(|r| r.kind == RelationshipKind::MemberOf)
+            .unwrap();
+        assert_eq!(member.until, Some(1250));
+        assert_eq!(member.since, None);
+    }
+
     #[test]
     fn compile_relationships() {
         let result = compile_source(
@@ -1502,4 +1981,307 @@ the Great Sundering is an event {
         let fiction = entity.components.fiction.as_ref().unwrap();
         assert_eq!(fiction.dialogues[0].id, "default");
     }
+
+    #[test]
+    fn compile_dialogue_nodes() {
+        let result = compile_source(
+            r#"Kael is a character {
+    dialogue {
+        node greeting
+        say "Well met."
+        say "You look tired."
+        choice "Ask about the road" -> road
+        choice "Show the seal" -> road requires has_item "Royal Seal"
+        choice "Leave"
+
+        node road
+        requires visited "the Crypt"
+        say "The road is washed out."
+        choice "Back" -> greeting
+    }
+}"#,
+        );
+        assert!(!result.has_errors(), "errors: {:?}", result.diagnostics);
+
+        let entity = result.world.find_by_name("Kael").unwrap();
+        let fiction = entity.components.fiction.as_ref().unwrap();
+        let ids: Vec<_> = fiction.dialogues.iter().map(|d| d.id.as_str()).collect();
+        assert_eq!(ids, vec!["greeting", "road"]);
+
+        let greeting = &fiction.dialogues[0];
+        assert_eq!(greeting.text, "Well met.\nYou look tired.");
+        assert_eq!(greeting.choices.len(), 3);
+        assert_eq!(greeting.choices[0].goto.as_deref(), Some("road"));
+        assert_eq!(
+            greeting.choices[1].conditions,
+            vec!["has_item \"Royal Seal\""]
+        );
+        assert!(greeting.choices[2].goto.is_none());
+
+        let road = &fiction.dialogues[1];
+        assert_eq!(road.conditions, vec!["visited \"the Crypt\""]);
+        assert_eq!(road.choices[0].goto.as_deref(), Some("greeting"));
+    }
+
+    #[test]
+    fn compile_dialogue_effect_lines_follow_their_choice() {
+        let result = compile_source(
+            r#"Kael is a character {
+    dialogue {
+        node greeting
+        gives "Torch"
+        choice "Take the torch"
+        gives "Torch"
+        sets flag "took_torch"
+        choice "Leave"
+    }
+}"#,
+        );
+        let diagnostics = non_orphan(&result);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].code,
+            Some(DiagnosticCode::MisplacedStatement)
+        );
+
+        let entity = result.world.find_by_name("Kael").unwrap();
+        let choices = &entity.components.fiction.as_ref().unwrap().dialogues[0].choices;
+        assert_eq!(
+            choices[0].effects,
+            vec!["gives \"Torch\"", "sets flag \"took_torch\""]
+        );
+        assert!(choices[1].effects.is_empty());
+    }
+
+    #[test]
+    fn compile_dialogue_warns_on_unknown_scripts() {
+        let source = r#"Kael is a character {
+    dialogue {
+        node greeting
+        requires player has join_order
+        choice "Pray" requires moon full
+        choice "Kael sent me" {
+            when "player has quest.join_order"
+            effect "set quest.armed true"
+            effect "shout loudly"
+        }
+    }
+}"#;
+        let result = compile_source(source);
+        let messages: Vec<&str> = result
+            .diagnostics
+            .iter()
+            .filter(|d| d.code == Some(DiagnosticCode::UnknownScript))
+            .map(|d| d.message.as_str())
+            .collect();
+        assert_eq!(
+            messages,
+            [
+                "unknown dialogue condition \"moon full\"; it will never hold",
+                "unknown dialogue effect \"shout loudly\"; it will be ignored",
+            ]
+        );
+    }
+
+    #[test]
+    fn compile_dialogue_missing_node_is_an_error() {
+        let source = r#"Kael is a character {
+    dialogue {
+        node greeting
+        say "Well met."
+        choice "Ask about the road" -> raod
+    }
+}"#;
+        let result = compile_source(source);
+        assert!(result.has_errors());
+        let diag = &result.diagnostics[0];
+        assert_eq!(diag.code, Some(DiagnosticCode::DanglingReference));
+        assert_eq!(&source[diag.span.clone()], "raod");
+        assert!(diag.message.contains("unknown dialogue node \"raod\""));
+    }
+
+    #[test]
+    fn compile_choice_line_outside_dialogue_warns() {
+        let result = compile_source("Kael is a character {\n    choice \"Leave\"\n}");
+        let diagnostics = non_orphan(&result);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].code,
+            Some(DiagnosticCode::MisplacedStatement)
+        );
+    }
+
+    #[test]
+    fn compile_gm_notes_stay_out_of_properties() {
+        let source = "Mira is a character {\n    gm_note \"secretly the heir\"\n    gm_note \"owes the Guild\"\n    gm_note 3\n}";
+        let result = compile_source(source);
+        let diagnostics = non_orphan(&result);
+        let mira = result.world.find_by_name("Mira").unwrap();
+        assert_eq!(mira.gm_notes, vec!["secretly the heir", "owes the Guild"]);
+        assert!(mira.properties.is_empty());
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Severity::Warning);
+    }
+
+    #[test]
+    fn compile_duplicate_property_warns_at_both_spans() {
+        let source = "Dune is a region {\n    climate arid\n    combat { hits 3 }\n    climate temperate\n    combat { hits 4 }\n    gm_note \"a\"\n    gm_note \"b\"\n}";
+        let result = compile_source(source);
+        let diagnostics = non_orphan(&result);
+        let dune = result.world.find_by_name("Dune").unwrap();
+        let location = dune.components.location.as_ref().unwrap();
+        assert_eq!(location.climate.as_deref(), Some("temperate"));
+
+        assert_eq!(diagnostics.len(), 2);
+        let climate = &diagnostics[0];
+        assert_eq!(climate.severity, Severity::Warning);
+        assert_eq!(climate.code, Some(DiagnosticCode::DuplicateProperty));
+        assert_eq!(&source[climate.span.clone()], "climate temperate");
+        assert_eq!(&source[climate.related[0].0.clone()], "climate arid");
+        assert!(diagnostics[1].message.contains("'combat.hits'"));
+    }
+
+    #[test]
+    fn compile_duplicate_list_property_suggests_merge() {
+        let source = "Kael is a character {\n    allies [Mira]\n    allies [Tomas]\n}";
+        let result = compile_source(source);
+        let diagnostics = non_orphan(&result);
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0].message.contains("merged"));
+    }
+
+    #[test]
+    fn compile_tags_populate_entity_tags() {
+        let source =
+            "Mira is a character {\n    tags [heir, \"north arc\", Heir]\n    tags rival\n}";
+        let result = compile_source(source);
+        let mira = result.world.find_by_name("Mira").unwrap();
+        assert_eq!(mira.tags, vec!["heir", "north arc", "rival"]);
+        assert!(mira.properties.is_empty());
+    }
+
+    #[test]
+    fn compile_aliases_resolve_references() {
+        let source = "Kael Stormborn is a character {\n    alias [Kael, Kael Stormborn]\n    member of the Order\n}\nthe Order is a faction {\n    alias Kael\n    led by Kael\n}";
+        let result = compile_source(source);
+        assert_eq!(result.diagnostics.len(), 1, "{:?}", result.diagnostics);
+        assert_eq!(
+            result.diagnostics[0].code,
+            Some(DiagnosticCode::DuplicateEntity)
+        );
+
+        let world = &result.world;
+        let kael = world.find_by_name("Kael Stormborn").unwrap();
+        assert_eq!(kael.aliases, vec!["Kael"]);
+        assert_eq!(world.find_by_name("kael").unwrap().id, kael.id);
+        let order = world.find_by_name("the Order").unwrap();
+        assert!(order.aliases.is_empty());
+        assert_eq!(world.relationship_count(), 2);
+    }
+
+    #[test]
+    fn compile_block_argument_namespaces_keys() {
+        let source = r#"the Bridge Ambush is an encounter {
+    participant "Kael" {
+        initiative 15
+    }
+    participant "Bandit" {
+        count 3
+    }
+}
+
+Kael is a character {}
+Bandit is a character {}"#;
+        let result = compile_source(source);
+        let diagnostics = non_orphan(&result);
+        assert!(diagnostics.is_empty(), "{:?}", diagnostics);
+        let ambush = result.world.find_by_name("the Bridge Ambush").unwrap();
+        assert_eq!(
+            ambush.properties.get("participant.Kael.initiative"),
+            Some(&MetadataValue::Integer(15))
+        );
+        assert_eq!(
+            ambush.properties.get("participant.Bandit.count"),
+            Some(&MetadataValue::Integer(3))
+        );
+    }
+
+    #[test]
+    fn lenient_compile_keeps_valid_entities() {
+        let source = "\
+Kael is a character {
+    species human
+    allied with Elara
+}
+
+Broken is {
+    species elf
+}
+
+Elara is a character {
+    status alive
+}";
+        let strict = crate::compile_source(source);
+        assert!(strict.has_errors());
+        assert_eq!(strict.world.entity_count(), 0);
+
+        let lenient = crate::compile_source_with_options(source, crate::CompileOptions::lenient());
+        assert!(lenient.has_errors());
+        assert!(lenient.world.find_by_name("Kael").is_some());
+        assert!(lenient.world.find_by_name("Elara").is_some());
+        assert!(lenient.world.find_by_name("Broken").is_none());
+    }
+
+    #[test]
+    fn timings_stop_at_failed_parse() {
+        let ok = crate::compile_source("Kael is a character {}");
+        let (Some(resolve), Some(compile)) = (ok.timings.resolve, ok.timings.compile) else {
+            panic!("every phase should run: {:?}", ok.timings);
+        };
+        assert_eq!(
+            ok.timings.total(),
+            ok.timings.lex + ok.timings.parse + resolve + compile
+        );
+
+        let failed = crate::compile_source("Broken is {\n    species elf\n}");
+        assert!(failed.has_errors());
+        assert_eq!(failed.timings.resolve, None);
+        assert_eq!(failed.timings.compile, None);
+        assert_eq!(
+            failed.timings.total(),
+            failed.timings.lex + failed.timings.parse
+        );
+    }
+
+    #[test]
+    fn orphans_warn_at_their_name_unless_standalone() {
+        let source = "Kael is a character {\n    member of the Order\n}\n\nthe Order is a faction {}\n\nthe Lonely Tower is a location {}\n\nthe Hermitage is a location {\n    standalone\n}\n";
+        let result = compile_source(source);
+        assert_eq!(result.diagnostics.len(), 1, "{:?}", result.diagnostics);
+        let orphan = &result.diagnostics[0];
+        assert_eq!(orphan.severity, Severity::Warning);
+        assert_eq!(orphan.code, Some(DiagnosticCode::Orphan));
+        assert_eq!(&source[orphan.span.clone()], "the Lonely Tower");
+        assert_eq!(orphan.message, "\"the Lonely Tower\" has no relationships");
+
+        let hermitage = result.world.find_by_name("the Hermitage").unwrap();
+        assert_eq!(
+            hermitage.properties.get("standalone"),
+            Some(&MetadataValue::Boolean(true))
+        );
+    }
+
+    #[test]
+    fn validate_points_at_entity_declaration() {
+        let source =
+            "Kael is a character {\n    located at the Order\n}\n\nthe Order is a faction {}";
+        let result = crate::compile_source(source);
+        assert!(!result.has_errors(), "errors: {:?}", result.diagnostics);
+
+        let diagnostics = result.validate(ValidateOptions::default());
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Severity::Warning);
+        assert_eq!(diagnostics[0].span, 0.."Kael".len());
+        assert_eq!(diagnostics[0].label.as_deref(), Some("kind-mismatch"));
+    }
 }
@@ -1,5 +1,11 @@
 use ariadne::{Color, Label, Report, ReportKind, Source};
 use std::fmt;
+use std::str::FromStr;
+
+use ww_core::validate::IssueKind;
+
+use crate::lexer::LexError;
+use crate::parser::ParseError;
 
 /// Severity level for diagnostics.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -10,17 +16,149 @@ pub enum Severity {
     Warning,
 }
 
+/// Stable, machine-readable identifier for a kind of diagnostic.
+///
+/// Codes are grouped by hundreds: `WW00xx` for reading and syntax,
+/// `WW01xx` for names and references, `WW02xx` for misplaced or malformed
+/// statements, and `WW03xx` for world validation checks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DiagnosticCode {
+    /// `WW0001`: the lexer hit an unexpected character.
+    Lex,
+    /// `WW0002`: the source does not match the grammar.
+    Parse,
+    /// `WW0003`: source files could not be found or read.
+    Source,
+    /// `WW0004`: files include each other in a cycle.
+    IncludeCycle,
+    /// `WW0101`: two entities share a name.
+    DuplicateEntity,
+    /// `WW0102`: a reference names an entity that is not defined.
+    UndefinedEntity,
+    /// `WW0103`: the world rejected an entity.
+    InvalidEntity,
+    /// `WW0104`: the world rejected a relationship or exit.
+    InvalidRelationship,
+    /// `WW0105`: entities inherit from each other in a cycle.
+    InheritanceCycle,
+    /// `WW0201`: a statement is not allowed where it appears.
+    MisplacedStatement,
+    /// `WW0202`: a property value has the wrong type.
+    InvalidProperty,
+    /// `WW0203`: an entity sets the same property more than once.
+    DuplicateProperty,
+    /// `WW0204`: a dialogue condition or effect is not understood.
+    UnknownScript,
+    /// `WW0301`: a reference names an entity or dialogue that does not exist.
+    DanglingReference,
+    /// `WW0302`: a relationship points at an entity of the wrong kind.
+    KindMismatch,
+    /// `WW0303`: an entity has no relationships.
+    Orphan,
+    /// `WW0304`: a location cannot be reached from the start location.
+    Unreachable,
+    /// `WW0305`: names differ only by letter case.
+    Casing,
+    /// `WW0306`: a description mentions a near-miss of an entity name.
+    Mention,
+}
+
+impl DiagnosticCode {
+    /// Every known code, in numeric order.
+    pub const ALL: &[DiagnosticCode] = &[
+        Self::Lex,
+        Self::Parse,
+        Self::Source,
+        Self::IncludeCycle,
+        Self::DuplicateEntity,
+        Self::UndefinedEntity,
+        Self::InvalidEntity,
+        Self::InvalidRelationship,
+        Self::InheritanceCycle,
+        Self::MisplacedStatement,
+        Self::InvalidProperty,
+        Self::DuplicateProperty,
+        Self::UnknownScript,
+        Self::DanglingReference,
+        Self::KindMismatch,
+        Self::Orphan,
+        Self::Unreachable,
+        Self::Casing,
+        Self::Mention,
+    ];
+
+    /// The code as written in output, e.g. `"WW0102"`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Lex => "WW0001",
+            Self::Parse => "WW0002",
+            Self::Source => "WW0003",
+            Self::IncludeCycle => "WW0004",
+            Self::DuplicateEntity => "WW0101",
+            Self::UndefinedEntity => "WW0102",
+            Self::InvalidEntity => "WW0103",
+            Self::InvalidRelationship => "WW0104",
+            Self::InheritanceCycle => "WW0105",
+            Self::MisplacedStatement => "WW0201",
+            Self::InvalidProperty => "WW0202",
+            Self::DuplicateProperty => "WW0203",
+            Self::UnknownScript => "WW0204",
+            Self::DanglingReference => "WW0301",
+            Self::KindMismatch => "WW0302",
+            Self::Orphan => "WW0303",
+            Self::Unreachable => "WW0304",
+            Self::Casing => "WW0305",
+            Self::Mention => "WW0306",
+        }
+    }
+}
+
+impl fmt::Display for DiagnosticCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for DiagnosticCode {
+    type Err = String;
+
+    /// Parse a code such as `WW0102` (case-insensitive).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .iter()
+            .find(|code| code.as_str().eq_ignore_ascii_case(s.trim()))
+            .copied()
+            .ok_or_else(|| format!("unknown diagnostic code: {s}"))
+    }
+}
+
+impl From<IssueKind> for DiagnosticCode {
+    fn from(kind: IssueKind) -> Self {
+        match kind {
+            IssueKind::DanglingReference => Self::DanglingReference,
+            IssueKind::KindMismatch => Self::KindMismatch,
+            IssueKind::Unreachable => Self::Unreachable,
+            IssueKind::Casing => Self::Casing,
+            IssueKind::Mention => Self::Mention,
+        }
+    }
+}
+
 /// A diagnostic message with source location.
 #[derive(Debug, Clone)]
 pub struct Diagnostic {
     /// Whether this diagnostic is an error or a warning.
     pub severity: Severity,
+    /// Stable code identifying the kind of problem, if one applies.
+    pub code: Option<DiagnosticCode>,
     /// Byte range in the source that this diagnostic refers to.
     pub span: std::ops::Range<usize>,
     /// Human-readable diagnostic message.
     pub message: String,
     /// Optional label displayed inline at the span location.
     pub label: Option<String>,
+    /// Other source locations involved in the problem, each with a label.
+    pub related: Vec<(std::ops::Range<usize>, String)>,
 }
 
 impl Diagnostic {
@@ -28,9 +166,11 @@ impl Diagnostic {
     pub fn error(span: std::ops::Range<usize>, message: impl Into<String>) -> Self {
         Self {
             severity: Severity::Error,
+            code: None,
             span,
             message: message.into(),
             label: None,
+            related: Vec::new(),
         }
     }
 
@@ -38,9 +178,11 @@ impl Diagnostic {
     pub fn warning(span: std::ops::Range<usize>, message: impl Into<String>) -> Self {
         Self {
             severity: Severity::Warning,
+            code: None,
             span,
             message: message.into(),
             label: None,
+            related: Vec::new(),
         }
     }
 
@@ -49,6 +191,30 @@ impl Diagnostic {
         self.label = Some(label.into());
         self
     }
+
+    /// Point at another source location involved in the problem.
+    pub fn with_related(mut self, span: std::ops::Range<usize>, label: impl Into<String>) -> Self {
+        self.related.push((span, label.into()));
+        self
+    }
+
+    /// Tag this diagnostic with a stable [`DiagnosticCode`].
+    pub fn with_code(mut self, code: DiagnosticCode) -> Self {
+        self.code = Some(code);
+        self
+    }
+}
+
+impl From<LexError> for Diagnostic {
+    fn from(e: LexError) -> Self {
+        Self::error(e.span, e.message).with_code(DiagnosticCode::Lex)
+    }
+}
+
+impl From<ParseError> for Diagnostic {
+    fn from(e: ParseError) -> Self {
+        Self::error(e.span, e.message).with_code(DiagnosticCode::Parse)
+    }
 }
 
 impl fmt::Display for Diagnostic {
@@ -57,10 +223,36 @@ impl fmt::Display for Diagnostic {
             Severity::Error => "error",
             Severity::Warning => "warning",
         };
-        write!(f, "{prefix}: {}", self.message)
+        match self.code {
+            Some(code) => write!(f, "{prefix}[{code}]: {}", self.message),
+            None => write!(f, "{prefix}: {}", self.message),
+        }
     }
 }
 
+/// Apply `--allow` / `--deny` style levels to a set of diagnostics.
+///
+/// Warnings whose code is in `allow` are dropped and warnings whose code is
+/// in `deny` become errors. Errors are never suppressed.
+pub fn apply_lint_levels(
+    diagnostics: Vec<Diagnostic>,
+    allow: &[DiagnosticCode],
+    deny: &[DiagnosticCode],
+) -> Vec<Diagnostic> {
+    diagnostics
+        .into_iter()
+        .filter(|d| {
+            d.severity == Severity::Error || !d.code.is_some_and(|code| allow.contains(&code))
+        })
+        .map(|mut d| {
+            if d.code.is_some_and(|code| deny.contains(&code)) {
+                d.severity = Severity::Error;
+            }
+            d
+        })
+        .collect()
+}
+
 /// Render diagnostics using ariadne for pretty terminal output.
 pub fn render_diagnostics(source: &str, filename: &str, diagnostics: &[Diagnostic]) -> String {
     let mut output = Vec::new();
@@ -77,6 +269,9 @@ pub fn render_diagnostics(source: &str, filename: &str, diagnostics: &[Diagnosti
 
         let span = (filename, diag.span.clone());
         let mut report = Report::build(kind, span).with_message(&diag.message);
+        if let Some(code) = diag.code {
+            report = report.with_code(code);
+        }
 
         let label_text = diag.label.as_deref().unwrap_or(&diag.message);
         report = report.with_label(
@@ -84,6 +279,13 @@ pub fn render_diagnostics(source: &str, filename: &str, diagnostics: &[Diagnosti
                 .with_message(label_text)
                 .with_color(color),
         );
+        for (span, label) in &diag.related {
+            report = report.with_label(
+                Label::new((filename, span.clone()))
+                    .with_message(label)
+                    .with_color(Color::Blue),
+            );
+        }
 
         report
             .finish()
@@ -104,6 +306,37 @@ mod tests {
         assert_eq!(d.to_string(), "error: entity not found: \"Kael\"");
     }
 
+    #[test]
+    fn codes_display_and_parse() {
+        let d = Diagnostic::error(0..5, "undefined entity: \"Kael\"")
+            .with_code(DiagnosticCode::UndefinedEntity);
+        assert_eq!(d.to_string(), "error[WW0102]: undefined entity: \"Kael\"");
+
+        for code in DiagnosticCode::ALL {
+            assert_eq!(code.as_str().parse::<DiagnosticCode>(), Ok(*code));
+        }
+        assert_eq!("ww0303".parse(), Ok(DiagnosticCode::Orphan));
+        assert!("WW9999".parse::<DiagnosticCode>().is_err());
+    }
+
+    #[test]
+    fn lint_levels_allow_and_deny_warnings() {
+        let diags = vec![
+            Diagnostic::warning(0..1, "orphan").with_code(DiagnosticCode::Orphan),
+            Diagnostic::warning(0..1, "casing").with_code(DiagnosticCode::Casing),
+            Diagnostic::error(0..1, "undefined").with_code(DiagnosticCode::UndefinedEntity),
+        ];
+        let out = apply_lint_levels(
+            diags,
+            &[DiagnosticCode::Orphan, DiagnosticCode::UndefinedEntity],
+            &[DiagnosticCode::Casing],
+        );
+        assert_eq!(out.len(), 2);
+        assert_eq!(out[0].code, Some(DiagnosticCode::Casing));
+        assert_eq!(out[0].severity, Severity::Error);
+        assert_eq!(out[1].code, Some(DiagnosticCode::UndefinedEntity));
+    }
+
     #[test]
     fn render_produces_output() {
         let source = "Kael is a character {\n    member of Unknown\n}";
@@ -0,0 +1,632 @@
+//! Source-level autofix passes.
+//!
+//! Fixes operate on the parsed AST but rewrite the original source text, so
+//! comments, spacing, and statement order are preserved. Each pass only
+//! touches the statements it is about, which makes running it twice a no-op.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::Path;
+
+use ww_core::Entity;
+
+use crate::ast::*;
+use crate::diagnostics::Diagnostic;
+use crate::{InputFile, include, parser, render};
+
+/// A source file rewritten by a fix pass.
+#[derive(Debug, Clone)]
+pub struct FileFix {
+    /// Display name of the file, as given in the [`InputFile`], or its path
+    /// relative to the directory for a file read because it was included.
+    pub name: String,
+    /// Full rewritten text of the file.
+    pub text: String,
+    /// Human-readable summary of each inserted or rewritten statement
+    /// (`"<entity>: <line>"`).
+    pub added: Vec<String>,
+}
+
+/// Return the opposite compass direction for an exit, if it has one.
+pub fn opposite_direction(direction: &str) -> Option<&'static str> {
+    match direction {
+        "north" => Some("south"),
+        "south" => Some("north"),
+        "east" => Some("west"),
+        "west" => Some("east"),
+        "up" => Some("down"),
+        "down" => Some("up"),
+        "northeast" => Some("southwest"),
+        "southwest" => Some("northeast"),
+        "northwest" => Some("southeast"),
+        "southeast" => Some("northwest"),
+        _ => None,
+    }
+}
+
+/// Insert missing reciprocal exits and symmetric relationships.
+///
+/// For every `north to B` in `A`, `B` gains `south to A` unless it already
+/// has an exit back to `A` or an exit of its own in the opposite direction.
+/// For every `allied with B` / `rival of B` in `A`, `B` gains the same
+/// relationship back to `A`. New statements are placed after the target
+/// entity's last exit or relationship so they stay grouped.
+///
+/// Includes are followed as [`include::load_sources`] does, with files not
+/// among `files` read from under `dir`, and each statement is inserted into
+/// the file that declares its entity. Only files that actually change are
+/// returned. Lex, include, or parse errors abort the pass and are returned
+/// as diagnostics.
+pub fn fix_relationships(dir: &Path, files: &[InputFile]) -> Result<Vec<FileFix>, Vec<Diagnostic>> {
+    let (sources, ast) = parse_sources(dir, files)?;
+    let source = &sources.text;
+    let source_map = &sources.source_map;
+    let entities = entity_decls(&ast);
+
+    // First declaration wins for duplicate names (the compiler reports those).
+    let mut by_name: HashMap<String, usize> = HashMap::new();
+    for (i, decl) in entities.iter().enumerate() {
+        by_name.entry(decl.name.node.to_lowercase()).or_insert(i);
+    }
+
+    let mut planned: HashSet<(usize, String)> = HashSet::new();
+    let mut insertions: Vec<(usize, Insertion)> = Vec::new();
+
+    for decl in &entities {
+        let name_lower = decl.name.node.to_lowercase();
+        let name_text = &source[decl.name.span.clone()];
+
+        for stmt in &decl.body {
+            match &stmt.node {
+                Statement::Exit(exit) => {
+                    let Some(opposite) = opposite_direction(&exit.direction) else {
+                        continue;
+                    };
+                    let Some(&target_idx) = by_name.get(&exit.target.node.to_lowercase()) else {
+                        continue;
+                    };
+                    let target = entities[target_idx];
+                    if has_exit_back(target, &name_lower, opposite) {
+                        continue;
+                    }
+                    let line = format!("{opposite} to {name_text}");
+                    if planned.insert((target_idx, line.clone())) {
+                        let group = Group::Exit;
+                        insertions.push((target_idx, Insertion { line, group }));
+                    }
+                }
+                Statement::Relationship(rel) => {
+                    let phrase = match rel.keyword {
+                        RelationshipKeyword::AlliedWith => "allied with",
+                        RelationshipKeyword::RivalOf => "rival of",
+                        _ => continue,
+                    };
+                    for target_ref in &rel.targets {
+                        let Some(&target_idx) = by_name.get(&target_ref.node.to_lowercase()) else {
+                            continue;
+                        };
+                        let target = entities[target_idx];
+                        if target.name.node.to_lowercase() == name_lower
+                            || has_relationship_back(target, &rel.keyword, &name_lower)
+                        {
+                            continue;
+                        }
+                        let line = format!("{phrase} {name_text}");
+                        if planned.insert((target_idx, line.clone())) {
+                            let group = Group::Relationship;
+                            insertions.push((target_idx, Insertion { line, group }));
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    // Group edits by file, keyed by local byte offset so they can be applied back to front.
+    let mut per_file: BTreeMap<usize, BTreeMap<usize, String>> = BTreeMap::new();
+    let mut added: BTreeMap<usize, Vec<String>> = BTreeMap::new();
+
+    for (target_idx, insertion) in insertions {
+        let target = entities[target_idx];
+        let (offset, text) = insertion_point(source, target, &insertion);
+        let Some(file_idx) = source_map.file_index_for_offset(target.name.span.start) else {
+            continue;
+        };
+        let file_offset = source_map.files()[file_idx].offset;
+        per_file
+            .entry(file_idx)
+            .or_default()
+            .entry(offset - file_offset)
+            .or_default()
+            .push_str(&text);
+        added
+            .entry(file_idx)
+            .or_default()
+            .push(format!("{}: {}", target.name.node, insertion.line));
+    }
+
+    Ok(per_file
+        .into_iter()
+        .map(|(file_idx, edits)| {
+            let file = &source_map.files()[file_idx];
+            let mut text = source[file.offset..file.offset + file.len].to_string();
+            for (offset, insert) in edits.into_iter().rev() {
+                text.insert_str(offset, &insert);
+            }
+            FileFix {
+                name: file.name.clone(),
+                text,
+                added: added.remove(&file_idx).unwrap_or_default(),
+            }
+        })
+        .collect())
+}
+
+/// Rewrite the `tags` statements of `entities` to list their current tags.
+///
+/// Declarations are found by name, following includes as
+/// [`fix_relationships`] does. An entity's `tags` statements are merged into
+/// one in place of the first, or removed when it has no tags left; an entity
+/// without one gets it after its `alias` statement or at the top of its
+/// body. Entities whose source already matches are left alone, and only
+/// files that actually change are returned. Lex, include, or parse errors
+/// abort the pass and are returned as diagnostics.
+pub fn write_tags(
+    dir: &Path,
+    files: &[InputFile],
+    entities: &[&Entity],
+) -> Result<Vec<FileFix>, Vec<Diagnostic>> {
+    let (sources, ast) = parse_sources(dir, files)?;
+    let source = &sources.text;
+    let source_map = &sources.source_map;
+    let decls = entity_decls(&ast);
+
+    // Replacements by file, as spans into the combined source.
+    let mut per_file: BTreeMap<usize, Vec<(Span, String)>> = BTreeMap::new();
+    let mut added: BTreeMap<usize, Vec<String>> = BTreeMap::new();
+
+    for entity in entities {
+        let name_lower = entity.name.to_lowercase();
+        let Some(decl) = decls
+            .iter()
+            .find(|d| d.name.node.to_lowercase() == name_lower)
+        else {
+            continue;
+        };
+        let Some(file_idx) = source_map.file_index_for_offset(decl.name.span.start) else {
+            continue;
+        };
+        let line = (!entity.tags.is_empty())
+            .then(|| format!("tags {}", render::format_str_list(&entity.tags)));
+        let statements: Vec<&Span> = decl
+            .body
+            .iter()
+            .filter(|s| matches!(&s.node, Statement::Property(p) if p.key == "tags"))
+            .map(|s| &s.span)
+            .collect();
+
+        let mut edits = Vec::new();
+        match (statements.split_first(), &line) {
+            (Some((first, [])), Some(line)) if source[(*first).clone()] == *line => continue,
+            (Some((first, rest)), _) => {
+                edits.push(match &line {
+                    Some(line) => ((*first).clone(), line.clone()),
+                    None => (statement_line(source, first), String::new()),
+                });
+                edits.extend(
+                    rest.iter()
+                        .map(|s| (statement_line(source, s), String::new())),
+                );
+            }
+            (None, Some(line)) => {
+                let insertion = Insertion {
+                    line: line.clone(),
+                    group: Group::Tags,
+                };
+                let (offset, text) = insertion_point(source, decl, &insertion);
+                edits.push((offset..offset, text));
+            }
+            (None, None) => continue,
+        }
+        per_file.entry(file_idx).or_default().extend(edits);
+        added.entry(file_idx).or_default().push(format!(
+            "{}: {}",
+            decl.name.node,
+            line.as_deref().unwrap_or("no tags")
+        ));
+    }
+
+    Ok(per_file
+        .into_iter()
+        .map(|(file_idx, mut edits)| {
+            let file = &source_map.files()[file_idx];
+            let mut text = source[file.offset..file.offset + file.len].to_string();
+            edits.sort_by_key(|(span, _)| span.start);
+            for (span, replacement) in edits.into_iter().rev() {
+                text.replace_range(
+                    span.start - file.offset..span.end - file.offset,
+                    &replacement,
+                );
+            }
+            FileFix {
+                name: file.name.clone(),
+                text,
+                added: added.remove(&file_idx).unwrap_or_default(),
+            }
+        })
+        .collect())
+}
+
+/// Gather `files` with their includes and parse them.
+fn parse_sources(
+    dir: &Path,
+    files: &[InputFile],
+) -> Result<(include::Sources, SourceFile), Vec<Diagnostic>> {
+    let sources = include::load_sources(dir, files);
+    if !sources.diagnostics.is_empty() {
+        return Err(sources.diagnostics);
+    }
+    let ast = parser::parse(&sources.tokens)
+        .map_err(|errors| errors.into_iter().map(Diagnostic::from).collect::<Vec<_>>())?;
+    Ok((sources, ast))
+}
+
+fn entity_decls(ast: &SourceFile) -> Vec<&EntityDecl> {
+    ast.declarations
+        .iter()
+        .filter_map(|d| match &d.node {
+            Declaration::Entity(e) => Some(e),
+            Declaration::World(_) => None,
+        })
+        .collect()
+}
+
+/// The span of a statement's whole line, newline included, when nothing
+/// else shares the line; otherwise the statement's own span.
+fn statement_line(source: &str, span: &Span) -> Span {
+    let start = source[..span.start].rfind('\n').map_or(0, |i| i + 1);
+    let end = source[span.end..]
+        .find('\n')
+        .map_or(source.len(), |i| span.end + i + 1);
+    if source[start..span.start].trim().is_empty() && source[span.end..end].trim().is_empty() {
+        start..end
+    } else {
+        span.clone()
+    }
+}
+
+struct Insertion {
+    line: String,
+    group: Group,
+}
+
+/// The kind of statement being inserted, which decides where it goes.
+enum Group {
+    Exit,
+    Relationship,
+    Tags,
+}
+
+fn has_exit_back(target: &EntityDecl, name_lower: &str, opposite: &str) -> bool {
+    target.body.iter().any(|stmt| {
+        matches!(&stmt.node, Statement::Exit(e)
+            if e.direction == opposite || e.target.node.to_lowercase() == name_lower)
+    })
+}
+
+fn has_relationship_back(
+    target: &EntityDecl,
+    keyword: &RelationshipKeyword,
+    name_lower: &str,
+) -> bool {
+    let points_back = |kw: &RelationshipKeyword, targets: &[Spanned<String>]| {
+        kw == keyword && targets.iter().any(|t| t.node.to_lowercase() == name_lower)
+    };
+    target
+        .annotations
+        .iter()
+        .any(|a| points_back(&a.node.keyword, &a.node.targets))
+        || target.body.iter().any(|stmt| {
+            matches!(&stmt.node, Statement::Relationship(r) if points_back(&r.keyword, &r.targets))
+        })
+}
+
+/// Choose where to insert a statement in `target` and the exact text to insert.
+///
+/// Exits and relationships go after the last statement of the same group,
+/// then of the other group, then after the last plain property or date.
+/// Tags go after the `alias` statement. Without such an anchor the statement
+/// opens the body, right after the brace.
+fn insertion_point(source: &str, target: &EntityDecl, insertion: &Insertion) -> (usize, String) {
+    let last = |pred: &dyn Fn(&Statement) -> bool| target.body.iter().rev().find(|s| pred(&s.node));
+    let is_exit = |s: &Statement| matches!(s, Statement::Exit(_));
+    let is_rel = |s: &Statement| matches!(s, Statement::Relationship(_));
+    let is_plain = |s: &Statement| matches!(s, Statement::Property(_) | Statement::Date(_));
+    let is_alias = |s: &Statement| matches!(s, Statement::Alias(_));
+
+    let anchor = match insertion.group {
+        Group::Exit => last(&is_exit)
+            .or_else(|| last(&is_rel))
+            .or_else(|| last(&is_plain)),
+        Group::Relationship => last(&is_rel)
+            .or_else(|| last(&is_exit))
+            .or_else(|| last(&is_plain)),
+        Group::Tags => last(&is_alias),
+    };
+
+    if let Some(stmt) = anchor {
+        let end = source[..stmt.span.end].trim_end().len();
+        let indent = line_indent(source, stmt.span.start);
+        return (end, format!("\n{indent}{}", insertion.line));
+    }
+
+    // No suitable anchor: open the body with the new statement.
+    let brace = source[target.kind.span.end..]
+        .find('{')
+        .map_or(target.kind.span.end, |i| target.kind.span.end + i);
+    let indent = format!("{}    ", line_indent(source, target.name.span.start));
+    let empty_inline = source[brace + 1..]
+        .trim_start_matches([' ', '\t'])
+        .starts_with('}');
+    let trailer = if empty_inline {
+        format!("\n{}", line_indent(source, target.name.span.start))
+    } else {
+        String::new()
+    };
+    (brace + 1, format!("\n{indent}{}{trailer}", insertion.line))
+}
+
+/// Leading whitespace of the line containing `offset`.
+fn line_indent(source: &str, offset: usize) -> &str {
+    let line_start = source[..offset].rfind('\n').map_or(0, |i| i + 1);
+    let line = &source[line_start..];
+    &line[..line.len() - line.trim_start_matches([' ', '\t']).len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fix(source: &str) -> Vec<FileFix> {
+        fix_relationships(
+            Path::new(""),
+            &[InputFile {
+                name: "test.ww".to_string(),
+                text: source.to_string(),
+            }],
+        )
+        .expect("fix should succeed")
+    }
+
+    #[test]
+    fn opposite_directions() {
+        assert_eq!(opposite_direction("north"), Some("south"));
+        assert_eq!(opposite_direction("northwest"), Some("southeast"));
+        assert_eq!(opposite_direction("up"), Some("down"));
+        assert_eq!(opposite_direction("out"), None);
+    }
+
+    #[test]
+    fn inserts_reciprocal_exit_after_existing_exits() {
+        let source = "\
+the Hall is a room {
+    north to the Tower
+}
+
+the Tower is a room {
+    climate cold
+    east to the Garden
+    -- the garden is lovely
+}
+
+the Garden is a room {
+    west to the Tower
+}
+";
+        let fixes = fix(source);
+        assert_eq!(fixes.len(), 1);
+        assert_eq!(fixes[0].added, vec!["the Tower: south to the Hall"]);
+        assert!(fixes[0].text.contains(
+            "    east to the Garden\n    south to the Hall\n    -- the garden is lovely"
+        ));
+        assert!(crate::compile_source(&fixes[0].text).diagnostics.is_empty());
+    }
+
+    #[test]
+    fn fix_is_idempotent() {
+        let source = "\
+Kael is a character {
+    allied with Elara
+}
+
+Elara is a character {
+    species elf
+}
+";
+        let fixes = fix(source);
+        assert_eq!(fixes.len(), 1);
+        assert!(
+            fixes[0]
+                .text
+                .contains("    species elf\n    allied with Kael\n}")
+        );
+        assert!(fix(&fixes[0].text).is_empty());
+    }
+
+    #[test]
+    fn respects_existing_reciprocals_and_conflicts() {
+        let source = "\
+A is a room {
+    north to B
+    allied with C
+}
+
+B is a room {
+    south to D
+}
+
+C (allied with A) is a faction {
+}
+
+D is a room {
+    north to B
+}
+";
+        // B already has a south exit (to D); C has the alliance inline.
+        assert!(fix(source).is_empty());
+    }
+
+    #[test]
+    fn empty_body_gets_statement() {
+        let fixes = fix("A is a room {\n    up to B\n}\n\nB is a room {}\n");
+        assert!(fixes[0].text.contains("B is a room {\n    down to A\n}"));
+    }
+
+    #[test]
+    fn quoted_names_are_preserved() {
+        let source = "\
+\"The Hall\" is a room {
+    north to Tower
+}
+
+Tower is a room {
+    \"\"\"
+    Tall.
+    \"\"\"
+}
+";
+        let fixes = fix(source);
+        assert!(
+            fixes[0]
+                .text
+                .contains("Tower is a room {\n    south to \"The Hall\"")
+        );
+        assert!(crate::compile_source(&fixes[0].text).diagnostics.is_empty());
+    }
+
+    #[test]
+    fn multi_file_edits_land_in_owning_file() {
+        let files = vec![
+            InputFile {
+                name: "a.ww".to_string(),
+                text: "A is a room {\n    east to B\n}\n".to_string(),
+            },
+            InputFile {
+                name: "b.ww".to_string(),
+                text: "B is a room {\n    climate dry\n}\n".to_string(),
+            },
+        ];
+        let fixes = fix_relationships(Path::new(""), &files).unwrap();
+        assert_eq!(fixes.len(), 1);
+        assert_eq!(fixes[0].name, "b.ww");
+        assert_eq!(
+            fixes[0].text,
+            "B is a room {\n    climate dry\n    west to A\n}\n"
+        );
+    }
+
+    #[test]
+    fn included_files_are_fixed_in_place() {
+        let files = vec![
+            InputFile {
+                name: "main.ww".to_string(),
+                text: "include \"regions/north.ww\"\n\nthe Hall is a room {}\n".to_string(),
+            },
+            InputFile {
+                name: "regions/north.ww".to_string(),
+                text: "the Gate is a room {\n    south to the Hall\n}\n".to_string(),
+            },
+        ];
+        let fixes = fix_relationships(Path::new(""), &files).unwrap();
+        assert_eq!(fixes.len(), 1);
+        assert_eq!(fixes[0].name, "main.ww");
+        assert_eq!(fixes[0].added, vec!["the Hall: north to the Gate"]);
+        assert!(
+            fixes[0]
+                .text
+                .starts_with("include \"regions/north.ww\"\n\nthe Hall is a room {\n")
+        );
+    }
+
+    fn tag(source: &str, name: &str, tags: &[&str]) -> Vec<FileFix> {
+        let world = crate::compile_source(source).world;
+        let mut entity = world.find_by_name(name).unwrap().clone();
+        entity.tags = tags.iter().map(|t| t.to_string()).collect();
+        let files = [InputFile {
+            name: "test.ww".to_string(),
+            text: source.to_string(),
+        }];
+        write_tags(Path::new(""), &files, &[&entity]).unwrap()
+    }
+
+    #[test]
+    fn tags_are_merged_into_the_first_statement() {
+        let source = "\
+Mira is a character {
+    tags [heir]
+    species human
+    -- raised in the north
+    tags rival
+}
+";
+        let fixes = tag(source, "Mira", &["heir", "rival", "north arc"]);
+        assert_eq!(
+            fixes[0].added,
+            vec!["Mira: tags [heir, rival, \"north arc\"]"]
+        );
+        assert_eq!(
+            fixes[0].text,
+            "\
+Mira is a character {
+    tags [heir, rival, \"north arc\"]
+    species human
+    -- raised in the north
+}
+"
+        );
+        assert!(tag(&fixes[0].text, "Mira", &["heir", "rival", "north arc"]).is_empty());
+
+        let fixes = tag(source, "Mira", &[]);
+        assert_eq!(
+            fixes[0].text,
+            "Mira is a character {\n    species human\n    -- raised in the north\n}\n"
+        );
+    }
+
+    #[test]
+    fn tags_open_the_body_or_follow_aliases() {
+        let fixes = tag(
+            "Mira is a character {\n    species human\n}\n",
+            "Mira",
+            &["heir"],
+        );
+        assert_eq!(
+            fixes[0].text,
+            "Mira is a character {\n    tags [heir]\n    species human\n}\n"
+        );
+
+        let source = "Mira is a character {\n    alias [Mi]\n    species human\n}\n";
+        let fixes = tag(source, "Mira", &["heir"]);
+        assert!(
+            fixes[0]
+                .text
+                .contains("    alias [Mi]\n    tags [heir]\n    species human")
+        );
+
+        let fixes = tag("Mira is a character {}\n", "Mira", &["heir"]);
+        assert_eq!(fixes[0].text, "Mira is a character {\n    tags [heir]\n}\n");
+        assert!(tag("Mira is a character {}\n", "Mira", &[]).is_empty());
+    }
+
+    #[test]
+    fn parse_errors_abort() {
+        let result = fix_relationships(
+            Path::new(""),
+            &[InputFile {
+                name: "bad.ww".to_string(),
+                text: "A is a room {".to_string(),
+            }],
+        );
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,454 @@
+//! Canonical formatting of `.ww` source.
+//!
+//! The formatter re-emits the parsed AST instead of patching the text: one
+//! statement per line, two-space indentation inside blocks, the `"""` of
+//! descriptions aligned with the statements around them, and one blank line
+//! between declarations. The indentation of description text is part of the
+//! text, so it is kept. Comments and `include` lines are not part of the
+//! AST, so they are taken from the token stream and put back where they
+//! appeared. A blank line the author left between statements is kept.
+
+use crate::ast::*;
+use crate::lexer::{self, Token};
+use crate::parser;
+use crate::render::{format_float, quote};
+
+const INDENT: &str = "  ";
+
+/// Format `.ww` source canonically.
+///
+/// Returns `None` if the source has lex or parse errors, so broken files are
+/// never rewritten.
+pub fn format_source(source: &str) -> Option<String> {
+    let (tokens, lex_errors, comments) = lexer::lex_with_comments(source);
+    if !lex_errors.is_empty() {
+        return None;
+    }
+    let ast = parser::parse(&tokens).ok()?;
+
+    let mut trivia: Vec<Trivia> = comments
+        .into_iter()
+        .map(|c| Trivia {
+            text: source[c.span.clone()].trim_end().to_string(),
+            span: c.span,
+            own_line: c.own_line,
+        })
+        .collect();
+    trivia.extend(tokens.iter().filter_map(|(tok, span)| match tok {
+        Token::Include(path) => Some(Trivia {
+            text: format!("include {}", quote(path)),
+            span: span.clone(),
+            own_line: true,
+        }),
+        _ => None,
+    }));
+    trivia.sort_by_key(|t| t.span.start);
+
+    let mut formatter = Formatter {
+        source,
+        trivia: trivia.into_iter().peekable(),
+        out: String::new(),
+        last_end: None,
+        force_blank: false,
+    };
+    for decl in &ast.declarations {
+        formatter.declaration(decl);
+    }
+    formatter.flush(usize::MAX, "");
+    Some(formatter.out)
+}
+
+/// A comment or `include` line carried over from the source.
+struct Trivia {
+    span: Span,
+    text: String,
+    /// False for a comment trailing code on the same line.
+    own_line: bool,
+}
+
+struct Formatter<'a> {
+    source: &'a str,
+    trivia: std::iter::Peekable<std::vec::IntoIter<Trivia>>,
+    out: String,
+    /// End of the last item written, or `None` right after an opening brace.
+    last_end: Option<usize>,
+    /// Set after a declaration so the next item is separated by a blank line.
+    force_blank: bool,
+}
+
+impl Formatter<'_> {
+    fn declaration(&mut self, decl: &Spanned<Declaration>) {
+        self.flush(decl.span.start, "");
+        let (header, body) = match &decl.node {
+            Declaration::World(world) => {
+                (format!("world {} {{", quote(&world.name.node)), &world.body)
+            }
+            Declaration::Entity(entity) => (self.entity_header(entity), &entity.body),
+        };
+        self.line("", &header, decl.span.start, decl.span.start);
+        self.body(body, 1, decl.span.end - 1);
+        self.close("", decl.span.end);
+        self.force_blank = true;
+    }
+
+    fn entity_header(&self, entity: &EntityDecl) -> String {
+        let mut header = self.name(&entity.name);
+        if !entity.annotations.is_empty() {
+            let annotations: Vec<String> = entity
+                .annotations
+                .iter()
+                .map(|ann| {
+                    let slice = &self.source[ann.span.clone()];
+                    let phrase = match ann.node.keyword {
+                        RelationshipKeyword::LedBy if slice.starts_with("leader") => "leader of",
+                        RelationshipKeyword::OwnedBy if slice.starts_with("owner") => "owner of",
+                        ref keyword => keyword_phrase(keyword),
+                    };
+                    format!("{phrase} {}", self.names(&ann.node.targets))
+                })
+                .collect();
+            header.push_str(&format!(" ({})", annotations.join(", ")));
+        }
+        let kind = self.name(&entity.kind);
+        let article = match kind.as_str() {
+            "lore" => "",
+            k if k
+                .trim_start_matches('"')
+                .starts_with(['a', 'e', 'i', 'o', 'u']) =>
+            {
+                "an "
+            }
+            _ => "a ",
+        };
+        header.push_str(&format!(" is {article}{kind} {{"));
+        header
+    }
+
+    fn body(&mut self, body: &[Spanned<Statement>], depth: usize, close: usize) {
+        let indent = INDENT.repeat(depth);
+        self.last_end = None;
+        for stmt in body {
+            self.flush(stmt.span.start, &indent);
+            self.statement(stmt, depth);
+        }
+        self.flush(close, &indent);
+    }
+
+    fn statement(&mut self, stmt: &Spanned<Statement>, depth: usize) {
+        let indent = INDENT.repeat(depth);
+        let (start, end) = (stmt.span.start, stmt.span.end);
+        let text = match &stmt.node {
+            Statement::Block(block) => {
+                let mut header = block.name.clone();
+                if let Some(arg) = &block.arg {
+                    header.push_str(&format!(" {}", quote(arg)));
+                }
+                header.push_str(" {");
+                self.line(&indent, &header, start, start);
+                self.body(&block.body, depth + 1, end - 1);
+                self.close(&indent, end);
+                return;
+            }
+            Statement::Description(_) => {
+                self.line(&indent, "\"\"\"", start, start);
+                for line in description_lines(&self.source[start + 3..end - 3], &indent) {
+                    self.out.push_str(&line);
+                    self.out.push('\n');
+                }
+                self.close_with(&indent, "\"\"\"", end);
+                return;
+            }
+            Statement::Property(prop) => format!("{} {}", prop.key, format_value(&prop.value)),
+            Statement::Relationship(rel) => {
+                let mut text = keyword_phrase(&rel.keyword).to_string();
+                match rel.keyword {
+                    RelationshipKeyword::Involving | RelationshipKeyword::References => {
+                        let names: Vec<String> = rel.targets.iter().map(|t| self.name(t)).collect();
+                        text.push_str(&format!(" [{}]", names.join(", ")));
+                    }
+                    _ => text.push_str(&format!(" {}", self.names(&rel.targets))),
+                }
+                for attr in &rel.attributes {
+                    match attr {
+                        RelationshipAttribute::Since(year) => {
+                            text.push_str(&format!(" since year {year}"))
+                        }
+                        RelationshipAttribute::Until(year) => {
+                            text.push_str(&format!(" until year {year}"))
+                        }
+                        RelationshipAttribute::Strength(n) => {
+                            text.push_str(&format!(" strength {n}"))
+                        }
+                    }
+                }
+                text
+            }
+            Statement::Exit(exit) => format!("{} to {}", exit.direction, self.name(&exit.target)),
+            Statement::Date(date) => {
+                let mut fields = Vec::new();
+                if let Some(year) = date.year {
+                    fields.push(format!("year {year}"));
+                }
+                if let Some(month) = date.month {
+                    fields.push(format!("month {month}"));
+                }
+                if let Some(day) = date.day {
+                    fields.push(format!("day {day}"));
+                }
+                if let Some(era) = &date.era {
+                    fields.push(format!("era {}", quote(era)));
+                }
+                format!("date {}", fields.join(", "))
+            }
+            Statement::Era(era) => format!("era {} starts year {}", quote(&era.name), era.start),
+            Statement::Choice(choice) => {
+                let mut text = format!("choice {}", quote(&choice.text));
+                if let Some(target) = &choice.target {
+                    text.push_str(&format!(" -> {}", self.name(target)));
+                }
+                if let Some(condition) = &choice.requires {
+                    text.push_str(&format!(" requires {condition}"));
+                }
+                text
+            }
+            Statement::Requires(condition) => format!("requires {condition}"),
+            Statement::Effect(effect) => effect.clone(),
+            Statement::Alias(names) => match names.as_slice() {
+                [name] => format!("alias {}", self.name(name)),
+                _ => {
+                    let names: Vec<String> = names.iter().map(|n| self.name(n)).collect();
+                    format!("alias [{}]", names.join(", "))
+                }
+            },
+        };
+        self.line(&indent, &text, start, end);
+    }
+
+    /// An entity name or kind, quoted only if the source quoted it.
+    fn name(&self, name: &Spanned<String>) -> String {
+        if self.source[name.span.clone()].starts_with('"') {
+            quote(&name.node)
+        } else {
+            name.node.clone()
+        }
+    }
+
+    fn names(&self, names: &[Spanned<String>]) -> String {
+        let names: Vec<String> = names.iter().map(|n| self.name(n)).collect();
+        names.join(" ")
+    }
+
+    /// Write a line for the item spanning `start..end`, after a blank line if
+    /// the source had one before it.
+    fn line(&mut self, indent: &str, text: &str, start: usize, end: usize) {
+        let blank = self.force_blank
+            || self.last_end.is_some_and(|last| {
+                self.source
+                    .get(last..start)
+                    .is_some_and(|gap| gap.matches('\n').count() >= 2)
+            });
+        if blank && !self.out.is_empty() {
+            self.out.push('\n');
+        }
+        self.force_blank = false;
+        self.out.push_str(&format!("{indent}{text}\n"));
+        self.last_end = Some(end);
+    }
+
+    fn close(&mut self, indent: &str, end: usize) {
+        self.close_with(indent, "}", end);
+    }
+
+    fn close_with(&mut self, indent: &str, text: &str, end: usize) {
+        self.out.push_str(&format!("{indent}{text}\n"));
+        self.last_end = Some(end);
+    }
+
+    /// Write the comments and includes that start before `offset`.
+    fn flush(&mut self, offset: usize, indent: &str) {
+        while let Some(item) = self.trivia.next_if(|t| t.span.start < offset) {
+            if !item.own_line && self.out.ends_with('\n') {
+                self.out.pop();
+                self.out.push_str(&format!(" {}\n", item.text));
+                self.last_end = Some(item.span.end);
+            } else {
+                self.line(indent, &item.text, item.span.start, item.span.end);
+            }
+        }
+    }
+}
+
+fn keyword_phrase(keyword: &RelationshipKeyword) -> &'static str {
+    match keyword {
+        RelationshipKeyword::In => "in",
+        RelationshipKeyword::MemberOf => "member of",
+        RelationshipKeyword::LocatedAt => "located at",
+        RelationshipKeyword::AlliedWith => "allied with",
+        RelationshipKeyword::RivalOf => "rival of",
+        RelationshipKeyword::OwnedBy => "owned by",
+        RelationshipKeyword::LedBy => "led by",
+        RelationshipKeyword::BasedAt => "based at",
+        RelationshipKeyword::Involving => "involving",
+        RelationshipKeyword::References => "references",
+        RelationshipKeyword::CausedBy => "caused by",
+    }
+}
+
+fn format_value(value: &Value) -> String {
+    match value {
+        Value::String(s) => quote(s),
+        Value::Integer(n) => n.to_string(),
+        Value::Float(n) => format_float(*n),
+        Value::Boolean(b) => b.to_string(),
+        Value::Identifier(word) => word.clone(),
+        Value::List(items) => {
+            let parts: Vec<String> = items.iter().map(|v| format_value(&v.node)).collect();
+            format!("[{}]", parts.join(", "))
+        }
+    }
+}
+
+/// Lines of a `"""` description as they are written back, read from its raw
+/// source between the quotes.
+///
+/// The compiler keeps a description's text with only its ends trimmed, so
+/// every line after the first keeps its indentation byte for byte and is
+/// written back as it is. Only the first line, whose indentation is trimmed
+/// away, is re-indented: to line up with the lines below it, or to `indent`
+/// when it stands alone.
+fn description_lines(raw: &str, indent: &str) -> Vec<String> {
+    let text = raw.trim();
+    if text.is_empty() {
+        return Vec::new();
+    }
+    let mut lines = text.split('\n');
+    let first = lines.next().unwrap_or_default();
+    let rest: Vec<&str> = lines.collect();
+    let lead = rest
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| &line[..line.len() - line.trim_start().len()])
+        .min_by_key(|lead| lead.len())
+        .unwrap_or(indent);
+    std::iter::once(format!("{lead}{first}"))
+        .chain(rest.iter().map(|line| line.to_string()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compile_source;
+
+    #[test]
+    fn formats_indentation_spacing_and_blank_lines() {
+        let source = r#"world   "Iron Kingdoms" {
+        genre "high fantasy"
+    era   "Age of Ruin"  starts year   -2000
+}
+Kael Stormborn (member of the Order) is a character {
+      species human
+   traits [brave,loyal]
+
+
+      """
+      A brave knight.
+         Sworn to the realm.
+      """
+}
+the Order is an faction {
+allied with "the Crown" since year 1203
+    date year -1247,
+      month 3
+  dialogue "greeting" {
+text "Welcome"
+    choice "Leave" { goto "end" }
+  }
+}
+"#;
+        let formatted = format_source(source).unwrap();
+        assert_eq!(
+            formatted,
+            r#"world "Iron Kingdoms" {
+  genre "high fantasy"
+  era "Age of Ruin" starts year -2000
+}
+
+Kael Stormborn (member of the Order) is a character {
+  species human
+  traits [brave, loyal]
+
+  """
+         A brave knight.
+         Sworn to the realm.
+  """
+}
+
+the Order is a faction {
+  allied with "the Crown" since year 1203
+  date year -1247, month 3
+  dialogue "greeting" {
+    text "Welcome"
+    choice "Leave" {
+      goto "end"
+    }
+  }
+}
+"#
+        );
+        assert_eq!(format_source(&formatted).unwrap(), formatted);
+
+        let before = compile_source(source).world;
+        let after = compile_source(&formatted).world;
+        assert_eq!(before.entity_count(), after.entity_count());
+        assert_eq!(before.relationship_count(), after.relationship_count());
+        let description = |world: &ww_core::World| {
+            world
+                .find_by_name("Kael Stormborn")
+                .unwrap()
+                .description
+                .clone()
+        };
+        assert_eq!(description(&after), description(&before));
+    }
+
+    #[test]
+    fn keeps_comments_includes_and_annotation_wording() {
+        let source = "include \"shared.ww\"\n\
+            -- The leader\n\
+            Kael (leader of the Order) is a character { -- hero\n\
+            \x20   -- his home\n\
+            \x20   located at the Citadel\n\
+            \x20   -- nothing else yet\n\
+            } -- end\n\
+            -- trailing note\n";
+        assert_eq!(
+            format_source(source).unwrap(),
+            "include \"shared.ww\"\n\
+             -- The leader\n\
+             Kael (leader of the Order) is a character { -- hero\n\
+             \x20 -- his home\n\
+             \x20 located at the Citadel\n\
+             \x20 -- nothing else yet\n\
+             } -- end\n\
+             \n\
+             -- trailing note\n"
+        );
+    }
+
+    #[test]
+    fn formats_dialogue_lines() {
+        let source = "Kael is a character {\n  dialogue {\n  node greeting\n      say \"Well met.\"\n    choice \"Road\"   ->   road   requires has_item \"Map\"\n  choice \"Leave\"\n  sets   flag \"met\"\n  node road\n  requires visited \"the Crypt\"\n  }\n}\n";
+        assert_eq!(
+            format_source(source).unwrap(),
+            "Kael is a character {\n  dialogue {\n    node greeting\n    say \"Well met.\"\n    choice \"Road\" -> road requires has_item \"Map\"\n    choice \"Leave\"\n    sets flag \"met\"\n    node road\n    requires visited \"the Crypt\"\n  }\n}\n"
+        );
+    }
+
+    #[test]
+    fn refuses_broken_source() {
+        assert!(format_source("Kael is a character {\n  species human\n").is_none());
+        assert!(format_source("Kael is a character { @ }").is_none());
+        assert_eq!(format_source("").unwrap(), "");
+    }
+}
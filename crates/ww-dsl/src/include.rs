@@ -0,0 +1,313 @@
+//! Following `include` statements across world files.
+//!
+//! An included file's tokens are spliced into the including file's token
+//! stream where its `include` stands, so its declarations take that place
+//! as if they had been written there. The text of every file still sits in
+//! one piece in the combined source, which lets the [`SourceMap`] point
+//! diagnostics into the right file.
+
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use std::path::{Component, Path, PathBuf};
+
+use crate::InputFile;
+use crate::ast::Span;
+use crate::diagnostics::{Diagnostic, DiagnosticCode};
+use crate::lexer::{self, Comment, LexError, Token, TokenStream};
+use crate::resolver::SourceMap;
+
+/// World files gathered for compiling, with their includes followed.
+#[derive(Debug, Default)]
+pub struct Sources {
+    /// Every file's text, joined with newlines; each file is in one piece.
+    pub text: String,
+    /// Where each file lies within [`text`](Self::text).
+    pub source_map: SourceMap,
+    /// Tokens with spans into [`text`](Self::text). Included files are
+    /// spliced in right after the `include` that names them, so spans are
+    /// not in ascending order once a file includes another.
+    pub tokens: TokenStream,
+    /// Line comments with spans into [`text`](Self::text), in text order.
+    pub comments: Vec<Comment>,
+    /// Lex errors and include problems, in the order they were found.
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+/// Gather `files` and everything they include.
+///
+/// File names are paths relative to `dir`, and include paths are relative to
+/// the file that contains them. An included file is taken from `files` when
+/// it is among them and read from disk under `dir` otherwise. Each file is
+/// loaded once, in the place of its first include or else in its place in
+/// `files`; missing files and include cycles are reported as diagnostics.
+pub fn load_sources(dir: &Path, files: &[InputFile]) -> Sources {
+    let files: Vec<LexedFile> = files
+        .iter()
+        .map(|file| LexedFile::new(file.name.clone(), file.text.clone()))
+        .collect();
+    link(&files, Some(dir))
+}
+
+/// Gather `files` and what they include, without reading from disk.
+///
+/// Like [`load_sources`], but an include must name one of `files`; any other
+/// is reported as a diagnostic at the `include`.
+pub fn link_files(files: &[InputFile]) -> Sources {
+    let files: Vec<LexedFile> = files
+        .iter()
+        .map(|file| LexedFile::new(file.name.clone(), file.text.clone()))
+        .collect();
+    link(&files, None)
+}
+
+/// A file with its lexer output, spans local to the file.
+#[derive(Debug, Clone)]
+pub(crate) struct LexedFile {
+    pub(crate) name: String,
+    pub(crate) text: String,
+    pub(crate) tokens: TokenStream,
+    pub(crate) lex_errors: Vec<LexError>,
+    pub(crate) comments: Vec<Comment>,
+}
+
+impl LexedFile {
+    pub(crate) fn new(name: String, text: String) -> Self {
+        let (tokens, lex_errors, comments) = lexer::lex_with_comments(&text);
+        Self {
+            name,
+            text,
+            tokens,
+            lex_errors,
+            comments,
+        }
+    }
+}
+
+/// Join already-lexed files, splicing in what they include.
+///
+/// With `disk`, included files that are not among `files` are read from
+/// under that directory; without it they are reported as missing.
+pub(crate) fn link(files: &[LexedFile], disk: Option<&Path>) -> Sources {
+    let mut linker = Linker {
+        files: files
+            .iter()
+            .map(|file| (normalize(Path::new(&file.name)), file))
+            .collect(),
+        disk,
+        sources: Sources::default(),
+        loaded: HashSet::new(),
+        stack: Vec::new(),
+    };
+    for file in files {
+        linker.load(normalize(Path::new(&file.name)), None);
+    }
+    linker.sources
+}
+
+struct Linker<'a> {
+    /// The given files, by normalized path.
+    files: HashMap<PathBuf, &'a LexedFile>,
+    disk: Option<&'a Path>,
+    sources: Sources,
+    loaded: HashSet<PathBuf>,
+    /// Files whose includes are being followed, outermost first.
+    stack: Vec<PathBuf>,
+}
+
+impl Linker<'_> {
+    /// Append the file at `path` and, in its token stream, what it includes.
+    ///
+    /// `include_span` is the span of the `include` statement that named the
+    /// file, if any; problems with it are reported there.
+    fn load(&mut self, path: PathBuf, include_span: Option<Span>) {
+        if self.stack.contains(&path) {
+            if let Some(span) = include_span {
+                let chain: Vec<String> = self
+                    .stack
+                    .iter()
+                    .skip_while(|p| **p != path)
+                    .chain([&path])
+                    .map(|p| display_name(p))
+                    .collect();
+                self.sources.diagnostics.push(
+                    Diagnostic::error(span, format!("circular include: {}", chain.join(" -> ")))
+                        .with_code(DiagnosticCode::IncludeCycle),
+                );
+            }
+            return;
+        }
+        if !self.loaded.insert(path.clone()) {
+            return;
+        }
+
+        let file = match self.files.get(&path) {
+            Some(file) => Cow::Borrowed(*file),
+            None => {
+                let read = match self.disk {
+                    Some(dir) => std::fs::read_to_string(dir.join(&path)),
+                    None => Err(std::io::Error::new(
+                        std::io::ErrorKind::NotFound,
+                        "not among the given files",
+                    )),
+                };
+                match read {
+                    Ok(text) => Cow::Owned(LexedFile::new(display_name(&path), text)),
+                    Err(e) => {
+                        if let Some(span) = include_span {
+                            self.sources.diagnostics.push(
+                                Diagnostic::error(
+                                    span,
+                                    format!("cannot include {}: {e}", display_name(&path)),
+                                )
+                                .with_code(DiagnosticCode::Source),
+                            );
+                        }
+                        return;
+                    }
+                }
+            }
+        };
+
+        let sources = &mut self.sources;
+        if !sources.text.is_empty() {
+            // Files are joined with a newline, which also ends the last line
+            let offset = sources.text.len();
+            sources.text.push('\n');
+            sources.tokens.push((Token::Newline, offset..offset + 1));
+        }
+        let offset = sources.text.len();
+        sources
+            .source_map
+            .add_file(file.name.clone(), offset, file.text.len());
+        sources.text.push_str(&file.text);
+        sources
+            .comments
+            .extend(file.comments.iter().map(|c| Comment {
+                span: c.span.start + offset..c.span.end + offset,
+                ..c.clone()
+            }));
+        sources.diagnostics.extend(file.lex_errors.iter().map(|e| {
+            Diagnostic::error(e.span.start + offset..e.span.end + offset, &e.message)
+                .with_code(DiagnosticCode::Lex)
+        }));
+
+        let base = path.parent().unwrap_or(Path::new("")).to_path_buf();
+        self.stack.push(path);
+        for (token, span) in &file.tokens {
+            let span = span.start + offset..span.end + offset;
+            self.sources.tokens.push((token.clone(), span.clone()));
+            if let Token::Include(target) = token {
+                self.load(normalize(&base.join(target)), Some(span));
+            }
+        }
+        self.stack.pop();
+    }
+}
+
+/// Resolve `.` and `..` without touching the file system, so the same file
+/// reached along different paths is recognized.
+fn normalize(path: &Path) -> PathBuf {
+    let mut out = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir
+                if matches!(out.components().next_back(), Some(Component::Normal(_))) =>
+            {
+                out.pop();
+            }
+            other => out.push(other),
+        }
+    }
+    out
+}
+
+/// A path as shown in diagnostics, e.g. `shared/factions.ww`.
+fn display_name(path: &Path) -> String {
+    path.to_string_lossy().replace('\\', "/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn file(name: &str, text: &str) -> InputFile {
+        InputFile {
+            name: name.to_string(),
+            text: text.to_string(),
+        }
+    }
+
+    fn words(sources: &Sources) -> Vec<&str> {
+        sources
+            .tokens
+            .iter()
+            .filter_map(|(token, _)| match token {
+                Token::Word(word) => Some(word.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn included_files_are_spliced_in_place() {
+        let sources = load_sources(
+            Path::new(""),
+            &[
+                file("main.ww", "first\ninclude \"shared/middle.ww\"\nlast\n"),
+                file("shared/middle.ww", "middle\ninclude \"../main.ww\"\n"),
+            ],
+        );
+        assert_eq!(words(&sources), ["first", "middle", "last"]);
+        assert_eq!(sources.source_map.files().len(), 2);
+        let (_, span) = &sources.tokens[sources.tokens.len() - 2];
+        assert_eq!(&sources.text[span.clone()], "last");
+        assert_eq!(sources.diagnostics.len(), 1);
+        assert_eq!(
+            sources.diagnostics[0].message,
+            "circular include: main.ww -> shared/middle.ww -> main.ww"
+        );
+    }
+
+    #[test]
+    fn missing_includes_are_reported_at_the_include() {
+        let sources = link(
+            &[LexedFile::new(
+                "main.ww".to_string(),
+                "include \"gone.ww\"\n".to_string(),
+            )],
+            None,
+        );
+        assert_eq!(sources.diagnostics.len(), 1);
+        assert_eq!(sources.diagnostics[0].span, 0..17);
+        assert!(
+            sources.diagnostics[0]
+                .message
+                .starts_with("cannot include gone.ww")
+        );
+    }
+
+    #[test]
+    fn linking_files_never_reads_from_disk() {
+        // Cargo runs tests from the crate root, where this file exists
+        let sources = link_files(&[file("main.ww", "include \"Cargo.toml\"\n")]);
+        assert_eq!(sources.source_map.files().len(), 1);
+        assert_eq!(sources.diagnostics.len(), 1);
+        assert_eq!(
+            sources.diagnostics[0].message,
+            "cannot include Cargo.toml: not among the given files"
+        );
+    }
+
+    #[test]
+    fn included_declarations_come_before_what_follows_the_include() {
+        let result = crate::compile_files(&[
+            file("main.ww", "include \"kael.ww\"\n\nKael is a character {}\n"),
+            file("kael.ww", "Kael is a character {}\n"),
+        ]);
+        let duplicate = &result.diagnostics[0];
+        assert!(duplicate.message.contains("Kael"), "{}", duplicate.message);
+        let (index, _) = result.source_map.to_local_span(&duplicate.span).unwrap();
+        assert_eq!(result.source_map.files()[index].name, "main.ww");
+    }
+}
@@ -0,0 +1,405 @@
+//! Incremental re-parsing for editors.
+//!
+//! An edit usually touches a single declaration. Rather than lexing and
+//! parsing the whole source again, [`compile_source_incremental`] re-lexes
+//! only the declarations the edit overlaps, splices them into the previous
+//! AST, and shifts the spans of everything after them.
+//!
+//! The previous AST must come from a parse without lex or parse errors, so
+//! the text between declarations holds nothing but whitespace and comments.
+//! If the edited region no longer parses on its own (e.g. a closing brace
+//! was deleted), the whole source is parsed again instead.
+
+use std::collections::BTreeSet;
+
+use crate::ast::{Declaration, SourceFile, Span, Spanned, Statement, Value};
+use crate::diagnostics::Diagnostic;
+use crate::lexer::{self, TokenStream};
+use crate::parser;
+
+/// A text change, described against the previous source.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceEdit {
+    /// Byte range of the replaced text in the previous source.
+    pub range: Span,
+    /// Length in bytes of the text that replaced it.
+    pub new_len: usize,
+}
+
+impl SourceEdit {
+    /// Describe the change between two versions of a source as one edit,
+    /// by trimming their common prefix and suffix.
+    pub fn between(old: &str, new: &str) -> Self {
+        let mut prefix = old
+            .bytes()
+            .zip(new.bytes())
+            .take_while(|(a, b)| a == b)
+            .count();
+        while !old.is_char_boundary(prefix) {
+            prefix -= 1;
+        }
+        let mut suffix = old
+            .bytes()
+            .rev()
+            .zip(new.bytes().rev())
+            .take(old.len().min(new.len()) - prefix)
+            .take_while(|(a, b)| a == b)
+            .count();
+        while !old.is_char_boundary(old.len() - suffix) {
+            suffix -= 1;
+        }
+        Self {
+            range: prefix..old.len() - suffix,
+            new_len: new.len() - suffix - prefix,
+        }
+    }
+
+    /// How far text after the edit moved.
+    fn delta(&self) -> isize {
+        self.new_len as isize - self.range.len() as isize
+    }
+}
+
+/// The result of [`compile_source_incremental`].
+#[derive(Debug, Clone)]
+pub struct IncrementalParse {
+    /// The patched AST, with spans into the new source.
+    pub ast: SourceFile,
+    /// Tokens of the re-lexed region, with spans into the new source.
+    pub tokens: TokenStream,
+    /// Byte range of the re-lexed region in the previous source.
+    pub replaced: Span,
+    /// Byte range of the re-lexed region in the new source.
+    pub reparsed: Span,
+    /// Names of the entities declared in the re-parsed region, before or
+    /// after the edit. Every other declaration only had its spans shifted.
+    pub invalidated: BTreeSet<String>,
+    /// Lex and parse errors, reported when the region failed to parse on its
+    /// own and the whole source was parsed again.
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl IncrementalParse {
+    /// Patch the previous source's tokens with the re-lexed region.
+    pub fn splice_tokens(
+        &self,
+        previous: &[(lexer::Token, std::ops::Range<usize>)],
+    ) -> TokenStream {
+        let delta = self.reparsed.len() as isize - self.replaced.len() as isize;
+        let before = previous
+            .iter()
+            .take_while(|(_, span)| span.end <= self.replaced.start)
+            .cloned();
+        let after = previous
+            .iter()
+            .skip_while(|(_, span)| span.start < self.replaced.end)
+            .map(|(token, span)| {
+                let mut span = span.clone();
+                shift(&mut span, delta);
+                (token.clone(), span)
+            });
+        before
+            .chain(self.tokens.iter().cloned())
+            .chain(after)
+            .collect()
+    }
+}
+
+/// Re-parse `source` after `edit`, reusing the untouched declarations of
+/// `previous`.
+///
+/// A declaration owns the text between it and the declaration before it,
+/// where its doc comment lives, so an edit there re-parses it as well.
+pub fn compile_source_incremental(
+    previous: &SourceFile,
+    source: &str,
+    edit: &SourceEdit,
+) -> IncrementalParse {
+    let delta = edit.delta();
+    let Some(old_len) = source.len().checked_add_signed(-delta) else {
+        return full_parse(previous, source, source.len());
+    };
+    if edit.range.end > old_len {
+        return full_parse(previous, source, old_len);
+    }
+
+    let decls = &previous.declarations;
+    let gap_start = |i: usize| if i == 0 { 0 } else { decls[i - 1].span.end };
+    let first = decls
+        .iter()
+        .position(|d| d.span.end >= edit.range.start)
+        .unwrap_or(decls.len());
+    let end = first
+        + (first..decls.len())
+            .take_while(|&i| gap_start(i) <= edit.range.end)
+            .count();
+
+    let mut start = gap_start(first);
+    let limit = decls
+        .get(first)
+        .map_or(edit.range.start, |d| d.span.start.min(edit.range.start));
+    // Start on a fresh line, so trailing comments after the previous `}` keep
+    // their position. Text before the edit is the same in both versions.
+    if let Some(newline) = source.get(start..limit).and_then(|s| s.find('\n')) {
+        start += newline + 1;
+    }
+    let old_end = match decls.get(end) {
+        Some(_) => decls[end - 1].span.end.max(edit.range.end),
+        None => old_len,
+    };
+    let Some(new_end) = old_end.checked_add_signed(delta) else {
+        return full_parse(previous, source, old_len);
+    };
+    let Some(text) = source.get(start..new_end) else {
+        return full_parse(previous, source, old_len);
+    };
+
+    let (mut tokens, lex_errors, mut comments) = lexer::lex_with_comments(text);
+    if !lex_errors.is_empty() {
+        return full_parse(previous, source, old_len);
+    }
+    for (_, span) in &mut tokens {
+        shift(span, start as isize);
+    }
+    for comment in &mut comments {
+        shift(&mut comment.span, start as isize);
+    }
+    let (mut region, parse_errors) = parser::parse_lenient(&tokens);
+    if !parse_errors.is_empty() {
+        return full_parse(previous, source, old_len);
+    }
+    parser::attach_doc_comments(&mut region, &tokens, &comments);
+
+    let invalidated = entity_names(&decls[first..end])
+        .chain(entity_names(&region.declarations))
+        .collect();
+
+    let mut declarations = decls[..first].to_vec();
+    declarations.append(&mut region.declarations);
+    declarations.extend(decls[end..].iter().cloned().map(|mut decl| {
+        shift_declaration(&mut decl, delta);
+        decl
+    }));
+
+    IncrementalParse {
+        ast: SourceFile { declarations },
+        tokens,
+        replaced: start..old_end,
+        reparsed: start..new_end,
+        invalidated,
+        diagnostics: Vec::new(),
+    }
+}
+
+/// Fall back to parsing all of `source`, invalidating every entity.
+fn full_parse(previous: &SourceFile, source: &str, old_len: usize) -> IncrementalParse {
+    let (tokens, lex_errors, comments) = lexer::lex_with_comments(source);
+    let (mut ast, parse_errors) = parser::parse_lenient(&tokens);
+    parser::attach_doc_comments(&mut ast, &tokens, &comments);
+
+    let mut diagnostics: Vec<Diagnostic> = lex_errors.into_iter().map(Diagnostic::from).collect();
+    diagnostics.extend(parse_errors.into_iter().map(Diagnostic::from));
+
+    IncrementalParse {
+        invalidated: entity_names(&previous.declarations)
+            .chain(entity_names(&ast.declarations))
+            .collect(),
+        ast,
+        tokens,
+        replaced: 0..old_len,
+        reparsed: 0..source.len(),
+        diagnostics,
+    }
+}
+
+fn entity_names(decls: &[Spanned<Declaration>]) -> impl Iterator<Item = String> + '_ {
+    decls.iter().filter_map(|decl| match &decl.node {
+        Declaration::Entity(entity) => Some(entity.name.node.clone()),
+        Declaration::World(_) => None,
+    })
+}
+
+fn shift(span: &mut Span, delta: isize) {
+    span.start = span.start.saturating_add_signed(delta);
+    span.end = span.end.saturating_add_signed(delta);
+}
+
+fn shift_declaration(decl: &mut Spanned<Declaration>, delta: isize) {
+    shift(&mut decl.span, delta);
+    match &mut decl.node {
+        Declaration::World(world) => {
+            shift(&mut world.name.span, delta);
+            shift_body(&mut world.body, delta);
+        }
+        Declaration::Entity(entity) => {
+            shift(&mut entity.name.span, delta);
+            shift(&mut entity.kind.span, delta);
+            for annotation in &mut entity.annotations {
+                shift(&mut annotation.span, delta);
+                for target in &mut annotation.node.targets {
+                    shift(&mut target.span, delta);
+                }
+            }
+            shift_body(&mut entity.body, delta);
+        }
+    }
+}
+
+fn shift_body(body: &mut [Spanned<Statement>], delta: isize) {
+    for stmt in body {
+        shift(&mut stmt.span, delta);
+        match &mut stmt.node {
+            Statement::Property(prop) => shift_value(&mut prop.value, delta),
+            Statement::Relationship(rel) => {
+                for target in &mut rel.targets {
+                    shift(&mut target.span, delta);
+                }
+            }
+            Statement::Exit(exit) => shift(&mut exit.target.span, delta),
+            Statement::Block(block) => shift_body(&mut block.body, delta),
+            Statement::Alias(names) => {
+                for name in names {
+                    shift(&mut name.span, delta);
+                }
+            }
+            Statement::Choice(choice) => {
+                if let Some(target) = &mut choice.target {
+                    shift(&mut target.span, delta);
+                }
+            }
+            Statement::Description(_)
+            | Statement::Date(_)
+            | Statement::Era(_)
+            | Statement::Requires(_)
+            | Statement::Effect(_) => {}
+        }
+    }
+}
+
+fn shift_value(value: &mut Value, delta: isize) {
+    if let Value::List(items) = value {
+        for item in items {
+            shift(&mut item.span, delta);
+            shift_value(&mut item.node, delta);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = r#"world "Test" {}
+
+-- The seat of the Order.
+the Keep is a fortress {
+    population 300
+    values [stone, "old walls"]
+}
+
+Kael is a character {
+    located at the Keep
+    stats {
+        strength 12
+    }
+}
+
+the Order is a faction {
+    based at the Keep
+}
+"#;
+
+    fn parse(source: &str) -> (SourceFile, TokenStream) {
+        let (tokens, lex_errors, comments) = lexer::lex_with_comments(source);
+        assert!(lex_errors.is_empty(), "lex errors: {lex_errors:?}");
+        let (mut ast, parse_errors) = parser::parse_lenient(&tokens);
+        assert!(parse_errors.is_empty(), "parse errors: {parse_errors:?}");
+        parser::attach_doc_comments(&mut ast, &tokens, &comments);
+        (ast, tokens)
+    }
+
+    /// Apply `edit` incrementally and check it matches a full parse.
+    fn reparse(old: &str, new: &str) -> IncrementalParse {
+        let (previous, previous_tokens) = parse(old);
+        let edit = SourceEdit::between(old, new);
+        let result = compile_source_incremental(&previous, new, &edit);
+
+        let (tokens, lex_errors, comments) = lexer::lex_with_comments(new);
+        let (mut expected, _) = parser::parse_lenient(&tokens);
+        parser::attach_doc_comments(&mut expected, &tokens, &comments);
+        assert!(lex_errors.is_empty());
+        assert_eq!(format!("{:?}", result.ast), format!("{expected:?}"));
+        assert_eq!(result.splice_tokens(&previous_tokens), tokens);
+        result
+    }
+
+    fn names(result: &IncrementalParse) -> Vec<&str> {
+        result.invalidated.iter().map(String::as_str).collect()
+    }
+
+    #[test]
+    fn edit_inside_a_declaration_reparses_only_it() {
+        let new = SOURCE.replace("strength 12", "strength 14\n        wits 9");
+        let result = reparse(SOURCE, &new);
+        assert_eq!(names(&result), ["Kael"]);
+        assert!(result.diagnostics.is_empty());
+        assert!(result.reparsed.start > SOURCE.find("old walls").unwrap());
+    }
+
+    #[test]
+    fn renaming_invalidates_old_and_new_name() {
+        let new = SOURCE.replace("the Order is", "the Silver Order is");
+        let result = reparse(SOURCE, &new);
+        assert_eq!(names(&result), ["the Order", "the Silver Order"]);
+    }
+
+    #[test]
+    fn doc_comment_belongs_to_the_next_declaration() {
+        let new = SOURCE.replace("seat of", "ancient seat of");
+        let result = reparse(SOURCE, &new);
+        assert_eq!(names(&result), ["the Keep"]);
+        let Declaration::Entity(keep) = &result.ast.declarations[1].node else {
+            panic!("expected the Keep");
+        };
+        assert_eq!(keep.doc.as_deref(), Some("The ancient seat of the Order."));
+    }
+
+    #[test]
+    fn appending_a_declaration() {
+        let new = format!("{SOURCE}\nthe Ashlands is a region {{}}\n");
+        let result = reparse(SOURCE, &new);
+        assert_eq!(names(&result), ["the Ashlands"]);
+    }
+
+    #[test]
+    fn unbalanced_edit_falls_back_to_a_full_parse() {
+        let (previous, _) = parse(SOURCE);
+        let new = SOURCE.replacen("    }\n}", "    }\n", 1);
+        let result =
+            compile_source_incremental(&previous, &new, &SourceEdit::between(SOURCE, &new));
+        assert_eq!(result.reparsed, 0..new.len());
+        assert_eq!(result.replaced, 0..SOURCE.len());
+        assert!(!result.diagnostics.is_empty());
+        assert!(result.invalidated.contains("the Keep"));
+    }
+
+    #[test]
+    fn edit_between_versions() {
+        let edit = SourceEdit::between("a {\n}\n", "a {\n  b 1\n}\n");
+        assert_eq!(
+            edit,
+            SourceEdit {
+                range: 4..4,
+                new_len: 6
+            }
+        );
+        let edit = SourceEdit::between("Kael", "Kaël");
+        assert_eq!(
+            edit,
+            SourceEdit {
+                range: 2..3,
+                new_len: 2
+            }
+        );
+    }
+}
@@ -22,6 +22,8 @@ pub enum Token {
     RParen,
     /// Comma separator `,`.
     Comma,
+    /// Arrow `->`, pointing a dialogue choice at its next node.
+    Arrow,
     /// Newline character (statement separator).
     Newline,
     /// Triple-quoted doc string (`"""..."""`).
@@ -36,6 +38,12 @@ pub enum Token {
     Float(f64, String),
     /// Bare word (identifier or keyword, disambiguated by the parser).
     Word(String),
+    /// Top-level `include "path"` statement, holding the path.
+    ///
+    /// Only recognized at the start of a line outside any braces. The parser
+    /// skips it; the included file is spliced in after it (see
+    /// [`crate::include`]).
+    Include(String),
 }
 
 impl fmt::Display for Token {
@@ -48,22 +56,26 @@ impl fmt::Display for Token {
             Token::LParen => write!(f, "("),
             Token::RParen => write!(f, ")"),
             Token::Comma => write!(f, ","),
+            Token::Arrow => write!(f, "->"),
             Token::Newline => write!(f, "newline"),
             Token::DocString(_) => write!(f, "doc string"),
             Token::Str(s) => write!(f, "\"{s}\""),
             Token::Integer(_, s) => write!(f, "{s}"),
             Token::Float(_, s) => write!(f, "{s}"),
             Token::Word(w) => write!(f, "{w}"),
+            Token::Include(path) => write!(f, "include \"{path}\""),
         }
     }
 }
 
 /// Internal logos token — borrows from source to avoid allocations during lexing.
 /// Converted to owned `Token` after lexing.
-#[derive(Logos, Debug)]
+#[derive(Logos, Debug, Clone)]
 #[logos(skip r"[ \t\r]+")]
-#[logos(skip r"--[^\n]*")]
 enum RawToken {
+    #[regex(r"--[^\n]*")]
+    Comment,
+
     #[token("{")]
     LBrace,
 
@@ -85,6 +97,9 @@ enum RawToken {
     #[token(",")]
     Comma,
 
+    #[token("->")]
+    Arrow,
+
     #[token("\n")]
     Newline,
 
@@ -113,27 +128,68 @@ pub struct LexError {
     pub message: String,
 }
 
+/// Tokens paired with their byte spans, as produced by [`lex`].
+pub type TokenStream = Vec<(Token, std::ops::Range<usize>)>;
+
+/// A `--` line comment, kept as trivia next to the token stream.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comment {
+    /// Comment text without the leading `--` and surrounding whitespace.
+    pub text: String,
+    /// Byte range of the comment (from `--` to the end of the line).
+    pub span: std::ops::Range<usize>,
+    /// True if nothing but whitespace precedes the comment on its line.
+    pub own_line: bool,
+}
+
 /// Lex source code into a sequence of `(Token, Span)` pairs.
 ///
 /// Returns the token stream and any lexer errors. Lexing continues past errors
 /// to collect as many tokens as possible (important for IDE/LSP support).
-pub fn lex(source: &str) -> (Vec<(Token, std::ops::Range<usize>)>, Vec<LexError>) {
+pub fn lex(source: &str) -> (TokenStream, Vec<LexError>) {
+    let (tokens, errors, _) = lex_with_comments(source);
+    (tokens, errors)
+}
+
+/// Like [`lex`], but also returns the line comments the token stream omits.
+///
+/// The parser never sees comments; they are only used to attach doc comments
+/// to declarations (see [`crate::parser::attach_doc_comments`]).
+pub fn lex_with_comments(source: &str) -> (TokenStream, Vec<LexError>, Vec<Comment>) {
     let mut tokens = Vec::new();
     let mut errors = Vec::new();
+    let mut comments = Vec::new();
     let mut lexer = RawToken::lexer(source);
+    let mut depth = 0usize;
 
     while let Some(result) = lexer.next() {
         let span = lexer.span();
         match result {
             Ok(raw) => {
                 let token = match raw {
-                    RawToken::LBrace => Token::LBrace,
-                    RawToken::RBrace => Token::RBrace,
+                    RawToken::Comment => {
+                        let own_line = matches!(tokens.last(), None | Some((Token::Newline, _)));
+                        comments.push(Comment {
+                            text: lexer.slice()[2..].trim().to_string(),
+                            span,
+                            own_line,
+                        });
+                        continue;
+                    }
+                    RawToken::LBrace => {
+                        depth += 1;
+                        Token::LBrace
+                    }
+                    RawToken::RBrace => {
+                        depth = depth.saturating_sub(1);
+                        Token::RBrace
+                    }
                     RawToken::LBracket => Token::LBracket,
                     RawToken::RBracket => Token::RBracket,
                     RawToken::LParen => Token::LParen,
                     RawToken::RParen => Token::RParen,
                     RawToken::Comma => Token::Comma,
+                    RawToken::Arrow => Token::Arrow,
                     RawToken::Newline => Token::Newline,
                     RawToken::DocStringStart => {
                         // Scan forward for closing """
@@ -142,7 +198,7 @@ pub fn lex(source: &str) -> (Vec<(Token, std::ops::Range<usize>)>, Vec<LexError>
                             Some(end_idx) => {
                                 let content = &remainder[..end_idx];
                                 lexer.bump(end_idx + 3);
-                                let full_span = span.start..lexer.span().start;
+                                let full_span = span.start..lexer.span().end;
                                 tokens.push((
                                     Token::DocString(content.trim().to_string()),
                                     full_span,
@@ -189,7 +245,22 @@ pub fn lex(source: &str) -> (Vec<(Token, std::ops::Range<usize>)>, Vec<LexError>
                             }
                         }
                     }
-                    RawToken::Word => Token::Word(lexer.slice().to_string()),
+                    RawToken::Word => {
+                        let at_line_start =
+                            matches!(tokens.last(), None | Some((Token::Newline, _)));
+                        if lexer.slice() == "include" && depth == 0 && at_line_start {
+                            let mut ahead = lexer.clone();
+                            if let Some(Ok(RawToken::Str)) = ahead.next() {
+                                let slice = ahead.slice();
+                                let path = unescape(&slice[1..slice.len() - 1]);
+                                let full_span = span.start..ahead.span().end;
+                                lexer = ahead;
+                                tokens.push((Token::Include(path), full_span));
+                                continue;
+                            }
+                        }
+                        Token::Word(lexer.slice().to_string())
+                    }
                 };
                 tokens.push((token, span));
             }
@@ -202,7 +273,7 @@ pub fn lex(source: &str) -> (Vec<(Token, std::ops::Range<usize>)>, Vec<LexError>
         }
     }
 
-    (tokens, errors)
+    (tokens, errors, comments)
 }
 
 /// Process escape sequences in a string literal.
@@ -299,6 +370,7 @@ mod tests {
         assert!(errors.is_empty(), "errors: {errors:?}");
         assert_eq!(tokens.len(), 1);
         assert!(matches!(&tokens[0].0, Token::DocString(s) if s == "Hello world.\nSecond line."));
+        assert_eq!(tokens[0].1, 0..source.len());
     }
 
     #[test]
@@ -313,6 +385,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn lex_arrow() {
+        let (tokens, errors) = lex("choice \"Leave\" -> farewell");
+        assert!(errors.is_empty());
+
+        let types: Vec<_> = tokens.iter().map(|(t, _)| format!("{t}")).collect();
+        assert_eq!(types, vec!["choice", "\"Leave\"", "->", "farewell"]);
+    }
+
     #[test]
     fn lex_comments_are_skipped() {
         let source = "-- This is a comment\nworld";
@@ -328,6 +409,37 @@ mod tests {
         assert!(matches!(&non_newline[0].0, Token::Word(w) if w == "world"));
     }
 
+    #[test]
+    fn lex_with_comments_keeps_trivia() {
+        let source = "-- Heading\nworld -- trailing\n";
+        let (tokens, errors, comments) = lex_with_comments(source);
+        assert!(errors.is_empty());
+        assert_eq!(tokens.len(), 3);
+        assert_eq!(comments.len(), 2);
+        assert_eq!(comments[0].text, "Heading");
+        assert!(comments[0].own_line);
+        assert_eq!(comments[1].text, "trailing");
+        assert!(!comments[1].own_line);
+        assert_eq!(&source[comments[1].span.clone()], "-- trailing");
+    }
+
+    #[test]
+    fn lex_include_only_at_top_level_line_start() {
+        let source = "include \"shared/factions.ww\"\nKael is a character {\n    include \"x\"\n}";
+        let (tokens, errors) = lex(source);
+        assert!(errors.is_empty());
+        assert!(matches!(&tokens[0].0, Token::Include(p) if p == "shared/factions.ww"));
+        assert_eq!(tokens[0].1, 0..28);
+        let includes = tokens
+            .iter()
+            .filter(|(t, _)| matches!(t, Token::Include(_)))
+            .count();
+        assert_eq!(includes, 1);
+
+        let (tokens, _) = lex("the include \"x\"");
+        assert!(tokens.iter().all(|(t, _)| !matches!(t, Token::Include(_))));
+    }
+
     #[test]
     fn lex_float() {
         let (tokens, errors) = lex("3.14");
@@ -74,6 +74,8 @@
 //! | `<Name> is <kind> { ... }` | Entity declaration (no article) |
 //! | `<key> <value>` | Property assignment |
 //! | `<key> [a, b, c]` | List property |
+//! | `tags [a, b, c]` | Entity tags (for search and bulk organization) |
+//! | `alias [<Name>, ...]` | Alternate names that references may use |
 //! | `<direction> to <Entity>` | Exit/connection (north, south, east, west, up, down) |
 //! | `member of <Entity>` | Relationship: membership |
 //! | `located at <Entity>` | Relationship: location |
@@ -86,9 +88,12 @@
 //! | `involving [<Entity>, ...]` | Relationship: participation |
 //! | `references [<Entity>, ...]` | Relationship: reference |
 //! | `caused by <Entity>` | Relationship: causation |
+//! | `... since year N`, `until year N`, `strength N` | Relationship attributes, in any order after the target |
 //! | `date year N, month N, day N, era "E"` | Date (year required, month/day/era optional) |
+//! | `era "E" starts year N` | Era start offset (world block only); dates in the era count from N |
 //! | `"""..."""` | Multiline description (Markdown) |
-//! | `-- comment` | Line comment |
+//! | `include "path.ww"` | Include another file (top level only) |
+//! | `-- comment` | Line comment (directly above an entity: its doc comment) |
 //! | `"string"` | Quoted string value |
 //! | `45_000`, `-1247` | Numbers (Rust-style underscores allowed) |
 //!
@@ -107,26 +112,64 @@
 //! The compiler reads all `.ww` files in a directory. File boundaries don't
 //! matter — entities can reference each other across files. See `iron-kingdoms/`
 //! for an example world split across multiple files.
+//!
+//! Files elsewhere, such as fragments shared between worlds, are pulled in
+//! with a top-level `include` statement. The path is relative to the file
+//! that contains it; each file is read only once, and files that include
+//! each other in a cycle are reported as an error:
+//!
+//! ```ww
+//! include "shared/factions.ww"
+//! ```
+//!
+//! An included file's declarations take the place of the `include`, as if
+//! they were written there. Every entry point follows includes: the
+//! in-memory ones ([`compile_source`], [`compile_files`]) only among the
+//! files they are given, without touching the disk (see
+//! [`include::link_files`]), and [`compile_dir`] reads the rest from under
+//! its directory (see [`include::load_sources`]).
 
 /// Abstract syntax tree types produced by the parser.
 pub mod ast;
+/// Stateful, in-memory incremental compilation for editors and embedders.
+pub mod builder;
 /// Compiler that transforms AST declarations into a `ww_core::World`.
 pub mod compiler;
 /// Diagnostic types and pretty-printing for errors and warnings.
 pub mod diagnostics;
+/// Source-rewriting autofix passes (e.g. reciprocal relationships).
+pub mod fix;
+/// Canonical source formatting.
+pub mod format;
+/// Following `include` statements and splicing included files in place.
+pub mod include;
+/// Re-parse only the declarations an edit touches.
+pub mod incremental;
 /// Tokenizer (lexer) for `.ww` source files.
 pub mod lexer;
 /// Chumsky-based parser that turns token streams into an AST.
 pub mod parser;
+/// Render compiled entities back to `.ww` source.
+pub mod render;
 /// Cross-file name resolver and source-map utilities.
 pub mod resolver;
 
+use std::collections::HashMap;
 use std::path::Path;
+use std::time::Instant;
 
+/// Re-export of [`builder::WorldBuilder`] for convenience.
+pub use builder::WorldBuilder;
 /// Re-export of [`compiler::CompileResult`] for convenience.
 pub use compiler::CompileResult;
+/// Re-export of [`compiler::CompileTimings`] for convenience.
+pub use compiler::CompileTimings;
 /// Re-export of [`diagnostics::Diagnostic`] for convenience.
 pub use diagnostics::Diagnostic;
+/// Re-export of [`diagnostics::DiagnosticCode`] for convenience.
+pub use diagnostics::DiagnosticCode;
+/// Re-exports of the [`incremental`] re-parsing API for convenience.
+pub use incremental::{IncrementalParse, SourceEdit, compile_source_incremental};
 /// Re-export of [`resolver::SourceMap`] for convenience.
 pub use resolver::SourceMap;
 
@@ -138,10 +181,42 @@ pub struct InputFile {
     pub text: String,
 }
 
+/// Options controlling how source is compiled.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CompileOptions {
+    /// Recover from parse errors at entity boundaries instead of giving up.
+    ///
+    /// When set, declarations that parse cleanly are still compiled into the
+    /// world and parse errors are reported alongside them as diagnostics (see
+    /// [`parser::parse_lenient`]). When unset, any parse error yields an empty
+    /// world.
+    pub lenient: bool,
+}
+
+impl CompileOptions {
+    /// Options for best-effort compilation, as used by the LSP.
+    pub fn lenient() -> Self {
+        Self { lenient: true }
+    }
+}
+
 /// Compile a single source string into a World.
 pub fn compile_source(source: &str) -> CompileResult {
-    let source_map = resolver::SourceMap::single(source.len());
-    compile_with_source_map(source, source_map)
+    compile_source_with_options(source, CompileOptions::default())
+}
+
+/// Compile a single source string into a World with explicit [`CompileOptions`].
+///
+/// The source cannot include other files; any `include` is reported as a
+/// diagnostic.
+pub fn compile_source_with_options(source: &str, options: CompileOptions) -> CompileResult {
+    let file = InputFile {
+        name: "<source>".to_string(),
+        text: source.to_string(),
+    };
+    let started = Instant::now();
+    let sources = include::link_files(&[file]);
+    compile_sources(sources, started.elapsed(), options)
 }
 
 /// Compile multiple named source files into a single World.
@@ -150,126 +225,194 @@ pub fn compile_source(source: &str) -> CompileResult {
 /// (e.g., from the LSP). The returned [`CompileResult`] includes a
 /// [`SourceMap`] that maps diagnostic spans back to individual files.
 pub fn compile_files(files: &[InputFile]) -> CompileResult {
-    let mut concatenated = String::new();
-    let mut source_map = resolver::SourceMap::new();
+    compile_files_with_options(files, CompileOptions::default())
+}
 
-    for file in files {
-        if !concatenated.is_empty() {
-            concatenated.push('\n');
-        }
-        let offset = concatenated.len();
-        source_map.add_file(file.name.clone(), offset, file.text.len());
-        concatenated.push_str(&file.text);
-    }
+/// Compile multiple named source files with explicit [`CompileOptions`].
+///
+/// File names double as paths for includes, and an included file must be
+/// among `files`: nothing is read from disk, and includes of other files are
+/// reported as diagnostics.
+pub fn compile_files_with_options(files: &[InputFile], options: CompileOptions) -> CompileResult {
+    let started = Instant::now();
+    let sources = include::link_files(files);
+    let lex = started.elapsed();
 
-    if concatenated.is_empty() {
+    if sources.text.is_empty() {
         return CompileResult {
             world: ww_core::World::new(ww_core::WorldMeta::new("Empty")),
-            diagnostics: vec![Diagnostic::error(0..0, "no source files provided")],
-            source_map,
+            diagnostics: vec![
+                Diagnostic::error(0..0, "no source files provided")
+                    .with_code(DiagnosticCode::Source),
+            ],
+            source_map: sources.source_map,
+            timings: CompileTimings::default(),
+            entity_spans: HashMap::new(),
         };
     }
 
-    compile_with_source_map(&concatenated, source_map)
+    compile_sources(sources, lex, options)
 }
 
 /// Compile all `.ww` files in a directory into a single World.
 pub fn compile_dir(dir: &Path) -> CompileResult {
-    let mut sources = String::new();
-    let mut source_map = resolver::SourceMap::new();
+    compile_dir_with_options(dir, CompileOptions::default())
+}
+
+/// Compile all `.ww` files in a directory with explicit [`CompileOptions`].
+pub fn compile_dir_with_options(dir: &Path, options: CompileOptions) -> CompileResult {
+    let started = Instant::now();
+    let sources = match load_dir(dir) {
+        Ok(sources) => sources,
+        Err(diagnostic) => {
+            return CompileResult {
+                world: ww_core::World::new(ww_core::WorldMeta::new("Error")),
+                diagnostics: vec![diagnostic],
+                source_map: SourceMap::new(),
+                timings: CompileTimings::default(),
+                entity_spans: HashMap::new(),
+            };
+        }
+    };
+    let lex = started.elapsed();
+
+    if sources.text.is_empty() {
+        return CompileResult {
+            world: ww_core::World::new(ww_core::WorldMeta::new("Empty")),
+            diagnostics: vec![
+                Diagnostic::error(0..0, format!("no .ww files found in {}", dir.display()))
+                    .with_code(DiagnosticCode::Source),
+            ],
+            source_map: sources.source_map,
+            timings: CompileTimings::default(),
+            entity_spans: HashMap::new(),
+        };
+    }
+
+    compile_sources(sources, lex, options)
+}
+
+/// The combined source text that [`compile_dir`] compiles, with included
+/// files after the files that include them, so diagnostic spans can be
+/// rendered against it.
+///
+/// Returns an empty string if the directory or one of its files cannot be
+/// read.
+pub fn read_dir_sources(dir: &Path) -> String {
+    load_dir(dir)
+        .map(|sources| sources.text)
+        .unwrap_or_default()
+}
 
+/// Read every `.ww` file in `dir`, in path order, and the files they include.
+///
+/// A directory or top-level file that cannot be read is returned as an error
+/// diagnostic.
+fn load_dir(dir: &Path) -> Result<include::Sources, Diagnostic> {
+    let files = read_dir_files(dir)?;
+    Ok(include::load_sources(dir, &files))
+}
+
+/// Read the top-level `.ww` files in `dir`, in path order, as [`compile_dir`]
+/// does. The files they include are not read.
+///
+/// A directory or file that cannot be read is returned as an error
+/// diagnostic.
+pub fn read_dir_files(dir: &Path) -> Result<Vec<InputFile>, Diagnostic> {
     let mut entries: Vec<_> = match std::fs::read_dir(dir) {
         Ok(entries) => entries
             .filter_map(|e| e.ok())
             .filter(|e| e.path().extension().is_some_and(|ext| ext == "ww"))
             .collect(),
         Err(e) => {
-            return CompileResult {
-                world: ww_core::World::new(ww_core::WorldMeta::new("Error")),
-                diagnostics: vec![Diagnostic::error(
-                    0..0,
-                    format!("cannot read directory: {e}"),
-                )],
-                source_map,
-            };
+            return Err(
+                Diagnostic::error(0..0, format!("cannot read directory: {e}"))
+                    .with_code(DiagnosticCode::Source),
+            );
         }
     };
-
     // Sort for deterministic ordering
     entries.sort_by_key(|e| e.path());
 
+    let mut files = Vec::new();
     for entry in entries {
-        match std::fs::read_to_string(entry.path()) {
-            Ok(content) => {
-                if !sources.is_empty() {
-                    sources.push('\n');
-                }
-                let offset = sources.len();
-                let len = content.len();
-                let file_name = entry
-                    .path()
-                    .file_name()
-                    .map(|n| n.to_string_lossy().to_string())
-                    .unwrap_or_else(|| entry.path().display().to_string());
-                source_map.add_file(file_name, offset, len);
-                sources.push_str(&content);
-            }
-            Err(e) => {
+        let text = std::fs::read_to_string(entry.path()).map_err(|e| {
+            Diagnostic::error(0..0, format!("cannot read {}: {e}", entry.path().display()))
+                .with_code(DiagnosticCode::Source)
+        })?;
+        files.push(InputFile {
+            name: entry.file_name().to_string_lossy().into_owned(),
+            text,
+        });
+    }
+    Ok(files)
+}
+
+/// Internal: compile gathered sources; `lex` is how long gathering took.
+fn compile_sources(
+    sources: include::Sources,
+    lex: std::time::Duration,
+    options: CompileOptions,
+) -> CompileResult {
+    let include::Sources {
+        source_map,
+        tokens,
+        comments,
+        diagnostics,
+        ..
+    } = sources;
+    let mut result = compile_tokens(&tokens, &comments, diagnostics, source_map, options);
+    result.timings.lex = lex;
+    result
+}
+
+/// Internal: parse, resolve, and compile an already-lexed token stream.
+///
+/// `diagnostics` holds lex errors; they are reported before everything else.
+/// `comments` supply the doc comments attached to entity declarations.
+pub(crate) fn compile_tokens(
+    tokens: &[(lexer::Token, std::ops::Range<usize>)],
+    comments: &[lexer::Comment],
+    mut diagnostics: Vec<Diagnostic>,
+    source_map: resolver::SourceMap,
+    options: CompileOptions,
+) -> CompileResult {
+    let mut timings = CompileTimings::default();
+    let started = Instant::now();
+    let mut ast = if options.lenient {
+        let (ast, parse_errors) = parser::parse_lenient(tokens);
+        diagnostics.extend(parse_errors.into_iter().map(Diagnostic::from));
+        ast
+    } else {
+        match parser::parse(tokens) {
+            Ok(ast) => ast,
+            Err(parse_errors) => {
+                diagnostics.extend(parse_errors.into_iter().map(Diagnostic::from));
+                timings.parse = started.elapsed();
                 return CompileResult {
                     world: ww_core::World::new(ww_core::WorldMeta::new("Error")),
-                    diagnostics: vec![Diagnostic::error(
-                        0..0,
-                        format!("cannot read {}: {e}", entry.path().display()),
-                    )],
+                    diagnostics,
                     source_map,
+                    timings,
+                    entity_spans: HashMap::new(),
                 };
             }
         }
-    }
-
-    if sources.is_empty() {
-        return CompileResult {
-            world: ww_core::World::new(ww_core::WorldMeta::new("Empty")),
-            diagnostics: vec![Diagnostic::error(
-                0..0,
-                format!("no .ww files found in {}", dir.display()),
-            )],
-            source_map,
-        };
-    }
-
-    compile_with_source_map(&sources, source_map)
-}
-
-/// Internal: compile a source string with an explicit SourceMap.
-fn compile_with_source_map(source: &str, source_map: resolver::SourceMap) -> CompileResult {
-    let (tokens, lex_errors) = lexer::lex(source);
-
-    let mut diagnostics: Vec<Diagnostic> = lex_errors
-        .into_iter()
-        .map(|e| Diagnostic::error(e.span, e.message))
-        .collect();
-
-    let ast = match parser::parse(&tokens) {
-        Ok(ast) => ast,
-        Err(parse_errors) => {
-            diagnostics.extend(
-                parse_errors
-                    .into_iter()
-                    .map(|e| Diagnostic::error(e.span, e.message)),
-            );
-            return CompileResult {
-                world: ww_core::World::new(ww_core::WorldMeta::new("Error")),
-                diagnostics,
-                source_map,
-            };
-        }
     };
+    parser::attach_doc_comments(&mut ast, tokens, comments);
+    timings.parse = started.elapsed();
 
+    let started = Instant::now();
     let resolver = resolver::Resolver::resolve(&ast, &source_map);
+    timings.resolve = Some(started.elapsed());
+
+    let started = Instant::now();
     let mut result = compiler::compile(&ast, &resolver, source_map);
+    timings.compile = Some(started.elapsed());
+
     // Prepend lex/parse errors before resolve/compile diagnostics
     diagnostics.append(&mut result.diagnostics);
     result.diagnostics = diagnostics;
+    result.timings = timings;
     result
 }
@@ -2,7 +2,8 @@ use chumsky::input::{Stream, ValueInput};
 use chumsky::prelude::*;
 
 use crate::ast::*;
-use crate::lexer::Token;
+use crate::lexer::{Comment, Token};
+use crate::render::quote;
 
 type Span = SimpleSpan;
 
@@ -26,7 +27,7 @@ fn spanned<T>(node: T, span: Span) -> Spanned<T> {
     }
 }
 
-fn is_direction(w: &str) -> bool {
+pub(crate) fn is_direction(w: &str) -> bool {
     matches!(
         w,
         "north"
@@ -136,13 +137,53 @@ where
 
     // -- Statements --
 
+    // Relationship target: an entity name that stops before attribute clauses
+    let rel_target_token = choice((
+        select! {
+            Token::Word(ref w) if !matches!(w.as_str(), "since" | "until" | "strength") => w.clone()
+        },
+        select! { Token::Integer(_, ref s) => s.clone() },
+        select! { Token::Float(_, ref s) => s.clone() },
+    ));
+    let rel_target = choice((
+        string_lit.map_with(|s, e| spanned(s, e.span())),
+        rel_target_token
+            .repeated()
+            .at_least(1)
+            .collect::<Vec<String>>()
+            .map_with(|words, e| spanned(words.join(" "), e.span())),
+    ))
+    .labelled("entity name")
+    .boxed();
+
+    // Relationship attributes: "since year N", "until year N", "strength N", in any order
+    let rel_attributes = choice((
+        kw("since")
+            .then(kw("year"))
+            .ignore_then(integer)
+            .map(RelationshipAttribute::Since),
+        kw("until")
+            .then(kw("year"))
+            .ignore_then(integer)
+            .map(RelationshipAttribute::Until),
+        kw("strength")
+            .ignore_then(integer)
+            .map(RelationshipAttribute::Strength),
+    ))
+    .labelled("relationship attribute")
+    .repeated()
+    .collect::<Vec<_>>()
+    .boxed();
+
     // Relationship: "in <name>"
     let rel_in = kw("in")
-        .ignore_then(name_ref)
-        .map(|target| {
+        .ignore_then(rel_target.clone())
+        .then(rel_attributes.clone())
+        .map(|(target, attributes)| {
             Statement::Relationship(RelationshipStmt {
                 keyword: RelationshipKeyword::In,
                 targets: vec![target],
+                attributes,
             })
         })
         .labelled("containment");
@@ -151,11 +192,13 @@ where
     let rel_two = |first: &'static str, second: &'static str, keyword: RelationshipKeyword| {
         kw(first)
             .then(kw(second))
-            .ignore_then(name_ref)
-            .map(move |target| {
+            .ignore_then(rel_target.clone())
+            .then(rel_attributes.clone())
+            .map(move |(target, attributes)| {
                 Statement::Relationship(RelationshipStmt {
                     keyword: keyword.clone(),
                     targets: vec![target],
+                    attributes,
                 })
             })
     };
@@ -173,10 +216,12 @@ where
                         nl.clone().then(just(Token::RBracket)),
                     ),
             )
-            .map(move |targets| {
+            .then(rel_attributes.clone())
+            .map(move |(targets, attributes)| {
                 Statement::Relationship(RelationshipStmt {
                     keyword: keyword.clone(),
                     targets,
+                    attributes,
                 })
             })
     };
@@ -194,7 +239,24 @@ where
         rel_list("involving", RelationshipKeyword::Involving),
         rel_list("references", RelationshipKeyword::References),
     ))
-    .labelled("relationship");
+    .labelled("relationship")
+    .boxed();
+
+    // Alias: "alias <name>" or "alias [<name>, ...]"
+    let alias_stmt = kw("alias")
+        .ignore_then(choice((
+            name_in_list
+                .separated_by(just(Token::Comma).then(nl.clone()))
+                .at_least(1)
+                .collect::<Vec<Spanned<String>>>()
+                .delimited_by(
+                    just(Token::LBracket).then(nl.clone()),
+                    nl.clone().then(just(Token::RBracket)),
+                ),
+            name_ref.map(|name| vec![name]),
+        )))
+        .map(Statement::Alias)
+        .labelled("alias");
 
     // Exit: "north to <name>"
     let exit_stmt = select! { Token::Word(ref w) if is_direction(w.as_str()) => w.clone() }
@@ -250,6 +312,15 @@ where
         })
         .labelled("date");
 
+    // Era: "era "Age of Ruin" starts year -2000"
+    let era_stmt = kw("era")
+        .ignore_then(string_lit)
+        .then_ignore(kw("starts"))
+        .then_ignore(kw("year"))
+        .then(integer)
+        .map(|(name, start)| Statement::Era(EraDecl { name, start }))
+        .labelled("era");
+
     // Description: """..."""
     let description = doc_string
         .map(Statement::Description)
@@ -266,6 +337,56 @@ where
         })
         .labelled("property");
 
+    // Flag: a bare "standalone" line reads as "standalone true"
+    let flag = kw("standalone")
+        .to(Statement::Property(Property {
+            key: "standalone".to_string(),
+            value: Value::Boolean(true),
+        }))
+        .labelled("flag");
+
+    // Dialogue condition: the words, strings and numbers up to the end of the line
+    let condition = choice((
+        word,
+        string_lit.map(|s| quote(&s)),
+        select! { Token::Integer(_, ref s) => s.clone() },
+        select! { Token::Float(_, ref s) => s.clone() },
+    ))
+    .repeated()
+    .at_least(1)
+    .collect::<Vec<String>>()
+    .map(|parts| parts.join(" "))
+    .labelled("condition");
+
+    // Requires: "requires <condition>"
+    let requires_stmt = kw("requires")
+        .ignore_then(condition)
+        .map(Statement::Requires)
+        .labelled("requires");
+
+    // Effect: "gives <item>", "takes <item>", "sets flag <key>", "clears flag <key>"
+    let effect_stmt = select! {
+        Token::Word(w) if matches!(w.as_str(), "gives" | "takes" | "sets" | "clears") => w
+    }
+    .then(condition)
+    .map(|(verb, args)| Statement::Effect(format!("{verb} {args}")))
+    .labelled("effect");
+
+    // Choice: "choice "<text>" [-> <node>] [requires <condition>]"
+    let choice_target = choice((string_lit, word)).map_with(|n, e| spanned(n, e.span()));
+    let choice_stmt = kw("choice")
+        .ignore_then(string_lit)
+        .then(just(Token::Arrow).ignore_then(choice_target).or_not())
+        .then(kw("requires").ignore_then(condition).or_not())
+        .map(|((text, target), requires)| {
+            Statement::Choice(ChoiceStmt {
+                text,
+                target,
+                requires,
+            })
+        })
+        .labelled("choice");
+
     // Statement: recursive to support nested blocks
     let statement = recursive(|stmt| {
         // Nested block: word [string] { statements }
@@ -283,14 +404,21 @@ where
             .map(|((name, arg), body)| Statement::Block(BlockStmt { name, arg, body }))
             .labelled("nested block");
 
-        // Try alternatives in order. Block before property to avoid ambiguity.
+        // Try alternatives in order. Block before property to avoid ambiguity,
+        // and before choice lines so `choice "..." { }` stays a block.
         choice((
             relationship,
+            alias_stmt,
             exit_stmt,
             date_stmt,
+            era_stmt,
             description,
             block_stmt,
+            choice_stmt,
+            requires_stmt,
+            effect_stmt,
             property,
+            flag,
         ))
         .map_with(|stmt, e| spanned(stmt, e.span()))
     });
@@ -378,6 +506,7 @@ where
                 annotations,
                 kind,
                 body,
+                doc: None,
             })
         })
         .labelled("entity declaration");
@@ -400,40 +529,55 @@ where
 
 /// Parse a token stream into an AST.
 pub fn parse(tokens: &[(Token, std::ops::Range<usize>)]) -> Result<SourceFile, Vec<ParseError>> {
-    let token_iter = tokens
-        .iter()
-        .map(|(tok, span)| (tok.clone(), Span::from(span.clone())));
-
-    let len = tokens.last().map_or(0, |(_, s)| s.end);
-    let eoi: Span = (len..len).into();
-    let stream = Stream::from_iter(token_iter).map(eoi, |(t, s): (_, _)| (t, s));
-
-    let (output, errors) = source_file_parser().parse(stream).into_output_errors();
+    match run_parser(tokens) {
+        (Some(ast), errors) if errors.is_empty() => Ok(ast),
+        (_, errors) => Err(errors),
+    }
+}
 
+/// Parse a token stream leniently — always returns an AST (possibly partial)
+/// alongside any errors. Useful for IDE/LSP contexts where partial results
+/// are better than nothing.
+///
+/// When the file as a whole fails to parse, the token stream is split at
+/// top-level declaration boundaries (a closing `}` at brace depth zero) and
+/// each declaration is parsed on its own. Declarations that parse cleanly are
+/// kept; the others contribute their errors. A declaration missing its closing
+/// brace swallows everything after it, since no boundary can be found.
+pub fn parse_lenient(tokens: &[(Token, std::ops::Range<usize>)]) -> (SourceFile, Vec<ParseError>) {
+    let (output, errors) = run_parser(tokens);
     if let Some(ast) = output
         && errors.is_empty()
     {
-        return Ok(ast);
+        return (ast, errors);
     }
 
-    Err(errors
-        .into_iter()
-        .map(|e| {
-            let span = e.span();
-            ParseError {
-                span: span.into_range(),
-                message: e.to_string(),
+    let mut declarations = Vec::new();
+    let mut recovered_errors = Vec::new();
+    for chunk in split_declarations(tokens) {
+        match run_parser(chunk) {
+            (Some(ast), chunk_errors) if chunk_errors.is_empty() => {
+                declarations.extend(ast.declarations);
             }
-        })
-        .collect())
+            (_, chunk_errors) => recovered_errors.extend(chunk_errors),
+        }
+    }
+
+    // Splitting can only hide errors, never invent them: fall back to the
+    // whole-file errors if every chunk parsed cleanly on its own.
+    if recovered_errors.is_empty() {
+        recovered_errors = errors;
+    }
+
+    (SourceFile { declarations }, recovered_errors)
 }
 
-/// Parse a token stream leniently — always returns an AST (possibly partial)
-/// alongside any errors. Useful for IDE/LSP contexts where partial results
-/// are better than no results.
-pub fn parse_lenient(tokens: &[(Token, std::ops::Range<usize>)]) -> (SourceFile, Vec<ParseError>) {
+/// Run the source-file parser, returning its (possibly absent) output and errors.
+fn run_parser(tokens: &[(Token, std::ops::Range<usize>)]) -> (Option<SourceFile>, Vec<ParseError>) {
+    // Includes are resolved before parsing; the grammar never sees them.
     let token_iter = tokens
         .iter()
+        .filter(|(tok, _)| !matches!(tok, Token::Include(_)))
         .map(|(tok, span)| (tok.clone(), Span::from(span.clone())));
 
     let len = tokens.last().map_or(0, |(_, s)| s.end);
@@ -442,10 +586,6 @@ pub fn parse_lenient(tokens: &[(Token, std::ops::Range<usize>)]) -> (SourceFile,
 
     let (output, errors) = source_file_parser().parse(stream).into_output_errors();
 
-    let ast = output.unwrap_or(SourceFile {
-        declarations: vec![],
-    });
-
     let errors = errors
         .into_iter()
         .map(|e| {
@@ -457,7 +597,91 @@ pub fn parse_lenient(tokens: &[(Token, std::ops::Range<usize>)]) -> (SourceFile,
         })
         .collect();
 
-    (ast, errors)
+    (output, errors)
+}
+
+/// Split a token stream into top-level declaration chunks.
+///
+/// A chunk ends at a `}` that brings the brace depth back to zero. Trailing
+/// tokens without a closing brace form a final chunk.
+fn split_declarations(
+    tokens: &[(Token, std::ops::Range<usize>)],
+) -> Vec<&[(Token, std::ops::Range<usize>)]> {
+    let mut chunks = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+
+    for (i, (tok, _)) in tokens.iter().enumerate() {
+        match tok {
+            Token::LBrace => depth += 1,
+            Token::RBrace => {
+                depth = depth.saturating_sub(1);
+                if depth == 0 {
+                    chunks.push(&tokens[start..=i]);
+                    start = i + 1;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let rest = &tokens[start..];
+    if rest.iter().any(|(tok, _)| *tok != Token::Newline) {
+        chunks.push(rest);
+    }
+
+    chunks
+}
+
+/// Attach `--` comment blocks to the entity declarations they directly precede.
+///
+/// A doc comment is a run of own-line comments on consecutive lines, the last
+/// of which sits on the line right above the declaration. A blank line, or
+/// code on the same line as a comment, breaks the run.
+pub fn attach_doc_comments(
+    ast: &mut SourceFile,
+    tokens: &[(Token, std::ops::Range<usize>)],
+    comments: &[Comment],
+) {
+    // Tokens of spliced includes are out of order; search a sorted copy
+    let sorted;
+    let tokens = if tokens.is_sorted_by_key(|(_, s)| s.start) {
+        tokens
+    } else {
+        sorted = {
+            let mut tokens = tokens.to_vec();
+            tokens.sort_by_key(|(_, s)| s.start);
+            tokens
+        };
+        &sorted
+    };
+    for decl in &mut ast.declarations {
+        let Declaration::Entity(entity) = &mut decl.node else {
+            continue;
+        };
+        let Ok(mut idx) = tokens.binary_search_by_key(&entity.name.span.start, |(_, s)| s.start)
+        else {
+            continue;
+        };
+
+        let mut lines = Vec::new();
+        while idx > 0 && tokens[idx - 1].0 == Token::Newline {
+            let line_end = tokens[idx - 1].1.start;
+            let Ok(c) = comments.binary_search_by_key(&line_end, |c| c.span.end) else {
+                break;
+            };
+            if !comments[c].own_line {
+                break;
+            }
+            lines.push(comments[c].text.as_str());
+            idx -= 1;
+        }
+
+        if !lines.is_empty() {
+            lines.reverse();
+            entity.doc = Some(lines.join("\n"));
+        }
+    }
 }
 
 #[cfg(test)]
@@ -544,6 +768,33 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_relationship_attributes() {
+        let ast = parse_source(
+            "Kael is a character {\n    allied with Elara of the Vale strength 3 since year 1203\n    rival of Marek\n}",
+        )
+        .unwrap();
+
+        let Declaration::Entity(e) = &ast.declarations[0].node else {
+            panic!("expected entity declaration");
+        };
+        let Statement::Relationship(allied) = &e.body[0].node else {
+            panic!("expected relationship, got {:?}", e.body[0].node);
+        };
+        assert_eq!(allied.targets[0].node, "Elara of the Vale");
+        assert_eq!(
+            allied.attributes,
+            vec![
+                RelationshipAttribute::Strength(3),
+                RelationshipAttribute::Since(1203)
+            ]
+        );
+        let Statement::Relationship(rival) = &e.body[1].node else {
+            panic!("expected relationship, got {:?}", e.body[1].node);
+        };
+        assert!(rival.attributes.is_empty());
+    }
+
     #[test]
     fn parse_relationship_in() {
         let ast = parse_source("the Citadel is a fortress {\n    in the Ashlands\n}").unwrap();
@@ -921,6 +1172,69 @@ the Citadel is a fortress {
         );
     }
 
+    #[test]
+    fn recover_lenient_keeps_valid_declarations() {
+        let source = "\
+Kael is a character {
+    species human
+}
+
+Broken is {
+    species elf
+}
+
+Elara is a character {
+    status alive
+}";
+        let (ast, errors) = parse_source_lenient(source);
+        assert!(!errors.is_empty());
+        let names: Vec<_> = ast
+            .declarations
+            .iter()
+            .filter_map(|d| match &d.node {
+                Declaration::Entity(e) => Some(e.name.node.as_str()),
+                Declaration::World(_) => None,
+            })
+            .collect();
+        assert_eq!(names, vec!["Kael", "Elara"]);
+        // Errors point into the broken declaration, not the valid ones.
+        assert!(
+            errors
+                .iter()
+                .all(|e| e.span.start >= source.find("Broken").unwrap())
+        );
+        assert!(
+            errors
+                .iter()
+                .all(|e| e.span.end <= source.find("Elara").unwrap())
+        );
+    }
+
+    #[test]
+    fn recover_lenient_nested_blocks_stay_together() {
+        let source = "\
+Kael is a character {
+    dialogue \"greeting\" {
+        text \"Hello.\"
+    }
+}
+
+Elara is a character {
+    traits [brave
+}";
+        let (ast, errors) = parse_source_lenient(source);
+        assert!(!errors.is_empty());
+        assert_eq!(ast.declarations.len(), 1);
+    }
+
+    #[test]
+    fn recover_lenient_clean_source_matches_parse() {
+        let source = "Kael is a character {\n    species human\n}\n";
+        let (ast, errors) = parse_source_lenient(source);
+        assert!(errors.is_empty());
+        assert_eq!(ast.declarations.len(), 1);
+    }
+
     // -- Block with string argument tests --
 
     #[test]
@@ -1006,6 +1320,63 @@ the Citadel is a fortress {
         }
     }
 
+    #[test]
+    fn parse_dialogue_lines() {
+        let source = r#"Kael is a character {
+    dialogue {
+        node greeting
+        say "Well met."
+        choice "Ask about the road" -> road
+        choice "Show the seal" -> "seal" requires has_item "Royal Seal"
+        choice "Leave"
+        sets flag "met_kael"
+
+        node road
+        requires visited "the Crypt"
+        say "The road is washed out."
+    }
+}"#;
+        let ast = parse_source(source).unwrap();
+
+        let Declaration::Entity(e) = &ast.declarations[0].node else {
+            panic!("expected entity");
+        };
+        let Statement::Block(dlg) = &e.body[0].node else {
+            panic!("expected dialogue block");
+        };
+        assert_eq!(dlg.name, "dialogue");
+        assert!(dlg.arg.is_none());
+        assert_eq!(dlg.body.len(), 9);
+
+        match &dlg.body[2].node {
+            Statement::Choice(choice) => {
+                assert_eq!(choice.text, "Ask about the road");
+                assert_eq!(choice.target.as_ref().unwrap().node, "road");
+                assert!(choice.requires.is_none());
+            }
+            other => panic!("expected choice, got {other:?}"),
+        }
+        match &dlg.body[3].node {
+            Statement::Choice(choice) => {
+                assert_eq!(choice.target.as_ref().unwrap().node, "seal");
+                assert_eq!(choice.requires.as_deref(), Some("has_item \"Royal Seal\""));
+            }
+            other => panic!("expected choice, got {other:?}"),
+        }
+        match &dlg.body[4].node {
+            Statement::Choice(choice) => assert!(choice.target.is_none()),
+            other => panic!("expected choice, got {other:?}"),
+        }
+        match &dlg.body[5].node {
+            Statement::Effect(effect) => assert_eq!(effect, "sets flag \"met_kael\""),
+            other => panic!("expected effect, got {other:?}"),
+        }
+        match &dlg.body[7].node {
+            Statement::Requires(condition) => assert_eq!(condition, "visited \"the Crypt\""),
+            other => panic!("expected requires, got {other:?}"),
+        }
+    }
+
     // -- Numeric entity name tests --
 
     #[test]
@@ -1079,4 +1450,38 @@ the Citadel is a fortress {
             _ => panic!("expected entity declaration"),
         }
     }
+
+    #[test]
+    fn doc_comments_attach_to_following_entity() {
+        let source = "\
+-- Section header
+
+-- Keeps the pass.
+-- Built after the war.
+the Keep is a fortress {}
+Kael is a character {} -- trailing
+-- orphaned by the blank line below
+
+Mira is a character {
+    -- inside the body
+}
+";
+        let (tokens, errors, comments) = lexer::lex_with_comments(source);
+        assert!(errors.is_empty());
+        let mut ast = parse(&tokens).unwrap();
+        attach_doc_comments(&mut ast, &tokens, &comments);
+
+        let docs: Vec<Option<&str>> = ast
+            .declarations
+            .iter()
+            .map(|d| match &d.node {
+                Declaration::Entity(e) => e.doc.as_deref(),
+                Declaration::World(_) => None,
+            })
+            .collect();
+        assert_eq!(
+            docs,
+            vec![Some("Keeps the pass.\nBuilt after the war."), None, None]
+        );
+    }
 }
@@ -0,0 +1,647 @@
+//! Render compiled entities back to `.ww` source.
+//!
+//! The output is canonical rather than a copy of the original text: comments
+//! (other than the entity's doc comment) and statement order are not
+//! preserved, but compiling the rendered declaration yields the same entity.
+//! Relationships that have no DSL syntax (e.g. `related to`, custom kinds)
+//! are emitted as comments so nothing is dropped silently.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use ww_core::component::CharacterStatus;
+use ww_core::entity::MetadataValue;
+use ww_core::{Entity, EntityKind, Relationship, RelationshipKind, World};
+
+use crate::parser::is_direction;
+
+const INDENT: &str = "    ";
+
+/// Render a single entity as a `.ww` declaration.
+///
+/// Relationships are written from the side that owns them in source:
+/// `led by`, `owned by`, and `involving` appear on the target of the
+/// underlying relationship, everything else on its source.
+pub fn entity_to_dsl(entity: &Entity, world: &World) -> String {
+    let mut out = String::new();
+    if let Some(doc) = &entity.doc {
+        for line in doc.lines() {
+            let _ = writeln!(out, "-- {line}");
+        }
+    }
+    let kind = header_kind(entity);
+    let article = match kind.as_str() {
+        "lore" => "",
+        k if k.starts_with(['a', 'e', 'i', 'o', 'u']) => "an ",
+        _ => "a ",
+    };
+    let _ = writeln!(
+        out,
+        "{} is {article}{kind} {{",
+        format_name(&entity.name, true)
+    );
+
+    let mut body = Vec::new();
+    if !entity.aliases.is_empty() {
+        let names: Vec<String> = entity
+            .aliases
+            .iter()
+            .map(|a| format_name(a, false))
+            .collect();
+        body.push(format!("alias [{}]", names.join(", ")));
+    }
+    if !entity.tags.is_empty() {
+        body.push(format!("tags {}", format_str_list(&entity.tags)));
+    }
+    push_generic_properties(&mut body, entity);
+    push_component_fields(&mut body, entity);
+    push_relationships(&mut body, entity, world);
+    push_fiction(&mut body, entity);
+    for note in &entity.gm_notes {
+        body.push(format!("gm_note {}", quote(note)));
+    }
+    for line in body {
+        if line.is_empty() {
+            out.push('\n');
+        } else {
+            let _ = writeln!(out, "{INDENT}{line}");
+        }
+    }
+
+    if !entity.description.is_empty() {
+        if !out.ends_with("{\n") {
+            out.push('\n');
+        }
+        // Continuation lines keep whatever indentation they were written
+        // with, so the text survives a round trip byte for byte.
+        let _ = writeln!(
+            out,
+            "{INDENT}\"\"\"\n{INDENT}{}\n{INDENT}\"\"\"",
+            entity.description
+        );
+    }
+    out.push('}');
+    out.push('\n');
+    out
+}
+
+fn header_kind(entity: &Entity) -> String {
+    match (&entity.kind, entity.location_subtype()) {
+        (EntityKind::Location, Some(sub)) if EntityKind::is_location_subtype(sub) => {
+            sub.to_string()
+        }
+        (EntityKind::Custom(kind), _) if !is_word(kind) => quote(kind),
+        (kind, _) => kind.to_string(),
+    }
+}
+
+// -- Properties --
+
+/// Flattened `a.b.c` property keys regrouped into their block structure.
+#[derive(Default)]
+struct Node {
+    value: Option<MetadataValue>,
+    children: BTreeMap<String, Node>,
+}
+
+impl Node {
+    fn insert(&mut self, path: &[&str], value: &MetadataValue) {
+        let Some((first, rest)) = path.split_first() else {
+            match value {
+                MetadataValue::Map(map) => {
+                    for (key, value) in map {
+                        self.insert(&[key.as_str()], value);
+                    }
+                }
+                other => self.value = Some(other.clone()),
+            }
+            return;
+        };
+        self.children
+            .entry((*first).to_string())
+            .or_default()
+            .insert(rest, value);
+    }
+}
+
+fn push_generic_properties(body: &mut Vec<String>, entity: &Entity) {
+    let mut root = Node::default();
+    for (key, value) in &entity.properties {
+        let path: Vec<&str> = key.split('.').collect();
+        root.insert(&path, value);
+    }
+    // Written before component fields so a generic `type` stays generic.
+    for (name, node) in &root.children {
+        push_node(body, name, node, "");
+    }
+}
+
+fn push_node(body: &mut Vec<String>, name: &str, node: &Node, indent: &str) {
+    if let Some(value) = &node.value {
+        body.push(format!("{indent}{name} {}", format_value(value)));
+    }
+    if node.children.is_empty() {
+        return;
+    }
+    let inner = format!("{indent}{INDENT}");
+    let (words, args): (Vec<_>, Vec<_>) = node.children.iter().partition(|(k, _)| is_word(k));
+    if !words.is_empty() {
+        body.push(format!("{indent}{name} {{"));
+        for (child, child_node) in words {
+            push_node(body, child, child_node, &inner);
+        }
+        body.push(format!("{indent}}}"));
+    }
+    // Keys that aren't plain words came from a block argument:
+    // `participant "Old Tom" { .. }` flattens to `participant.Old Tom.*`.
+    for (arg, arg_node) in args {
+        body.push(format!("{indent}{name} {} {{", quote(arg)));
+        for (child, child_node) in &arg_node.children {
+            push_node(body, child, child_node, &inner);
+        }
+        body.push(format!("{indent}}}"));
+    }
+}
+
+fn push_component_fields(body: &mut Vec<String>, entity: &Entity) {
+    let c = &entity.components;
+
+    if let Some(event) = &c.event
+        && let Some(date) = &event.date
+    {
+        let mut parts = vec![format!("year {}", date.year)];
+        if let Some(m) = date.month {
+            parts.push(format!("month {m}"));
+        }
+        if let Some(d) = date.day {
+            parts.push(format!("day {d}"));
+        }
+        if let Some(era) = &date.era {
+            parts.push(format!("era {}", quote(era)));
+        }
+        body.push(format!("date {}", parts.join(", ")));
+    }
+
+    if let Some(ch) = &c.character {
+        let mut field = |key: &str, value: &Option<String>| {
+            if let Some(v) = value {
+                body.push(format!("{key} {}", format_str(v)));
+            }
+        };
+        field("species", &ch.species);
+        field("occupation", &ch.occupation);
+        let status = match &ch.status {
+            CharacterStatus::Alive => Some("alive".to_string()),
+            CharacterStatus::Dead => Some("dead".to_string()),
+            CharacterStatus::Custom(s) => Some(s.clone()),
+            // Keep an otherwise empty component alive on recompile.
+            CharacterStatus::Unknown
+                if ch.species.is_none() && ch.occupation.is_none() && ch.traits.is_empty() =>
+            {
+                Some("unknown".to_string())
+            }
+            CharacterStatus::Unknown => None,
+        };
+        field("status", &status);
+        if !ch.traits.is_empty() {
+            body.push(format!("traits {}", format_str_list(&ch.traits)));
+        }
+    }
+
+    if let Some(loc) = &c.location {
+        let mut field = |key: &str, value: &Option<String>| {
+            if let Some(v) = value {
+                body.push(format!("{key} {}", format_str(v)));
+            }
+        };
+        field("climate", &loc.climate);
+        field("terrain", &loc.terrain);
+        if let Some(pop) = loc.population {
+            body.push(format!("population {pop}"));
+        }
+    }
+
+    if let Some(fac) = &c.faction {
+        if let Some(alignment) = &fac.alignment {
+            body.push(format!("alignment {}", format_str(alignment)));
+        }
+        if !fac.values.is_empty() || fac.alignment.is_none() {
+            body.push(format!("values {}", format_str_list(&fac.values)));
+        }
+    }
+
+    if let Some(event) = &c.event {
+        if let Some(outcome) = &event.outcome {
+            body.push(format!("outcome {}", format_str(outcome)));
+        }
+        if let Some(duration) = &event.duration {
+            body.push(format!("duration {}", format_str(duration)));
+        }
+    }
+    if let Some(item) = &c.item
+        && let Some(rarity) = &item.rarity
+    {
+        body.push(format!("rarity {}", format_str(rarity)));
+    }
+    if let Some(lore) = &c.lore {
+        if let Some(source) = &lore.source {
+            body.push(format!("source {}", format_str(source)));
+        }
+        if let Some(reliability) = &lore.reliability {
+            body.push(format!("reliability {}", format_str(reliability)));
+        }
+    }
+
+    // `type` only lands on a component that already exists, so it goes last
+    // and names the first component the compiler would pick.
+    let ty = c
+        .event
+        .as_ref()
+        .map(|e| &e.event_type)
+        .or(c.faction.as_ref().map(|f| &f.faction_type))
+        .or(c.item.as_ref().map(|i| &i.item_type))
+        .or(c.lore.as_ref().map(|l| &l.lore_type));
+    if let Some(Some(ty)) = ty {
+        body.push(format!("type {}", format_str(ty)));
+    }
+
+    if let Some(sim) = &c.simulation {
+        if let Some(speed) = sim.speed {
+            body.push(format!("speed {}", format_float(speed)));
+        }
+        if let Some(schedule) = &sim.schedule {
+            let entries: Vec<String> = schedule
+                .iter()
+                .map(|e| {
+                    format!(
+                        "[{}, {}, {}]",
+                        format_float(e.start_hour),
+                        format_float(e.end_hour),
+                        format_str(&e.activity)
+                    )
+                })
+                .collect();
+            body.push(format!("schedule [{}]", entries.join(", ")));
+        }
+        if let Some(needs) = &sim.initial_needs {
+            let sorted: BTreeMap<_, _> = needs.iter().collect();
+            body.push("needs {".to_string());
+            for (need, level) in sorted {
+                body.push(format!("{INDENT}{need} {}", format_float(*level)));
+            }
+            body.push("}".to_string());
+        }
+    }
+}
+
+// -- Relationships --
+
+fn push_relationships(body: &mut Vec<String>, entity: &Entity, world: &World) {
+    let mut lines: BTreeMap<u8, Vec<String>> = BTreeMap::new();
+    let mut exits = Vec::new();
+    let mut involving = Vec::new();
+    let mut references = Vec::new();
+    let mut unsupported = Vec::new();
+
+    for rel in world.relationships_from(entity.id) {
+        if rel.source != entity.id {
+            continue;
+        }
+        let target = world.entity_name(rel.target);
+        let order = match &rel.kind {
+            RelationshipKind::ContainedIn => 0,
+            RelationshipKind::MemberOf => 1,
+            RelationshipKind::LocatedAt => 2,
+            RelationshipKind::AlliedWith => 3,
+            RelationshipKind::RivalOf => 4,
+            RelationshipKind::BasedAt => 5,
+            RelationshipKind::CausedBy => 6,
+            RelationshipKind::References => {
+                references.push(target.to_string());
+                continue;
+            }
+            RelationshipKind::ConnectedTo => match rel.label.as_deref() {
+                Some(dir) if is_direction(dir) => {
+                    exits.push(format!("{dir} to {}", format_name(target, false)));
+                    continue;
+                }
+                _ => {
+                    unsupported.push(format!("-- connected to {target}"));
+                    continue;
+                }
+            },
+            // Written on the other side as `led by`, `owned by`, `involving`.
+            RelationshipKind::LeaderOf
+            | RelationshipKind::OwnedBy
+            | RelationshipKind::ParticipatedIn => continue,
+            kind @ (RelationshipKind::RelatedTo | RelationshipKind::Custom(_)) => {
+                unsupported.push(format!("-- {kind} {target}"));
+                continue;
+            }
+        };
+        lines.entry(order).or_default().push(format!(
+            "{} {}{}",
+            rel.kind.as_phrase(),
+            format_name(target, false),
+            format_attributes(rel)
+        ));
+    }
+
+    for rel in world.relationships_to(entity.id) {
+        if rel.target != entity.id {
+            continue;
+        }
+        let source = world.entity_name(rel.source);
+        match rel.kind {
+            RelationshipKind::LeaderOf => lines.entry(7).or_default().push(format!(
+                "led by {}{}",
+                format_name(source, false),
+                format_attributes(rel)
+            )),
+            RelationshipKind::OwnedBy => lines.entry(8).or_default().push(format!(
+                "owned by {}{}",
+                format_name(source, false),
+                format_attributes(rel)
+            )),
+            RelationshipKind::ParticipatedIn => involving.push(source.to_string()),
+            _ => {}
+        }
+    }
+
+    for group in lines.values_mut() {
+        group.sort();
+        body.append(group);
+    }
+    if !involving.is_empty() {
+        body.push(format!("involving {}", format_name_list(&mut involving)));
+    }
+    if !references.is_empty() {
+        body.push(format!("references {}", format_name_list(&mut references)));
+    }
+    exits.sort();
+    body.append(&mut exits);
+    unsupported.sort();
+    body.append(&mut unsupported);
+}
+
+/// Trailing `since year N`, `until year N`, and `strength N` clauses.
+fn format_attributes(rel: &Relationship) -> String {
+    let mut out = String::new();
+    for key in Relationship::ATTRIBUTES {
+        if let Some(value) = rel.attribute(key) {
+            let _ = match key {
+                "strength" => write!(out, " {key} {value}"),
+                _ => write!(out, " {key} year {value}"),
+            };
+        }
+    }
+    out
+}
+
+fn push_fiction(body: &mut Vec<String>, entity: &Entity) {
+    let Some(fiction) = &entity.components.fiction else {
+        return;
+    };
+    for dialogue in &fiction.dialogues {
+        body.push(format!("dialogue {} {{", quote(&dialogue.id)));
+        if !dialogue.text.is_empty() {
+            body.push(format!("{INDENT}text {}", quote(&dialogue.text)));
+        }
+        for cond in &dialogue.conditions {
+            body.push(format!("{INDENT}when {}", quote(cond)));
+        }
+        for choice in &dialogue.choices {
+            body.push(format!("{INDENT}choice {} {{", quote(&choice.text)));
+            if !choice.response.is_empty() {
+                body.push(format!(
+                    "{INDENT}{INDENT}response {}",
+                    quote(&choice.response)
+                ));
+            }
+            for effect in &choice.effects {
+                body.push(format!("{INDENT}{INDENT}effect {}", quote(effect)));
+            }
+            for cond in &choice.conditions {
+                body.push(format!("{INDENT}{INDENT}when {}", quote(cond)));
+            }
+            if let Some(goto) = &choice.goto {
+                body.push(format!("{INDENT}{INDENT}goto {}", quote(goto)));
+            }
+            body.push(format!("{INDENT}}}"));
+        }
+        body.push("}".to_string());
+    }
+}
+
+// -- Value formatting --
+
+/// True if `s` lexes as a single DSL word.
+fn is_word(s: &str) -> bool {
+    let mut chars = s.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '\'' | '-'))
+}
+
+/// Format an entity name, quoting it when bare words would not parse back.
+fn format_name(name: &str, declaration: bool) -> String {
+    let plain = !name.is_empty()
+        && name.split(' ').all(|w| {
+            (is_word(w) && !(declaration && w == "is"))
+                || (!w.is_empty() && w.chars().all(|c| c.is_ascii_digit()))
+        });
+    if plain { name.to_string() } else { quote(name) }
+}
+
+/// Format `name` for the head of a declaration (`<name> is a ...`), quoting
+/// it when bare words would not parse back.
+pub fn declaration_name(name: &str) -> String {
+    format_name(name, true)
+}
+
+fn format_name_list(names: &mut [String]) -> String {
+    names.sort();
+    let parts: Vec<String> = names.iter().map(|n| format_name(n, false)).collect();
+    format!("[{}]", parts.join(", "))
+}
+
+/// Format a string value: bare identifier when possible, quoted otherwise.
+fn format_str(s: &str) -> String {
+    if is_word(s) && s != "true" && s != "false" {
+        s.to_string()
+    } else {
+        quote(s)
+    }
+}
+
+pub(crate) fn format_str_list(items: &[String]) -> String {
+    let parts: Vec<String> = items.iter().map(|s| format_str(s)).collect();
+    format!("[{}]", parts.join(", "))
+}
+
+pub(crate) fn format_float(n: f64) -> String {
+    let s = n.to_string();
+    if s.contains('.') { s } else { format!("{s}.0") }
+}
+
+fn format_value(value: &MetadataValue) -> String {
+    match value {
+        MetadataValue::String(s) => format_str(s),
+        MetadataValue::Integer(n) => n.to_string(),
+        MetadataValue::Float(n) => format_float(*n),
+        MetadataValue::Boolean(b) => b.to_string(),
+        MetadataValue::List(items) => {
+            let parts: Vec<String> = items.iter().map(format_value).collect();
+            format!("[{}]", parts.join(", "))
+        }
+        // Maps are expanded into blocks by `Node::insert`.
+        MetadataValue::Map(_) => "[]".to_string(),
+    }
+}
+
+/// Quote a string literal. The lexer has no escape for a literal `"` inside
+/// a string token, so embedded double quotes degrade to single quotes.
+pub fn quote(s: &str) -> String {
+    let escaped = s
+        .replace('\\', "\\\\")
+        .replace('\n', "\\n")
+        .replace('\t', "\\t")
+        .replace('"', "'");
+    format!("\"{escaped}\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compile_source;
+
+    const SOURCE: &str = r#"
+the Iron Citadel is a fortress {
+    climate arid
+    population 45000
+    north to the Ashlands
+}
+
+the Ashlands is a region {
+    south to the Iron Citadel
+}
+
+the Order of Dawn is a faction {
+    values [honor, duty]
+    type military_order
+    led by Kael Stormborn
+    based at the Iron Citadel
+}
+
+-- Design note: keep him morally grey.
+Kael Stormborn is a character {
+    alias [Kael, "the Stormborn"]
+    tags [protagonist, "north arc"]
+    species human
+    status alive
+    traits [brave, "quick to anger"]
+    member of the Order of Dawn since year 1240 strength 2
+    located at the Iron Citadel
+    speed 1.5
+    schedule [[6, 8.5, breakfast]]
+    needs {
+        hunger 0.5
+    }
+    mechanics {
+        brawn 12
+        focuses ["Blade", "Heavy Armor"]
+    }
+    participant "Old Tom" {
+        initiative 15
+    }
+    dialogue "greeting" {
+        text "Well met."
+        choice "Ask about the Order" {
+            response "We hold the line."
+            effect "sets flag asked"
+            goto "farewell"
+        }
+    }
+    dialogue "farewell" {
+        text "Go well."
+    }
+    gm_note "Secretly doubts the Order."
+
+    """
+    A weathered knight.
+    Second line keeps its indent.
+    """
+}
+
+the Great Sundering is an event {
+    date year -1247, month 3, era "Age of Ruin"
+    type cataclysm
+    involving [Kael Stormborn, the Order of Dawn]
+}
+"#;
+
+    fn render(world: &World, name: &str) -> String {
+        entity_to_dsl(world.find_by_name(name).unwrap(), world)
+    }
+
+    #[test]
+    fn renders_canonical_declaration() {
+        let result = compile_source(SOURCE);
+        assert!(result.diagnostics.is_empty(), "{:?}", result.diagnostics);
+        let dsl = render(&result.world, "the Order of Dawn");
+        assert_eq!(
+            dsl,
+            "the Order of Dawn is a faction {\n    values [honor, duty]\n    type military_order\n    \
+             based at the Iron Citadel\n    led by Kael Stormborn\n}\n"
+        );
+        assert!(
+            render(&result.world, "the Iron Citadel")
+                .starts_with("the Iron Citadel is a fortress {")
+        );
+        assert!(
+            render(&result.world, "the Great Sundering")
+                .contains("involving [Kael Stormborn, the Order of Dawn]")
+        );
+    }
+
+    #[test]
+    fn rendered_entities_compile_back_to_the_same_entity() {
+        let original = compile_source(SOURCE).world;
+        let rendered: String = original
+            .all_entities()
+            .map(|e| entity_to_dsl(e, &original))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let result = compile_source(&rendered);
+        assert!(
+            result.diagnostics.is_empty(),
+            "{rendered}\n{:?}",
+            result.diagnostics
+        );
+        let world = result.world;
+
+        assert_eq!(world.entity_count(), original.entity_count());
+        assert_eq!(world.relationship_count(), original.relationship_count());
+        assert!(rendered.contains("member of the Order of Dawn since year 1240 strength 2"));
+        for entity in original.all_entities() {
+            let copy = world.find_by_name(&entity.name).unwrap();
+            assert_eq!(copy.kind, entity.kind);
+            assert_eq!(copy.description, entity.description);
+            assert_eq!(copy.doc, entity.doc);
+            assert_eq!(copy.tags, entity.tags);
+            assert_eq!(copy.aliases, entity.aliases);
+            assert_eq!(copy.properties, entity.properties);
+            assert_eq!(copy.gm_notes, entity.gm_notes);
+            // Component maps hold at most one entry here, so Debug is stable.
+            assert_eq!(
+                format!("{:?}", copy.components),
+                format!("{:?}", entity.components),
+                "{}",
+                entity.name
+            );
+            assert_eq!(
+                entity_to_dsl(copy, &world),
+                entity_to_dsl(entity, &original)
+            );
+        }
+    }
+}
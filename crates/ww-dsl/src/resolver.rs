@@ -2,8 +2,8 @@ use std::collections::HashMap;
 
 use ww_core::entity::EntityId;
 
-use crate::ast::{Declaration, Span};
-use crate::diagnostics::Diagnostic;
+use crate::ast::{Declaration, Span, Statement};
+use crate::diagnostics::{Diagnostic, DiagnosticCode};
 
 /// Tracks where each source file lives within a concatenated source string.
 #[derive(Debug, Clone)]
@@ -101,6 +101,8 @@ pub struct ResolvedEntity {
 pub struct Resolver {
     /// Map from lowercased entity name to resolution info.
     names: HashMap<String, ResolvedEntity>,
+    /// Map from lowercased alias to the lowercased name it stands for.
+    aliases: HashMap<String, String>,
     /// Diagnostics produced during resolution (e.g., duplicate names).
     pub diagnostics: Vec<Diagnostic>,
     /// Maps child entity name (lowercase) → parent entity name (lowercase)
@@ -145,6 +147,7 @@ impl Resolver {
 
                     diagnostics.push(
                         Diagnostic::error(name_span, message)
+                            .with_code(DiagnosticCode::DuplicateEntity)
                             .with_label(format!("first defined in {existing_file}")),
                     );
                 } else {
@@ -161,6 +164,71 @@ impl Resolver {
             }
         }
 
+        // Register aliases once every name is known, so an alias cannot
+        // shadow an entity declared further down.
+        let mut aliases: HashMap<String, String> = HashMap::new();
+        // The declared name of each alias's entity, for conflict messages
+        let mut owners: HashMap<String, &str> = HashMap::new();
+        for decl in &ast.declarations {
+            let Declaration::Entity(entity_decl) = &decl.node else {
+                continue;
+            };
+            let name_lower = entity_decl.name.node.to_lowercase();
+            // Aliases of a rejected duplicate would point at the wrong entity
+            if names
+                .get(&name_lower)
+                .is_none_or(|r| r.name_span != entity_decl.name.span)
+            {
+                continue;
+            }
+            for stmt in &entity_decl.body {
+                let Statement::Alias(alias_names) = &stmt.node else {
+                    continue;
+                };
+                for alias in alias_names {
+                    let alias_lower = alias.node.to_lowercase();
+                    if alias_lower == name_lower {
+                        continue;
+                    }
+                    let conflict = if let Some(other) = names.get(&alias_lower) {
+                        Some(
+                            Diagnostic::error(
+                                alias.span.clone(),
+                                format!(
+                                    "alias \"{}\" is already the name of another entity",
+                                    alias.node
+                                ),
+                            )
+                            .with_related(other.name_span.clone(), "entity declared here"),
+                        )
+                    } else {
+                        aliases
+                            .get(&alias_lower)
+                            .filter(|owner| **owner != name_lower)
+                            .map(|_| {
+                                let owner = owners[&alias_lower];
+                                Diagnostic::error(
+                                    alias.span.clone(),
+                                    format!(
+                                        "alias \"{}\" is already used by \"{owner}\"",
+                                        alias.node
+                                    ),
+                                )
+                            })
+                    };
+                    match conflict {
+                        Some(diagnostic) => {
+                            diagnostics.push(diagnostic.with_code(DiagnosticCode::DuplicateEntity))
+                        }
+                        None => {
+                            owners.insert(alias_lower.clone(), &entity_decl.name.node);
+                            aliases.insert(alias_lower, name_lower.clone());
+                        }
+                    }
+                }
+            }
+        }
+
         // Detect inheritance: if an entity's kind matches a known entity name,
         // record it as an inheritance relationship.
         let mut inheritance = HashMap::new();
@@ -176,6 +244,7 @@ impl Resolver {
 
         Self {
             names,
+            aliases,
             diagnostics,
             inheritance,
         }
@@ -190,8 +259,7 @@ impl Resolver {
         source_map: &SourceMap,
         diagnostics: &mut Vec<Diagnostic>,
     ) -> Option<EntityId> {
-        let lower = name.to_lowercase();
-        if let Some(resolved) = self.names.get(&lower) {
+        if let Some(resolved) = self.get(name) {
             Some(resolved.id)
         } else {
             let file_hint = source_map
@@ -201,15 +269,21 @@ impl Resolver {
 
             diagnostics.push(
                 Diagnostic::error(span.clone(), format!("undefined entity: \"{name}\""))
+                    .with_code(DiagnosticCode::UndefinedEntity)
                     .with_label(file_hint),
             );
             None
         }
     }
 
-    /// Get the resolved entity info for a name (no diagnostic on miss).
+    /// Get the resolved entity info for a name or alias (no diagnostic on miss).
     pub fn get(&self, name: &str) -> Option<&ResolvedEntity> {
-        self.names.get(&name.to_lowercase())
+        let lower = name.to_lowercase();
+        self.names.get(&lower).or_else(|| {
+            self.aliases
+                .get(&lower)
+                .and_then(|target| self.names.get(target))
+        })
     }
 
     /// Number of resolved entities.
@@ -222,9 +296,9 @@ impl Resolver {
         self.names.is_empty()
     }
 
-    /// Check if a name is a known entity.
+    /// Check if a name or alias is a known entity.
     pub fn is_entity(&self, name: &str) -> bool {
-        self.names.contains_key(&name.to_lowercase())
+        self.get(name).is_some()
     }
 }
 
@@ -266,6 +340,28 @@ mod tests {
         assert!(resolver.get("nobody").is_none());
     }
 
+    #[test]
+    fn aliases_resolve_to_their_entity() {
+        let source = "Kael Stormborn is a character {\n    alias [Kael, the Stormborn]\n}\nthe Keep is a fortress {\n    alias Kael\n}";
+        let ast = parse_source(source);
+        let sm = SourceMap::single(source.len());
+        let resolver = Resolver::resolve(&ast, &sm);
+
+        let kael = resolver.get("Kael Stormborn").unwrap().id;
+        assert_eq!(resolver.get("the stormborn").unwrap().id, kael);
+        assert_eq!(resolver.get("kael").unwrap().id, kael);
+        assert_eq!(resolver.len(), 2);
+
+        assert_eq!(resolver.diagnostics.len(), 1);
+        let diagnostic = &resolver.diagnostics[0];
+        assert_eq!(
+            diagnostic.message,
+            "alias \"Kael\" is already used by \"Kael Stormborn\""
+        );
+        assert_eq!(&source[diagnostic.span.clone()], "Kael");
+        assert!(diagnostic.span.start > source.find("the Keep").unwrap());
+    }
+
     #[test]
     fn duplicate_detection() {
         let source = "Kael is a character { species human }\nKael is a faction { type guild }";
@@ -1,6 +1,7 @@
 //! Condition evaluation for dialogue branches.
 
 use ww_core::entity::MetadataValue;
+use ww_core::script::ConditionScript;
 use ww_core::{EntityKind, RelationshipKind, World};
 
 use crate::player::PlayerState;
@@ -18,6 +19,16 @@ pub enum Condition {
         /// Item name.
         item: String,
     },
+    /// Check if the player has been to a location.
+    Visited {
+        /// Location name.
+        location: String,
+    },
+    /// Check if a flag is set, whatever its value.
+    HasFlag {
+        /// Flag key.
+        key: String,
+    },
     /// Check if a knowledge flag is set.
     HasKnowledge {
         /// Knowledge key.
@@ -52,12 +63,31 @@ pub enum Condition {
     And(Vec<Condition>),
     /// Logical OR.
     Or(Vec<Condition>),
+    /// Condition text that could not be understood; never holds, so a
+    /// mistyped requirement keeps its choice hidden rather than open.
+    Unknown(String),
     /// Always true.
     #[default]
     Always,
 }
 
 impl Condition {
+    /// Parse a condition as written after `requires` in the DSL.
+    ///
+    /// Understands `has_item "Key"`, `visited "Crypt"`, `at "Tavern"`,
+    /// `knows "secret"`, `flag "met_king"`, `flag "mood" angry`,
+    /// `not <condition>`, and the older `player has <key>`. Returns `None`
+    /// for anything else.
+    pub fn parse(text: &str) -> Option<Self> {
+        ConditionScript::parse(text).map(Self::from)
+    }
+
+    /// Parse a condition, keeping text that does not parse as
+    /// [`Condition::Unknown`].
+    pub fn parse_or_unknown(text: &str) -> Self {
+        Self::parse(text).unwrap_or_else(|| Self::Unknown(text.to_string()))
+    }
+
     /// Evaluate the condition against the current state.
     pub fn evaluate(&self, world: &World, player: &PlayerState) -> bool {
         match self {
@@ -75,6 +105,10 @@ impl Condition {
                     false
                 }
             }
+            Condition::Visited { location } => world
+                .find_by_name(location)
+                .is_some_and(|loc| player.has_visited(loc.id)),
+            Condition::HasFlag { key } => player.has_flag(key),
             Condition::HasKnowledge { key } => player.has_knowledge(key),
             Condition::FlagEquals { key, value } => {
                 player.get_flag(key).is_some_and(|v| v == value)
@@ -104,11 +138,33 @@ impl Condition {
             Condition::Not(inner) => !inner.evaluate(world, player),
             Condition::And(conditions) => conditions.iter().all(|c| c.evaluate(world, player)),
             Condition::Or(conditions) => conditions.iter().any(|c| c.evaluate(world, player)),
+            Condition::Unknown(_) => false,
             Condition::Always => true,
         }
     }
 }
 
+impl From<ConditionScript> for Condition {
+    fn from(script: ConditionScript) -> Self {
+        match script {
+            ConditionScript::HasItem(item) => Self::HasItem { item },
+            ConditionScript::Visited(location) => Self::Visited { location },
+            ConditionScript::At(location) => Self::PlayerAt { location },
+            ConditionScript::Knows(key) => Self::HasKnowledge { key },
+            ConditionScript::Flag { key, value: None } => Self::HasFlag { key },
+            ConditionScript::Flag {
+                key,
+                value: Some(value),
+            } => Self::FlagEquals { key, value },
+            ConditionScript::PlayerHas(key) => Self::Or(vec![
+                Self::HasFlag { key: key.clone() },
+                Self::HasItem { item: key },
+            ]),
+            ConditionScript::Not(inner) => Self::Not(Box::new(Self::from(*inner))),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -173,6 +229,76 @@ mod tests {
         assert!(cond.evaluate(&world, &player));
     }
 
+    #[test]
+    fn parse_conditions() {
+        assert!(matches!(
+            Condition::parse("has_item \"Golden Key\""),
+            Some(Condition::HasItem { item }) if item == "Golden Key"
+        ));
+        assert!(matches!(
+            Condition::parse("visited Crypt"),
+            Some(Condition::Visited { location }) if location == "Crypt"
+        ));
+        assert!(matches!(
+            Condition::parse("flag \"mood\" 3"),
+            Some(Condition::FlagEquals {
+                value: MetadataValue::Integer(3),
+                ..
+            })
+        ));
+        assert!(matches!(
+            Condition::parse("not knows \"secret\""),
+            Some(Condition::Not(inner)) if matches!(*inner, Condition::HasKnowledge { .. })
+        ));
+        assert!(matches!(
+            Condition::parse("player has knowledge.ashlands"),
+            Some(Condition::HasKnowledge { key }) if key == "ashlands"
+        ));
+        assert!(Condition::parse("has_item").is_none());
+    }
+
+    #[test]
+    fn visited_and_flags() {
+        let world = test_world();
+        let tavern = world.find_by_name("the Tavern").unwrap();
+        let mut player = PlayerState::new(EntityId::new(), EntityId::new());
+
+        let visited = Condition::parse("visited \"the Tavern\"").unwrap();
+        assert!(!visited.evaluate(&world, &player));
+        player.move_to(tavern.id);
+        assert!(visited.evaluate(&world, &player));
+
+        let flag = Condition::parse("flag met_king").unwrap();
+        assert!(!flag.evaluate(&world, &player));
+        player.set_flag("met_king", MetadataValue::Boolean(true));
+        assert!(flag.evaluate(&world, &player));
+    }
+
+    #[test]
+    fn player_has_reads_flags_and_items() {
+        let world = test_world();
+        let mut player = PlayerState::new(EntityId::new(), EntityId::new());
+
+        let quest = Condition::parse("player has quest.join_order").unwrap();
+        assert!(!quest.evaluate(&world, &player));
+        player.set_flag("quest.join_order", MetadataValue::Boolean(true));
+        assert!(quest.evaluate(&world, &player));
+
+        let key = Condition::parse("player has \"Golden Key\"").unwrap();
+        assert!(!key.evaluate(&world, &player));
+        player.add_item(world.find_by_name("Golden Key").unwrap().id);
+        assert!(key.evaluate(&world, &player));
+    }
+
+    #[test]
+    fn unknown_conditions_never_hold() {
+        let world = test_world();
+        let player = PlayerState::new(EntityId::new(), EntityId::new());
+        let cond = Condition::parse_or_unknown("the moon is full");
+        assert!(matches!(&cond, Condition::Unknown(text) if text == "the moon is full"));
+        assert!(!cond.evaluate(&world, &player));
+    }
+
     #[test]
     fn logical_not() {
         let world = test_world();
@@ -1,7 +1,11 @@
 //! Dialogue trees and choice structures.
 
+use ww_core::World;
+use ww_core::component::{ChoiceData, DialogueData};
+
 use super::condition::Condition;
 use super::effect::Effect;
+use crate::player::PlayerState;
 
 /// A dialogue tree for a character.
 #[derive(Debug, Clone)]
@@ -30,6 +34,29 @@ impl Dialogue {
         }
     }
 
+    /// Build a dialogue node from its compiled DSL form, spoken by `speaker`.
+    ///
+    /// Conditions that [`Condition::parse`] does not understand never hold,
+    /// so the node stays unavailable.
+    pub fn from_data(data: &DialogueData, speaker: impl Into<String>) -> Self {
+        Self {
+            id: data.id.clone(),
+            speaker: Some(speaker.into()),
+            conditions: data
+                .conditions
+                .iter()
+                .map(|c| Condition::parse_or_unknown(c))
+                .collect(),
+            text: data.text.clone(),
+            choices: data.choices.iter().map(Choice::from_data).collect(),
+        }
+    }
+
+    /// Whether all of the dialogue's conditions hold.
+    pub fn is_available(&self, world: &World, player: &PlayerState) -> bool {
+        self.conditions.iter().all(|c| c.evaluate(world, player))
+    }
+
     /// Set the speaker.
     pub fn with_speaker(mut self, speaker: impl Into<String>) -> Self {
         self.speaker = Some(speaker.into());
@@ -76,6 +103,28 @@ impl Choice {
         }
     }
 
+    /// Build a choice from its compiled DSL form.
+    ///
+    /// Conditions that do not parse never hold, hiding the choice; effects
+    /// that do not parse are dropped. The compiler warns about both.
+    pub fn from_data(data: &ChoiceData) -> Self {
+        Self {
+            text: data.text.clone(),
+            conditions: data
+                .conditions
+                .iter()
+                .map(|c| Condition::parse_or_unknown(c))
+                .collect(),
+            response: data.response.clone(),
+            effects: data
+                .effects
+                .iter()
+                .filter_map(|e| Effect::parse(e))
+                .collect(),
+            goto: data.goto.clone(),
+        }
+    }
+
     /// Add a condition.
     pub fn with_condition(mut self, condition: Condition) -> Self {
         self.conditions.push(condition);
@@ -88,6 +137,11 @@ impl Choice {
         self
     }
 
+    /// Whether all of the choice's conditions hold.
+    pub fn is_available(&self, world: &World, player: &PlayerState) -> bool {
+        self.conditions.iter().all(|c| c.evaluate(world, player))
+    }
+
     /// Set the goto branch.
     pub fn with_goto(mut self, dialogue_id: impl Into<String>) -> Self {
         self.goto = Some(dialogue_id.into());
@@ -111,6 +165,42 @@ mod tests {
         assert_eq!(dialogue.choices.len(), 1);
     }
 
+    #[test]
+    fn from_compiled_data() {
+        let data = DialogueData {
+            id: "greeting".to_string(),
+            text: "Well met.".to_string(),
+            conditions: Vec::new(),
+            choices: vec![ChoiceData {
+                text: "Ask about the road".to_string(),
+                response: String::new(),
+                effects: Vec::new(),
+                conditions: Vec::new(),
+                goto: Some("road".to_string()),
+            }],
+        };
+        let dialogue = Dialogue::from_data(&data, "Kael");
+        assert_eq!(dialogue.speaker.as_deref(), Some("Kael"));
+        assert_eq!(dialogue.text, "Well met.");
+        assert_eq!(dialogue.choices[0].goto.as_deref(), Some("road"));
+    }
+
+    #[test]
+    fn unparseable_conditions_hide_choices() {
+        let data = ChoiceData {
+            text: "Kael sent me".to_string(),
+            response: String::new(),
+            effects: vec!["summons a dragon".to_string()],
+            conditions: vec!["player is worthy".to_string()],
+            goto: None,
+        };
+        let choice = Choice::from_data(&data);
+        assert!(choice.effects.is_empty());
+        let world = World::new(ww_core::WorldMeta::new("Test"));
+        let player = PlayerState::new(ww_core::EntityId::new(), ww_core::EntityId::new());
+        assert!(!choice.is_available(&world, &player));
+    }
+
     #[test]
     fn choice_builder() {
         let choice = Choice::new("Ask about rumors", "I've heard strange things...")
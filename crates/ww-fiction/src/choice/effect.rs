@@ -2,6 +2,7 @@
 
 use ww_core::RelationshipKind;
 use ww_core::entity::MetadataValue;
+use ww_core::script::EffectScript;
 
 /// An effect that can be applied when a choice is made.
 #[derive(Debug, Clone)]
@@ -13,6 +14,11 @@ pub enum Effect {
         /// Value to set.
         value: MetadataValue,
     },
+    /// Remove a player flag.
+    ClearFlag {
+        /// Flag key.
+        key: String,
+    },
     /// Set a knowledge flag.
     SetKnowledge {
         /// Knowledge key.
@@ -59,3 +65,61 @@ pub enum Effect {
         dialogue_id: String,
     },
 }
+
+impl Effect {
+    /// Parse an effect as written in a DSL dialogue.
+    ///
+    /// Understands `gives "Torch"`, `takes "Torch"`, `sets flag "met_king"`,
+    /// `sets flag "mood" angry`, `clears flag "met_king"`,
+    /// `sets knowledge "secret"`, `clears knowledge "secret"`, and the older
+    /// `set knowledge.<key> <bool>` / `set <key> <value>` forms. Returns
+    /// `None` for anything else.
+    pub fn parse(text: &str) -> Option<Self> {
+        EffectScript::parse(text).map(Self::from)
+    }
+}
+
+impl From<EffectScript> for Effect {
+    fn from(script: EffectScript) -> Self {
+        match script {
+            EffectScript::Give(item) => Self::GiveItem { item },
+            EffectScript::Take(item) => Self::TakeItem { item },
+            EffectScript::SetFlag { key, value } => Self::SetFlag { key, value },
+            EffectScript::ClearFlag(key) => Self::ClearFlag { key },
+            EffectScript::SetKnowledge { key, value } => Self::SetKnowledge { key, value },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_effects() {
+        assert!(matches!(
+            Effect::parse("gives \"Iron Torch\""),
+            Some(Effect::GiveItem { item }) if item == "Iron Torch"
+        ));
+        assert!(matches!(
+            Effect::parse("sets flag \"met_king\""),
+            Some(Effect::SetFlag { key, value: MetadataValue::Boolean(true) }) if key == "met_king"
+        ));
+        assert!(matches!(
+            Effect::parse("clears knowledge secret"),
+            Some(Effect::SetKnowledge { value: false, .. })
+        ));
+        assert!(matches!(
+            Effect::parse("set knowledge.ashlands true"),
+            Some(Effect::SetKnowledge { key, value: true }) if key == "ashlands"
+        ));
+        assert!(matches!(
+            Effect::parse("set quest.join_order true"),
+            Some(Effect::SetFlag {
+                value: MetadataValue::Boolean(true),
+                ..
+            })
+        ));
+        assert!(Effect::parse("dance").is_none());
+    }
+}
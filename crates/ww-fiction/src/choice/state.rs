@@ -2,8 +2,10 @@
 
 use std::collections::{HashMap, HashSet};
 
+use serde::{Deserialize, Serialize};
+
 /// Tracks which dialogues and choices the player has seen.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ChoiceState {
     /// Dialogues that have been started.
     seen_dialogues: HashSet<String>,
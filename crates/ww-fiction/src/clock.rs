@@ -0,0 +1,247 @@
+//! In-session clock for time-of-day narration.
+//!
+//! The clock is optional and only exists when the world opts in via
+//! `fiction.minutes_per_move`, `fiction.minutes_per_scene`, or
+//! `fiction.start_hour` in its metadata. Movement and scenes advance it.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use ww_core::Entity;
+use ww_core::entity::MetadataValue;
+
+/// Default in-game minutes consumed by moving between locations.
+pub const DEFAULT_MINUTES_PER_MOVE: u32 = 10;
+/// Default in-game minutes consumed by a solo scene.
+pub const DEFAULT_MINUTES_PER_SCENE: u32 = 60;
+/// Default hour of day the clock starts at.
+pub const DEFAULT_START_HOUR: u32 = 8;
+
+const MINUTES_PER_DAY: u64 = 24 * 60;
+
+/// Coarse period of the day used by the narrator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeOfDay {
+    /// 05:00 – 07:59.
+    Dawn,
+    /// 08:00 – 11:59.
+    Morning,
+    /// 12:00 – 16:59.
+    Afternoon,
+    /// 17:00 – 20:59.
+    Evening,
+    /// 21:00 – 04:59.
+    Night,
+}
+
+impl TimeOfDay {
+    /// Classify an hour (0–23) into a period of the day.
+    pub fn from_hour(hour: u32) -> Self {
+        match hour {
+            5..=7 => Self::Dawn,
+            8..=11 => Self::Morning,
+            12..=16 => Self::Afternoon,
+            17..=20 => Self::Evening,
+            _ => Self::Night,
+        }
+    }
+
+    /// Whether this period counts as night.
+    pub fn is_night(&self) -> bool {
+        matches!(self, Self::Night)
+    }
+
+    /// Lowercase display name.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Dawn => "dawn",
+            Self::Morning => "morning",
+            Self::Afternoon => "afternoon",
+            Self::Evening => "evening",
+            Self::Night => "night",
+        }
+    }
+}
+
+impl fmt::Display for TimeOfDay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// In-game clock measured in minutes since midnight of day 1.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GameClock {
+    /// Elapsed minutes since day 1, 00:00.
+    pub minutes: u64,
+    /// Minutes consumed by each move between locations.
+    pub minutes_per_move: u32,
+    /// Minutes consumed by each solo scene.
+    pub minutes_per_scene: u32,
+}
+
+impl GameClock {
+    /// Create a clock starting on day 1 at the given hour, with default rates.
+    pub fn new(start_hour: u32) -> Self {
+        Self {
+            minutes: u64::from(start_hour % 24) * 60,
+            minutes_per_move: DEFAULT_MINUTES_PER_MOVE,
+            minutes_per_scene: DEFAULT_MINUTES_PER_SCENE,
+        }
+    }
+
+    /// Build a clock from world metadata, or `None` if the world does not use one.
+    ///
+    /// Reads `fiction.start_hour`, `fiction.minutes_per_move`, and
+    /// `fiction.minutes_per_scene`; any of them enables the clock.
+    pub fn from_world_meta(properties: &HashMap<String, MetadataValue>) -> Option<Self> {
+        let start = extract_u32(properties, "fiction.start_hour");
+        let per_move = extract_u32(properties, "fiction.minutes_per_move");
+        let per_scene = extract_u32(properties, "fiction.minutes_per_scene");
+
+        if start.is_none() && per_move.is_none() && per_scene.is_none() {
+            return None;
+        }
+
+        let mut clock = Self::new(start.unwrap_or(DEFAULT_START_HOUR));
+        clock.minutes_per_move = per_move.unwrap_or(DEFAULT_MINUTES_PER_MOVE);
+        clock.minutes_per_scene = per_scene.unwrap_or(DEFAULT_MINUTES_PER_SCENE);
+        Some(clock)
+    }
+
+    /// Advance the clock by a number of minutes.
+    pub fn advance(&mut self, minutes: u32) {
+        self.minutes += u64::from(minutes);
+    }
+
+    /// Current day, starting at 1.
+    pub fn day(&self) -> u64 {
+        self.minutes / MINUTES_PER_DAY + 1
+    }
+
+    /// Current hour (0–23).
+    pub fn hour(&self) -> u32 {
+        ((self.minutes % MINUTES_PER_DAY) / 60) as u32
+    }
+
+    /// Current minute within the hour (0–59).
+    pub fn minute(&self) -> u32 {
+        (self.minutes % 60) as u32
+    }
+
+    /// Current period of the day.
+    pub fn time_of_day(&self) -> TimeOfDay {
+        TimeOfDay::from_hour(self.hour())
+    }
+
+    /// Whether a location is open right now.
+    ///
+    /// Locations without an `open_hours` property (e.g. `open_hours "8-20"`)
+    /// are always open. Ranges may wrap past midnight (`"20-4"`).
+    pub fn is_open(&self, location: &Entity) -> bool {
+        match location.properties.get("open_hours") {
+            Some(MetadataValue::String(s)) => {
+                parse_hours(s).is_none_or(|(open, close)| hour_in_range(self.hour(), open, close))
+            }
+            _ => true,
+        }
+    }
+}
+
+impl fmt::Display for GameClock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Day {}, {:02}:{:02} ({})",
+            self.day(),
+            self.hour(),
+            self.minute(),
+            self.time_of_day()
+        )
+    }
+}
+
+/// Parse an `"open-close"` hour range such as `"8-20"`.
+fn parse_hours(s: &str) -> Option<(u32, u32)> {
+    let (open, close) = s.split_once('-')?;
+    let open: u32 = open.trim().parse().ok()?;
+    let close: u32 = close.trim().parse().ok()?;
+    (open <= 24 && close <= 24).then_some((open % 24, close % 24))
+}
+
+fn hour_in_range(hour: u32, open: u32, close: u32) -> bool {
+    if open <= close {
+        open <= hour && hour < close
+    } else {
+        hour >= open || hour < close
+    }
+}
+
+fn extract_u32(properties: &HashMap<String, MetadataValue>, key: &str) -> Option<u32> {
+    match properties.get(key) {
+        Some(MetadataValue::Integer(n)) if *n >= 0 => Some(*n as u32),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ww_core::EntityKind;
+
+    #[test]
+    fn time_of_day_boundaries() {
+        assert_eq!(TimeOfDay::from_hour(4), TimeOfDay::Night);
+        assert_eq!(TimeOfDay::from_hour(5), TimeOfDay::Dawn);
+        assert_eq!(TimeOfDay::from_hour(12), TimeOfDay::Afternoon);
+        assert_eq!(TimeOfDay::from_hour(20), TimeOfDay::Evening);
+        assert!(TimeOfDay::from_hour(23).is_night());
+    }
+
+    #[test]
+    fn advance_rolls_over_days() {
+        let mut clock = GameClock::new(23);
+        clock.advance(90);
+        assert_eq!(clock.day(), 2);
+        assert_eq!(clock.hour(), 0);
+        assert_eq!(clock.minute(), 30);
+        assert_eq!(clock.to_string(), "Day 2, 00:30 (night)");
+    }
+
+    #[test]
+    fn from_world_meta_optional() {
+        let mut props = HashMap::new();
+        assert!(GameClock::from_world_meta(&props).is_none());
+
+        props.insert(
+            "fiction.minutes_per_move".to_string(),
+            MetadataValue::Integer(15),
+        );
+        let clock = GameClock::from_world_meta(&props).unwrap();
+        assert_eq!(clock.minutes_per_move, 15);
+        assert_eq!(clock.minutes_per_scene, DEFAULT_MINUTES_PER_SCENE);
+        assert_eq!(clock.hour(), DEFAULT_START_HOUR);
+    }
+
+    #[test]
+    fn open_hours() {
+        let mut shop = Entity::new(EntityKind::Location, "Shop");
+        let mut clock = GameClock::new(10);
+        assert!(clock.is_open(&shop));
+
+        shop.properties.insert(
+            "open_hours".to_string(),
+            MetadataValue::String("8-20".to_string()),
+        );
+        assert!(clock.is_open(&shop));
+        clock.advance(11 * 60);
+        assert!(!clock.is_open(&shop));
+
+        shop.properties.insert(
+            "open_hours".to_string(),
+            MetadataValue::String("20-4".to_string()),
+        );
+        assert!(clock.is_open(&shop));
+    }
+}
@@ -38,10 +38,18 @@ pub enum FictionError {
     #[error("unknown command: {0}")]
     UnknownCommand(String),
 
+    /// A known command was given arguments it does not accept.
+    #[error("usage: {0}")]
+    Usage(String),
+
     /// Dialogue not found.
     #[error("dialogue not found: {0}")]
     DialogueNotFound(String),
 
+    /// A choice was picked outside of a conversation.
+    #[error("you are not in a conversation")]
+    NoConversation,
+
     /// Invalid choice selection.
     #[error("invalid choice: {0}")]
     InvalidChoice(usize),
@@ -58,6 +66,28 @@ pub enum FictionError {
     #[error("cannot take: {0}")]
     CannotTake(String),
 
+    /// Item belongs to someone else and the player may not take it.
+    #[error("{item} belongs to {owner}.")]
+    ItemOwned {
+        /// The item's name.
+        item: String,
+        /// The owner's name.
+        owner: String,
+    },
+
+    /// An error together with a suggestion of what the player meant.
+    #[error("{error}. {hint}")]
+    WithHint {
+        /// The underlying error.
+        error: Box<FictionError>,
+        /// The suggestion, e.g. "Did you mean Old Tom?".
+        hint: String,
+    },
+
+    /// A session save could not be read.
+    #[error("invalid save: {0}")]
+    InvalidSave(String),
+
     /// Simulation error.
     #[error("simulation error: {0}")]
     Simulation(#[from] ww_simulation::SimError),
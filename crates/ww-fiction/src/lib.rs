@@ -7,21 +7,29 @@
 
 /// Choice engine for branching narratives.
 pub mod choice;
+/// Optional in-session clock for time-of-day narration.
+pub mod clock;
 /// Error types for the fiction engine.
 pub mod error;
+/// ASCII map of the exits around the player.
+pub mod map;
 /// Narrator system for descriptive text generation.
 pub mod narrator;
 /// Command parsing and entity resolution.
 pub mod parser;
 /// Player state management.
 pub mod player;
+/// Saving and resuming fiction sessions.
+pub mod save;
 /// Interactive fiction session management.
 pub mod session;
 /// Fiction system for simulation integration.
 pub mod system;
 
+pub use clock::{GameClock, TimeOfDay};
 pub use error::{FictionError, FictionResult};
 pub use parser::{Command, Direction, parse_command};
 pub use player::PlayerState;
+pub use save::SessionSave;
 pub use session::FictionSession;
 pub use system::FictionSystem;
@@ -0,0 +1,210 @@
+//! ASCII map of the exits around the player's location.
+//!
+//! Only the immediate exits are drawn: north, south, east and west around
+//! the current location, with up and down listed beneath. Rooms the player
+//! has already been to are marked with `*`.
+
+use ww_core::{EntityId, RelationshipKind, World};
+
+use crate::parser::Direction;
+
+/// Joins a room to its neighbour on the left or right.
+const LINK: &str = " -- ";
+
+/// The exits leading out of one location.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExitMap {
+    /// Name of the location at the center.
+    pub here: String,
+    /// Exits by direction, in the order they were found.
+    pub exits: Vec<MapExit>,
+}
+
+/// One exit on an [`ExitMap`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MapExit {
+    /// Direction the exit leads.
+    pub direction: Direction,
+    /// Name of the location it leads to.
+    pub name: String,
+    /// Whether the player has been there.
+    pub visited: bool,
+}
+
+/// The directional exits leading out of `location`, in the order they were
+/// declared: connections from it whose label names a direction.
+///
+/// These are the exits movement follows; connections only labelled from the
+/// other end do not lead back.
+pub fn exits(world: &World, location: EntityId) -> Vec<(Direction, EntityId)> {
+    world
+        .relationships_from(location)
+        .into_iter()
+        .filter(|r| r.kind == RelationshipKind::ConnectedTo && r.target != location)
+        .filter_map(|r| Some((Direction::parse(r.label.as_deref()?)?, r.target)))
+        .collect()
+}
+
+impl ExitMap {
+    /// Collect the directional [`exits`] of `location`. The first exit found
+    /// in a direction wins.
+    pub fn build(world: &World, location: EntityId, visited: impl Fn(EntityId) -> bool) -> Self {
+        let mut map: Vec<MapExit> = Vec::new();
+        for (direction, other) in exits(world, location) {
+            if map.iter().any(|e| e.direction == direction) {
+                continue;
+            }
+            map.push(MapExit {
+                direction,
+                name: world.entity_name(other).to_string(),
+                visited: visited(other),
+            });
+        }
+
+        Self {
+            here: world.entity_name(location).to_string(),
+            exits: map,
+        }
+    }
+
+    /// Draw the map as lines of text without trailing whitespace.
+    pub fn render(&self) -> String {
+        let label = |direction: Direction| {
+            self.exits
+                .iter()
+                .find(|e| e.direction == direction)
+                .map(|e| format!("{}{}", e.name, if e.visited { "*" } else { "" }))
+        };
+        let north = label(Direction::North);
+        let south = label(Direction::South);
+        let west = label(Direction::West);
+        let east = label(Direction::East);
+        let here = format!("[{}]", self.here);
+
+        // Vertical exits are centered on the current location's box
+        let width = |text: &str| text.chars().count();
+        let left = west.as_deref().map_or(0, |w| width(w) + width(LINK));
+        let center = left + width(&here) / 2;
+        let centered = |text: &str| {
+            let pad = center.saturating_sub(width(text) / 2);
+            format!("{}{text}", " ".repeat(pad))
+        };
+
+        let mut lines = Vec::new();
+        if let Some(north) = &north {
+            lines.push(centered(north));
+            lines.push(centered("|"));
+        }
+        let mut middle = match &west {
+            Some(west) => format!("{west}{LINK}"),
+            None => String::new(),
+        };
+        middle.push_str(&here);
+        if let Some(east) = &east {
+            middle.push_str(LINK);
+            middle.push_str(east);
+        }
+        lines.push(middle);
+        if let Some(south) = &south {
+            lines.push(centered("|"));
+            lines.push(centered(south));
+        }
+
+        for (direction, heading) in [(Direction::Up, "Up"), (Direction::Down, "Down")] {
+            if let Some(name) = label(direction) {
+                lines.push(format!("{heading}: {name}"));
+            }
+        }
+        if self.exits.is_empty() {
+            lines.push("There are no exits.".to_string());
+        }
+        if self.exits.iter().any(|e| e.visited) {
+            lines.push("* visited".to_string());
+        }
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn exit(direction: Direction, name: &str, visited: bool) -> MapExit {
+        MapExit {
+            direction,
+            name: name.to_string(),
+            visited,
+        }
+    }
+
+    #[test]
+    fn render_compass_and_vertical_exits() {
+        let map = ExitMap {
+            here: "Hall".to_string(),
+            exits: vec![
+                exit(Direction::North, "Library", true),
+                exit(Direction::West, "Kitchen", false),
+                exit(Direction::East, "Yard", false),
+                exit(Direction::South, "Gate", false),
+                exit(Direction::Down, "Cellar", true),
+            ],
+        };
+        let expected = [
+            "          Library*",
+            "              |",
+            "Kitchen -- [Hall] -- Yard",
+            "              |",
+            "            Gate",
+            "Down: Cellar*",
+            "* visited",
+        ];
+        assert_eq!(map.render(), expected.join("\n"));
+    }
+
+    #[test]
+    fn render_pads_by_characters() {
+        let map = ExitMap {
+            here: "Hof".to_string(),
+            exits: vec![
+                exit(Direction::North, "Türme", false),
+                exit(Direction::West, "Küche", false),
+            ],
+        };
+        let expected = ["         Türme", "           |", "Küche -- [Hof]"];
+        assert_eq!(map.render(), expected.join("\n"));
+    }
+
+    #[test]
+    fn build_follows_exits_labelled_from_here() {
+        use ww_core::{Entity, EntityKind, Relationship, WorldMeta};
+
+        let mut world = World::new(WorldMeta::new("Test"));
+        let mut add = |name: &str| {
+            world
+                .add_entity(Entity::new(EntityKind::Location, name))
+                .unwrap()
+        };
+        let (hall, yard, attic) = (add("Hall"), add("Yard"), add("Attic"));
+        for (from, direction, to) in [(hall, "east", yard), (attic, "down", hall)] {
+            world
+                .add_relationship(
+                    Relationship::new(from, RelationshipKind::ConnectedTo, to)
+                        .with_label(direction),
+                )
+                .unwrap();
+        }
+
+        let map = ExitMap::build(&world, hall, |id| id == yard);
+        assert_eq!(map.exits, vec![exit(Direction::East, "Yard", true)]);
+        assert_eq!(exits(&world, yard), Vec::new());
+    }
+
+    #[test]
+    fn render_without_exits() {
+        let map = ExitMap {
+            here: "Cell".to_string(),
+            exits: Vec::new(),
+        };
+        assert_eq!(map.render(), "[Cell]\nThere are no exits.");
+    }
+}
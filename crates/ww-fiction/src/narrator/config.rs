@@ -1,5 +1,7 @@
 //! Narrator configuration.
 
+use serde::{Deserialize, Serialize};
+
 /// Narrator tone - affects the style of descriptions.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum NarratorTone {
@@ -31,8 +33,11 @@ impl NarratorTone {
 }
 
 /// Narrative perspective.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum Perspective {
+    /// First person ("I enter the room").
+    FirstPerson,
     /// Second person ("You enter the room").
     #[default]
     SecondPerson,
@@ -40,8 +45,38 @@ pub enum Perspective {
     ThirdPerson,
 }
 
+impl Perspective {
+    /// Parse a perspective from a string.
+    ///
+    /// Accepts `"first"`, `"second"`, `"third"`, optionally followed by
+    /// `_person`, `-person` or `person` (case-insensitive).
+    /// Returns `None` for unrecognized values.
+    pub fn parse(s: &str) -> Option<Self> {
+        let s = s.to_lowercase();
+        let s = s
+            .trim_end_matches("person")
+            .trim_end_matches(['_', '-', ' ']);
+        match s {
+            "first" | "1st" => Some(Self::FirstPerson),
+            "second" | "2nd" => Some(Self::SecondPerson),
+            "third" | "3rd" => Some(Self::ThirdPerson),
+            _ => None,
+        }
+    }
+
+    /// Get the display name for this perspective.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::FirstPerson => "first person",
+            Self::SecondPerson => "second person",
+            Self::ThirdPerson => "third person",
+        }
+    }
+}
+
 /// Verbosity level for descriptions.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum Verbosity {
     /// Minimal descriptions.
     Terse,
@@ -52,6 +87,53 @@ pub enum Verbosity {
     Verbose,
 }
 
+impl Verbosity {
+    /// Parse a verbosity level from a string.
+    ///
+    /// Accepts `"terse"`/`"brief"`, `"normal"` and `"verbose"`
+    /// (case-insensitive). Returns `None` for unrecognized values.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "terse" | "brief" => Some(Self::Terse),
+            "normal" => Some(Self::Normal),
+            "verbose" => Some(Self::Verbose),
+            _ => None,
+        }
+    }
+
+    /// Get the display name for this verbosity level.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Terse => "terse",
+            Self::Normal => "normal",
+            Self::Verbose => "verbose",
+        }
+    }
+}
+
+/// How multi-item lists (inventory, exits, trackers) are rendered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ListStyle {
+    /// One item per line, prefixed with `- `.
+    Bulleted,
+    /// One item per line, numbered from 1 so players can refer to items by number.
+    Numbered,
+}
+
+impl ListStyle {
+    /// Parse a list style from a string.
+    ///
+    /// Accepts `"bulleted"`/`"bullets"` and `"numbered"`/`"numbers"`
+    /// (case-insensitive). Returns `None` for unrecognized values.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "bulleted" | "bullets" | "bullet" => Some(Self::Bulleted),
+            "numbered" | "numbers" | "number" => Some(Self::Numbered),
+            _ => None,
+        }
+    }
+}
+
 /// Configuration for the narrator.
 #[derive(Debug, Clone, Default)]
 pub struct NarratorConfig {
@@ -63,6 +145,11 @@ pub struct NarratorConfig {
     pub verbosity: Verbosity,
     /// Name to use for the player in third person.
     pub player_name: Option<String>,
+    /// List style for every list view; `None` keeps each view's own default
+    /// (inline exits, bulleted inventory).
+    pub list_style: Option<ListStyle>,
+    /// Column width narration is word-wrapped to; `None` leaves it unwrapped.
+    pub wrap_width: Option<usize>,
 }
 
 impl NarratorConfig {
@@ -95,17 +182,56 @@ impl NarratorConfig {
         self
     }
 
+    /// Set the list style used by every list view.
+    pub fn with_list_style(mut self, style: ListStyle) -> Self {
+        self.list_style = Some(style);
+        self
+    }
+
+    /// Word-wrap narration to `width` columns.
+    pub fn with_wrap_width(mut self, width: usize) -> Self {
+        self.wrap_width = Some(width);
+        self
+    }
+
     /// Get the subject pronoun for the player.
     pub fn player_subject(&self) -> &str {
         match self.perspective {
+            Perspective::FirstPerson => "I",
             Perspective::SecondPerson => "You",
             Perspective::ThirdPerson => self.player_name.as_deref().unwrap_or("The hero"),
         }
     }
 
+    /// Get the player subject followed by a verb phrase in the matching person.
+    ///
+    /// `verb` is given in its base form ("arrive", "set down"); in third person
+    /// the leading verb takes its -s/-es ending ("The hero arrives").
+    pub fn player_acts(&self, verb: &str) -> String {
+        let subject = self.player_subject();
+        if self.perspective != Perspective::ThirdPerson {
+            return format!("{subject} {verb}");
+        }
+        let (head, rest) = verb.split_once(' ').unwrap_or((verb, ""));
+        let head = if ["s", "sh", "ch", "x", "z", "o"]
+            .iter()
+            .any(|ending| head.ends_with(ending))
+        {
+            format!("{head}es")
+        } else {
+            format!("{head}s")
+        };
+        if rest.is_empty() {
+            format!("{subject} {head}")
+        } else {
+            format!("{subject} {head} {rest}")
+        }
+    }
+
     /// Get the object pronoun for the player.
     pub fn player_object(&self) -> &str {
         match self.perspective {
+            Perspective::FirstPerson => "me",
             Perspective::SecondPerson => "you",
             Perspective::ThirdPerson => self.player_name.as_deref().unwrap_or("the hero"),
         }
@@ -114,6 +240,7 @@ impl NarratorConfig {
     /// Get the possessive for the player.
     pub fn player_possessive(&self) -> &str {
         match self.perspective {
+            Perspective::FirstPerson => "my",
             Perspective::SecondPerson => "your",
             Perspective::ThirdPerson => {
                 // Could do "the hero's" but that's awkward
@@ -168,6 +295,51 @@ mod tests {
         assert_eq!(config.player_subject(), "Kael");
     }
 
+    #[test]
+    fn verbs_agree_with_perspective() {
+        let config = NarratorConfig::new();
+        assert_eq!(config.player_acts("set down"), "You set down");
+
+        let config = config.with_perspective(Perspective::FirstPerson);
+        assert_eq!(config.player_acts("toss"), "I toss");
+
+        let config = config.with_perspective(Perspective::ThirdPerson);
+        assert_eq!(config.player_acts("arrive"), "The hero arrives");
+        assert_eq!(config.player_acts("toss"), "The hero tosses");
+        assert_eq!(config.player_acts("relinquish"), "The hero relinquishes");
+        assert_eq!(config.player_acts("set foot"), "The hero sets foot");
+    }
+
+    #[test]
+    fn pronouns_first_person() {
+        let config = NarratorConfig::new().with_perspective(Perspective::FirstPerson);
+        assert_eq!(config.player_subject(), "I");
+        assert_eq!(config.player_object(), "me");
+        assert_eq!(config.player_possessive(), "my");
+    }
+
+    #[test]
+    fn perspective_parse() {
+        assert_eq!(Perspective::parse("first"), Some(Perspective::FirstPerson));
+        assert_eq!(
+            Perspective::parse("Third-Person"),
+            Some(Perspective::ThirdPerson)
+        );
+        assert_eq!(
+            Perspective::parse("second_person"),
+            Some(Perspective::SecondPerson)
+        );
+        assert_eq!(Perspective::parse("fourth"), None);
+    }
+
+    #[test]
+    fn verbosity_parse() {
+        assert_eq!(Verbosity::parse("brief"), Some(Verbosity::Terse));
+        assert_eq!(Verbosity::parse("Verbose"), Some(Verbosity::Verbose));
+        assert_eq!(Verbosity::parse("normal"), Some(Verbosity::Normal));
+        assert_eq!(Verbosity::parse("chatty"), None);
+    }
+
     #[test]
     fn tone_parse_valid() {
         assert_eq!(NarratorTone::parse("formal"), Some(NarratorTone::Formal));
@@ -191,6 +363,13 @@ mod tests {
         assert_eq!(NarratorTone::parse("CASUAL"), Some(NarratorTone::Casual));
     }
 
+    #[test]
+    fn list_style_parse() {
+        assert_eq!(ListStyle::parse("Numbered"), Some(ListStyle::Numbered));
+        assert_eq!(ListStyle::parse("bullets"), Some(ListStyle::Bulleted));
+        assert_eq!(ListStyle::parse("table"), None);
+    }
+
     #[test]
     fn tone_parse_invalid() {
         assert_eq!(NarratorTone::parse("epic"), None);
@@ -3,5 +3,5 @@
 mod config;
 mod templates;
 
-pub use config::{NarratorConfig, NarratorTone, Perspective, Verbosity};
-pub use templates::TemplateRegistry;
+pub use config::{ListStyle, NarratorConfig, NarratorTone, Perspective, Verbosity};
+pub use templates::{ListItem, TemplateRegistry};
@@ -2,9 +2,36 @@
 
 use std::collections::HashMap;
 
+use ww_core::grammar::Grammar;
 use ww_core::{Entity, EntityKind, World};
 
-use super::config::{NarratorConfig, NarratorTone, Verbosity};
+use super::config::{ListStyle, NarratorConfig, NarratorTone, Verbosity};
+use crate::clock::TimeOfDay;
+
+/// An entry in a rendered list, optionally with nested sub-items.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ListItem {
+    /// Text shown for the item.
+    pub text: String,
+    /// Sub-items rendered indented beneath this one.
+    pub children: Vec<ListItem>,
+}
+
+impl ListItem {
+    /// Create a list item without sub-items.
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Attach nested sub-items.
+    pub fn with_children(mut self, children: Vec<ListItem>) -> Self {
+        self.children = children;
+        self
+    }
+}
 
 /// Registry of narrative templates.
 #[derive(Debug, Clone)]
@@ -18,6 +45,17 @@ impl TemplateRegistry {
         Self { config }
     }
 
+    /// Get the narrator configuration.
+    pub fn config(&self) -> &NarratorConfig {
+        &self.config
+    }
+
+    /// Get a mutable reference to the configuration, e.g. to change
+    /// verbosity mid-session.
+    pub fn config_mut(&mut self) -> &mut NarratorConfig {
+        &mut self.config
+    }
+
     /// Describe a location for the player.
     pub fn describe_location(
         &self,
@@ -75,7 +113,7 @@ impl TemplateRegistry {
             output.push_str(&entity.description);
         } else {
             output.push('\n');
-            output.push_str(&self.default_entity_description(entity));
+            output.push_str(&self.default_entity_description(entity, world));
         }
 
         // Show properties based on verbosity
@@ -92,50 +130,52 @@ impl TemplateRegistry {
 
     /// Narrate an arrival at a location.
     pub fn narrate_arrival(&self, location: &Entity) -> String {
-        let subject = self.config.player_subject();
+        let acts = |verb| self.config.player_acts(verb);
         match self.config.tone {
-            NarratorTone::Formal => format!("{subject} arrive at {}.", location.name),
-            NarratorTone::Casual => format!("{subject} head into {}.", location.name),
+            NarratorTone::Formal => format!("{} at {}.", acts("arrive"), location.name),
+            NarratorTone::Casual => format!("{} into {}.", acts("head"), location.name),
             NarratorTone::Dramatic => {
-                format!("{subject} set foot upon {}.", location.name)
+                format!("{} upon {}.", acts("set foot"), location.name)
             }
             NarratorTone::Humorous => {
-                format!("{subject} wander into {}. It's a place.", location.name)
+                format!("{} into {}. It's a place.", acts("wander"), location.name)
             }
         }
     }
 
     /// Narrate taking an item.
     pub fn narrate_take(&self, item: &Entity) -> String {
-        let subject = self.config.player_subject();
+        let acts = |verb| self.config.player_acts(verb);
         match self.config.tone {
-            NarratorTone::Formal => format!("{subject} take {}.", item.name),
-            NarratorTone::Casual => format!("{subject} grab {}.", item.name),
+            NarratorTone::Formal => format!("{} {}.", acts("take"), item.name),
+            NarratorTone::Casual => format!("{} {}.", acts("grab"), item.name),
             NarratorTone::Dramatic => {
                 format!(
-                    "{subject} claim {} as {} own.",
+                    "{} {} as {} own.",
+                    acts("claim"),
                     item.name,
                     self.config.player_possessive()
                 )
             }
             NarratorTone::Humorous => {
-                format!("{subject} pocket {}. Five-finger discount.", item.name)
+                format!("{} {}. Five-finger discount.", acts("pocket"), item.name)
             }
         }
     }
 
     /// Narrate dropping an item.
     pub fn narrate_drop(&self, item: &Entity) -> String {
-        let subject = self.config.player_subject();
+        let acts = |verb| self.config.player_acts(verb);
         match self.config.tone {
-            NarratorTone::Formal => format!("{subject} set down {}.", item.name),
-            NarratorTone::Casual => format!("{subject} drop {}.", item.name),
+            NarratorTone::Formal => format!("{} {}.", acts("set down"), item.name),
+            NarratorTone::Casual => format!("{} {}.", acts("drop"), item.name),
             NarratorTone::Dramatic => {
-                format!("{subject} relinquish {}.", item.name)
+                format!("{} {}.", acts("relinquish"), item.name)
             }
             NarratorTone::Humorous => {
                 format!(
-                    "{subject} toss {} aside. It wasn't that great anyway.",
+                    "{} {} aside. It wasn't that great anyway.",
+                    acts("toss"),
                     item.name
                 )
             }
@@ -159,7 +199,62 @@ impl TemplateRegistry {
                 )
             }
             NarratorTone::Humorous => {
-                format!("{subject} walk {direction} into a wall. Ouch.")
+                format!(
+                    "{} {direction} into a wall. Ouch.",
+                    self.config.player_acts("walk")
+                )
+            }
+        }
+    }
+
+    /// Narrate a failed attempt to retrace the player's steps.
+    pub fn narrate_no_way_back(&self) -> String {
+        let subject = self.config.player_subject();
+        match self.config.tone {
+            NarratorTone::Formal => format!("{subject} cannot go back that way."),
+            NarratorTone::Casual => "There's no going back that way.".to_string(),
+            NarratorTone::Dramatic => {
+                format!("The way back is lost to {}.", self.config.player_object())
+            }
+            NarratorTone::Humorous => {
+                format!(
+                    "{} around. The way back has wandered off.",
+                    self.config.player_acts("turn")
+                )
+            }
+        }
+    }
+
+    /// Narrate the current period of the day.
+    pub fn narrate_time_of_day(&self, time: TimeOfDay) -> String {
+        match (self.config.tone, time) {
+            (NarratorTone::Dramatic, TimeOfDay::Night) => {
+                "Darkness presses in from every side.".to_string()
+            }
+            (NarratorTone::Humorous, TimeOfDay::Night) => {
+                "It's dark. Like, really dark.".to_string()
+            }
+            (_, TimeOfDay::Dawn) => "The first light of dawn creeps across the sky.".to_string(),
+            (_, TimeOfDay::Morning) => "It is morning.".to_string(),
+            (_, TimeOfDay::Afternoon) => "The afternoon sun hangs overhead.".to_string(),
+            (_, TimeOfDay::Evening) => "Evening shadows grow long.".to_string(),
+            (_, TimeOfDay::Night) => "Night has fallen.".to_string(),
+        }
+    }
+
+    /// Narrate a location that is closed at the current hour.
+    pub fn narrate_closed(&self, location: &Entity) -> String {
+        match self.config.tone {
+            NarratorTone::Formal => format!("{} is closed at this hour.", location.name),
+            NarratorTone::Casual => format!("{} is shut right now.", location.name),
+            NarratorTone::Dramatic => {
+                format!(
+                    "The doors of {} are sealed against the hour.",
+                    location.name
+                )
+            }
+            NarratorTone::Humorous => {
+                format!("{} is closed. Should have checked the sign.", location.name)
             }
         }
     }
@@ -174,6 +269,18 @@ impl TemplateRegistry {
         format!("  [{}] {}", index + 1, text)
     }
 
+    /// Render a list in the configured [`ListStyle`], falling back to `default`.
+    ///
+    /// Items are indented by two spaces; sub-items are indented under their
+    /// parent's text and numbered from 1 again in numbered style. No trailing
+    /// newline is added.
+    pub fn format_list(&self, items: &[ListItem], default: ListStyle) -> String {
+        let style = self.config.list_style.unwrap_or(default);
+        let mut lines = Vec::new();
+        push_list_lines(items, style, "  ", &mut lines);
+        lines.join("\n")
+    }
+
     fn format_location_header(&self, location: &Entity) -> String {
         format!("**{}**", location.name)
     }
@@ -192,9 +299,9 @@ impl TemplateRegistry {
     }
 
     fn format_item_present(&self, item: &Entity) -> String {
-        let subject = self.config.player_subject();
+        let acts = |verb| self.config.player_acts(verb);
         match self.config.tone {
-            NarratorTone::Formal => format!("{subject} see {} here.", item.name),
+            NarratorTone::Formal => format!("{} {} here.", acts("see"), item.name),
             NarratorTone::Casual => format!("There's {} lying around.", item.name),
             NarratorTone::Dramatic => format!("{} gleams in the shadows.", item.name),
             NarratorTone::Humorous => format!("{} is just sitting here. Rude.", item.name),
@@ -202,6 +309,10 @@ impl TemplateRegistry {
     }
 
     fn format_exits(&self, exits: &[String]) -> String {
+        if let Some(style) = self.config.list_style {
+            let items: Vec<_> = exits.iter().map(ListItem::new).collect();
+            return format!("Exits:\n{}", self.format_list(&items, style));
+        }
         match self.config.verbosity {
             Verbosity::Terse => format!("[{}]", exits.join(", ")),
             Verbosity::Normal | Verbosity::Verbose => {
@@ -210,21 +321,24 @@ impl TemplateRegistry {
         }
     }
 
-    fn default_entity_description(&self, entity: &Entity) -> String {
+    fn default_entity_description(&self, entity: &Entity, world: &World) -> String {
+        let grammar = Grammar::from_meta(&world.meta);
         match entity.kind {
             EntityKind::Character => {
                 if let Some(c) = &entity.components.character {
                     let mut parts = Vec::new();
                     if let Some(species) = &c.species {
-                        parts.push(format!("A {species}"));
+                        parts.push(grammar.with_article(species));
                     }
                     if let Some(occupation) = &c.occupation {
-                        parts.push(format!("a {occupation}"));
+                        parts.push(grammar.with_article(occupation));
                     }
                     if parts.is_empty() {
                         "A mysterious figure.".to_string()
                     } else {
-                        format!("{}.", parts.join(", "))
+                        // Every part starts with an ASCII article.
+                        let text = parts.join(", ");
+                        format!("{}{}.", text[..1].to_uppercase(), &text[1..])
                     }
                 } else {
                     "A mysterious figure.".to_string()
@@ -248,8 +362,8 @@ impl TemplateRegistry {
 
         // Show relationships
         for rel in world.relationships_from(entity.id) {
-            if let Some(target) = world.get_entity(rel.target) {
-                details.push(format!("  {} {}", rel.kind, target.name));
+            if world.get_entity(rel.target).is_some() {
+                details.push(format!("  {}", rel.describe(world)));
             }
         }
 
@@ -257,6 +371,20 @@ impl TemplateRegistry {
     }
 }
 
+fn push_list_lines(items: &[ListItem], style: ListStyle, indent: &str, lines: &mut Vec<String>) {
+    for (i, item) in items.iter().enumerate() {
+        let marker = match style {
+            ListStyle::Bulleted => "- ".to_string(),
+            ListStyle::Numbered => format!("{}. ", i + 1),
+        };
+        lines.push(format!("{indent}{marker}{}", item.text));
+        if !item.children.is_empty() {
+            let nested = format!("{indent}{}", " ".repeat(marker.len()));
+            push_list_lines(&item.children, style, &nested, lines);
+        }
+    }
+}
+
 impl Default for TemplateRegistry {
     fn default() -> Self {
         Self::new(NarratorConfig::default())
@@ -293,6 +421,20 @@ mod tests {
         assert!(output.contains("pewter mug"));
     }
 
+    #[test]
+    fn default_character_description_picks_articles() {
+        let registry = TemplateRegistry::default();
+        let world = World::new(ww_core::WorldMeta::new("Test"));
+        let mut elf = Entity::new(EntityKind::Character, "Lira");
+        elf.components.character = Some(ww_core::component::CharacterComponent {
+            species: Some("elf".to_string()),
+            occupation: Some("innkeeper".to_string()),
+            ..Default::default()
+        });
+        let output = registry.describe_entity(&elf, &world);
+        assert!(output.contains("An elf, an innkeeper."), "{output}");
+    }
+
     #[test]
     fn narrate_arrival_tones() {
         let location = Entity::new(EntityKind::Location, "the Citadel");
@@ -317,8 +459,48 @@ mod tests {
                 .with_player_name("Kael"),
         );
         let location = Entity::new(EntityKind::Location, "the Citadel");
-        let output = registry.narrate_arrival(&location);
-        assert!(output.contains("Kael"));
+        assert_eq!(
+            registry.narrate_arrival(&location),
+            "Kael arrives at the Citadel."
+        );
+
+        let humorous = TemplateRegistry::new(
+            NarratorConfig::new()
+                .with_tone(NarratorTone::Humorous)
+                .with_perspective(crate::narrator::Perspective::ThirdPerson),
+        );
+        let item = Entity::new(EntityKind::Item, "a rusty key");
+        assert_eq!(
+            humorous.narrate_drop(&item),
+            "The hero tosses a rusty key aside. It wasn't that great anyway."
+        );
+    }
+
+    #[test]
+    fn narrate_time_of_day_and_closed() {
+        let registry = TemplateRegistry::default();
+        assert_eq!(
+            registry.narrate_time_of_day(TimeOfDay::Night),
+            "Night has fallen."
+        );
+        let dramatic =
+            TemplateRegistry::new(NarratorConfig::new().with_tone(NarratorTone::Dramatic));
+        assert!(
+            dramatic
+                .narrate_time_of_day(TimeOfDay::Night)
+                .contains("Darkness")
+        );
+
+        assert_eq!(
+            registry.narrate_no_way_back(),
+            "You cannot go back that way."
+        );
+
+        let shop = Entity::new(EntityKind::Location, "the Shop");
+        assert_eq!(
+            registry.narrate_closed(&shop),
+            "the Shop is closed at this hour."
+        );
     }
 
     #[test]
@@ -330,4 +512,40 @@ mod tests {
             "  [1] Ask about rumors"
         );
     }
+
+    #[test]
+    fn format_list_styles_and_nesting() {
+        let items = vec![
+            ListItem::new("Leather Pouch")
+                .with_children(vec![ListItem::new("Gem"), ListItem::new("Key")]),
+            ListItem::new("Lantern"),
+        ];
+
+        let registry = TemplateRegistry::default();
+        assert_eq!(
+            registry.format_list(&items, ListStyle::Bulleted),
+            "  - Leather Pouch\n    - Gem\n    - Key\n  - Lantern"
+        );
+
+        let numbered =
+            TemplateRegistry::new(NarratorConfig::new().with_list_style(ListStyle::Numbered));
+        assert_eq!(
+            numbered.format_list(&items, ListStyle::Bulleted),
+            "  1. Leather Pouch\n     1. Gem\n     2. Key\n  2. Lantern"
+        );
+    }
+
+    #[test]
+    fn exits_follow_configured_list_style() {
+        let room = Entity::new(EntityKind::Location, "the Hall");
+        let exits = vec!["north".to_string(), "down".to_string()];
+
+        let inline = TemplateRegistry::default().describe_location(&room, &[], &[], &exits);
+        assert!(inline.ends_with("Exits: north, down"));
+
+        let numbered =
+            TemplateRegistry::new(NarratorConfig::new().with_list_style(ListStyle::Numbered));
+        let listed = numbered.describe_location(&room, &[], &[], &exits);
+        assert!(listed.ends_with("Exits:\n  1. north\n  2. down"));
+    }
 }
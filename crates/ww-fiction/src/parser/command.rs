@@ -1,5 +1,9 @@
 //! Command parsing for player input.
 
+use ww_core::text::similarity;
+
+use crate::narrator::{Perspective, Verbosity};
+
 /// Direction for movement commands.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Direction {
@@ -58,6 +62,22 @@ impl Direction {
             Self::Southwest => "southwest",
         }
     }
+
+    /// The direction leading back the way this one came.
+    pub fn opposite(&self) -> Self {
+        match self {
+            Self::North => Self::South,
+            Self::South => Self::North,
+            Self::East => Self::West,
+            Self::West => Self::East,
+            Self::Up => Self::Down,
+            Self::Down => Self::Up,
+            Self::Northeast => Self::Southwest,
+            Self::Northwest => Self::Southeast,
+            Self::Southeast => Self::Northwest,
+            Self::Southwest => Self::Northeast,
+        }
+    }
 }
 
 /// A parsed player command.
@@ -68,6 +88,8 @@ pub enum Command {
         /// The direction to move.
         direction: Direction,
     },
+    /// Return to the previous location.
+    Back,
     /// Go to a named location.
     Go {
         /// The target location name.
@@ -102,8 +124,27 @@ pub enum Command {
         /// Optional target to use item on.
         target: Option<String>,
     },
+    /// Pick a numbered choice in the ongoing conversation.
+    Choose {
+        /// The choice number as shown, starting at 1.
+        number: usize,
+    },
     /// List inventory.
     Inventory,
+    /// Draw the exits around the current location.
+    Map,
+    /// Report the in-session time of day.
+    Time,
+    /// Change how much detail the narrator gives.
+    Verbosity {
+        /// The new verbosity level.
+        level: Verbosity,
+    },
+    /// Show or change the narrative perspective.
+    Perspective {
+        /// The new perspective, or `None` to show the current one.
+        perspective: Option<Perspective>,
+    },
     /// Show help.
     Help {
         /// Optional help topic.
@@ -111,6 +152,11 @@ pub enum Command {
     },
     /// Quit the game.
     Quit,
+    /// A known verb used with arguments it does not accept.
+    Usage {
+        /// How the verb is used.
+        usage: String,
+    },
     /// Unknown command.
     Unknown {
         /// The original input.
@@ -120,15 +166,62 @@ pub enum Command {
 
 /// Verb synonyms for command parsing.
 const MOVE_VERBS: &[&str] = &["go", "move", "walk", "head", "travel"];
+const BACK_VERBS: &[&str] = &["back", "return", "retreat"];
 const LOOK_VERBS: &[&str] = &["look", "l", "examine", "ex", "x", "describe", "inspect"];
 const TAKE_VERBS: &[&str] = &["take", "get", "pick", "grab"];
 const DROP_VERBS: &[&str] = &["drop", "put", "leave", "discard"];
 const TALK_VERBS: &[&str] = &["talk", "speak", "ask", "chat", "converse"];
 const USE_VERBS: &[&str] = &["use", "apply", "activate"];
+const CHOOSE_VERBS: &[&str] = &["choose", "pick", "select", "reply"];
 const INVENTORY_VERBS: &[&str] = &["inventory", "inv", "i", "items"];
+const TIME_VERBS: &[&str] = &["time", "clock"];
+const MAP_VERBS: &[&str] = &["map"];
+const VERBOSITY_VERBS: &[&str] = &["verbose", "normal", "terse", "brief"];
+const PERSPECTIVE_VERBS: &[&str] = &["perspective", "pov"];
 const HELP_VERBS: &[&str] = &["help", "h", "?", "commands"];
 const QUIT_VERBS: &[&str] = &["quit", "q", "exit", "bye"];
 
+/// Every verb list, for suggesting a known verb after a typo.
+const ALL_VERBS: &[&[&str]] = &[
+    MOVE_VERBS,
+    BACK_VERBS,
+    LOOK_VERBS,
+    TAKE_VERBS,
+    DROP_VERBS,
+    TALK_VERBS,
+    USE_VERBS,
+    CHOOSE_VERBS,
+    INVENTORY_VERBS,
+    TIME_VERBS,
+    MAP_VERBS,
+    VERBOSITY_VERBS,
+    PERSPECTIVE_VERBS,
+    HELP_VERBS,
+    QUIT_VERBS,
+];
+
+/// Minimum similarity for a verb to be suggested in place of a typo.
+const VERB_HINT_THRESHOLD: f64 = 0.8;
+
+/// The known verb closest to the first word of `input`, if any is close.
+///
+/// Returns `None` when the word already is a known verb, so `"tkae mug"`
+/// suggests `"take"` but `"take"` on its own suggests nothing.
+pub fn closest_verb(input: &str) -> Option<&'static str> {
+    let word = input.split_whitespace().next()?.to_lowercase();
+    let verbs = ALL_VERBS.iter().flat_map(|verbs| verbs.iter().copied());
+    if verbs.clone().any(|verb| verb == word) {
+        return None;
+    }
+    verbs
+        // One- and two-letter abbreviations match too much to be useful
+        .filter(|verb| verb.len() > 2)
+        .map(|verb| (verb, similarity(&word, verb)))
+        .filter(|(_, score)| *score >= VERB_HINT_THRESHOLD)
+        .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+        .map(|(verb, _)| verb)
+}
+
 /// Parse a player input string into a command.
 pub fn parse_command(input: &str) -> Command {
     let input = input.trim();
@@ -145,7 +238,21 @@ pub fn parse_command(input: &str) -> Command {
         return Command::Move { direction: dir };
     }
 
+    // A bare number, or "choose 2", picks a dialogue choice
+    if let Ok(number) = verb.parse() {
+        return Command::Choose { number };
+    }
+    if CHOOSE_VERBS.contains(&verb.as_str())
+        && let [word] = rest
+        && let Ok(number) = word.parse()
+    {
+        return Command::Choose { number };
+    }
+
     // Check verb categories
+    if BACK_VERBS.contains(&verb.as_str()) && rest.is_empty() {
+        return Command::Back;
+    }
     if MOVE_VERBS.contains(&verb.as_str()) {
         return parse_move(rest);
     }
@@ -167,6 +274,20 @@ pub fn parse_command(input: &str) -> Command {
     if INVENTORY_VERBS.contains(&verb.as_str()) {
         return Command::Inventory;
     }
+    if TIME_VERBS.contains(&verb.as_str()) {
+        return Command::Time;
+    }
+    if MAP_VERBS.contains(&verb.as_str()) {
+        return Command::Map;
+    }
+    if let Some(level) = Verbosity::parse(&verb)
+        && rest.is_empty()
+    {
+        return Command::Verbosity { level };
+    }
+    if PERSPECTIVE_VERBS.contains(&verb.as_str()) {
+        return parse_perspective(rest);
+    }
     if HELP_VERBS.contains(&verb.as_str()) {
         return parse_help(rest);
     }
@@ -184,6 +305,12 @@ fn parse_move(rest: &[&str]) -> Command {
         return Command::Look { target: None };
     }
 
+    if let [word] = rest
+        && word.eq_ignore_ascii_case("back")
+    {
+        return Command::Back;
+    }
+
     // Check if first word is a direction
     if let Some(dir) = Direction::parse(rest[0]) {
         return Command::Move { direction: dir };
@@ -195,6 +322,20 @@ fn parse_move(rest: &[&str]) -> Command {
     }
 }
 
+fn parse_perspective(rest: &[&str]) -> Command {
+    if rest.is_empty() {
+        return Command::Perspective { perspective: None };
+    }
+    match Perspective::parse(&rest.join(" ")) {
+        Some(perspective) => Command::Perspective {
+            perspective: Some(perspective),
+        },
+        None => Command::Usage {
+            usage: "perspective [first | second | third]".to_string(),
+        },
+    }
+}
+
 fn parse_look(rest: &[&str]) -> Command {
     if rest.is_empty() {
         return Command::Look { target: None };
@@ -374,6 +515,14 @@ mod tests {
         );
     }
 
+    #[test]
+    fn opposite_directions() {
+        assert_eq!(Direction::North.opposite(), Direction::South);
+        assert_eq!(Direction::Up.opposite(), Direction::Down);
+        assert_eq!(Direction::Northeast.opposite(), Direction::Southwest);
+        assert_eq!(Direction::West.opposite().opposite(), Direction::West);
+    }
+
     #[test]
     fn parse_go_location() {
         assert_eq!(
@@ -446,6 +595,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_choose() {
+        assert_eq!(parse_command("2"), Command::Choose { number: 2 });
+        assert_eq!(parse_command("choose 1"), Command::Choose { number: 1 });
+        assert_eq!(
+            parse_command("pick up lamp"),
+            Command::Take {
+                item: "lamp".to_string()
+            }
+        );
+    }
+
     #[test]
     fn parse_use() {
         assert_eq!(
@@ -470,6 +631,12 @@ mod tests {
         assert_eq!(parse_command("i"), Command::Inventory);
     }
 
+    #[test]
+    fn parse_time() {
+        assert_eq!(parse_command("time"), Command::Time);
+        assert_eq!(parse_command("clock"), Command::Time);
+    }
+
     #[test]
     fn parse_help() {
         assert_eq!(parse_command("help"), Command::Help { topic: None });
@@ -502,4 +669,76 @@ mod tests {
         assert_eq!(parse_command(""), Command::Look { target: None });
         assert_eq!(parse_command("   "), Command::Look { target: None });
     }
+
+    #[test]
+    fn parse_map() {
+        assert_eq!(parse_command("map"), Command::Map);
+        assert_eq!(parse_command("MAP"), Command::Map);
+    }
+
+    #[test]
+    fn parse_back() {
+        assert_eq!(parse_command("back"), Command::Back);
+        assert_eq!(parse_command("go back"), Command::Back);
+        assert_eq!(parse_command("Return"), Command::Back);
+        assert_eq!(
+            parse_command("go back alley"),
+            Command::Go {
+                target: "back alley".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn parse_verbosity() {
+        assert_eq!(
+            parse_command("brief"),
+            Command::Verbosity {
+                level: Verbosity::Terse
+            }
+        );
+        assert_eq!(
+            parse_command("VERBOSE"),
+            Command::Verbosity {
+                level: Verbosity::Verbose
+            }
+        );
+        assert!(matches!(
+            parse_command("normal people"),
+            Command::Unknown { .. }
+        ));
+    }
+
+    #[test]
+    fn parse_perspective() {
+        assert_eq!(
+            parse_command("perspective"),
+            Command::Perspective { perspective: None }
+        );
+        assert_eq!(
+            parse_command("perspective first"),
+            Command::Perspective {
+                perspective: Some(Perspective::FirstPerson)
+            }
+        );
+        assert_eq!(
+            parse_command("perspective third person"),
+            Command::Perspective {
+                perspective: Some(Perspective::ThirdPerson)
+            }
+        );
+        assert!(matches!(
+            parse_command("perspective sideways"),
+            Command::Usage { .. }
+        ));
+    }
+
+    #[test]
+    fn closest_verb_suggests_typo_fixes() {
+        assert_eq!(closest_verb("tkae mug"), Some("take"));
+        assert_eq!(closest_verb("inventroy"), Some("inventory"));
+        assert_eq!(closest_verb("take what?"), None);
+        assert_eq!(closest_verb("dance wildly"), None);
+        assert_eq!(closest_verb(""), None);
+    }
 }
@@ -3,5 +3,8 @@
 mod command;
 mod resolver;
 
-pub use command::{Command, Direction, parse_command};
-pub use resolver::{fuzzy_match, resolve_entity, resolve_entity_at_location, suggest_entities};
+pub use command::{Command, Direction, closest_verb, parse_command};
+pub use resolver::{
+    entities_at, fuzzy_match, resolve_entity, resolve_entity_at_location, suggest_among,
+    suggest_entities,
+};
@@ -1,6 +1,6 @@
 //! Entity name resolution with fuzzy matching.
 
-use strsim::jaro_winkler;
+use ww_core::text::similarity;
 use ww_core::{EntityId, RelationshipKind, World};
 
 /// Minimum similarity score for fuzzy matching (0.0-1.0).
@@ -22,12 +22,10 @@ pub fn resolve_entity(world: &World, input: &str) -> Option<EntityId> {
 ///
 /// Returns a list of (EntityId, score) sorted by score descending.
 pub fn fuzzy_match(world: &World, input: &str, threshold: f64) -> Vec<(EntityId, f64)> {
-    let input_lower = input.to_lowercase();
     let mut matches: Vec<(EntityId, f64)> = world
         .all_entities()
         .filter_map(|entity| {
-            let name_lower = entity.name.to_lowercase();
-            let score = jaro_winkler(&input_lower, &name_lower);
+            let score = similarity(input, &entity.name);
             if score >= threshold {
                 Some((entity.id, score))
             } else {
@@ -52,7 +50,7 @@ pub fn suggest_entities(world: &World, partial: &str, limit: usize) -> Vec<Strin
             } else if name_lower.contains(&partial_lower) {
                 Some((entity.name.clone(), 1.0))
             } else {
-                let score = jaro_winkler(&partial_lower, &name_lower);
+                let score = similarity(&partial_lower, &name_lower);
                 if score >= 0.6 {
                     Some((entity.name.clone(), score))
                 } else {
@@ -70,16 +68,44 @@ pub fn suggest_entities(world: &World, partial: &str, limit: usize) -> Vec<Strin
         .collect()
 }
 
-/// Resolve an entity at a specific location.
-pub fn resolve_entity_at_location(
+/// Suggest names among `candidates` for input that did not resolve.
+///
+/// Names that start with or contain the input come first, then close
+/// misspellings; anything less similar is left out.
+pub fn suggest_among(
     world: &World,
-    input: &str,
-    location: EntityId,
-) -> Option<EntityId> {
-    let input_lower = input.to_lowercase();
+    partial: &str,
+    candidates: impl IntoIterator<Item = EntityId>,
+) -> Vec<String> {
+    let partial_lower = partial.to_lowercase();
+    let mut suggestions: Vec<(String, f64)> = Vec::new();
+    for id in candidates {
+        let Some(entity) = world.get_entity(id) else {
+            continue;
+        };
+        if suggestions.iter().any(|(name, _)| *name == entity.name) {
+            continue;
+        }
+        let name_lower = entity.name.to_lowercase();
+        let score = if name_lower.starts_with(&partial_lower) {
+            2.0
+        } else if name_lower.contains(&partial_lower) {
+            1.0
+        } else {
+            similarity(&partial_lower, &name_lower)
+        };
+        if score >= FUZZY_THRESHOLD {
+            suggestions.push((entity.name.clone(), score));
+        }
+    }
 
-    // Get all entities at this location
-    let at_location: Vec<_> = world
+    suggestions.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+    suggestions.into_iter().map(|(name, _)| name).collect()
+}
+
+/// Entities located at, based at, or contained in `location`.
+pub fn entities_at(world: &World, location: EntityId) -> Vec<EntityId> {
+    world
         .all_entities()
         .filter(|e| {
             world.relationships_from(e.id).iter().any(|r| {
@@ -92,6 +118,22 @@ pub fn resolve_entity_at_location(
                     )
             })
         })
+        .map(|e| e.id)
+        .collect()
+}
+
+/// Resolve an entity at a specific location.
+pub fn resolve_entity_at_location(
+    world: &World,
+    input: &str,
+    location: EntityId,
+) -> Option<EntityId> {
+    let input_lower = input.to_lowercase();
+
+    // Get all entities at this location
+    let at_location: Vec<_> = entities_at(world, location)
+        .into_iter()
+        .filter_map(|id| world.get_entity(id))
         .collect();
 
     // Try exact match first
@@ -104,7 +146,7 @@ pub fn resolve_entity_at_location(
     // Try fuzzy match
     let mut best: Option<(EntityId, f64)> = None;
     for entity in &at_location {
-        let score = jaro_winkler(&input_lower, &entity.name.to_lowercase());
+        let score = similarity(&input_lower, &entity.name);
         if score >= FUZZY_THRESHOLD && (best.is_none() || score > best.unwrap().1) {
             best = Some((entity.id, score));
         }
@@ -1,9 +1,14 @@
 //! Player state management.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use ww_core::EntityId;
 use ww_core::entity::MetadataValue;
 
+use crate::clock::GameClock;
+
+/// How many locations [`PlayerState::history`] remembers for going back.
+pub const HISTORY_LIMIT: usize = 100;
+
 /// The player's current state in the fiction session.
 #[derive(Debug, Clone)]
 pub struct PlayerState {
@@ -17,6 +22,13 @@ pub struct PlayerState {
     pub knowledge: HashMap<String, bool>,
     /// Arbitrary state flags.
     pub flags: HashMap<String, MetadataValue>,
+    /// In-session clock, if the world enables one.
+    pub clock: Option<GameClock>,
+    /// Locations the player has been to, including the current one.
+    pub visited: HashSet<EntityId>,
+    /// Locations the player has left, most recent last, for going back; at
+    /// most [`HISTORY_LIMIT`] of them.
+    pub history: Vec<EntityId>,
 }
 
 impl PlayerState {
@@ -28,7 +40,45 @@ impl PlayerState {
             inventory: Vec::new(),
             knowledge: HashMap::new(),
             flags: HashMap::new(),
+            clock: None,
+            visited: HashSet::from([location]),
+            history: Vec::new(),
+        }
+    }
+
+    /// Check if the player has been to a location.
+    pub fn has_visited(&self, location: EntityId) -> bool {
+        self.visited.contains(&location)
+    }
+
+    /// Move the player to a location and remember it as visited.
+    ///
+    /// The location left behind is pushed onto the movement history, which
+    /// forgets its oldest entry once it is full.
+    pub fn move_to(&mut self, location: EntityId) {
+        if location != self.location {
+            if self.history.len() >= HISTORY_LIMIT {
+                self.history.remove(0);
+            }
+            self.history.push(self.location);
         }
+        self.location = location;
+        self.visited.insert(location);
+    }
+
+    /// The location the player came from, if any.
+    pub fn previous_location(&self) -> Option<EntityId> {
+        self.history.last().copied()
+    }
+
+    /// Return to the previous location, taking it off the movement history.
+    ///
+    /// Returns the location moved to, or `None` if there is no history.
+    pub fn step_back(&mut self) -> Option<EntityId> {
+        let previous = self.history.pop()?;
+        self.location = previous;
+        self.visited.insert(previous);
+        Some(previous)
     }
 
     /// Check if the player has a knowledge flag set.
@@ -56,6 +106,11 @@ impl PlayerState {
         self.flags.insert(key.into(), value);
     }
 
+    /// Remove a flag, returning whether it was set.
+    pub fn clear_flag(&mut self, key: &str) -> bool {
+        self.flags.remove(key).is_some()
+    }
+
     /// Check if the player has an item.
     pub fn has_item(&self, item_id: EntityId) -> bool {
         self.inventory.contains(&item_id)
@@ -95,6 +150,7 @@ mod tests {
         assert!(state.inventory.is_empty());
         assert!(state.knowledge.is_empty());
         assert!(state.flags.is_empty());
+        assert!(state.clock.is_none());
     }
 
     #[test]
@@ -139,5 +195,53 @@ mod tests {
             state.get_flag("reputation"),
             Some(&MetadataValue::Integer(50))
         );
+        assert!(state.clear_flag("reputation"));
+        assert!(!state.has_flag("reputation"));
+        assert!(!state.clear_flag("reputation"));
+    }
+
+    #[test]
+    fn move_to_records_visits() {
+        let start = EntityId::new();
+        let next = EntityId::new();
+        let mut state = PlayerState::new(EntityId::new(), start);
+
+        assert!(state.has_visited(start));
+        assert!(!state.has_visited(next));
+        state.move_to(next);
+        assert_eq!(state.location, next);
+        assert!(state.has_visited(start) && state.has_visited(next));
+    }
+
+    #[test]
+    fn movement_history() {
+        let (a, b, c) = (EntityId::new(), EntityId::new(), EntityId::new());
+        let mut state = PlayerState::new(EntityId::new(), a);
+        assert_eq!(state.previous_location(), None);
+
+        state.move_to(b);
+        state.move_to(b);
+        state.move_to(c);
+        assert_eq!(state.history, [a, b]);
+
+        assert_eq!(state.step_back(), Some(b));
+        assert_eq!(state.location, b);
+        assert_eq!(state.step_back(), Some(a));
+        assert_eq!(state.step_back(), None);
+        assert_eq!(state.location, a);
+    }
+
+    #[test]
+    fn movement_history_is_capped() {
+        let start = EntityId::new();
+        let mut state = PlayerState::new(EntityId::new(), start);
+        let mut last = start;
+        for _ in 0..HISTORY_LIMIT + 5 {
+            last = EntityId::new();
+            state.move_to(last);
+        }
+        assert_eq!(state.history.len(), HISTORY_LIMIT);
+        assert!(!state.history.contains(&start));
+        assert_eq!(state.location, last);
     }
 }
@@ -0,0 +1,65 @@
+//! Saving and resuming fiction sessions.
+//!
+//! Entity IDs are regenerated every time a world is compiled, so a save
+//! refers to locations and items by name and is resolved against the world
+//! it is loaded into.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use ww_core::entity::MetadataValue;
+
+use crate::choice::ChoiceState;
+use crate::clock::GameClock;
+use crate::error::{FictionError, FictionResult};
+use crate::narrator::{Perspective, Verbosity};
+
+/// The progress of a fiction session, written by
+/// [`FictionSession::save`](crate::FictionSession::save) and resumed with
+/// [`FictionSession::load`](crate::FictionSession::load).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionSave {
+    /// Name of the player's current location.
+    pub location: String,
+    /// Names of the items the player carries, in pickup order.
+    pub inventory: Vec<String>,
+    /// Items the player has put down, mapped to the location they lie in.
+    #[serde(default)]
+    pub dropped: BTreeMap<String, String>,
+    /// Names of the locations the player has been to.
+    #[serde(default)]
+    pub visited: Vec<String>,
+    /// Names of the locations the player has left, most recent last.
+    #[serde(default)]
+    pub history: Vec<String>,
+    /// Knowledge flags.
+    #[serde(default)]
+    pub knowledge: BTreeMap<String, bool>,
+    /// State flags.
+    #[serde(default)]
+    pub flags: BTreeMap<String, MetadataValue>,
+    /// In-session clock, if the world enables one.
+    #[serde(default)]
+    pub clock: Option<GameClock>,
+    /// Dialogues and choices the player has seen.
+    #[serde(default)]
+    pub dialogue: ChoiceState,
+    /// Narrator verbosity chosen during play; `None` keeps the world's.
+    #[serde(default)]
+    pub verbosity: Option<Verbosity>,
+    /// Narrative perspective chosen during play; `None` keeps the world's.
+    #[serde(default)]
+    pub perspective: Option<Perspective>,
+}
+
+impl SessionSave {
+    /// Serialize the save to pretty-printed JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+
+    /// Read a save written by [`SessionSave::to_json`].
+    pub fn from_json(s: &str) -> FictionResult<Self> {
+        serde_json::from_str(s).map_err(|e| FictionError::InvalidSave(e.to_string()))
+    }
+}
@@ -2,14 +2,24 @@
 
 use std::collections::HashMap;
 
+use crate::choice::{ChoiceState, Dialogue, Effect};
+use crate::clock::GameClock;
 use crate::error::{FictionError, FictionResult};
-use crate::narrator::{NarratorConfig, NarratorTone, Perspective, TemplateRegistry};
+use crate::map::{self, ExitMap};
+use crate::narrator::{
+    ListItem, ListStyle, NarratorConfig, NarratorTone, Perspective, TemplateRegistry, Verbosity,
+};
 use crate::parser::{
-    Command, Direction, parse_command, resolve_entity, resolve_entity_at_location,
+    Command, Direction, closest_verb, entities_at, parse_command, resolve_entity,
+    resolve_entity_at_location, suggest_among,
 };
-use crate::player::PlayerState;
+use crate::player::{HISTORY_LIMIT, PlayerState};
+use crate::save::SessionSave;
 use ww_core::entity::MetadataValue;
-use ww_core::{EntityId, EntityKind, RelationshipKind, World};
+use ww_core::{EntityId, EntityKind, Relationship, RelationshipKind, World};
+
+/// Most entity names offered in a "Did you mean ...?" hint.
+const HINT_LIMIT: usize = 3;
 
 /// An interactive fiction session.
 pub struct FictionSession {
@@ -19,27 +29,47 @@ pub struct FictionSession {
     player: PlayerState,
     /// Narrator for tone-aware text generation.
     narrator: TemplateRegistry,
+    /// Dialogues and choices the player has seen.
+    dialogue: ChoiceState,
+    /// Items the player has put down, mapped to where they lie.
+    dropped: HashMap<EntityId, EntityId>,
+    /// The conversation awaiting the player's choice, if any.
+    conversation: Option<Conversation>,
+}
+
+/// Where the player is in a conversation.
+#[derive(Debug, Clone)]
+struct Conversation {
+    /// The entity being talked to.
+    speaker: EntityId,
+    /// The dialogue node whose choices are on offer.
+    node: String,
+    /// Indices of the node's choices as numbered for the player.
+    options: Vec<usize>,
 }
 
 impl FictionSession {
     /// Build a `TemplateRegistry` from world meta properties.
     ///
-    /// Reads `fiction.tone` and `fiction.perspective` from the properties map.
+    /// Reads `fiction.tone`, `fiction.perspective`, and `fiction.list_style`
+    /// from the properties map.
     fn build_narrator(properties: &HashMap<String, MetadataValue>) -> TemplateRegistry {
         let tone = extract_string(properties, "fiction.tone")
             .and_then(|s| NarratorTone::parse(&s))
             .unwrap_or_default();
 
         let perspective = extract_string(properties, "fiction.perspective")
-            .map(|s| match s.to_lowercase().as_str() {
-                "third" | "third_person" | "third-person" => Perspective::ThirdPerson,
-                _ => Perspective::SecondPerson,
-            })
+            .and_then(|s| Perspective::parse(&s))
             .unwrap_or_default();
 
-        let config = NarratorConfig::new()
+        let mut config = NarratorConfig::new()
             .with_tone(tone)
             .with_perspective(perspective);
+        if let Some(style) =
+            extract_string(properties, "fiction.list_style").and_then(|s| ListStyle::parse(&s))
+        {
+            config = config.with_list_style(style);
+        }
 
         TemplateRegistry::new(config)
     }
@@ -67,12 +97,16 @@ impl FictionSession {
             };
 
         let player_id = EntityId::new();
-        let player = PlayerState::new(player_id, start_location);
+        let mut player = PlayerState::new(player_id, start_location);
+        player.clock = GameClock::from_world_meta(&world.meta.properties);
 
         Ok(Self {
             world,
             player,
             narrator,
+            dialogue: ChoiceState::new(),
+            dropped: HashMap::new(),
+            conversation: None,
         })
     }
 
@@ -85,12 +119,16 @@ impl FictionSession {
             .ok_or_else(|| FictionError::LocationNotFound(location_name.to_string()))?;
 
         let player_id = EntityId::new();
-        let player = PlayerState::new(player_id, location.id);
+        let mut player = PlayerState::new(player_id, location.id);
+        player.clock = GameClock::from_world_meta(&world.meta.properties);
 
         Ok(Self {
             world,
             player,
             narrator,
+            dialogue: ChoiceState::new(),
+            dropped: HashMap::new(),
+            conversation: None,
         })
     }
 
@@ -99,6 +137,11 @@ impl FictionSession {
         &self.world
     }
 
+    /// Get a mutable reference to the world, e.g. to add improvised locations.
+    pub fn world_mut(&mut self) -> &mut World {
+        &mut self.world
+    }
+
     /// Get the player state.
     pub fn player(&self) -> &PlayerState {
         &self.player
@@ -109,25 +152,203 @@ impl FictionSession {
         &mut self.player
     }
 
+    /// Get the in-session clock, if the world enables one.
+    pub fn clock(&self) -> Option<&GameClock> {
+        self.player.clock.as_ref()
+    }
+
+    /// Advance the in-session clock by the given minutes (no-op without a clock).
+    pub fn pass_time(&mut self, minutes: u32) {
+        if let Some(clock) = &mut self.player.clock {
+            clock.advance(minutes);
+        }
+    }
+
+    /// Get the dialogue state.
+    pub fn dialogue(&self) -> &ChoiceState {
+        &self.dialogue
+    }
+
+    /// Capture the session's progress for [`FictionSession::load`].
+    pub fn save(&self) -> SessionSave {
+        let name = |id: &EntityId| self.world.entity_name(*id).to_string();
+        let mut visited: Vec<String> = self.player.visited.iter().map(name).collect();
+        visited.sort();
+
+        SessionSave {
+            location: name(&self.player.location),
+            inventory: self.player.inventory.iter().map(name).collect(),
+            dropped: self
+                .dropped
+                .iter()
+                .map(|(item, location)| (name(item), name(location)))
+                .collect(),
+            visited,
+            history: self.player.history.iter().map(name).collect(),
+            knowledge: self.player.knowledge.clone().into_iter().collect(),
+            flags: self.player.flags.clone().into_iter().collect(),
+            clock: self.player.clock.clone(),
+            dialogue: self.dialogue.clone(),
+            verbosity: Some(self.narrator.config().verbosity),
+            perspective: Some(self.narrator.config().perspective),
+        }
+    }
+
+    /// Resume a saved session in a freshly loaded world.
+    ///
+    /// Carried and dropped items are moved to where they were when the
+    /// session was saved.
+    pub fn load(world: World, save: SessionSave) -> FictionResult<Self> {
+        let find = |name: &str| world.find_by_name(name).map(|e| e.id);
+        let location = find(&save.location)
+            .ok_or_else(|| FictionError::LocationNotFound(save.location.clone()))?;
+
+        let mut player = PlayerState::new(EntityId::new(), location);
+        for name in &save.visited {
+            let id = find(name).ok_or_else(|| FictionError::LocationNotFound(name.clone()))?;
+            player.visited.insert(id);
+        }
+        let skip = save.history.len().saturating_sub(HISTORY_LIMIT);
+        for name in &save.history[skip..] {
+            let id = find(name).ok_or_else(|| FictionError::LocationNotFound(name.clone()))?;
+            player.history.push(id);
+        }
+        for name in &save.inventory {
+            let id = find(name).ok_or_else(|| FictionError::EntityNotFound(name.clone()))?;
+            player.add_item(id);
+        }
+        let mut dropped = HashMap::new();
+        for (item, place) in &save.dropped {
+            let item_id = find(item).ok_or_else(|| FictionError::EntityNotFound(item.clone()))?;
+            let place_id =
+                find(place).ok_or_else(|| FictionError::LocationNotFound(place.clone()))?;
+            dropped.insert(item_id, place_id);
+        }
+        player.knowledge = save.knowledge.into_iter().collect();
+        player.flags = save.flags.into_iter().collect();
+        player.clock = save.clock;
+
+        let mut narrator = Self::build_narrator(&world.meta.properties);
+        if let Some(verbosity) = save.verbosity {
+            narrator.config_mut().verbosity = verbosity;
+        }
+        if let Some(perspective) = save.perspective {
+            narrator.config_mut().perspective = perspective;
+        }
+
+        let mut session = Self {
+            narrator,
+            world,
+            player,
+            dialogue: save.dialogue,
+            dropped: HashMap::new(),
+            conversation: None,
+        };
+        for item in session.player.inventory.clone() {
+            session.unplace(item)?;
+        }
+        let mut dropped: Vec<_> = dropped.into_iter().collect();
+        dropped.sort_by_key(|(item, _)| item.0);
+        for (item, location) in dropped {
+            session.place(item, location)?;
+        }
+        Ok(session)
+    }
+
     /// Process a player command and return a response.
+    ///
+    /// The response is word-wrapped to the narrator's
+    /// [`wrap_width`](NarratorConfig::wrap_width), if it has one.
     pub fn process(&mut self, input: &str) -> FictionResult<String> {
         let command = parse_command(input);
-        self.execute(command)
+        let output = self
+            .execute(command)
+            .map_err(|error| self.add_hint(error))?;
+        Ok(match self.narrator.config().wrap_width {
+            Some(width) => ww_core::text::wrap(&output, width),
+            None => output,
+        })
+    }
+
+    /// Word-wrap responses to `width` columns, or leave them unwrapped.
+    pub fn set_wrap_width(&mut self, width: Option<usize>) {
+        self.narrator.config_mut().wrap_width = width;
+    }
+
+    /// Attach a "Did you mean ...?" suggestion to an error, when one fits.
+    ///
+    /// Unknown verbs suggest the closest known verb; unresolved names
+    /// suggest the closest entity names, only locations for a location.
+    fn add_hint(&self, error: FictionError) -> FictionError {
+        let hint = match &error {
+            FictionError::UnknownCommand(input) => {
+                closest_verb(input).map(|verb| format!("Did you mean \"{verb}\"?"))
+            }
+            FictionError::EntityNotFound(name) => self.suggest(name, self.visible_entities()),
+            FictionError::LocationNotFound(name) => self.suggest(name, self.known_destinations()),
+            _ => None,
+        };
+        match hint {
+            Some(hint) => FictionError::WithHint {
+                error: Box::new(error),
+                hint,
+            },
+            None => error,
+        }
+    }
+
+    /// "Did you mean A, B or C?" from the `candidates` closest to `name`.
+    fn suggest(&self, name: &str, candidates: Vec<EntityId>) -> Option<String> {
+        let names = suggest_among(&self.world, name, candidates);
+        match &names[..names.len().min(HINT_LIMIT)] {
+            [] => None,
+            [one] => Some(format!("Did you mean {one}?")),
+            [rest @ .., last] => Some(format!("Did you mean {} or {last}?", rest.join(", "))),
+        }
+    }
+
+    /// What the player can see: the current location, what is there, the
+    /// places its exits lead to, and the inventory.
+    fn visible_entities(&self) -> Vec<EntityId> {
+        let here = self.player.location;
+        let mut visible = vec![here];
+        visible.extend(entities_at(&self.world, here));
+        visible.extend(map::exits(&self.world, here).into_iter().map(|(_, to)| to));
+        visible.extend(self.player.inventory.iter().copied());
+        visible
+    }
+
+    /// Where the player could mean to go: places the exits lead to, then
+    /// places already visited.
+    fn known_destinations(&self) -> Vec<EntityId> {
+        let mut known: Vec<EntityId> = map::exits(&self.world, self.player.location)
+            .into_iter()
+            .map(|(_, to)| to)
+            .collect();
+        known.extend(self.player.visited.iter().copied());
+        known
     }
 
     /// Execute a parsed command.
     pub fn execute(&mut self, command: Command) -> FictionResult<String> {
         match command {
             Command::Move { direction } => self.do_move(direction),
+            Command::Back => self.do_back(),
             Command::Go { target } => self.do_go(&target),
             Command::Look { target } => self.do_look(target.as_deref()),
             Command::Take { item } => self.do_take(&item),
             Command::Drop { item } => self.do_drop(&item),
             Command::Talk { character, topic } => self.do_talk(&character, topic.as_deref()),
+            Command::Choose { number } => self.do_choose(number),
             Command::Use { item, target } => self.do_use(&item, target.as_deref()),
             Command::Inventory => self.do_inventory(),
+            Command::Time => Ok(self.do_time()),
+            Command::Map => Ok(self.do_map()),
+            Command::Verbosity { level } => Ok(self.do_verbosity(level)),
+            Command::Perspective { perspective } => Ok(self.do_perspective(perspective)),
             Command::Help { topic } => self.do_help(topic.as_deref()),
             Command::Quit => Ok("Goodbye!".to_string()),
+            Command::Usage { usage } => Err(FictionError::Usage(usage)),
             Command::Unknown { input } => Err(FictionError::UnknownCommand(input)),
         }
     }
@@ -136,30 +357,55 @@ impl FictionSession {
         let current = self.player.location;
 
         // Find exit in that direction
-        let exit = self
-            .world
-            .relationships_from(current)
+        let exit = map::exits(&self.world, current)
             .into_iter()
-            .find(|r| {
-                r.kind == RelationshipKind::ConnectedTo
-                    && r.label
-                        .as_ref()
-                        .is_some_and(|l| l.to_lowercase() == direction.name())
-            })
-            .map(|r| r.target);
+            .find(|&(d, _)| d == direction)
+            .map(|(_, target)| target);
 
         if let Some(destination) = exit {
-            self.player.location = destination;
-            let location = self.world.get_entity(destination).unwrap();
-            let mut output = self.narrator.narrate_arrival(location);
-            output.push_str("\n\n");
-            output.push_str(&self.do_look(None)?);
-            Ok(output)
+            if let Some(closed) = self.closed_message(destination) {
+                return Ok(closed);
+            }
+            self.travel_to(destination);
+            self.narrate_arrival(destination)
         } else {
             Ok(self.narrator.narrate_no_exit(direction.name()))
         }
     }
 
+    /// Retrace the last step, if an exit still leads back.
+    fn do_back(&mut self) -> FictionResult<String> {
+        let Some(previous) = self.player.previous_location() else {
+            return Ok(self.narrator.narrate_no_way_back());
+        };
+        // Going back follows an exit like any other move
+        let connected = map::exits(&self.world, self.player.location)
+            .iter()
+            .any(|&(_, to)| to == previous);
+        if !connected {
+            return Ok(self.narrator.narrate_no_way_back());
+        }
+
+        if let Some(closed) = self.closed_message(previous) {
+            return Ok(closed);
+        }
+        self.set_out();
+        self.player.step_back();
+        self.narrate_arrival(previous)
+    }
+
+    /// Arrival narration followed by the new location's description.
+    fn narrate_arrival(&self, destination: EntityId) -> FictionResult<String> {
+        let location = self
+            .world
+            .get_entity(destination)
+            .ok_or_else(|| FictionError::LocationNotFound("current location".to_string()))?;
+        let mut output = self.narrator.narrate_arrival(location);
+        output.push_str("\n\n");
+        output.push_str(&self.do_look(None)?);
+        Ok(output)
+    }
+
     fn do_go(&mut self, target: &str) -> FictionResult<String> {
         // First check if it's a direction
         if let Some(dir) = Direction::parse(target) {
@@ -175,10 +421,71 @@ impl FictionSession {
             return Err(FictionError::LocationNotFound(target.to_string()));
         }
 
-        self.player.location = destination;
+        if let Some(closed) = self.closed_message(destination) {
+            return Ok(closed);
+        }
+        self.travel_to(destination);
         self.do_look(None)
     }
 
+    /// Move the player and let the clock run for the journey.
+    fn travel_to(&mut self, destination: EntityId) {
+        self.set_out();
+        self.player.move_to(destination);
+    }
+
+    /// End any conversation and let the clock run for a journey.
+    fn set_out(&mut self) {
+        // Walking away ends any conversation
+        self.conversation = None;
+        if let Some(clock) = &mut self.player.clock {
+            let minutes = clock.minutes_per_move;
+            clock.advance(minutes);
+        }
+    }
+
+    /// Narration for a destination that is closed at the current hour, if any.
+    fn closed_message(&self, destination: EntityId) -> Option<String> {
+        let clock = self.player.clock.as_ref()?;
+        let location = self.world.get_entity(destination)?;
+        (!clock.is_open(location)).then(|| self.narrator.narrate_closed(location))
+    }
+
+    fn do_time(&self) -> String {
+        match &self.player.clock {
+            Some(clock) => format!(
+                "It is {clock}.\n{}",
+                self.narrator.narrate_time_of_day(clock.time_of_day())
+            ),
+            None => "Time does not pass here.".to_string(),
+        }
+    }
+
+    fn do_map(&self) -> String {
+        ExitMap::build(&self.world, self.player.location, |id| {
+            self.player.has_visited(id)
+        })
+        .render()
+    }
+
+    fn do_verbosity(&mut self, level: Verbosity) -> String {
+        self.narrator.config_mut().verbosity = level;
+        format!("Descriptions are now {}.", level.name())
+    }
+
+    fn do_perspective(&mut self, perspective: Option<Perspective>) -> String {
+        match perspective {
+            Some(perspective) => {
+                self.narrator.config_mut().perspective = perspective;
+                format!("Narrating in the {} now.", perspective.name())
+            }
+            None => format!(
+                "Narrating in the {}.",
+                self.narrator.config().perspective.name()
+            ),
+        }
+    }
+
     fn do_look(&self, target: Option<&str>) -> FictionResult<String> {
         if let Some(target_name) = target {
             // Look at a specific entity
@@ -240,14 +547,24 @@ impl FictionSession {
             .filter_map(|r| r.label.clone())
             .collect();
 
-        self.narrator
-            .describe_location(location, &characters, &items, &exits)
+        let mut output = self
+            .narrator
+            .describe_location(location, &characters, &items, &exits);
+        if let Some(clock) = &self.player.clock {
+            output.push('\n');
+            output.push_str(&self.narrator.narrate_time_of_day(clock.time_of_day()));
+        }
+        output
     }
 
     fn describe_entity(&self, entity: &ww_core::Entity) -> String {
         self.narrator.describe_entity(entity, &self.world)
     }
 
+    /// Move an item from the current location into the inventory.
+    ///
+    /// Items owned by someone else can only be taken once the player has
+    /// the `take.<item name>` flag, e.g. set by a dialogue choice.
     fn do_take(&mut self, item_name: &str) -> FictionResult<String> {
         let item_id = resolve_entity(&self.world, item_name)
             .ok_or_else(|| FictionError::EntityNotFound(item_name.to_string()))?;
@@ -256,25 +573,56 @@ impl FictionSession {
         if entity.is_none() || entity.unwrap().kind != EntityKind::Item {
             return Err(FictionError::CannotTake(item_name.to_string()));
         }
+        let name = self.world.entity_name(item_id).to_string();
+        if self.player.has_item(item_id) {
+            return Ok(format!("You already have {name}."));
+        }
 
-        // Check if item is at current location
-        let at_location = self.world.relationships_from(item_id).iter().any(|r| {
-            r.target == self.player.location
-                && matches!(
-                    r.kind,
-                    RelationshipKind::LocatedAt | RelationshipKind::ContainedIn
-                )
-        });
+        // The item's placements at the current location
+        let placements: Vec<_> = self
+            .world
+            .relationships_from(item_id)
+            .iter()
+            .filter(|r| {
+                r.source == item_id
+                    && r.target == self.player.location
+                    && matches!(
+                        r.kind,
+                        RelationshipKind::LocatedAt | RelationshipKind::ContainedIn
+                    )
+            })
+            .map(|r| r.id)
+            .collect();
+        if placements.is_empty() {
+            return Err(FictionError::EntityNotHere(name));
+        }
 
-        if !at_location {
-            return Err(FictionError::EntityNotFound(item_name.to_string()));
+        let owner = self
+            .world
+            .relationships_to(item_id)
+            .iter()
+            .find(|r| {
+                r.kind == RelationshipKind::OwnedBy
+                    && r.target == item_id
+                    && r.source != self.player.entity_id
+            })
+            .map(|r| r.source);
+        if let Some(owner) = owner
+            && !self.player.has_flag(&take_flag(&name))
+        {
+            return Err(FictionError::ItemOwned {
+                item: name,
+                owner: self.world.entity_name(owner).to_string(),
+            });
         }
 
+        self.unplace(item_id)?;
         self.player.add_item(item_id);
         let entity = self.world.get_entity(item_id).unwrap();
         Ok(self.narrator.narrate_take(entity))
     }
 
+    /// Move an item from the inventory to the current location.
     fn do_drop(&mut self, item_name: &str) -> FictionResult<String> {
         let item_id = resolve_entity(&self.world, item_name)
             .ok_or_else(|| FictionError::ItemNotInInventory(item_name.to_string()))?;
@@ -284,11 +632,50 @@ impl FictionSession {
         }
 
         self.player.remove_item(item_id);
+        self.place(item_id, self.player.location)?;
         let entity = self.world.get_entity(item_id).unwrap();
         Ok(self.narrator.narrate_drop(entity))
     }
 
-    fn do_talk(&self, entity_name: &str, topic: Option<&str>) -> FictionResult<String> {
+    /// Remove an item from wherever it lies.
+    fn unplace(&mut self, item: EntityId) -> FictionResult<()> {
+        let placements: Vec<_> = self
+            .world
+            .relationships_from(item)
+            .iter()
+            .filter(|r| {
+                r.source == item
+                    && matches!(
+                        r.kind,
+                        RelationshipKind::LocatedAt | RelationshipKind::ContainedIn
+                    )
+            })
+            .map(|r| r.id)
+            .collect();
+        for id in placements {
+            self.world
+                .remove_relationship(id)
+                .map_err(|e| FictionError::CannotTake(e.to_string()))?;
+        }
+        self.dropped.remove(&item);
+        Ok(())
+    }
+
+    /// Put an item down at a location.
+    fn place(&mut self, item: EntityId, location: EntityId) -> FictionResult<()> {
+        self.unplace(item)?;
+        self.world
+            .add_relationship(Relationship::new(
+                item,
+                RelationshipKind::LocatedAt,
+                location,
+            ))
+            .map_err(|e| FictionError::CannotTake(e.to_string()))?;
+        self.dropped.insert(item, location);
+        Ok(())
+    }
+
+    fn do_talk(&mut self, entity_name: &str, topic: Option<&str>) -> FictionResult<String> {
         // Resolve entity at current location (strict proximity)
         let entity_id = if let Some(id) =
             resolve_entity_at_location(&self.world, entity_name, self.player.location)
@@ -300,35 +687,180 @@ impl FictionSession {
             return Err(FictionError::EntityNotFound(entity_name.to_string()));
         };
 
-        let entity = self.world.get_entity(entity_id).unwrap();
+        let dialogues = self.dialogues_of(entity_id);
+        // Find matching dialogue by topic or use first available
+        let dialogue = dialogues
+            .iter()
+            .filter(|d| topic.is_none_or(|t| d.id == t))
+            .find(|d| d.is_available(&self.world, &self.player));
+
+        match dialogue {
+            Some(dlg) => Ok(self.enter_node(entity_id, dlg)),
+            None => Ok(format!(
+                "{} has nothing to say.",
+                self.world.entity_name(entity_id)
+            )),
+        }
+    }
 
-        // Check for DSL-defined dialogues (works for any entity kind)
-        if let Some(fiction) = &entity.components.fiction {
-            // Find matching dialogue by topic or use first available
-            let dialogue = if let Some(t) = topic {
-                fiction.dialogues.iter().find(|d| d.id == t)
-            } else {
-                fiction.dialogues.first()
-            };
+    /// Pick a numbered choice in the ongoing conversation.
+    fn do_choose(&mut self, number: usize) -> FictionResult<String> {
+        let conversation = self
+            .conversation
+            .clone()
+            .ok_or(FictionError::NoConversation)?;
+        let dialogues = self.dialogues_of(conversation.speaker);
+        let node = dialogues
+            .iter()
+            .find(|d| d.id == conversation.node)
+            .ok_or_else(|| FictionError::DialogueNotFound(conversation.node.clone()))?;
+        let index = number
+            .checked_sub(1)
+            .and_then(|i| conversation.options.get(i).copied())
+            .ok_or(FictionError::InvalidChoice(number))?;
+        let choice = &node.choices[index];
+        self.dialogue.mark_choice(&node.id, index);
+
+        let mut output = Vec::new();
+        if !choice.response.is_empty() {
+            let speaker = self.world.entity_name(conversation.speaker);
+            output.push(self.narrator.format_dialogue(speaker, &choice.response));
+        }
+        for effect in &choice.effects {
+            if let Some(line) = self.apply_effect(effect)? {
+                output.push(line);
+            }
+        }
+        // A node whose conditions no longer hold ends the conversation
+        let next = choice
+            .goto
+            .as_deref()
+            .and_then(|id| dialogues.iter().find(|d| d.id == id))
+            .filter(|d| d.is_available(&self.world, &self.player));
+        match next {
+            Some(next) => output.push(self.enter_node(conversation.speaker, next)),
+            None => {
+                self.dialogue.mark_completed(&node.id);
+                self.conversation = None;
+                output.push("The conversation ends.".to_string());
+            }
+        }
+        Ok(output.join("\n\n"))
+    }
+
+    /// The DSL-defined dialogue nodes of an entity (any entity kind can talk).
+    fn dialogues_of(&self, entity_id: EntityId) -> Vec<Dialogue> {
+        let Some(entity) = self.world.get_entity(entity_id) else {
+            return Vec::new();
+        };
+        entity
+            .components
+            .fiction
+            .iter()
+            .flat_map(|f| &f.dialogues)
+            .map(|data| Dialogue::from_data(data, &entity.name))
+            .collect()
+    }
 
-            if let Some(dlg) = dialogue {
-                let mut output = self.narrator.format_dialogue(&entity.name, &dlg.text);
+    /// Show a dialogue node, and wait for a choice if it offers any.
+    fn enter_node(&mut self, speaker: EntityId, dlg: &Dialogue) -> String {
+        self.dialogue.mark_seen(&dlg.id);
+        let name = dlg.speaker.as_deref().unwrap_or_default();
+        let mut output = self.narrator.format_dialogue(name, &dlg.text);
 
-                if !dlg.choices.is_empty() {
-                    output.push('\n');
-                    for (i, choice) in dlg.choices.iter().enumerate() {
-                        output.push_str(&format!(
-                            "\n{}",
-                            self.narrator.format_choice(i, &choice.text)
-                        ));
+        if dlg.choices.is_empty() {
+            self.dialogue.mark_completed(&dlg.id);
+            self.conversation = None;
+            return output;
+        }
+
+        // Choices whose conditions fail are not offered
+        let options: Vec<usize> = (0..dlg.choices.len())
+            .filter(|&i| dlg.choices[i].is_available(&self.world, &self.player))
+            .collect();
+        if options.is_empty() {
+            self.dialogue.mark_completed(&dlg.id);
+            self.conversation = None;
+            return output;
+        }
+
+        output.push('\n');
+        for (number, &i) in options.iter().enumerate() {
+            output.push_str(&format!(
+                "\n{}",
+                self.narrator.format_choice(number, &dlg.choices[i].text)
+            ));
+        }
+        self.conversation = Some(Conversation {
+            speaker,
+            node: dlg.id.clone(),
+            options,
+        });
+        output
+    }
+
+    /// Apply a dialogue effect, returning a line to narrate if it needs one.
+    fn apply_effect(&mut self, effect: &Effect) -> FictionResult<Option<String>> {
+        match effect {
+            Effect::SetFlag { key, value } => self.player.set_flag(key.clone(), value.clone()),
+            Effect::ClearFlag { key } => {
+                self.player.clear_flag(key);
+            }
+            Effect::SetKnowledge { key, value } => self.player.set_knowledge(key.clone(), *value),
+            Effect::GiveItem { item } => {
+                let id = resolve_entity(&self.world, item)
+                    .ok_or_else(|| FictionError::EntityNotFound(item.clone()))?;
+                self.unplace(id)?;
+                self.player.add_item(id);
+                return Ok(Some(format!("You receive {}.", self.world.entity_name(id))));
+            }
+            Effect::TakeItem { item } => {
+                if let Some(id) = resolve_entity(&self.world, item)
+                    && self.player.remove_item(id)
+                {
+                    return Ok(Some(format!(
+                        "You hand over {}.",
+                        self.world.entity_name(id)
+                    )));
+                }
+            }
+            Effect::MovePlayer { location } => {
+                let id = resolve_entity(&self.world, location)
+                    .ok_or_else(|| FictionError::LocationNotFound(location.clone()))?;
+                self.travel_to(id);
+            }
+            Effect::CreateRelationship { from, kind, to } => {
+                if let (Some(from), Some(to)) = (
+                    resolve_entity(&self.world, from),
+                    resolve_entity(&self.world, to),
+                ) {
+                    // A relationship the world refuses is simply not made
+                    let _ = self
+                        .world
+                        .add_relationship(Relationship::new(from, kind.clone(), to));
+                }
+            }
+            Effect::RemoveRelationship { from, kind, to } => {
+                if let (Some(from), Some(to)) = (
+                    resolve_entity(&self.world, from),
+                    resolve_entity(&self.world, to),
+                ) {
+                    let ids: Vec<_> = self
+                        .world
+                        .relationships_from(from)
+                        .iter()
+                        .filter(|r| r.source == from && r.target == to && &r.kind == kind)
+                        .map(|r| r.id)
+                        .collect();
+                    for id in ids {
+                        let _ = self.world.remove_relationship(id);
                     }
                 }
-
-                return Ok(output);
             }
+            // Branching is done through the choice's goto
+            Effect::EmitDialogue { .. } => {}
         }
-
-        Ok(format!("{} has nothing to say.", entity.name))
+        Ok(None)
     }
 
     fn do_use(&self, item_name: &str, target: Option<&str>) -> FictionResult<String> {
@@ -356,14 +888,48 @@ impl FictionSession {
             return Ok("You are carrying nothing.".to_string());
         }
 
-        let mut output = "You are carrying:\n".to_string();
-        for item_id in &self.player.inventory {
-            if let Some(item) = self.world.get_entity(*item_id) {
-                output.push_str(&format!("  - {}\n", item.name));
-            }
-        }
+        let items: Vec<ListItem> = self
+            .player
+            .inventory
+            .iter()
+            .filter_map(|id| self.world.get_entity(*id))
+            .map(|item| {
+                let description = item.description.trim();
+                let text = if description.is_empty() {
+                    item.name.clone()
+                } else {
+                    format!("{}: {description}", item.name)
+                };
+                ListItem::new(text).with_children(self.contents_of(item.id, &mut vec![]))
+            })
+            .collect();
 
-        Ok(output)
+        Ok(format!(
+            "You are carrying:\n{}\n",
+            self.narrator.format_list(&items, ListStyle::Bulleted)
+        ))
+    }
+
+    /// Items contained in `container`, nested by their own contents.
+    ///
+    /// `path` holds the containers above this one so containment cycles stop.
+    fn contents_of(&self, container: EntityId, path: &mut Vec<EntityId>) -> Vec<ListItem> {
+        path.push(container);
+        let mut contents: Vec<_> = self
+            .world
+            .relationships_to(container)
+            .into_iter()
+            .filter(|rel| rel.kind == RelationshipKind::ContainedIn)
+            .filter_map(|rel| self.world.get_entity(rel.source))
+            .filter(|e| e.kind == EntityKind::Item && !path.contains(&e.id))
+            .collect();
+        contents.sort_by(|a, b| a.name.cmp(&b.name));
+        let items = contents
+            .into_iter()
+            .map(|item| ListItem::new(&item.name).with_children(self.contents_of(item.id, path)))
+            .collect();
+        path.pop();
+        items
     }
 
     /// Get the narrator's template registry.
@@ -377,7 +943,9 @@ impl FictionSession {
                 "movement" | "move" | "go" => Ok("**Movement**\n\
                     Use cardinal directions: north, south, east, west, up, down\n\
                     Or abbreviations: n, s, e, w, u, d\n\
-                    You can also: go <location name>"
+                    You can also: go <location name>\n\
+                    back (or go back) - return the way you came\n\
+                    map - draw the exits around you, * marking places you have been"
                     .to_string()),
                 "look" | "examine" => Ok("**Looking**\n\
                     look - describe current location\n\
@@ -388,9 +956,18 @@ impl FictionSession {
                     drop <item> - drop an item\n\
                     inventory (or i) - list what you're carrying"
                     .to_string()),
+                "time" | "clock" => Ok("**Time**\n\
+                    time - show the current day and hour\n\
+                    Moving between locations lets time pass; some places close at night."
+                    .to_string()),
+                "narrator" | "verbosity" | "perspective" => Ok("**Narration**\n\
+                    verbose / normal / terse (or brief) - how much detail descriptions give\n\
+                    perspective [first|second|third] - show or change the narrative perspective"
+                    .to_string()),
                 "talk" | "dialogue" => Ok("**Talking**\n\
                     talk to <entity> - interact with someone or something nearby\n\
                     ask <entity> about <topic> - ask about a specific topic\n\
+                    <number> (or choose <number>) - pick a numbered reply\n\
                     Note: the entity must be at your current location."
                     .to_string()),
                 _ => Ok(format!("No help available for '{}'.", t)),
@@ -399,12 +976,18 @@ impl FictionSession {
             Ok("**Commands**\n\
                 Movement: north, south, east, west, up, down (or n, s, e, w, u, d)\n\
                 go <location> - travel to a named location\n\
+                back - return to the previous location\n\
+                map - draw the exits around you\n\
                 look [target] - examine surroundings or something specific\n\
                 take <item> - pick up an item\n\
                 drop <item> - drop an item\n\
                 inventory (or i) - list what you're carrying\n\
                 talk to <entity> - interact with someone or something nearby\n\
+                <number> - pick a reply in a conversation\n\
                 use <item> [on <target>] - use an item\n\
+                time - show the time of day\n\
+                verbose / normal / terse - change how much detail descriptions give\n\
+                perspective [first|second|third] - change the narrative perspective\n\
                 help [topic] - show help\n\
                 quit - exit the game\n\n\
                 Type 'help <topic>' for more details."
@@ -414,6 +997,11 @@ impl FictionSession {
 }
 
 /// Extract an optional string value from a properties map.
+/// The player flag that allows taking an item owned by someone else.
+fn take_flag(item_name: &str) -> String {
+    format!("take.{}", item_name.to_lowercase())
+}
+
 fn extract_string(properties: &HashMap<String, MetadataValue>, key: &str) -> Option<String> {
     match properties.get(key) {
         Some(MetadataValue::String(s)) => Some(s.clone()),
@@ -424,7 +1012,7 @@ fn extract_string(properties: &HashMap<String, MetadataValue>, key: &str) -> Opt
 #[cfg(test)]
 mod tests {
     use super::*;
-    use ww_core::{Entity, Relationship, WorldMeta};
+    use ww_core::{Entity, WorldMeta};
 
     fn test_world() -> World {
         let mut world = World::new(WorldMeta::new("Test World"));
@@ -525,6 +1113,209 @@ mod tests {
         assert!(session.player().inventory.len() == 1);
     }
 
+    #[test]
+    fn take_and_drop_move_items() {
+        let world = test_world();
+        let mut session = FictionSession::at_location(world, "the Rusty Tankard").unwrap();
+
+        session.process("take pewter mug").unwrap();
+        assert!(!session.do_look(None).unwrap().contains("pewter mug"));
+        assert!(
+            session
+                .do_take("pewter mug")
+                .unwrap()
+                .contains("already have")
+        );
+
+        session.process("e").unwrap();
+        session.process("drop pewter mug").unwrap();
+        assert!(session.player().inventory.is_empty());
+        assert!(session.do_look(None).unwrap().contains("pewter mug"));
+
+        session.process("w").unwrap();
+        assert!(matches!(
+            session.do_take("pewter mug"),
+            Err(FictionError::EntityNotHere(_))
+        ));
+    }
+
+    #[test]
+    fn save_and_load_restore_progress() {
+        use ww_core::component::{DialogueData, FictionComponent};
+
+        // Each call compiles fresh IDs, like reloading a world file
+        let build = || {
+            let mut world = test_world();
+            let tom = world.find_id_by_name("Old Tom").unwrap();
+            world.get_entity_mut(tom).unwrap().components.fiction = Some(FictionComponent {
+                dialogues: vec![DialogueData {
+                    id: "greeting".to_string(),
+                    text: "Welcome!".to_string(),
+                    conditions: vec![],
+                    choices: vec![],
+                }],
+            });
+            let tavern = world.find_id_by_name("the Rusty Tankard").unwrap();
+            let spoon = world
+                .add_entity(Entity::new(EntityKind::Item, "tin spoon"))
+                .unwrap();
+            world
+                .add_relationship(Relationship::new(
+                    spoon,
+                    RelationshipKind::LocatedAt,
+                    tavern,
+                ))
+                .unwrap();
+            world
+        };
+
+        let mut session = FictionSession::at_location(build(), "the Rusty Tankard").unwrap();
+        session.process("talk to Old Tom").unwrap();
+        session.process("take pewter mug").unwrap();
+        session.process("take tin spoon").unwrap();
+        session.process("e").unwrap();
+        session.process("drop tin spoon").unwrap();
+        session.player_mut().set_knowledge("rumor", true);
+
+        let json = session.save().to_json();
+        let save = SessionSave::from_json(&json).unwrap();
+        assert_eq!(save.visited, ["Market Street", "the Rusty Tankard"]);
+
+        let mut loaded = FictionSession::load(build(), save).unwrap();
+        let street = loaded.world().find_id_by_name("Market Street").unwrap();
+        let tavern = loaded.world().find_id_by_name("the Rusty Tankard").unwrap();
+        assert_eq!(loaded.player().location, street);
+        assert!(loaded.player().has_visited(tavern));
+        assert!(loaded.player().has_knowledge("rumor"));
+        assert!(loaded.dialogue().has_seen("greeting"));
+        assert!(loaded.do_inventory().unwrap().contains("pewter mug"));
+        assert!(loaded.do_look(None).unwrap().contains("tin spoon"));
+
+        loaded.process("w").unwrap();
+        let look = loaded.do_look(None).unwrap();
+        assert!(!look.contains("pewter mug"));
+        assert!(!look.contains("tin spoon"));
+    }
+
+    #[test]
+    fn unknown_verb_suggests_closest_verb() {
+        let mut session = FictionSession::at_location(test_world(), "the Rusty Tankard").unwrap();
+        let err = session.process("tkae pewter mug").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "unknown command: tkae pewter mug. Did you mean \"take\"?"
+        );
+        let err = session.process("dance wildly").unwrap_err();
+        assert!(matches!(err, FictionError::UnknownCommand(_)));
+    }
+
+    #[test]
+    fn missing_entity_suggests_names() {
+        let mut session = FictionSession::at_location(test_world(), "the Rusty Tankard").unwrap();
+        let err = session.process("look at Tom").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "entity not found: Tom. Did you mean Old Tom?"
+        );
+        let err = session.process("look at zzzz").unwrap_err();
+        assert!(matches!(err, FictionError::EntityNotFound(_)));
+    }
+
+    #[test]
+    fn missing_location_suggests_only_locations() {
+        let mut session = FictionSession::at_location(test_world(), "the Rusty Tankard").unwrap();
+        let err = session.process("go Street").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "location not found: Street. Did you mean Market Street?"
+        );
+        // "pewter" names an item, never offered as a place to go
+        let err = session.process("go pewter").unwrap_err();
+        assert!(matches!(err, FictionError::LocationNotFound(_)));
+    }
+
+    #[test]
+    fn suggestions_leave_out_what_the_player_cannot_see() {
+        let mut world = test_world();
+        let street = world.find_id_by_name("Market Street").unwrap();
+        let vault = world
+            .add_entity(Entity::new(EntityKind::Location, "the Secret Vault"))
+            .unwrap();
+        world
+            .add_relationship(
+                Relationship::new(street, RelationshipKind::ConnectedTo, vault).with_label("down"),
+            )
+            .unwrap();
+        let mut ghost = Entity::new(EntityKind::Character, "Old Tomas");
+        ghost.description = "Haunts the vault.".to_string();
+        let ghost = world.add_entity(ghost).unwrap();
+        world
+            .add_relationship(Relationship::new(ghost, RelationshipKind::LocatedAt, vault))
+            .unwrap();
+
+        let mut session = FictionSession::at_location(world, "the Rusty Tankard").unwrap();
+        let err = session.process("talk to Tom").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "entity not found: Tom. Did you mean Old Tom?"
+        );
+        let err = session.process("go Vault").unwrap_err();
+        assert_eq!(err.to_string(), "location not found: Vault");
+
+        session.process("east").unwrap();
+        let err = session.process("go Vault").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "location not found: Vault. Did you mean the Secret Vault?"
+        );
+    }
+
+    #[test]
+    fn load_rejects_unknown_location() {
+        let world = test_world();
+        let mut save = FictionSession::new(world.clone()).unwrap().save();
+        save.location = "Atlantis".to_string();
+        assert!(matches!(
+            FictionSession::load(world, save),
+            Err(FictionError::LocationNotFound(_))
+        ));
+        assert!(matches!(
+            SessionSave::from_json("{"),
+            Err(FictionError::InvalidSave(_))
+        ));
+    }
+
+    #[test]
+    fn take_owned_item_needs_flag() {
+        let mut world = test_world();
+        let tavern = world.find_by_name("the Rusty Tankard").unwrap().id;
+        let tom = world.find_by_name("Old Tom").unwrap().id;
+        let ledger = world
+            .add_entity(Entity::new(EntityKind::Item, "ledger"))
+            .unwrap();
+        world
+            .add_relationship(Relationship::new(
+                ledger,
+                RelationshipKind::LocatedAt,
+                tavern,
+            ))
+            .unwrap();
+        world
+            .add_relationship(Relationship::new(tom, RelationshipKind::OwnedBy, ledger))
+            .unwrap();
+        let mut session = FictionSession::at_location(world, "the Rusty Tankard").unwrap();
+
+        let err = session.do_take("ledger").unwrap_err();
+        assert_eq!(err.to_string(), "ledger belongs to Old Tom.");
+        assert!(session.player().inventory.is_empty());
+
+        session
+            .player_mut()
+            .set_flag("take.ledger", MetadataValue::Boolean(true));
+        session.do_take("ledger").unwrap();
+        assert!(session.player().has_item(ledger));
+    }
+
     #[test]
     fn inventory_empty() {
         let world = test_world();
@@ -543,6 +1334,47 @@ mod tests {
         assert!(output.contains("pewter mug"));
     }
 
+    #[test]
+    fn inventory_lists_descriptions() {
+        let mut world = test_world();
+        let mug = world.find_by_name("pewter mug").unwrap().id;
+        world.get_entity_mut(mug).unwrap().description = "Dented, but serviceable.".to_string();
+        let mut session = FictionSession::at_location(world, "the Rusty Tankard").unwrap();
+        session.do_take("pewter mug").unwrap();
+
+        let output = session.do_inventory().unwrap();
+        assert!(output.contains("pewter mug: Dented, but serviceable."));
+    }
+
+    #[test]
+    fn inventory_nests_contents_in_configured_style() {
+        let mut world = test_world();
+        world.meta.properties.insert(
+            "fiction.list_style".to_string(),
+            MetadataValue::String("numbered".to_string()),
+        );
+        let mug_id = world.find_by_name("pewter mug").unwrap().id;
+        let coin_id = world
+            .add_entity(Entity::new(EntityKind::Item, "copper coin"))
+            .unwrap();
+        world
+            .add_relationship(Relationship::new(
+                coin_id,
+                RelationshipKind::ContainedIn,
+                mug_id,
+            ))
+            .unwrap();
+
+        let mut session = FictionSession::at_location(world, "the Rusty Tankard").unwrap();
+        session.do_take("pewter mug").unwrap();
+
+        let output = session.do_inventory().unwrap();
+        assert_eq!(
+            output,
+            "You are carrying:\n  1. pewter mug\n     1. copper coin\n"
+        );
+    }
+
     #[test]
     fn process_command() {
         let world = test_world();
@@ -600,7 +1432,7 @@ mod tests {
             }],
         });
 
-        let session = FictionSession::at_location(world, "the Rusty Tankard").unwrap();
+        let mut session = FictionSession::at_location(world, "the Rusty Tankard").unwrap();
         let output = session.do_talk("Old Tom", None).unwrap();
 
         assert!(output.contains("Old Tom"));
@@ -609,10 +1441,164 @@ mod tests {
         assert!(output.contains("[2] Just passing through."));
     }
 
+    /// Old Tom with a two-node dialogue tree.
+    fn dialogue_tree_world() -> World {
+        use ww_core::component::{ChoiceData, DialogueData, FictionComponent};
+
+        let choice = |text: &str, response: &str, goto: Option<&str>| ChoiceData {
+            text: text.to_string(),
+            response: response.to_string(),
+            effects: vec![],
+            conditions: vec![],
+            goto: goto.map(str::to_string),
+        };
+        let mut world = test_world();
+        let tom_id = world.find_id_by_name("Old Tom").unwrap();
+        world.get_entity_mut(tom_id).unwrap().components.fiction = Some(FictionComponent {
+            dialogues: vec![
+                DialogueData {
+                    id: "greeting".to_string(),
+                    text: "Welcome, stranger.".to_string(),
+                    conditions: vec![],
+                    choices: vec![
+                        choice("Ask about the road", "", Some("road")),
+                        choice("Leave", "Safe travels.", None),
+                    ],
+                },
+                DialogueData {
+                    id: "road".to_string(),
+                    text: "The road north is washed out.".to_string(),
+                    conditions: vec![],
+                    choices: vec![choice("Thanks", "", Some("greeting"))],
+                },
+            ],
+        });
+        world
+    }
+
+    #[test]
+    fn talk_walks_dialogue_tree() {
+        let mut session =
+            FictionSession::at_location(dialogue_tree_world(), "the Rusty Tankard").unwrap();
+        session.process("talk to Old Tom").unwrap();
+
+        let output = session.process("1").unwrap();
+        assert!(output.contains("washed out"));
+        assert!(output.contains("[1] Thanks"));
+        assert!(session.dialogue().has_seen("road"));
+
+        let output = session.process("choose 1").unwrap();
+        assert!(output.contains("Welcome, stranger."));
+
+        let output = session.process("2").unwrap();
+        assert!(output.contains("**Old Tom**: Safe travels."));
+        assert!(output.ends_with("The conversation ends."));
+        assert!(session.dialogue().has_selected_choice("greeting", 1));
+        assert!(matches!(
+            session.process("1"),
+            Err(FictionError::NoConversation)
+        ));
+    }
+
+    #[test]
+    fn dialogue_conditions_hide_choices_and_effects_apply() {
+        use ww_core::component::{ChoiceData, DialogueData, FictionComponent};
+
+        let choice = |text: &str, conditions: &[&str], effects: &[&str]| ChoiceData {
+            text: text.to_string(),
+            response: String::new(),
+            effects: effects.iter().map(|e| e.to_string()).collect(),
+            conditions: conditions.iter().map(|c| c.to_string()).collect(),
+            goto: Some("greeting".to_string()),
+        };
+        let mut world = test_world();
+        let tom_id = world.find_id_by_name("Old Tom").unwrap();
+        world.get_entity_mut(tom_id).unwrap().components.fiction = Some(FictionComponent {
+            dialogues: vec![DialogueData {
+                id: "greeting".to_string(),
+                text: "What'll it be?".to_string(),
+                conditions: vec![],
+                choices: vec![
+                    choice("Show the mug", &["has_item \"pewter mug\""], &[]),
+                    choice(
+                        "Ask for a drink",
+                        &["not flag served"],
+                        &["gives \"pewter mug\"", "sets flag served"],
+                    ),
+                ],
+            }],
+        });
+
+        let mut session = FictionSession::at_location(world, "the Rusty Tankard").unwrap();
+        let output = session.process("talk to Old Tom").unwrap();
+        assert!(!output.contains("Show the mug"));
+        assert!(output.contains("[1] Ask for a drink"));
+
+        let output = session.process("1").unwrap();
+        assert!(output.contains("You receive pewter mug."));
+        assert!(output.contains("[1] Show the mug"));
+        assert!(!output.contains("Ask for a drink"));
+        assert!(session.player().has_flag("served"));
+        let mug = session.world().find_id_by_name("pewter mug").unwrap();
+        assert!(session.player().has_item(mug));
+    }
+
+    #[test]
+    fn older_and_unknown_conditions_gate_choices() {
+        use ww_core::component::{ChoiceData, DialogueData, FictionComponent};
+
+        let choice = |text: &str, condition: &str| ChoiceData {
+            text: text.to_string(),
+            response: String::new(),
+            effects: vec![],
+            conditions: vec![condition.to_string()],
+            goto: None,
+        };
+        let mut world = test_world();
+        let tom_id = world.find_id_by_name("Old Tom").unwrap();
+        world.get_entity_mut(tom_id).unwrap().components.fiction = Some(FictionComponent {
+            dialogues: vec![DialogueData {
+                id: "greeting".to_string(),
+                text: "What'll it be?".to_string(),
+                conditions: vec![],
+                choices: vec![
+                    choice("Kael sent me", "player has quest.join_order"),
+                    choice("Whisper the password", "the moon is full"),
+                    choice("Leave", "not flag gone"),
+                ],
+            }],
+        });
+
+        let mut session = FictionSession::at_location(world, "the Rusty Tankard").unwrap();
+        let output = session.process("talk to Old Tom").unwrap();
+        assert!(!output.contains("Kael sent me"));
+        assert!(!output.contains("Whisper"));
+
+        session
+            .player_mut()
+            .set_flag("quest.join_order", MetadataValue::Boolean(true));
+        let output = session.process("talk to Old Tom").unwrap();
+        assert!(output.contains("[1] Kael sent me"));
+        assert!(output.contains("[2] Leave"));
+        assert!(!output.contains("Whisper"));
+    }
+
+    #[test]
+    fn choose_out_of_range_keeps_conversation() {
+        let mut session =
+            FictionSession::at_location(dialogue_tree_world(), "the Rusty Tankard").unwrap();
+        session.process("talk to Old Tom").unwrap();
+        assert!(matches!(
+            session.process("3"),
+            Err(FictionError::InvalidChoice(3))
+        ));
+        assert!(session.process("1").unwrap().contains("washed out"));
+    }
+
     #[test]
     fn talk_no_dialogue_fallback() {
         let world = test_world();
-        let session = FictionSession::at_location(world, "the Rusty Tankard").unwrap();
+        let mut session = FictionSession::at_location(world, "the Rusty Tankard").unwrap();
 
         let output = session.do_talk("Old Tom", None).unwrap();
         assert!(output.contains("nothing to say"));
@@ -633,7 +1619,7 @@ mod tests {
             .unwrap();
 
         // Player is at the tavern, Sly Pete is at Market Street
-        let session = FictionSession::at_location(world, "the Rusty Tankard").unwrap();
+        let mut session = FictionSession::at_location(world, "the Rusty Tankard").unwrap();
         let result = session.do_talk("Sly Pete", None);
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("not here"));
@@ -642,7 +1628,7 @@ mod tests {
     #[test]
     fn talk_entity_not_found() {
         let world = test_world();
-        let session = FictionSession::at_location(world, "the Rusty Tankard").unwrap();
+        let mut session = FictionSession::at_location(world, "the Rusty Tankard").unwrap();
         let result = session.do_talk("Nonexistent Person", None);
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("not found"));
@@ -673,7 +1659,7 @@ mod tests {
             ))
             .unwrap();
 
-        let session = FictionSession::at_location(world, "the Rusty Tankard").unwrap();
+        let mut session = FictionSession::at_location(world, "the Rusty Tankard").unwrap();
         let output = session.do_talk("MemoComm", None).unwrap();
         assert!(output.contains("PLAYBACK"));
     }
@@ -704,7 +1690,7 @@ mod tests {
             .unwrap();
 
         // Player is at the tavern, Terminal is at Market Street
-        let session = FictionSession::at_location(world, "the Rusty Tankard").unwrap();
+        let mut session = FictionSession::at_location(world, "the Rusty Tankard").unwrap();
         let result = session.do_talk("Terminal", None);
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("not here"));
@@ -770,6 +1756,22 @@ mod tests {
         assert!(output.contains("stands before"));
     }
 
+    #[test]
+    fn responses_wrap_to_the_configured_width() {
+        let world = test_world();
+        let mut session = FictionSession::at_location(world, "the Rusty Tankard").unwrap();
+        let unwrapped = session.process("look").unwrap();
+        assert!(unwrapped.lines().any(|line| line.chars().count() > 30));
+
+        session.set_wrap_width(Some(30));
+        let wrapped = session.process("look").unwrap();
+        assert!(wrapped.lines().all(|line| line.chars().count() <= 30));
+        assert_eq!(
+            wrapped.split_whitespace().collect::<Vec<_>>(),
+            unwrapped.split_whitespace().collect::<Vec<_>>()
+        );
+    }
+
     #[test]
     fn narrator_used_for_move() {
         let mut world = test_world();
@@ -796,4 +1798,227 @@ mod tests {
         // Humorous no-exit: "walk north into a wall"
         assert!(output.contains("wall"));
     }
+
+    #[test]
+    fn map_marks_visited_exits() {
+        let mut world = test_world();
+        let tavern = world.find_id_by_name("the Rusty Tankard").unwrap();
+        let cellar = world
+            .add_entity(Entity::new(EntityKind::Location, "the Cellar"))
+            .unwrap();
+        world
+            .add_relationship(
+                Relationship::new(tavern, RelationshipKind::ConnectedTo, cellar).with_label("down"),
+            )
+            .unwrap();
+        let mut session = FictionSession::at_location(world, "the Rusty Tankard").unwrap();
+        assert_eq!(
+            session.process("map").unwrap(),
+            "[the Rusty Tankard] -- Market Street\nDown: the Cellar"
+        );
+
+        session.process("e").unwrap();
+        assert_eq!(
+            session.process("map").unwrap(),
+            "the Rusty Tankard* -- [Market Street]\n* visited"
+        );
+    }
+
+    #[test]
+    fn back_retraces_steps() {
+        let mut session = FictionSession::at_location(test_world(), "the Rusty Tankard").unwrap();
+        assert_eq!(
+            session.process("back").unwrap(),
+            "You cannot go back that way."
+        );
+
+        session.process("e").unwrap();
+        let output = session.process("go back").unwrap();
+        assert!(output.starts_with("You arrive at the Rusty Tankard."));
+        assert!(output.contains("Old Tom is here."));
+        assert!(session.player().history.is_empty());
+    }
+
+    #[test]
+    fn back_needs_a_connecting_exit() {
+        let mut world = test_world();
+        world
+            .add_entity(Entity::new(EntityKind::Location, "the Cellar"))
+            .unwrap();
+        let mut session = FictionSession::at_location(world, "the Rusty Tankard").unwrap();
+        session.process("go the Cellar").unwrap();
+        assert_eq!(
+            session.process("back").unwrap(),
+            "You cannot go back that way."
+        );
+        assert_eq!(
+            session.world().entity_name(session.player().location),
+            "the Cellar"
+        );
+    }
+
+    #[test]
+    fn back_needs_an_exit_leading_back() {
+        let mut world = test_world();
+        let tavern = world.find_id_by_name("the Rusty Tankard").unwrap();
+        let cellar = world
+            .add_entity(Entity::new(EntityKind::Location, "the Cellar"))
+            .unwrap();
+        world
+            .add_relationship(
+                Relationship::new(tavern, RelationshipKind::ConnectedTo, cellar).with_label("down"),
+            )
+            .unwrap();
+        // Connected, but with no direction to leave by
+        world
+            .add_relationship(Relationship::new(
+                cellar,
+                RelationshipKind::ConnectedTo,
+                tavern,
+            ))
+            .unwrap();
+        let mut session = FictionSession::at_location(world, "the Rusty Tankard").unwrap();
+        assert!(session.process("down").unwrap().contains("the Cellar"));
+        assert_eq!(
+            session.process("back").unwrap(),
+            "You cannot go back that way."
+        );
+    }
+
+    #[test]
+    fn history_survives_save() {
+        let mut session = FictionSession::at_location(test_world(), "the Rusty Tankard").unwrap();
+        session.process("e").unwrap();
+        let save = SessionSave::from_json(&session.save().to_json()).unwrap();
+        assert_eq!(save.history, ["the Rusty Tankard"]);
+        let mut loaded = FictionSession::load(test_world(), save).unwrap();
+        assert!(loaded.process("back").unwrap().contains("Rusty Tankard"));
+    }
+
+    #[test]
+    fn verbosity_changes_live() {
+        let mut session = FictionSession::at_location(test_world(), "the Rusty Tankard").unwrap();
+        assert!(
+            session
+                .process("look")
+                .unwrap()
+                .contains("Exits: east, west")
+        );
+        assert_eq!(
+            session.process("brief").unwrap(),
+            "Descriptions are now terse."
+        );
+        assert!(session.process("look").unwrap().contains("[east, west]"));
+        session.process("verbose").unwrap();
+        assert_eq!(session.narrator().config().verbosity, Verbosity::Verbose);
+    }
+
+    #[test]
+    fn perspective_changes_live() {
+        let mut session = FictionSession::at_location(test_world(), "the Rusty Tankard").unwrap();
+        assert_eq!(
+            session.process("perspective").unwrap(),
+            "Narrating in the second person."
+        );
+        session.process("perspective first").unwrap();
+        assert!(
+            session
+                .process("look")
+                .unwrap()
+                .contains("I see pewter mug here.")
+        );
+        assert!(session.process("e").unwrap().contains("I arrive at"));
+
+        session.process("perspective third").unwrap();
+        assert!(
+            session
+                .process("w")
+                .unwrap()
+                .contains("The hero arrives at")
+        );
+        assert_eq!(
+            session
+                .process("perspective sideways")
+                .unwrap_err()
+                .to_string(),
+            "usage: perspective [first | second | third]"
+        );
+    }
+
+    #[test]
+    fn narration_settings_survive_save() {
+        let mut session = FictionSession::at_location(test_world(), "the Rusty Tankard").unwrap();
+        session.process("terse").unwrap();
+        session.process("perspective third").unwrap();
+        let save = SessionSave::from_json(&session.save().to_json()).unwrap();
+        let loaded = FictionSession::load(test_world(), save).unwrap();
+        let config = loaded.narrator().config();
+        assert_eq!(config.verbosity, Verbosity::Terse);
+        assert_eq!(config.perspective, Perspective::ThirdPerson);
+    }
+
+    #[test]
+    fn clock_disabled_by_default() {
+        let mut session = FictionSession::at_location(test_world(), "the Rusty Tankard").unwrap();
+        assert!(session.clock().is_none());
+        assert_eq!(session.process("time").unwrap(), "Time does not pass here.");
+        session.pass_time(60);
+        assert!(session.clock().is_none());
+    }
+
+    #[test]
+    fn clock_advances_on_move() {
+        let mut world = test_world();
+        world
+            .meta
+            .properties
+            .insert("fiction.start_hour".to_string(), MetadataValue::Integer(20));
+        world.meta.properties.insert(
+            "fiction.minutes_per_move".to_string(),
+            MetadataValue::Integer(90),
+        );
+        let mut session = FictionSession::at_location(world, "the Rusty Tankard").unwrap();
+        assert!(
+            session
+                .process("time")
+                .unwrap()
+                .contains("Day 1, 20:00 (evening)")
+        );
+
+        let output = session.process("east").unwrap();
+        assert!(output.contains("Night has fallen."));
+        assert!(session.process("time").unwrap().contains("21:30"));
+    }
+
+    #[test]
+    fn closed_location_blocks_entry() {
+        let mut world = test_world();
+        world
+            .meta
+            .properties
+            .insert("fiction.start_hour".to_string(), MetadataValue::Integer(22));
+        let street_id = world.find_by_name("Market Street").unwrap().id;
+        world.get_entity_mut(street_id).unwrap().properties.insert(
+            "open_hours".to_string(),
+            MetadataValue::String("8-20".to_string()),
+        );
+        let mut session = FictionSession::at_location(world, "the Rusty Tankard").unwrap();
+
+        let output = session.process("east").unwrap();
+        assert!(output.contains("closed at this hour"));
+        assert_eq!(
+            session
+                .world()
+                .get_entity(session.player().location)
+                .unwrap()
+                .name,
+            "the Rusty Tankard"
+        );
+        // No time passes for a refused move
+        assert_eq!(session.clock().unwrap().hour(), 22);
+
+        session.pass_time(11 * 60);
+        let output = session.process("go Market Street").unwrap();
+        assert!(output.contains("Market Street"));
+    }
 }
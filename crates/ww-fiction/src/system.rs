@@ -103,6 +103,18 @@ impl System for FictionSystem {
                         None
                     }
                 }
+                SimEventKind::WeatherChanged { region, to, .. } => ctx
+                    .world
+                    .get_entity(*region)
+                    .map(|e| format!("The weather over {} turns to {to}.", e.name)),
+                SimEventKind::ResourceSurplus { faction, .. } => ctx
+                    .world
+                    .get_entity(*faction)
+                    .map(|e| format!("{} prospers.", e.name)),
+                SimEventKind::ResourceShortage { faction, .. } => ctx
+                    .world
+                    .get_entity(*faction)
+                    .map(|e| format!("{} has run out of resources!", e.name)),
                 SimEventKind::EntityDied { entity, cause } => ctx
                     .world
                     .get_entity(*entity)
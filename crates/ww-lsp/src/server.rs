@@ -9,7 +9,10 @@ use tower_lsp::jsonrpc::Result;
 use tower_lsp::lsp_types::*;
 use tower_lsp::{Client, LanguageServer};
 
-use ww_dsl::ast::{Declaration, SourceFile, Statement};
+use ww_core::mention;
+use ww_core::validate::ValidateOptions;
+use ww_dsl::SourceEdit;
+use ww_dsl::ast::{Declaration, SourceFile, Spanned, Statement};
 use ww_dsl::diagnostics::Severity;
 use ww_dsl::lexer::Token;
 use ww_dsl::resolver::{Resolver, SourceMap};
@@ -27,6 +30,8 @@ struct FileSlice {
 struct EntityInfo {
     name: String,
     kind: String,
+    /// Authoring doc comment from the `--` lines above the declaration.
+    doc: Option<String>,
     /// Byte span within that file's own text (not the concatenated source).
     local_span: std::ops::Range<usize>,
     /// Which file this entity is defined in.
@@ -55,6 +60,9 @@ struct WorkspaceState {
     source_map: Option<SourceMap>,
     /// Hash of the last compiled source (for incremental compilation).
     last_source_hash: u64,
+    /// Whether the last source lexed and parsed without errors, so the next
+    /// change can re-parse only the declarations it touches.
+    parse_clean: bool,
 }
 
 pub struct WwLanguageServer {
@@ -77,6 +85,7 @@ impl WwLanguageServer {
                 concatenated_source: String::new(),
                 source_map: None,
                 last_source_hash: 0,
+                parse_clean: false,
             })),
         }
     }
@@ -90,6 +99,14 @@ impl WwLanguageServer {
         };
         let open_docs = state.open_docs.clone();
         let last_hash = state.last_source_hash;
+        let previous = match (&state.ast, &state.tokens) {
+            (Some(ast), Some(tokens)) if state.parse_clean => Some((
+                ast.clone(),
+                tokens.clone(),
+                state.concatenated_source.clone(),
+            )),
+            _ => None,
+        };
         drop(state);
 
         // Discover all .ww files recursively
@@ -97,50 +114,39 @@ impl WwLanguageServer {
         collect_ww_files(&root, &mut file_paths);
         file_paths.sort();
 
-        // Build file slices + SourceMap
-        let mut slices: Vec<FileSlice> = Vec::new();
-        let mut concatenated = String::new();
-        let mut dsl_source_map = SourceMap::new();
-
+        // Gather the files, following includes, with open documents taking
+        // precedence over what is on disk
+        let mut inputs = Vec::new();
         for path in &file_paths {
-            let uri = match Url::from_file_path(path) {
-                Ok(u) => u,
-                Err(()) => match Url::parse(&format!("file://{}", path.display())) {
-                    Ok(u) => u,
-                    Err(_) => continue,
-                },
-            };
-
-            let text = if let Some(open_text) = open_docs.get(&uri) {
-                open_text.clone()
-            } else {
-                match std::fs::read_to_string(path) {
+            let text = match file_uri(path).and_then(|uri| open_docs.get(&uri)) {
+                Some(open_text) => open_text.clone(),
+                None => match std::fs::read_to_string(path) {
                     Ok(t) => t,
                     Err(_) => continue,
-                }
+                },
             };
-
-            let len = text.len();
-
-            if !concatenated.is_empty() {
-                concatenated.push('\n');
-            }
-            let offset = concatenated.len();
-            concatenated.push_str(&text);
-
-            let file_name = path
-                .file_name()
-                .map(|n| n.to_string_lossy().to_string())
-                .unwrap_or_else(|| path.display().to_string());
-            dsl_source_map.add_file(file_name, offset, len);
-
-            slices.push(FileSlice {
-                uri,
-                offset,
-                len,
+            let name = path.strip_prefix(&root).unwrap_or(path);
+            inputs.push(ww_dsl::InputFile {
+                name: name.to_string_lossy().replace('\\', "/"),
                 text,
             });
         }
+        let sources = ww_dsl::include::load_sources(&root, &inputs);
+        let concatenated = sources.text;
+        let dsl_source_map = sources.source_map;
+
+        let slices: Vec<FileSlice> = dsl_source_map
+            .files()
+            .iter()
+            .filter_map(|file| {
+                Some(FileSlice {
+                    uri: file_uri(&root.join(&file.name))?,
+                    offset: file.offset,
+                    len: file.len,
+                    text: concatenated[file.offset..file.offset + file.len].to_string(),
+                })
+            })
+            .collect();
 
         if concatenated.is_empty() {
             return;
@@ -154,25 +160,37 @@ impl WwLanguageServer {
             return;
         }
 
-        // Step-by-step pipeline (matching compile_with_source_map in ww-dsl)
-        let (tokens, lex_errors) = lexer::lex(&concatenated);
-
-        let mut diagnostics: Vec<ww_dsl::Diagnostic> = lex_errors
-            .into_iter()
-            .map(|e| ww_dsl::Diagnostic::error(e.span, e.message))
-            .collect();
-
-        let (ast, parse_errors) = parser::parse_lenient(&tokens);
-        diagnostics.extend(
-            parse_errors
-                .into_iter()
-                .map(|e| ww_dsl::Diagnostic::error(e.span, e.message)),
-        );
+        // Step-by-step pipeline (matching compile_files in ww-dsl),
+        // re-parsing only the edited declarations when the last parse was
+        // clean. Included files are spliced out of text order, which the
+        // incremental re-parse cannot follow.
+        let has_includes = sources
+            .tokens
+            .iter()
+            .any(|(token, _)| matches!(token, Token::Include(_)));
+        let (ast, tokens, mut diagnostics) = match previous {
+            Some((previous_ast, previous_tokens, previous_source)) if !has_includes => {
+                let edit = SourceEdit::between(&previous_source, &concatenated);
+                let reparse =
+                    ww_dsl::compile_source_incremental(&previous_ast, &concatenated, &edit);
+                let tokens = reparse.splice_tokens(&previous_tokens);
+                (reparse.ast, tokens, reparse.diagnostics)
+            }
+            _ => {
+                let mut diagnostics = sources.diagnostics;
+                let (mut ast, parse_errors) = parser::parse_lenient(&sources.tokens);
+                diagnostics.extend(parse_errors.into_iter().map(ww_dsl::Diagnostic::from));
+                parser::attach_doc_comments(&mut ast, &sources.tokens, &sources.comments);
+                (ast, sources.tokens, diagnostics)
+            }
+        };
+        let parse_clean = diagnostics.is_empty() && !has_includes;
 
         let resolver = Resolver::resolve(&ast, &dsl_source_map);
         let mut result = compiler::compile(&ast, &resolver, dsl_source_map);
 
         diagnostics.append(&mut result.diagnostics);
+        diagnostics.extend(result.validate(ValidateOptions::default()));
         result.diagnostics = diagnostics;
 
         let per_file_diags = map_diagnostics_to_files(&slices, &result.diagnostics);
@@ -193,6 +211,7 @@ impl WwLanguageServer {
                 entities.push(EntityInfo {
                     name: entity.name.clone(),
                     kind: entity.kind.to_string(),
+                    doc: entity.doc.clone(),
                     local_span: local_start..local_end,
                     uri: slice.uri.clone(),
                 });
@@ -210,6 +229,7 @@ impl WwLanguageServer {
             state.concatenated_source = concatenated;
             state.source_map = Some(result.source_map);
             state.last_source_hash = source_hash;
+            state.parse_clean = parse_clean;
         }
 
         // Publish diagnostics for each file
@@ -219,6 +239,13 @@ impl WwLanguageServer {
     }
 }
 
+/// The `file://` URL of a path.
+fn file_uri(path: &std::path::Path) -> Option<Url> {
+    Url::from_file_path(path)
+        .ok()
+        .or_else(|| Url::parse(&format!("file://{}", path.display())).ok())
+}
+
 /// Recursively collect all .ww files under a directory.
 fn collect_ww_files(dir: &PathBuf, out: &mut Vec<PathBuf>) {
     let entries = match std::fs::read_dir(dir) {
@@ -326,14 +353,35 @@ fn map_diagnostics_to_files(
                 Severity::Error => Some(DiagnosticSeverity::ERROR),
                 Severity::Warning => Some(DiagnosticSeverity::WARNING),
             };
+            let related: Vec<DiagnosticRelatedInformation> = diag
+                .related
+                .iter()
+                .filter_map(|(span, label)| {
+                    let related_slice = find_slice_for_span(slices, span)?;
+                    let start = span.start.saturating_sub(related_slice.offset);
+                    let end = span
+                        .end
+                        .saturating_sub(related_slice.offset)
+                        .min(related_slice.len);
+                    Some(DiagnosticRelatedInformation {
+                        location: Location {
+                            uri: related_slice.uri.clone(),
+                            range: byte_span_to_range(&related_slice.text, &(start..end)),
+                        },
+                        message: label.clone(),
+                    })
+                })
+                .collect();
             per_file
                 .entry(slice.uri.clone())
                 .or_default()
                 .push(Diagnostic {
                     range,
                     severity,
+                    code: diag.code.map(|c| NumberOrString::String(c.to_string())),
                     source: Some("ww".into()),
                     message: diag.message.clone(),
+                    related_information: (!related.is_empty()).then_some(related),
                     ..Default::default()
                 });
         }
@@ -412,7 +460,7 @@ fn find_all_entity_references(ast: &SourceFile, name: &str) -> Vec<(std::ops::Ra
 
 /// Recursively collect entity name references from a statement body (handles nested blocks).
 fn collect_refs_in_body(
-    body: &[ww_dsl::ast::Spanned<Statement>],
+    body: &[Spanned<Statement>],
     name_lower: &str,
     refs: &mut Vec<(std::ops::Range<usize>, bool)>,
 ) {
@@ -425,10 +473,8 @@ fn collect_refs_in_body(
                     }
                 }
             }
-            Statement::Exit(exit) => {
-                if exit.target.node.to_lowercase() == name_lower {
-                    refs.push((exit.target.span.clone(), false));
-                }
+            Statement::Exit(exit) if exit.target.node.to_lowercase() == name_lower => {
+                refs.push((exit.target.span.clone(), false));
             }
             Statement::Block(block) => {
                 collect_refs_in_body(&block.body, name_lower, refs);
@@ -453,6 +499,120 @@ fn global_span_to_location(
     })
 }
 
+/// Inlay hints after each relationship target in `slice`, showing the
+/// inverse relationship the target entity gets, e.g. `Kael leads the Order`
+/// after `led by Kael`. Only hints inside `range` are returned.
+fn inverse_relationship_hints(ast: &SourceFile, slice: &FileSlice, range: Range) -> Vec<InlayHint> {
+    let mut hints = Vec::new();
+    for decl in &ast.declarations {
+        let Declaration::Entity(entity) = &decl.node else {
+            continue;
+        };
+        if decl.span.start < slice.offset || decl.span.start >= slice.offset + slice.len {
+            continue;
+        }
+        for stmt in &entity.body {
+            let Statement::Relationship(rel) = &stmt.node else {
+                continue;
+            };
+            // The hint reads from the target, which is the relationship's
+            // source for reversed keywords like `led by`
+            let kind = rel.keyword.kind();
+            let phrase = if rel.keyword.is_reversed() {
+                kind.verb_phrase()
+            } else {
+                let Some(inverse) = kind.inverse_phrase() else {
+                    continue;
+                };
+                inverse
+            };
+            for target in &rel.targets {
+                let end = target.span.end.saturating_sub(slice.offset).min(slice.len);
+                let position = byte_offset_to_position(&slice.text, end);
+                if position < range.start || position > range.end {
+                    continue;
+                }
+                hints.push(InlayHint {
+                    position,
+                    label: InlayHintLabel::String(format!(
+                        "{} {phrase} {}",
+                        target.node, entity.name.node
+                    )),
+                    kind: None,
+                    text_edits: None,
+                    tooltip: Some(InlayHintTooltip::String(format!(
+                        "Implied on {}",
+                        target.node
+                    ))),
+                    padding_left: Some(true),
+                    padding_right: None,
+                    data: None,
+                });
+            }
+        }
+    }
+    hints
+}
+
+/// Folding ranges for the declarations, nested blocks, and multiline doc
+/// strings that lie in `slice`, from AST spans over the concatenated source.
+fn folding_ranges(ast: &SourceFile, slice: &FileSlice) -> Vec<FoldingRange> {
+    let mut ranges = Vec::new();
+    for decl in &ast.declarations {
+        if decl.span.start < slice.offset || decl.span.start >= slice.offset + slice.len {
+            continue;
+        }
+        push_fold(&mut ranges, slice, &decl.span, FoldingRangeKind::Region);
+        let body = match &decl.node {
+            Declaration::Entity(entity) => &entity.body,
+            Declaration::World(world) => &world.body,
+        };
+        push_statement_folds(&mut ranges, slice, body);
+    }
+    ranges
+}
+
+fn push_statement_folds(
+    ranges: &mut Vec<FoldingRange>,
+    slice: &FileSlice,
+    body: &[Spanned<Statement>],
+) {
+    for stmt in body {
+        match &stmt.node {
+            Statement::Block(block) => {
+                push_fold(ranges, slice, &stmt.span, FoldingRangeKind::Region);
+                push_statement_folds(ranges, slice, &block.body);
+            }
+            Statement::Description(_) => {
+                push_fold(ranges, slice, &stmt.span, FoldingRangeKind::Comment);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Add a fold over a global span if it covers more than one line of `slice`.
+fn push_fold(
+    ranges: &mut Vec<FoldingRange>,
+    slice: &FileSlice,
+    span: &std::ops::Range<usize>,
+    kind: FoldingRangeKind,
+) {
+    let start = span.start.saturating_sub(slice.offset);
+    let end = span.end.saturating_sub(slice.offset).min(slice.len);
+    let range = byte_span_to_range(&slice.text, &(start..end));
+    if range.end.line > range.start.line {
+        ranges.push(FoldingRange {
+            start_line: range.start.line,
+            start_character: None,
+            end_line: range.end.line,
+            end_character: None,
+            kind: Some(kind),
+            collapsed_text: None,
+        });
+    }
+}
+
 /// Get the text of the current line up to the cursor position.
 fn get_line_prefix(text: &str, pos: Position) -> String {
     for (i, line) in text.lines().enumerate() {
@@ -556,7 +716,8 @@ fn classify_token(token: &Token, next: Option<&Token>) -> Option<u32> {
         | Token::RBracket
         | Token::LParen
         | Token::RParen
-        | Token::Comma => Some(6), // OPERATOR
+        | Token::Comma
+        | Token::Arrow => Some(6), // OPERATOR
         _ => None,
     }
 }
@@ -598,6 +759,10 @@ fn is_semantic_keyword(word: &str) -> bool {
             | "month"
             | "day"
             | "era"
+            | "starts"
+            | "alias"
+            | "since"
+            | "until"
     )
 }
 
@@ -613,23 +778,6 @@ fn is_value_start(token: &Token) -> bool {
     )
 }
 
-/// Collect all byte spans where entity names appear in the text.
-fn collect_entity_name_spans(text: &str, entity_names: &[String]) -> Vec<std::ops::Range<usize>> {
-    let text_lower = text.to_lowercase();
-    let mut spans = Vec::new();
-    for name in entity_names {
-        let name_lower = name.to_lowercase();
-        let mut search_from = 0;
-        while let Some(found) = text_lower[search_from..].find(&name_lower) {
-            let start = search_from + found;
-            let end = start + name.len();
-            spans.push(start..end);
-            search_from = start + 1;
-        }
-    }
-    spans
-}
-
 /// Check if a token span falls within any entity name span.
 fn is_in_entity_span(
     entity_spans: &[std::ops::Range<usize>],
@@ -731,6 +879,9 @@ impl LanguageServer for WwLanguageServer {
                     work_done_progress_options: WorkDoneProgressOptions::default(),
                 })),
                 document_symbol_provider: Some(OneOf::Left(true)),
+                document_formatting_provider: Some(OneOf::Left(true)),
+                folding_range_provider: Some(FoldingRangeProviderCapability::Simple(true)),
+                inlay_hint_provider: Some(OneOf::Left(true)),
                 semantic_tokens_provider: Some(
                     SemanticTokensServerCapabilities::SemanticTokensOptions(
                         SemanticTokensOptions {
@@ -753,6 +904,10 @@ impl LanguageServer for WwLanguageServer {
                     ),
                 ),
                 code_action_provider: Some(CodeActionProviderCapability::Simple(true)),
+                execute_command_provider: Some(ExecuteCommandOptions {
+                    commands: vec![REVEAL_POSITION_COMMAND.to_string()],
+                    work_done_progress_options: WorkDoneProgressOptions::default(),
+                }),
                 ..Default::default()
             },
             ..Default::default()
@@ -887,6 +1042,7 @@ impl LanguageServer for WwLanguageServer {
                     "alignment",
                     "traits",
                     "values",
+                    "gm_note",
                 ];
                 for (i, key) in property_keys.iter().enumerate() {
                     items.push(CompletionItem {
@@ -1071,10 +1227,12 @@ impl LanguageServer for WwLanguageServer {
                         .and_then(|p| p.file_name().map(|f| f.to_string_lossy().to_string()))
                         .unwrap_or_default();
 
-                    let hover_text = format!(
-                        "**{}** [{}]\n\nDefined in `{}`",
-                        entity.name, entity.kind, defined_in
-                    );
+                    let mut hover_text = format!("**{}** [{}]", entity.name, entity.kind);
+                    if let Some(doc) = &entity.doc {
+                        hover_text.push_str("\n\n");
+                        hover_text.push_str(doc);
+                    }
+                    hover_text.push_str(&format!("\n\nDefined in `{defined_in}`"));
                     return Ok(Some(Hover {
                         contents: HoverContents::Markup(MarkupContent {
                             kind: MarkupKind::Markdown,
@@ -1308,7 +1466,7 @@ impl LanguageServer for WwLanguageServer {
         }
 
         let (tokens, _) = lexer::lex(&text);
-        let entity_spans = collect_entity_name_spans(&text, &state.entity_names);
+        let entity_spans = mention::name_spans(&text, &state.entity_names);
 
         let mut semantic_tokens = Vec::new();
         let mut prev_line = 0u32;
@@ -1331,7 +1489,8 @@ impl LanguageServer for WwLanguageServer {
             };
 
             let pos = byte_offset_to_position(&text, span.start);
-            let length = (span.end - span.start) as u32;
+            // Doc strings can span lines but tokens may not: cover the first line
+            let length = text[span.clone()].lines().next().map_or(0, str::len) as u32;
 
             let delta_line = pos.line - prev_line;
             let delta_start = if delta_line == 0 {
@@ -1358,6 +1517,61 @@ impl LanguageServer for WwLanguageServer {
         })))
     }
 
+    async fn folding_range(&self, params: FoldingRangeParams) -> Result<Option<Vec<FoldingRange>>> {
+        let uri = params.text_document.uri;
+        let state = self.state.read().await;
+
+        let Some(ast) = &state.ast else {
+            return Ok(None);
+        };
+        let Some(file_slice) = state.slices.iter().find(|s| s.uri == uri) else {
+            return Ok(None);
+        };
+
+        Ok(Some(folding_ranges(ast, file_slice)))
+    }
+
+    async fn inlay_hint(&self, params: InlayHintParams) -> Result<Option<Vec<InlayHint>>> {
+        let uri = params.text_document.uri;
+        let state = self.state.read().await;
+
+        let Some(ast) = &state.ast else {
+            return Ok(None);
+        };
+        let Some(file_slice) = state.slices.iter().find(|s| s.uri == uri) else {
+            return Ok(None);
+        };
+
+        Ok(Some(inverse_relationship_hints(
+            ast,
+            file_slice,
+            params.range,
+        )))
+    }
+
+    async fn formatting(&self, params: DocumentFormattingParams) -> Result<Option<Vec<TextEdit>>> {
+        let uri = params.text_document.uri;
+        let state = self.state.read().await;
+        let text = get_file_text(&state, &uri);
+        drop(state);
+
+        // Never rewrite a file that does not parse
+        let Some(formatted) = ww_dsl::format::format_source(&text) else {
+            return Ok(None);
+        };
+        if formatted == text {
+            return Ok(Some(Vec::new()));
+        }
+
+        Ok(Some(vec![TextEdit {
+            range: Range {
+                start: Position::new(0, 0),
+                end: byte_offset_to_position(&text, text.len()),
+            },
+            new_text: formatted,
+        }]))
+    }
+
     async fn code_action(&self, params: CodeActionParams) -> Result<Option<CodeActionResponse>> {
         let uri = params.text_document.uri;
         let mut actions = Vec::new();
@@ -1368,32 +1582,34 @@ impl LanguageServer for WwLanguageServer {
                 let text = get_file_text(&state, &uri);
                 drop(state);
 
-                let stub = format!(
-                    "\n{entity_name} is a character {{\n    -- TODO: fill in details\n}}\n"
-                );
-
-                let end_pos = byte_offset_to_position(&text, text.len());
-                let edit = TextEdit {
-                    range: Range {
-                        start: end_pos,
-                        end: end_pos,
-                    },
-                    new_text: stub,
-                };
+                for (i, kind) in CREATE_ENTITY_KINDS.iter().enumerate() {
+                    let (edit, cursor) = create_entity_edit(&text, entity_name, kind);
 
-                let mut changes = HashMap::new();
-                changes.insert(uri.clone(), vec![edit]);
+                    let mut changes = HashMap::new();
+                    changes.insert(uri.clone(), vec![edit]);
 
-                actions.push(CodeActionOrCommand::CodeAction(CodeAction {
-                    title: format!("Create entity \"{entity_name}\""),
-                    kind: Some(CodeActionKind::QUICKFIX),
-                    diagnostics: Some(vec![diag.clone()]),
-                    edit: Some(WorkspaceEdit {
-                        changes: Some(changes),
+                    actions.push(CodeActionOrCommand::CodeAction(CodeAction {
+                        title: format!("Create {kind} \"{entity_name}\""),
+                        kind: Some(CodeActionKind::QUICKFIX),
+                        diagnostics: Some(vec![diag.clone()]),
+                        edit: Some(WorkspaceEdit {
+                            changes: Some(changes),
+                            ..Default::default()
+                        }),
+                        // Runs after the edit is applied, to put the cursor
+                        // inside the new entity's braces.
+                        command: Some(Command {
+                            title: "Move cursor".to_string(),
+                            command: REVEAL_POSITION_COMMAND.to_string(),
+                            arguments: Some(vec![
+                                serde_json::json!(uri),
+                                serde_json::json!(cursor),
+                            ]),
+                        }),
+                        is_preferred: Some(i == 0),
                         ..Default::default()
-                    }),
-                    ..Default::default()
-                }));
+                    }));
+                }
             }
         }
 
@@ -1403,6 +1619,75 @@ impl LanguageServer for WwLanguageServer {
             Ok(Some(actions))
         }
     }
+
+    async fn execute_command(
+        &self,
+        params: ExecuteCommandParams,
+    ) -> Result<Option<serde_json::Value>> {
+        if params.command != REVEAL_POSITION_COMMAND {
+            return Ok(None);
+        }
+        let mut args = params.arguments.into_iter();
+        let uri = args
+            .next()
+            .and_then(|v| serde_json::from_value::<Url>(v).ok());
+        let pos = args
+            .next()
+            .and_then(|v| serde_json::from_value::<Position>(v).ok());
+        if let (Some(uri), Some(pos)) = (uri, pos) {
+            let _ = self
+                .client
+                .show_document(ShowDocumentParams {
+                    uri,
+                    external: None,
+                    take_focus: Some(true),
+                    selection: Some(Range {
+                        start: pos,
+                        end: pos,
+                    }),
+                })
+                .await;
+        }
+        Ok(None)
+    }
+}
+
+/// Command that moves the client's cursor to a position, given the
+/// document URI and the position as arguments.
+const REVEAL_POSITION_COMMAND: &str = "ww.revealPosition";
+
+/// Kinds offered when creating a missing entity; the first is preferred.
+const CREATE_ENTITY_KINDS: &[&str] = &["character", "location", "faction", "item", "event", "lore"];
+
+/// Build the edit appending an empty `<name> is a <kind> { }` stub to the
+/// end of `text`, and the position inside its braces.
+fn create_entity_edit(text: &str, name: &str, kind: &str) -> (TextEdit, Position) {
+    let article = if kind.starts_with(['a', 'e', 'i', 'o', 'u']) {
+        "an"
+    } else {
+        "a"
+    };
+    let separator = if text.is_empty() {
+        ""
+    } else if text.ends_with('\n') {
+        "\n"
+    } else {
+        "\n\n"
+    };
+
+    let end_pos = byte_offset_to_position(text, text.len());
+    let cursor = Position {
+        line: end_pos.line + separator.len() as u32 + 1,
+        character: 4,
+    };
+    let edit = TextEdit {
+        range: Range {
+            start: end_pos,
+            end: end_pos,
+        },
+        new_text: format!("{separator}{name} is {article} {kind} {{\n    \n}}\n"),
+    };
+    (edit, cursor)
 }
 
 /// Get a file's text from open docs or disk.
@@ -1840,6 +2125,19 @@ mod tests {
         assert_eq!(loc.range.end, Position::new(0, 5));
     }
 
+    #[test]
+    fn mapped_diagnostics_carry_codes() {
+        let slices = test_slices_with_text();
+        let diags = vec![
+            ww_dsl::Diagnostic::error(12..14, "undefined entity: \"X\"")
+                .with_code(ww_dsl::DiagnosticCode::UndefinedEntity),
+        ];
+        let per_file = map_diagnostics_to_files(&slices, &diags);
+        let b = &per_file[&Url::parse("file:///b.ww").unwrap()];
+        assert_eq!(b[0].code, Some(NumberOrString::String("WW0102".into())));
+        assert!(per_file[&Url::parse("file:///a.ww").unwrap()].is_empty());
+    }
+
     // -- entity_kind_to_symbol_kind --
 
     #[test]
@@ -1946,6 +2244,110 @@ mod tests {
         );
     }
 
+    // -- folding_ranges --
+
+    #[test]
+    fn folding_ranges_cover_blocks_and_doc_strings() {
+        let first = "Kael is a character {}\n";
+        let second = "world \"Test\" {\n    genre fantasy\n}\n\nMira is a character {\n    dialogue \"hi\" {\n        text \"Hello\"\n    }\n    \"\"\"\n    A guide.\n    Knows the roads.\n    \"\"\"\n}\n";
+        let source = format!("{first}\n{second}");
+        let ast = parse_source(&source);
+        let slice = FileSlice {
+            uri: Url::parse("file:///b.ww").unwrap(),
+            offset: first.len() + 1,
+            len: second.len(),
+            text: second.to_string(),
+        };
+
+        let folds: Vec<_> = folding_ranges(&ast, &slice)
+            .into_iter()
+            .map(|r| (r.start_line, r.end_line, r.kind.unwrap()))
+            .collect();
+        assert_eq!(
+            folds,
+            vec![
+                (0, 2, FoldingRangeKind::Region),
+                (4, 12, FoldingRangeKind::Region),
+                (5, 7, FoldingRangeKind::Region),
+                (8, 11, FoldingRangeKind::Comment),
+            ]
+        );
+    }
+
+    // -- inverse_relationship_hints --
+
+    #[test]
+    fn inlay_hints_show_inverse_relationships() {
+        let source = "the Order is a faction {\n    led by Kael\n    involving [Mira, Tomas]\n}\n";
+        let ast = parse_source(source);
+        let slice = FileSlice {
+            uri: Url::parse("file:///a.ww").unwrap(),
+            offset: 0,
+            len: source.len(),
+            text: source.to_string(),
+        };
+        let everything = Range::new(Position::new(0, 0), Position::new(10, 0));
+
+        let hints: Vec<_> = inverse_relationship_hints(&ast, &slice, everything)
+            .into_iter()
+            .map(|h| match h.label {
+                InlayHintLabel::String(label) => (h.position, label),
+                InlayHintLabel::LabelParts(_) => unreachable!(),
+            })
+            .collect();
+        assert_eq!(
+            hints,
+            vec![
+                (Position::new(1, 15), "Kael leads the Order".to_string()),
+                (
+                    Position::new(2, 19),
+                    "Mira took part in the Order".to_string()
+                ),
+                (
+                    Position::new(2, 26),
+                    "Tomas took part in the Order".to_string()
+                ),
+            ]
+        );
+
+        let first_line = Range::new(Position::new(1, 0), Position::new(1, 40));
+        assert_eq!(
+            inverse_relationship_hints(&ast, &slice, first_line).len(),
+            1
+        );
+    }
+
+    // -- create_entity_edit --
+
+    #[test]
+    fn create_entity_stub_places_cursor_in_braces() {
+        let text = "Kael is a character {\n    member of the Order\n}\n";
+        let (edit, cursor) = create_entity_edit(text, "the Order", "faction");
+        assert_eq!(edit.range.start, Position::new(3, 0));
+        assert_eq!(edit.new_text, "\nthe Order is a faction {\n    \n}\n");
+
+        let mut result = text.to_string();
+        result.push_str(&edit.new_text);
+        let line = result.lines().nth(cursor.line as usize).unwrap();
+        assert_eq!(line, "    ");
+        assert_eq!(cursor.character, 4);
+        assert_eq!(
+            result.lines().nth(cursor.line as usize - 1),
+            Some("the Order is a faction {")
+        );
+    }
+
+    #[test]
+    fn create_entity_stub_handles_articles_and_unterminated_files() {
+        let (edit, cursor) = create_entity_edit("Kael is a character {}", "Relic", "item");
+        assert_eq!(edit.new_text, "\n\nRelic is an item {\n    \n}\n");
+        assert_eq!(cursor, Position::new(3, 4));
+
+        let (edit, cursor) = create_entity_edit("", "Relic", "event");
+        assert_eq!(edit.new_text, "Relic is an event {\n    \n}\n");
+        assert_eq!(cursor, Position::new(1, 4));
+    }
+
     // -- source hash (incremental compilation) --
 
     #[test]
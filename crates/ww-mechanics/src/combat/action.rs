@@ -3,11 +3,19 @@
 use rand::rngs::StdRng;
 
 use crate::error::{MechError, MechResult};
+use crate::resolution::Outcome;
 use crate::rules::{self, CheckRequest, CheckResult, RuleSet};
 use crate::sheet::CharacterSheet;
 
 use super::Combat;
 
+/// Zone traits that hinder checks made inside the zone; each one costs a
+/// point of advantage.
+const HINDERING_TRAITS: [&str; 4] = ["cramped", "dark", "narrow", "slippery"];
+/// Zone traits that favour checks made inside the zone; each one grants a
+/// point of advantage.
+const FAVOURING_TRAITS: [&str; 2] = ["elevated", "cover"];
+
 /// An action a participant can take during their turn.
 #[derive(Debug, Clone)]
 pub enum CombatAction {
@@ -34,6 +42,31 @@ pub enum CombatAction {
     Custom(String),
 }
 
+impl CombatAction {
+    /// Every value [`CombatAction::kind`] can return.
+    pub const KINDS: [&'static str; 5] = ["attack", "defend", "move", "skill", "custom"];
+
+    /// Short name of the action's kind, as used by phase restrictions.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Attack { .. } => "attack",
+            Self::Defend => "defend",
+            Self::Move { .. } => "move",
+            Self::UseSkill { .. } => "skill",
+            Self::Custom(_) => "custom",
+        }
+    }
+
+    /// Index of the participant the action names as its target, if any.
+    pub fn target(&self) -> Option<usize> {
+        match self {
+            Self::Attack { target } => Some(*target),
+            Self::UseSkill { target, .. } => *target,
+            _ => None,
+        }
+    }
+}
+
 impl std::fmt::Display for CombatAction {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -61,13 +94,188 @@ pub struct CombatEvent {
     pub actor: usize,
     /// What action was taken.
     pub action: CombatAction,
+    /// Index of the participant the action was aimed at, if any.
+    pub target: Option<usize>,
     /// The mechanical result of the action.
     pub result: CheckResult,
+    /// Harm dealt to the target's harm track.
+    pub harm: i32,
+    /// Whether this action took the target out of the fight.
+    pub taken_out: bool,
     /// A narrative description of what happened.
     pub description: String,
 }
 
+impl Combat {
+    /// Resolve `action` by `actor` against `target`: perform the check,
+    /// apply harm to the target on success, and log the event.
+    ///
+    /// Traits of the actor's zone shift the check: hindering ones such as
+    /// "cramped" or "dark" impose disadvantage, favouring ones such as
+    /// "elevated" grant advantage. Attacks that succeed deal 1 harm plus the
+    /// margin (one more on a critical) to the target's first track named
+    /// Wounds, Health, Harm, or HP; a partial success deals 1. Other actions,
+    /// skills included, deal no harm. Driving that track to its minimum takes
+    /// the target out, which the logged event records.
+    ///
+    /// An actor that is taken out cannot act, an attack must be aimed at
+    /// another participant who is still in the fight, and an action that
+    /// names its own target must name `target`.
+    pub fn resolve_action(
+        &mut self,
+        actor: usize,
+        action: CombatAction,
+        target: usize,
+        ruleset: &RuleSet,
+        rng: &mut StdRng,
+    ) -> MechResult<CombatEvent> {
+        self.check_participants(actor, &action, Some(target))?;
+        if let Some(named) = action.target()
+            && named != target
+        {
+            return Err(MechError::CombatError(format!(
+                "{action} cannot be resolved against target #{target}"
+            )));
+        }
+        self.check_action(&action)?;
+
+        let attacker = &self.participants[actor];
+        let mut request = build_request_for_action(&action, &attacker.sheet, ruleset)?;
+        request.advantage += self
+            .zones
+            .get(attacker.zone_index)
+            .map(|zone| zone_advantage(&zone.traits))
+            .unwrap_or(0);
+        let result = rules::perform_check(ruleset, &attacker.sheet, &request, rng)?;
+
+        let harm = match &action {
+            CombatAction::Attack { .. } => harm_for(&result.outcome),
+            _ => 0,
+        };
+        let mut taken_out = false;
+        if harm > 0 {
+            let track = harm_track(&self.participants[target].sheet)?;
+            let was_out = self.participants[target].taken_out;
+            self.harm(target, &track, harm)?;
+            taken_out = !was_out && self.participants[target].taken_out;
+        }
+
+        let actor_name = &self.participants[actor].name;
+        let target_name = &self.participants[target].name;
+        let mut description = format!(
+            "{actor_name}: {action} ({target_name}) → {}",
+            result.outcome
+        );
+        if harm > 0 {
+            description.push_str(&format!(", {harm} harm"));
+        }
+        if taken_out {
+            description.push_str(&format!("; {target_name} is taken out"));
+        }
+
+        let event = CombatEvent {
+            round: self.round,
+            actor,
+            action,
+            target: Some(target),
+            result,
+            harm,
+            taken_out,
+            description,
+        };
+        self.log_event(event.clone());
+        Ok(event)
+    }
+
+    /// Check that `actor` may take `action` against `target`: both must
+    /// exist, the actor must still be in the fight, and an attack must be
+    /// aimed at another participant who is too.
+    fn check_participants(
+        &self,
+        actor: usize,
+        action: &CombatAction,
+        target: Option<usize>,
+    ) -> MechResult<()> {
+        let acting = self
+            .participants
+            .get(actor)
+            .ok_or_else(|| MechError::CombatError(format!("actor index {actor} out of bounds")))?;
+        if acting.taken_out {
+            return Err(MechError::CombatError(format!(
+                "{} is taken out and cannot act",
+                acting.name
+            )));
+        }
+        let Some(target) = target else {
+            return Ok(());
+        };
+        let targeted = self.participants.get(target).ok_or_else(|| {
+            MechError::CombatError(format!("target index {target} out of bounds"))
+        })?;
+        if let CombatAction::Attack { .. } = action {
+            if target == actor {
+                return Err(MechError::CombatError(format!(
+                    "{} cannot attack themselves",
+                    acting.name
+                )));
+            }
+            if targeted.taken_out {
+                return Err(MechError::CombatError(format!(
+                    "{} is already taken out",
+                    targeted.name
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Net advantage a zone's traits give checks made inside it.
+fn zone_advantage(traits: &[String]) -> i32 {
+    traits
+        .iter()
+        .map(|t| {
+            let t = t.to_lowercase();
+            if HINDERING_TRAITS.contains(&t.as_str()) {
+                -1
+            } else if FAVOURING_TRAITS.contains(&t.as_str()) {
+                1
+            } else {
+                0
+            }
+        })
+        .sum()
+}
+
+/// Harm an offensive action deals for a check outcome.
+fn harm_for(outcome: &Outcome) -> i32 {
+    match outcome {
+        Outcome::CriticalSuccess { margin } => 2 + *margin as i32,
+        Outcome::Success { margin } => 1 + *margin as i32,
+        Outcome::Partial => 1,
+        Outcome::Failure | Outcome::CriticalFailure => 0,
+    }
+}
+
+/// The track harm is applied to on this sheet.
+fn harm_track(sheet: &CharacterSheet) -> MechResult<String> {
+    ["Wounds", "Health", "Harm", "HP"]
+        .iter()
+        .find_map(|name| {
+            sheet
+                .tracks
+                .keys()
+                .find(|track| track.eq_ignore_ascii_case(name))
+        })
+        .cloned()
+        .ok_or_else(|| MechError::TrackNotFound("Wounds".to_string()))
+}
+
 /// Resolve a combat action, producing a check result and effects.
+///
+/// Fails if the current combat phase does not allow the action, or if the
+/// actor or an attack's target cannot take part (see
+/// [`Combat::resolve_action`]).
 pub fn resolve_action(
     combat: &Combat,
     ruleset: &RuleSet,
@@ -75,9 +283,9 @@ pub fn resolve_action(
     action: &CombatAction,
     rng: &mut StdRng,
 ) -> MechResult<CheckResult> {
-    let actor = combat.participants.get(actor_index).ok_or_else(|| {
-        MechError::CombatError(format!("actor index {actor_index} out of bounds"))
-    })?;
+    combat.check_participants(actor_index, action, action.target())?;
+    combat.check_action(action)?;
+    let actor = &combat.participants[actor_index];
 
     let request = build_request_for_action(action, &actor.sheet, ruleset)?;
     rules::perform_check(ruleset, &actor.sheet, &request, rng)
@@ -270,6 +478,175 @@ mod tests {
         assert!(resolve_action(&combat, &ruleset, 99, &CombatAction::Defend, &mut rng).is_err());
     }
 
+    #[test]
+    fn resolve_attack_harms_target_until_taken_out() {
+        let (mut combat, ruleset) = setup_combat();
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut total_harm = 0;
+        for _ in 0..50 {
+            let event = combat
+                .resolve_action(0, CombatAction::Attack { target: 1 }, 1, &ruleset, &mut rng)
+                .unwrap();
+            total_harm += event.harm;
+            if event.taken_out {
+                break;
+            }
+        }
+
+        assert!(combat.participants[1].taken_out);
+        assert!(
+            combat.participants[1]
+                .sheet
+                .track("Wounds")
+                .unwrap()
+                .is_empty()
+        );
+        assert!(total_harm >= 5);
+        let last = combat.log.last().unwrap();
+        assert!(last.taken_out);
+        assert_eq!(last.target, Some(1));
+        assert!(last.description.ends_with("Bob is taken out"));
+        assert_eq!(combat.log.iter().filter(|e| e.taken_out).count(), 1);
+    }
+
+    #[test]
+    fn resolve_action_in_cramped_zone_has_disadvantage() {
+        let (mut combat, ruleset) = setup_combat();
+        combat.zones[0].traits = vec!["Cramped".to_string()];
+        let mut rng = StdRng::seed_from_u64(42);
+        let event = combat
+            .resolve_action(0, CombatAction::Attack { target: 1 }, 1, &ruleset, &mut rng)
+            .unwrap();
+        assert_eq!(event.result.roll.dropped.len(), 1);
+        assert!(event.result.explain().contains("disadvantage dropped"));
+
+        let event = combat
+            .resolve_action(1, CombatAction::Defend, 1, &ruleset, &mut rng)
+            .unwrap();
+        assert_eq!(event.harm, 0);
+        assert!(
+            combat
+                .resolve_action(0, CombatAction::Defend, 9, &ruleset, &mut rng)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn resolve_action_rejects_a_different_target() {
+        let (mut combat, ruleset) = setup_combat();
+        let mut rng = StdRng::seed_from_u64(42);
+        let err = combat
+            .resolve_action(1, CombatAction::Attack { target: 1 }, 0, &ruleset, &mut rng)
+            .unwrap_err();
+        assert!(err.to_string().contains("Attack target #1"), "{err}");
+        assert!(combat.log.is_empty());
+
+        let skill = CombatAction::UseSkill {
+            skill: "Melee".to_string(),
+            target: None,
+        };
+        assert!(
+            combat
+                .resolve_action(0, skill, 1, &ruleset, &mut rng)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn taken_out_actors_cannot_act() {
+        let (mut combat, ruleset) = setup_combat();
+        combat.participants[0].taken_out = true;
+        let mut rng = StdRng::seed_from_u64(42);
+        let err = combat
+            .resolve_action(0, CombatAction::Attack { target: 1 }, 1, &ruleset, &mut rng)
+            .unwrap_err();
+        assert!(err.to_string().contains("Alice is taken out"), "{err}");
+        assert!(resolve_action(&combat, &ruleset, 0, &CombatAction::Defend, &mut rng).is_err());
+        assert!(combat.log.is_empty());
+    }
+
+    #[test]
+    fn taken_out_targets_cannot_be_attacked() {
+        let (mut combat, ruleset) = setup_combat();
+        combat.participants[1].taken_out = true;
+        let mut rng = StdRng::seed_from_u64(42);
+        let attack = CombatAction::Attack { target: 1 };
+        let err = combat
+            .resolve_action(0, attack.clone(), 1, &ruleset, &mut rng)
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("Bob is already taken out"),
+            "{err}"
+        );
+        assert!(resolve_action(&combat, &ruleset, 0, &attack, &mut rng).is_err());
+
+        // Other actions may still name them, e.g. to tend to a fallen ally
+        let tend = CombatAction::UseSkill {
+            skill: "Melee".to_string(),
+            target: Some(1),
+        };
+        assert!(
+            combat
+                .resolve_action(0, tend, 1, &ruleset, &mut rng)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn attacks_on_oneself_are_rejected() {
+        let (mut combat, ruleset) = setup_combat();
+        let mut rng = StdRng::seed_from_u64(42);
+        let attack = CombatAction::Attack { target: 0 };
+        let err = combat
+            .resolve_action(0, attack.clone(), 0, &ruleset, &mut rng)
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("cannot attack themselves"),
+            "{err}"
+        );
+        assert!(resolve_action(&combat, &ruleset, 0, &attack, &mut rng).is_err());
+        assert!(
+            combat
+                .resolve_action(0, CombatAction::Defend, 0, &ruleset, &mut rng)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn skills_deal_no_harm() {
+        let (mut combat, ruleset) = setup_combat();
+        let before = combat.participants[1]
+            .sheet
+            .track("Wounds")
+            .unwrap()
+            .current;
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..20 {
+            let skill = CombatAction::UseSkill {
+                skill: "Melee".to_string(),
+                target: Some(1),
+            };
+            let event = combat
+                .resolve_action(0, skill, 1, &ruleset, &mut rng)
+                .unwrap();
+            assert_eq!(event.harm, 0);
+        }
+        let after = combat.participants[1]
+            .sheet
+            .track("Wounds")
+            .unwrap()
+            .current;
+        assert_eq!(after, before);
+    }
+
+    #[test]
+    fn zone_traits_shift_advantage() {
+        let traits = |list: &[&str]| list.iter().map(|t| t.to_string()).collect::<Vec<_>>();
+        assert_eq!(zone_advantage(&traits(&["cramped", "dark"])), -2);
+        assert_eq!(zone_advantage(&traits(&["elevated", "narrow"])), 0);
+        assert_eq!(zone_advantage(&traits(&["misty"])), 0);
+    }
+
     #[test]
     fn combat_action_display() {
         assert_eq!(
@@ -0,0 +1,290 @@
+//! Building combats from encounters defined in the world.
+//!
+//! An encounter is an entity of kind `encounter` whose blocks name the
+//! participants and zones:
+//!
+//! ```text
+//! the Bridge Ambush is an encounter {
+//!     zones ["the Bridge", "the Riverbank"]
+//!     participant "Kael" {
+//!         initiative 15
+//!         zone "the Bridge"
+//!     }
+//!     participant "Bandit" {
+//!         count 3
+//!         zone "the Riverbank"
+//!     }
+//!     zone "the Bridge" {
+//!         traits ["narrow", "slippery"]
+//!     }
+//! }
+//! ```
+//!
+//! Participants without a fixed `initiative` roll the ruleset's check die.
+
+use std::collections::HashMap;
+
+use rand::rngs::StdRng;
+use ww_core::entity::{EntityKind, MetadataValue};
+use ww_core::grammar::Grammar;
+use ww_core::world::World;
+
+use super::{Combat, Zone};
+use crate::dice::DicePool;
+use crate::error::{MechError, MechResult};
+use crate::rules::RuleSet;
+use crate::sheet::CharacterSheet;
+
+type Props = HashMap<String, MetadataValue>;
+
+impl Combat {
+    /// Build a ready-to-start combat from the encounter entity `name`.
+    ///
+    /// Each participant must name an entity in the world; its sheet is built
+    /// with `ruleset`. A `count` above 1 adds numbered copies ("Bandit 1",
+    /// "Bandit 2", ...), each with its own initiative roll.
+    pub fn from_encounter(
+        world: &World,
+        name: &str,
+        ruleset: &RuleSet,
+        rng: &mut StdRng,
+    ) -> MechResult<Self> {
+        let encounter = world
+            .find_by_name(name)
+            .ok_or_else(|| MechError::EntityNotFound(name.to_string()))?;
+        if encounter.kind != EntityKind::Custom("encounter".to_string()) {
+            return Err(MechError::InvalidEncounter(format!(
+                "'{}' is {}, not an encounter",
+                encounter.name,
+                Grammar::from_meta(&world.meta).with_article(&encounter.kind.to_string())
+            )));
+        }
+        let props = &encounter.properties;
+
+        let mut combat = Combat::new();
+        combat.phases = ruleset.combat_phases.clone();
+        let zone_names = instance_names(props, "zones", "zone");
+        for zone in &zone_names {
+            let traits = string_list(props, &format!("zone.{zone}.traits"));
+            combat.add_zone(Zone::with_traits(zone.clone(), traits));
+        }
+
+        for participant in instance_names(props, "participants", "participant") {
+            let entity = world
+                .find_by_name(&participant)
+                .ok_or_else(|| MechError::EntityNotFound(participant.clone()))?;
+            let sheet = CharacterSheet::from_entity(entity, ruleset)?;
+
+            let key = |field: &str| format!("participant.{participant}.{field}");
+            let count = match props.get(&key("count")) {
+                None => 1,
+                Some(MetadataValue::Integer(n)) if *n >= 1 => *n as u32,
+                Some(other) => {
+                    return Err(MechError::InvalidEncounter(format!(
+                        "count for '{participant}' must be a positive integer, got {other}"
+                    )));
+                }
+            };
+            let fixed_initiative = match props.get(&key("initiative")) {
+                None => None,
+                Some(MetadataValue::Integer(n)) if *n >= 0 => Some(*n as u32),
+                Some(other) => {
+                    return Err(MechError::InvalidEncounter(format!(
+                        "initiative for '{participant}' must be a non-negative integer, got {other}"
+                    )));
+                }
+            };
+            let zone_index = match props.get(&key("zone")) {
+                None => 0,
+                Some(MetadataValue::String(zone)) => zone_names
+                    .iter()
+                    .position(|z| z.eq_ignore_ascii_case(zone))
+                    .ok_or_else(|| {
+                        MechError::InvalidEncounter(format!(
+                            "'{participant}' starts in unknown zone '{zone}'"
+                        ))
+                    })?,
+                Some(other) => {
+                    return Err(MechError::InvalidEncounter(format!(
+                        "zone for '{participant}' must be a zone name, got {other}"
+                    )));
+                }
+            };
+
+            for copy in 1..=count {
+                let display = if count > 1 {
+                    format!("{} {copy}", entity.name)
+                } else {
+                    entity.name.clone()
+                };
+                let initiative = fixed_initiative
+                    .unwrap_or_else(|| DicePool::new().add(ruleset.check_die, 1).roll(rng).total());
+                combat.add_participant(display, sheet.clone(), initiative);
+                let index = combat.participants.len() - 1;
+                combat.participants[index].zone_index = zone_index;
+            }
+        }
+
+        if combat.participants.is_empty() {
+            return Err(MechError::InvalidEncounter(format!(
+                "'{}' has no participants",
+                encounter.name
+            )));
+        }
+
+        Ok(combat)
+    }
+}
+
+/// Instance names for a block kind: the explicit order list first, then any
+/// `<block>.<name>.*` instances it does not mention, alphabetically.
+fn instance_names(props: &Props, list_key: &str, block: &str) -> Vec<String> {
+    let mut names = string_list(props, list_key);
+
+    let prefix = format!("{block}.");
+    let mut extra: Vec<String> = props
+        .keys()
+        .filter_map(|k| k.strip_prefix(&prefix))
+        .filter_map(|rest| rest.rsplit_once('.').map(|(name, _)| name.to_string()))
+        .filter(|name| !names.iter().any(|n| n.eq_ignore_ascii_case(name)))
+        .collect();
+    extra.sort();
+    extra.dedup();
+
+    names.extend(extra);
+    names
+}
+
+fn string_list(props: &Props, key: &str) -> Vec<String> {
+    match props.get(key) {
+        Some(MetadataValue::List(items)) => items
+            .iter()
+            .filter_map(|v| match v {
+                MetadataValue::String(s) => Some(s.clone()),
+                _ => None,
+            })
+            .collect(),
+        Some(MetadataValue::String(s)) => vec![s.clone()],
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::preset;
+    use rand::SeedableRng;
+    use ww_core::entity::Entity;
+    use ww_core::world::WorldMeta;
+
+    fn world_with(encounter: Entity) -> World {
+        let mut world = World::new(WorldMeta::new("Test"));
+        world
+            .add_entity(Entity::new(EntityKind::Character, "Kael"))
+            .unwrap();
+        world
+            .add_entity(Entity::new(EntityKind::Character, "Bandit"))
+            .unwrap();
+        world.add_entity(encounter).unwrap();
+        world
+    }
+
+    fn ambush() -> Entity {
+        let mut e = Entity::new(
+            EntityKind::Custom("encounter".to_string()),
+            "the Bridge Ambush",
+        );
+        let props = [
+            (
+                "zones",
+                MetadataValue::List(vec![
+                    MetadataValue::String("the Bridge".to_string()),
+                    MetadataValue::String("the Riverbank".to_string()),
+                ]),
+            ),
+            (
+                "zone.the Bridge.traits",
+                MetadataValue::List(vec![MetadataValue::String("narrow".to_string())]),
+            ),
+            ("participant.Kael.initiative", MetadataValue::Integer(15)),
+            (
+                "participant.Kael.zone",
+                MetadataValue::String("the Bridge".to_string()),
+            ),
+            ("participant.Bandit.count", MetadataValue::Integer(3)),
+            (
+                "participant.Bandit.zone",
+                MetadataValue::String("the riverbank".to_string()),
+            ),
+        ];
+        for (key, value) in props {
+            e.properties.insert(key.to_string(), value);
+        }
+        e
+    }
+
+    #[test]
+    fn builds_participants_and_zones() {
+        let world = world_with(ambush());
+        let ruleset = preset::two_d20();
+        let mut rng = StdRng::seed_from_u64(42);
+        let combat =
+            Combat::from_encounter(&world, "the bridge ambush", &ruleset, &mut rng).unwrap();
+
+        assert_eq!(combat.zones.len(), 2);
+        assert_eq!(combat.zones[0].traits, vec!["narrow".to_string()]);
+
+        let names: Vec<_> = combat
+            .participants
+            .iter()
+            .map(|p| p.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["Bandit 1", "Bandit 2", "Bandit 3", "Kael"]);
+        let kael = &combat.participants[3];
+        assert_eq!(kael.initiative, 15);
+        assert_eq!(kael.zone_index, 0);
+        for bandit in &combat.participants[..3] {
+            assert_eq!(bandit.zone_index, 1);
+            assert!((1..=20).contains(&bandit.initiative));
+        }
+        assert!(combat.is_not_started());
+    }
+
+    #[test]
+    fn rejects_bad_encounters() {
+        let ruleset = preset::two_d20();
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let world = world_with(ambush());
+        assert!(matches!(
+            Combat::from_encounter(&world, "Kael", &ruleset, &mut rng),
+            Err(MechError::InvalidEncounter(_))
+        ));
+        assert!(matches!(
+            Combat::from_encounter(&world, "Nowhere", &ruleset, &mut rng),
+            Err(MechError::EntityNotFound(_))
+        ));
+
+        let mut unknown = ambush();
+        unknown.properties.insert(
+            "participant.Ghost.initiative".to_string(),
+            MetadataValue::Integer(3),
+        );
+        let world = world_with(unknown);
+        assert!(matches!(
+            Combat::from_encounter(&world, "the Bridge Ambush", &ruleset, &mut rng),
+            Err(MechError::EntityNotFound(name)) if name == "Ghost"
+        ));
+
+        let mut bad_zone = ambush();
+        bad_zone.properties.insert(
+            "participant.Kael.zone".to_string(),
+            MetadataValue::String("the Moon".to_string()),
+        );
+        let world = world_with(bad_zone);
+        assert!(matches!(
+            Combat::from_encounter(&world, "the Bridge Ambush", &ruleset, &mut rng),
+            Err(MechError::InvalidEncounter(_))
+        ));
+    }
+}
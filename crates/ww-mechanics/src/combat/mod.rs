@@ -1,14 +1,19 @@
 //! Combat state machine and participant management.
 //!
 //! Tracks participants, zones (optional spatial areas), turn order,
-//! and a log of combat events.
+//! and a log of combat events. Combats can be set up by hand or built from
+//! an encounter entity with [`Combat::from_encounter`]. Rounds may be split
+//! into named [phases](phase).
 
 pub mod action;
+mod encounter;
+pub mod phase;
 
 pub use action::{CombatAction, CombatEvent};
+pub use phase::CombatPhase;
 
 use crate::error::{MechError, MechResult};
-use crate::sheet::CharacterSheet;
+use crate::sheet::{AdjustResult, CharacterSheet};
 
 /// A participant in combat.
 #[derive(Debug, Clone)]
@@ -21,6 +26,9 @@ pub struct Participant {
     pub zone_index: usize,
     /// Initiative score (higher goes first).
     pub initiative: u32,
+    /// Whether harm has driven one of the participant's tracks to its
+    /// minimum, taking them out of the fight.
+    pub taken_out: bool,
 }
 
 /// A zone in the combat area (optional spatial subdivision).
@@ -59,12 +67,20 @@ pub struct Combat {
     pub zones: Vec<Zone>,
     /// Current round number (1-based).
     pub round: u32,
+    /// Phases each round passes through, in order. Empty for rounds
+    /// without phases.
+    pub phases: Vec<CombatPhase>,
+    /// Index into `phases` for the current phase.
+    phase_index: usize,
     /// Index into `participants` for the current turn (by initiative order).
     turn_index: usize,
     /// Sorted participant indices by initiative (descending).
     initiative_order: Vec<usize>,
     /// Log of all combat events.
     pub log: Vec<CombatEvent>,
+    /// Attribute that breaks initiative ties, higher first (e.g. Agility).
+    /// Remaining ties go by name.
+    pub tiebreak: Option<String>,
 }
 
 impl Combat {
@@ -74,9 +90,20 @@ impl Combat {
             participants: Vec::new(),
             zones: Vec::new(),
             round: 0,
+            phases: Vec::new(),
+            phase_index: 0,
             turn_index: 0,
             initiative_order: Vec::new(),
             log: Vec::new(),
+            tiebreak: None,
+        }
+    }
+
+    /// Create a combat whose initiative ties are broken by `attribute`.
+    pub fn with_tiebreak(attribute: impl Into<String>) -> Self {
+        Self {
+            tiebreak: Some(attribute.into()),
+            ..Self::new()
         }
     }
 
@@ -92,6 +119,7 @@ impl Combat {
             sheet,
             zone_index: 0,
             initiative,
+            taken_out: false,
         });
     }
 
@@ -104,6 +132,7 @@ impl Combat {
     /// Sorts participants by initiative and begins the first turn.
     pub fn start(&mut self) {
         self.round = 1;
+        self.phase_index = 0;
         self.turn_index = 0;
         self.sort_initiative();
     }
@@ -131,21 +160,64 @@ impl Combat {
             )))
     }
 
+    /// Apply `amount` of harm to a participant's track.
+    ///
+    /// A participant whose track is driven to its minimum is taken out;
+    /// the returned [`AdjustResult`] carries any excess harm past that
+    /// point for systems with further consequences.
+    pub fn harm(&mut self, index: usize, track: &str, amount: i32) -> MechResult<AdjustResult> {
+        let participant = self.participant_mut(index)?;
+        let track = participant.sheet.track_mut(track)?;
+        let result = track.adjust(-amount);
+        if amount > 0 && track.is_empty() {
+            participant.taken_out = true;
+        }
+        Ok(result)
+    }
+
     /// Advance to the next turn. Returns true if a new round started.
+    ///
+    /// With phases, every participant acts in each phase; after the last
+    /// participant the combat moves on to the next phase, and after the
+    /// last phase to the next round.
     pub fn next_turn(&mut self) -> bool {
         if self.initiative_order.is_empty() {
             return false;
         }
         self.turn_index += 1;
         if self.turn_index >= self.initiative_order.len() {
-            self.turn_index = 0;
-            self.round += 1;
-            true
+            self.advance_phase()
         } else {
             false
         }
     }
 
+    /// Skip the rest of the current phase and start the next one with the
+    /// first participant. Returns true if a new round started.
+    pub fn next_phase(&mut self) -> bool {
+        if self.initiative_order.is_empty() {
+            return false;
+        }
+        self.advance_phase()
+    }
+
+    /// The current phase, or `None` if rounds have no phases.
+    pub fn current_phase(&self) -> Option<&CombatPhase> {
+        self.phases.get(self.phase_index)
+    }
+
+    /// Check that the current phase allows `action`.
+    pub fn check_action(&self, action: &CombatAction) -> MechResult<()> {
+        match self.current_phase() {
+            Some(phase) if !phase.allows(action) => Err(MechError::CombatError(format!(
+                "{} is not allowed during the {} phase",
+                action.kind(),
+                phase.name
+            ))),
+            _ => Ok(()),
+        }
+    }
+
     /// Get the current round number.
     pub fn current_round(&self) -> u32 {
         self.round
@@ -166,13 +238,36 @@ impl Combat {
         self.log.push(event);
     }
 
-    /// Sort participants by initiative (descending).
+    /// Move to the first turn of the next phase, wrapping into a new round
+    /// after the last one.
+    fn advance_phase(&mut self) -> bool {
+        self.turn_index = 0;
+        self.phase_index += 1;
+        if self.phase_index >= self.phases.len() {
+            self.phase_index = 0;
+            self.round += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Sort participants by initiative (descending), breaking ties by the
+    /// tiebreak attribute (descending), then by name.
     fn sort_initiative(&mut self) {
+        let tiebreak = |p: &Participant| {
+            self.tiebreak
+                .as_deref()
+                .and_then(|attr| p.sheet.attribute(attr).ok())
+                .unwrap_or(0)
+        };
         let mut indices: Vec<usize> = (0..self.participants.len()).collect();
         indices.sort_by(|&a, &b| {
-            self.participants[b]
-                .initiative
-                .cmp(&self.participants[a].initiative)
+            let (a, b) = (&self.participants[a], &self.participants[b]);
+            b.initiative
+                .cmp(&a.initiative)
+                .then_with(|| tiebreak(b).cmp(&tiebreak(a)))
+                .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
         });
         self.initiative_order = indices;
     }
@@ -189,7 +284,7 @@ mod tests {
     use super::*;
     use crate::rules::preset;
     use crate::sheet::CharacterSheet;
-    use ww_core::entity::{Entity, EntityKind};
+    use ww_core::entity::{Entity, EntityKind, MetadataValue};
 
     fn make_sheet(name: &str) -> CharacterSheet {
         let ruleset = preset::two_d20();
@@ -232,6 +327,100 @@ mod tests {
         assert_eq!(current.name, "Charlie");
     }
 
+    #[test]
+    fn initiative_ties_break_by_attribute_then_name() {
+        let ruleset = preset::two_d20();
+        let sheet = |name: &str, agility: i64| {
+            let mut entity = Entity::new(EntityKind::Character, name);
+            entity.properties.insert(
+                "mechanics.agility".to_string(),
+                MetadataValue::Integer(agility),
+            );
+            CharacterSheet::from_entity(&entity, &ruleset).unwrap()
+        };
+
+        let order = |combat: &mut Combat| -> Vec<String> {
+            combat.start();
+            (0..combat.participant_count())
+                .map(|_| {
+                    let name = combat.current_participant().unwrap().name.clone();
+                    combat.next_turn();
+                    name
+                })
+                .collect()
+        };
+
+        let mut combat = Combat::with_tiebreak("Agility");
+        combat.add_participant("Cora", sheet("Cora", 9), 12);
+        combat.add_participant("Bram", sheet("Bram", 11), 12);
+        combat.add_participant("Ada", sheet("Ada", 9), 12);
+        combat.add_participant("Dax", sheet("Dax", 5), 14);
+        assert_eq!(order(&mut combat), ["Dax", "Bram", "Ada", "Cora"]);
+        // Stable across restarts and insertion order
+        combat.participants.reverse();
+        assert_eq!(order(&mut combat), ["Dax", "Bram", "Ada", "Cora"]);
+
+        let mut combat = Combat::new();
+        combat.add_participant("Cora", sheet("Cora", 9), 12);
+        combat.add_participant("Bram", sheet("Bram", 11), 12);
+        combat.add_participant("Ada", sheet("Ada", 9), 12);
+        assert_eq!(order(&mut combat), ["Ada", "Bram", "Cora"]);
+    }
+
+    #[test]
+    fn phases_advance_before_the_round() {
+        let mut combat = Combat::new();
+        combat.add_participant("Alice", make_sheet("Alice"), 15);
+        combat.add_participant("Bob", make_sheet("Bob"), 10);
+        combat.phases = vec![CombatPhase::new("declare"), CombatPhase::new("resolve")];
+        combat.start();
+        assert_eq!(combat.current_phase().unwrap().name, "declare");
+
+        assert!(!combat.next_turn());
+        assert_eq!(combat.current_participant().unwrap().name, "Bob");
+        assert!(!combat.next_turn());
+        assert_eq!(combat.current_phase().unwrap().name, "resolve");
+        assert_eq!(combat.current_participant().unwrap().name, "Alice");
+        assert_eq!(combat.current_round(), 1);
+
+        assert!(combat.next_phase());
+        assert_eq!(combat.current_round(), 2);
+        assert_eq!(combat.current_phase().unwrap().name, "declare");
+        assert_eq!(combat.current_participant().unwrap().name, "Alice");
+    }
+
+    #[test]
+    fn phases_restrict_actions() {
+        let mut combat = Combat::new();
+        combat.add_participant("Alice", make_sheet("Alice"), 15);
+        combat.phases = vec![
+            CombatPhase::with_actions("declare", vec!["move".to_string()]),
+            CombatPhase::new("resolve"),
+        ];
+        combat.start();
+
+        assert!(
+            combat
+                .check_action(&CombatAction::Move { to_zone: 1 })
+                .is_ok()
+        );
+        let err = combat
+            .check_action(&CombatAction::Attack { target: 0 })
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "combat error: attack is not allowed during the declare phase"
+        );
+
+        combat.next_phase();
+        assert!(
+            combat
+                .check_action(&CombatAction::Attack { target: 0 })
+                .is_ok()
+        );
+        assert!(Combat::new().check_action(&CombatAction::Defend).is_ok());
+    }
+
     #[test]
     fn combat_zones() {
         let mut combat = Combat::new();
@@ -256,6 +445,23 @@ mod tests {
         assert_eq!(combat.participants[0].zone_index, 1);
     }
 
+    #[test]
+    fn harm_takes_out_at_minimum() {
+        let mut combat = Combat::new();
+        combat.add_participant("Alice", make_sheet("Alice"), 10);
+
+        let result = combat.harm(0, "Wounds", 3).unwrap();
+        assert_eq!(result.applied, -3);
+        assert!(!combat.participants[0].taken_out);
+
+        let result = combat.harm(0, "Wounds", 4).unwrap();
+        assert_eq!(result.applied, -2);
+        assert_eq!(result.overflow, -2);
+        assert!(combat.participants[0].taken_out);
+
+        assert!(combat.harm(0, "Nonexistent", 1).is_err());
+    }
+
     #[test]
     fn participant_mut_out_of_bounds() {
         let mut combat = Combat::new();
@@ -0,0 +1,61 @@
+//! Phased combat rounds.
+//!
+//! Some systems split each round into phases, e.g. everyone declares, then
+//! everyone resolves. A ruleset lists its phases in order and may restrict
+//! which actions are allowed in each:
+//!
+//! ```text
+//! mechanics {
+//!     phases ["declare", "resolve"]
+//!     phase {
+//!         declare ["move", "custom"]
+//!     }
+//! }
+//! ```
+//!
+//! Phases without a restriction allow every action.
+
+use super::CombatAction;
+
+/// A named step of a combat round.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CombatPhase {
+    /// Display name of the phase (e.g., "declare").
+    pub name: String,
+    /// Action kinds allowed in this phase (see [`CombatAction::kind`]).
+    /// Empty allows every action.
+    pub actions: Vec<String>,
+}
+
+impl CombatPhase {
+    /// Create a phase that allows every action.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            actions: Vec::new(),
+        }
+    }
+
+    /// Create a phase that allows only the given action kinds.
+    pub fn with_actions(name: impl Into<String>, actions: Vec<String>) -> Self {
+        Self {
+            name: name.into(),
+            actions,
+        }
+    }
+
+    /// Returns true if `action` may be taken during this phase.
+    pub fn allows(&self, action: &CombatAction) -> bool {
+        self.actions.is_empty()
+            || self
+                .actions
+                .iter()
+                .any(|a| a.eq_ignore_ascii_case(action.kind()))
+    }
+}
+
+impl std::fmt::Display for CombatPhase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
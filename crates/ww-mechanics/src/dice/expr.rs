@@ -0,0 +1,226 @@
+//! Dice expressions such as `2d6+3`, `d20-1`, or `1d8+2d6+1`.
+
+use super::{DicePool, Die, RollResult};
+use crate::error::{MechError, MechResult};
+
+/// One or more dice groups plus a flat modifier.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiceExpression {
+    /// Dice groups in the order written, as `(count, die)`.
+    pub groups: Vec<(u32, Die)>,
+    /// Sum of the constant terms, e.g. `+3` or `-1`.
+    pub modifier: i32,
+}
+
+impl DiceExpression {
+    /// Parse an expression of `+`/`-` separated terms, each either a dice
+    /// group (`2d6`, `d20`, `4dF`) or an integer. Whitespace is ignored.
+    ///
+    /// At least one dice group is required, and dice groups can only be
+    /// added, not subtracted.
+    pub fn parse(input: &str) -> MechResult<Self> {
+        let compact: String = input.chars().filter(|c| !c.is_whitespace()).collect();
+        let invalid = || MechError::InvalidDiceExpression(input.trim().to_string());
+        if compact.is_empty() {
+            return Err(invalid());
+        }
+
+        let mut groups = Vec::new();
+        let mut modifier = 0i32;
+        let mut rest = compact.as_str();
+        let mut negative = false;
+        loop {
+            let end = rest.find(['+', '-']).unwrap_or(rest.len());
+            let term = &rest[..end];
+            if term.is_empty() {
+                return Err(invalid());
+            }
+
+            if let Some((count, sides)) = term.split_once(['d', 'D']) {
+                if negative {
+                    return Err(MechError::InvalidDiceExpression(format!(
+                        "cannot subtract dice: -{term}"
+                    )));
+                }
+                let count = if count.is_empty() {
+                    1
+                } else {
+                    count.parse::<u32>().map_err(|_| invalid())?
+                };
+                if count == 0 {
+                    return Err(MechError::InvalidDiceExpression(
+                        "dice count must be at least 1".to_string(),
+                    ));
+                }
+                let die = Die::from_str_tag(&format!("d{sides}")).ok_or_else(|| {
+                    MechError::InvalidDiceExpression(format!("invalid die type: d{sides}"))
+                })?;
+                groups.push((count, die));
+            } else {
+                let n = term.parse::<i32>().map_err(|_| invalid())?;
+                let n = if negative { n.checked_neg() } else { Some(n) };
+                modifier = n.and_then(|n| modifier.checked_add(n)).ok_or_else(|| {
+                    MechError::InvalidDiceExpression(format!("modifier out of range: {input}"))
+                })?;
+            }
+
+            if end == rest.len() {
+                break;
+            }
+            negative = rest[end..].starts_with('-');
+            rest = &rest[end + 1..];
+        }
+
+        if groups.is_empty() {
+            return Err(invalid());
+        }
+        Ok(Self { groups, modifier })
+    }
+
+    /// A pool holding every die in the expression, group by group.
+    pub fn pool(&self) -> DicePool {
+        self.groups
+            .iter()
+            .fold(DicePool::new(), |pool, &(count, die)| pool.add(die, count))
+    }
+
+    /// The roll's net total plus the modifier.
+    ///
+    /// Fails with [`MechError::InvalidDiceExpression`] if the sum does not
+    /// fit in an `i32`.
+    pub fn total(&self, roll: &RollResult) -> MechResult<i32> {
+        roll.dice
+            .iter()
+            .try_fold(self.modifier, |sum, d| sum.checked_add(d.face_value()))
+            .ok_or_else(|| MechError::InvalidDiceExpression(format!("total out of range: {self}")))
+    }
+
+    /// Per-group die values followed by the modifier, e.g. `[5] + [3, 4] + 1`.
+    /// Fudge dice show their sign, e.g. `[+1, 0, -1]`.
+    ///
+    /// `roll` must come from this expression's [`pool`](Self::pool).
+    pub fn breakdown(&self, roll: &RollResult) -> String {
+        let mut dice = roll.dice.iter();
+        let mut parts: Vec<String> = self
+            .groups
+            .iter()
+            .map(|&(count, _)| {
+                let values: Vec<String> = dice
+                    .by_ref()
+                    .take(count as usize)
+                    .map(|d| match d.face_value() {
+                        v if d.die == Die::Fudge && v > 0 => format!("+{v}"),
+                        v => v.to_string(),
+                    })
+                    .collect();
+                format!("[{}]", values.join(", "))
+            })
+            .collect();
+        let mut out = parts.remove(0);
+        for part in parts {
+            out.push_str(" + ");
+            out.push_str(&part);
+        }
+        match self.modifier {
+            0 => {}
+            m if m > 0 => out.push_str(&format!(" + {m}")),
+            m => out.push_str(&format!(" - {}", -m)),
+        }
+        out
+    }
+}
+
+impl std::fmt::Display for DiceExpression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, (count, die)) in self.groups.iter().enumerate() {
+            if i > 0 {
+                write!(f, "+")?;
+            }
+            write!(f, "{count}{die}")?;
+        }
+        match self.modifier {
+            0 => Ok(()),
+            m => write!(f, "{m:+}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dice::DiceTag;
+
+    #[test]
+    fn parse_simple_groups() {
+        let expr = DiceExpression::parse("d100").unwrap();
+        assert_eq!(expr.groups, vec![(1, Die::D100)]);
+        assert_eq!(expr.modifier, 0);
+        assert_eq!(expr.to_string(), "1d100");
+
+        let expr = DiceExpression::parse("4dF").unwrap();
+        assert_eq!(expr.groups, vec![(4, Die::Fudge)]);
+    }
+
+    #[test]
+    fn parse_modifiers_and_multiple_groups() {
+        let expr = DiceExpression::parse("2d6+3").unwrap();
+        assert_eq!(expr.groups, vec![(2, Die::D6)]);
+        assert_eq!(expr.modifier, 3);
+
+        assert_eq!(DiceExpression::parse("d20-1").unwrap().modifier, -1);
+
+        let expr = DiceExpression::parse("1d8 + 2d6 + 1").unwrap();
+        assert_eq!(expr.groups, vec![(1, Die::D8), (2, Die::D6)]);
+        assert_eq!(expr.modifier, 1);
+        assert_eq!(expr.to_string(), "1d8+2d6+1");
+        assert_eq!(expr.pool().count(), 3);
+    }
+
+    #[test]
+    fn parse_rejects_invalid_expressions() {
+        for input in [
+            "", "xyz", "0d6", "2d6+", "+2d6", "3", "2d6-1d4", "2d1", "2d6++1",
+        ] {
+            assert!(DiceExpression::parse(input).is_err(), "{input:?} parsed");
+        }
+    }
+
+    #[test]
+    fn breakdown_and_total() {
+        let expr = DiceExpression::parse("1d8+2d6-2").unwrap();
+        let roll = expr.pool().manual_roll(&[5, 3, 4]).unwrap();
+        assert_eq!(expr.breakdown(&roll), "[5] + [3, 4] - 2");
+        assert_eq!(expr.total(&roll).unwrap(), 10);
+
+        let fudge = DiceExpression::parse("4dF+1").unwrap();
+        let roll = RollResult {
+            dice: [3, 2, 1, 3]
+                .iter()
+                .map(|&value| crate::dice::DieResult {
+                    die: Die::Fudge,
+                    tag: DiceTag::Default,
+                    value,
+                    exploded: false,
+                    rerolled: false,
+                    slot: 0,
+                })
+                .collect(),
+            dropped: Vec::new(),
+        };
+        assert_eq!(fudge.breakdown(&roll), "[+1, 0, -1, +1] + 1");
+        assert_eq!(fudge.total(&roll).unwrap(), 2);
+    }
+
+    #[test]
+    fn out_of_range_modifiers_and_totals_are_errors() {
+        assert!(DiceExpression::parse("d6+2147483647+1").is_err());
+        assert!(DiceExpression::parse("d6-2147483647-2").is_err());
+
+        let expr = DiceExpression::parse("2d6+2147483640").unwrap();
+        let roll = expr.pool().manual_roll(&[6, 6]).unwrap();
+        assert!(matches!(
+            expr.total(&roll),
+            Err(MechError::InvalidDiceExpression(_))
+        ));
+    }
+}
@@ -1,13 +1,16 @@
 //! Dice types, pools, and rolling.
 //!
-//! Supports standard polyhedral dice (d4 through d100) and custom dice.
+//! Supports standard polyhedral dice (d4 through d100), Fudge/FATE dice,
+//! and custom dice.
 //! Dice can be tagged (light, dark, momentum, wagered) for system-specific
 //! behavior during resolution.
 
+pub mod expr;
 pub mod pool;
 pub mod roll;
 
-pub use pool::DicePool;
+pub use expr::DiceExpression;
+pub use pool::{DicePool, Keep};
 pub use roll::{DieResult, RollResult};
 
 use serde::{Deserialize, Serialize};
@@ -31,6 +34,8 @@ pub enum Die {
     D100,
     /// A die with a custom number of sides.
     Custom(u32),
+    /// Fudge/FATE die: faces 1, 2, 3 read as -1, 0, +1.
+    Fudge,
 }
 
 impl Die {
@@ -45,10 +50,20 @@ impl Die {
             Self::D20 => 20,
             Self::D100 => 100,
             Self::Custom(n) => n,
+            Self::Fudge => 3,
         }
     }
 
-    /// Parse a die from a string like "d20", "d6", "d100".
+    /// What a rolled face counts for: the face itself, or -1/0/+1 on a
+    /// Fudge die.
+    pub fn face_value(self, face: u32) -> i32 {
+        match self {
+            Self::Fudge => face as i32 - 2,
+            _ => face as i32,
+        }
+    }
+
+    /// Parse a die from a string like "d20", "d6", "d100", or "dF".
     pub fn from_str_tag(s: &str) -> Option<Self> {
         let s = s.trim().to_lowercase();
         match s.as_str() {
@@ -59,6 +74,7 @@ impl Die {
             "d12" => Some(Self::D12),
             "d20" => Some(Self::D20),
             "d100" => Some(Self::D100),
+            "df" => Some(Self::Fudge),
             other => {
                 let num = other.strip_prefix('d')?.parse::<u32>().ok()?;
                 if num >= 2 {
@@ -82,6 +98,7 @@ impl std::fmt::Display for Die {
             Self::D20 => write!(f, "d20"),
             Self::D100 => write!(f, "d100"),
             Self::Custom(n) => write!(f, "d{n}"),
+            Self::Fudge => write!(f, "dF"),
         }
     }
 }
@@ -131,6 +148,16 @@ mod tests {
         assert_eq!(Die::D20.sides(), 20);
         assert_eq!(Die::D100.sides(), 100);
         assert_eq!(Die::Custom(30).sides(), 30);
+        assert_eq!(Die::Fudge.sides(), 3);
+    }
+
+    #[test]
+    fn fudge_faces() {
+        assert_eq!(Die::from_str_tag("dF"), Some(Die::Fudge));
+        assert_eq!(Die::Fudge.to_string(), "dF");
+        let faces: Vec<i32> = (1..=3).map(|f| Die::Fudge.face_value(f)).collect();
+        assert_eq!(faces, vec![-1, 0, 1]);
+        assert_eq!(Die::D6.face_value(4), 4);
     }
 
     #[test]
@@ -5,18 +5,54 @@ use rand::rngs::StdRng;
 
 use super::roll::{DieResult, RollResult};
 use super::{DiceTag, Die};
+use crate::error::{MechError, MechResult};
+
+/// Default cap on how many times a single die may explode.
+pub const DEFAULT_MAX_EXPLOSIONS: u32 = 100;
+
+/// Which dice of a roll count toward the result.
+///
+/// A die and the dice its explosions added are kept or dropped together,
+/// ranked by their combined value, and count as one die toward `n`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Keep {
+    /// Keep the `n` highest dice and drop the rest.
+    Highest(u32),
+    /// Keep the `n` lowest dice and drop the rest.
+    Lowest(u32),
+}
 
 /// A collection of dice to be rolled together.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone)]
 pub struct DicePool {
     /// The dice in this pool with their tags.
     pub dice: Vec<(Die, DiceTag)>,
+    /// Dice showing this value or higher roll an extra die of the same kind.
+    pub explode: Option<u32>,
+    /// How many extra dice a single die may chain into when exploding.
+    pub max_explosions: u32,
+    /// Dice showing this value or lower are rolled again, once.
+    pub reroll: Option<u32>,
+    /// Which dice to keep after rolling; the rest are dropped.
+    pub keep: Option<Keep>,
+}
+
+impl Default for DicePool {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl DicePool {
     /// Create an empty dice pool.
     pub fn new() -> Self {
-        Self { dice: Vec::new() }
+        Self {
+            dice: Vec::new(),
+            explode: None,
+            max_explosions: DEFAULT_MAX_EXPLOSIONS,
+            reroll: None,
+            keep: None,
+        }
     }
 
     /// Add `count` dice of the given type with the default tag.
@@ -35,6 +71,38 @@ impl DicePool {
         self
     }
 
+    /// Explode dice that show `threshold` or higher: each such die adds
+    /// another roll of the same die and tag, which may explode in turn.
+    pub fn explode(mut self, threshold: u32) -> Self {
+        self.explode = Some(threshold);
+        self
+    }
+
+    /// Limit how many times a single die may explode (default: 100).
+    pub fn max_explosions(mut self, max: u32) -> Self {
+        self.max_explosions = max;
+        self
+    }
+
+    /// Reroll dice showing `below` or lower, once each; the new value
+    /// stands even if it is low again. `reroll(1)` rerolls ones.
+    pub fn reroll(mut self, below: u32) -> Self {
+        self.reroll = Some(below);
+        self
+    }
+
+    /// Keep only the `n` highest dice of the roll.
+    pub fn keep_highest(mut self, n: u32) -> Self {
+        self.keep = Some(Keep::Highest(n));
+        self
+    }
+
+    /// Keep only the `n` lowest dice of the roll.
+    pub fn keep_lowest(mut self, n: u32) -> Self {
+        self.keep = Some(Keep::Lowest(n));
+        self
+    }
+
     /// Returns how many dice are in the pool.
     pub fn count(&self) -> usize {
         self.dice.len()
@@ -46,20 +114,126 @@ impl DicePool {
     }
 
     /// Roll all dice in the pool using the given RNG.
+    ///
+    /// Low dice are rerolled first and marked with [`DieResult::rerolled`].
+    /// Exploded dice follow the die that triggered them and are marked
+    /// with [`DieResult::exploded`]. Dice left out by [`Keep`] end up in
+    /// [`RollResult::dropped`].
     pub fn roll(&self, rng: &mut StdRng) -> RollResult {
+        let mut dice = Vec::with_capacity(self.dice.len());
+        for (slot, (die, tag)) in self.dice.iter().enumerate() {
+            let mut value = rng.random_range(1..=die.sides());
+            let rerolled = self.reroll.is_some_and(|below| value <= below);
+            if rerolled {
+                value = rng.random_range(1..=die.sides());
+            }
+            dice.push(DieResult {
+                die: *die,
+                tag: tag.clone(),
+                value,
+                exploded: false,
+                rerolled,
+                slot,
+            });
+            let Some(threshold) = self.explode else {
+                continue;
+            };
+            let mut depth = 0;
+            while value >= threshold && depth < self.max_explosions {
+                value = rng.random_range(1..=die.sides());
+                dice.push(DieResult {
+                    die: *die,
+                    tag: tag.clone(),
+                    value,
+                    exploded: true,
+                    rerolled: false,
+                    slot,
+                });
+                depth += 1;
+            }
+        }
+        self.keep_dice(dice)
+    }
+
+    /// Build a result from dice rolled by hand, one value per die in order.
+    ///
+    /// Fails unless there is exactly one value per die and each lies within
+    /// its die's faces. [`Keep`] still applies; rerolls and explosions are
+    /// left to the player.
+    pub fn manual_roll(&self, values: &[u32]) -> MechResult<RollResult> {
+        if values.len() != self.dice.len() {
+            return Err(MechError::InvalidRoll(format!(
+                "expected {} values, got {}",
+                self.dice.len(),
+                values.len()
+            )));
+        }
         let dice = self
             .dice
             .iter()
-            .map(|(die, tag)| {
-                let value = rng.random_range(1..=die.sides());
-                DieResult {
+            .zip(values)
+            .enumerate()
+            .map(|(slot, ((die, tag), &value))| {
+                if !(1..=die.sides()).contains(&value) {
+                    return Err(MechError::InvalidRoll(format!(
+                        "{value} is not a face of a {die}"
+                    )));
+                }
+                Ok(DieResult {
                     die: *die,
                     tag: tag.clone(),
                     value,
-                }
+                    exploded: false,
+                    rerolled: false,
+                    slot,
+                })
             })
-            .collect();
-        RollResult { dice }
+            .collect::<MechResult<_>>()?;
+        Ok(self.keep_dice(dice))
+    }
+
+    /// Split rolled dice into kept and dropped per [`Self::keep`], keeping
+    /// roll order on both sides. Explosion chains stay whole.
+    fn keep_dice(&self, dice: Vec<DieResult>) -> RollResult {
+        let Some(keep) = self.keep else {
+            return RollResult {
+                dice,
+                dropped: Vec::new(),
+            };
+        };
+        // Each chain is a rolled die followed by the dice it exploded into
+        let mut chains: Vec<Vec<DieResult>> = Vec::new();
+        for die in dice {
+            match chains.last_mut() {
+                Some(chain) if die.exploded => chain.push(die),
+                _ => chains.push(vec![die]),
+            }
+        }
+        let total = |chain: &[DieResult]| chain.iter().map(DieResult::face_value).sum::<i32>();
+        let mut order: Vec<usize> = (0..chains.len()).collect();
+        let n = match keep {
+            Keep::Highest(n) => {
+                order.sort_by_key(|&i| std::cmp::Reverse(total(&chains[i])));
+                n
+            }
+            Keep::Lowest(n) => {
+                order.sort_by_key(|&i| total(&chains[i]));
+                n
+            }
+        };
+        let mut kept = vec![false; chains.len()];
+        for &i in order.iter().take(n as usize) {
+            kept[i] = true;
+        }
+        let mut result = RollResult::default();
+        for (chain, kept) in chains.into_iter().zip(kept) {
+            if kept {
+                result.dice.extend(chain);
+            } else {
+                result.dropped.extend(chain);
+            }
+        }
+        result
     }
 }
 
@@ -103,6 +277,130 @@ mod tests {
         }
     }
 
+    #[test]
+    fn manual_roll_validates_faces_and_count() {
+        let pool = DicePool::new().add(Die::D6, 2);
+        let result = pool.manual_roll(&[4, 5]).unwrap();
+        assert_eq!(result.total(), 9);
+        assert!(matches!(
+            pool.manual_roll(&[4]),
+            Err(MechError::InvalidRoll(msg)) if msg == "expected 2 values, got 1"
+        ));
+        assert!(matches!(
+            pool.manual_roll(&[4, 7]),
+            Err(MechError::InvalidRoll(msg)) if msg == "7 is not a face of a d6"
+        ));
+        assert!(pool.manual_roll(&[0, 1]).is_err());
+    }
+
+    #[test]
+    fn exploding_dice_append_extra_rolls() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let pool = DicePool::new().add(Die::D6, 20).explode(6);
+        let result = pool.roll(&mut rng);
+
+        let sixes = result.dice.iter().filter(|d| d.value == 6).count();
+        assert!(sixes > 0, "seed should roll at least one six");
+        assert_eq!(result.count(), 20 + sixes);
+        assert_eq!(result.exploded().count(), sixes);
+        for pair in result.dice.windows(2) {
+            if pair[1].exploded {
+                assert_eq!(pair[0].value, 6);
+                assert_eq!(pair[1].slot, pair[0].slot);
+            } else {
+                assert_eq!(pair[1].slot, pair[0].slot + 1);
+            }
+        }
+    }
+
+    #[test]
+    fn explosions_stop_at_max_depth() {
+        let mut rng = StdRng::seed_from_u64(1);
+        // Every face explodes, so only the cap ends the chain
+        let pool = DicePool::new().add(Die::D4, 2).explode(1).max_explosions(5);
+        let result = pool.roll(&mut rng);
+        assert_eq!(result.count(), 12);
+        assert_eq!(result.exploded().count(), 10);
+        assert_eq!(DicePool::new().max_explosions, DEFAULT_MAX_EXPLOSIONS);
+    }
+
+    #[test]
+    fn reroll_replaces_low_dice_once() {
+        let mut rng = StdRng::seed_from_u64(11);
+        let pool = DicePool::new().add(Die::D6, 30).reroll(1);
+        let result = pool.roll(&mut rng);
+
+        assert_eq!(result.count(), 30);
+        assert!(result.rerolled().count() > 0, "seed should roll a one");
+        // Only rerolled dice may still show a one
+        for die in &result.dice {
+            assert!(die.value > 1 || die.rerolled);
+        }
+        let plain = DicePool::new().add(Die::D6, 30).roll(&mut rng);
+        assert_eq!(plain.rerolled().count(), 0);
+    }
+
+    #[test]
+    fn keep_highest_and_lowest_drop_the_rest() {
+        let pool = DicePool::new().add(Die::D6, 4);
+        let values = |dice: &[DieResult]| -> Vec<u32> { dice.iter().map(|d| d.value).collect() };
+
+        let result = pool
+            .clone()
+            .keep_highest(3)
+            .manual_roll(&[2, 6, 1, 4])
+            .unwrap();
+        assert_eq!(values(&result.dice), [2, 6, 4]);
+        assert_eq!(values(&result.dropped), [1]);
+        assert_eq!(result.total(), 12);
+
+        let result = pool
+            .clone()
+            .keep_lowest(1)
+            .manual_roll(&[2, 6, 1, 4])
+            .unwrap();
+        assert_eq!(values(&result.dice), [1]);
+        assert_eq!(values(&result.dropped), [2, 6, 4]);
+
+        // Keeping more dice than were rolled keeps them all
+        let mut rng = StdRng::seed_from_u64(5);
+        let result = pool.keep_highest(9).roll(&mut rng);
+        assert_eq!((result.count(), result.dropped.len()), (4, 0));
+    }
+
+    #[test]
+    fn keep_treats_explosion_chains_as_one_die() {
+        let die = |value, exploded| DieResult {
+            die: Die::D6,
+            tag: DiceTag::Default,
+            value,
+            exploded,
+            rerolled: false,
+            slot: 0,
+        };
+        let values = |dice: &[DieResult]| -> Vec<u32> { dice.iter().map(|d| d.value).collect() };
+        let rolled = || vec![die(6, false), die(6, true), die(1, true), die(5, false)];
+
+        let result = DicePool::new().keep_highest(1).keep_dice(rolled());
+        assert_eq!(values(&result.dice), [6, 6, 1]);
+        assert_eq!(values(&result.dropped), [5]);
+        assert_eq!(result.total(), 13);
+
+        let result = DicePool::new().keep_lowest(1).keep_dice(rolled());
+        assert_eq!(values(&result.dice), [5]);
+        assert_eq!(values(&result.dropped), [6, 6, 1]);
+
+        // Seeded rolls never split a chain
+        let mut rng = StdRng::seed_from_u64(3);
+        let pool = DicePool::new().add(Die::D6, 10).explode(6).keep_highest(4);
+        let result = pool.roll(&mut rng);
+        assert!(result.exploded().count() > 0, "seed should explode a die");
+        for kept in [&result.dice, &result.dropped] {
+            assert!(kept.first().is_none_or(|d| !d.exploded));
+        }
+        assert_eq!(result.dice.iter().filter(|d| !d.exploded).count(), 4);
+    }
+
     #[test]
     fn roll_deterministic_with_seed() {
         let pool = DicePool::new().add(Die::D20, 3);
@@ -13,6 +13,24 @@ pub struct DieResult {
     pub tag: DiceTag,
     /// The value rolled (1 to die.sides()).
     pub value: u32,
+    /// Whether this die was added by another die exploding.
+    #[serde(default)]
+    pub exploded: bool,
+    /// Whether this value replaced a low roll of the same die.
+    #[serde(default)]
+    pub rerolled: bool,
+    /// Which die of the pool this is, counting from 0. Dice added by
+    /// explosions share the slot of the die that exploded, and the slot
+    /// stays put when dice are reordered or dropped.
+    #[serde(default)]
+    pub slot: usize,
+}
+
+impl DieResult {
+    /// What this die counts for; see [`Die::face_value`].
+    pub fn face_value(&self) -> i32 {
+        self.die.face_value(self.value)
+    }
 }
 
 /// The result of rolling an entire dice pool.
@@ -20,6 +38,12 @@ pub struct DieResult {
 pub struct RollResult {
     /// Individual die results.
     pub dice: Vec<DieResult>,
+    /// Dice rolled but set aside, e.g. by advantage, disadvantage, or
+    /// keeping only the highest or lowest few.
+    ///
+    /// Totals, counts, and resolution only look at [`dice`](Self::dice).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dropped: Vec<DieResult>,
 }
 
 impl RollResult {
@@ -28,6 +52,16 @@ impl RollResult {
         self.dice.iter().map(|d| d.value).sum()
     }
 
+    /// Sum of what the dice count for, so Fudge dice add -1/0/+1.
+    pub fn net_total(&self) -> i32 {
+        self.dice.iter().map(DieResult::face_value).sum()
+    }
+
+    /// Whether any die in the result is a Fudge die.
+    pub fn has_fudge(&self) -> bool {
+        self.dice.iter().any(|d| d.die == Die::Fudge)
+    }
+
     /// The highest single die value, or 0 if empty.
     pub fn highest(&self) -> u32 {
         self.dice.iter().map(|d| d.value).max().unwrap_or(0)
@@ -58,6 +92,16 @@ impl RollResult {
         self.by_tag(tag).iter().map(|d| d.value).max().unwrap_or(0)
     }
 
+    /// Dice added by explosions, in roll order.
+    pub fn exploded(&self) -> impl Iterator<Item = &DieResult> {
+        self.dice.iter().filter(|d| d.exploded)
+    }
+
+    /// Dice whose first roll was rerolled, in roll order.
+    pub fn rerolled(&self) -> impl Iterator<Item = &DieResult> {
+        self.dice.iter().filter(|d| d.rerolled)
+    }
+
     /// Number of dice in the result.
     pub fn count(&self) -> usize {
         self.dice.len()
@@ -66,8 +110,32 @@ impl RollResult {
 
 impl std::fmt::Display for RollResult {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let values: Vec<String> = self.dice.iter().map(|d| d.value.to_string()).collect();
-        write!(f, "[{}] = {}", values.join(", "), self.total())
+        let values: Vec<String> = self
+            .dice
+            .iter()
+            .map(|d| {
+                let value = match d.face_value() {
+                    0 if d.die == Die::Fudge => "0".to_string(),
+                    v if d.die == Die::Fudge => format!("{v:+}"),
+                    _ => d.value.to_string(),
+                };
+                let value = if d.rerolled {
+                    format!("{value}r")
+                } else {
+                    value
+                };
+                if d.exploded {
+                    format!("+{value}")
+                } else {
+                    value
+                }
+            })
+            .collect();
+        if self.has_fudge() {
+            write!(f, "[{}] = {:+}", values.join(", "), self.net_total())
+        } else {
+            write!(f, "[{}] = {}", values.join(", "), self.total())
+        }
     }
 }
 
@@ -83,8 +151,12 @@ mod tests {
                     die: *die,
                     tag: tag.clone(),
                     value: *value,
+                    exploded: false,
+                    rerolled: false,
+                    slot: 0,
                 })
                 .collect(),
+            dropped: Vec::new(),
         }
     }
 
@@ -162,4 +234,41 @@ mod tests {
         ]);
         assert_eq!(r.to_string(), "[3, 5] = 8");
     }
+
+    #[test]
+    fn fudge_totals_and_display() {
+        let r = make_result(&[
+            (Die::Fudge, DiceTag::Default, 3),
+            (Die::Fudge, DiceTag::Default, 2),
+            (Die::Fudge, DiceTag::Default, 1),
+            (Die::Fudge, DiceTag::Default, 3),
+        ]);
+        assert!(r.has_fudge());
+        assert_eq!(r.net_total(), 1);
+        assert_eq!(r.to_string(), "[+1, 0, -1, +1] = +1");
+    }
+
+    #[test]
+    fn exploded_dice_count_and_display() {
+        let mut r = make_result(&[
+            (Die::D6, DiceTag::Default, 6),
+            (Die::D6, DiceTag::Default, 4),
+            (Die::D6, DiceTag::Default, 2),
+        ]);
+        r.dice[1].exploded = true;
+        assert_eq!(r.exploded().count(), 1);
+        assert_eq!(r.total(), 12);
+        assert_eq!(r.to_string(), "[6, +4, 2] = 12");
+    }
+
+    #[test]
+    fn rerolled_dice_are_marked() {
+        let mut r = make_result(&[
+            (Die::D6, DiceTag::Default, 5),
+            (Die::D6, DiceTag::Default, 3),
+        ]);
+        r.dice[0].rerolled = true;
+        assert_eq!(r.rerolled().count(), 1);
+        assert_eq!(r.to_string(), "[5r, 3] = 8");
+    }
 }
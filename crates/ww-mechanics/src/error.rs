@@ -19,6 +19,22 @@ pub enum MechError {
     #[error("invalid pool: {0}")]
     InvalidPool(String),
 
+    /// Manually entered die values do not fit the pool.
+    #[error("invalid roll: {0}")]
+    InvalidRoll(String),
+
+    /// A dice expression such as `2d6+3` could not be parsed.
+    #[error("invalid dice expression: {0}")]
+    InvalidDiceExpression(String),
+
+    /// An entity referenced by name does not exist in the world.
+    #[error("entity not found: {0}")]
+    EntityNotFound(String),
+
+    /// A derived-stat formula could not be parsed or evaluated.
+    #[error("invalid formula: {0}")]
+    InvalidFormula(String),
+
     /// No mechanics configuration was found in the world.
     #[error("no mechanics config found in world")]
     NoMechanicsConfig,
@@ -31,6 +47,10 @@ pub enum MechError {
     #[error("combat error: {0}")]
     CombatError(String),
 
+    /// An encounter definition in the world is malformed.
+    #[error("invalid encounter: {0}")]
+    InvalidEncounter(String),
+
     /// No participant is currently active in combat.
     #[error("no active participant")]
     NoActiveParticipant,
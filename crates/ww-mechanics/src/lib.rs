@@ -13,9 +13,14 @@ pub mod rules;
 pub mod sheet;
 pub mod validate;
 
-pub use dice::{DicePool, DiceTag, Die, DieResult, RollResult};
+pub use dice::{DiceExpression, DicePool, DiceTag, Die, DieResult, Keep, RollResult};
 pub use error::{MechError, MechResult};
-pub use resolution::{CountSuccesses, HighestDie, Outcome, ResolutionStrategy, RollUnder, SumPool};
-pub use rules::{CheckEffect, CheckRequest, CheckResult, RuleSet, TrackDefinition};
-pub use sheet::{CharacterSheet, Track};
+pub use resolution::{
+    CountSuccesses, HighestDie, Outcome, ResolutionStrategy, RollPlus, RollUnder, SumPool,
+};
+pub use rules::{
+    CheckEffect, CheckRequest, CheckResult, DerivedStat, EffectRule, EffectSpec, EffectTrigger,
+    Formula, RuleSet, TrackDefinition,
+};
+pub use sheet::{AdjustResult, CharacterSheet, Track};
 pub use validate::validate_world;
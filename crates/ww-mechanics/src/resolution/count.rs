@@ -3,6 +3,9 @@
 //! Roll multiple d20s. Each die at or below the target number scores one success.
 //! A die at or below the critical threshold scores two successes instead.
 //! Meeting or exceeding `successes_needed` is a success.
+//!
+//! Individual dice can use their own target number via `die_targets`, which
+//! covers systems where a focus or talent improves only one die of the pool.
 
 use serde::{Deserialize, Serialize};
 
@@ -18,6 +21,13 @@ pub struct CountSuccesses {
     pub critical_threshold: u32,
     /// How many successes are needed for a standard success.
     pub successes_needed: u32,
+    /// Per-die target numbers, indexed by the die's slot in the pool
+    /// (see [`DieResult::slot`](crate::dice::DieResult::slot)), so dice an
+    /// explosion added share the target of the die that exploded.
+    ///
+    /// Slots beyond the end of this list use `target_number`.
+    #[serde(default)]
+    pub die_targets: Vec<u32>,
 }
 
 impl Default for CountSuccesses {
@@ -26,11 +36,20 @@ impl Default for CountSuccesses {
             target_number: 10,
             critical_threshold: 1,
             successes_needed: 1,
+            die_targets: Vec::new(),
         }
     }
 }
 
 impl CountSuccesses {
+    /// The target number for the die in pool slot `slot`.
+    pub fn target_for(&self, slot: usize) -> u32 {
+        self.die_targets
+            .get(slot)
+            .copied()
+            .unwrap_or(self.target_number)
+    }
+
     /// Resolve a roll by counting successes.
     pub fn resolve(&self, roll: &RollResult) -> Outcome {
         let mut successes: u32 = 0;
@@ -39,7 +58,7 @@ impl CountSuccesses {
         for die in &roll.dice {
             if die.value <= self.critical_threshold {
                 successes += 2;
-            } else if die.value <= self.target_number {
+            } else if die.value <= self.target_for(die.slot) {
                 successes += 1;
             }
             if die.value == die.die.sides() {
@@ -64,6 +83,53 @@ impl CountSuccesses {
             Outcome::Failure
         }
     }
+
+    /// Describe how a roll is tallied, without the final outcome.
+    pub fn explain(&self, roll: &RollResult) -> String {
+        let (mut hits, mut crits, mut successes) = (0, 0, 0);
+        for die in &roll.dice {
+            if die.value <= self.critical_threshold {
+                crits += 1;
+                successes += 2;
+            } else if die.value <= self.target_for(die.slot) {
+                hits += 1;
+                successes += 1;
+            }
+        }
+
+        let targets: Vec<u32> = roll.dice.iter().map(|d| self.target_for(d.slot)).collect();
+        let tn = if targets.iter().all(|&t| t == self.target_number) {
+            format!("TN {}", self.target_number)
+        } else {
+            let list: Vec<String> = targets.iter().map(u32::to_string).collect();
+            format!("TNs {}", list.join("/"))
+        };
+
+        let mut text = format!(
+            "{} vs {tn}: {} rolled at or under",
+            super::dice_count(roll.dice.len()),
+            hits + crits
+        );
+        if crits > 0 {
+            text.push_str(&format!(
+                ", {crits} at or under {} counting double",
+                self.critical_threshold
+            ));
+        }
+        text.push_str(&format!(
+            " → {successes} {}, {} needed",
+            if successes == 1 {
+                "success"
+            } else {
+                "successes"
+            },
+            self.successes_needed
+        ));
+        if successes == 0 && roll.dice.iter().any(|d| d.value == d.die.sides()) {
+            text.push_str("; a natural max with no successes");
+        }
+        text
+    }
 }
 
 #[cfg(test)]
@@ -75,12 +141,17 @@ mod tests {
         RollResult {
             dice: values
                 .iter()
-                .map(|&v| DieResult {
+                .enumerate()
+                .map(|(slot, &v)| DieResult {
                     die: Die::D20,
                     tag: DiceTag::Default,
                     value: v,
+                    exploded: false,
+                    rerolled: false,
+                    slot,
                 })
                 .collect(),
+            dropped: Vec::new(),
         }
     }
 
@@ -90,6 +161,7 @@ mod tests {
             target_number: 12,
             critical_threshold: 2,
             successes_needed: 1,
+            ..CountSuccesses::default()
         };
         let roll = make_d20_roll(&[5, 8]);
         assert_eq!(
@@ -104,6 +176,7 @@ mod tests {
             target_number: 10,
             critical_threshold: 1,
             successes_needed: 1,
+            ..CountSuccesses::default()
         };
         let roll = make_d20_roll(&[7, 15]);
         assert_eq!(strategy.resolve(&roll), Outcome::Success { margin: 0 });
@@ -115,6 +188,7 @@ mod tests {
             target_number: 10,
             critical_threshold: 1,
             successes_needed: 1,
+            ..CountSuccesses::default()
         };
         let roll = make_d20_roll(&[15, 18]);
         assert_eq!(strategy.resolve(&roll), Outcome::Failure);
@@ -126,6 +200,7 @@ mod tests {
             target_number: 10,
             critical_threshold: 2,
             successes_needed: 2,
+            ..CountSuccesses::default()
         };
         // A roll of 1 gives 2 successes (critical)
         let roll = make_d20_roll(&[1, 15]);
@@ -138,6 +213,7 @@ mod tests {
             target_number: 5,
             critical_threshold: 1,
             successes_needed: 1,
+            ..CountSuccesses::default()
         };
         let roll = make_d20_roll(&[20, 18]);
         assert_eq!(strategy.resolve(&roll), Outcome::CriticalFailure);
@@ -149,6 +225,7 @@ mod tests {
             target_number: 10,
             critical_threshold: 1,
             successes_needed: 3,
+            ..CountSuccesses::default()
         };
         let roll = make_d20_roll(&[5, 8, 15, 18]);
         // 2 successes out of 3 needed
@@ -161,5 +238,77 @@ mod tests {
         assert_eq!(strategy.target_number, 10);
         assert_eq!(strategy.critical_threshold, 1);
         assert_eq!(strategy.successes_needed, 1);
+        assert!(strategy.die_targets.is_empty());
+    }
+
+    #[test]
+    fn per_die_targets() {
+        let strategy = CountSuccesses {
+            target_number: 10,
+            critical_threshold: 1,
+            successes_needed: 2,
+            die_targets: vec![14],
+        };
+        // First die succeeds against its own TN of 14, second uses the base TN
+        let roll = make_d20_roll(&[13, 9]);
+        assert_eq!(strategy.resolve(&roll), Outcome::Success { margin: 0 });
+        let roll = make_d20_roll(&[9, 13]);
+        assert_eq!(strategy.resolve(&roll), Outcome::Partial);
+        assert_eq!(strategy.target_for(0), 14);
+        assert_eq!(strategy.target_for(1), 10);
+    }
+
+    #[test]
+    fn per_die_targets_follow_explosions() {
+        let strategy = CountSuccesses {
+            target_number: 10,
+            critical_threshold: 1,
+            successes_needed: 2,
+            die_targets: vec![14],
+        };
+        // The first die explodes; the die it adds keeps its TN of 14 and
+        // the second die of the pool still uses the base TN
+        let mut roll = make_d20_roll(&[20, 13, 12]);
+        roll.dice[1].exploded = true;
+        roll.dice[1].slot = 0;
+        roll.dice[2].slot = 1;
+        assert_eq!(strategy.resolve(&roll), Outcome::Partial);
+        assert!(
+            strategy
+                .explain(&roll)
+                .starts_with("3 dice vs TNs 14/14/10")
+        );
+
+        let mut roll = make_d20_roll(&[20, 13, 9]);
+        roll.dice[1].exploded = true;
+        roll.dice[1].slot = 0;
+        roll.dice[2].slot = 1;
+        assert_eq!(strategy.resolve(&roll), Outcome::Success { margin: 0 });
+    }
+
+    #[test]
+    fn explain_tallies_successes() {
+        let strategy = CountSuccesses {
+            target_number: 12,
+            critical_threshold: 3,
+            ..CountSuccesses::default()
+        };
+        let roll = make_d20_roll(&[2, 11, 17]);
+        assert_eq!(
+            strategy.explain(&roll),
+            "3 dice vs TN 12: 2 rolled at or under, 1 at or under 3 counting double \
+             → 3 successes, 1 needed"
+        );
+
+        let strategy = CountSuccesses {
+            die_targets: vec![14],
+            ..strategy
+        };
+        let roll = make_d20_roll(&[15, 20]);
+        assert_eq!(
+            strategy.explain(&roll),
+            "2 dice vs TNs 14/12: 0 rolled at or under → 0 successes, 1 needed; \
+             a natural max with no successes"
+        );
     }
 }
@@ -71,6 +71,21 @@ impl HighestDie {
         }
     }
 
+    /// Describe how a roll is read, without the final outcome.
+    pub fn explain(&self, roll: &RollResult) -> String {
+        let mut text = format!(
+            "highest of {} is {}: {}+ succeeds, {}+ is partial",
+            super::dice_count(roll.dice.len()),
+            roll.highest(),
+            self.success_min,
+            self.partial_min
+        );
+        if self.dark_die_penalty && self.is_dark_highest(roll) {
+            text.push_str("; the highest die is dark, which downgrades the outcome");
+        }
+        text
+    }
+
     /// Returns true if the highest-value die in the roll is a dark die.
     pub fn is_dark_highest(&self, roll: &RollResult) -> bool {
         let highest_value = roll.highest();
@@ -93,14 +108,23 @@ mod tests {
                 die: Die::D6,
                 tag: DiceTag::Light,
                 value: v,
+                exploded: false,
+                rerolled: false,
+                slot: 0,
             })
             .collect();
         dice.extend(dark.iter().map(|&v| DieResult {
             die: Die::D6,
             tag: DiceTag::Dark,
             value: v,
+            exploded: false,
+            rerolled: false,
+            slot: 0,
         }));
-        RollResult { dice }
+        RollResult {
+            dice,
+            dropped: Vec::new(),
+        }
     }
 
     #[test]
@@ -5,16 +5,19 @@
 //! - **Highest die** (Trophy Gold): check the single highest die value
 //! - **Sum pool** (Blood & Honor): sum all dice and compare to a target
 //! - **Roll under** (Mothership): roll one die at or below a target value
+//! - **Roll plus** (Powered by the Apocalypse): roll 2d6 plus a stat into bands
 
 pub mod count;
 pub mod highest;
+pub mod roll_plus;
 pub mod roll_under;
 pub mod sum;
 
 pub use count::CountSuccesses;
 pub use highest::HighestDie;
+pub use roll_plus::RollPlus;
 pub use roll_under::RollUnder;
-pub use sum::SumPool;
+pub use sum::{SumPool, ladder_rung};
 
 use serde::{Deserialize, Serialize};
 
@@ -31,6 +34,8 @@ pub enum ResolutionStrategy {
     Sum(SumPool),
     /// Roll a single die and check if it's at or below a target (Mothership-style).
     RollUnder(RollUnder),
+    /// Add a modifier to the dice total and read it against bands (PbtA-style).
+    RollPlus(RollPlus),
 }
 
 /// The outcome of resolving a dice roll.
@@ -73,6 +78,30 @@ pub fn resolve(strategy: &ResolutionStrategy, roll: &RollResult) -> Outcome {
         ResolutionStrategy::Highest(s) => s.resolve(roll),
         ResolutionStrategy::Sum(s) => s.resolve(roll),
         ResolutionStrategy::RollUnder(s) => s.resolve(roll),
+        ResolutionStrategy::RollPlus(s) => s.resolve(roll),
+    }
+}
+
+/// Describe how the given strategy reads a roll, e.g.
+/// `"3 dice vs TN 12: 2 rolled ≤12 → 2 successes, 1 needed"`.
+///
+/// The outcome itself is not included; see [`crate::rules::CheckResult::explain`].
+pub fn explain(strategy: &ResolutionStrategy, roll: &RollResult) -> String {
+    match strategy {
+        ResolutionStrategy::Count(s) => s.explain(roll),
+        ResolutionStrategy::Highest(s) => s.explain(roll),
+        ResolutionStrategy::Sum(s) => s.explain(roll),
+        ResolutionStrategy::RollUnder(s) => s.explain(roll),
+        ResolutionStrategy::RollPlus(s) => s.explain(roll),
+    }
+}
+
+/// `"1 die"` or `"N dice"`.
+pub(crate) fn dice_count(n: usize) -> String {
+    if n == 1 {
+        "1 die".to_string()
+    } else {
+        format!("{n} dice")
     }
 }
 
@@ -0,0 +1,146 @@
+//! Roll-plus-modifier resolution (Powered by the Apocalypse-style).
+//!
+//! Roll 2d6 and add a stat: 6 or less is a miss, 7-9 a partial hit,
+//! 10 or more a full hit. Some games treat 12 or more as a critical.
+
+use serde::{Deserialize, Serialize};
+
+use crate::dice::RollResult;
+use crate::resolution::Outcome;
+
+/// Configuration for roll-plus-modifier resolution.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RollPlus {
+    /// Added to the dice total (the character's stat plus any bonus).
+    pub modifier: i32,
+    /// Lowest total for a partial hit (default: 7).
+    pub partial_min: i32,
+    /// Lowest total for a full hit (default: 10).
+    pub success_min: i32,
+    /// Whether a total of 12 or more is a critical success.
+    pub twelve_plus_crit: bool,
+}
+
+impl Default for RollPlus {
+    fn default() -> Self {
+        Self {
+            modifier: 0,
+            partial_min: 7,
+            success_min: 10,
+            twelve_plus_crit: false,
+        }
+    }
+}
+
+/// Lowest total that counts as a critical when `twelve_plus_crit` is set.
+const CRIT_MIN: i32 = 12;
+
+impl RollPlus {
+    /// Resolve a roll by adding the modifier and comparing to the bands.
+    pub fn resolve(&self, roll: &RollResult) -> Outcome {
+        let total = roll.net_total() + self.modifier;
+        let margin = (total - self.success_min).max(0) as u32;
+        if self.twelve_plus_crit && total >= CRIT_MIN {
+            Outcome::CriticalSuccess { margin }
+        } else if total >= self.success_min {
+            Outcome::Success { margin }
+        } else if total >= self.partial_min {
+            Outcome::Partial
+        } else {
+            Outcome::Failure
+        }
+    }
+
+    /// Describe how a roll is read, without the final outcome.
+    pub fn explain(&self, roll: &RollResult) -> String {
+        let crit = if self.twelve_plus_crit {
+            format!(", {CRIT_MIN}+ for a critical")
+        } else {
+            String::new()
+        };
+        format!(
+            "{} total {} {:+} = {} ({}+ for a hit, {}+ for a partial{crit})",
+            super::dice_count(roll.dice.len()),
+            roll.net_total(),
+            self.modifier,
+            roll.net_total() + self.modifier,
+            self.success_min,
+            self.partial_min,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dice::{DiceTag, Die, DieResult};
+
+    fn make_d6_roll(values: &[u32]) -> RollResult {
+        RollResult {
+            dice: values
+                .iter()
+                .map(|&v| DieResult {
+                    die: Die::D6,
+                    tag: DiceTag::Default,
+                    value: v,
+                    exploded: false,
+                    rerolled: false,
+                    slot: 0,
+                })
+                .collect(),
+            dropped: Vec::new(),
+        }
+    }
+
+    fn with_modifier(modifier: i32) -> RollPlus {
+        RollPlus {
+            modifier,
+            ..RollPlus::default()
+        }
+    }
+
+    #[test]
+    fn bands_with_modifier() {
+        let strategy = with_modifier(1);
+        assert_eq!(strategy.resolve(&make_d6_roll(&[3, 2])), Outcome::Failure); // 6
+        assert_eq!(strategy.resolve(&make_d6_roll(&[3, 3])), Outcome::Partial); // 7
+        assert_eq!(strategy.resolve(&make_d6_roll(&[4, 4])), Outcome::Partial); // 9
+        assert_eq!(
+            strategy.resolve(&make_d6_roll(&[5, 4])),
+            Outcome::Success { margin: 0 }
+        ); // 10
+    }
+
+    #[test]
+    fn negative_modifier_drops_a_band() {
+        let strategy = with_modifier(-1);
+        assert_eq!(strategy.resolve(&make_d6_roll(&[4, 3])), Outcome::Failure); // 6
+        assert_eq!(strategy.resolve(&make_d6_roll(&[6, 4])), Outcome::Partial); // 9
+    }
+
+    #[test]
+    fn twelve_plus_is_critical_only_when_flagged() {
+        let roll = make_d6_roll(&[6, 5]); // 11 + 1 = 12
+        assert_eq!(
+            with_modifier(1).resolve(&roll),
+            Outcome::Success { margin: 2 }
+        );
+        let crit = RollPlus {
+            twelve_plus_crit: true,
+            ..with_modifier(1)
+        };
+        assert_eq!(crit.resolve(&roll), Outcome::CriticalSuccess { margin: 2 });
+    }
+
+    #[test]
+    fn explain_shows_total_and_bands() {
+        let crit = RollPlus {
+            twelve_plus_crit: true,
+            ..with_modifier(2)
+        };
+        assert_eq!(
+            crit.explain(&make_d6_roll(&[4, 2])),
+            "2 dice total 6 +2 = 8 (10+ for a hit, 7+ for a partial, 12+ for a critical)"
+        );
+    }
+}
@@ -3,7 +3,8 @@
 //! Roll a single die and check if the result is at or below a target number
 //! (usually an attribute value). Doubles (11, 22, 33, etc.) are special:
 //! doubles at or below the target are critical successes, doubles above
-//! the target are critical failures.
+//! the target are critical failures. Systems without doubles crits can turn
+//! this off with `doubles_critical`.
 
 use serde::{Deserialize, Serialize};
 
@@ -15,26 +16,36 @@ use crate::resolution::Outcome;
 pub struct RollUnder {
     /// The target number to roll at or under (set per check from attribute).
     pub target_number: u32,
+    /// Whether doubles turn a result critical (default: true).
+    #[serde(default = "default_doubles_critical")]
+    pub doubles_critical: bool,
 }
 
 impl Default for RollUnder {
     fn default() -> Self {
-        Self { target_number: 50 }
+        Self {
+            target_number: 50,
+            doubles_critical: true,
+        }
     }
 }
 
+fn default_doubles_critical() -> bool {
+    true
+}
+
 impl RollUnder {
     /// Resolve a roll by comparing the first die against the target number.
     ///
     /// Success if roll ≤ target. Doubles below = critical success,
-    /// doubles above = critical failure.
+    /// doubles above = critical failure, unless `doubles_critical` is off.
     pub fn resolve(&self, roll: &RollResult) -> Outcome {
         let value = roll.dice.first().map(|d| d.value).unwrap_or(0);
         if value == 0 {
             return Outcome::Failure;
         }
 
-        let doubles = is_doubles(value);
+        let doubles = self.doubles_critical && is_doubles(value);
 
         if value <= self.target_number {
             let margin = self.target_number - value;
@@ -49,6 +60,19 @@ impl RollUnder {
             Outcome::Failure
         }
     }
+
+    /// Describe how a roll is read, without the final outcome.
+    pub fn explain(&self, roll: &RollResult) -> String {
+        let value = roll.dice.first().map(|d| d.value).unwrap_or(0);
+        let mut text = format!(
+            "rolled {value} vs {}: at or under succeeds",
+            self.target_number
+        );
+        if value > 0 && self.doubles_critical && is_doubles(value) {
+            text.push_str("; doubles make it critical");
+        }
+        text
+    }
 }
 
 /// Check if a d100 roll shows doubles (11, 22, 33, ..., 99, or 100 as "00").
@@ -75,34 +99,50 @@ mod tests {
                 die: Die::D100,
                 tag: DiceTag::Default,
                 value,
+                exploded: false,
+                rerolled: false,
+                slot: 0,
             }],
+            dropped: Vec::new(),
         }
     }
 
     #[test]
     fn success_below_target() {
-        let strategy = RollUnder { target_number: 60 };
+        let strategy = RollUnder {
+            target_number: 60,
+            ..RollUnder::default()
+        };
         let roll = make_d100_roll(45);
         assert_eq!(strategy.resolve(&roll), Outcome::Success { margin: 15 });
     }
 
     #[test]
     fn success_at_target() {
-        let strategy = RollUnder { target_number: 60 };
+        let strategy = RollUnder {
+            target_number: 60,
+            ..RollUnder::default()
+        };
         let roll = make_d100_roll(60);
         assert_eq!(strategy.resolve(&roll), Outcome::Success { margin: 0 });
     }
 
     #[test]
     fn failure_above_target() {
-        let strategy = RollUnder { target_number: 60 };
+        let strategy = RollUnder {
+            target_number: 60,
+            ..RollUnder::default()
+        };
         let roll = make_d100_roll(75);
         assert_eq!(strategy.resolve(&roll), Outcome::Failure);
     }
 
     #[test]
     fn critical_success_doubles_under() {
-        let strategy = RollUnder { target_number: 60 };
+        let strategy = RollUnder {
+            target_number: 60,
+            ..RollUnder::default()
+        };
         let roll = make_d100_roll(33);
         assert_eq!(
             strategy.resolve(&roll),
@@ -112,14 +152,20 @@ mod tests {
 
     #[test]
     fn critical_failure_doubles_over() {
-        let strategy = RollUnder { target_number: 60 };
+        let strategy = RollUnder {
+            target_number: 60,
+            ..RollUnder::default()
+        };
         let roll = make_d100_roll(88);
         assert_eq!(strategy.resolve(&roll), Outcome::CriticalFailure);
     }
 
     #[test]
     fn critical_failure_99() {
-        let strategy = RollUnder { target_number: 99 };
+        let strategy = RollUnder {
+            target_number: 99,
+            ..RollUnder::default()
+        };
         let roll = make_d100_roll(99);
         // 99 is doubles AND ≤ 99, so it's a crit success
         assert_eq!(
@@ -130,12 +176,49 @@ mod tests {
 
     #[test]
     fn roll_of_100_as_doubles() {
-        let strategy = RollUnder { target_number: 50 };
+        let strategy = RollUnder {
+            target_number: 50,
+            ..RollUnder::default()
+        };
         let roll = make_d100_roll(100);
         // 100 (00 on percentile) is doubles and above 50 → crit failure
         assert_eq!(strategy.resolve(&roll), Outcome::CriticalFailure);
     }
 
+    #[test]
+    fn roll_of_100_succeeds_critically_at_target_100() {
+        let strategy = RollUnder {
+            target_number: 100,
+            ..RollUnder::default()
+        };
+        let roll = make_d100_roll(100);
+        assert_eq!(
+            strategy.resolve(&roll),
+            Outcome::CriticalSuccess { margin: 0 }
+        );
+    }
+
+    #[test]
+    fn doubles_critical_can_be_disabled() {
+        let strategy = RollUnder {
+            target_number: 60,
+            doubles_critical: false,
+        };
+        assert_eq!(
+            strategy.resolve(&make_d100_roll(33)),
+            Outcome::Success { margin: 27 }
+        );
+        assert_eq!(strategy.resolve(&make_d100_roll(88)), Outcome::Failure);
+        assert_eq!(strategy.resolve(&make_d100_roll(100)), Outcome::Failure);
+        assert!(!strategy.explain(&make_d100_roll(33)).contains("critical"));
+    }
+
+    #[test]
+    fn doubles_critical_defaults_on_when_missing() {
+        let strategy: RollUnder = serde_json::from_str(r#"{"target_number": 40}"#).unwrap();
+        assert!(strategy.doubles_critical);
+    }
+
     #[test]
     fn empty_roll() {
         let strategy = RollUnder::default();
@@ -3,6 +3,10 @@
 //! Roll a pool of dice and sum all values. If the total meets or exceeds
 //! the target number, the check succeeds. Wagered dice add to the pool
 //! but are risked — if the check fails, the wagered amount is lost.
+//!
+//! Fudge dice (FATE-style) are read against the adjective ladder instead:
+//! the net total is compared to the target rung, a tie is a partial, and
+//! beating it by three shifts or more succeeds with style.
 
 use serde::{Deserialize, Serialize};
 
@@ -27,9 +31,34 @@ impl Default for SumPool {
     }
 }
 
+/// Shifts over the target needed to succeed with style on the ladder.
+const STYLE_SHIFTS: i32 = 3;
+
+/// The FATE adjective for a ladder rung, e.g. `2` → `"Fair"`.
+///
+/// Rungs beyond the ends of the ladder take the nearest adjective.
+pub fn ladder_rung(value: i32) -> &'static str {
+    match value {
+        i32::MIN..=-2 => "Terrible",
+        -1 => "Poor",
+        0 => "Mediocre",
+        1 => "Average",
+        2 => "Fair",
+        3 => "Good",
+        4 => "Great",
+        5 => "Superb",
+        6 => "Fantastic",
+        7 => "Epic",
+        _ => "Legendary",
+    }
+}
+
 impl SumPool {
     /// Resolve a roll by summing all dice and comparing to the target.
     pub fn resolve(&self, roll: &RollResult) -> Outcome {
+        if roll.has_fudge() {
+            return self.resolve_ladder(roll);
+        }
         let total = roll.total();
 
         if total == 0 {
@@ -55,6 +84,38 @@ impl SumPool {
         }
     }
 
+    /// Resolve Fudge dice: compare the net total to the target rung.
+    fn resolve_ladder(&self, roll: &RollResult) -> Outcome {
+        let shifts = roll.net_total() - self.target_number as i32;
+        match shifts {
+            s if s >= STYLE_SHIFTS => Outcome::CriticalSuccess { margin: s as u32 },
+            s if s > 0 => Outcome::Success { margin: s as u32 },
+            0 => Outcome::Partial,
+            _ => Outcome::Failure,
+        }
+    }
+
+    /// Describe how a roll is read, without the final outcome.
+    pub fn explain(&self, roll: &RollResult) -> String {
+        if roll.has_fudge() {
+            let total = roll.net_total();
+            let target = self.target_number as i32;
+            return format!(
+                "{} total {total:+} ({}) vs {} ({target:+}) ({STYLE_SHIFTS} shifts for a critical, a tie for a partial)",
+                super::dice_count(roll.dice.len()),
+                ladder_rung(total),
+                ladder_rung(target),
+            );
+        }
+        format!(
+            "{} total {} vs TN {} ({} for a critical, within 2 for a partial)",
+            super::dice_count(roll.dice.len()),
+            roll.total(),
+            self.target_number,
+            self.target_number * 2
+        )
+    }
+
     /// Returns how many wagered dice are in this configuration.
     pub fn wager_count(&self) -> u32 {
         self.wager_bonus
@@ -74,11 +135,67 @@ mod tests {
                     die: Die::D6,
                     tag: DiceTag::Default,
                     value: v,
+                    exploded: false,
+                    rerolled: false,
+                    slot: 0,
                 })
                 .collect(),
+            dropped: Vec::new(),
         }
     }
 
+    fn make_fudge_roll(faces: &[u32]) -> RollResult {
+        RollResult {
+            dice: faces
+                .iter()
+                .map(|&v| DieResult {
+                    die: Die::Fudge,
+                    tag: DiceTag::Default,
+                    value: v,
+                    exploded: false,
+                    rerolled: false,
+                    slot: 0,
+                })
+                .collect(),
+            dropped: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn fudge_dice_read_against_ladder() {
+        let strategy = SumPool {
+            target_number: 2, // Fair
+            wager_bonus: 0,
+        };
+        // +1 +1 +1 +1 = +4: two shifts
+        let roll = make_fudge_roll(&[3, 3, 3, 3]);
+        assert_eq!(strategy.resolve(&roll), Outcome::Success { margin: 2 });
+        assert_eq!(
+            strategy.explain(&roll),
+            "4 dice total +4 (Great) vs Fair (+2) (3 shifts for a critical, a tie for a partial)"
+        );
+        // +1 +1 0 0 = +2: tie
+        assert_eq!(
+            strategy.resolve(&make_fudge_roll(&[3, 3, 2, 2])),
+            Outcome::Partial
+        );
+        // -1 -1 0 0 = -2: no zero-total critical failure on the ladder
+        assert_eq!(
+            strategy.resolve(&make_fudge_roll(&[1, 1, 2, 2])),
+            Outcome::Failure
+        );
+        let easy = SumPool {
+            target_number: 0,
+            wager_bonus: 0,
+        };
+        assert_eq!(
+            easy.resolve(&make_fudge_roll(&[3, 3, 3, 2])),
+            Outcome::CriticalSuccess { margin: 3 }
+        );
+        assert_eq!(ladder_rung(-4), "Terrible");
+        assert_eq!(ladder_rung(9), "Legendary");
+    }
+
     #[test]
     fn success_at_target() {
         let strategy = SumPool::default(); // TN 10
@@ -0,0 +1,353 @@
+//! Outcome-to-effect mappings.
+//!
+//! An [`EffectRule`] pairs a trigger (an outcome, or the dark die coming up
+//! highest) with the [`CheckEffect`] it produces. Worlds declare them as
+//! `mechanics.effects.<trigger>` lists of effect specs:
+//!
+//! ```text
+//! mechanics {
+//!     effects {
+//!         success ["momentum margin"]
+//!         critical_failure ["Ruin +1", "complication: The rope snaps"]
+//!     }
+//! }
+//! ```
+//!
+//! Triggers are `critical_success`, `success` (any success, critical or
+//! not), `partial`, `failure` (any failure), `critical_failure`, and
+//! `dark_die`. Specs are `momentum margin`, `momentum <n>`,
+//! `complication[: <text>]`, or `<Track> +<n>` / `<Track> -<n>`.
+
+use std::collections::{HashMap, HashSet};
+
+use ww_core::entity::MetadataValue;
+
+use crate::dice::{DiceTag, RollResult};
+use crate::error::{MechError, MechResult};
+use crate::resolution::Outcome;
+
+use super::CheckEffect;
+
+/// What makes an [`EffectRule`] fire.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EffectTrigger {
+    /// The check was a critical success.
+    CriticalSuccess,
+    /// The check succeeded, critically or not.
+    Success,
+    /// The check was a partial success.
+    Partial,
+    /// The check failed, critically or not.
+    Failure,
+    /// The check was a critical failure.
+    CriticalFailure,
+    /// The highest die rolled is a dark die.
+    DarkDie,
+}
+
+impl EffectTrigger {
+    /// Every trigger, in the order rules are applied.
+    pub const ALL: &[EffectTrigger] = &[
+        Self::CriticalSuccess,
+        Self::Success,
+        Self::Partial,
+        Self::Failure,
+        Self::CriticalFailure,
+        Self::DarkDie,
+    ];
+
+    /// The trigger's key under `mechanics.effects`.
+    pub fn key(&self) -> &'static str {
+        match self {
+            Self::CriticalSuccess => "critical_success",
+            Self::Success => "success",
+            Self::Partial => "partial",
+            Self::Failure => "failure",
+            Self::CriticalFailure => "critical_failure",
+            Self::DarkDie => "dark_die",
+        }
+    }
+
+    /// Returns true if this trigger fires for the given roll and outcome.
+    pub fn matches(&self, roll: &RollResult, outcome: &Outcome) -> bool {
+        match self {
+            Self::CriticalSuccess => matches!(outcome, Outcome::CriticalSuccess { .. }),
+            Self::Success => matches!(
+                outcome,
+                Outcome::Success { .. } | Outcome::CriticalSuccess { .. }
+            ),
+            Self::Partial => matches!(outcome, Outcome::Partial),
+            Self::Failure => matches!(outcome, Outcome::Failure | Outcome::CriticalFailure),
+            Self::CriticalFailure => matches!(outcome, Outcome::CriticalFailure),
+            Self::DarkDie => {
+                let highest = roll.highest();
+                roll.dice
+                    .iter()
+                    .any(|d| d.value == highest && d.tag == DiceTag::Dark)
+            }
+        }
+    }
+}
+
+/// The effect an [`EffectRule`] produces.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EffectSpec {
+    /// Generate momentum equal to the success margin (nothing at margin 0).
+    MomentumFromMargin,
+    /// Generate or spend a fixed amount of momentum.
+    Momentum(i32),
+    /// Adjust a resource track.
+    TrackAdjust {
+        /// Name of the track to adjust.
+        track: String,
+        /// Amount to change (positive or negative).
+        delta: i32,
+    },
+    /// Add a complication with the given description.
+    Complication(String),
+}
+
+impl EffectSpec {
+    /// Parse a spec such as `"momentum margin"`, `"Ruin +1"`, or
+    /// `"complication: The rope snaps"`.
+    pub fn parse(spec: &str) -> MechResult<Self> {
+        let spec = spec.trim();
+        let invalid = || MechError::InvalidConfig(format!("invalid effect: {spec}"));
+
+        if let Some(rest) = strip_keyword(spec, "complication") {
+            let text = rest.trim_start_matches(':').trim();
+            let text = if text.is_empty() {
+                "Complication"
+            } else {
+                text
+            };
+            return Ok(Self::Complication(text.to_string()));
+        }
+        if let Some(rest) = strip_keyword(spec, "momentum") {
+            let rest = rest.trim();
+            if rest.eq_ignore_ascii_case("margin") {
+                return Ok(Self::MomentumFromMargin);
+            }
+            return rest.parse().map(Self::Momentum).map_err(|_| invalid());
+        }
+
+        let (track, delta) = spec.rsplit_once(' ').ok_or_else(invalid)?;
+        if !delta.starts_with(['+', '-']) {
+            return Err(invalid());
+        }
+        let delta = delta.parse().map_err(|_| invalid())?;
+        Ok(Self::TrackAdjust {
+            track: track.trim().to_string(),
+            delta,
+        })
+    }
+
+    /// The concrete effect for a check with the given outcome, if any.
+    fn produce(&self, outcome: &Outcome) -> Option<CheckEffect> {
+        match self {
+            Self::MomentumFromMargin => match outcome {
+                Outcome::Success { margin } | Outcome::CriticalSuccess { margin }
+                    if *margin > 0 =>
+                {
+                    Some(CheckEffect::Momentum(*margin as i32))
+                }
+                _ => None,
+            },
+            Self::Momentum(n) => Some(CheckEffect::Momentum(*n)),
+            Self::TrackAdjust { track, delta } => Some(CheckEffect::TrackAdjust {
+                track: track.clone(),
+                delta: *delta,
+            }),
+            Self::Complication(text) => Some(CheckEffect::Complication(text.clone())),
+        }
+    }
+}
+
+/// Strip a leading keyword (case-insensitive) that ends the spec or is
+/// followed by a space or colon.
+fn strip_keyword<'a>(spec: &'a str, keyword: &str) -> Option<&'a str> {
+    let head = spec.get(..keyword.len())?;
+    let rest = &spec[keyword.len()..];
+    (head.eq_ignore_ascii_case(keyword) && (rest.is_empty() || rest.starts_with([' ', ':'])))
+        .then_some(rest)
+}
+
+/// One outcome-to-effect mapping in a ruleset.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EffectRule {
+    /// When the rule fires.
+    pub trigger: EffectTrigger,
+    /// What it produces.
+    pub effect: EffectSpec,
+}
+
+impl EffectRule {
+    /// Create a rule.
+    pub fn new(trigger: EffectTrigger, effect: EffectSpec) -> Self {
+        Self { trigger, effect }
+    }
+}
+
+/// The 2d20 momentum economy: margin becomes momentum, and critical
+/// failures add a complication.
+pub fn momentum_economy() -> Vec<EffectRule> {
+    vec![
+        EffectRule::new(EffectTrigger::Success, EffectSpec::MomentumFromMargin),
+        EffectRule::new(
+            EffectTrigger::CriticalFailure,
+            EffectSpec::Complication("Critical failure complication".to_string()),
+        ),
+    ]
+}
+
+/// Trophy Gold ruin: Ruin rises when the dark die comes up highest.
+pub fn dark_die_ruin() -> Vec<EffectRule> {
+    vec![EffectRule::new(
+        EffectTrigger::DarkDie,
+        EffectSpec::TrackAdjust {
+            track: "Ruin".to_string(),
+            delta: 1,
+        },
+    )]
+}
+
+/// The rules implied by the `momentum_economy` and `dark_die_ruin` flags,
+/// for worlds that enable them without declaring `mechanics.effects`.
+pub fn from_flags(flags: &HashSet<String>) -> Vec<EffectRule> {
+    let mut rules = Vec::new();
+    if flags.contains("momentum_economy") {
+        rules.extend(momentum_economy());
+    }
+    if flags.contains("dark_die_ruin") {
+        rules.extend(dark_die_ruin());
+    }
+    rules
+}
+
+/// Parse `mechanics.effects.<trigger>` properties.
+///
+/// Returns `None` when no such properties are set. Rules are ordered by
+/// trigger, then as listed.
+pub fn parse_effect_rules(
+    props: &HashMap<String, MetadataValue>,
+) -> MechResult<Option<Vec<EffectRule>>> {
+    let mut found = false;
+    for key in props.keys() {
+        if let Some(trigger) = key.strip_prefix("mechanics.effects.") {
+            found = true;
+            if !EffectTrigger::ALL.iter().any(|t| t.key() == trigger) {
+                return Err(MechError::InvalidConfig(format!(
+                    "unknown effect trigger: {trigger}"
+                )));
+            }
+        }
+    }
+    if !found {
+        return Ok(None);
+    }
+
+    let mut rules = Vec::new();
+    for trigger in EffectTrigger::ALL {
+        let specs = match props.get(&format!("mechanics.effects.{}", trigger.key())) {
+            None => continue,
+            Some(MetadataValue::String(s)) => vec![s.as_str()],
+            Some(MetadataValue::List(items)) => items
+                .iter()
+                .map(|item| match item {
+                    MetadataValue::String(s) => Ok(s.as_str()),
+                    other => Err(MechError::InvalidConfig(format!(
+                        "effect must be a string, got {other}"
+                    ))),
+                })
+                .collect::<MechResult<_>>()?,
+            Some(other) => {
+                return Err(MechError::InvalidConfig(format!(
+                    "effects for {} must be a list of strings, got {other}",
+                    trigger.key()
+                )));
+            }
+        };
+        for spec in specs {
+            rules.push(EffectRule::new(*trigger, EffectSpec::parse(spec)?));
+        }
+    }
+    Ok(Some(rules))
+}
+
+/// Apply effect rules to a resolved roll, in order.
+pub fn generate(rules: &[EffectRule], roll: &RollResult, outcome: &Outcome) -> Vec<CheckEffect> {
+    rules
+        .iter()
+        .filter(|rule| rule.trigger.matches(roll, outcome))
+        .filter_map(|rule| rule.effect.produce(outcome))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_specs() {
+        assert_eq!(
+            EffectSpec::parse("momentum margin").unwrap(),
+            EffectSpec::MomentumFromMargin
+        );
+        assert_eq!(
+            EffectSpec::parse("Momentum -1").unwrap(),
+            EffectSpec::Momentum(-1)
+        );
+        assert_eq!(
+            EffectSpec::parse("Ruin +1").unwrap(),
+            EffectSpec::TrackAdjust {
+                track: "Ruin".to_string(),
+                delta: 1
+            }
+        );
+        assert_eq!(
+            EffectSpec::parse("complication: The rope snaps").unwrap(),
+            EffectSpec::Complication("The rope snaps".to_string())
+        );
+        assert_eq!(
+            EffectSpec::parse("complication").unwrap(),
+            EffectSpec::Complication("Complication".to_string())
+        );
+        assert!(EffectSpec::parse("Ruin 1").is_err());
+        assert!(EffectSpec::parse("momentum lots").is_err());
+    }
+
+    #[test]
+    fn parse_rules_from_properties() {
+        let mut props = HashMap::new();
+        assert_eq!(parse_effect_rules(&props).unwrap(), None);
+
+        props.insert(
+            "mechanics.effects.partial".to_string(),
+            MetadataValue::String("complication".to_string()),
+        );
+        props.insert(
+            "mechanics.effects.critical_failure".to_string(),
+            MetadataValue::List(vec![
+                MetadataValue::String("Ruin +1".to_string()),
+                MetadataValue::String("Stress +2".to_string()),
+            ]),
+        );
+        let rules = parse_effect_rules(&props).unwrap().unwrap();
+        assert_eq!(rules.len(), 3);
+        assert_eq!(rules[0].trigger, EffectTrigger::Partial);
+        assert_eq!(rules[2].trigger, EffectTrigger::CriticalFailure);
+
+        props.insert(
+            "mechanics.effects.fumble".to_string(),
+            MetadataValue::String("Ruin +1".to_string()),
+        );
+        assert!(parse_effect_rules(&props).is_err());
+    }
+
+    #[test]
+    fn flags_map_to_reference_rules() {
+        let flags = HashSet::from(["momentum_economy".to_string()]);
+        assert_eq!(from_flags(&flags), momentum_economy());
+        assert!(from_flags(&HashSet::new()).is_empty());
+    }
+}
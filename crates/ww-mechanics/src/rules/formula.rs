@@ -0,0 +1,323 @@
+//! Arithmetic formulas for derived stats.
+//!
+//! A [`Formula`] is a small integer expression such as `10 + Agility` or
+//! `(Brawn + Will) / 2`. It supports `+`, `-`, `*`, `/`, unary minus,
+//! parentheses, integer literals, and stat names. There are no function calls
+//! or other side effects: evaluation only ever looks up stat values.
+
+use std::fmt;
+
+use crate::error::{MechError, MechResult};
+
+/// A parsed derived-stat formula.
+#[derive(Debug, Clone)]
+pub struct Formula {
+    source: String,
+    expr: Expr,
+}
+
+#[derive(Debug, Clone)]
+enum Expr {
+    Number(i32),
+    Stat(String),
+    Neg(Box<Expr>),
+    Binary(Op, Box<Expr>, Box<Expr>),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Op {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(i32),
+    Ident(String),
+    Op(char),
+    LParen,
+    RParen,
+}
+
+impl Formula {
+    /// Parse a formula from source text.
+    pub fn parse(source: &str) -> MechResult<Self> {
+        let tokens = tokenize(source)?;
+        let mut parser = Parser {
+            tokens: &tokens,
+            pos: 0,
+            source,
+            depth: 0,
+        };
+        let expr = parser.expr()?;
+        if let Some(tok) = parser.peek() {
+            return Err(parser.error(&format!("unexpected {tok:?}")));
+        }
+        Ok(Self {
+            source: source.trim().to_string(),
+            expr,
+        })
+    }
+
+    /// The original source text.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Names of all stats referenced by the formula, in order of appearance.
+    pub fn stats(&self) -> Vec<&str> {
+        let mut out = Vec::new();
+        collect_stats(&self.expr, &mut out);
+        out
+    }
+
+    /// Evaluate the formula, resolving stat names through `lookup`.
+    ///
+    /// Unknown stats yield [`MechError::UnknownAttribute`]; division by zero
+    /// and overflow yield [`MechError::InvalidFormula`]. Division truncates
+    /// toward zero.
+    pub fn eval(&self, lookup: &dyn Fn(&str) -> Option<i32>) -> MechResult<i32> {
+        eval(&self.expr, lookup, &self.source)
+    }
+}
+
+impl fmt::Display for Formula {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+fn collect_stats<'a>(expr: &'a Expr, out: &mut Vec<&'a str>) {
+    match expr {
+        Expr::Number(_) => {}
+        Expr::Stat(name) => out.push(name),
+        Expr::Neg(inner) => collect_stats(inner, out),
+        Expr::Binary(_, lhs, rhs) => {
+            collect_stats(lhs, out);
+            collect_stats(rhs, out);
+        }
+    }
+}
+
+fn eval(expr: &Expr, lookup: &dyn Fn(&str) -> Option<i32>, source: &str) -> MechResult<i32> {
+    let overflow = || MechError::InvalidFormula(format!("'{source}' overflowed"));
+    match expr {
+        Expr::Number(n) => Ok(*n),
+        Expr::Stat(name) => lookup(name).ok_or_else(|| MechError::UnknownAttribute(name.clone())),
+        Expr::Neg(inner) => eval(inner, lookup, source)?
+            .checked_neg()
+            .ok_or_else(overflow),
+        Expr::Binary(op, lhs, rhs) => {
+            let a = eval(lhs, lookup, source)?;
+            let b = eval(rhs, lookup, source)?;
+            match op {
+                Op::Add => a.checked_add(b).ok_or_else(overflow),
+                Op::Sub => a.checked_sub(b).ok_or_else(overflow),
+                Op::Mul => a.checked_mul(b).ok_or_else(overflow),
+                Op::Div if b == 0 => Err(MechError::InvalidFormula(format!(
+                    "'{source}' divides by zero"
+                ))),
+                Op::Div => a.checked_div(b).ok_or_else(overflow),
+            }
+        }
+    }
+}
+
+fn tokenize(source: &str) -> MechResult<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut chars = source.char_indices().peekable();
+
+    while let Some(&(start, c)) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+        } else if c.is_ascii_digit() {
+            let mut end = start;
+            while let Some(&(i, d)) = chars.peek()
+                && d.is_ascii_digit()
+            {
+                end = i + d.len_utf8();
+                chars.next();
+            }
+            let n = source[start..end].parse().map_err(|_| {
+                MechError::InvalidFormula(format!("number too large in '{source}'"))
+            })?;
+            tokens.push(Token::Number(n));
+        } else if c.is_alphabetic() || c == '_' {
+            let mut end = start;
+            while let Some(&(i, d)) = chars.peek()
+                && (d.is_alphanumeric() || d == '_')
+            {
+                end = i + d.len_utf8();
+                chars.next();
+            }
+            tokens.push(Token::Ident(source[start..end].to_string()));
+        } else {
+            chars.next();
+            tokens.push(match c {
+                '+' | '-' | '*' | '/' => Token::Op(c),
+                '(' => Token::LParen,
+                ')' => Token::RParen,
+                other => {
+                    return Err(MechError::InvalidFormula(format!(
+                        "unexpected character '{other}' in '{source}'"
+                    )));
+                }
+            });
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// How deeply parentheses and unary minus may nest, so hostile formulas
+/// fail to parse instead of overflowing the stack.
+const MAX_DEPTH: usize = 64;
+
+/// Recursive-descent parser: `expr := term (('+'|'-') term)*`,
+/// `term := unary (('*'|'/') unary)*`, `unary := '-' unary | atom`.
+struct Parser<'a> {
+    tokens: &'a [Token],
+    pos: usize,
+    source: &'a str,
+    /// Current nesting of `unary`, which every recursion passes through.
+    depth: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let tok = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        tok
+    }
+
+    fn error(&self, message: &str) -> MechError {
+        MechError::InvalidFormula(format!("{message} in '{}'", self.source))
+    }
+
+    fn expr(&mut self) -> MechResult<Expr> {
+        let mut lhs = self.term()?;
+        while let Some(Token::Op(c @ ('+' | '-'))) = self.peek() {
+            let op = if *c == '+' { Op::Add } else { Op::Sub };
+            self.pos += 1;
+            lhs = Expr::Binary(op, Box::new(lhs), Box::new(self.term()?));
+        }
+        Ok(lhs)
+    }
+
+    fn term(&mut self) -> MechResult<Expr> {
+        let mut lhs = self.unary()?;
+        while let Some(Token::Op(c @ ('*' | '/'))) = self.peek() {
+            let op = if *c == '*' { Op::Mul } else { Op::Div };
+            self.pos += 1;
+            lhs = Expr::Binary(op, Box::new(lhs), Box::new(self.unary()?));
+        }
+        Ok(lhs)
+    }
+
+    fn unary(&mut self) -> MechResult<Expr> {
+        if self.depth == MAX_DEPTH {
+            return Err(self.error(&format!("nested more than {MAX_DEPTH} levels deep")));
+        }
+        self.depth += 1;
+        let expr = if self.peek() == Some(&Token::Op('-')) {
+            self.pos += 1;
+            self.unary().map(|inner| Expr::Neg(Box::new(inner)))
+        } else {
+            self.atom()
+        };
+        self.depth -= 1;
+        expr
+    }
+
+    fn atom(&mut self) -> MechResult<Expr> {
+        match self.next() {
+            Some(Token::Number(n)) => Ok(Expr::Number(n)),
+            Some(Token::Ident(name)) => Ok(Expr::Stat(name)),
+            Some(Token::LParen) => {
+                let inner = self.expr()?;
+                match self.next() {
+                    Some(Token::RParen) => Ok(inner),
+                    _ => Err(self.error("missing ')'")),
+                }
+            }
+            Some(tok) => Err(self.error(&format!("unexpected {tok:?}"))),
+            None => Err(self.error("unexpected end of formula")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(name: &str) -> Option<i32> {
+        match name {
+            "Agility" => Some(3),
+            "Brawn" => Some(4),
+            _ => None,
+        }
+    }
+
+    fn eval_str(source: &str) -> MechResult<i32> {
+        Formula::parse(source)?.eval(&stats)
+    }
+
+    #[test]
+    fn precedence_and_parentheses() {
+        assert_eq!(eval_str("10 + Agility").unwrap(), 13);
+        assert_eq!(eval_str("2 + Brawn * 3").unwrap(), 14);
+        assert_eq!(eval_str("(2 + Brawn) * 3").unwrap(), 18);
+        assert_eq!(eval_str("(Agility + Brawn) / 2").unwrap(), 3);
+        assert_eq!(eval_str("-Agility + 1").unwrap(), -2);
+        assert_eq!(eval_str("10 - 4 - 3").unwrap(), 3);
+    }
+
+    #[test]
+    fn stats_lists_references() {
+        let f = Formula::parse("Brawn + 5 * Agility").unwrap();
+        assert_eq!(f.stats(), vec!["Brawn", "Agility"]);
+        assert_eq!(f.to_string(), "Brawn + 5 * Agility");
+    }
+
+    #[test]
+    fn errors() {
+        assert!(matches!(
+            eval_str("Luck + 1"),
+            Err(MechError::UnknownAttribute(name)) if name == "Luck"
+        ));
+        assert!(matches!(
+            eval_str("Brawn / 0"),
+            Err(MechError::InvalidFormula(_))
+        ));
+        for bad in ["", "1 +", "(1 + 2", "1 2", "Brawn; 1", "2147483648"] {
+            assert!(Formula::parse(bad).is_err(), "{bad:?} should not parse");
+        }
+    }
+
+    #[test]
+    fn deep_nesting_is_an_error() {
+        let nested = |depth: usize| format!("{}1{}", "(".repeat(depth), ")".repeat(depth));
+        assert_eq!(eval_str(&nested(MAX_DEPTH - 1)).unwrap(), 1);
+        assert_eq!(
+            eval_str(&format!("{}1", "-".repeat(MAX_DEPTH - 1))).unwrap(),
+            -1
+        );
+
+        for deep in [
+            nested(MAX_DEPTH),
+            nested(100_000),
+            "-".repeat(100_000) + "1",
+        ] {
+            assert!(matches!(
+                Formula::parse(&deep),
+                Err(MechError::InvalidFormula(msg)) if msg.starts_with("nested more than 64 levels deep")
+            ));
+        }
+    }
+}
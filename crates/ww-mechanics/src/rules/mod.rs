@@ -4,8 +4,13 @@
 //! It can be loaded from a world's `mechanics.*` properties via [`RuleSet::from_world`],
 //! or constructed programmatically using the preset functions in [`preset`].
 
+pub mod effects;
+pub mod formula;
 pub mod preset;
 
+pub use effects::{EffectRule, EffectSpec, EffectTrigger};
+pub use formula::Formula;
+
 use std::collections::HashSet;
 
 use rand::rngs::StdRng;
@@ -13,10 +18,11 @@ use serde::{Deserialize, Serialize};
 use ww_core::entity::MetadataValue;
 use ww_core::world::World;
 
-use crate::dice::{DicePool, DiceTag, Die, RollResult};
+use crate::combat::{CombatAction, CombatPhase};
+use crate::dice::{DicePool, Die, RollResult};
 use crate::error::{MechError, MechResult};
 use crate::resolution::{
-    self, CountSuccesses, HighestDie, Outcome, ResolutionStrategy, RollUnder, SumPool,
+    self, CountSuccesses, HighestDie, Outcome, ResolutionStrategy, RollPlus, RollUnder, SumPool,
 };
 
 /// Definition of a resource track in a ruleset.
@@ -30,6 +36,15 @@ pub struct TrackDefinition {
     pub min: i32,
 }
 
+/// A derived stat computed from other stats (e.g., Defense = 10 + Agility).
+#[derive(Debug, Clone)]
+pub struct DerivedStat {
+    /// Stat name (e.g., "Defense").
+    pub name: String,
+    /// Formula computing the stat from attributes and skills.
+    pub formula: Formula,
+}
+
 /// A complete game system configuration.
 #[derive(Debug, Clone)]
 pub struct RuleSet {
@@ -39,6 +54,10 @@ pub struct RuleSet {
     pub check_die: Die,
     /// Default number of dice in the pool.
     pub default_pool_size: u32,
+    /// Largest pool a check may roll; bigger pools are capped. `None` = no cap.
+    pub max_pool_size: Option<u32>,
+    /// What a check does when modifiers leave fewer than one die.
+    pub short_pool: ShortPool,
     /// How dice rolls are resolved.
     pub resolution: ResolutionStrategy,
     /// Named attributes in this system (e.g., Agility, Brawn).
@@ -49,6 +68,14 @@ pub struct RuleSet {
     pub track_definitions: Vec<TrackDefinition>,
     /// System flags (e.g., "momentum_economy", "wager_system").
     pub flags: HashSet<String>,
+    /// Derived stats, from `mechanics.derived.<name> "<formula>"` properties.
+    pub derived: Vec<DerivedStat>,
+    /// Outcome-to-effect mappings applied to every check, from
+    /// `mechanics.effects.<trigger>` properties.
+    pub effects: Vec<EffectRule>,
+    /// Phases of a combat round, from `mechanics.phases` with optional
+    /// `mechanics.phase.<name>` action lists. Empty = undivided rounds.
+    pub combat_phases: Vec<CombatPhase>,
 }
 
 impl RuleSet {
@@ -75,6 +102,15 @@ impl RuleSet {
         })?;
 
         let default_pool_size = extract_u32(props, "mechanics.pool_size").unwrap_or(2);
+        let max_pool_size = extract_u32(props, "mechanics.max_pool_size");
+        let short_pool = match extract_string(props, "mechanics.short_pool") {
+            Some(s) => ShortPool::parse(&s).ok_or_else(|| {
+                MechError::InvalidConfig(format!(
+                    "invalid short_pool: {s} (expected clamp, disadvantage, or complication)"
+                ))
+            })?,
+            None => ShortPool::default(),
+        };
 
         let resolution = build_resolution(props, &name)?;
 
@@ -84,16 +120,27 @@ impl RuleSet {
         let flags = extract_string_list(props, "mechanics.flags")
             .into_iter()
             .collect();
+        let derived = parse_derived_stats(props)?;
+        let effects = match effects::parse_effect_rules(props)? {
+            Some(rules) => rules,
+            None => effects::from_flags(&flags),
+        };
+        let combat_phases = parse_combat_phases(props)?;
 
         Ok(Self {
             name,
             check_die,
             default_pool_size,
+            max_pool_size,
+            short_pool,
             resolution,
             attributes,
             skills,
             track_definitions,
             flags,
+            derived,
+            effects,
+            combat_phases,
         })
     }
 
@@ -101,6 +148,79 @@ impl RuleSet {
     pub fn has_flag(&self, flag: &str) -> bool {
         self.flags.contains(flag)
     }
+
+    /// Look up a derived stat definition by name (case-insensitive).
+    pub fn derived_stat(&self, name: &str) -> Option<&DerivedStat> {
+        let lower = name.to_lowercase();
+        self.derived.iter().find(|d| d.name.to_lowercase() == lower)
+    }
+}
+
+/// How a check handles modifiers that would leave fewer than one die.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ShortPool {
+    /// Roll a single die anyway.
+    #[default]
+    Clamp,
+    /// Roll two dice and keep the worse one.
+    Disadvantage,
+    /// Roll a single die and add an automatic complication.
+    Complication,
+}
+
+impl ShortPool {
+    /// Parse a `mechanics.short_pool` value (case-insensitive).
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "clamp" => Some(Self::Clamp),
+            "disadvantage" => Some(Self::Disadvantage),
+            "complication" => Some(Self::Complication),
+            _ => None,
+        }
+    }
+}
+
+/// A change the ruleset's pool limits made to a check's dice pool.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PoolLimit {
+    /// The pool exceeded [`RuleSet::max_pool_size`] and was capped.
+    Capped {
+        /// Pool size after modifiers, before capping.
+        requested: i32,
+        /// The cap that was applied.
+        max: u32,
+    },
+    /// Modifiers left fewer than one die; handled per [`RuleSet::short_pool`].
+    Short {
+        /// Pool size after modifiers (zero or negative).
+        requested: i32,
+        /// The rule that was applied.
+        rule: ShortPool,
+        /// The better die discarded under [`ShortPool::Disadvantage`].
+        dropped: Option<u32>,
+    },
+}
+
+impl std::fmt::Display for PoolLimit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Capped { requested, max } => {
+                write!(f, "Pool capped at {max} (from {requested})")
+            }
+            Self::Short {
+                requested, rule, ..
+            } => match rule {
+                ShortPool::Clamp => write!(f, "Pool of {requested} rolled as 1 die"),
+                ShortPool::Disadvantage => {
+                    write!(f, "Pool of {requested}: rolled with disadvantage")
+                }
+                ShortPool::Complication => {
+                    write!(f, "Pool of {requested}: automatic complication")
+                }
+            },
+        }
+    }
 }
 
 /// A request to perform a mechanical check.
@@ -116,6 +236,31 @@ pub struct CheckRequest {
     pub extra_dice: u32,
     /// Override the default difficulty/target number.
     pub difficulty: Option<u32>,
+    /// Focus that applies to this check (2d20: widens the critical range
+    /// to the skill rating when the character is trained and has the focus).
+    pub focus: Option<String>,
+    /// Per-die target number overrides, indexed by position in the pool.
+    pub die_targets: Vec<u32>,
+    /// Oppose the check with another entity's stat, as `(entity name, stat)`.
+    ///
+    /// Resolved by [`perform_check_in_world`], which weighs the stat's value
+    /// the way the ruleset reads checks:
+    /// - count and roll-under: the target moves away from the stat by as much
+    ///   as the stat differs from it, so an even match leaves it unchanged
+    /// - sum: the stat adds to the ruleset's target number
+    /// - highest die: each point of the stat removes a die from the pool
+    /// - roll-plus: the stat subtracts from the total
+    ///
+    /// An explicit `difficulty` takes precedence.
+    pub difficulty_from: Option<(String, String)>,
+    /// Advantage (positive) or disadvantage (negative): roll this many
+    /// extra dice, then drop that many of the worst (or best) results.
+    pub advantage: i32,
+    /// Die values rolled by hand, used instead of the RNG.
+    ///
+    /// Must hold one value per die the check rolls, each within the check
+    /// die's faces.
+    pub manual_values: Vec<u32>,
 }
 
 /// The result of performing a check.
@@ -127,6 +272,29 @@ pub struct CheckResult {
     pub outcome: Outcome,
     /// Side effects triggered by the check.
     pub effects: Vec<CheckEffect>,
+    /// Set when the pool was capped or fell below one die.
+    pub pool_limit: Option<PoolLimit>,
+    /// How the roll was read, followed by notes on what changed the check.
+    ///
+    /// Built during resolution; see [`CheckResult::explain`].
+    pub explanation: Vec<String>,
+}
+
+impl CheckResult {
+    /// A human-readable breakdown of the check, e.g.
+    /// `"3 dice vs TN 12: 2 rolled at or under → 2 successes, 1 needed → Success (margin 1)"`.
+    pub fn explain(&self) -> String {
+        let mut parts = self.explanation.iter();
+        let mut text = match parts.next() {
+            Some(tally) => format!("{tally} → {}", self.outcome),
+            None => self.outcome.to_string(),
+        };
+        for note in parts {
+            text.push_str("; ");
+            text.push_str(note);
+        }
+        text
+    }
 }
 
 /// A side effect produced by a check resolution.
@@ -180,33 +348,304 @@ pub fn perform_check(
     request: &CheckRequest,
     rng: &mut StdRng,
 ) -> MechResult<CheckResult> {
-    // Build the dice pool
-    let pool_size =
-        (ruleset.default_pool_size as i32 + request.modifier + request.extra_dice as i32).max(1)
-            as u32;
-
-    let pool = DicePool::new().add(ruleset.check_die, pool_size);
-    let roll = pool.roll(rng);
+    if let Some((entity, stat)) = &request.difficulty_from
+        && request.difficulty.is_none()
+    {
+        return Err(MechError::InvalidConfig(format!(
+            "opposed difficulty {entity}.{stat} needs a world; use perform_check_in_world"
+        )));
+    }
+    roll_check(ruleset, sheet, request, None, rng)
+}
 
+/// Perform a check, opposed by an entity stat of value `opposed` when given.
+fn roll_check(
+    ruleset: &RuleSet,
+    sheet: &crate::sheet::CharacterSheet,
+    request: &CheckRequest,
+    opposed: Option<u32>,
+    rng: &mut StdRng,
+) -> MechResult<CheckResult> {
     // Adjust resolution strategy based on request
-    let strategy = apply_check_modifiers(ruleset, sheet, request)?;
+    let strategy = apply_check_modifiers(ruleset, sheet, request, opposed)?;
+
+    // Build the dice pool, applying the ruleset's limits
+    let requested = ruleset.default_pool_size as i32
+        + pool_modifier(&strategy, request, opposed)
+        + request.extra_dice as i32;
+    let dice = CheckDice {
+        die: ruleset.check_die,
+        advantage: request.advantage,
+        low_is_good: low_is_good(&strategy),
+        manual: &request.manual_values,
+    };
+    let (roll, pool_limit) = match ruleset.max_pool_size {
+        _ if requested < 1 => roll_short_pool(ruleset.short_pool, &dice, requested, rng)?,
+        Some(max) if requested > max as i32 => (
+            dice.roll(max, rng)?,
+            Some(PoolLimit::Capped { requested, max }),
+        ),
+        _ => (dice.roll(requested as u32, rng)?, None),
+    };
+
     let outcome = resolution::resolve(&strategy, &roll);
 
+    let mut explanation = vec![resolution::explain(&strategy, &roll)];
+    explanation.extend(check_notes(ruleset, request, &strategy, opposed, requested));
+    if request.advantage != 0 {
+        let dropped: Vec<String> = roll
+            .dropped
+            .iter()
+            .take(request.advantage.unsigned_abs() as usize)
+            .map(|d| d.value.to_string())
+            .collect();
+        let label = if request.advantage > 0 {
+            "advantage"
+        } else {
+            "disadvantage"
+        };
+        explanation.push(format!("{label} dropped [{}]", dropped.join(", ")));
+    }
+    if let Some(limit) = &pool_limit {
+        explanation.push(limit.to_string());
+    }
+
     // Generate effects based on system
-    let effects = generate_effects(ruleset, &roll, &outcome);
+    let mut effects = generate_effects(ruleset, &roll, &outcome);
+    if let Some(PoolLimit::Short {
+        rule: ShortPool::Complication,
+        ..
+    }) = pool_limit
+    {
+        effects.push(CheckEffect::Complication(
+            "Dice pool reduced below one die".to_string(),
+        ));
+    }
 
     Ok(CheckResult {
         roll,
         outcome,
         effects,
+        pool_limit,
+        explanation,
     })
 }
 
+/// Notes on how the request changed the ruleset's defaults, for [`CheckResult::explain`].
+fn check_notes(
+    ruleset: &RuleSet,
+    request: &CheckRequest,
+    strategy: &ResolutionStrategy,
+    opposed: Option<u32>,
+    requested: i32,
+) -> Vec<String> {
+    let mut notes = Vec::new();
+    if pool_modifier(strategy, request, opposed) != 0 || request.extra_dice > 0 {
+        notes.push(format!(
+            "pool of {} adjusted to {requested}",
+            ruleset.default_pool_size
+        ));
+    }
+    match (strategy, &ruleset.resolution) {
+        (ResolutionStrategy::Count(count), ResolutionStrategy::Count(base)) => {
+            if let Some(attr) = &request.attribute {
+                notes.push(format!("{attr} sets the TN"));
+            }
+            if count.critical_threshold > base.critical_threshold {
+                let focus = request.focus.as_deref().unwrap_or("focus");
+                notes.push(format!(
+                    "{focus} widens criticals to {} or under",
+                    count.critical_threshold
+                ));
+            }
+        }
+        (ResolutionStrategy::RollUnder(_), _) if request.difficulty.is_none() => {
+            if let Some(attr) = &request.attribute {
+                notes.push(format!("{attr} sets the target"));
+            }
+        }
+        (ResolutionStrategy::RollPlus(_), _) => {
+            if let Some(attr) = &request.attribute {
+                notes.push(format!("{attr} adds to the total"));
+            }
+            if request.modifier != 0 {
+                notes.push(format!("modifier {:+} adds to the total", request.modifier));
+            }
+        }
+        _ => {}
+    }
+    if let (Some((entity, stat)), Some(value)) = (&request.difficulty_from, opposed) {
+        notes.push(format!("opposed by {entity}.{stat} ({value})"));
+    }
+    notes
+}
+
+/// Roll `pool`, or take the player's hand-rolled `manual` values when given.
+fn roll_pool(pool: DicePool, manual: &[u32], rng: &mut StdRng) -> MechResult<RollResult> {
+    if manual.is_empty() {
+        Ok(pool.roll(rng))
+    } else {
+        pool.manual_roll(manual)
+    }
+}
+
+/// How much the request's modifier grows or shrinks the pool.
+///
+/// Roll-plus systems add the modifier to the total instead, so their pool
+/// stays at the ruleset's default. Highest-die systems lose a die per point
+/// of an `opposed` stat.
+fn pool_modifier(
+    strategy: &ResolutionStrategy,
+    request: &CheckRequest,
+    opposed: Option<u32>,
+) -> i32 {
+    match strategy {
+        ResolutionStrategy::RollPlus(_) => 0,
+        ResolutionStrategy::Highest(_) => request.modifier - opposed.unwrap_or(0) as i32,
+        _ => request.modifier,
+    }
+}
+
+/// Whether the strategy succeeds on low rolls, so the "better" die is the lower one.
+fn low_is_good(strategy: &ResolutionStrategy) -> bool {
+    matches!(
+        strategy,
+        ResolutionStrategy::Count(_) | ResolutionStrategy::RollUnder(_)
+    )
+}
+
+/// How a check rolls its dice: which die, advantage, and any hand-rolled values.
+struct CheckDice<'a> {
+    die: Die,
+    advantage: i32,
+    low_is_good: bool,
+    manual: &'a [u32],
+}
+
+impl CheckDice<'_> {
+    /// Roll `count` dice plus one per point of advantage or disadvantage,
+    /// then set aside the extras: the worst under advantage, the best under
+    /// disadvantage. The dice left over fill the pool's slots in order.
+    fn roll(&self, count: u32, rng: &mut StdRng) -> MechResult<RollResult> {
+        let extra = self.advantage.unsigned_abs();
+        let pool = DicePool::new().add(self.die, count + extra);
+        let mut roll = roll_pool(pool, self.manual, rng)?;
+        for _ in 0..extra {
+            // Under advantage drop the worst die, under disadvantage the best
+            let drop_high = (self.advantage > 0) == self.low_is_good;
+            let values = roll.dice.iter().enumerate();
+            let pos = if drop_high {
+                values.max_by_key(|(_, d)| d.value)
+            } else {
+                values.min_by_key(|(_, d)| d.value)
+            }
+            .map(|(i, _)| i)
+            .expect("extra dice were rolled");
+            let die = roll.dice.remove(pos);
+            roll.dropped.push(die);
+        }
+        if extra > 0 {
+            fill_slots(&mut roll);
+        }
+        Ok(roll)
+    }
+}
+
+/// Number the kept dice's slots in order, after extra dice were set aside.
+fn fill_slots(roll: &mut RollResult) {
+    for (slot, die) in roll.dice.iter_mut().enumerate() {
+        die.slot = slot;
+    }
+}
+
+/// Roll a pool that modifiers shrank below one die, per the ruleset's [`ShortPool`] rule.
+fn roll_short_pool(
+    rule: ShortPool,
+    dice: &CheckDice,
+    requested: i32,
+    rng: &mut StdRng,
+) -> MechResult<(RollResult, Option<PoolLimit>)> {
+    if rule != ShortPool::Disadvantage {
+        let roll = dice.roll(1, rng)?;
+        let limit = PoolLimit::Short {
+            requested,
+            rule,
+            dropped: None,
+        };
+        return Ok((roll, Some(limit)));
+    }
+
+    // Roll two and keep the worse: the higher die where low rolls succeed.
+    let mut roll = dice.roll(2, rng)?;
+    roll.dice.sort_by_key(|d| d.value);
+    let better = if dice.low_is_good {
+        roll.dice.remove(0)
+    } else {
+        roll.dice.pop().expect("two dice were rolled")
+    };
+    let limit = PoolLimit::Short {
+        requested,
+        rule,
+        dropped: Some(better.value),
+    };
+    roll.dropped.push(better);
+    fill_slots(&mut roll);
+    Ok((roll, Some(limit)))
+}
+
+/// Perform a check, first resolving an opposed `difficulty_from` against the world.
+pub fn perform_check_in_world(
+    ruleset: &RuleSet,
+    sheet: &crate::sheet::CharacterSheet,
+    request: &CheckRequest,
+    world: &World,
+    rng: &mut StdRng,
+) -> MechResult<CheckResult> {
+    match &request.difficulty_from {
+        Some((entity, stat)) if request.difficulty.is_none() => {
+            let opposed = opposed_difficulty(ruleset, world, entity, stat)?;
+            roll_check(ruleset, sheet, request, Some(opposed), rng)
+        }
+        _ => perform_check(ruleset, sheet, request, rng),
+    }
+}
+
+/// Look up an attribute or skill value on another entity's character sheet.
+///
+/// Names are matched case-insensitively; attributes are checked before skills.
+pub fn opposed_difficulty(
+    ruleset: &RuleSet,
+    world: &World,
+    entity_name: &str,
+    stat: &str,
+) -> MechResult<u32> {
+    let entity = world
+        .find_by_name(entity_name)
+        .ok_or_else(|| MechError::EntityNotFound(entity_name.to_string()))?;
+    let sheet = crate::sheet::CharacterSheet::from_entity(entity, ruleset)?;
+
+    // A negative attribute makes for the easiest possible opposition
+    let stat_lower = stat.to_lowercase();
+    let attributes = sheet
+        .attributes
+        .iter()
+        .map(|(name, value)| (name, (*value).max(0) as u32));
+    attributes
+        .chain(sheet.skills.iter().map(|(name, value)| (name, *value)))
+        .find(|(name, _)| name.to_lowercase() == stat_lower)
+        .map(|(_, value)| value)
+        .ok_or_else(|| MechError::UnknownAttribute(format!("{}.{stat}", entity.name)))
+}
+
 /// Adjust the resolution strategy based on the character's stats and request.
+///
+/// `opposed` is the value of the stat the check is opposed by; see
+/// [`CheckRequest::difficulty_from`].
 fn apply_check_modifiers(
     ruleset: &RuleSet,
     sheet: &crate::sheet::CharacterSheet,
     request: &CheckRequest,
+    opposed: Option<u32>,
 ) -> MechResult<ResolutionStrategy> {
     let mut strategy = ruleset.resolution.clone();
 
@@ -214,22 +653,35 @@ fn apply_check_modifiers(
         ResolutionStrategy::Count(count) => {
             // In 2d20: attribute sets the TN, skill gives bonus successes
             if let Some(ref attr) = request.attribute {
-                count.target_number = sheet.attribute(attr)?;
+                count.target_number = sheet.attribute(attr)?.max(0) as u32;
             }
             if let Some(ref skill) = request.skill {
+                // Having the skill doesn't change TN but focuses do:
+                // a trained character with a matching focus scores two
+                // successes on any die at or below their skill rating.
                 let skill_val = sheet.skill(skill);
-                // Having the skill doesn't change TN but focuses do
-                // Keep it simple: skill > 0 means trained
-                let _ = skill_val;
+                let focused = request.focus.as_ref().is_some_and(|f| sheet.has_focus(f));
+                if skill_val > 0 && focused {
+                    count.critical_threshold = count.critical_threshold.max(skill_val);
+                }
+            }
+            if !request.die_targets.is_empty() {
+                count.die_targets = request.die_targets.clone();
             }
             if let Some(difficulty) = request.difficulty {
                 count.successes_needed = difficulty;
             }
+            if let Some(stat) = opposed {
+                count.target_number = contested_target(count.target_number, stat);
+            }
         }
         ResolutionStrategy::Sum(sum) => {
             if let Some(difficulty) = request.difficulty {
                 sum.target_number = difficulty;
             }
+            if let Some(stat) = opposed {
+                sum.target_number += stat;
+            }
         }
         ResolutionStrategy::Highest(highest) => {
             if let Some(difficulty) = request.difficulty {
@@ -239,55 +691,42 @@ fn apply_check_modifiers(
         ResolutionStrategy::RollUnder(ru) => {
             // In roll-under: the attribute value IS the target number
             if let Some(ref attr) = request.attribute {
-                ru.target_number = sheet.attribute(attr)?;
+                ru.target_number = sheet.attribute(attr)?.max(0) as u32;
             }
             if let Some(difficulty) = request.difficulty {
                 ru.target_number = difficulty;
             }
+            if let Some(stat) = opposed {
+                ru.target_number = contested_target(ru.target_number, stat);
+            }
+        }
+        ResolutionStrategy::RollPlus(plus) => {
+            // In roll-plus: the attribute and modifier add to the total
+            if let Some(ref attr) = request.attribute {
+                plus.modifier += sheet.attribute(attr)?;
+            }
+            plus.modifier += request.modifier;
+            if let Some(difficulty) = request.difficulty {
+                plus.success_min = difficulty as i32;
+            }
+            if let Some(stat) = opposed {
+                plus.modifier -= stat as i32;
+            }
         }
     }
 
     Ok(strategy)
 }
 
+/// A roll-under target contested by an opposing stat: lowered by as much as
+/// the stat exceeds it, raised by as much as the stat falls short.
+fn contested_target(target: u32, opposed: u32) -> u32 {
+    (2 * target).saturating_sub(opposed)
+}
+
 /// Generate side effects based on the outcome and system flags.
 fn generate_effects(ruleset: &RuleSet, roll: &RollResult, outcome: &Outcome) -> Vec<CheckEffect> {
-    let mut effects = Vec::new();
-
-    // 2d20 momentum economy
-    if ruleset.has_flag("momentum_economy") {
-        match outcome {
-            Outcome::Success { margin } | Outcome::CriticalSuccess { margin } => {
-                if *margin > 0 {
-                    effects.push(CheckEffect::Momentum(*margin as i32));
-                }
-            }
-            Outcome::CriticalFailure => {
-                effects.push(CheckEffect::Complication(
-                    "Critical failure complication".to_string(),
-                ));
-            }
-            _ => {}
-        }
-    }
-
-    // Trophy Gold ruin tracking
-    if ruleset.has_flag("dark_die_ruin") {
-        let highest_val = roll.highest();
-        let highest_is_dark = roll
-            .dice
-            .iter()
-            .filter(|d| d.value == highest_val)
-            .any(|d| d.tag == DiceTag::Dark);
-        if highest_is_dark {
-            effects.push(CheckEffect::TrackAdjust {
-                track: "Ruin".to_string(),
-                delta: 1,
-            });
-        }
-    }
-
-    effects
+    effects::generate(&ruleset.effects, roll, outcome)
 }
 
 // --- Helper functions for parsing world properties ---
@@ -312,6 +751,23 @@ fn extract_u32(props: &std::collections::HashMap<String, MetadataValue>, key: &s
     }
 }
 
+/// Extract a list of non-negative integers from a property map.
+fn extract_u32_list(
+    props: &std::collections::HashMap<String, MetadataValue>,
+    key: &str,
+) -> Vec<u32> {
+    match props.get(key) {
+        Some(MetadataValue::List(items)) => items
+            .iter()
+            .filter_map(|v| match v {
+                MetadataValue::Integer(n) if *n >= 0 => Some(*n as u32),
+                _ => None,
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
 /// Extract a boolean from a property map.
 fn extract_bool(
     props: &std::collections::HashMap<String, MetadataValue>,
@@ -372,6 +828,57 @@ fn parse_track_definitions(
         .collect()
 }
 
+/// Parse combat phases from `mechanics.phases` and the per-phase action
+/// lists under `mechanics.phase.<name>`.
+fn parse_combat_phases(
+    props: &std::collections::HashMap<String, MetadataValue>,
+) -> MechResult<Vec<CombatPhase>> {
+    extract_string_list(props, "mechanics.phases")
+        .into_iter()
+        .map(|name| {
+            let actions = extract_string_list(props, &format!("mechanics.phase.{name}"));
+            if let Some(unknown) = actions
+                .iter()
+                .find(|a| !CombatAction::KINDS.contains(&a.to_lowercase().as_str()))
+            {
+                return Err(MechError::InvalidConfig(format!(
+                    "unknown action '{unknown}' in phase {name} (expected one of: {})",
+                    CombatAction::KINDS.join(", ")
+                )));
+            }
+            Ok(CombatPhase::with_actions(name, actions))
+        })
+        .collect()
+}
+
+/// Parse derived stat formulas from `mechanics.derived.<name>` properties.
+///
+/// Stats are sorted by name so the order does not depend on map iteration.
+fn parse_derived_stats(
+    props: &std::collections::HashMap<String, MetadataValue>,
+) -> MechResult<Vec<DerivedStat>> {
+    let mut derived = props
+        .iter()
+        .filter_map(|(key, value)| {
+            let name = key.strip_prefix("mechanics.derived.")?;
+            Some((name, value))
+        })
+        .map(|(name, value)| {
+            let MetadataValue::String(source) = value else {
+                return Err(MechError::InvalidConfig(format!(
+                    "derived stat {name} must be a formula string"
+                )));
+            };
+            Ok(DerivedStat {
+                name: name.to_string(),
+                formula: Formula::parse(source)?,
+            })
+        })
+        .collect::<MechResult<Vec<_>>>()?;
+    derived.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(derived)
+}
+
 /// Build a resolution strategy from properties and system name.
 fn build_resolution(
     props: &std::collections::HashMap<String, MetadataValue>,
@@ -386,10 +893,12 @@ fn build_resolution(
             let critical_threshold =
                 extract_u32(props, "mechanics.critical_threshold").unwrap_or(1);
             let successes_needed = extract_u32(props, "mechanics.successes_needed").unwrap_or(1);
+            let die_targets = extract_u32_list(props, "mechanics.die_targets");
             Ok(ResolutionStrategy::Count(CountSuccesses {
                 target_number,
                 critical_threshold,
                 successes_needed,
+                die_targets,
             }))
         }
         "highest_die" | "trophy_gold" => {
@@ -413,7 +922,27 @@ fn build_resolution(
         }
         "roll_under" | "mothership" => {
             let target_number = extract_u32(props, "mechanics.target_number").unwrap_or(50);
-            Ok(ResolutionStrategy::RollUnder(RollUnder { target_number }))
+            let doubles_critical =
+                extract_bool(props, "mechanics.doubles_critical").unwrap_or(true);
+            Ok(ResolutionStrategy::RollUnder(RollUnder {
+                target_number,
+                doubles_critical,
+            }))
+        }
+        "roll_plus" | "pbta" => {
+            let defaults = RollPlus::default();
+            let partial_min = extract_u32(props, "mechanics.partial_min")
+                .map_or(defaults.partial_min, |n| n as i32);
+            let success_min = extract_u32(props, "mechanics.success_min")
+                .map_or(defaults.success_min, |n| n as i32);
+            let twelve_plus_crit =
+                extract_bool(props, "mechanics.twelve_plus_crit").unwrap_or(false);
+            Ok(ResolutionStrategy::RollPlus(RollPlus {
+                modifier: 0,
+                partial_min,
+                success_min,
+                twelve_plus_crit,
+            }))
         }
         other => Err(MechError::InvalidConfig(format!(
             "unknown resolution type: {other}"
@@ -487,6 +1016,7 @@ mod tests {
         assert_eq!(ruleset.skills.len(), 1);
         assert_eq!(ruleset.track_definitions.len(), 2);
         assert!(ruleset.has_flag("momentum_economy"));
+        assert_eq!(ruleset.effects, effects::momentum_economy());
     }
 
     #[test]
@@ -521,36 +1051,710 @@ mod tests {
     }
 
     #[test]
-    fn from_world_no_config() {
-        let world = World::new(WorldMeta::new("Empty"));
-        assert!(matches!(
-            RuleSet::from_world(&world),
-            Err(MechError::NoMechanicsConfig)
-        ));
+    fn from_world_roll_under_doubles_flag() {
+        let world = make_world_with_mechanics(vec![
+            (
+                "mechanics.system",
+                MetadataValue::String("mothership".to_string()),
+            ),
+            (
+                "mechanics.check_die",
+                MetadataValue::String("d100".to_string()),
+            ),
+            ("mechanics.doubles_critical", MetadataValue::Boolean(false)),
+        ]);
+
+        let ruleset = RuleSet::from_world(&world).unwrap();
+        let ResolutionStrategy::RollUnder(ru) = ruleset.resolution else {
+            panic!("expected roll-under resolution");
+        };
+        assert!(!ru.doubles_critical);
     }
 
     #[test]
-    fn perform_check_2d20() {
-        let ruleset = preset::two_d20();
-        let mut entity = Entity::new(EntityKind::Character, "Test");
+    fn from_world_pbta_roll_plus() {
+        let world = make_world_with_mechanics(vec![
+            (
+                "mechanics.system",
+                MetadataValue::String("pbta".to_string()),
+            ),
+            (
+                "mechanics.check_die",
+                MetadataValue::String("d6".to_string()),
+            ),
+            ("mechanics.twelve_plus_crit", MetadataValue::Boolean(true)),
+        ]);
+
+        let ruleset = RuleSet::from_world(&world).unwrap();
+        let ResolutionStrategy::RollPlus(plus) = ruleset.resolution else {
+            panic!("expected roll-plus resolution");
+        };
+        assert_eq!((plus.partial_min, plus.success_min), (7, 10));
+        assert!(plus.twelve_plus_crit);
+    }
+
+    #[test]
+    fn roll_plus_adds_stat_and_modifier_to_the_total() {
+        let ruleset = preset::pbta();
+        let mut entity = Entity::new(EntityKind::Character, "Dremmer");
         entity
             .properties
-            .insert("mechanics.agility".to_string(), MetadataValue::Integer(12));
+            .insert("mechanics.hard".to_string(), MetadataValue::Integer(2));
+        let sheet = crate::sheet::CharacterSheet::from_entity(&entity, &ruleset).unwrap();
+        let mut rng = StdRng::seed_from_u64(3);
+
+        let request = CheckRequest {
+            attribute: Some("Hard".to_string()),
+            modifier: 1,
+            manual_values: vec![2, 3],
+            ..CheckRequest::default()
+        };
+        let result = perform_check(&ruleset, &sheet, &request, &mut rng).unwrap();
+        // 2 + 3 + 2 (Hard) + 1 = 8: a partial hit, still rolling two dice
+        assert_eq!(result.roll.dice.len(), 2);
+        assert_eq!(result.outcome, Outcome::Partial);
+        let explanation = result.explain();
+        assert!(explanation.contains("total 5 +3 = 8"), "{explanation}");
+        assert!(
+            explanation.contains("Hard adds to the total"),
+            "{explanation}"
+        );
+        assert!(!explanation.contains("pool of"), "{explanation}");
+    }
+
+    #[test]
+    fn roll_plus_subtracts_a_negative_stat() {
+        let ruleset = preset::pbta();
+        let mut entity = Entity::new(EntityKind::Character, "Dremmer");
         entity
             .properties
-            .insert("mechanics.melee".to_string(), MetadataValue::Integer(2));
-
+            .insert("mechanics.weird".to_string(), MetadataValue::Integer(-1));
         let sheet = crate::sheet::CharacterSheet::from_entity(&entity, &ruleset).unwrap();
+        assert_eq!(sheet.attribute("Weird").unwrap(), -1);
+        let mut rng = StdRng::seed_from_u64(3);
+
         let request = CheckRequest {
-            attribute: Some("Agility".to_string()),
-            difficulty: Some(1),
+            attribute: Some("Weird".to_string()),
+            manual_values: vec![4, 3],
             ..CheckRequest::default()
         };
-
-        let mut rng = StdRng::seed_from_u64(42);
         let result = perform_check(&ruleset, &sheet, &request, &mut rng).unwrap();
-        // With seed 42 and TN 12, we should get a deterministic result
-        assert!(!result.roll.dice.is_empty());
+        // 4 + 3 - 1 (Weird) = 6: a miss
+        assert_eq!(result.outcome, Outcome::Failure);
+        let explanation = result.explain();
+        assert!(explanation.contains("= 6"), "{explanation}");
+    }
+
+    #[test]
+    fn from_world_derived_stats() {
+        let world = make_world_with_mechanics(vec![
+            (
+                "mechanics.system",
+                MetadataValue::String("2d20".to_string()),
+            ),
+            (
+                "mechanics.derived.Defense",
+                MetadataValue::String("10 + Agility".to_string()),
+            ),
+            (
+                "mechanics.derived.Max_Stress",
+                MetadataValue::String("Brawn + 5".to_string()),
+            ),
+        ]);
+
+        let ruleset = RuleSet::from_world(&world).unwrap();
+        assert_eq!(ruleset.derived.len(), 2);
+        assert_eq!(ruleset.derived[0].name, "Defense");
+        assert_eq!(
+            ruleset.derived_stat("max_stress").unwrap().formula.source(),
+            "Brawn + 5"
+        );
+
+        let bad = make_world_with_mechanics(vec![
+            (
+                "mechanics.system",
+                MetadataValue::String("2d20".to_string()),
+            ),
+            (
+                "mechanics.derived.Defense",
+                MetadataValue::String("10 +".to_string()),
+            ),
+        ]);
+        assert!(matches!(
+            RuleSet::from_world(&bad),
+            Err(MechError::InvalidFormula(_))
+        ));
+    }
+
+    #[test]
+    fn from_world_effect_mappings_replace_flags() {
+        let world = make_world_with_mechanics(vec![
+            (
+                "mechanics.system",
+                MetadataValue::String("custom".to_string()),
+            ),
+            (
+                "mechanics.check_die",
+                MetadataValue::String("d6".to_string()),
+            ),
+            (
+                "mechanics.resolution",
+                MetadataValue::String("highest_die".to_string()),
+            ),
+            // Nothing a d6 can roll reaches a partial or a success
+            ("mechanics.partial_min", MetadataValue::Integer(7)),
+            (
+                "mechanics.flags",
+                MetadataValue::List(vec![MetadataValue::String("momentum_economy".to_string())]),
+            ),
+            (
+                "mechanics.effects.failure",
+                MetadataValue::List(vec![
+                    MetadataValue::String("Ruin +1".to_string()),
+                    MetadataValue::String("complication: The torch gutters".to_string()),
+                ]),
+            ),
+        ]);
+
+        let ruleset = RuleSet::from_world(&world).unwrap();
+        assert_eq!(ruleset.effects.len(), 2);
+
+        let mut rng = StdRng::seed_from_u64(1);
+        let request = CheckRequest {
+            difficulty: Some(7),
+            ..Default::default()
+        };
+        let sheet = crate::sheet::CharacterSheet::from_entity(
+            &Entity::new(EntityKind::Character, "Kael"),
+            &ruleset,
+        )
+        .unwrap();
+        let result = perform_check(&ruleset, &sheet, &request, &mut rng).unwrap();
+        assert_eq!(
+            result
+                .effects
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            ["Ruin +1", "Complication: The torch gutters"]
+        );
+
+        let bad = make_world_with_mechanics(vec![
+            (
+                "mechanics.system",
+                MetadataValue::String("custom".to_string()),
+            ),
+            (
+                "mechanics.effects.success",
+                MetadataValue::String("lots of momentum".to_string()),
+            ),
+        ]);
+        assert!(matches!(
+            RuleSet::from_world(&bad),
+            Err(MechError::InvalidConfig(_))
+        ));
+    }
+
+    #[test]
+    fn from_world_combat_phases() {
+        let world = make_world_with_mechanics(vec![
+            (
+                "mechanics.system",
+                MetadataValue::String("2d20".to_string()),
+            ),
+            (
+                "mechanics.phases",
+                MetadataValue::List(vec![
+                    MetadataValue::String("declare".to_string()),
+                    MetadataValue::String("resolve".to_string()),
+                ]),
+            ),
+            (
+                "mechanics.phase.declare",
+                MetadataValue::List(vec![
+                    MetadataValue::String("Move".to_string()),
+                    MetadataValue::String("custom".to_string()),
+                ]),
+            ),
+        ]);
+
+        let ruleset = RuleSet::from_world(&world).unwrap();
+        assert_eq!(
+            ruleset.combat_phases,
+            [
+                CombatPhase::with_actions(
+                    "declare",
+                    vec!["Move".to_string(), "custom".to_string()]
+                ),
+                CombatPhase::new("resolve"),
+            ]
+        );
+
+        let bad = make_world_with_mechanics(vec![
+            (
+                "mechanics.system",
+                MetadataValue::String("2d20".to_string()),
+            ),
+            (
+                "mechanics.phases",
+                MetadataValue::List(vec![MetadataValue::String("declare".to_string())]),
+            ),
+            (
+                "mechanics.phase.declare",
+                MetadataValue::List(vec![MetadataValue::String("shout".to_string())]),
+            ),
+        ]);
+        assert!(matches!(
+            RuleSet::from_world(&bad),
+            Err(MechError::InvalidConfig(_))
+        ));
+    }
+
+    #[test]
+    fn from_world_no_config() {
+        let world = World::new(WorldMeta::new("Empty"));
+        assert!(matches!(
+            RuleSet::from_world(&world),
+            Err(MechError::NoMechanicsConfig)
+        ));
+    }
+
+    #[test]
+    fn perform_check_2d20() {
+        let ruleset = preset::two_d20();
+        let mut entity = Entity::new(EntityKind::Character, "Test");
+        entity
+            .properties
+            .insert("mechanics.agility".to_string(), MetadataValue::Integer(12));
+        entity
+            .properties
+            .insert("mechanics.melee".to_string(), MetadataValue::Integer(2));
+
+        let sheet = crate::sheet::CharacterSheet::from_entity(&entity, &ruleset).unwrap();
+        let request = CheckRequest {
+            attribute: Some("Agility".to_string()),
+            difficulty: Some(1),
+            ..CheckRequest::default()
+        };
+
+        let mut rng = StdRng::seed_from_u64(42);
+        let result = perform_check(&ruleset, &sheet, &request, &mut rng).unwrap();
+        // With seed 42 and TN 12, we should get a deterministic result
+        assert!(!result.roll.dice.is_empty());
+    }
+
+    #[test]
+    fn manual_values_replace_the_rng() {
+        let ruleset = preset::two_d20();
+        let sheet = focused_sheet(&ruleset);
+        let mut rng = StdRng::seed_from_u64(7);
+
+        let request = CheckRequest {
+            manual_values: vec![1, 20],
+            ..CheckRequest::default()
+        };
+        let result = perform_check(&ruleset, &sheet, &request, &mut rng).unwrap();
+        let values: Vec<u32> = result.roll.dice.iter().map(|d| d.value).collect();
+        assert_eq!(values, [1, 20]);
+
+        let wrong_count = CheckRequest {
+            manual_values: vec![5],
+            ..CheckRequest::default()
+        };
+        assert!(matches!(
+            perform_check(&ruleset, &sheet, &wrong_count, &mut rng),
+            Err(MechError::InvalidRoll(_))
+        ));
+        let off_die = CheckRequest {
+            manual_values: vec![5, 21],
+            ..CheckRequest::default()
+        };
+        assert!(perform_check(&ruleset, &sheet, &off_die, &mut rng).is_err());
+    }
+
+    #[test]
+    fn advantage_rolls_extra_dice_and_drops_them() {
+        let ruleset = preset::two_d20();
+        let sheet = focused_sheet(&ruleset);
+        let mut rng = StdRng::seed_from_u64(1);
+        let values = |dice: &[crate::dice::DieResult]| -> Vec<u32> {
+            dice.iter().map(|d| d.value).collect()
+        };
+
+        // 2d20 succeeds low, so advantage sets aside the highest die
+        let advantage = CheckRequest {
+            advantage: 1,
+            manual_values: vec![1, 20, 5],
+            ..CheckRequest::default()
+        };
+        let result = perform_check(&ruleset, &sheet, &advantage, &mut rng).unwrap();
+        assert_eq!(values(&result.roll.dice), [1, 5]);
+        assert_eq!(values(&result.roll.dropped), [20]);
+        assert!(result.explain().contains("advantage dropped [20]"));
+
+        let disadvantage = CheckRequest {
+            advantage: -1,
+            ..advantage
+        };
+        let result = perform_check(&ruleset, &sheet, &disadvantage, &mut rng).unwrap();
+        assert_eq!(values(&result.roll.dice), [20, 5]);
+        assert_eq!(values(&result.roll.dropped), [1]);
+
+        // Roll-under keeps the lowest die too
+        let ruleset = preset::mothership();
+        let sheet = crate::sheet::CharacterSheet::from_entity(
+            &Entity::new(EntityKind::Character, "Ripley"),
+            &ruleset,
+        )
+        .unwrap();
+        let request = CheckRequest {
+            advantage: 1,
+            difficulty: Some(40),
+            manual_values: vec![72, 35],
+            ..CheckRequest::default()
+        };
+        let result = perform_check(&ruleset, &sheet, &request, &mut rng).unwrap();
+        assert_eq!(values(&result.roll.dice), [35]);
+        assert!(matches!(result.outcome, Outcome::Success { .. }));
+    }
+
+    #[test]
+    fn pool_limits_cap_and_handle_short_pools() {
+        let mut ruleset = preset::two_d20();
+        ruleset.max_pool_size = Some(3);
+        let sheet = focused_sheet(&ruleset);
+        let mut rng = StdRng::seed_from_u64(7);
+
+        let big = CheckRequest {
+            extra_dice: 4,
+            ..CheckRequest::default()
+        };
+        let result = perform_check(&ruleset, &sheet, &big, &mut rng).unwrap();
+        assert_eq!(result.roll.count(), 3);
+        assert_eq!(
+            result.pool_limit,
+            Some(PoolLimit::Capped {
+                requested: 6,
+                max: 3
+            })
+        );
+
+        let short = CheckRequest {
+            modifier: -3,
+            ..CheckRequest::default()
+        };
+        let result = perform_check(&ruleset, &sheet, &short, &mut rng).unwrap();
+        assert_eq!(result.roll.count(), 1);
+        assert!(matches!(
+            result.pool_limit,
+            Some(PoolLimit::Short {
+                requested: -1,
+                rule: ShortPool::Clamp,
+                dropped: None
+            })
+        ));
+
+        // 2d20 succeeds on low rolls, so disadvantage keeps the higher die
+        ruleset.short_pool = ShortPool::Disadvantage;
+        let result = perform_check(&ruleset, &sheet, &short, &mut rng).unwrap();
+        assert_eq!(result.roll.count(), 1);
+        match result.pool_limit {
+            Some(PoolLimit::Short {
+                dropped: Some(dropped),
+                ..
+            }) => assert!(dropped <= result.roll.dice[0].value),
+            other => panic!("expected disadvantage, got {other:?}"),
+        }
+
+        ruleset.short_pool = ShortPool::Complication;
+        let result = perform_check(&ruleset, &sheet, &short, &mut rng).unwrap();
+        assert!(
+            result
+                .effects
+                .iter()
+                .any(|e| matches!(e, CheckEffect::Complication(_)))
+        );
+    }
+
+    #[test]
+    fn from_world_reads_pool_limits() {
+        let world = make_world_with_mechanics(vec![
+            (
+                "mechanics.system",
+                MetadataValue::String("2d20".to_string()),
+            ),
+            ("mechanics.max_pool_size", MetadataValue::Integer(5)),
+            (
+                "mechanics.short_pool",
+                MetadataValue::String("Disadvantage".to_string()),
+            ),
+        ]);
+        let ruleset = RuleSet::from_world(&world).unwrap();
+        assert_eq!(ruleset.max_pool_size, Some(5));
+        assert_eq!(ruleset.short_pool, ShortPool::Disadvantage);
+
+        let world = make_world_with_mechanics(vec![
+            (
+                "mechanics.system",
+                MetadataValue::String("2d20".to_string()),
+            ),
+            (
+                "mechanics.short_pool",
+                MetadataValue::String("explode".to_string()),
+            ),
+        ]);
+        assert!(matches!(
+            RuleSet::from_world(&world),
+            Err(MechError::InvalidConfig(_))
+        ));
+    }
+
+    fn focused_sheet(ruleset: &RuleSet) -> crate::sheet::CharacterSheet {
+        let mut entity = Entity::new(EntityKind::Character, "Kael");
+        entity
+            .properties
+            .insert("mechanics.agility".to_string(), MetadataValue::Integer(10));
+        entity
+            .properties
+            .insert("mechanics.melee".to_string(), MetadataValue::Integer(4));
+        entity.properties.insert(
+            "mechanics.focuses".to_string(),
+            MetadataValue::List(vec![MetadataValue::String("Blade".to_string())]),
+        );
+        crate::sheet::CharacterSheet::from_entity(&entity, ruleset).unwrap()
+    }
+
+    #[test]
+    fn focus_widens_critical_range() {
+        let ruleset = preset::two_d20();
+        let sheet = focused_sheet(&ruleset);
+        let request = CheckRequest {
+            attribute: Some("Agility".to_string()),
+            skill: Some("Melee".to_string()),
+            focus: Some("blade".to_string()),
+            ..CheckRequest::default()
+        };
+        let strategy = apply_check_modifiers(&ruleset, &sheet, &request, None).unwrap();
+        let ResolutionStrategy::Count(count) = strategy else {
+            panic!("expected count strategy");
+        };
+        assert_eq!(count.target_number, 10);
+        assert_eq!(count.critical_threshold, 4);
+    }
+
+    #[test]
+    fn check_result_explains_roll_and_modifiers() {
+        let ruleset = preset::two_d20();
+        let sheet = focused_sheet(&ruleset);
+        let request = CheckRequest {
+            attribute: Some("Agility".to_string()),
+            skill: Some("Melee".to_string()),
+            focus: Some("Blade".to_string()),
+            extra_dice: 1,
+            ..CheckRequest::default()
+        };
+        let mut rng = StdRng::seed_from_u64(42);
+        let result = perform_check(&ruleset, &sheet, &request, &mut rng).unwrap();
+
+        let explained = result.explain();
+        assert!(explained.starts_with("3 dice vs TN 10: "), "{explained}");
+        assert!(explained.contains(&format!("→ {}", result.outcome)));
+        assert!(explained.contains("; pool of 2 adjusted to 3"));
+        assert!(explained.contains("; Agility sets the TN"));
+        assert!(explained.contains("; Blade widens criticals to 4 or under"));
+    }
+
+    #[test]
+    fn focus_without_training_or_match_has_no_effect() {
+        let ruleset = preset::two_d20();
+        let sheet = focused_sheet(&ruleset);
+
+        let unmatched = CheckRequest {
+            attribute: Some("Agility".to_string()),
+            skill: Some("Melee".to_string()),
+            focus: Some("Shield".to_string()),
+            ..CheckRequest::default()
+        };
+        let untrained = CheckRequest {
+            attribute: Some("Agility".to_string()),
+            skill: Some("Stealth".to_string()),
+            focus: Some("Blade".to_string()),
+            ..CheckRequest::default()
+        };
+        for request in [unmatched, untrained] {
+            let strategy = apply_check_modifiers(&ruleset, &sheet, &request, None).unwrap();
+            let ResolutionStrategy::Count(count) = strategy else {
+                panic!("expected count strategy");
+            };
+            assert_eq!(count.critical_threshold, 1);
+        }
+    }
+
+    #[test]
+    fn request_die_targets_override_strategy() {
+        let ruleset = preset::two_d20();
+        let sheet = focused_sheet(&ruleset);
+        let request = CheckRequest {
+            die_targets: vec![15],
+            ..CheckRequest::default()
+        };
+        let strategy = apply_check_modifiers(&ruleset, &sheet, &request, None).unwrap();
+        let ResolutionStrategy::Count(count) = strategy else {
+            panic!("expected count strategy");
+        };
+        assert_eq!(count.target_for(0), 15);
+        assert_eq!(count.target_for(1), 10);
+    }
+
+    #[test]
+    fn from_world_reads_die_targets() {
+        let world = make_world_with_mechanics(vec![
+            (
+                "mechanics.system",
+                MetadataValue::String("2d20".to_string()),
+            ),
+            (
+                "mechanics.die_targets",
+                MetadataValue::List(vec![MetadataValue::Integer(14)]),
+            ),
+        ]);
+        let ruleset = RuleSet::from_world(&world).unwrap();
+        let ResolutionStrategy::Count(count) = ruleset.resolution else {
+            panic!("expected count strategy");
+        };
+        assert_eq!(count.die_targets, vec![14]);
+    }
+
+    fn opposed_world() -> World {
+        let mut world = World::new(WorldMeta::new("Test World"));
+        let mut guard = Entity::new(EntityKind::Character, "Guard");
+        guard
+            .properties
+            .insert("mechanics.melee".to_string(), MetadataValue::Integer(3));
+        guard
+            .properties
+            .insert("mechanics.agility".to_string(), MetadataValue::Integer(9));
+        world.add_entity(guard).unwrap();
+        world
+    }
+
+    #[test]
+    fn opposed_difficulty_reads_other_sheet() {
+        let ruleset = preset::two_d20();
+        let world = opposed_world();
+        assert_eq!(
+            opposed_difficulty(&ruleset, &world, "guard", "MELEE").unwrap(),
+            3
+        );
+        assert_eq!(
+            opposed_difficulty(&ruleset, &world, "Guard", "agility").unwrap(),
+            9
+        );
+        assert!(matches!(
+            opposed_difficulty(&ruleset, &world, "Nobody", "melee"),
+            Err(MechError::EntityNotFound(_))
+        ));
+        assert!(matches!(
+            opposed_difficulty(&ruleset, &world, "Guard", "charm"),
+            Err(MechError::UnknownAttribute(_))
+        ));
+    }
+
+    #[test]
+    fn perform_check_in_world_resolves_difficulty_from() {
+        let ruleset = preset::two_d20();
+        let sheet = focused_sheet(&ruleset);
+        let world = opposed_world();
+        let request = CheckRequest {
+            attribute: Some("Agility".to_string()),
+            difficulty_from: Some(("Guard".to_string(), "Melee".to_string())),
+            ..CheckRequest::default()
+        };
+
+        // Needs a world to resolve
+        let mut rng = StdRng::seed_from_u64(7);
+        assert!(perform_check(&ruleset, &sheet, &request, &mut rng).is_err());
+
+        // Agility 10 against Melee 3: the TN rises from 10 to 17, one success needed
+        let mut rng = StdRng::seed_from_u64(7);
+        let opposed = perform_check_in_world(&ruleset, &sheet, &request, &world, &mut rng).unwrap();
+        assert!(
+            opposed.explain().contains("vs TN 17"),
+            "{}",
+            opposed.explain()
+        );
+        assert!(
+            opposed.explain().contains("1 needed"),
+            "{}",
+            opposed.explain()
+        );
+        assert!(opposed.explain().contains("opposed by Guard.Melee (3)"));
+
+        // An explicit difficulty takes precedence
+        let explicit = CheckRequest {
+            difficulty: Some(2),
+            ..request
+        };
+        let result = perform_check_in_world(&ruleset, &sheet, &explicit, &world, &mut rng).unwrap();
+        assert!(
+            result.explain().contains("vs TN 10"),
+            "{}",
+            result.explain()
+        );
+        assert!(
+            result.explain().contains("2 needed"),
+            "{}",
+            result.explain()
+        );
+    }
+
+    #[test]
+    fn opposed_stats_contest_each_strategy() {
+        let request = CheckRequest {
+            attribute: Some("Agility".to_string()),
+            ..CheckRequest::default()
+        };
+
+        // Count: the actor's attribute still sets the TN, moved by the opposition
+        let ruleset = preset::two_d20();
+        let sheet = focused_sheet(&ruleset);
+        let ResolutionStrategy::Count(count) =
+            apply_check_modifiers(&ruleset, &sheet, &request, Some(14)).unwrap()
+        else {
+            panic!("expected count strategy");
+        };
+        assert_eq!((count.target_number, count.successes_needed), (6, 1));
+
+        // Roll-under: a weaker opponent makes the check easier than usual
+        let ruleset = preset::mothership();
+        let mut entity = Entity::new(EntityKind::Character, "Ripley");
+        entity
+            .properties
+            .insert("mechanics.speed".to_string(), MetadataValue::Integer(40));
+        let sheet = crate::sheet::CharacterSheet::from_entity(&entity, &ruleset).unwrap();
+        let request = CheckRequest {
+            attribute: Some("Speed".to_string()),
+            ..CheckRequest::default()
+        };
+        let strategy = |opposed| match apply_check_modifiers(&ruleset, &sheet, &request, opposed) {
+            Ok(ResolutionStrategy::RollUnder(ru)) => ru.target_number,
+            _ => panic!("expected roll-under strategy"),
+        };
+        assert_eq!(strategy(None), 40);
+        assert_eq!(strategy(Some(40)), 40);
+        assert_eq!(strategy(Some(55)), 25);
+        assert_eq!(strategy(Some(30)), 50);
+        assert_eq!(strategy(Some(90)), 0);
+
+        // Sum: the stat adds to the ruleset's target number
+        let ruleset = preset::blood_and_honor();
+        let sheet = crate::sheet::CharacterSheet::from_entity(&entity, &ruleset).unwrap();
+        let Ok(ResolutionStrategy::Sum(sum)) =
+            apply_check_modifiers(&ruleset, &sheet, &CheckRequest::default(), Some(3))
+        else {
+            panic!("expected sum strategy");
+        };
+        assert_eq!(sum.target_number, 13);
     }
 
     #[test]
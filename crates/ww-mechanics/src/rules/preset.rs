@@ -6,8 +6,10 @@
 use std::collections::HashSet;
 
 use crate::dice::Die;
-use crate::resolution::{CountSuccesses, HighestDie, ResolutionStrategy, RollUnder, SumPool};
-use crate::rules::{RuleSet, TrackDefinition};
+use crate::resolution::{
+    CountSuccesses, HighestDie, ResolutionStrategy, RollPlus, RollUnder, SumPool,
+};
+use crate::rules::{RuleSet, ShortPool, TrackDefinition, effects};
 
 /// 2d20 system (Modiphius-style).
 ///
@@ -19,10 +21,13 @@ pub fn two_d20() -> RuleSet {
         name: "2d20".to_string(),
         check_die: Die::D20,
         default_pool_size: 2,
+        max_pool_size: None,
+        short_pool: ShortPool::Clamp,
         resolution: ResolutionStrategy::Count(CountSuccesses {
             target_number: 10,
             critical_threshold: 1,
             successes_needed: 1,
+            die_targets: Vec::new(),
         }),
         attributes: vec![
             "Agility".to_string(),
@@ -62,6 +67,9 @@ pub fn two_d20() -> RuleSet {
             },
         ],
         flags: HashSet::from(["momentum_economy".to_string()]),
+        derived: Vec::new(),
+        effects: effects::momentum_economy(),
+        combat_phases: Vec::new(),
     }
 }
 
@@ -76,6 +84,8 @@ pub fn trophy_gold() -> RuleSet {
         name: "trophy_gold".to_string(),
         check_die: Die::D6,
         default_pool_size: 1,
+        max_pool_size: None,
+        short_pool: ShortPool::Clamp,
         resolution: ResolutionStrategy::Highest(HighestDie {
             partial_min: 4,
             success_min: 6,
@@ -111,6 +121,9 @@ pub fn trophy_gold() -> RuleSet {
             },
         ],
         flags: HashSet::from(["dark_die_ruin".to_string()]),
+        derived: Vec::new(),
+        effects: effects::dark_die_ruin(),
+        combat_phases: Vec::new(),
     }
 }
 
@@ -123,6 +136,8 @@ pub fn blood_and_honor() -> RuleSet {
         name: "blood_and_honor".to_string(),
         check_die: Die::D6,
         default_pool_size: 3,
+        max_pool_size: None,
+        short_pool: ShortPool::Clamp,
         resolution: ResolutionStrategy::Sum(SumPool {
             target_number: 10,
             wager_bonus: 0,
@@ -161,6 +176,9 @@ pub fn blood_and_honor() -> RuleSet {
             },
         ],
         flags: HashSet::from(["wager_system".to_string()]),
+        derived: Vec::new(),
+        effects: Vec::new(),
+        combat_phases: Vec::new(),
     }
 }
 
@@ -173,7 +191,12 @@ pub fn mothership() -> RuleSet {
         name: "mothership".to_string(),
         check_die: Die::D100,
         default_pool_size: 1,
-        resolution: ResolutionStrategy::RollUnder(RollUnder { target_number: 50 }),
+        max_pool_size: None,
+        short_pool: ShortPool::Clamp,
+        resolution: ResolutionStrategy::RollUnder(RollUnder {
+            target_number: 50,
+            doubles_critical: true,
+        }),
         attributes: vec![
             "Strength".to_string(),
             "Speed".to_string(),
@@ -206,6 +229,48 @@ pub fn mothership() -> RuleSet {
             },
         ],
         flags: HashSet::new(),
+        derived: Vec::new(),
+        effects: Vec::new(),
+        combat_phases: Vec::new(),
+    }
+}
+
+/// Powered by the Apocalypse system.
+///
+/// Roll 2d6 and add a stat: 6- is a miss, 7-9 a partial hit, 10+ a full hit.
+/// Stats are small (-1 to +3) and harm is tracked on a short clock.
+pub fn pbta() -> RuleSet {
+    RuleSet {
+        name: "pbta".to_string(),
+        check_die: Die::D6,
+        default_pool_size: 2,
+        max_pool_size: Some(2),
+        short_pool: ShortPool::Clamp,
+        resolution: ResolutionStrategy::RollPlus(RollPlus::default()),
+        attributes: vec![
+            "Cool".to_string(),
+            "Hard".to_string(),
+            "Hot".to_string(),
+            "Sharp".to_string(),
+            "Weird".to_string(),
+        ],
+        skills: Vec::new(),
+        track_definitions: vec![
+            TrackDefinition {
+                name: "Harm".to_string(),
+                default_max: 6,
+                min: 0,
+            },
+            TrackDefinition {
+                name: "Experience".to_string(),
+                default_max: 5,
+                min: 0,
+            },
+        ],
+        flags: HashSet::new(),
+        derived: Vec::new(),
+        effects: Vec::new(),
+        combat_phases: Vec::new(),
     }
 }
 
@@ -262,9 +327,28 @@ mod tests {
         assert!(matches!(rs.resolution, ResolutionStrategy::RollUnder(_)));
     }
 
+    #[test]
+    fn pbta_preset() {
+        let rs = pbta();
+        assert_eq!(rs.name, "pbta");
+        assert_eq!(rs.check_die, Die::D6);
+        assert_eq!(rs.default_pool_size, 2);
+        assert_eq!(rs.attributes.len(), 5);
+        let ResolutionStrategy::RollPlus(plus) = rs.resolution else {
+            panic!("expected roll-plus resolution");
+        };
+        assert_eq!((plus.partial_min, plus.success_min), (7, 10));
+    }
+
     #[test]
     fn presets_have_tracks() {
-        for rs in [two_d20(), trophy_gold(), blood_and_honor(), mothership()] {
+        for rs in [
+            two_d20(),
+            trophy_gold(),
+            blood_and_honor(),
+            mothership(),
+            pbta(),
+        ] {
             assert!(
                 !rs.track_definitions.is_empty(),
                 "{} has no track definitions",
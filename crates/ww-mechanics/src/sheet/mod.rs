@@ -5,22 +5,23 @@
 
 pub mod track;
 
-pub use track::Track;
+pub use track::{AdjustResult, Track};
 
 use std::collections::HashMap;
 
 use ww_core::entity::{Entity, MetadataValue};
 
 use crate::error::{MechError, MechResult};
-use crate::rules::{RuleSet, TrackDefinition};
+use crate::rules::{DerivedStat, RuleSet, TrackDefinition};
 
 /// A character's mechanical state within a game system.
 #[derive(Debug, Clone)]
 pub struct CharacterSheet {
     /// Character name.
     pub name: String,
-    /// Attribute scores (e.g., Agility: 3, Brawn: 4).
-    pub attributes: HashMap<String, u32>,
+    /// Attribute scores (e.g., Agility: 3, Brawn: 4). Some systems use
+    /// negative scores, like a PbtA stat of -1.
+    pub attributes: HashMap<String, i32>,
     /// Skill scores (e.g., Melee: 2, Stealth: 1).
     pub skills: HashMap<String, u32>,
     /// Focuses that grant bonuses (e.g., "Blade", "Heavy Armor").
@@ -29,6 +30,8 @@ pub struct CharacterSheet {
     pub tracks: HashMap<String, Track>,
     /// Narrative traits with no mechanical value.
     pub traits: Vec<String>,
+    /// Derived stat formulas copied from the ruleset.
+    pub derived: Vec<DerivedStat>,
 }
 
 impl CharacterSheet {
@@ -57,7 +60,7 @@ impl CharacterSheet {
                 .iter()
                 .any(|a| a.to_lowercase() == field_lower)
             {
-                if let Some(v) = extract_u32(value) {
+                if let Some(v) = extract_i32(value) {
                     let canonical = ruleset
                         .attributes
                         .iter()
@@ -149,11 +152,12 @@ impl CharacterSheet {
             focuses,
             tracks,
             traits,
+            derived: ruleset.derived.clone(),
         })
     }
 
     /// Get an attribute value, returning an error if not found.
-    pub fn attribute(&self, name: &str) -> MechResult<u32> {
+    pub fn attribute(&self, name: &str) -> MechResult<i32> {
         self.attributes
             .get(name)
             .copied()
@@ -179,11 +183,167 @@ impl CharacterSheet {
             .ok_or_else(|| MechError::TrackNotFound(name.to_string()))
     }
 
+    /// Compute a derived stat (e.g., Defense) from this sheet's attributes and skills.
+    ///
+    /// Stat names in the formula match attributes first, then skills, both
+    /// case-insensitively.
+    pub fn derived(&self, name: &str) -> MechResult<i32> {
+        let lower = name.to_lowercase();
+        let stat = self
+            .derived
+            .iter()
+            .find(|d| d.name.to_lowercase() == lower)
+            .ok_or_else(|| MechError::UnknownAttribute(name.to_string()))?;
+        stat.formula.eval(&|stat| self.stat_value(stat))
+    }
+
+    /// All derived stats with their computed values, in ruleset order.
+    pub fn derived_values(&self) -> Vec<(&str, MechResult<i32>)> {
+        self.derived
+            .iter()
+            .map(|d| (d.name.as_str(), self.derived(&d.name)))
+            .collect()
+    }
+
+    /// Look up an attribute or skill value by case-insensitive name.
+    fn stat_value(&self, name: &str) -> Option<i32> {
+        let lower = name.to_lowercase();
+        let attribute = self
+            .attributes
+            .iter()
+            .find(|(k, _)| k.to_lowercase() == lower)
+            .map(|(_, v)| *v);
+        attribute.or_else(|| {
+            self.skills
+                .iter()
+                .find(|(k, _)| k.to_lowercase() == lower)
+                .map(|(_, v)| *v as i32)
+        })
+    }
+
     /// Returns true if the character has a specific focus.
     pub fn has_focus(&self, focus: &str) -> bool {
         let lower = focus.to_lowercase();
         self.focuses.iter().any(|f| f.to_lowercase() == lower)
     }
+
+    /// Render the sheet as a Markdown handout.
+    ///
+    /// Attributes, skills, and tracks follow the ruleset's order; tracks
+    /// are drawn as bars like `[###--] 3/5`.
+    pub fn to_markdown(&self, ruleset: &RuleSet) -> String {
+        let mut out = format!(
+            "# {}\n\n*{} ({})*\n",
+            self.name, ruleset.name, ruleset.check_die
+        );
+
+        fn stat_section<T: std::fmt::Display>(
+            out: &mut String,
+            title: &str,
+            names: &[String],
+            values: &HashMap<String, T>,
+        ) {
+            let rows: Vec<_> = names
+                .iter()
+                .filter_map(|name| values.get(name).map(|v| (name, v)))
+                .collect();
+            if rows.is_empty() {
+                return;
+            }
+            out.push_str(&format!(
+                "\n## {title}\n\n| Name | Value |\n| --- | ---: |\n"
+            ));
+            for (name, value) in rows {
+                out.push_str(&format!("| {name} | {value} |\n"));
+            }
+        }
+        stat_section(
+            &mut out,
+            "Attributes",
+            &ruleset.attributes,
+            &self.attributes,
+        );
+        stat_section(&mut out, "Skills", &ruleset.skills, &self.skills);
+
+        if !self.derived.is_empty() {
+            out.push_str("\n## Derived\n\n");
+            for (name, value) in self.derived_values() {
+                match value {
+                    Ok(v) => out.push_str(&format!("- **{name}**: {v}\n")),
+                    Err(_) => out.push_str(&format!("- **{name}**: ?\n")),
+                }
+            }
+        }
+
+        let tracks: Vec<&Track> = ruleset
+            .track_definitions
+            .iter()
+            .filter_map(|def| self.tracks.get(&def.name))
+            .collect();
+        if !tracks.is_empty() {
+            out.push_str("\n## Tracks\n\n");
+            for track in tracks {
+                out.push_str(&format!("- **{}**: `{}`\n", track.name, track_bar(track)));
+            }
+        }
+
+        for (title, items) in [("Focuses", &self.focuses), ("Traits", &self.traits)] {
+            if !items.is_empty() {
+                out.push_str(&format!("\n## {title}\n\n"));
+                for item in items {
+                    out.push_str(&format!("- {item}\n"));
+                }
+            }
+        }
+        out
+    }
+
+    /// Render the sheet as pretty-printed JSON.
+    pub fn to_json(&self) -> String {
+        let tracks: serde_json::Map<String, serde_json::Value> = self
+            .tracks
+            .iter()
+            .map(|(name, track)| {
+                let value = serde_json::json!({
+                    "current": track.current,
+                    "min": track.min,
+                    "max": track.max,
+                });
+                (name.clone(), value)
+            })
+            .collect();
+        let derived: serde_json::Map<String, serde_json::Value> = self
+            .derived_values()
+            .into_iter()
+            .filter_map(|(name, value)| Some((name.to_string(), value.ok()?.into())))
+            .collect();
+        let value = serde_json::json!({
+            "name": self.name,
+            "attributes": self.attributes,
+            "skills": self.skills,
+            "derived": derived,
+            "tracks": tracks,
+            "focuses": self.focuses,
+            "traits": self.traits,
+        });
+        serde_json::to_string_pretty(&value).unwrap_or_default()
+    }
+}
+
+/// Longest bar drawn for a track; wider tracks are scaled down to fit.
+const BAR_WIDTH: i32 = 10;
+
+/// A track as a filled bar with its value, e.g. `[###--] 3/5`.
+fn track_bar(track: &Track) -> String {
+    let width = (track.max - track.min).clamp(1, BAR_WIDTH);
+    let filled = (track.fraction() * width as f64).round() as usize;
+    format!(
+        "[{}{}] {}/{}",
+        "#".repeat(filled),
+        "-".repeat(width as usize - filled),
+        track.current,
+        track.max
+    )
 }
 
 /// Build tracks from definitions, applying any current-value overrides.
@@ -231,6 +391,8 @@ mod tests {
             name: "test".to_string(),
             check_die: crate::dice::Die::D20,
             default_pool_size: 2,
+            max_pool_size: None,
+            short_pool: crate::rules::ShortPool::Clamp,
             resolution: crate::resolution::ResolutionStrategy::Count(
                 crate::resolution::CountSuccesses::default(),
             ),
@@ -249,6 +411,12 @@ mod tests {
                 },
             ],
             flags: std::collections::HashSet::new(),
+            derived: vec![DerivedStat {
+                name: "Defense".to_string(),
+                formula: crate::rules::Formula::parse("10 + Agility + Melee / 2").unwrap(),
+            }],
+            effects: Vec::new(),
+            combat_phases: Vec::new(),
         }
     }
 
@@ -273,6 +441,74 @@ mod tests {
         assert_eq!(sheet.skill("Stealth"), 0); // untrained
     }
 
+    fn exported_sheet(ruleset: &RuleSet) -> CharacterSheet {
+        let mut entity = Entity::new(EntityKind::Character, "Kael");
+        for (key, value) in [
+            ("mechanics.agility", MetadataValue::Integer(3)),
+            ("mechanics.melee", MetadataValue::Integer(2)),
+            ("mechanics.stress", MetadataValue::Integer(3)),
+            (
+                "mechanics.focus",
+                MetadataValue::String("Blade".to_string()),
+            ),
+            (
+                "mechanics.trait",
+                MetadataValue::String("Sworn to the Lamp".to_string()),
+            ),
+        ] {
+            entity.properties.insert(key.to_string(), value);
+        }
+        CharacterSheet::from_entity(&entity, ruleset).unwrap()
+    }
+
+    #[test]
+    fn to_markdown_renders_sections_and_track_bars() {
+        let ruleset = test_ruleset();
+        let markdown = exported_sheet(&ruleset).to_markdown(&ruleset);
+        assert!(
+            markdown.starts_with("# Kael\n\n*test (d20)*\n"),
+            "{markdown}"
+        );
+        assert!(
+            markdown
+                .contains("## Attributes\n\n| Name | Value |\n| --- | ---: |\n| Agility | 3 |\n")
+        );
+        assert!(
+            markdown.contains("## Skills\n\n| Name | Value |\n| --- | ---: |\n| Melee | 2 |\n")
+        );
+        assert!(markdown.contains("- **Defense**: 14\n"));
+        assert!(markdown.contains("- **Stress**: `[###--] 3/5`\n- **Momentum**: `[######] 6/6`\n"));
+        assert!(markdown.contains("## Focuses\n\n- Blade\n"));
+        assert!(markdown.contains("## Traits\n\n- Sworn to the Lamp\n"));
+    }
+
+    #[test]
+    fn to_json_includes_stats_and_tracks() {
+        let ruleset = test_ruleset();
+        let json: serde_json::Value =
+            serde_json::from_str(&exported_sheet(&ruleset).to_json()).unwrap();
+        assert_eq!(json["name"], "Kael");
+        assert_eq!(json["attributes"]["Agility"], 3);
+        assert_eq!(json["skills"]["Melee"], 2);
+        assert_eq!(json["derived"]["Defense"], 14);
+        assert_eq!(json["tracks"]["Stress"]["current"], 3);
+        assert_eq!(json["tracks"]["Stress"]["max"], 5);
+        assert_eq!(json["focuses"][0], "Blade");
+        assert_eq!(json["traits"][0], "Sworn to the Lamp");
+    }
+
+    #[test]
+    fn track_bar_scales_wide_tracks() {
+        assert_eq!(
+            track_bar(&Track::with_range("Gold", 50, 0, 100)),
+            "[#####-----] 50/100"
+        );
+        assert_eq!(
+            track_bar(&Track::with_range("Ruin", 1, 1, 6)),
+            "[-----] 1/6"
+        );
+    }
+
     #[test]
     fn from_entity_reads_focuses() {
         let ruleset = test_ruleset();
@@ -345,4 +581,28 @@ mod tests {
         stress.adjust(-2);
         assert_eq!(sheet.track("Stress").unwrap().current, 3);
     }
+
+    #[test]
+    fn derived_stat_uses_attributes_and_skills() {
+        let ruleset = test_ruleset();
+        let mut entity = Entity::new(EntityKind::Character, "Kael");
+        entity
+            .properties
+            .insert("mechanics.agility".to_string(), MetadataValue::Integer(3));
+        entity
+            .properties
+            .insert("mechanics.melee".to_string(), MetadataValue::Integer(4));
+
+        let sheet = CharacterSheet::from_entity(&entity, &ruleset).unwrap();
+        assert_eq!(sheet.derived("defense").unwrap(), 15);
+        assert_eq!(sheet.derived_values()[0].0, "Defense");
+        assert!(matches!(
+            sheet.derived("Initiative"),
+            Err(MechError::UnknownAttribute(_))
+        ));
+
+        entity.properties.remove("mechanics.agility");
+        let sheet = CharacterSheet::from_entity(&entity, &ruleset).unwrap();
+        assert!(sheet.derived("Defense").is_err());
+    }
 }
@@ -40,10 +40,19 @@ impl Track {
         }
     }
 
-    /// Adjust the track by a delta, clamping to bounds. Returns the new value.
-    pub fn adjust(&mut self, delta: i32) -> i32 {
-        self.current = (self.current + delta).clamp(self.min, self.max);
-        self.current
+    /// Adjust the track by a delta, clamping to bounds.
+    ///
+    /// The result reports how much of the delta was applied and how much
+    /// was clamped away, so callers can react to a track being pushed past
+    /// its limits.
+    pub fn adjust(&mut self, delta: i32) -> AdjustResult {
+        let old = self.current;
+        let target = self.current.saturating_add(delta);
+        self.current = target.clamp(self.min, self.max);
+        AdjustResult {
+            applied: self.current - old,
+            overflow: target - self.current,
+        }
     }
 
     /// Returns true if the track is at its minimum value.
@@ -66,6 +75,28 @@ impl Track {
     }
 }
 
+/// Outcome of [`Track::adjust`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AdjustResult {
+    /// The change actually made to the track.
+    pub applied: i32,
+    /// The part of the delta lost to clamping: positive past the maximum,
+    /// negative past the minimum, zero if the delta fit.
+    pub overflow: i32,
+}
+
+impl AdjustResult {
+    /// Returns true if the delta pushed the track past its maximum.
+    pub fn overflowed(&self) -> bool {
+        self.overflow > 0
+    }
+
+    /// Returns true if the delta pushed the track past its minimum.
+    pub fn underflowed(&self) -> bool {
+        self.overflow < 0
+    }
+}
+
 impl std::fmt::Display for Track {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}: {}/{}", self.name, self.current, self.max)
@@ -97,21 +128,43 @@ mod tests {
     #[test]
     fn adjust_clamps_to_max() {
         let mut t = Track::new("HP", 5);
-        assert_eq!(t.adjust(10), 5);
+        let result = t.adjust(10);
+        assert_eq!(t.current, 5);
+        assert_eq!(
+            result,
+            AdjustResult {
+                applied: 0,
+                overflow: 10
+            }
+        );
+        assert!(result.overflowed());
         assert!(t.is_full());
     }
 
     #[test]
     fn adjust_clamps_to_min() {
         let mut t = Track::new("HP", 5);
-        assert_eq!(t.adjust(-20), 0);
+        let result = t.adjust(-20);
+        assert_eq!(t.current, 0);
+        assert_eq!(
+            result,
+            AdjustResult {
+                applied: -5,
+                overflow: -15
+            }
+        );
+        assert!(result.underflowed());
         assert!(t.is_empty());
     }
 
     #[test]
     fn adjust_normal() {
         let mut t = Track::new("HP", 10);
-        assert_eq!(t.adjust(-3), 7);
+        let result = t.adjust(-3);
+        assert_eq!(t.current, 7);
+        assert_eq!(result.applied, -3);
+        assert_eq!(result.overflow, 0);
+        assert!(!result.overflowed() && !result.underflowed());
         assert!(!t.is_empty());
         assert!(!t.is_full());
     }
@@ -0,0 +1,106 @@
+//! Markdown chronicles of a simulation's event history.
+//!
+//! A chronicle groups the event log by in-world day and renders each day as
+//! a short section, turning a simulation run into readable world history.
+
+use std::fmt::Write;
+
+use ww_core::World;
+use ww_core::text::capitalize;
+
+use crate::clock::SimClock;
+use crate::event::EventLog;
+
+/// Render the event log as a Markdown chronicle, one section per in-world day.
+///
+/// Dates are computed from the clock's start date and tick length, so the
+/// clock should be the one the events were recorded with.
+pub fn chronicle(world: &World, clock: &SimClock, events: &EventLog) -> String {
+    let mut out = format!("# Chronicle of {}\n\n", world.meta.name);
+    let _ = writeln!(
+        out,
+        "*{} ticks at {}h per tick, from {} to {}.*\n",
+        clock.tick(),
+        clock.hours_per_tick(),
+        clock.date_at_tick(0),
+        clock.current_date()
+    );
+
+    if events.is_empty() {
+        out.push_str("Nothing of note happened.\n");
+        return out;
+    }
+
+    let mut current_day = None;
+    for event in events.events() {
+        let day = clock.day_index_at_tick(event.tick);
+        if current_day != Some(day) {
+            if current_day.is_some() {
+                out.push('\n');
+            }
+            let _ = writeln!(
+                out,
+                "## Day {} — {}\n",
+                day + 1,
+                clock.date_at_tick(event.tick)
+            );
+            current_day = Some(day);
+        }
+
+        let hour = clock.hour_at_tick(event.tick);
+        let _ = writeln!(
+            out,
+            "- {:02}:{:02} — {}.",
+            hour.floor() as u32,
+            (hour.fract() * 60.0).round() as u32 % 60,
+            capitalize(&event.description)
+        );
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::{SimEvent, SimEventKind};
+    use ww_core::WorldMeta;
+    use ww_core::component::WorldDate;
+    use ww_core::entity::EntityId;
+
+    #[test]
+    fn chronicle_groups_events_by_day() {
+        let world = World::new(WorldMeta::new("Testland"));
+        let mut clock = SimClock::new(WorldDate::new(1), 6.0);
+        for _ in 0..8 {
+            clock.advance();
+        }
+
+        let mut events = EventLog::new(0);
+        let custom = |label: &str| SimEventKind::Custom {
+            label: label.to_string(),
+            entities: vec![EntityId::new()],
+        };
+        events.push(SimEvent::new(1, custom("a"), "the famine began"));
+        events.push(SimEvent::new(2, custom("b"), "Kael left the city"));
+        events.push(SimEvent::new(5, custom("c"), "the rains returned"));
+
+        let text = chronicle(&world, &clock, &events);
+        assert!(text.starts_with("# Chronicle of Testland\n"));
+        assert!(text.contains(
+            "*8 ticks at 6h per tick, from Year 1, Month 1, Day 1 to Year 1, Month 1, Day 3.*"
+        ));
+        assert!(text.contains("## Day 1 — Year 1, Month 1, Day 1\n\n- 06:00 — The famine began.\n- 12:00 — Kael left the city.\n"));
+        assert!(
+            text.contains("## Day 2 — Year 1, Month 1, Day 2\n\n- 06:00 — The rains returned.\n")
+        );
+    }
+
+    #[test]
+    fn chronicle_without_events() {
+        let world = World::new(WorldMeta::new("Quiet"));
+        let clock = SimClock::new(WorldDate::new(1), 1.0);
+        let text = chronicle(&world, &clock, &EventLog::new(0));
+        assert!(text.ends_with("Nothing of note happened.\n"));
+    }
+}
@@ -1,10 +1,11 @@
+use serde::{Deserialize, Serialize};
 use ww_core::component::WorldDate;
 
 /// Tracks simulation time: a monotonic tick counter and an in-world date.
 ///
 /// Uses a simplified 360-day year (12 months x 30 days) for deterministic
 /// calendar math suitable for fantasy settings.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SimClock {
     tick: u64,
     start_date: WorldDate,
@@ -37,7 +38,26 @@ impl SimClock {
 
     /// Current in-world date derived from start date + accumulated hours.
     pub fn current_date(&self) -> WorldDate {
-        let total_days = (self.accumulated_hours / 24.0).floor() as i64;
+        self.date_after_hours(self.accumulated_hours)
+    }
+
+    /// In-world date at a given tick of this clock's run.
+    pub fn date_at_tick(&self, tick: u64) -> WorldDate {
+        self.date_after_hours(tick as f64 * self.hours_per_tick)
+    }
+
+    /// Hour of the day (0.0..24.0) at a given tick of this clock's run.
+    pub fn hour_at_tick(&self, tick: u64) -> f64 {
+        (tick as f64 * self.hours_per_tick) % 24.0
+    }
+
+    /// Zero-based number of whole in-world days elapsed at a given tick.
+    pub fn day_index_at_tick(&self, tick: u64) -> u64 {
+        (tick as f64 * self.hours_per_tick / 24.0).floor() as u64
+    }
+
+    fn date_after_hours(&self, hours: f64) -> WorldDate {
+        let total_days = (hours / 24.0).floor() as i64;
 
         let start_day = self.start_date.day.unwrap_or(1) as i64 - 1;
         let start_month = self.start_date.month.unwrap_or(1) as i64 - 1;
@@ -130,6 +150,18 @@ mod tests {
         assert_eq!(date.day, Some(2));
     }
 
+    #[test]
+    fn clock_date_at_tick_matches_current_date() {
+        let mut clock = SimClock::new(WorldDate::new(1), 2.0);
+        for _ in 0..30 {
+            clock.advance();
+        }
+        assert_eq!(clock.date_at_tick(30), clock.current_date());
+        assert_eq!(clock.date_at_tick(12).day, Some(2));
+        assert_eq!(clock.day_index_at_tick(13), 1);
+        assert!((clock.hour_at_tick(13) - 2.0).abs() < f64::EPSILON);
+    }
+
     #[test]
     fn clock_date_advances_across_months() {
         let start = WorldDate {
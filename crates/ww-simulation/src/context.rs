@@ -1,8 +1,13 @@
-use rand::rngs::StdRng;
 use ww_core::world::World;
 
 use crate::clock::SimClock;
-use crate::event::{EventLog, SimEvent, SimEventKind};
+use crate::event::{EntityChange, EventLog, SimEvent, SimEventKind};
+
+/// The simulation's random number generator.
+///
+/// The same ChaCha12 generator as `rand::rngs::StdRng`, but serializable so
+/// that a [`SimSnapshot`](crate::snapshot::SimSnapshot) can capture its state.
+pub type SimRng = rand_chacha::ChaCha12Rng;
 
 /// Mutable context passed to each system during a tick.
 pub struct SimContext<'a> {
@@ -13,7 +18,7 @@ pub struct SimContext<'a> {
     /// Mutable reference to the event log for recording events.
     pub events: &'a mut EventLog,
     /// Mutable reference to the deterministic random number generator.
-    pub rng: &'a mut StdRng,
+    pub rng: &'a mut SimRng,
 }
 
 impl SimContext<'_> {
@@ -28,6 +33,13 @@ impl SimContext<'_> {
         self.clock.tick()
     }
 
+    /// Entities whose state changed so far in the current tick.
+    ///
+    /// Systems ticked later see the changes made by earlier ones.
+    pub fn changed_entities(&self) -> Vec<EntityChange> {
+        self.events.changes_at_tick(self.clock.tick())
+    }
+
     /// Return the current in-world hour of day (0.0..24.0).
     pub fn hour_of_day(&self) -> f64 {
         self.clock.hour_of_day()
@@ -0,0 +1,151 @@
+//! Single-entity diaries of a simulation run.
+//!
+//! When a [`Simulation`](crate::Simulation) is focused on one entity, it
+//! records a [`DiaryEntry`] for that entity after every tick: where they
+//! are, what they are doing, how their needs stand, and the events that
+//! involved them. [`render`] turns those entries into a readable diary.
+
+use std::fmt::Write;
+
+use ww_core::World;
+use ww_core::entity::EntityId;
+
+use crate::clock::SimClock;
+use crate::event::SimEvent;
+use crate::narrate::narrate_event;
+use crate::needs::NeedKind;
+
+/// The focused entity's state at the end of one tick.
+#[derive(Debug, Clone)]
+pub struct DiaryEntry {
+    /// The tick this entry records.
+    pub tick: u64,
+    /// Scheduled activity, if the entity has one.
+    pub activity: Option<String>,
+    /// Current location, if the entity is tracked spatially.
+    pub location: Option<EntityId>,
+    /// Travel destination while on the move.
+    pub destination: Option<EntityId>,
+    /// Need levels (0.0..=1.0), ordered by need name.
+    pub needs: Vec<(NeedKind, f64)>,
+    /// Events this tick that involved the entity.
+    pub events: Vec<SimEvent>,
+}
+
+/// Render diary entries as plain text, one block per tick.
+///
+/// Each block leads with the in-world time, place, activity, and needs,
+/// followed by the tick's events narrated as prose.
+pub fn render(world: &World, clock: &SimClock, entity: EntityId, entries: &[DiaryEntry]) -> String {
+    let mut out = format!("Diary of {}\n", world.entity_name(entity));
+    if entries.is_empty() {
+        out.push_str("\nNo ticks recorded.\n");
+        return out;
+    }
+
+    let mut current_day = None;
+    for entry in entries {
+        let day = clock.day_index_at_tick(entry.tick);
+        if current_day != Some(day) {
+            let _ = writeln!(
+                out,
+                "\nDay {} — {}",
+                day + 1,
+                clock.date_at_tick(entry.tick)
+            );
+            current_day = Some(day);
+        }
+
+        let mut parts = Vec::new();
+        if let Some(location) = entry.location {
+            let place = world.entity_name(location);
+            parts.push(match entry.destination {
+                Some(dest) => format!("{place}, bound for {}", world.entity_name(dest)),
+                None => format!("at {place}"),
+            });
+        }
+        if let Some(activity) = &entry.activity {
+            parts.push(activity.clone());
+        }
+        if !entry.needs.is_empty() {
+            let needs: Vec<String> = entry
+                .needs
+                .iter()
+                .map(|(need, level)| format!("{need} {:.0}%", level * 100.0))
+                .collect();
+            parts.push(needs.join(", "));
+        }
+
+        let hour = clock.hour_at_tick(entry.tick);
+        let _ = writeln!(
+            out,
+            "  {:02}:{:02}  {}",
+            hour.floor() as u32,
+            (hour.fract() * 60.0).round() as u32 % 60,
+            parts.join(" — ")
+        );
+        for event in &entry.events {
+            let _ = writeln!(out, "         {}", narrate_event(event, world));
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::SimEventKind;
+    use ww_core::WorldMeta;
+    use ww_core::component::WorldDate;
+    use ww_core::entity::{Entity, EntityKind};
+
+    #[test]
+    fn render_groups_ticks_by_day() {
+        let mut world = World::new(WorldMeta::new("Testland"));
+        let kael = world
+            .add_entity(Entity::new(EntityKind::Character, "Kael"))
+            .unwrap();
+        let keep = world
+            .add_entity(Entity::new(EntityKind::Location, "the Keep"))
+            .unwrap();
+        let clock = SimClock::new(WorldDate::new(1), 12.0);
+
+        let entries = vec![
+            DiaryEntry {
+                tick: 1,
+                activity: Some("working".to_string()),
+                location: Some(keep),
+                destination: None,
+                needs: vec![(NeedKind::Hunger, 0.5)],
+                events: vec![SimEvent::new(
+                    1,
+                    SimEventKind::Arrived {
+                        entity: kael,
+                        at: keep,
+                    },
+                    "",
+                )],
+            },
+            DiaryEntry {
+                tick: 2,
+                activity: Some("sleeping".to_string()),
+                location: None,
+                destination: None,
+                needs: Vec::new(),
+                events: Vec::new(),
+            },
+        ];
+
+        let text = render(&world, &clock, kael, &entries);
+        assert_eq!(
+            text,
+            "Diary of Kael\n\
+             \nDay 1 — Year 1, Month 1, Day 1\
+             \n  12:00  at the Keep — working — hunger 50%\
+             \n         Kael arrives at the Keep.\
+             \n\nDay 2 — Year 1, Month 1, Day 2\
+             \n  00:00  sleeping\n"
+        );
+    }
+}
@@ -0,0 +1,315 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use ww_core::entity::{Entity, EntityId, EntityKind, MetadataValue};
+use ww_core::relationship::RelationshipKind;
+use ww_core::world::World;
+
+use crate::context::SimContext;
+use crate::error::SimResult;
+use crate::event::SimEventKind;
+use crate::snapshot::{from_state, to_state};
+use crate::system::System;
+
+/// Configuration for the economy system.
+#[derive(Debug, Clone)]
+pub struct EconomyConfig {
+    /// Resources earned per tick for each inhabitant of a faction's bases.
+    pub income_per_capita: f64,
+    /// Resources spent per tick to hold each base.
+    pub upkeep_per_base: f64,
+    /// Resources spent per tick on each member of the faction.
+    pub upkeep_per_member: f64,
+    /// Wealth of factions without a `wealth` resource or property.
+    pub starting_wealth: f64,
+    /// Wealth at or above which a faction is in surplus.
+    pub surplus_threshold: f64,
+}
+
+impl Default for EconomyConfig {
+    fn default() -> Self {
+        Self {
+            income_per_capita: 0.001,
+            upkeep_per_base: 0.5,
+            upkeep_per_member: 0.25,
+            starting_wealth: 100.0,
+            surplus_threshold: 1000.0,
+        }
+    }
+}
+
+/// A faction's resources over the course of a run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FactionEconomy {
+    /// Wealth after each tick, starting with the wealth at tick 0.
+    pub history: Vec<f64>,
+}
+
+impl FactionEconomy {
+    /// Current wealth.
+    pub fn wealth(&self) -> f64 {
+        self.history.last().copied().unwrap_or(0.0)
+    }
+
+    /// Change in wealth during the most recent tick.
+    pub fn last_delta(&self) -> f64 {
+        match self.history.as_slice() {
+            [.., before, after] => after - before,
+            _ => 0.0,
+        }
+    }
+}
+
+/// Tracks the wealth of every faction.
+///
+/// Each tick, a faction earns from the population of the locations it is
+/// `based at` and pays upkeep for those bases and for its members. Wealth
+/// never drops below zero. Crossing the surplus threshold emits
+/// `ResourceSurplus`; running out emits `ResourceShortage`.
+#[derive(Debug)]
+pub struct EconomySystem {
+    config: EconomyConfig,
+    factions: HashMap<EntityId, FactionEconomy>,
+}
+
+impl EconomySystem {
+    /// Create a new economy system with the given configuration.
+    pub fn new(config: EconomyConfig) -> Self {
+        Self {
+            config,
+            factions: HashMap::new(),
+        }
+    }
+
+    /// Create a new economy system using the default configuration.
+    pub fn with_default_config() -> Self {
+        Self::new(EconomyConfig::default())
+    }
+
+    /// Return the economy of the given faction, if tracked.
+    pub fn get_economy(&self, faction: EntityId) -> Option<&FactionEconomy> {
+        self.factions.get(&faction)
+    }
+
+    /// Return all tracked faction economies keyed by faction ID.
+    pub fn all_economies(&self) -> &HashMap<EntityId, FactionEconomy> {
+        &self.factions
+    }
+
+    /// A faction's current wealth.
+    pub fn wealth(&self, faction: EntityId) -> Option<f64> {
+        self.factions.get(&faction).map(FactionEconomy::wealth)
+    }
+
+    /// A faction's wealth at the end of the given tick, if it was tracked then.
+    pub fn wealth_at(&self, faction: EntityId, tick: u64) -> Option<f64> {
+        let economy = self.factions.get(&faction)?;
+        let index = usize::try_from(tick).ok()?;
+        economy.history.get(index).copied()
+    }
+
+    /// Resources a faction gains per tick from its bases, minus upkeep.
+    pub fn delta(&self, world: &World, faction: EntityId) -> f64 {
+        let mut bases = 0;
+        let mut population = 0;
+        for rel in world.relationships_from(faction) {
+            if rel.kind == RelationshipKind::BasedAt && rel.source == faction {
+                bases += 1;
+                population += world
+                    .get_entity(rel.target)
+                    .and_then(|e| e.components.location.as_ref())
+                    .and_then(|l| l.population)
+                    .unwrap_or(0);
+            }
+        }
+        let members = world
+            .relationships_to(faction)
+            .iter()
+            .filter(|r| r.kind == RelationshipKind::MemberOf && r.target == faction)
+            .count();
+
+        population as f64 * self.config.income_per_capita
+            - bases as f64 * self.config.upkeep_per_base
+            - members as f64 * self.config.upkeep_per_member
+    }
+
+    fn starting_wealth(&self, faction: &Entity) -> f64 {
+        let value = faction
+            .components
+            .faction
+            .as_ref()
+            .and_then(|f| f.resources.get("wealth"))
+            .or_else(|| faction.properties.get("wealth"));
+        match value {
+            Some(MetadataValue::Integer(n)) => *n as f64,
+            Some(MetadataValue::Float(n)) => *n,
+            _ => self.config.starting_wealth,
+        }
+    }
+}
+
+impl System for EconomySystem {
+    fn name(&self) -> &str {
+        "economy"
+    }
+
+    fn init(&mut self, ctx: &mut SimContext<'_>) -> SimResult<()> {
+        for faction in ctx.world.entities_by_kind(&EntityKind::Faction) {
+            let wealth = self.starting_wealth(faction).max(0.0);
+            self.factions.insert(
+                faction.id,
+                FactionEconomy {
+                    history: vec![wealth],
+                },
+            );
+        }
+        Ok(())
+    }
+
+    fn tick(&mut self, ctx: &mut SimContext<'_>) -> SimResult<()> {
+        let mut ids: Vec<EntityId> = self.factions.keys().copied().collect();
+        ids.sort_by_key(|id| id.0);
+
+        for id in ids {
+            let delta = self.delta(ctx.world, id);
+            let Some(economy) = self.factions.get_mut(&id) else {
+                continue;
+            };
+            let prev = economy.wealth();
+            let curr = (prev + delta).max(0.0);
+            economy.history.push(curr);
+
+            let surplus = self.config.surplus_threshold;
+            let name = ctx.world.entity_name(id).to_string();
+            if prev < surplus && curr >= surplus {
+                ctx.emit(
+                    SimEventKind::ResourceSurplus {
+                        faction: id,
+                        wealth: curr.round() as i64,
+                    },
+                    format!("{name} has a surplus of {curr:.0}"),
+                );
+            } else if prev > 0.0 && curr <= 0.0 {
+                ctx.emit(
+                    SimEventKind::ResourceShortage {
+                        faction: id,
+                        wealth: 0,
+                    },
+                    format!("{name} has run out of resources"),
+                );
+            }
+        }
+        Ok(())
+    }
+
+    fn save_state(&self) -> Option<serde_json::Value> {
+        to_state(&self.factions)
+    }
+
+    fn load_state(&mut self, state: serde_json::Value) -> SimResult<()> {
+        self.factions = from_state(state)?;
+        Ok(())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::SimConfig;
+    use crate::simulation::Simulation;
+    use ww_core::WorldMeta;
+    use ww_core::component::LocationComponent;
+    use ww_core::relationship::Relationship;
+
+    /// A faction based at a town of `population`, with `members` members.
+    fn world(population: u64, members: usize, wealth: i64) -> (World, EntityId) {
+        let mut world = World::new(WorldMeta::new("Economy Test"));
+        let mut guild = Entity::new(EntityKind::Faction, "Merchant Guild");
+        guild
+            .properties
+            .insert("wealth".to_string(), MetadataValue::Integer(wealth));
+        let guild = world.add_entity(guild).unwrap();
+        let mut town = Entity::new(EntityKind::Location, "Port Town");
+        town.components.location = Some(LocationComponent {
+            population: Some(population),
+            ..Default::default()
+        });
+        let town = world.add_entity(town).unwrap();
+        world
+            .add_relationship(Relationship::new(guild, RelationshipKind::BasedAt, town))
+            .unwrap();
+        for i in 0..members {
+            let member = world
+                .add_entity(Entity::new(EntityKind::Character, format!("Clerk {i}")))
+                .unwrap();
+            world
+                .add_relationship(Relationship::new(member, RelationshipKind::MemberOf, guild))
+                .unwrap();
+        }
+        (world, guild)
+    }
+
+    fn run(world: World, ticks: u64) -> Simulation {
+        let mut sim = Simulation::new(world, SimConfig::default());
+        sim.add_system(EconomySystem::with_default_config());
+        sim.run(ticks).unwrap();
+        sim
+    }
+
+    #[test]
+    fn delta_from_population_and_upkeep() {
+        let (world, guild) = world(10_000, 2, 0);
+        let system = EconomySystem::with_default_config();
+        // 10 income - 0.5 base upkeep - 2 * 0.25 member upkeep
+        assert!((system.delta(&world, guild) - 9.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn wealth_history_by_tick() {
+        let (world, guild) = world(10_000, 2, 50);
+        let sim = run(world, 10);
+        let economy = sim.get_system::<EconomySystem>().unwrap();
+        assert_eq!(economy.wealth_at(guild, 0), Some(50.0));
+        assert_eq!(economy.wealth_at(guild, 4), Some(86.0));
+        assert_eq!(economy.wealth(guild), Some(140.0));
+        assert_eq!(economy.wealth_at(guild, 11), None);
+        assert!((economy.get_economy(guild).unwrap().last_delta() - 9.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn surplus_emitted_once() {
+        let (world, guild) = world(100_000, 0, 900);
+        let sim = run(world, 20);
+        let surpluses: Vec<_> = sim
+            .events()
+            .events()
+            .iter()
+            .filter(|e| matches!(e.kind, SimEventKind::ResourceSurplus { .. }))
+            .collect();
+        assert_eq!(surpluses.len(), 1);
+        assert!(surpluses[0].kind.involves(guild));
+    }
+
+    #[test]
+    fn shortage_when_upkeep_exceeds_income() {
+        let (world, guild) = world(0, 4, 3);
+        let sim = run(world, 10);
+        let economy = sim.get_system::<EconomySystem>().unwrap();
+        assert_eq!(economy.wealth(guild), Some(0.0));
+        let shortages = sim
+            .events()
+            .events()
+            .iter()
+            .filter(|e| matches!(e.kind, SimEventKind::ResourceShortage { .. }))
+            .count();
+        assert_eq!(shortages, 1);
+    }
+}
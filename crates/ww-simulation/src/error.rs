@@ -19,6 +19,10 @@ pub enum SimError {
         to: EntityId,
     },
 
+    /// A simulation snapshot could not be read or restored.
+    #[error("invalid snapshot: {0}")]
+    InvalidSnapshot(String),
+
     /// A generic error from a simulation system.
     #[error("system error: {0}")]
     SystemError(String),
@@ -1,7 +1,11 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
 use ww_core::entity::EntityId;
 
 /// What kind of simulation event occurred.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum SimEventKind {
     // Needs
     /// A need dropped below the critical threshold.
@@ -53,6 +57,33 @@ pub enum SimEventKind {
         at: EntityId,
     },
 
+    // Weather
+    /// The weather over a region changed.
+    WeatherChanged {
+        /// The region whose weather changed.
+        region: EntityId,
+        /// The previous weather.
+        from: String,
+        /// The new weather.
+        to: String,
+    },
+
+    // Economy
+    /// A faction's wealth rose to the surplus threshold.
+    ResourceSurplus {
+        /// The faction in surplus.
+        faction: EntityId,
+        /// The faction's wealth, rounded.
+        wealth: i64,
+    },
+    /// A faction ran out of resources.
+    ResourceShortage {
+        /// The faction in shortage.
+        faction: EntityId,
+        /// The faction's wealth, rounded.
+        wealth: i64,
+    },
+
     // Lifecycle
     /// An entity died.
     EntityDied {
@@ -73,6 +104,23 @@ pub enum SimEventKind {
 }
 
 impl SimEventKind {
+    /// Short machine-readable name for the kind of event (e.g. `"need_critical"`).
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::NeedCritical { .. } => "need_critical",
+            Self::NeedSatisfied { .. } => "need_satisfied",
+            Self::NeedDepleted { .. } => "need_depleted",
+            Self::ActivityChanged { .. } => "activity_changed",
+            Self::Departed { .. } => "departed",
+            Self::Arrived { .. } => "arrived",
+            Self::WeatherChanged { .. } => "weather_changed",
+            Self::ResourceSurplus { .. } => "resource_surplus",
+            Self::ResourceShortage { .. } => "resource_shortage",
+            Self::EntityDied { .. } => "entity_died",
+            Self::Custom { .. } => "custom",
+        }
+    }
+
     /// Check whether a given entity is involved in this event.
     pub fn involves(&self, id: EntityId) -> bool {
         match self {
@@ -81,6 +129,10 @@ impl SimEventKind {
             | Self::NeedDepleted { entity, .. }
             | Self::ActivityChanged { entity, .. }
             | Self::EntityDied { entity, .. } => *entity == id,
+            Self::WeatherChanged { region, .. } => *region == id,
+            Self::ResourceSurplus { faction, .. } | Self::ResourceShortage { faction, .. } => {
+                *faction == id
+            }
             Self::Departed { entity, from } => *entity == id || *from == id,
             Self::Arrived { entity, at } => *entity == id || *at == id,
             Self::Custom { entities, .. } => entities.contains(&id),
@@ -88,8 +140,79 @@ impl SimEventKind {
     }
 }
 
+/// Which part of an entity's state an event touched.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChangeAspect {
+    /// Life status (e.g. the entity died).
+    Status,
+    /// Location or travel state.
+    Location,
+    /// Current scheduled activity.
+    Activity,
+    /// The weather over a region.
+    Weather,
+    /// A faction's resources.
+    Resources,
+    /// A tracked need, by name.
+    Need(String),
+    /// A custom event, by label.
+    Custom(String),
+}
+
+impl fmt::Display for ChangeAspect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Status => write!(f, "status"),
+            Self::Location => write!(f, "location"),
+            Self::Activity => write!(f, "activity"),
+            Self::Weather => write!(f, "weather"),
+            Self::Resources => write!(f, "resources"),
+            Self::Need(need) => write!(f, "{need}"),
+            Self::Custom(label) => write!(f, "{label}"),
+        }
+    }
+}
+
+/// An entity whose state changed during a tick, with what changed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EntityChange {
+    /// The entity that changed.
+    pub entity: EntityId,
+    /// The aspects that changed, in the order they were first touched.
+    pub aspects: Vec<ChangeAspect>,
+}
+
+impl SimEventKind {
+    /// The entities whose state this event changes, with the aspect touched.
+    ///
+    /// Locations in `Departed`/`Arrived` are only the setting of the move,
+    /// so only the traveller is reported.
+    pub fn changes(&self) -> Vec<(EntityId, ChangeAspect)> {
+        match self {
+            Self::NeedCritical { entity, need }
+            | Self::NeedSatisfied { entity, need }
+            | Self::NeedDepleted { entity, need } => {
+                vec![(*entity, ChangeAspect::Need(need.clone()))]
+            }
+            Self::ActivityChanged { entity, .. } => vec![(*entity, ChangeAspect::Activity)],
+            Self::Departed { entity, .. } | Self::Arrived { entity, .. } => {
+                vec![(*entity, ChangeAspect::Location)]
+            }
+            Self::WeatherChanged { region, .. } => vec![(*region, ChangeAspect::Weather)],
+            Self::ResourceSurplus { faction, .. } | Self::ResourceShortage { faction, .. } => {
+                vec![(*faction, ChangeAspect::Resources)]
+            }
+            Self::EntityDied { entity, .. } => vec![(*entity, ChangeAspect::Status)],
+            Self::Custom { label, entities } => entities
+                .iter()
+                .map(|e| (*e, ChangeAspect::Custom(label.clone())))
+                .collect(),
+        }
+    }
+}
+
 /// A record of something that happened during simulation.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SimEvent {
     /// The simulation tick when this event occurred.
     pub tick: u64,
@@ -111,7 +234,7 @@ impl SimEvent {
 }
 
 /// Accumulates events during a simulation run.
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct EventLog {
     events: Vec<SimEvent>,
     max_events: usize,
@@ -145,6 +268,32 @@ impl EventLog {
         self.events.iter().filter(|e| e.tick == tick).collect()
     }
 
+    /// Return the entities whose state changed at the given tick.
+    ///
+    /// Entities are listed in the order they first changed, each with the
+    /// distinct aspects that changed.
+    pub fn changes_at_tick(&self, tick: u64) -> Vec<EntityChange> {
+        let mut changes: Vec<EntityChange> = Vec::new();
+        for event in self.events.iter().filter(|e| e.tick == tick) {
+            for (entity, aspect) in event.kind.changes() {
+                let index = match changes.iter().position(|c| c.entity == entity) {
+                    Some(index) => index,
+                    None => {
+                        changes.push(EntityChange {
+                            entity,
+                            aspects: Vec::new(),
+                        });
+                        changes.len() - 1
+                    }
+                };
+                if !changes[index].aspects.contains(&aspect) {
+                    changes[index].aspects.push(aspect);
+                }
+            }
+        }
+        changes
+    }
+
     /// Return all events involving the given entity.
     pub fn events_for_entity(&self, id: EntityId) -> Vec<&SimEvent> {
         self.events.iter().filter(|e| e.kind.involves(id)).collect()
@@ -207,6 +356,44 @@ mod tests {
         assert_eq!(log.events()[1].tick, 4);
     }
 
+    #[test]
+    fn changes_at_tick_groups_by_entity() {
+        let kael = EntityId::new();
+        let mira = EntityId::new();
+        let square = EntityId::new();
+        let mut log = EventLog::new(0);
+        let need = |need: &str| SimEventKind::NeedCritical {
+            entity: kael,
+            need: need.into(),
+        };
+        log.push(SimEvent::new(1, need("hunger"), "earlier"));
+        log.push(SimEvent::new(2, need("hunger"), "hungry"));
+        log.push(SimEvent::new(
+            2,
+            SimEventKind::Departed {
+                entity: mira,
+                from: square,
+            },
+            "left",
+        ));
+        log.push(SimEvent::new(2, need("rest"), "tired"));
+        log.push(SimEvent::new(2, need("hunger"), "still hungry"));
+
+        let changes = log.changes_at_tick(2);
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].entity, kael);
+        assert_eq!(
+            changes[0].aspects,
+            vec![
+                ChangeAspect::Need("hunger".into()),
+                ChangeAspect::Need("rest".into())
+            ]
+        );
+        assert_eq!(changes[1].entity, mira);
+        assert_eq!(changes[1].aspects, vec![ChangeAspect::Location]);
+        assert!(log.changes_at_tick(3).is_empty());
+    }
+
     #[test]
     fn event_kind_involves_entity() {
         let e1 = EntityId::new();
@@ -5,26 +5,38 @@
 //! keeping ww-core clean. The simulation reads from and optionally writes to
 //! entity components when appropriate (e.g., updating character status on death).
 
+/// Markdown chronicles of a simulation's event history.
+pub mod chronicle;
 /// Simulation clock for tracking ticks and in-world time.
 pub mod clock;
 /// Configuration types for simulation runs.
 pub mod config;
 /// Mutable context passed to systems each tick.
 pub mod context;
+/// Single-entity diaries of a simulation run.
+pub mod diary;
+/// Economy system: tracks faction wealth from bases and upkeep.
+pub mod economy;
 /// Error types for the simulation crate.
 pub mod error;
 /// Simulation event types and the event log.
 pub mod event;
+/// Prose narration of simulation events.
+pub mod narrate;
 /// Needs system: tracks and decays entity needs like hunger and rest.
 pub mod needs;
 /// Schedule system: assigns time-based activities to entities.
 pub mod schedule;
 /// Top-level simulation orchestrator.
 pub mod simulation;
+/// Saving and restoring simulation runs.
+pub mod snapshot;
 /// Spatial system: tracks entity locations and movement.
 pub mod spatial;
 /// The trait that all simulation systems implement.
 pub mod system;
+/// Weather system: tracks per-region weather driven by climate.
+pub mod weather;
 
 /// Re-export of [`clock::SimClock`].
 pub use clock::SimClock;
@@ -34,9 +46,12 @@ pub use config::SimConfig;
 pub use context::SimContext;
 /// Re-exports of [`error::SimError`] and [`error::SimResult`].
 pub use error::{SimError, SimResult};
-/// Re-exports of [`event::EventLog`], [`event::SimEvent`], and [`event::SimEventKind`].
-pub use event::{EventLog, SimEvent, SimEventKind};
+/// Re-exports of [`event::EventLog`], [`event::SimEvent`], [`event::SimEventKind`],
+/// [`event::EntityChange`], and [`event::ChangeAspect`].
+pub use event::{ChangeAspect, EntityChange, EventLog, SimEvent, SimEventKind};
 /// Re-export of [`simulation::Simulation`].
 pub use simulation::Simulation;
+/// Re-export of [`snapshot::SimSnapshot`].
+pub use snapshot::SimSnapshot;
 /// Re-export of [`system::System`].
 pub use system::System;
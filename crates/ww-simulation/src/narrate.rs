@@ -0,0 +1,186 @@
+//! Prose narration of simulation events.
+//!
+//! Each [`SimEventKind`] maps to a sentence template. Worlds can override any
+//! template with a `simulation.narrate.<label>` property in the world block,
+//! where `<label>` is [`SimEventKind::label`]:
+//!
+//! ```ww
+//! world "The Iron Kingdoms" {
+//!     simulation {
+//!         narrate {
+//!             arrived "{entity} strides through the gates of {at}."
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! Templates may use `{entity}`, `{need}`, `{from}`, `{to}`, `{at}`,
+//! `{region}`, `{wealth}`, `{cause}`, `{label}`, and `{entities}`;
+//! placeholders an event does not provide are left as-is.
+
+use ww_core::World;
+use ww_core::entity::MetadataValue;
+use ww_core::text::capitalize;
+
+use crate::event::{SimEvent, SimEventKind};
+
+/// The built-in template for an event label, or `None` for unknown labels.
+pub fn default_template(label: &str) -> Option<&'static str> {
+    Some(match label {
+        "need_critical" => "{entity} is badly in need of {need}.",
+        "need_satisfied" => "{entity} finally finds some {need}.",
+        "need_depleted" => "{entity} can go on no longer without {need}.",
+        "activity_changed" => "{entity} turns from {from} to {to}.",
+        "departed" => "{entity} sets out from {from}.",
+        "arrived" => "{entity} arrives at {at}.",
+        "weather_changed" => "the weather over {region} turns from {from} to {to}.",
+        "resource_surplus" => "the coffers of {entity} swell to {wealth}.",
+        "resource_shortage" => "{entity} has run out of resources.",
+        "entity_died" => "{entity} is dead, of {cause}.",
+        "custom" => "{label}: {entities}.",
+        _ => return None,
+    })
+}
+
+/// Render a simulation event as a sentence of prose.
+pub fn narrate_event(event: &SimEvent, world: &World) -> String {
+    let label = event.kind.label();
+    let key = format!("simulation.narrate.{label}");
+    let template = match world.meta.properties.get(&key) {
+        Some(MetadataValue::String(t)) => t.as_str(),
+        _ => default_template(label).unwrap_or("{label}"),
+    };
+
+    let name = |id| world.entity_name(id).to_string();
+    let vars: Vec<(&str, String)> = match &event.kind {
+        SimEventKind::NeedCritical { entity, need }
+        | SimEventKind::NeedSatisfied { entity, need }
+        | SimEventKind::NeedDepleted { entity, need } => {
+            vec![("entity", name(*entity)), ("need", need_noun(need))]
+        }
+        SimEventKind::ActivityChanged { entity, from, to } => vec![
+            ("entity", name(*entity)),
+            ("from", from.clone()),
+            ("to", to.clone()),
+        ],
+        SimEventKind::Departed { entity, from } => {
+            vec![("entity", name(*entity)), ("from", name(*from))]
+        }
+        SimEventKind::Arrived { entity, at } => {
+            vec![("entity", name(*entity)), ("at", name(*at))]
+        }
+        SimEventKind::WeatherChanged { region, from, to } => vec![
+            ("region", name(*region)),
+            ("from", from.clone()),
+            ("to", to.clone()),
+        ],
+        SimEventKind::ResourceSurplus { faction, wealth }
+        | SimEventKind::ResourceShortage { faction, wealth } => {
+            vec![("entity", name(*faction)), ("wealth", wealth.to_string())]
+        }
+        SimEventKind::EntityDied { entity, cause } => {
+            vec![("entity", name(*entity)), ("cause", cause.clone())]
+        }
+        SimEventKind::Custom { label, entities } => {
+            let names: Vec<String> = entities.iter().map(|e| name(*e)).collect();
+            vec![("label", label.clone()), ("entities", names.join(", "))]
+        }
+    };
+
+    let mut text = template.to_string();
+    for (key, value) in vars {
+        text = text.replace(&format!("{{{key}}}"), &value);
+    }
+    capitalize(&text)
+}
+
+/// What an entity lacks when a need runs low, as a noun phrase.
+fn need_noun(need: &str) -> String {
+    match need {
+        "hunger" => "food",
+        "rest" => "rest",
+        "social" => "company",
+        "safety" => "safety",
+        other => other,
+    }
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ww_core::entity::{Entity, EntityKind};
+    use ww_core::{EntityId, WorldMeta};
+
+    fn world() -> (World, EntityId, EntityId) {
+        let mut world = World::new(WorldMeta::new("Testland"));
+        let kael = world
+            .add_entity(Entity::new(EntityKind::Character, "Kael"))
+            .unwrap();
+        let keep = world
+            .add_entity(Entity::new(EntityKind::Location, "the Keep"))
+            .unwrap();
+        (world, kael, keep)
+    }
+
+    #[test]
+    fn narrates_each_kind_with_defaults() {
+        let (world, kael, keep) = world();
+        let event = |kind| SimEvent::new(1, kind, "");
+
+        let arrived = event(SimEventKind::Arrived {
+            entity: kael,
+            at: keep,
+        });
+        assert_eq!(narrate_event(&arrived, &world), "Kael arrives at the Keep.");
+
+        let hungry = event(SimEventKind::NeedCritical {
+            entity: kael,
+            need: "hunger".to_string(),
+        });
+        assert_eq!(
+            narrate_event(&hungry, &world),
+            "Kael is badly in need of food."
+        );
+
+        let departed = event(SimEventKind::Departed {
+            entity: kael,
+            from: keep,
+        });
+        assert_eq!(
+            narrate_event(&departed, &world),
+            "Kael sets out from the Keep."
+        );
+
+        let weather = event(SimEventKind::WeatherChanged {
+            region: keep,
+            from: "clear".to_string(),
+            to: "rain".to_string(),
+        });
+        assert_eq!(
+            narrate_event(&weather, &world),
+            "The weather over the Keep turns from clear to rain."
+        );
+    }
+
+    #[test]
+    fn world_meta_overrides_templates() {
+        let (mut world, kael, keep) = world();
+        world.meta.properties.insert(
+            "simulation.narrate.arrived".to_string(),
+            MetadataValue::String("the gates of {at} open for {entity}".to_string()),
+        );
+        let arrived = SimEvent::new(
+            1,
+            SimEventKind::Arrived {
+                entity: kael,
+                at: keep,
+            },
+            "",
+        );
+        assert_eq!(
+            narrate_event(&arrived, &world),
+            "The gates of the Keep open for Kael"
+        );
+    }
+}
@@ -1,15 +1,18 @@
 use std::collections::HashMap;
 
+use serde::{Deserialize, Serialize};
 use ww_core::component::CharacterStatus;
 use ww_core::entity::{EntityId, EntityKind};
 
 use crate::context::SimContext;
 use crate::error::SimResult;
 use crate::event::SimEventKind;
+use crate::snapshot::{from_state, to_state};
 use crate::system::System;
 
 /// Built-in need categories.
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(into = "String", from = "String")]
 pub enum NeedKind {
     /// The need for food.
     Hunger,
@@ -35,8 +38,27 @@ impl std::fmt::Display for NeedKind {
     }
 }
 
+impl From<String> for NeedKind {
+    /// Built-in needs by name (case-insensitive); anything else is custom.
+    fn from(name: String) -> Self {
+        match name.to_lowercase().as_str() {
+            "hunger" => Self::Hunger,
+            "rest" => Self::Rest,
+            "social" => Self::Social,
+            "safety" => Self::Safety,
+            _ => Self::Custom(name),
+        }
+    }
+}
+
+impl From<NeedKind> for String {
+    fn from(need: NeedKind) -> Self {
+        need.to_string()
+    }
+}
+
 /// Current need levels for a single entity. Each value is 0.0..=1.0.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NeedState {
     /// Current level for each tracked need (0.0 = depleted, 1.0 = fully satisfied).
     pub levels: HashMap<NeedKind, f64>,
@@ -189,14 +211,7 @@ impl System for NeedsSystem {
             if let Some(custom) = sim_comp.and_then(|s| s.initial_needs.as_ref()) {
                 let mut levels = HashMap::new();
                 for (name, val) in custom {
-                    let kind = match name.to_lowercase().as_str() {
-                        "hunger" => NeedKind::Hunger,
-                        "rest" => NeedKind::Rest,
-                        "social" => NeedKind::Social,
-                        "safety" => NeedKind::Safety,
-                        _ => NeedKind::Custom(name.clone()),
-                    };
-                    levels.insert(kind, *val);
+                    levels.insert(NeedKind::from(name.clone()), *val);
                 }
                 // Fill in any missing needs from config at 1.0
                 for need in &self.config.needs {
@@ -211,7 +226,8 @@ impl System for NeedsSystem {
     }
 
     fn tick(&mut self, ctx: &mut SimContext<'_>) -> SimResult<()> {
-        let ids: Vec<EntityId> = self.states.keys().copied().collect();
+        let mut ids: Vec<EntityId> = self.states.keys().copied().collect();
+        ids.sort_by_key(|id| id.0);
 
         for id in ids {
             if !Self::is_alive(ctx, id) {
@@ -261,6 +277,15 @@ impl System for NeedsSystem {
         Ok(())
     }
 
+    fn save_state(&self) -> Option<serde_json::Value> {
+        to_state(&self.states)
+    }
+
+    fn load_state(&mut self, state: serde_json::Value) -> SimResult<()> {
+        self.states = from_state(state)?;
+        Ok(())
+    }
+
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
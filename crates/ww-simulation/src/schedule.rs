@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+use serde::{Deserialize, Serialize};
 use ww_core::component::CharacterStatus;
 use ww_core::entity::{EntityId, EntityKind};
 
@@ -7,10 +8,11 @@ use crate::context::SimContext;
 use crate::error::SimResult;
 use crate::event::SimEventKind;
 use crate::needs::NeedKind;
+use crate::snapshot::{from_state, to_state};
 use crate::system::System;
 
 /// An activity that an NPC can perform.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Activity {
     /// Sleeping or resting to recover energy.
     Rest,
@@ -46,7 +48,7 @@ impl std::fmt::Display for Activity {
 }
 
 /// A single schedule entry: from hour_start to hour_end, do activity.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ScheduleEntry {
     /// The starting hour of this time slot (0.0..24.0).
     pub hour_start: f64,
@@ -78,7 +80,7 @@ impl ScheduleEntry {
 }
 
 /// A daily schedule: ordered list of time-slotted activities.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Schedule {
     /// The ordered list of time-slotted activity entries.
     pub entries: Vec<ScheduleEntry>,
@@ -225,7 +227,8 @@ impl System for ScheduleSystem {
 
     fn tick(&mut self, ctx: &mut SimContext<'_>) -> SimResult<()> {
         let hour = ctx.hour_of_day();
-        let ids: Vec<EntityId> = self.schedules.keys().copied().collect();
+        let mut ids: Vec<EntityId> = self.schedules.keys().copied().collect();
+        ids.sort_by_key(|id| id.0);
 
         for id in ids {
             let schedule = match self.schedules.get(&id) {
@@ -264,6 +267,15 @@ impl System for ScheduleSystem {
         Ok(())
     }
 
+    fn save_state(&self) -> Option<serde_json::Value> {
+        to_state(&(&self.schedules, &self.current_activities))
+    }
+
+    fn load_state(&mut self, state: serde_json::Value) -> SimResult<()> {
+        (self.schedules, self.current_activities) = from_state(state)?;
+        Ok(())
+    }
+
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
@@ -1,14 +1,17 @@
 use rand::SeedableRng;
-use rand::rngs::StdRng;
+use ww_core::entity::EntityId;
 use ww_core::world::World;
 
 use crate::clock::SimClock;
 use crate::config::SimConfig;
-use crate::context::SimContext;
-use crate::error::SimResult;
-use crate::event::EventLog;
+use crate::context::{SimContext, SimRng};
+use crate::diary::DiaryEntry;
+use crate::error::{SimError, SimResult};
+use crate::event::{EntityChange, EventLog};
 use crate::needs::NeedsSystem;
 use crate::schedule::ScheduleSystem;
+use crate::snapshot::SimSnapshot;
+use crate::spatial::SpatialSystem;
 use crate::system::System;
 
 /// The top-level simulation orchestrator.
@@ -18,10 +21,12 @@ use crate::system::System;
 pub struct Simulation {
     world: World,
     clock: SimClock,
-    rng: StdRng,
+    rng: SimRng,
     events: EventLog,
     systems: Vec<Box<dyn System>>,
     initialized: bool,
+    focus: Option<EntityId>,
+    diary: Vec<DiaryEntry>,
 }
 
 impl std::fmt::Debug for Simulation {
@@ -38,7 +43,7 @@ impl Simulation {
     /// Create a new simulation from a world and configuration.
     pub fn new(world: World, config: SimConfig) -> Self {
         let clock = SimClock::new(config.start_date, config.hours_per_tick);
-        let rng = StdRng::seed_from_u64(config.seed);
+        let rng = SimRng::seed_from_u64(config.seed);
         let events = EventLog::new(config.max_events);
         Self {
             world,
@@ -47,6 +52,8 @@ impl Simulation {
             events,
             systems: Vec::new(),
             initialized: false,
+            focus: None,
+            diary: Vec::new(),
         }
     }
 
@@ -96,6 +103,10 @@ impl Simulation {
         }
 
         self.apply_cross_system_effects();
+        if let Some(entity) = self.focus {
+            let entry = self.diary_entry(entity);
+            self.diary.push(entry);
+        }
         Ok(())
     }
 
@@ -133,6 +144,119 @@ impl Simulation {
         }
     }
 
+    /// Capture the world, clock, RNG, event log, and system state.
+    ///
+    /// Restoring the snapshot into a simulation with the same systems and
+    /// continuing produces the same events as never having stopped.
+    pub fn snapshot(&self) -> SimSnapshot {
+        SimSnapshot {
+            world: self.world.to_json_value(),
+            clock: self.clock.clone(),
+            rng: self.rng.clone(),
+            events: self.events.clone(),
+            initialized: self.initialized,
+            systems: self
+                .systems
+                .iter()
+                .filter_map(|s| Some((s.name().to_string(), s.save_state()?)))
+                .collect(),
+        }
+    }
+
+    /// Resume from a snapshot taken by [`Simulation::snapshot`].
+    ///
+    /// Register the same systems first: each one found in the snapshot has
+    /// its state loaded instead of being initialized. Any focus and diary
+    /// are cleared.
+    pub fn restore(&mut self, snapshot: SimSnapshot) -> SimResult<()> {
+        self.world = snapshot.world()?;
+        for system in &mut self.systems {
+            if let Some(state) = snapshot.systems.get(system.name()) {
+                system.load_state(state.clone()).map_err(|e| {
+                    SimError::InvalidSnapshot(format!("{} system: {e}", system.name()))
+                })?;
+            }
+        }
+        self.clock = snapshot.clock;
+        self.rng = snapshot.rng;
+        self.events = snapshot.events;
+        self.initialized = snapshot.initialized;
+        self.focus = None;
+        self.diary.clear();
+        Ok(())
+    }
+
+    /// Follow one entity, recording a [`DiaryEntry`] for it after every tick.
+    ///
+    /// The simulation itself runs as usual; focusing only adds the diary.
+    /// Refocusing clears the entries recorded so far.
+    pub fn focus(&mut self, entity: EntityId) {
+        self.focus = Some(entity);
+        self.diary.clear();
+    }
+
+    /// The focused entity, if any.
+    pub fn focused(&self) -> Option<EntityId> {
+        self.focus
+    }
+
+    /// Diary entries recorded for the focused entity, oldest first.
+    pub fn diary(&self) -> &[DiaryEntry] {
+        &self.diary
+    }
+
+    /// Render the focused entity's diary as text, if an entity is focused.
+    pub fn render_diary(&self) -> Option<String> {
+        let entity = self.focus?;
+        Some(crate::diary::render(
+            &self.world,
+            &self.clock,
+            entity,
+            &self.diary,
+        ))
+    }
+
+    /// Snapshot an entity's needs, activity, location, and events this tick.
+    fn diary_entry(&self, entity: EntityId) -> DiaryEntry {
+        let tick = self.clock.tick();
+        let mut needs: Vec<_> = self
+            .get_system::<NeedsSystem>()
+            .and_then(|n| n.get_state(entity))
+            .map(|state| {
+                state
+                    .levels
+                    .iter()
+                    .map(|(need, level)| (need.clone(), *level))
+                    .collect()
+            })
+            .unwrap_or_default();
+        needs.sort_by_key(|(need, _)| need.to_string());
+
+        let spatial = self
+            .get_system::<SpatialSystem>()
+            .and_then(|s| s.get_state(entity));
+
+        DiaryEntry {
+            tick,
+            activity: self
+                .get_system::<ScheduleSystem>()
+                .and_then(|s| s.current_activity(entity))
+                .map(|a| a.to_string()),
+            location: spatial.map(|s| s.current_location),
+            destination: spatial
+                .filter(|s| s.is_traveling())
+                .and_then(|s| s.destination),
+            needs,
+            events: self
+                .events
+                .events_at_tick(tick)
+                .into_iter()
+                .filter(|e| e.kind.involves(entity))
+                .cloned()
+                .collect(),
+        }
+    }
+
     /// Return a reference to the simulation world.
     pub fn world(&self) -> &World {
         &self.world
@@ -153,6 +277,16 @@ impl Simulation {
         &self.events
     }
 
+    /// Entities whose state changed during the most recent tick.
+    pub fn changed_entities(&self) -> Vec<EntityChange> {
+        self.events.changes_at_tick(self.clock.tick())
+    }
+
+    /// Render the event history so far as a Markdown chronicle.
+    pub fn chronicle(&self) -> String {
+        crate::chronicle::chronicle(&self.world, &self.clock, &self.events)
+    }
+
     /// Access a system by downcasting to a concrete type.
     pub fn get_system<T: System + 'static>(&self) -> Option<&T> {
         self.systems
@@ -200,6 +334,7 @@ impl System for NoopSystem {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::event::SimEventKind;
     use crate::needs::{NeedsConfig, NeedsSystem};
     use crate::schedule::ScheduleSystem;
     use crate::spatial::SpatialSystem;
@@ -266,6 +401,42 @@ mod tests {
         assert!(custom.ticked);
     }
 
+    #[test]
+    fn changed_entities_reports_current_tick() {
+        #[derive(Debug)]
+        struct Observer {
+            seen: Vec<usize>,
+        }
+        impl System for Observer {
+            fn name(&self) -> &str {
+                "observer"
+            }
+            fn tick(&mut self, ctx: &mut SimContext<'_>) -> SimResult<()> {
+                self.seen.push(ctx.changed_entities().len());
+                Ok(())
+            }
+            fn as_any(&self) -> &dyn std::any::Any {
+                self
+            }
+            fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+                self
+            }
+        }
+
+        let (world, id) = test_world_with_character();
+        let mut sim = Simulation::new(world, SimConfig::default());
+        sim.add_system(ScheduleSystem::new());
+        sim.add_system(Observer { seen: Vec::new() });
+
+        sim.tick().unwrap();
+        let changes = sim.changed_entities();
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].entity, id);
+        assert_eq!(changes[0].aspects, vec![crate::ChangeAspect::Activity]);
+        // The observer runs after the schedule system and sees its change
+        assert_eq!(sim.get_system::<Observer>().unwrap().seen, vec![1]);
+    }
+
     #[test]
     fn deterministic_rng() {
         let make_sim = || {
@@ -404,6 +575,36 @@ mod tests {
         assert!(!state.is_traveling());
     }
 
+    #[test]
+    fn focus_records_a_diary_entry_per_tick() {
+        let (mut world, kael) = test_world_with_character();
+        let mut mira = Entity::new(EntityKind::Character, "Mira");
+        mira.components.character = Some(CharacterComponent {
+            status: CharacterStatus::Alive,
+            ..Default::default()
+        });
+        let mira = world.add_entity(mira).unwrap();
+
+        let mut sim = Simulation::new(world, SimConfig::default());
+        sim.add_system(NeedsSystem::with_default_config());
+        sim.add_system(ScheduleSystem::new());
+        sim.focus(kael);
+        sim.run(3).unwrap();
+
+        let diary = sim.diary();
+        assert_eq!(diary.len(), 3);
+        assert_eq!(diary[0].tick, 1);
+        assert!(diary[0].activity.is_some());
+        assert_eq!(diary[0].needs.len(), 4);
+        assert!(
+            diary
+                .iter()
+                .flat_map(|e| &e.events)
+                .all(|e| e.kind.involves(kael) && !e.kind.involves(mira))
+        );
+        assert!(sim.render_diary().unwrap().starts_with("Diary of Kael\n"));
+    }
+
     #[test]
     fn init_is_idempotent() {
         let (world, _) = test_world_with_character();
@@ -560,6 +761,69 @@ mod tests {
         assert!(sim.get_system::<SpatialSystem>().is_none());
     }
 
+    #[test]
+    fn restored_snapshot_continues_identically() {
+        let (mut world, kael) = test_world_with_character();
+        let mut vale = Entity::new(EntityKind::Location, "Misty Vale");
+        vale.components.location = Some(ww_core::component::LocationComponent {
+            climate: Some("temperate".to_string()),
+            ..Default::default()
+        });
+        let vale = world.add_entity(vale).unwrap();
+        world
+            .add_relationship(Relationship::new(kael, RelationshipKind::LocatedAt, vale))
+            .unwrap();
+        let make_sim = || {
+            let world = world.clone();
+            let mut sim = Simulation::new(world, SimConfig::default().with_seed(99));
+            sim.add_system(NeedsSystem::with_default_config());
+            sim.add_system(ScheduleSystem::new());
+            sim.add_system(SpatialSystem::new());
+            sim.add_system(crate::weather::WeatherSystem::new().with_change_chance(0.5));
+            sim
+        };
+        let history = |sim: &Simulation| -> Vec<(u64, SimEventKind, String)> {
+            sim.events()
+                .events()
+                .iter()
+                .map(|e| (e.tick, e.kind.clone(), e.description.clone()))
+                .collect()
+        };
+
+        let mut straight = make_sim();
+        straight.run(60).unwrap();
+
+        let mut first = make_sim();
+        first.run(30).unwrap();
+        let json = first.snapshot().to_json();
+
+        let mut resumed = make_sim();
+        resumed
+            .restore(SimSnapshot::from_json(&json).unwrap())
+            .unwrap();
+        assert_eq!(resumed.current_tick(), 30);
+        resumed.run(30).unwrap();
+
+        assert_eq!(history(&resumed), history(&straight));
+        assert_eq!(
+            resumed.world().to_json_value(),
+            straight.world().to_json_value()
+        );
+    }
+
+    #[test]
+    fn restore_rejects_bad_snapshot() {
+        assert!(SimSnapshot::from_json("{}").is_err());
+        let mut snapshot =
+            Simulation::new(World::new(WorldMeta::new("Test")), SimConfig::default()).snapshot();
+        snapshot.world = serde_json::json!({"schema_version": 999});
+        let mut sim = Simulation::new(World::new(WorldMeta::new("Test")), SimConfig::default());
+        assert!(matches!(
+            sim.restore(snapshot),
+            Err(SimError::InvalidSnapshot(_))
+        ));
+    }
+
     #[test]
     fn simulation_debug_format() {
         let world = World::new(WorldMeta::new("Test"));
@@ -0,0 +1,60 @@
+use std::collections::BTreeMap;
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use ww_core::World;
+
+use crate::clock::SimClock;
+use crate::context::SimRng;
+use crate::error::{SimError, SimResult};
+use crate::event::EventLog;
+
+/// Everything needed to resume a simulation run exactly where it stopped.
+///
+/// Taken with [`Simulation::snapshot`](crate::Simulation::snapshot) and
+/// applied with [`Simulation::restore`](crate::Simulation::restore). The
+/// world is stored in its versioned JSON form; system state is keyed by
+/// [`System::name`](crate::System::name).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimSnapshot {
+    /// The world as written by [`World::to_json_value`].
+    pub world: serde_json::Value,
+    /// The clock, including the current tick.
+    pub clock: SimClock,
+    /// The random number generator, mid-stream.
+    pub rng: SimRng,
+    /// Events recorded so far.
+    pub events: EventLog,
+    /// Whether the systems had been initialized.
+    pub initialized: bool,
+    /// Saved state of each system that has any, by system name.
+    pub systems: BTreeMap<String, serde_json::Value>,
+}
+
+impl SimSnapshot {
+    /// Serialize the snapshot to pretty-printed JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+
+    /// Read a snapshot written by [`SimSnapshot::to_json`].
+    pub fn from_json(s: &str) -> SimResult<Self> {
+        serde_json::from_str(s).map_err(|e| SimError::InvalidSnapshot(e.to_string()))
+    }
+
+    /// Rebuild the snapshot's world.
+    pub fn world(&self) -> SimResult<World> {
+        World::from_json(&self.world.to_string())
+            .map_err(|e| SimError::InvalidSnapshot(e.to_string()))
+    }
+}
+
+/// Serialize a system's state for [`System::save_state`](crate::System::save_state).
+pub(crate) fn to_state<T: Serialize>(state: &T) -> Option<serde_json::Value> {
+    serde_json::to_value(state).ok()
+}
+
+/// Deserialize a system's state for [`System::load_state`](crate::System::load_state).
+pub(crate) fn from_state<T: DeserializeOwned>(state: serde_json::Value) -> SimResult<T> {
+    serde_json::from_value(state).map_err(|e| SimError::InvalidSnapshot(e.to_string()))
+}
@@ -1,5 +1,6 @@
 use std::collections::{HashMap, VecDeque};
 
+use serde::{Deserialize, Serialize};
 use ww_core::entity::{EntityId, EntityKind};
 use ww_core::relationship::RelationshipKind;
 use ww_core::world::World;
@@ -7,10 +8,11 @@ use ww_core::world::World;
 use crate::context::SimContext;
 use crate::error::{SimError, SimResult};
 use crate::event::SimEventKind;
+use crate::snapshot::{from_state, to_state};
 use crate::system::System;
 
 /// Spatial simulation state for a single entity.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SpatialState {
     /// The entity's current location.
     pub current_location: EntityId,
@@ -227,7 +229,8 @@ impl System for SpatialSystem {
     }
 
     fn tick(&mut self, ctx: &mut SimContext<'_>) -> SimResult<()> {
-        let ids: Vec<EntityId> = self.states.keys().copied().collect();
+        let mut ids: Vec<EntityId> = self.states.keys().copied().collect();
+        ids.sort_by_key(|id| id.0);
 
         for id in ids {
             let state = match self.states.get_mut(&id) {
@@ -286,6 +289,15 @@ impl System for SpatialSystem {
         Ok(())
     }
 
+    fn save_state(&self) -> Option<serde_json::Value> {
+        to_state(&self.states)
+    }
+
+    fn load_state(&mut self, state: serde_json::Value) -> SimResult<()> {
+        self.states = from_state(state)?;
+        Ok(())
+    }
+
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
@@ -18,6 +18,19 @@ pub trait System: std::fmt::Debug {
         Ok(())
     }
 
+    /// Serialize this system's state for a
+    /// [`SimSnapshot`](crate::snapshot::SimSnapshot). Stateless systems
+    /// return `None`.
+    fn save_state(&self) -> Option<serde_json::Value> {
+        None
+    }
+
+    /// Restore state written by [`System::save_state`]. Called instead of
+    /// [`System::init`] when a simulation is restored from a snapshot.
+    fn load_state(&mut self, _state: serde_json::Value) -> SimResult<()> {
+        Ok(())
+    }
+
     /// Support downcasting to concrete types for cross-system communication.
     fn as_any(&self) -> &dyn std::any::Any;
 
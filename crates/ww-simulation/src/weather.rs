@@ -0,0 +1,348 @@
+use std::collections::HashMap;
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use ww_core::World;
+use ww_core::entity::{EntityId, EntityKind, MetadataValue};
+
+use crate::context::SimContext;
+use crate::error::SimResult;
+use crate::event::SimEventKind;
+use crate::snapshot::{from_state, to_state};
+use crate::system::System;
+
+/// The property a region's current weather is written to.
+pub const WEATHER_PROPERTY: &str = "weather";
+
+/// The weather over a region.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Weather {
+    /// Clear skies.
+    Clear,
+    /// Rainfall.
+    Rain,
+    /// A storm.
+    Storm,
+    /// Snowfall.
+    Snow,
+}
+
+impl Weather {
+    /// All weather states, in the order used by [`climate_weights`].
+    pub const ALL: [Weather; 4] = [Self::Clear, Self::Rain, Self::Storm, Self::Snow];
+
+    /// Parse a weather name as written to the `weather` property.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "clear" => Some(Self::Clear),
+            "rain" => Some(Self::Rain),
+            "storm" => Some(Self::Storm),
+            "snow" => Some(Self::Snow),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for Weather {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Clear => write!(f, "clear"),
+            Self::Rain => write!(f, "rain"),
+            Self::Storm => write!(f, "storm"),
+            Self::Snow => write!(f, "snow"),
+        }
+    }
+}
+
+/// Relative likelihood of clear, rain, storm, and snow for a climate.
+///
+/// Unknown climates are treated as temperate.
+pub fn climate_weights(climate: &str) -> [u32; 4] {
+    let climate = climate.to_lowercase();
+    let has = |words: &[&str]| words.iter().any(|w| climate.contains(w));
+    if has(&["arid", "desert", "dry"]) {
+        [85, 10, 5, 0]
+    } else if has(&["arctic", "polar", "tundra", "cold", "frozen", "alpine"]) {
+        [35, 5, 15, 45]
+    } else if has(&["tropical", "jungle", "humid", "wet", "monsoon"]) {
+        [40, 40, 20, 0]
+    } else {
+        [55, 30, 10, 5]
+    }
+}
+
+/// The weather at a location: its own `weather` property, or that of the
+/// nearest enclosing location that has one.
+pub fn weather_at(world: &World, location: EntityId) -> Option<Weather> {
+    let mut current = Some(location);
+    let mut hops = 0;
+    while let Some(id) = current
+        && hops <= world.entity_count()
+    {
+        let entity = world.get_entity(id)?;
+        if let Some(MetadataValue::String(name)) = entity.properties.get(WEATHER_PROPERTY) {
+            return Weather::from_name(name);
+        }
+        current = entity
+            .components
+            .location
+            .as_ref()
+            .and_then(|l| l.parent_location);
+        hops += 1;
+    }
+    None
+}
+
+/// Tracks the weather over every location with a `climate`.
+///
+/// Each tick, a region's weather changes with probability `change_chance`,
+/// to a state drawn from its climate's weights. Changes are written to the
+/// region's `weather` property and emitted as `WeatherChanged` events.
+/// All randomness comes from the simulation's seeded RNG, and regions are
+/// visited in a fixed order, so the same seed replays the same weather.
+#[derive(Debug)]
+pub struct WeatherSystem {
+    change_chance: f64,
+    regions: Vec<(EntityId, [u32; 4])>,
+    states: HashMap<EntityId, Weather>,
+}
+
+impl WeatherSystem {
+    /// Create a weather system where weather shifts 10% of ticks.
+    pub fn new() -> Self {
+        Self {
+            change_chance: 0.1,
+            regions: Vec::new(),
+            states: HashMap::new(),
+        }
+    }
+
+    /// Set the per-tick chance (0.0..=1.0) that a region's weather shifts.
+    pub fn with_change_chance(mut self, chance: f64) -> Self {
+        self.change_chance = chance.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Return the current weather over a region, if tracked.
+    pub fn weather(&self, region: EntityId) -> Option<Weather> {
+        self.states.get(&region).copied()
+    }
+
+    /// Return all tracked weather states keyed by region ID.
+    pub fn all_weather(&self) -> &HashMap<EntityId, Weather> {
+        &self.states
+    }
+
+    fn draw(rng: &mut impl Rng, weights: &[u32; 4]) -> Weather {
+        let total: u32 = weights.iter().sum();
+        let mut roll = rng.random_range(0..total.max(1));
+        for (weather, &weight) in Weather::ALL.iter().zip(weights) {
+            if roll < weight {
+                return *weather;
+            }
+            roll -= weight;
+        }
+        Weather::Clear
+    }
+
+    fn record(ctx: &mut SimContext<'_>, region: EntityId, weather: Weather) {
+        if let Some(entity) = ctx.world.get_entity_mut(region) {
+            entity.properties.insert(
+                WEATHER_PROPERTY.to_string(),
+                MetadataValue::String(weather.to_string()),
+            );
+        }
+    }
+}
+
+impl Default for WeatherSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl System for WeatherSystem {
+    fn name(&self) -> &str {
+        "weather"
+    }
+
+    fn init(&mut self, ctx: &mut SimContext<'_>) -> SimResult<()> {
+        let mut regions: Vec<_> = ctx
+            .world
+            .entities_by_kind(&EntityKind::Location)
+            .iter()
+            .filter_map(|e| {
+                let climate = e.components.location.as_ref()?.climate.as_deref()?;
+                Some((e.name.to_lowercase(), e.id, climate_weights(climate)))
+            })
+            .collect();
+        regions.sort_by(|a, b| a.0.cmp(&b.0));
+        self.regions = regions
+            .into_iter()
+            .map(|(_, id, weights)| (id, weights))
+            .collect();
+
+        for &(id, weights) in &self.regions {
+            let preset =
+                ctx.world
+                    .get_entity(id)
+                    .and_then(|e| match e.properties.get(WEATHER_PROPERTY) {
+                        Some(MetadataValue::String(name)) => Weather::from_name(name),
+                        _ => None,
+                    });
+            let weather = preset.unwrap_or_else(|| Self::draw(ctx.rng, &weights));
+            self.states.insert(id, weather);
+            Self::record(ctx, id, weather);
+        }
+        Ok(())
+    }
+
+    fn tick(&mut self, ctx: &mut SimContext<'_>) -> SimResult<()> {
+        for &(id, weights) in &self.regions {
+            if ctx.rng.random::<f64>() >= self.change_chance {
+                continue;
+            }
+            let next = Self::draw(ctx.rng, &weights);
+            let prev = self.states.insert(id, next).unwrap_or(next);
+            if prev == next {
+                continue;
+            }
+            Self::record(ctx, id, next);
+            ctx.emit(
+                SimEventKind::WeatherChanged {
+                    region: id,
+                    from: prev.to_string(),
+                    to: next.to_string(),
+                },
+                format!(
+                    "The weather over {} turns from {prev} to {next}",
+                    ctx.world.entity_name(id)
+                ),
+            );
+        }
+        Ok(())
+    }
+
+    fn save_state(&self) -> Option<serde_json::Value> {
+        to_state(&(&self.regions, &self.states))
+    }
+
+    fn load_state(&mut self, state: serde_json::Value) -> SimResult<()> {
+        (self.regions, self.states) = from_state(state)?;
+        Ok(())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::SimConfig;
+    use crate::simulation::Simulation;
+    use ww_core::WorldMeta;
+    use ww_core::component::LocationComponent;
+    use ww_core::entity::Entity;
+
+    fn region(world: &mut World, name: &str, climate: &str) -> EntityId {
+        let mut entity = Entity::new(EntityKind::Location, name);
+        entity.components.location = Some(LocationComponent {
+            location_type: "region".to_string(),
+            climate: Some(climate.to_string()),
+            ..Default::default()
+        });
+        world.add_entity(entity).unwrap()
+    }
+
+    fn world() -> (World, EntityId, EntityId) {
+        let mut world = World::new(WorldMeta::new("Weather Test"));
+        let north = region(&mut world, "Frostmarch", "arctic");
+        let south = region(&mut world, "Sunreach", "arid");
+        (world, north, south)
+    }
+
+    fn weather_history(seed: u64) -> Vec<(u64, String)> {
+        let (world, _, _) = world();
+        let mut sim = Simulation::new(world, SimConfig::default().with_seed(seed));
+        sim.add_system(WeatherSystem::new().with_change_chance(0.5));
+        sim.init().unwrap();
+        sim.run(100).unwrap();
+        sim.events()
+            .events()
+            .iter()
+            .map(|e| (e.tick, e.description.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn climate_weights_by_climate() {
+        assert_eq!(climate_weights("Arid")[3], 0);
+        assert!(climate_weights("polar tundra")[3] > climate_weights("temperate")[3]);
+        assert_eq!(climate_weights("unheard of"), climate_weights("temperate"));
+    }
+
+    #[test]
+    fn init_writes_weather_property() {
+        let (world, north, south) = world();
+        let mut sim = Simulation::new(world, SimConfig::default());
+        sim.add_system(WeatherSystem::new());
+        sim.init().unwrap();
+
+        let system = sim.get_system::<WeatherSystem>().unwrap();
+        assert_eq!(system.all_weather().len(), 2);
+        assert_eq!(weather_at(sim.world(), north), system.weather(north));
+        assert_ne!(system.weather(south), Some(Weather::Snow));
+    }
+
+    #[test]
+    fn weather_changes_emit_events() {
+        let (world, _, _) = world();
+        let mut sim = Simulation::new(world, SimConfig::default());
+        sim.add_system(WeatherSystem::new().with_change_chance(1.0));
+        sim.init().unwrap();
+        sim.run(50).unwrap();
+
+        let changes: Vec<_> = sim
+            .events()
+            .events()
+            .iter()
+            .filter(|e| matches!(e.kind, SimEventKind::WeatherChanged { .. }))
+            .collect();
+        assert!(!changes.is_empty());
+        let system = sim.get_system::<WeatherSystem>().unwrap();
+        for (&id, &weather) in system.all_weather() {
+            assert_eq!(weather_at(sim.world(), id), Some(weather));
+        }
+    }
+
+    #[test]
+    fn same_seed_replays_same_weather() {
+        assert_eq!(weather_history(7), weather_history(7));
+        assert_ne!(weather_history(7), weather_history(8));
+    }
+
+    #[test]
+    fn weather_at_inherits_from_parent() {
+        let (mut world, north, _) = world();
+        let mut hut = Entity::new(EntityKind::Location, "Trapper's Hut");
+        hut.components.location = Some(LocationComponent {
+            parent_location: Some(north),
+            ..Default::default()
+        });
+        let hut = world.add_entity(hut).unwrap();
+        assert_eq!(weather_at(&world, hut), None);
+
+        world.get_entity_mut(north).unwrap().properties.insert(
+            WEATHER_PROPERTY.to_string(),
+            MetadataValue::String("snow".to_string()),
+        );
+        assert_eq!(weather_at(&world, hut), Some(Weather::Snow));
+    }
+}
@@ -1,25 +1,56 @@
 //! Chaos factor tracking for the solo engine.
 //!
-//! The chaos factor (1-9) represents how unpredictable the story has become.
-//! Higher chaos increases the chance of "Yes" answers from the oracle and
-//! makes scene interruptions more likely.
+//! The chaos factor (1-9 by default) represents how unpredictable the story
+//! has become. Higher chaos increases the chance of "Yes" answers from the
+//! oracle and makes scene interruptions more likely. Variants with other
+//! ranges set their own bounds; the fate chart scales to them.
 
 use serde::{Deserialize, Serialize};
 
-/// The chaos factor, ranging from 1 (orderly) to 9 (chaotic).
+use crate::oracle::fate_chart::scale_chaos;
+
+/// Default lowest chaos value.
+pub const DEFAULT_CHAOS_MIN: u32 = 1;
+
+/// Default highest chaos value.
+pub const DEFAULT_CHAOS_MAX: u32 = 9;
+
+/// The chaos factor, ranging from `min` (orderly) to `max` (chaotic).
 ///
 /// Starts at 5 by default. Increases when scenes go badly for the player,
 /// decreases when they go well.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ChaosFactor {
     value: u32,
+    #[serde(default = "default_min")]
+    min: u32,
+    #[serde(default = "default_max")]
+    max: u32,
+}
+
+fn default_min() -> u32 {
+    DEFAULT_CHAOS_MIN
+}
+
+fn default_max() -> u32 {
+    DEFAULT_CHAOS_MAX
 }
 
 impl ChaosFactor {
     /// Create a new chaos factor, clamped to 1-9.
     pub fn new(value: u32) -> Self {
+        Self::with_bounds(value, DEFAULT_CHAOS_MIN, DEFAULT_CHAOS_MAX)
+    }
+
+    /// Create a chaos factor with custom bounds, clamped to them.
+    ///
+    /// A `max` below `min` is raised to `min`.
+    pub fn with_bounds(value: u32, min: u32, max: u32) -> Self {
+        let max = max.max(min);
         Self {
-            value: value.clamp(1, 9),
+            value: value.clamp(min, max),
+            min,
+            max,
         }
     }
 
@@ -28,14 +59,29 @@ impl ChaosFactor {
         self.value
     }
 
-    /// Increase chaos by 1 (max 9). Called when a scene goes badly.
+    /// The lowest chaos value.
+    pub fn min(&self) -> u32 {
+        self.min
+    }
+
+    /// The highest chaos value.
+    pub fn max(&self) -> u32 {
+        self.max
+    }
+
+    /// The value mapped onto the fate chart's 1-9 columns.
+    pub fn chart_value(&self) -> u32 {
+        scale_chaos(self.value, self.min, self.max)
+    }
+
+    /// Increase chaos by 1 (up to the max). Called when a scene goes badly.
     pub fn increase(&mut self) {
-        self.value = (self.value + 1).min(9);
+        self.value = (self.value + 1).min(self.max);
     }
 
-    /// Decrease chaos by 1 (min 1). Called when a scene goes well.
+    /// Decrease chaos by 1 (down to the min). Called when a scene goes well.
     pub fn decrease(&mut self) {
-        self.value = self.value.saturating_sub(1).max(1);
+        self.value = self.value.saturating_sub(1).max(self.min);
     }
 }
 
@@ -79,6 +125,32 @@ mod tests {
         assert_eq!(c.value(), 1);
     }
 
+    #[test]
+    fn custom_bounds_clamp_and_cap() {
+        let mut c = ChaosFactor::with_bounds(9, 3, 6);
+        assert_eq!(c.value(), 6);
+        c.increase();
+        assert_eq!(c.value(), 6);
+        let mut c = ChaosFactor::with_bounds(4, 3, 6);
+        c.decrease();
+        c.decrease();
+        assert_eq!(c.value(), 3);
+    }
+
+    #[test]
+    fn chart_value_spans_the_chart() {
+        assert_eq!(ChaosFactor::new(7).chart_value(), 7);
+        assert_eq!(ChaosFactor::with_bounds(3, 3, 6).chart_value(), 1);
+        assert_eq!(ChaosFactor::with_bounds(6, 3, 6).chart_value(), 9);
+        assert_eq!(ChaosFactor::with_bounds(5, 0, 10).chart_value(), 5);
+    }
+
+    #[test]
+    fn old_saves_get_default_bounds() {
+        let c: ChaosFactor = serde_json::from_str(r#"{"value":4}"#).unwrap();
+        assert_eq!((c.min(), c.max()), (1, 9));
+    }
+
     #[test]
     fn round_trip_serde() {
         let c = ChaosFactor::new(7);
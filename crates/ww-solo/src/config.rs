@@ -4,20 +4,39 @@ use std::collections::HashMap;
 
 use ww_core::entity::MetadataValue;
 
+use crate::chaos::{DEFAULT_CHAOS_MAX, DEFAULT_CHAOS_MIN};
+
+/// Seed used when neither the caller nor the world's `rng.seed` picks one.
+pub const DEFAULT_SEED: u64 = 42;
+
 /// Configuration for a solo session.
 #[derive(Debug, Clone)]
 pub struct SoloConfig {
     /// RNG seed for reproducible oracle rolls.
-    pub seed: u64,
-    /// Initial chaos factor (1-9).
+    ///
+    /// `None` defers to the world's `rng.seed`, then [`DEFAULT_SEED`].
+    pub seed: Option<u64>,
+    /// Initial chaos factor, within the chaos bounds.
     pub initial_chaos: u32,
+    /// Lowest chaos factor (default: 1).
+    pub chaos_min: u32,
+    /// Highest chaos factor (default: 9).
+    pub chaos_max: u32,
+    /// Column width output is word-wrapped to.
+    ///
+    /// `None` leaves output unwrapped, except for journal exports, which
+    /// defer to the world's `solo.wrap_width`.
+    pub wrap_width: Option<usize>,
 }
 
 impl Default for SoloConfig {
     fn default() -> Self {
         Self {
-            seed: 42,
+            seed: None,
             initial_chaos: 5,
+            chaos_min: DEFAULT_CHAOS_MIN,
+            chaos_max: DEFAULT_CHAOS_MAX,
+            wrap_width: None,
         }
     }
 }
@@ -25,13 +44,29 @@ impl Default for SoloConfig {
 impl SoloConfig {
     /// Set the RNG seed.
     pub fn with_seed(mut self, seed: u64) -> Self {
-        self.seed = seed;
+        self.seed = Some(seed);
         self
     }
 
-    /// Set the initial chaos factor (clamped to 1-9).
+    /// Set the initial chaos factor (clamped to the chaos bounds).
     pub fn with_chaos(mut self, chaos: u32) -> Self {
-        self.initial_chaos = chaos.clamp(1, 9);
+        self.initial_chaos = chaos.clamp(self.chaos_min, self.chaos_max);
+        self
+    }
+
+    /// Set the chaos bounds, re-clamping the initial chaos factor.
+    ///
+    /// A `max` below `min` is raised to `min`.
+    pub fn with_chaos_bounds(mut self, min: u32, max: u32) -> Self {
+        self.chaos_min = min;
+        self.chaos_max = max.max(min);
+        self.initial_chaos = self.initial_chaos.clamp(self.chaos_min, self.chaos_max);
+        self
+    }
+
+    /// Word-wrap output to `width` columns.
+    pub fn with_wrap_width(mut self, width: usize) -> Self {
+        self.wrap_width = Some(width);
         self
     }
 }
@@ -59,6 +94,10 @@ impl SoloConfig {
 ///         event_prefix "The tunnel shifts:"
 ///         reaction_prefix "Response"
 ///         enable_chaos true
+///         scene_prompt true
+///         session_scene_limit 8
+///         wrap_width 72
+///         oracle_facts true
 ///     }
 /// }
 /// ```
@@ -88,6 +127,17 @@ pub struct SoloWorldConfig {
     pub reaction_prefix: Option<String>,
     /// Enable Mythic-style chaos/scene management (default: true).
     pub enable_chaos: bool,
+    /// Suggest a next scene from threads and NPCs when a scene ends
+    /// (default: false).
+    pub scene_prompt: bool,
+    /// Scenes planned for one session; starting a scene near or past it
+    /// prompts the player to wrap up (default: no limit).
+    pub session_scene_limit: Option<u32>,
+    /// Column width journal exports are word-wrapped to (default: unwrapped).
+    pub wrap_width: Option<usize>,
+    /// Remember oracle answers as established facts, so asking the same
+    /// question again repeats the answer instead of rolling (default: false).
+    pub oracle_facts: bool,
 }
 
 impl Default for SoloWorldConfig {
@@ -105,6 +155,10 @@ impl Default for SoloWorldConfig {
             event_prefix: None,
             reaction_prefix: None,
             enable_chaos: true, // Default to enabled for backwards compatibility
+            scene_prompt: false,
+            session_scene_limit: None,
+            wrap_width: None,
+            oracle_facts: false,
         }
     }
 }
@@ -128,6 +182,16 @@ impl SoloWorldConfig {
             event_prefix: extract_string(properties, "solo.event_prefix"),
             reaction_prefix: extract_string(properties, "solo.reaction_prefix"),
             enable_chaos: extract_bool(properties, "solo.enable_chaos").unwrap_or(true),
+            scene_prompt: extract_bool(properties, "solo.scene_prompt").unwrap_or(false),
+            session_scene_limit: match properties.get("solo.session_scene_limit") {
+                Some(MetadataValue::Integer(n)) if *n > 0 => Some(*n as u32),
+                _ => None,
+            },
+            wrap_width: match properties.get("solo.wrap_width") {
+                Some(MetadataValue::Integer(n)) if *n > 0 => Some(*n as usize),
+                _ => None,
+            },
+            oracle_facts: extract_bool(properties, "solo.oracle_facts").unwrap_or(false),
         }
     }
 }
@@ -155,14 +219,14 @@ mod tests {
     #[test]
     fn default_config() {
         let cfg = SoloConfig::default();
-        assert_eq!(cfg.seed, 42);
+        assert_eq!(cfg.seed, None);
         assert_eq!(cfg.initial_chaos, 5);
     }
 
     #[test]
     fn builder_methods() {
         let cfg = SoloConfig::default().with_seed(123).with_chaos(8);
-        assert_eq!(cfg.seed, 123);
+        assert_eq!(cfg.seed, Some(123));
         assert_eq!(cfg.initial_chaos, 8);
     }
 
@@ -174,6 +238,15 @@ mod tests {
         assert_eq!(cfg.initial_chaos, 9);
     }
 
+    #[test]
+    fn chaos_bounds_reclamp() {
+        let cfg = SoloConfig::default().with_chaos(8).with_chaos_bounds(3, 6);
+        assert_eq!((cfg.chaos_min, cfg.chaos_max), (3, 6));
+        assert_eq!(cfg.initial_chaos, 6);
+        let cfg = cfg.with_chaos(1);
+        assert_eq!(cfg.initial_chaos, 3);
+    }
+
     #[test]
     fn solo_world_config_from_empty() {
         let props = HashMap::new();
@@ -272,6 +345,25 @@ mod tests {
         assert!(cfg.chaos_label.is_none());
         assert!(cfg.event_prefix.is_none());
         assert!(cfg.reaction_prefix.is_none());
+        assert!(cfg.session_scene_limit.is_none());
+    }
+
+    #[test]
+    fn solo_world_config_scene_limit_must_be_positive() {
+        let mut props = HashMap::new();
+        props.insert(
+            "solo.session_scene_limit".to_string(),
+            MetadataValue::Integer(6),
+        );
+        let cfg = SoloWorldConfig::from_world_meta(&props);
+        assert_eq!(cfg.session_scene_limit, Some(6));
+
+        props.insert(
+            "solo.session_scene_limit".to_string(),
+            MetadataValue::Integer(0),
+        );
+        let cfg = SoloWorldConfig::from_world_meta(&props);
+        assert!(cfg.session_scene_limit.is_none());
     }
 
     #[test]
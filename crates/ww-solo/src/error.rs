@@ -32,6 +32,10 @@ pub enum SoloError {
     #[error("unknown command: {0}")]
     UnknownCommand(String),
 
+    /// A session save could not be read.
+    #[error("invalid save: {0}")]
+    InvalidSave(String),
+
     /// Fiction engine error.
     #[error("{0}")]
     Fiction(#[from] ww_fiction::FictionError),
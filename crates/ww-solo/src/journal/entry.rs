@@ -18,6 +18,9 @@ pub enum JournalEntry {
         result: String,
         /// Random event description, if triggered.
         random_event: Option<String>,
+        /// Action/subject words drawn to interpret the answer (`ask complex`).
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        interpretation: Option<String>,
         /// When the query was made.
         timestamp: DateTime<Utc>,
     },
@@ -81,8 +84,8 @@ pub enum JournalEntry {
         attribute: String,
         /// Dice expression that was rolled.
         dice: String,
-        /// Individual die values.
-        values: Vec<u32>,
+        /// What each die counted for (-1/0/+1 on Fudge dice).
+        values: Vec<i32>,
         /// The resolved outcome (e.g., "Success", "Critical Failure").
         outcome: String,
         /// When rolled.
@@ -92,11 +95,37 @@ pub enum JournalEntry {
     DiceRoll {
         /// Dice expression (e.g., "2d6", "d100").
         expression: String,
-        /// Individual die values.
-        values: Vec<u32>,
+        /// What each die counted for (-1/0/+1 on Fudge dice).
+        values: Vec<i32>,
         /// Sum of all dice.
-        total: u32,
+        total: i32,
         /// When rolled.
         timestamp: DateTime<Utc>,
     },
+    /// A location improvised with `generate location`.
+    LocationGenerated {
+        /// Location name.
+        name: String,
+        /// Features and exits, as prose.
+        description: String,
+        /// Suggested encounter prompt.
+        encounter: String,
+        /// When generated.
+        timestamp: DateTime<Utc>,
+    },
+    /// A named bookmark of the session state.
+    Bookmark {
+        /// Bookmark name.
+        name: String,
+        /// When the bookmark was saved.
+        timestamp: DateTime<Utc>,
+    },
+    /// A reroll: the result recorded just before this entry is superseded
+    /// by the one just after it.
+    Reroll {
+        /// The command that was re-executed (e.g., "ask likely Is it locked?").
+        command: String,
+        /// When the reroll happened.
+        timestamp: DateTime<Utc>,
+    },
 }
@@ -3,6 +3,7 @@
 use serde::{Deserialize, Serialize};
 
 use super::entry::JournalEntry;
+use crate::tracker::ThreadList;
 
 /// A chronological log of session events.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -21,6 +22,11 @@ impl Journal {
         self.entries.push(entry);
     }
 
+    /// Insert an entry at a position, shifting later entries back.
+    pub(crate) fn insert(&mut self, index: usize, entry: JournalEntry) {
+        self.entries.insert(index.min(self.entries.len()), entry);
+    }
+
     /// Get all entries.
     pub fn entries(&self) -> &[JournalEntry] {
         &self.entries
@@ -36,6 +42,26 @@ impl Journal {
         self.entries.is_empty()
     }
 
+    /// The last `limit` oracle answers and random events, newest first.
+    ///
+    /// Results superseded by a reroll are left out.
+    pub fn recent_oracle(&self, limit: usize) -> Vec<&JournalEntry> {
+        self.entries
+            .iter()
+            .enumerate()
+            .rev()
+            .filter(|(_, e)| {
+                matches!(
+                    e,
+                    JournalEntry::OracleQuery { .. } | JournalEntry::RandomEvent { .. }
+                )
+            })
+            .filter(|(i, _)| !matches!(self.entries.get(i + 1), Some(JournalEntry::Reroll { .. })))
+            .map(|(_, e)| e)
+            .take(limit)
+            .collect()
+    }
+
     /// Export the journal as markdown.
     pub fn export_markdown(&self) -> String {
         let mut out = String::from("# Solo Session Journal\n\n");
@@ -46,10 +72,14 @@ impl Journal {
                     likelihood,
                     result,
                     random_event,
+                    interpretation,
                     ..
                 } => {
                     out.push_str(&format!("**Oracle** ({likelihood}): {question}\n"));
                     out.push_str(&format!("  **Answer**: {result}\n"));
+                    if let Some(words) = interpretation {
+                        out.push_str(&format!("  *Meaning*: {words}\n"));
+                    }
                     if let Some(event) = random_event {
                         out.push_str(&format!("  *Random Event*: {event}\n"));
                     }
@@ -123,8 +153,101 @@ impl Journal {
                         vals.join(", ")
                     ));
                 }
+                JournalEntry::LocationGenerated {
+                    name,
+                    description,
+                    encounter,
+                    ..
+                } => {
+                    out.push_str(&format!("**Location** ({name}): {description}\n"));
+                    out.push_str(&format!("  *Encounter*: {encounter}\n\n"));
+                }
+                JournalEntry::Bookmark { name, .. } => {
+                    out.push_str(&format!("*Bookmark*: {name}\n\n"));
+                }
+                JournalEntry::Reroll { command, .. } => {
+                    out.push_str(&format!(
+                        "*Reroll* (`{command}`): the result above is superseded\n\n"
+                    ));
+                }
+            }
+        }
+        out
+    }
+
+    /// Export a player-facing recap of the session as markdown.
+    ///
+    /// Unlike [`Journal::export_markdown`], the handout keeps only the story:
+    /// scene setups and summaries, narrative beats, oracle answers told as
+    /// events, improvised locations, the NPCs met, and the threads still open
+    /// in `threads`. Dice, checks, notes, bookmarks, and rerolled results are
+    /// left out.
+    pub fn export_handout(&self, threads: &ThreadList) -> String {
+        let mut out = String::from("# Session Recap\n\n");
+        let mut npcs: Vec<(&str, &str)> = Vec::new();
+        for (i, entry) in self.entries.iter().enumerate() {
+            if matches!(self.entries.get(i + 1), Some(JournalEntry::Reroll { .. })) {
+                continue;
+            }
+            match entry {
+                JournalEntry::SceneStart {
+                    scene_number,
+                    setup,
+                    ..
+                } => {
+                    out.push_str(&format!("## Scene {scene_number}: {setup}\n\n"));
+                }
+                JournalEntry::SceneEnd { summary, .. } if !summary.is_empty() => {
+                    out.push_str(&format!("*{summary}*\n\n"));
+                }
+                JournalEntry::NarrativeBeat { text, .. } => {
+                    out.push_str(&format!("{text}\n\n"));
+                }
+                JournalEntry::OracleQuery {
+                    question,
+                    result,
+                    random_event,
+                    ..
+                } => {
+                    out.push_str(&format!("**{question}** {result}."));
+                    if let Some(event) = random_event {
+                        out.push_str(&format!(" Then, unexpectedly: {event}."));
+                    }
+                    out.push_str("\n\n");
+                }
+                JournalEntry::RandomEvent { description, .. } => {
+                    out.push_str(&format!("*Unexpectedly*: {description}.\n\n"));
+                }
+                JournalEntry::LocationGenerated {
+                    name, description, ..
+                } => {
+                    out.push_str(&format!("**{name}**: {description}\n\n"));
+                }
+                JournalEntry::NpcReaction {
+                    npc_name, reaction, ..
+                } => match npcs.iter_mut().find(|(name, _)| *name == npc_name.as_str()) {
+                    Some(npc) => npc.1 = reaction,
+                    None => npcs.push((npc_name, reaction)),
+                },
+                _ => {}
             }
         }
+
+        if !npcs.is_empty() {
+            out.push_str("## Notable NPCs\n\n");
+            for (name, reaction) in &npcs {
+                out.push_str(&format!("- {name} ({})\n", reaction.to_lowercase()));
+            }
+            out.push('\n');
+        }
+        let open = threads.active();
+        if !open.is_empty() {
+            out.push_str("## Open Threads\n\n");
+            for thread in open {
+                out.push_str(&format!("- {}\n", thread.name));
+            }
+            out.push('\n');
+        }
         out
     }
 
@@ -138,10 +261,14 @@ impl Journal {
                     likelihood,
                     result,
                     random_event,
+                    interpretation,
                     ..
                 } => {
                     out.push_str(&format!("Oracle ({likelihood}): {question}\n"));
                     out.push_str(&format!("  Answer: {result}\n"));
+                    if let Some(words) = interpretation {
+                        out.push_str(&format!("  Meaning: {words}\n"));
+                    }
                     if let Some(event) = random_event {
                         out.push_str(&format!("  Random Event: {event}\n"));
                     }
@@ -215,6 +342,23 @@ impl Journal {
                         vals.join(", ")
                     ));
                 }
+                JournalEntry::LocationGenerated {
+                    name,
+                    description,
+                    encounter,
+                    ..
+                } => {
+                    out.push_str(&format!("Location ({name}): {description}\n"));
+                    out.push_str(&format!("  Encounter: {encounter}\n\n"));
+                }
+                JournalEntry::Bookmark { name, .. } => {
+                    out.push_str(&format!("Bookmark: {name}\n\n"));
+                }
+                JournalEntry::Reroll { command, .. } => {
+                    out.push_str(&format!(
+                        "Reroll ({command}): the result above is superseded\n\n"
+                    ));
+                }
             }
         }
         out
@@ -244,6 +388,48 @@ mod tests {
         assert!(!j.is_empty());
     }
 
+    fn oracle(question: &str, result: &str) -> JournalEntry {
+        JournalEntry::OracleQuery {
+            question: question.to_string(),
+            likelihood: "50/50".to_string(),
+            chaos: 5,
+            result: result.to_string(),
+            random_event: None,
+            interpretation: None,
+            timestamp: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn recent_oracle_skips_superseded_results() {
+        let mut j = Journal::new();
+        j.append(oracle("Is it dark?", "Yes"));
+        j.append(JournalEntry::Note {
+            text: "aside".to_string(),
+            timestamp: Utc::now(),
+        });
+        j.append(oracle("Is it locked?", "No"));
+        j.append(JournalEntry::Reroll {
+            command: "ask Is it locked?".to_string(),
+            timestamp: Utc::now(),
+        });
+        j.append(oracle("Is it locked?", "Exceptional Yes"));
+        j.append(JournalEntry::RandomEvent {
+            description: "NPC Action: betray".to_string(),
+            timestamp: Utc::now(),
+        });
+
+        let recent = j.recent_oracle(2);
+        assert_eq!(recent.len(), 2);
+        assert!(matches!(recent[0], JournalEntry::RandomEvent { .. }));
+        assert!(
+            matches!(recent[1], JournalEntry::OracleQuery { result, .. } if result == "Exceptional Yes")
+        );
+        let all = j.recent_oracle(10);
+        assert_eq!(all.len(), 3);
+        assert!(matches!(all[2], JournalEntry::OracleQuery { result, .. } if result == "Yes"));
+    }
+
     #[test]
     fn export_markdown_oracle() {
         let mut j = Journal::new();
@@ -253,6 +439,7 @@ mod tests {
             chaos: 5,
             result: "Yes".to_string(),
             random_event: None,
+            interpretation: None,
             timestamp: Utc::now(),
         });
         let md = j.export_markdown();
@@ -281,6 +468,66 @@ mod tests {
         assert!(md.contains("Chaos: -1"));
     }
 
+    #[test]
+    fn export_handout_keeps_only_the_story() {
+        let mut j = Journal::new();
+        j.append(JournalEntry::SceneStart {
+            scene_number: 1,
+            setup: "Enter the tavern".to_string(),
+            status: "Normal".to_string(),
+            timestamp: Utc::now(),
+        });
+        j.append(JournalEntry::NarrativeBeat {
+            text: "The fire has burned low.".to_string(),
+            timestamp: Utc::now(),
+        });
+        j.append(oracle("Is the innkeeper awake?", "No"));
+        j.append(JournalEntry::Reroll {
+            command: "ask Is the innkeeper awake?".to_string(),
+            timestamp: Utc::now(),
+        });
+        j.append(oracle("Is the innkeeper awake?", "Yes"));
+        j.append(JournalEntry::DiceRoll {
+            expression: "2d6".to_string(),
+            values: vec![3, 5],
+            total: 8,
+            timestamp: Utc::now(),
+        });
+        j.append(JournalEntry::Note {
+            text: "private aside".to_string(),
+            timestamp: Utc::now(),
+        });
+        j.append(JournalEntry::NpcReaction {
+            npc_name: "Innkeeper".to_string(),
+            reaction: "Cautious".to_string(),
+            roll: 9,
+            timestamp: Utc::now(),
+        });
+        j.append(JournalEntry::SceneEnd {
+            scene_number: 1,
+            summary: "Made an ally".to_string(),
+            chaos_adjustment: -1,
+            timestamp: Utc::now(),
+        });
+        let mut threads = ThreadList::new();
+        threads.add("Find the key");
+        threads.add("Pay the debt");
+        threads.close("Pay the debt");
+
+        assert_eq!(
+            j.export_handout(&threads),
+            "# Session Recap\n\n\
+             ## Scene 1: Enter the tavern\n\n\
+             The fire has burned low.\n\n\
+             **Is the innkeeper awake?** Yes.\n\n\
+             *Made an ally*\n\n\
+             ## Notable NPCs\n\n\
+             - Innkeeper (cautious)\n\n\
+             ## Open Threads\n\n\
+             - Find the key\n\n"
+        );
+    }
+
     #[test]
     fn export_text_note() {
         let mut j = Journal::new();
@@ -301,10 +548,13 @@ mod tests {
             chaos: 5,
             result: "No".to_string(),
             random_event: Some("NPC Action: Betray + Allies".to_string()),
+            interpretation: Some("Guard + Secrets".to_string()),
             timestamp: Utc::now(),
         });
         let md = j.export_markdown();
         assert!(md.contains("*Random Event*: NPC Action: Betray + Allies"));
+        assert!(md.contains("*Meaning*: Guard + Secrets"));
+        assert!(j.export_text().contains("  Meaning: Guard + Secrets\n"));
     }
 
     #[test]
@@ -413,4 +663,15 @@ mod tests {
         let j2: Journal = serde_json::from_str(&json).unwrap();
         assert_eq!(j2.len(), 2);
     }
+
+    #[test]
+    fn export_bookmark() {
+        let mut j = Journal::new();
+        j.append(JournalEntry::Bookmark {
+            name: "before the heist".to_string(),
+            timestamp: Utc::now(),
+        });
+        assert!(j.export_markdown().contains("*Bookmark*: before the heist"));
+        assert!(j.export_text().contains("Bookmark: before the heist"));
+    }
 }
@@ -9,6 +9,7 @@ pub mod config;
 pub mod error;
 pub mod journal;
 pub mod oracle;
+pub mod save;
 pub mod scene;
 pub mod session;
 pub mod tracker;
@@ -16,4 +17,5 @@ pub mod tracker;
 pub use chaos::ChaosFactor;
 pub use config::SoloConfig;
 pub use error::{SoloError, SoloResult};
+pub use save::SoloSave;
 pub use session::SoloSession;
@@ -78,28 +78,45 @@ impl std::fmt::Display for RandomEvent {
     }
 }
 
-/// Roll on the event focus table (d100).
+/// A d100 event focus table: each entry is the highest roll that yields
+/// its focus, in ascending order and ending at 100.
+pub type FocusTable = [(u32, EventFocus)];
+
+/// The standard event focus table.
+pub const DEFAULT_FOCUS_TABLE: &FocusTable = &[
+    (7, EventFocus::RemoteEvent),
+    (28, EventFocus::NpcAction),
+    (35, EventFocus::IntroduceNpc),
+    (45, EventFocus::MoveTowardThread),
+    (52, EventFocus::MoveAwayFromThread),
+    (55, EventFocus::CloseThread),
+    (67, EventFocus::PcNegative),
+    (75, EventFocus::PcPositive),
+    (83, EventFocus::AmbiguousEvent),
+    (92, EventFocus::NpcNegative),
+    (100, EventFocus::NpcPositive),
+];
+
+/// Roll on the standard event focus table (d100).
 pub fn roll_event_focus(rng: &mut StdRng) -> EventFocus {
+    roll_event_focus_on(rng, DEFAULT_FOCUS_TABLE)
+}
+
+/// Roll on a custom event focus table (d100).
+///
+/// Falls back to [`EventFocus::AmbiguousEvent`] if the table does not
+/// cover the roll.
+pub fn roll_event_focus_on(rng: &mut StdRng, table: &FocusTable) -> EventFocus {
     let roll: u32 = rng.random_range(1..=100);
-    match roll {
-        1..=7 => EventFocus::RemoteEvent,
-        8..=28 => EventFocus::NpcAction,
-        29..=35 => EventFocus::IntroduceNpc,
-        36..=45 => EventFocus::MoveTowardThread,
-        46..=52 => EventFocus::MoveAwayFromThread,
-        53..=55 => EventFocus::CloseThread,
-        56..=67 => EventFocus::PcNegative,
-        68..=75 => EventFocus::PcPositive,
-        76..=83 => EventFocus::AmbiguousEvent,
-        84..=92 => EventFocus::NpcNegative,
-        93..=100 => EventFocus::NpcPositive,
-        _ => unreachable!(),
-    }
+    table
+        .iter()
+        .find(|(max, _)| roll <= *max)
+        .map_or(EventFocus::AmbiguousEvent, |(_, focus)| *focus)
 }
 
 /// Generate a complete random event using the given oracle configuration.
 pub fn generate_random_event(rng: &mut StdRng, config: &OracleConfig) -> RandomEvent {
-    let focus = roll_event_focus(rng);
+    let focus = roll_event_focus_on(rng, &config.focus_table);
     match config.mode {
         OracleMode::ActionSubject => {
             let action = config.random_action(rng).to_string();
@@ -165,6 +165,19 @@ const FATE_CHART: [[u32; 9]; 11] = [
     [55, 75, 85, 90, 95, 97, 99, 99, 99], // Has To Be
 ];
 
+/// Map a chaos value in `min..=max` onto the chart's 1-9 columns.
+///
+/// Lets variants with a different chaos range share the standard chart.
+/// A single-value range sits in the middle column.
+pub fn scale_chaos(chaos: u32, min: u32, max: u32) -> u32 {
+    if max <= min {
+        return 5;
+    }
+    let offset = chaos.clamp(min, max) - min;
+    let span = max - min;
+    1 + (offset * 8 + span / 2) / span
+}
+
 /// Look up the "Yes" threshold for a given likelihood and chaos factor.
 pub fn fate_threshold(likelihood: Likelihood, chaos: u32) -> u32 {
     let chaos_idx = (chaos.clamp(1, 9) - 1) as usize;
@@ -231,6 +244,17 @@ mod tests {
     use super::*;
     use rand::SeedableRng;
 
+    #[test]
+    fn scale_chaos_maps_ranges_to_chart() {
+        for chaos in 1..=9 {
+            assert_eq!(scale_chaos(chaos, 1, 9), chaos);
+        }
+        assert_eq!(scale_chaos(3, 3, 6), 1);
+        assert_eq!(scale_chaos(4, 3, 6), 4);
+        assert_eq!(scale_chaos(6, 3, 6), 9);
+        assert_eq!(scale_chaos(7, 7, 7), 5);
+    }
+
     #[test]
     fn fate_threshold_increases_with_chaos() {
         for lk in Likelihood::all() {
@@ -0,0 +1,234 @@
+//! Random location generation for improvised dungeon rooms and sites.
+//!
+//! A generated location has a name, a few exits, a couple of features,
+//! and an encounter prompt drawn from the oracle's meaning tables.
+
+use rand::Rng;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+
+use super::tables::OracleConfig;
+
+/// Adjectives for generated location names.
+pub const LOCATION_ADJECTIVES: &[&str] = &[
+    "Flooded",
+    "Collapsed",
+    "Silent",
+    "Smoky",
+    "Frozen",
+    "Overgrown",
+    "Sunken",
+    "Gilded",
+    "Echoing",
+    "Ruined",
+    "Narrow",
+    "Vaulted",
+    "Crooked",
+    "Forgotten",
+    "Scorched",
+    "Mossy",
+    "Hidden",
+    "Broken",
+    "Dripping",
+    "Ashen",
+];
+
+/// Kinds of place for generated location names.
+pub const LOCATION_KINDS: &[&str] = &[
+    "Hall",
+    "Chamber",
+    "Cellar",
+    "Crypt",
+    "Gallery",
+    "Cavern",
+    "Shrine",
+    "Armory",
+    "Library",
+    "Well",
+    "Kitchen",
+    "Barracks",
+    "Vault",
+    "Passage",
+    "Grotto",
+    "Workshop",
+    "Chapel",
+    "Pit",
+    "Storeroom",
+    "Tower",
+];
+
+/// Features that furnish a generated location.
+pub const LOCATION_FEATURES: &[&str] = &[
+    "a toppled statue",
+    "a pool of black water",
+    "scattered bones",
+    "a rusted portcullis",
+    "faded murals on the walls",
+    "a cold hearth",
+    "a collapsed ceiling",
+    "an altar stained dark",
+    "rows of empty shelves",
+    "a locked iron chest",
+    "roots breaking through the stone",
+    "a draft from a hidden crack",
+    "a spiral stair",
+    "a broken cart",
+    "claw marks on the floor",
+    "a dry fountain",
+    "hanging chains",
+    "a pile of moldering sacks",
+    "candles still burning",
+    "a mosaic of a forgotten god",
+];
+
+/// Compass directions a generated location's exits may lead.
+pub const EXIT_DIRECTIONS: &[&str] = &["north", "east", "south", "west"];
+
+/// A randomly generated location.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GeneratedLocation {
+    /// Location name, e.g. "the Flooded Crypt".
+    pub name: String,
+    /// Directions exits lead, in compass order.
+    pub exits: Vec<String>,
+    /// Notable features of the location.
+    pub features: Vec<String>,
+    /// Encounter prompt from the oracle's meaning tables.
+    pub encounter: String,
+}
+
+impl GeneratedLocation {
+    /// A prose description of the location for its entity and the journal.
+    pub fn description(&self) -> String {
+        let exits = match self.exits.as_slice() {
+            [] => "There are no other ways out.".to_string(),
+            [one] => format!("An exit leads {one}."),
+            [rest @ .., last] => format!("Exits lead {} and {last}.", rest.join(", ")),
+        };
+        format!("Here you find {}. {exits}", join_and(&self.features))
+    }
+}
+
+impl std::fmt::Display for GeneratedLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Location: {}", self.name)?;
+        writeln!(f, "  Exits: {}", self.exits.join(", "))?;
+        writeln!(f, "  Features: {}", self.features.join("; "))?;
+        write!(f, "  Encounter: {}", self.encounter)
+    }
+}
+
+/// Generate a location: one to three exits, two features, and an encounter.
+///
+/// With `known_exit`, e.g. the way back to the room the location is attached
+/// to, that is its only exit: every exit the location describes then exists
+/// in the world, and further rooms are attached to it one at a time.
+pub fn generate_location(
+    rng: &mut StdRng,
+    config: &OracleConfig,
+    known_exit: Option<&str>,
+) -> GeneratedLocation {
+    let pick = |rng: &mut StdRng, table: &[&'static str]| table[rng.random_range(0..table.len())];
+    let name = format!(
+        "the {} {}",
+        pick(rng, LOCATION_ADJECTIVES),
+        pick(rng, LOCATION_KINDS)
+    );
+
+    let exits = match known_exit {
+        Some(exit) => vec![exit],
+        None => {
+            let mut directions = EXIT_DIRECTIONS.to_vec();
+            directions.shuffle(rng);
+            let count = rng.random_range(1..=3);
+            let mut exits: Vec<&str> = directions.into_iter().take(count).collect();
+            exits.sort_by_key(|exit| EXIT_DIRECTIONS.iter().position(|d| d == exit));
+            exits
+        }
+    };
+
+    let mut features = LOCATION_FEATURES.to_vec();
+    features.shuffle(rng);
+    features.truncate(2);
+
+    let encounter = config.random_meaning(rng);
+
+    GeneratedLocation {
+        name,
+        exits: exits.into_iter().map(str::to_string).collect(),
+        features: features.into_iter().map(str::to_string).collect(),
+        encounter,
+    }
+}
+
+/// `"a"`, `"a and b"`, or `"a, b and c"`.
+fn join_and(items: &[String]) -> String {
+    match items {
+        [] => String::new(),
+        [one] => one.clone(),
+        [rest @ .., last] => format!("{} and {last}", rest.join(", ")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+
+    #[test]
+    fn deterministic_under_seed() {
+        let config = OracleConfig::default();
+        let a = generate_location(&mut StdRng::seed_from_u64(9), &config, None);
+        let b = generate_location(&mut StdRng::seed_from_u64(9), &config, None);
+        assert_eq!(a, b);
+        assert!(a.name.starts_with("the "));
+        assert!((1..=3).contains(&a.exits.len()));
+        assert_eq!(a.features.len(), 2);
+        assert!(a.encounter.contains(" + "));
+    }
+
+    #[test]
+    fn exits_are_in_compass_order() {
+        let config = OracleConfig::default();
+        let mut rng = StdRng::seed_from_u64(1);
+        for _ in 0..20 {
+            let location = generate_location(&mut rng, &config, None);
+            let order: Vec<_> = location
+                .exits
+                .iter()
+                .map(|e| EXIT_DIRECTIONS.iter().position(|d| d == e))
+                .collect();
+            assert!(order.iter().all(Option::is_some));
+            assert!(order.is_sorted());
+        }
+    }
+
+    #[test]
+    fn known_exit_is_the_only_exit() {
+        let config = OracleConfig::default();
+        let mut rng = StdRng::seed_from_u64(1);
+        for _ in 0..20 {
+            let location = generate_location(&mut rng, &config, Some("down"));
+            assert_eq!(location.exits, ["down"]);
+            assert!(location.description().ends_with("An exit leads down."));
+        }
+    }
+
+    #[test]
+    fn description_and_display() {
+        let location = GeneratedLocation {
+            name: "the Silent Crypt".to_string(),
+            exits: vec!["north".to_string(), "west".to_string()],
+            features: vec!["scattered bones".to_string(), "a cold hearth".to_string()],
+            encounter: "Ambush + Allies".to_string(),
+        };
+        assert_eq!(
+            location.description(),
+            "Here you find scattered bones and a cold hearth. Exits lead north and west."
+        );
+        assert_eq!(
+            location.to_string(),
+            "Location: the Silent Crypt\n  Exits: north, west\n  Features: scattered bones; a cold hearth\n  Encounter: Ambush + Allies"
+        );
+    }
+}
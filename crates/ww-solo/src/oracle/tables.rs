@@ -1,7 +1,8 @@
 //! Meaning tables for random event interpretation.
 //!
 //! Action and subject word lists used by the random event generator
-//! to create event descriptions that the player interprets narratively.
+//! to create event descriptions that the player interprets narratively,
+//! plus built-in genre packs that swap in themed tables wholesale.
 
 use rand::Rng;
 use rand::rngs::StdRng;
@@ -9,6 +10,8 @@ use rand::rngs::StdRng;
 use ww_core::World;
 use ww_core::entity::MetadataValue;
 
+use super::event::{DEFAULT_FOCUS_TABLE, EventFocus, FocusTable};
+
 /// Action/verb words for event meaning (100 entries).
 pub const ACTION_WORDS: &[&str] = &[
     "Attainment",
@@ -227,6 +230,254 @@ pub fn random_subject(rng: &mut StdRng) -> &'static str {
     SUBJECT_WORDS[rng.random_range(0..SUBJECT_WORDS.len())]
 }
 
+/// A named set of themed oracle tables.
+///
+/// Selected per world with `solo.genre_pack`; `oracle.*` lists in the world
+/// still take precedence over the pack's tables.
+#[derive(Debug)]
+pub struct GenrePack {
+    /// Pack name as used in `solo.genre_pack`.
+    pub name: &'static str,
+    /// Action/verb words for random events.
+    pub actions: &'static [&'static str],
+    /// Subject/noun words for random events.
+    pub subjects: &'static [&'static str],
+    /// Event focus table, weighted toward the genre's typical turns.
+    pub focus_table: &'static FocusTable,
+    /// Names for improvised NPCs.
+    pub names: &'static [&'static str],
+}
+
+/// Built-in genre packs.
+pub const GENRE_PACKS: &[GenrePack] = &[
+    GenrePack {
+        name: "fantasy",
+        actions: &[
+            "Quest", "Enchant", "Betray", "Summon", "Guard", "Wander", "Curse", "Bless", "Forge",
+            "Duel", "Prophesy", "Conquer", "Heal", "Steal", "Awaken", "Banish", "Hunt", "Swear",
+            "Ransom", "Rescue",
+        ],
+        subjects: &[
+            "Dragon", "Crown", "Sword", "Temple", "Ruins", "Oath", "Prophecy", "Tavern", "Wizard",
+            "Forest", "Relic", "Knight", "Goblin", "Throne", "Spell", "Heir", "Tower", "Village",
+            "Gold", "Portal",
+        ],
+        focus_table: DEFAULT_FOCUS_TABLE,
+        names: &[
+            "Aldric", "Brenna", "Cedric", "Dagna", "Elowen", "Fenwick", "Gareth", "Isolde",
+            "Jorund", "Kestrel", "Lirien", "Maelis", "Osric", "Rowena", "Theron", "Wynne",
+        ],
+    },
+    GenrePack {
+        name: "sci-fi",
+        actions: &[
+            "Hack",
+            "Malfunction",
+            "Transmit",
+            "Colonize",
+            "Scan",
+            "Mutiny",
+            "Salvage",
+            "Decode",
+            "Dock",
+            "Evacuate",
+            "Terraform",
+            "Jump",
+            "Quarantine",
+            "Upgrade",
+            "Intercept",
+            "Negotiate",
+            "Sabotage",
+            "Override",
+            "Launch",
+            "Contact",
+        ],
+        subjects: &[
+            "Reactor",
+            "Colony",
+            "Signal",
+            "Android",
+            "Corporation",
+            "Wormhole",
+            "Freighter",
+            "Station",
+            "AI",
+            "Cargo",
+            "Virus",
+            "Fleet",
+            "Beacon",
+            "Asteroid",
+            "Implant",
+            "Relay",
+            "Outpost",
+            "Drone",
+            "Contract",
+            "Alien",
+        ],
+        focus_table: &[
+            (12, EventFocus::RemoteEvent),
+            (30, EventFocus::NpcAction),
+            (38, EventFocus::IntroduceNpc),
+            (48, EventFocus::MoveTowardThread),
+            (55, EventFocus::MoveAwayFromThread),
+            (58, EventFocus::CloseThread),
+            (69, EventFocus::PcNegative),
+            (77, EventFocus::PcPositive),
+            (85, EventFocus::AmbiguousEvent),
+            (93, EventFocus::NpcNegative),
+            (100, EventFocus::NpcPositive),
+        ],
+        names: &[
+            "Vega",
+            "Orin Kade",
+            "Juno",
+            "Rask",
+            "Tamsin Vale",
+            "Ixar",
+            "Nova",
+            "Cass Teller",
+            "Dray",
+            "Maru",
+            "Soren",
+            "Kit Halloway",
+            "Zeph",
+            "Anika Roe",
+            "Quill",
+            "Tycho",
+        ],
+    },
+    GenrePack {
+        name: "horror",
+        actions: &[
+            "Whisper", "Vanish", "Possess", "Haunt", "Rot", "Stalk", "Scream", "Bleed", "Lure",
+            "Hide", "Decay", "Unseal", "Consume", "Mimic", "Twist", "Follow", "Trap", "Drown",
+            "Forget", "Awaken",
+        ],
+        subjects: &[
+            "Cellar", "Mirror", "Child", "Doll", "Blood", "Shadow", "Grave", "Diary", "Fog",
+            "Chapel", "Stranger", "Bones", "Door", "Hunger", "Ritual", "Attic", "Radio", "Well",
+            "Mask", "Darkness",
+        ],
+        focus_table: &[
+            (10, EventFocus::RemoteEvent),
+            (26, EventFocus::NpcAction),
+            (32, EventFocus::IntroduceNpc),
+            (38, EventFocus::MoveTowardThread),
+            (48, EventFocus::MoveAwayFromThread),
+            (50, EventFocus::CloseThread),
+            (70, EventFocus::PcNegative),
+            (74, EventFocus::PcPositive),
+            (86, EventFocus::AmbiguousEvent),
+            (97, EventFocus::NpcNegative),
+            (100, EventFocus::NpcPositive),
+        ],
+        names: &[
+            "Agnes",
+            "Bartholomew",
+            "Clara",
+            "Ezekiel",
+            "Harriet",
+            "Ichabod",
+            "Lenore",
+            "Mortimer",
+            "Ophelia",
+            "Silas",
+            "Thaddeus",
+            "Wilhelmina",
+            "Abigail",
+            "Jeremiah",
+            "Prudence",
+            "Edgar",
+        ],
+    },
+    GenrePack {
+        name: "noir",
+        actions: &[
+            "Bribe",
+            "Tail",
+            "Double-cross",
+            "Blackmail",
+            "Frame",
+            "Confess",
+            "Stake out",
+            "Shake down",
+            "Cover up",
+            "Tip off",
+            "Squeal",
+            "Pin",
+            "Gamble",
+            "Seduce",
+            "Lean on",
+            "Skip town",
+            "Launder",
+            "Fence",
+            "Wire",
+            "Ditch",
+        ],
+        subjects: &[
+            "Dame",
+            "Gun",
+            "Ledger",
+            "Cop",
+            "Mob",
+            "Alibi",
+            "Nightclub",
+            "Photograph",
+            "Rain",
+            "Cigarette",
+            "Debt",
+            "Widow",
+            "Docks",
+            "Payoff",
+            "Witness",
+            "Safe",
+            "Jazz",
+            "Badge",
+            "Motel",
+            "Corpse",
+        ],
+        focus_table: &[
+            (6, EventFocus::RemoteEvent),
+            (32, EventFocus::NpcAction),
+            (40, EventFocus::IntroduceNpc),
+            (48, EventFocus::MoveTowardThread),
+            (56, EventFocus::MoveAwayFromThread),
+            (59, EventFocus::CloseThread),
+            (70, EventFocus::PcNegative),
+            (75, EventFocus::PcPositive),
+            (88, EventFocus::AmbiguousEvent),
+            (96, EventFocus::NpcNegative),
+            (100, EventFocus::NpcPositive),
+        ],
+        names: &[
+            "Vic Marlowe",
+            "Lola Vance",
+            "Eddie Sloane",
+            "Rita Kaye",
+            "Frankie Doyle",
+            "Sal Moretti",
+            "Vera Lane",
+            "Mickey Shaw",
+            "Dolores Grey",
+            "Nick Carver",
+            "Ruby Flynn",
+            "Lou Benetti",
+            "Hazel Monroe",
+            "Joe Rourke",
+            "Ivy Malone",
+            "Tony Greco",
+        ],
+    },
+];
+
+/// Look up a built-in genre pack by name (case-insensitive; `scifi` is
+/// accepted for `sci-fi`).
+pub fn genre_pack(name: &str) -> Option<&'static GenrePack> {
+    let name = name.trim().to_lowercase();
+    let name = if name == "scifi" { "sci-fi" } else { &name };
+    GENRE_PACKS.iter().find(|p| p.name == name)
+}
+
 /// Oracle mode: action/subject tables (Mythic-style) or symbol list (Semiotic Standard).
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum OracleMode {
@@ -259,6 +510,21 @@ pub enum OracleMode {
 ///     }
 /// }
 /// ```
+///
+/// Setting-specific meaning tables can also be declared on the world itself:
+///
+/// ```text
+/// world "The Shattered Isles" {
+///     oracle {
+///         action_table ["Plunder", "Parley", "Maroon"]
+///         subject_table ["Galleon", "Treasure", "Navy"]
+///     }
+/// }
+/// ```
+///
+/// A world can also start from a [`GenrePack`] with `solo.genre_pack "horror"`
+/// in its `world` block; any of the lists above (and `oracle.names`) override
+/// the pack's tables individually.
 pub struct OracleConfig {
     /// Oracle mode: action/subject or symbols.
     pub mode: OracleMode,
@@ -268,6 +534,10 @@ pub struct OracleConfig {
     pub subjects: Vec<String>,
     /// Symbol words for prompts (Semiotic Standard mode).
     pub symbols: Vec<String>,
+    /// Event focus table for random events.
+    pub focus_table: Vec<(u32, EventFocus)>,
+    /// Names for improvised NPCs (empty if none are configured).
+    pub names: Vec<String>,
 }
 
 impl Default for OracleConfig {
@@ -277,6 +547,8 @@ impl Default for OracleConfig {
             actions: ACTION_WORDS.iter().map(|s| (*s).to_string()).collect(),
             subjects: SUBJECT_WORDS.iter().map(|s| (*s).to_string()).collect(),
             symbols: Vec::new(),
+            focus_table: DEFAULT_FOCUS_TABLE.to_vec(),
+            names: Vec::new(),
         }
     }
 }
@@ -285,57 +557,42 @@ impl OracleConfig {
     /// Load oracle configuration from a compiled world.
     ///
     /// Scans all entities for `oracle.actions`, `oracle.subjects`, or `oracle.symbols`
-    /// properties (set via `oracle { ... }` blocks in `.ww` files).
+    /// properties (set via `oracle { ... }` blocks in `.ww` files). The
+    /// world's own `oracle.action_table` and `oracle.subject_table` lists
+    /// replace the action and subject words from those blocks.
     ///
     /// If `oracle.symbols` is found, uses Semiotic Standard mode (single symbol list).
     /// Otherwise, uses action/subject mode (Mythic GME style).
     ///
-    /// Falls back to built-in defaults if not found or if custom lists are empty.
+    /// Falls back to the world's genre pack (`solo.genre_pack`), then to the
+    /// built-in defaults, if not found or if custom lists are empty. An
+    /// unknown pack name is ignored.
     pub fn from_world(world: &World) -> Self {
         let mut custom_actions: Option<Vec<String>> = None;
         let mut custom_subjects: Option<Vec<String>> = None;
         let mut custom_symbols: Option<Vec<String>> = None;
+        let mut custom_names: Option<Vec<String>> = None;
 
         for entity in world.all_entities() {
-            if let Some(MetadataValue::List(items)) = entity.properties.get("oracle.actions") {
-                let strings: Vec<String> = items
-                    .iter()
-                    .filter_map(|v| match v {
-                        MetadataValue::String(s) => Some(s.clone()),
-                        _ => None,
-                    })
-                    .collect();
-                if !strings.is_empty() {
-                    custom_actions = Some(strings);
-                }
-            }
-            if let Some(MetadataValue::List(items)) = entity.properties.get("oracle.subjects") {
-                let strings: Vec<String> = items
-                    .iter()
-                    .filter_map(|v| match v {
-                        MetadataValue::String(s) => Some(s.clone()),
-                        _ => None,
-                    })
-                    .collect();
-                if !strings.is_empty() {
-                    custom_subjects = Some(strings);
-                }
-            }
-            if let Some(MetadataValue::List(items)) = entity.properties.get("oracle.symbols") {
-                let strings: Vec<String> = items
-                    .iter()
-                    .filter_map(|v| match v {
-                        MetadataValue::String(s) => Some(s.clone()),
-                        _ => None,
-                    })
-                    .collect();
-                if !strings.is_empty() {
-                    custom_symbols = Some(strings);
-                }
-            }
+            let list = |key: &str| string_list(entity.properties.get(key));
+            custom_actions = list("oracle.actions").or(custom_actions);
+            custom_subjects = list("oracle.subjects").or(custom_subjects);
+            custom_symbols = list("oracle.symbols").or(custom_symbols);
+            custom_names = list("oracle.names").or(custom_names);
         }
 
-        let defaults = Self::default();
+        // World-level meaning tables take precedence over `oracle` blocks
+        let meta_list = |key: &str| string_list(world.meta.properties.get(key));
+        custom_actions = meta_list("oracle.action_table").or(custom_actions);
+        custom_subjects = meta_list("oracle.subject_table").or(custom_subjects);
+
+        let defaults = match world.meta.properties.get("solo.genre_pack") {
+            Some(MetadataValue::String(name)) => {
+                genre_pack(name).map_or_else(Self::default, Self::from_pack)
+            }
+            _ => Self::default(),
+        };
+        let names = custom_names.unwrap_or(defaults.names);
 
         // If symbols are provided, use Semiotic Standard mode
         if let Some(symbols) = custom_symbols {
@@ -344,6 +601,8 @@ impl OracleConfig {
                 actions: Vec::new(),
                 subjects: Vec::new(),
                 symbols,
+                focus_table: defaults.focus_table,
+                names,
             }
         } else {
             // Otherwise use action/subject mode
@@ -352,10 +611,25 @@ impl OracleConfig {
                 actions: custom_actions.unwrap_or(defaults.actions),
                 subjects: custom_subjects.unwrap_or(defaults.subjects),
                 symbols: Vec::new(),
+                focus_table: defaults.focus_table,
+                names,
             }
         }
     }
 
+    /// Build an action/subject configuration from a genre pack.
+    pub fn from_pack(pack: &GenrePack) -> Self {
+        let owned = |words: &[&str]| words.iter().map(|s| (*s).to_string()).collect();
+        Self {
+            mode: OracleMode::ActionSubject,
+            actions: owned(pack.actions),
+            subjects: owned(pack.subjects),
+            symbols: Vec::new(),
+            focus_table: pack.focus_table.to_vec(),
+            names: owned(pack.names),
+        }
+    }
+
     /// Pick a random action word from this config (ActionSubject mode).
     pub fn random_action<'a>(&'a self, rng: &mut StdRng) -> &'a str {
         if self.actions.is_empty() {
@@ -382,11 +656,47 @@ impl OracleConfig {
             &self.symbols[rng.random_range(0..self.symbols.len())]
         }
     }
+
+    /// A meaning-table prompt: `"Action + Subject"`, or a single symbol in
+    /// Symbols mode.
+    pub fn random_meaning(&self, rng: &mut StdRng) -> String {
+        match self.mode {
+            OracleMode::ActionSubject => {
+                format!("{} + {}", self.random_action(rng), self.random_subject(rng))
+            }
+            OracleMode::Symbols => self.random_symbol(rng).to_string(),
+        }
+    }
+
+    /// Pick a random NPC name, if any names are configured.
+    pub fn random_name<'a>(&'a self, rng: &mut StdRng) -> Option<&'a str> {
+        if self.names.is_empty() {
+            None
+        } else {
+            Some(&self.names[rng.random_range(0..self.names.len())])
+        }
+    }
+}
+
+/// The strings in a list property, or `None` if it is absent or has none.
+fn string_list(value: Option<&MetadataValue>) -> Option<Vec<String>> {
+    let Some(MetadataValue::List(items)) = value else {
+        return None;
+    };
+    let strings: Vec<String> = items
+        .iter()
+        .filter_map(|v| match v {
+            MetadataValue::String(s) => Some(s.clone()),
+            _ => None,
+        })
+        .collect();
+    (!strings.is_empty()).then_some(strings)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::oracle::event::generate_random_event;
     use rand::SeedableRng;
     use ww_core::{Entity, EntityKind, WorldMeta};
 
@@ -446,6 +756,46 @@ mod tests {
         assert!(config.symbols.is_empty());
     }
 
+    #[test]
+    fn world_meaning_tables_flavor_random_events() {
+        let words = |items: &[&str]| {
+            MetadataValue::List(
+                items
+                    .iter()
+                    .map(|s| MetadataValue::String((*s).to_string()))
+                    .collect(),
+            )
+        };
+        let mut world = World::new(WorldMeta::new("Shattered Isles"));
+        world.meta.properties.insert(
+            "oracle.action_table".to_string(),
+            words(&["Plunder", "Parley", "Maroon"]),
+        );
+        world.meta.properties.insert(
+            "oracle.subject_table".to_string(),
+            words(&["Galleon", "Treasure", "Navy"]),
+        );
+        // The world's tables win over an `oracle` block
+        let mut lore = Entity::new(EntityKind::Lore, "Oracle Tables");
+        lore.properties
+            .insert("oracle.actions".to_string(), words(&["Attack"]));
+        world.add_entity(lore).unwrap();
+
+        let config = OracleConfig::from_world(&world);
+        assert_eq!(config.actions, ["Plunder", "Parley", "Maroon"]);
+
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..20 {
+            let event = generate_random_event(&mut rng, &config);
+            assert!(config.actions.contains(&event.action));
+            assert!(config.subjects.contains(&event.subject));
+        }
+
+        // Without the lists, the built-in tables are used
+        let plain = OracleConfig::from_world(&World::new(WorldMeta::new("Plain")));
+        assert_eq!(plain.actions.len(), ACTION_WORDS.len());
+    }
+
     #[test]
     fn oracle_config_from_world_symbols() {
         let mut world = World::new(WorldMeta::new("Test"));
@@ -478,13 +828,58 @@ mod tests {
         assert_eq!(config.subjects.len(), 100);
     }
 
+    #[test]
+    fn genre_packs_are_complete() {
+        for pack in GENRE_PACKS {
+            assert!(!pack.actions.is_empty(), "{}", pack.name);
+            assert!(!pack.subjects.is_empty(), "{}", pack.name);
+            assert!(!pack.names.is_empty(), "{}", pack.name);
+            assert!(
+                pack.focus_table.windows(2).all(|w| w[0].0 < w[1].0),
+                "{}",
+                pack.name
+            );
+            assert_eq!(pack.focus_table.last().map(|(max, _)| *max), Some(100));
+        }
+        assert_eq!(genre_pack("SciFi").map(|p| p.name), Some("sci-fi"));
+        assert!(genre_pack("western").is_none());
+    }
+
+    #[test]
+    fn oracle_config_from_world_genre_pack_with_override() {
+        let mut meta = WorldMeta::new("Test");
+        meta.properties.insert(
+            "solo.genre_pack".to_string(),
+            MetadataValue::String("horror".to_string()),
+        );
+        let mut world = World::new(meta);
+        let mut lore = Entity::new(EntityKind::Lore, "Oracle Tables");
+        lore.properties.insert(
+            "oracle.subjects".to_string(),
+            MetadataValue::List(vec![MetadataValue::String("Lighthouse".to_string())]),
+        );
+        world.add_entity(lore).unwrap();
+
+        let config = OracleConfig::from_world(&world);
+        let horror = genre_pack("horror").unwrap();
+        assert_eq!(config.actions[0], horror.actions[0]);
+        assert_eq!(config.subjects, vec!["Lighthouse"]);
+        assert_eq!(config.focus_table, horror.focus_table.to_vec());
+        let mut rng = StdRng::seed_from_u64(7);
+        assert!(
+            horror
+                .names
+                .contains(&config.random_name(&mut rng).unwrap())
+        );
+    }
+
     #[test]
     fn oracle_config_random_picks() {
         let config = OracleConfig {
             mode: OracleMode::ActionSubject,
             actions: vec!["Alpha".to_string(), "Beta".to_string()],
             subjects: vec!["One".to_string(), "Two".to_string()],
-            symbols: Vec::new(),
+            ..OracleConfig::default()
         };
         let mut rng = StdRng::seed_from_u64(42);
         for _ in 0..20 {
@@ -506,6 +901,7 @@ mod tests {
                 "Second".to_string(),
                 "Third".to_string(),
             ],
+            ..OracleConfig::default()
         };
         let mut rng = StdRng::seed_from_u64(42);
         for _ in 0..20 {
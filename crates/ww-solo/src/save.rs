@@ -0,0 +1,51 @@
+//! Saving and resuming solo sessions.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use ww_fiction::SessionSave;
+
+use crate::chaos::ChaosFactor;
+use crate::error::{SoloError, SoloResult};
+use crate::journal::log::Journal;
+use crate::scene::Scene;
+use crate::tracker::npcs::NpcList;
+use crate::tracker::threads::ThreadList;
+
+/// The progress of a solo session, written by
+/// [`SoloSession::save`](crate::SoloSession::save) and resumed with
+/// [`SoloSession::load`](crate::SoloSession::load).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SoloSave {
+    /// The embedded fiction session's progress.
+    pub fiction: SessionSave,
+    /// The chaos factor.
+    pub chaos: ChaosFactor,
+    /// The scene in progress, if any.
+    pub current_scene: Option<Scene>,
+    /// Number of scenes played.
+    pub scene_count: u32,
+    /// The session journal.
+    pub journal: Journal,
+    /// Open and resolved threads.
+    pub threads: ThreadList,
+    /// Tracked NPCs.
+    pub npcs: NpcList,
+    /// Oracle answers established so far.
+    #[serde(default)]
+    pub facts: BTreeMap<String, String>,
+    /// Seed for the random stream after loading.
+    pub rng_seed: u64,
+}
+
+impl SoloSave {
+    /// Serialize the save to pretty-printed JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+
+    /// Read a save written by [`SoloSave::to_json`].
+    pub fn from_json(s: &str) -> SoloResult<Self> {
+        serde_json::from_str(s).map_err(|e| SoloError::InvalidSave(e.to_string()))
+    }
+}
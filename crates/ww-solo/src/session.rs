@@ -4,28 +4,38 @@
 //! solo-specific commands: oracle queries, scene management, thread/NPC
 //! tracking, and journaling.
 
+use std::collections::BTreeMap;
+
 use chrono::Utc;
 use rand::Rng;
 use rand::SeedableRng;
 use rand::rngs::StdRng;
 
-use ww_core::World;
-use ww_fiction::FictionSession;
-use ww_mechanics::{CharacterSheet, CheckRequest, DicePool, Die, RuleSet};
+use ww_core::{Entity, EntityKind, Relationship, RelationshipKind, World};
+use ww_fiction::narrator::{ListItem, ListStyle};
+use ww_fiction::{Direction, FictionSession, PlayerState};
+use ww_mechanics::resolution::ladder_rung;
+use ww_mechanics::{CharacterSheet, CheckRequest, DiceExpression, RuleSet};
 
 use crate::chaos::ChaosFactor;
-use crate::config::{SoloConfig, SoloWorldConfig};
+use crate::config::{DEFAULT_SEED, SoloConfig, SoloWorldConfig};
 use crate::error::{SoloError, SoloResult};
 use crate::journal::entry::JournalEntry;
 use crate::journal::log::Journal;
 use crate::oracle::event::generate_random_event;
 use crate::oracle::fate_chart::{Likelihood, consult_oracle};
+use crate::oracle::location::generate_location;
 use crate::oracle::reaction::roll_npc_reaction;
 use crate::oracle::tables::OracleConfig;
+use crate::save::SoloSave;
 use crate::scene::{Scene, SceneStatus, check_scene_setup};
 use crate::tracker::npcs::NpcList;
+use crate::tracker::rolls::{RollLog, RollStats};
 use crate::tracker::threads::ThreadList;
 
+/// How many scenes before `session_scene_limit` the pacing prompt starts.
+const PACING_WINDOW: u32 = 2;
+
 /// An interactive solo TTRPG session.
 pub struct SoloSession {
     fiction: FictionSession,
@@ -40,14 +50,82 @@ pub struct SoloSession {
     ruleset: Option<RuleSet>,
     sheet: Option<CharacterSheet>,
     world_config: SoloWorldConfig,
+    /// Column width output is wrapped to, from [`SoloConfig::wrap_width`].
+    wrap_width: Option<usize>,
+    bookmarks: BTreeMap<String, SessionSnapshot>,
+    pending_restore: Option<String>,
+    /// The most recent oracle/check/dice command, for `reroll`.
+    last_roll: Option<String>,
+    /// Breakdown of the most recent check, for `explain`.
+    last_check: Option<String>,
+    /// Recent checks and dice rolls, for `stats`.
+    rolls: RollLog,
+    /// Oracle answers established so far, keyed by normalized question.
+    facts: BTreeMap<String, String>,
+}
+
+/// A full copy of the mutable session state, stored under a bookmark name.
+#[derive(Debug, Clone)]
+struct SessionSnapshot {
+    name: String,
+    player: PlayerState,
+    chaos: ChaosFactor,
+    current_scene: Option<Scene>,
+    scene_count: u32,
+    journal: Journal,
+    threads: ThreadList,
+    npcs: NpcList,
+    rng: StdRng,
+    sheet: Option<CharacterSheet>,
+    facts: BTreeMap<String, String>,
 }
 
 impl SoloSession {
     /// Create a new solo session from a compiled world.
     pub fn new(world: World, config: SoloConfig) -> SoloResult<Self> {
+        let seed = world.meta.rng_seed(config.seed).unwrap_or(DEFAULT_SEED);
         let fiction = FictionSession::new(world)?;
-        let rng = StdRng::seed_from_u64(config.seed);
-        let chaos = ChaosFactor::new(config.initial_chaos);
+        Ok(Self::with_fiction(fiction, config, seed))
+    }
+
+    /// Resume a saved session in a freshly loaded world.
+    pub fn load(world: World, config: SoloConfig, save: SoloSave) -> SoloResult<Self> {
+        let fiction = FictionSession::load(world, save.fiction)?;
+        let mut session = Self::with_fiction(fiction, config, save.rng_seed);
+        // The configured bounds win over those the save was made with
+        session.chaos =
+            ChaosFactor::with_bounds(save.chaos.value(), session.chaos.min(), session.chaos.max());
+        session.current_scene = save.current_scene;
+        session.scene_count = save.scene_count;
+        session.journal = save.journal;
+        session.threads = save.threads;
+        session.npcs = save.npcs;
+        session.facts = save.facts;
+        Ok(session)
+    }
+
+    /// Capture the session's progress for [`SoloSession::load`].
+    ///
+    /// Bookmarks and the character sheet's current state are not saved.
+    pub fn save(&self) -> SoloSave {
+        SoloSave {
+            fiction: self.fiction.save(),
+            chaos: self.chaos.clone(),
+            current_scene: self.current_scene.clone(),
+            scene_count: self.scene_count,
+            journal: self.journal.clone(),
+            threads: self.threads.clone(),
+            npcs: self.npcs.clone(),
+            facts: self.facts.clone(),
+            // Continue the random stream from here rather than its start
+            rng_seed: self.rng.clone().random(),
+        }
+    }
+
+    fn with_fiction(fiction: FictionSession, config: SoloConfig, seed: u64) -> Self {
+        let rng = StdRng::seed_from_u64(seed);
+        let chaos =
+            ChaosFactor::with_bounds(config.initial_chaos, config.chaos_min, config.chaos_max);
         let oracle_config = OracleConfig::from_world(fiction.world());
         let npcs = NpcList::new();
 
@@ -64,7 +142,7 @@ impl SoloSession {
                 .find_map(|e| CharacterSheet::from_entity(e, rs).ok())
         });
 
-        Ok(Self {
+        Self {
             fiction,
             chaos,
             oracle_config,
@@ -77,7 +155,14 @@ impl SoloSession {
             ruleset,
             sheet,
             world_config,
-        })
+            wrap_width: config.wrap_width,
+            bookmarks: BTreeMap::new(),
+            pending_restore: None,
+            last_roll: None,
+            last_check: None,
+            rolls: RollLog::new(),
+            facts: BTreeMap::new(),
+        }
     }
 
     /// Get the chaos factor.
@@ -120,11 +205,21 @@ impl SoloSession {
         self.sheet.as_ref()
     }
 
+    /// Statistics over the session's recent checks and dice rolls.
+    pub fn roll_stats(&self) -> RollStats {
+        self.rolls.stats()
+    }
+
     /// Get the world-level solo configuration.
     pub fn world_config(&self) -> &SoloWorldConfig {
         &self.world_config
     }
 
+    /// Names of all bookmarks, in alphabetical order.
+    pub fn bookmark_names(&self) -> Vec<&str> {
+        self.bookmarks.values().map(|b| b.name.as_str()).collect()
+    }
+
     /// Get the solo session intro/welcome text.
     ///
     /// Returns custom text from the world's `solo { intro "..." }` block
@@ -181,10 +276,14 @@ impl SoloSession {
         // Top-level command list (used for empty input and prefix matching)
         let mut commands: Vec<&str> = vec![
             "ask ",
+            "reask ",
             "reaction ",
             "event",
             "check ",
             "roll ",
+            "reroll",
+            "explain",
+            "stats",
             "panic",
             "encounter ",
             "sheet",
@@ -193,16 +292,22 @@ impl SoloSession {
             "threads",
             "npcs",
             "note ",
+            "bookmark ",
+            "bookmarks",
+            "restore ",
             "export ",
             "thread add ",
             "thread close ",
             "thread remove ",
+            "thread priority ",
             "npc add ",
             "npc remove ",
             "help",
             "look",
             "examine ",
             "go ",
+            "back",
+            "map",
             "talk ",
         ];
 
@@ -221,7 +326,11 @@ impl SoloSession {
         let rest = parts.get(1).copied().unwrap_or("");
 
         match cmd.as_str() {
-            "ask" if parts.len() > 1 => {
+            "ask" | "reask" if parts.len() > 1 => {
+                let (cmd, rest) = match split_complex(rest) {
+                    Ok((true, rest)) => (format!("{cmd} complex"), rest),
+                    _ => (cmd, rest),
+                };
                 let rest_lower = rest.to_lowercase();
                 let likelihoods = [
                     "impossible",
@@ -236,10 +345,16 @@ impl SoloSession {
                     "a sure thing",
                     "has to be",
                 ];
-                likelihoods
-                    .iter()
-                    .filter(|l| l.starts_with(&rest_lower))
-                    .map(|l| format!("ask {l} "))
+                let complex = (!cmd.ends_with(" complex") && "complex".starts_with(&rest_lower))
+                    .then(|| format!("{cmd} complex "));
+                complex
+                    .into_iter()
+                    .chain(
+                        likelihoods
+                            .iter()
+                            .filter(|l| l.starts_with(&rest_lower))
+                            .map(|l| format!("{cmd} {l} ")),
+                    )
                     .collect()
             }
             "check" if parts.len() > 1 => {
@@ -288,18 +403,17 @@ impl SoloSession {
                 let sub = sub_parts[0].to_lowercase();
                 let sub_rest = sub_parts.get(1).copied().unwrap_or("");
 
+                let subs = ["add", "close", "remove", "priority"];
                 if sub.is_empty()
-                    || (!["add", "close", "remove"].contains(&sub.as_str())
-                        && ["add", "close", "remove"]
-                            .iter()
-                            .any(|s| s.starts_with(&sub)))
+                    || (!subs.contains(&sub.as_str()) && subs.iter().any(|s| s.starts_with(&sub)))
                 {
-                    ["add", "close", "remove"]
-                        .iter()
+                    subs.iter()
                         .filter(|s| s.starts_with(&sub))
                         .map(|s| format!("thread {s} "))
                         .collect()
-                } else if (sub == "close" || sub == "remove") && sub_parts.len() > 1 {
+                } else if (sub == "close" || sub == "remove" || sub == "priority")
+                    && sub_parts.len() > 1
+                {
                     let name_lower = sub_rest.to_lowercase();
                     self.threads
                         .active()
@@ -339,9 +453,17 @@ impl SoloSession {
                     vec![]
                 }
             }
+            "restore" if parts.len() > 1 => {
+                let rest_lower = rest.to_lowercase();
+                self.bookmarks
+                    .values()
+                    .filter(|b| b.name.to_lowercase().starts_with(&rest_lower))
+                    .map(|b| format!("restore {}", b.name))
+                    .collect()
+            }
             "export" if parts.len() > 1 => {
                 let rest_lower = rest.to_lowercase();
-                ["markdown", "text"]
+                ["markdown", "text", "handout"]
                     .iter()
                     .filter(|f| f.starts_with(&rest_lower))
                     .map(|f| format!("export {f}"))
@@ -380,7 +502,17 @@ impl SoloSession {
     }
 
     /// Process a line of user input and return a response.
+    ///
+    /// The response is word-wrapped to [`SoloConfig::wrap_width`], if set.
     pub fn process(&mut self, input: &str) -> SoloResult<String> {
+        let output = self.dispatch(input)?;
+        Ok(match self.wrap_width {
+            Some(width) => ww_core::text::wrap(&output, width),
+            None => output,
+        })
+    }
+
+    fn dispatch(&mut self, input: &str) -> SoloResult<String> {
         let trimmed = input.trim();
         if trimmed.is_empty() {
             return Ok(String::new());
@@ -391,8 +523,16 @@ impl SoloSession {
         let cmd = parts[0].to_lowercase();
         let rest = parts.get(1).map(|s| s.trim()).unwrap_or("");
 
-        match cmd.as_str() {
-            "ask" => self.do_oracle(rest),
+        // A pending restore only survives until the next command
+        if let Some(key) = self.pending_restore.take()
+            && cmd == "confirm"
+        {
+            return self.do_restore_confirmed(&key);
+        }
+
+        let result = match cmd.as_str() {
+            "ask" => self.do_oracle(rest, false),
+            "reask" => self.do_oracle(rest, true),
             "reaction" => self.do_reaction(rest),
             "event" => self.do_event(),
             "scene" => {
@@ -421,13 +561,20 @@ impl SoloSession {
             "npc" => self.do_npc(rest),
             "npcs" => self.do_npc_list(),
             "note" => self.do_note(rest),
+            "bookmark" => self.do_bookmark(rest),
+            "bookmarks" => self.do_bookmark_list(),
+            "restore" => self.do_restore(rest),
             "journal" => self.do_journal_show(),
             "export" => self.do_journal_export(rest),
             "check" => self.do_check(rest),
             "roll" => self.do_roll(rest),
+            "reroll" => self.do_reroll(),
+            "explain" => self.do_explain(),
+            "stats" => Ok(self.roll_stats().to_string()),
             "panic" => self.do_panic(),
             "encounter" => self.do_encounter(rest),
-            "sheet" => self.do_sheet(),
+            "generate" => self.do_generate(rest),
+            "sheet" => self.do_sheet(rest),
             "status" => self.do_status(),
             "help" => self.do_help(rest),
             "quit" | "q" => Ok("Goodbye!".to_string()),
@@ -435,17 +582,42 @@ impl SoloSession {
                 // Forward to fiction session
                 self.fiction.process(trimmed).map_err(SoloError::from)
             }
+        };
+
+        // Hand-entered results have nothing to reroll
+        if result.is_ok()
+            && is_rerollable(&cmd)
+            && !(matches!(cmd.as_str(), "roll" | "check") && rest.contains('='))
+        {
+            self.last_roll = Some(trimmed.to_string());
         }
+        result
     }
 
-    fn do_oracle(&mut self, rest: &str) -> SoloResult<String> {
-        // Parse: ask [likelihood] question?
+    fn do_oracle(&mut self, rest: &str, reask: bool) -> SoloResult<String> {
+        // Parse: ask [complex] [likelihood] question?
         // Try to find likelihood as first word, otherwise default to 50/50
+        let (complex, rest) = split_complex(rest)?;
         let (likelihood, question) = parse_oracle_input(rest)?;
 
+        let fact_key = self
+            .world_config
+            .oracle_facts
+            .then(|| normalize_question(question));
+        if !reask && let Some(answer) = fact_key.as_ref().and_then(|key| self.facts.get(key)) {
+            let prefix = self
+                .world_config
+                .oracle_prefix
+                .as_deref()
+                .unwrap_or("Oracle:");
+            return Ok(format!(
+                "{prefix} {answer}\n[established earlier; 'reask' to roll again]"
+            ));
+        }
+
         let result = consult_oracle(
             likelihood,
-            self.chaos.value(),
+            self.chaos.chart_value(),
             &mut self.rng,
             &self.oracle_config,
         );
@@ -462,6 +634,12 @@ impl SoloSession {
             )
         };
 
+        // Compound questions get meaning words to read the answer by
+        let interpretation = complex.then(|| self.oracle_config.random_meaning(&mut self.rng));
+        if let Some(words) = &interpretation {
+            output.push_str(&format!("\n  Meaning: {words}"));
+        }
+
         let random_event_str = if let Some(ref event) = result.random_event {
             let desc = event.to_string();
             output.push_str(&format!("\n  Random Event! {desc}"));
@@ -476,9 +654,14 @@ impl SoloSession {
             chaos: self.chaos.value(),
             result: result.answer.to_string(),
             random_event: random_event_str,
+            interpretation,
             timestamp: Utc::now(),
         });
 
+        if let Some(key) = fact_key {
+            self.facts.insert(key, result.answer.to_string());
+        }
+
         Ok(output)
     }
 
@@ -536,7 +719,11 @@ impl SoloSession {
         }
 
         self.scene_count += 1;
-        let status = check_scene_setup(self.chaos.value(), &mut self.rng, &self.oracle_config);
+        if let Some(minutes) = self.fiction.clock().map(|c| c.minutes_per_scene) {
+            self.fiction.pass_time(minutes);
+        }
+        let status =
+            check_scene_setup(self.chaos.chart_value(), &mut self.rng, &self.oracle_config);
         let n = self.scene_count;
 
         let status_text = status.to_string();
@@ -547,6 +734,12 @@ impl SoloSession {
             .unwrap_or("--- Scene {n} ---")
             .replace("{n}", &n.to_string());
         let mut output = format!("{header}\nSetup: {setup}\n");
+        if let Some(clock) = self.fiction.clock() {
+            output.push_str(&format!("Time: {clock}\n"));
+        }
+        if let Some(prompt) = self.pacing_prompt() {
+            output.push_str(&format!("{prompt}\n"));
+        }
 
         match &status {
             SceneStatus::Normal => {
@@ -597,6 +790,32 @@ impl SoloSession {
         Ok(output)
     }
 
+    /// A wrap-up nudge once the current scene is within
+    /// [`PACING_WINDOW`] scenes of the world's `session_scene_limit`.
+    fn pacing_prompt(&self) -> Option<String> {
+        let limit = self.world_config.session_scene_limit?;
+        let n = self.scene_count;
+        if n > limit {
+            Some(format!(
+                "Past the session's {limit}-scene limit: time to bring the story to a close."
+            ))
+        } else if n == limit {
+            Some("Final scene of the session: consider resolving your threads.".to_string())
+        } else if limit - n <= PACING_WINDOW {
+            let remaining = limit - n;
+            let scenes = if remaining == 1 {
+                "scene remains"
+            } else {
+                "scenes remain"
+            };
+            Some(format!(
+                "Consider resolving your threads; {remaining} {scenes} after this one."
+            ))
+        } else {
+            None
+        }
+    }
+
     fn do_scene_end(&mut self, rest: &str) -> SoloResult<String> {
         if self.current_scene.is_none() {
             return Err(SoloError::NoActiveScene);
@@ -626,11 +845,17 @@ impl SoloSession {
             .chaos_label
             .as_deref()
             .unwrap_or("Chaos factor");
-        let output = format!(
+        let mut output = format!(
             "{end_label} {summary}\n{chaos_label}: {} ({})",
             self.chaos.value(),
             if went_well { "-1" } else { "+1" }
         );
+        if self.world_config.scene_prompt
+            && let Some(prompt) = self.next_scene_prompt()
+        {
+            output.push('\n');
+            output.push_str(&prompt);
+        }
 
         self.journal.append(JournalEntry::SceneEnd {
             scene_number: scene_num,
@@ -642,6 +867,24 @@ impl SoloSession {
         Ok(output)
     }
 
+    /// Suggest what the next scene could involve: a priority-weighted active
+    /// thread and a tracked NPC. `None` if both trackers are empty.
+    fn next_scene_prompt(&mut self) -> Option<String> {
+        let thread = self
+            .threads
+            .random_active(&mut self.rng)
+            .map(|t| t.name.clone());
+        let npc = self.npcs.random(&mut self.rng).map(|n| n.name.clone());
+        let parts: Vec<String> = thread.into_iter().chain(npc).collect();
+        if parts.is_empty() {
+            return None;
+        }
+        Some(format!(
+            "Consider a scene involving: {}.",
+            parts.join(" / ")
+        ))
+    }
+
     fn do_thread(&mut self, rest: &str) -> SoloResult<String> {
         let parts: Vec<&str> = rest.splitn(2, ' ').collect();
         let sub = parts[0].to_lowercase();
@@ -666,8 +909,25 @@ impl SoloSession {
                     Ok(format!("Thread not found: {arg}"))
                 }
             }
+            "priority" => {
+                let parsed = arg
+                    .rsplit_once(' ')
+                    .and_then(|(name, n)| Some((name.trim(), n.parse::<u32>().ok()?)));
+                match parsed {
+                    Some((name, priority)) if !name.is_empty() => {
+                        if self.threads.set_priority(name, priority) {
+                            Ok(format!("Thread priority set: {name} ({priority})"))
+                        } else {
+                            Ok(format!("Thread not found: {name}"))
+                        }
+                    }
+                    _ => Err(SoloError::InvalidChoice(
+                        "usage: thread priority <name> <n>".to_string(),
+                    )),
+                }
+            }
             _ => Err(SoloError::InvalidChoice(
-                "usage: thread add|close|remove <name>".to_string(),
+                "usage: thread add|close|remove <name>, thread priority <name> <n>".to_string(),
             )),
         }
     }
@@ -677,11 +937,12 @@ impl SoloSession {
         if active.is_empty() {
             return Ok("No active threads.".to_string());
         }
-        let mut out = format!("Active threads ({}):\n", active.len());
-        for (i, t) in active.iter().enumerate() {
-            out.push_str(&format!("  {}. {}\n", i + 1, t.name));
-        }
-        Ok(out.trim_end().to_string())
+        let items: Vec<_> = active.iter().map(|t| ListItem::new(&t.name)).collect();
+        Ok(format!(
+            "Active threads ({}):\n{}",
+            active.len(),
+            self.format_list(&items)
+        ))
     }
 
     fn do_npc(&mut self, rest: &str) -> SoloResult<String> {
@@ -694,6 +955,16 @@ impl SoloSession {
                 self.npcs.add(arg);
                 Ok(format!("NPC added: {arg}"))
             }
+            "add" => match self.oracle_config.random_name(&mut self.rng) {
+                Some(name) => {
+                    let name = name.to_string();
+                    self.npcs.add(&name);
+                    Ok(format!("NPC added: {name}"))
+                }
+                None => Err(SoloError::InvalidChoice(
+                    "usage: npc add <name> (no name list to draw from)".to_string(),
+                )),
+            },
             "remove" if !arg.is_empty() => {
                 if self.npcs.remove(arg) {
                     Ok(format!("NPC removed: {arg}"))
@@ -712,15 +983,107 @@ impl SoloSession {
         if list.is_empty() {
             return Ok("No tracked NPCs.".to_string());
         }
-        let mut out = format!("Tracked NPCs ({}):\n", list.len());
-        for (i, n) in list.iter().enumerate() {
-            out.push_str(&format!("  {}. {}", i + 1, n.name));
-            if let Some(notes) = &n.notes {
-                out.push_str(&format!(" — {notes}"));
+        let items: Vec<_> = list
+            .iter()
+            .map(|n| match &n.notes {
+                Some(notes) => ListItem::new(format!("{} — {notes}", n.name)),
+                None => ListItem::new(&n.name),
+            })
+            .collect();
+        Ok(format!(
+            "Tracked NPCs ({}):\n{}",
+            list.len(),
+            self.format_list(&items)
+        ))
+    }
+
+    /// Improvise a location; with a direction, also add it to the world
+    /// beyond an exit from the player's current location.
+    fn do_generate(&mut self, rest: &str) -> SoloResult<String> {
+        let usage =
+            || SoloError::InvalidChoice("usage: generate location [<direction>]".to_string());
+        let mut words = rest.split_whitespace();
+        if words.next().map(str::to_lowercase).as_deref() != Some("location") {
+            return Err(usage());
+        }
+        let direction = match words.next() {
+            Some(word) => Some(Direction::parse(word).ok_or_else(usage)?),
+            None => None,
+        };
+
+        let here = self.fiction.player().location;
+        if let Some(direction) = direction {
+            let taken = self
+                .fiction
+                .world()
+                .relationships_from(here)
+                .iter()
+                .any(|r| {
+                    r.kind == RelationshipKind::ConnectedTo
+                        && r.label
+                            .as_deref()
+                            .is_some_and(|l| l.eq_ignore_ascii_case(direction.name()))
+                });
+            if taken {
+                return Err(SoloError::InvalidChoice(format!(
+                    "there is already an exit {}",
+                    direction.name()
+                )));
             }
-            out.push('\n');
         }
-        Ok(out.trim_end().to_string())
+
+        let back = direction.map(|d| d.opposite().name());
+        let mut location = generate_location(&mut self.rng, &self.oracle_config, back);
+        let description = location.description();
+
+        if let Some(direction) = direction {
+            let world = self.fiction.world_mut();
+            let base = location.name.clone();
+            let mut n = 2;
+            while world.find_by_name(&location.name).is_some() {
+                location.name = format!("{base} {n}");
+                n += 1;
+            }
+            let mut entity = Entity::new(EntityKind::Location, &location.name);
+            entity.description = description.clone();
+            let id = entity.id;
+            world
+                .add_entity(entity)
+                .map_err(|e| SoloError::InvalidChoice(e.to_string()))?;
+            let connected = world
+                .add_relationship(
+                    Relationship::new(here, RelationshipKind::ConnectedTo, id)
+                        .with_label(direction.name()),
+                )
+                .and_then(|_| {
+                    world.add_relationship(
+                        Relationship::new(id, RelationshipKind::ConnectedTo, here)
+                            .with_label(direction.opposite().name()),
+                    )
+                });
+            if let Err(e) = connected {
+                // Leave no half-attached room behind
+                let _ = world.remove_entity(id);
+                return Err(SoloError::InvalidChoice(e.to_string()));
+            }
+        }
+
+        self.journal.append(JournalEntry::LocationGenerated {
+            name: location.name.clone(),
+            description,
+            encounter: location.encounter.clone(),
+            timestamp: Utc::now(),
+        });
+
+        let mut output = location.to_string();
+        if let Some(direction) = direction {
+            output.push_str(&format!(
+                "\n{} now lies to the {}.",
+                capitalize_first(&location.name),
+                direction.name()
+            ));
+        }
+        Ok(output)
     }
 
     fn do_note(&mut self, text: &str) -> SoloResult<String> {
@@ -734,6 +1097,113 @@ impl SoloSession {
         Ok("Note recorded.".to_string())
     }
 
+    fn do_bookmark(&mut self, name: &str) -> SoloResult<String> {
+        if name.is_empty() {
+            return Err(SoloError::InvalidChoice(
+                "usage: bookmark <name>".to_string(),
+            ));
+        }
+
+        // Journal first, so the restored journal ends at the bookmark marker
+        self.journal.append(JournalEntry::Bookmark {
+            name: name.to_string(),
+            timestamp: Utc::now(),
+        });
+
+        let snapshot = SessionSnapshot {
+            name: name.to_string(),
+            player: self.fiction.player().clone(),
+            chaos: self.chaos.clone(),
+            current_scene: self.current_scene.clone(),
+            scene_count: self.scene_count,
+            journal: self.journal.clone(),
+            threads: self.threads.clone(),
+            npcs: self.npcs.clone(),
+            rng: self.rng.clone(),
+            sheet: self.sheet.clone(),
+            facts: self.facts.clone(),
+        };
+        let replaced = self
+            .bookmarks
+            .insert(name.to_lowercase(), snapshot)
+            .is_some();
+
+        if replaced {
+            Ok(format!("Bookmark updated: {name}"))
+        } else {
+            Ok(format!("Bookmark saved: {name}"))
+        }
+    }
+
+    fn do_bookmark_list(&self) -> SoloResult<String> {
+        if self.bookmarks.is_empty() {
+            return Ok("No bookmarks.".to_string());
+        }
+        let items: Vec<_> = self
+            .bookmarks
+            .values()
+            .map(|b| ListItem::new(format!("{} ({} journal entries)", b.name, b.journal.len())))
+            .collect();
+        Ok(format!(
+            "Bookmarks ({}):\n{}",
+            self.bookmarks.len(),
+            self.format_list(&items)
+        ))
+    }
+
+    /// Render a tracker list in the narrator's list style (numbered by default).
+    fn format_list(&self, items: &[ListItem]) -> String {
+        self.fiction
+            .narrator()
+            .format_list(items, ListStyle::Numbered)
+    }
+
+    fn do_restore(&mut self, name: &str) -> SoloResult<String> {
+        if name.is_empty() {
+            return Err(SoloError::InvalidChoice(
+                "usage: restore <bookmark name>".to_string(),
+            ));
+        }
+        let key = name.to_lowercase();
+        let Some(snapshot) = self.bookmarks.get(&key) else {
+            return Err(SoloError::InvalidChoice(format!(
+                "bookmark not found: {name}"
+            )));
+        };
+
+        let discarded = self.journal.len().saturating_sub(snapshot.journal.len());
+        let output = format!(
+            "Restore bookmark '{}'? This discards the current state and {discarded} journal \
+             entries recorded since.\nType 'confirm' to proceed, or any other command to cancel.",
+            snapshot.name
+        );
+        self.pending_restore = Some(key);
+        Ok(output)
+    }
+
+    fn do_restore_confirmed(&mut self, key: &str) -> SoloResult<String> {
+        let Some(snapshot) = self.bookmarks.get(key).cloned() else {
+            return Err(SoloError::InvalidChoice(format!(
+                "bookmark not found: {key}"
+            )));
+        };
+
+        *self.fiction.player_mut() = snapshot.player;
+        self.chaos = snapshot.chaos;
+        self.current_scene = snapshot.current_scene;
+        self.scene_count = snapshot.scene_count;
+        self.journal = snapshot.journal;
+        self.threads = snapshot.threads;
+        self.npcs = snapshot.npcs;
+        self.rng = snapshot.rng;
+        self.sheet = snapshot.sheet;
+        self.facts = snapshot.facts;
+        self.last_roll = None;
+        self.last_check = None;
+
+        Ok(format!("Restored bookmark: {}", snapshot.name))
+    }
+
     fn do_journal_show(&self) -> SoloResult<String> {
         if self.journal.is_empty() {
             return Ok("Journal is empty.".to_string());
@@ -758,13 +1228,21 @@ impl SoloSession {
     }
 
     fn do_journal_export(&self, format: &str) -> SoloResult<String> {
-        match format.to_lowercase().as_str() {
-            "markdown" | "md" | "" => Ok(self.journal.export_markdown()),
-            "text" | "txt" => Ok(self.journal.export_text()),
-            other => Err(SoloError::InvalidChoice(format!(
-                "unknown format '{other}', use: markdown, text"
-            ))),
-        }
+        let text = match format.to_lowercase().as_str() {
+            "markdown" | "md" | "" => self.journal.export_markdown(),
+            "text" | "txt" => self.journal.export_text(),
+            "handout" => self.journal.export_handout(&self.threads),
+            other => {
+                return Err(SoloError::InvalidChoice(format!(
+                    "unknown format '{other}', use: markdown, text, handout"
+                )));
+            }
+        };
+        // An explicit width (e.g. `--width`) wins over the world's
+        Ok(match self.wrap_width.or(self.world_config.wrap_width) {
+            Some(width) => ww_core::text::wrap(&text, width),
+            None => text,
+        })
     }
 
     fn do_check(&mut self, rest: &str) -> SoloResult<String> {
@@ -779,30 +1257,69 @@ impl SoloSession {
             ));
         };
 
-        let (attribute, modifier) = parse_check_input(rest)?;
+        let (rest, manual_values) = split_manual_values(rest)?;
+        let (check, opposed) = split_opposed(rest)?;
+        let (attribute, modifier, advantage) = parse_check_input(check)?;
 
+        let entered = !manual_values.is_empty();
         let request = CheckRequest {
             attribute: Some(attribute.clone()),
             modifier,
+            advantage,
+            difficulty_from: opposed.map(|(entity, stat)| (entity.to_string(), stat.to_string())),
+            manual_values,
             ..CheckRequest::default()
         };
 
-        let result = ww_mechanics::rules::perform_check(ruleset, sheet, &request, &mut self.rng)?;
+        let world = self.fiction.world();
+        let result = ww_mechanics::rules::perform_check_in_world(
+            ruleset,
+            sheet,
+            &request,
+            world,
+            &mut self.rng,
+        )?;
+
+        let attribute = match &request.difficulty_from {
+            Some((entity, stat)) => {
+                let value = ww_mechanics::rules::opposed_difficulty(ruleset, world, entity, stat)?;
+                format!("{attribute} vs {entity}.{stat} ({value})")
+            }
+            None => attribute,
+        };
 
-        let values: Vec<u32> = result.roll.dice.iter().map(|d| d.value).collect();
+        let values: Vec<i32> = result.roll.dice.iter().map(|d| d.face_value()).collect();
         let vals_str: Vec<String> = values.iter().map(|v| v.to_string()).collect();
         let dice_desc = format!("{}x{}", result.roll.dice.len(), ruleset.check_die);
 
+        let dropped = if result.roll.dropped.is_empty() {
+            String::new()
+        } else {
+            let values: Vec<String> = result
+                .roll
+                .dropped
+                .iter()
+                .map(|d| d.face_value().to_string())
+                .collect();
+            format!(" (dropped {})", values.join(", "))
+        };
+
         let mut output = format!(
-            "Check {attribute}: {dice_desc} = [{}] — {outcome}",
+            "Check {attribute}: {dice_desc} = [{}]{dropped}{} — {outcome}",
             vals_str.join(", "),
+            if entered { " (entered)" } else { "" },
             outcome = result.outcome,
         );
 
+        if let Some(limit) = &result.pool_limit {
+            output.push_str(&format!("\n  {limit}"));
+        }
         for effect in &result.effects {
             output.push_str(&format!("\n  {effect}"));
         }
 
+        self.last_check = Some(format!("Check {attribute}: {}", result.explain()));
+        self.rolls.record_check(&result);
         self.journal.append(JournalEntry::MechanicsCheck {
             attribute,
             dice: dice_desc,
@@ -815,20 +1332,40 @@ impl SoloSession {
     }
 
     fn do_roll(&mut self, rest: &str) -> SoloResult<String> {
-        let (count, die) = parse_dice_expression(rest)?;
+        let (input, manual_values) = split_manual_values(rest)?;
+        if input.trim().is_empty() {
+            return Err(SoloError::InvalidChoice(
+                "usage: roll <dice> (e.g., d100, 2d6+3, 1d8+2d6, 4dF)".to_string(),
+            ));
+        }
+        let expression = DiceExpression::parse(input)?;
 
-        let pool = DicePool::new().add(die, count);
-        let roll = pool.roll(&mut self.rng);
+        let pool = expression.pool();
+        let roll = if manual_values.is_empty() {
+            pool.roll(&mut self.rng)
+        } else {
+            pool.manual_roll(&manual_values)?
+        };
+        let total = expression.total(&roll)?;
+        self.rolls.record_roll(&roll);
 
-        let values: Vec<u32> = roll.dice.iter().map(|d| d.value).collect();
-        let total: u32 = values.iter().sum();
-        let vals_str: Vec<String> = values.iter().map(|v| v.to_string()).collect();
-        let expression = format!("{count}{die}");
+        let values: Vec<i32> = roll.dice.iter().map(|d| d.face_value()).collect();
+        let breakdown = expression.breakdown(&roll);
 
-        let output = format!("Roll {expression}: [{}] = {total}", vals_str.join(", "));
+        let mut output = if roll.has_fudge() {
+            format!(
+                "Roll {expression}: {breakdown} = {total:+} ({})",
+                ladder_rung(total)
+            )
+        } else {
+            format!("Roll {expression}: {breakdown} = {total}")
+        };
+        if !manual_values.is_empty() {
+            output.push_str(" (entered)");
+        }
 
         self.journal.append(JournalEntry::DiceRoll {
-            expression,
+            expression: input.trim().to_string(),
             values,
             total,
             timestamp: Utc::now(),
@@ -837,6 +1374,44 @@ impl SoloSession {
         Ok(output)
     }
 
+    fn do_explain(&self) -> SoloResult<String> {
+        self.last_check.clone().ok_or_else(|| {
+            SoloError::InvalidChoice("nothing to explain: make a check first".to_string())
+        })
+    }
+
+    fn do_reroll(&mut self) -> SoloResult<String> {
+        let Some(mut command) = self.last_roll.clone() else {
+            return Err(SoloError::InvalidChoice(
+                "nothing to reroll: ask, reaction, event, check, or roll first".to_string(),
+            ));
+        };
+        // Rerolling a question overrides its established answer, in the
+        // same mode it was asked
+        if self.world_config.oracle_facts
+            && let Some((cmd, rest)) = command.split_once(' ')
+            && cmd.eq_ignore_ascii_case("ask")
+        {
+            command = match split_complex(rest)? {
+                (true, question) => format!("reask complex {question}"),
+                (false, question) => format!("reask {question}"),
+            };
+        }
+
+        // The new result's entry lands at `marker`; the marker goes in front of it.
+        let marker = self.journal.len();
+        let output = self.process(&command)?;
+        self.journal.insert(
+            marker,
+            JournalEntry::Reroll {
+                command: command.clone(),
+                timestamp: Utc::now(),
+            },
+        );
+
+        Ok(format!("Reroll: {command}\n{output}"))
+    }
+
     fn do_panic(&mut self) -> SoloResult<String> {
         let roll: u32 = self.rng.random_range(1..=20);
 
@@ -864,14 +1439,20 @@ impl SoloSession {
             && let Some(track) = sheet.tracks.get_mut("Stress")
         {
             let old = track.current;
-            track.adjust(1);
+            let result = track.adjust(1);
             output.push_str(&format!("\nStress: {old} -> {}", track.current));
+            if result.overflowed() {
+                output.push_str(&format!(
+                    "\nBREAKDOWN! Stress is already at its maximum of {}.",
+                    track.max
+                ));
+            }
         }
 
         self.journal.append(JournalEntry::DiceRoll {
             expression: "d20 (PANIC)".to_string(),
-            values: vec![roll],
-            total: roll,
+            values: vec![roll as i32],
+            total: roll as i32,
             timestamp: Utc::now(),
         });
 
@@ -939,7 +1520,7 @@ impl SoloSession {
         Ok(output.trim_end().to_string())
     }
 
-    fn do_sheet(&self) -> SoloResult<String> {
+    fn do_sheet(&self, rest: &str) -> SoloResult<String> {
         let Some(ruleset) = &self.ruleset else {
             return Err(SoloError::InvalidChoice(
                 "no game mechanics defined in this world".to_string(),
@@ -951,6 +1532,26 @@ impl SoloSession {
             ));
         };
 
+        let mut words = rest.split_whitespace();
+        match words.next().map(str::to_lowercase).as_deref() {
+            None => {}
+            Some("export") => {
+                let format = words.next().unwrap_or("markdown").to_lowercase();
+                return match format.as_str() {
+                    "markdown" | "md" => Ok(sheet.to_markdown(ruleset).trim_end().to_string()),
+                    "json" => Ok(sheet.to_json()),
+                    other => Err(SoloError::InvalidChoice(format!(
+                        "unknown format '{other}', use: markdown, json"
+                    ))),
+                };
+            }
+            Some(other) => {
+                return Err(SoloError::InvalidChoice(format!(
+                    "unknown sheet command '{other}', use: sheet [export [markdown|json]]"
+                )));
+            }
+        }
+
         let mut out = format!(
             "Character: {}\nSystem: {} ({})\n\n",
             sheet.name, ruleset.name, ruleset.check_die
@@ -976,6 +1577,17 @@ impl SoloSession {
             out.push('\n');
         }
 
+        if !sheet.derived.is_empty() {
+            out.push_str("Derived:\n");
+            for (name, value) in sheet.derived_values() {
+                match value {
+                    Ok(v) => out.push_str(&format!("  {name}: {v}\n")),
+                    Err(e) => out.push_str(&format!("  {name}: ? ({e})\n")),
+                }
+            }
+            out.push('\n');
+        }
+
         if !sheet.tracks.is_empty() {
             out.push_str("Tracks:\n");
             let mut tracks: Vec<_> = sheet.tracks.iter().collect();
@@ -1003,7 +1615,11 @@ impl SoloSession {
                 .chaos_label
                 .as_deref()
                 .unwrap_or("Chaos Factor");
-            out.push_str(&format!("{chaos_label}: {}/9\n", self.chaos.value()));
+            out.push_str(&format!(
+                "{chaos_label}: {}/{}\n",
+                self.chaos.value(),
+                self.chaos.max()
+            ));
 
             match &self.current_scene {
                 Some(scene) => out.push_str(&format!("Current Scene: #{}\n", scene.number)),
@@ -1011,6 +1627,10 @@ impl SoloSession {
             }
         }
 
+        if let Some(clock) = self.fiction.clock() {
+            out.push_str(&format!("Time: {clock}\n"));
+        }
+
         out.push_str(&format!(
             "Threads: {} active\n",
             self.threads.active_count()
@@ -1033,6 +1653,8 @@ impl SoloSession {
             "oracle" | "ask" => Ok("\
 Oracle Commands:
   ask [likelihood] <question>   Consult the oracle (yes/no)
+  ask complex [likelihood] <q>  Also draw meaning words to interpret the answer
+  reask [complex] <question>    Roll again, replacing an established answer
   reaction <npc>                Roll NPC reaction (2d10)
   event                         Generate a random event
 
@@ -1057,27 +1679,43 @@ Thread Commands:
   thread add <name>             Add a plot thread
   thread close <name>           Close a thread
   thread remove <name>          Remove a thread
+  thread priority <name> <n>    Weight a thread for random picks (default 1)
   threads                       List active threads"
                 .to_string()),
             "npc" | "npcs" => Ok("\
 NPC Commands:
   npc add <name>                Track an NPC
+  npc add                       Track an NPC with a name from the genre pack
   npc remove <name>             Remove an NPC
   npcs                          List tracked NPCs"
                 .to_string()),
-            "journal" | "note" => Ok("\
+            "journal" | "note" | "bookmark" | "restore" => Ok("\
 Journal Commands:
   note <text>                   Add a journal note
   journal                       Show recent entries
-  export [markdown|text]        Export full journal"
+  export [markdown|text]        Export full journal
+  export handout                Export a player-facing session recap
+  bookmark <name>               Save the session state under a name
+  bookmarks                     List bookmarks
+  restore <name>                Return to a bookmark (asks to confirm)"
                 .to_string()),
-            "mechanics" | "check" | "roll" | "sheet" | "panic" | "encounter" => Ok("\
+            "mechanics" | "check" | "roll" | "reroll" | "explain" | "stats" | "sheet" | "panic"
+            | "encounter" | "generate" => Ok("\
 Mechanics Commands:
   check <attribute> [modifier]  Roll a check using world rules
-  roll <dice>                   Roll dice (e.g., d100, 2d6, d20)
+  check <attr> adv | dis        Roll an extra die and keep the best / worst
+  check <attr> vs <npc>.<stat>  Opposed check: the NPC's stat sets the difficulty
+  roll <dice>                   Roll dice (e.g., d100, 2d6+3, 1d8+2d6, 4dF)
+  roll <dice> = <values>        Enter physical dice (e.g., roll 2d6 = 4,5)
+  check <attr> = <values>       Resolve a check with physical dice
+  reroll                        Repeat the last ask/reaction/event/check/roll
+  explain                       Break down how the last check was resolved
+  stats                         Summarize recent checks and rolls
   panic                         PANIC check (d20 vs Stress, +1 Stress)
   encounter <creature>          Show creature stats from world
-  sheet                         Show character attributes and tracks"
+  generate location [<dir>]     Improvise a room; with a direction, add it to the world
+  sheet                         Show character attributes and tracks
+  sheet export [markdown|json]  Print the character sheet as a handout"
                 .to_string()),
             _ if self.world_config.help.is_some() => {
                 Ok(self.world_config.help.as_ref().unwrap().clone())
@@ -1097,12 +1735,18 @@ Mechanics Commands:
                     "\
 Solo TTRPG Commands:
   ask [likelihood] <question>   Consult the oracle
+  ask complex <question>        Consult the oracle and draw meaning words
+  reask [likelihood] <question> Ask again, replacing an established answer
   reaction <npc>                Roll NPC reaction
   event                         Force a random event
 {scene_help}  check <attribute> [modifier]  Roll a mechanics check
-  roll <dice>                   Roll dice (d100, 2d6, d20)
+  roll <dice>                   Roll dice (d100, 2d6+3, d20-1)
+  reroll                        Redo the last oracle or dice roll
+  explain                       Explain the last check
+  stats                         Show roll statistics
   panic                         PANIC check (d20 vs Stress)
   encounter <creature>          Show creature stats
+  generate location [<dir>]     Improvise a room to explore
   sheet                         Show character sheet
   thread add|close|remove       Manage plot threads
   threads                       List threads
@@ -1111,6 +1755,9 @@ Solo TTRPG Commands:
   note <text>                   Add journal note
   journal                       Show journal
   export [markdown|text]        Export journal
+  export handout                Export a session recap for players
+  bookmark <name>               Save a named bookmark
+  restore <name>                Return to a bookmark
   status                        Show session status
 {help_topics}
   quit                          Exit
@@ -1124,6 +1771,20 @@ World interaction (forwarded to fiction engine):
 }
 
 /// Parse oracle input: `[likelihood] question?`
+/// Split a leading `complex` off an oracle question.
+fn split_complex(input: &str) -> SoloResult<(bool, &str)> {
+    let (first, rest) = input.split_once(' ').unwrap_or((input, ""));
+    if !first.eq_ignore_ascii_case("complex") {
+        return Ok((false, input));
+    }
+    if rest.trim().is_empty() {
+        return Err(SoloError::InvalidChoice(
+            "usage: ask complex [likelihood] <question>".to_string(),
+        ));
+    }
+    Ok((true, rest.trim_start()))
+}
+
 fn parse_oracle_input(input: &str) -> SoloResult<(Likelihood, &str)> {
     if input.is_empty() {
         return Err(SoloError::InvalidChoice(
@@ -1152,58 +1813,88 @@ fn parse_oracle_input(input: &str) -> SoloResult<(Likelihood, &str)> {
     Ok((Likelihood::FiftyFifty, input))
 }
 
-/// Parse check input: `<attribute> [modifier]`
-fn parse_check_input(input: &str) -> SoloResult<(String, i32)> {
-    if input.is_empty() {
-        return Err(SoloError::InvalidChoice(
-            "usage: check <attribute> [modifier]".to_string(),
-        ));
-    }
-    let parts: Vec<&str> = input.splitn(2, ' ').collect();
-    let attribute = capitalize_first(parts[0]);
-    let modifier = parts
-        .get(1)
-        .and_then(|s| s.trim().parse::<i32>().ok())
-        .unwrap_or(0);
-    Ok((attribute, modifier))
+/// Normalize an oracle question for fact lookup: lowercase, punctuation
+/// dropped, whitespace collapsed.
+fn normalize_question(question: &str) -> String {
+    question
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
 }
 
-/// Parse a dice expression like "d100", "2d6", "d20".
-fn parse_dice_expression(input: &str) -> SoloResult<(u32, Die)> {
-    let input = input.trim().to_lowercase();
+/// Parse check input: `<attribute> [modifier]`
+fn parse_check_input(input: &str) -> SoloResult<(String, i32, i32)> {
     if input.is_empty() {
         return Err(SoloError::InvalidChoice(
-            "usage: roll <dice> (e.g., d100, 2d6, d20)".to_string(),
+            "usage: check <attribute> [modifier] [adv|dis]".to_string(),
         ));
     }
-
-    // Split on 'd' to get count and sides
-    let parts: Vec<&str> = input.splitn(2, 'd').collect();
-    if parts.len() != 2 || parts[1].is_empty() {
-        return Err(SoloError::InvalidChoice(format!(
-            "invalid dice expression: {input}"
-        )));
+    let mut words = input.split_whitespace();
+    let attribute = capitalize_first(words.next().unwrap_or_default());
+    let (mut modifier, mut advantage) = (0, 0);
+    for word in words {
+        match word.to_lowercase().as_str() {
+            "adv" | "advantage" => advantage += 1,
+            "dis" | "disadvantage" => advantage -= 1,
+            other => modifier = other.parse::<i32>().unwrap_or(modifier),
+        }
     }
+    Ok((attribute, modifier, advantage))
+}
 
-    let count = if parts[0].is_empty() {
-        1
-    } else {
-        parts[0]
-            .parse::<u32>()
-            .map_err(|_| SoloError::InvalidChoice(format!("invalid dice count: {}", parts[0])))?
+/// Split `<check> vs <entity>.<stat>` into the check part and the opposed stat.
+fn split_opposed(input: &str) -> SoloResult<(&str, Option<(&str, &str)>)> {
+    let Some(pos) = input
+        .as_bytes()
+        .windows(" vs ".len())
+        .position(|w| w.eq_ignore_ascii_case(b" vs "))
+    else {
+        return Ok((input, None));
     };
+    let target = input[pos + " vs ".len()..].trim();
+    let (entity, stat) = target
+        .rsplit_once('.')
+        .filter(|(e, s)| !e.trim().is_empty() && !s.trim().is_empty())
+        .ok_or_else(|| {
+            SoloError::InvalidChoice(
+                "usage: check <attribute> [modifier] vs <entity>.<stat>".to_string(),
+            )
+        })?;
+    Ok((input[..pos].trim(), Some((entity.trim(), stat.trim()))))
+}
 
-    if count == 0 {
+/// Split hand-rolled results off a roll or check: `2d6 = 4,5` gives
+/// `("2d6", [4, 5])`. Without `=` the values are empty.
+fn split_manual_values(input: &str) -> SoloResult<(&str, Vec<u32>)> {
+    let Some((head, values)) = input.split_once('=') else {
+        return Ok((input, Vec::new()));
+    };
+    let values = values
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|v| !v.is_empty())
+        .map(|v| {
+            v.parse::<u32>()
+                .map_err(|_| SoloError::InvalidChoice(format!("invalid die value: {v}")))
+        })
+        .collect::<SoloResult<Vec<u32>>>()?;
+    if values.is_empty() {
         return Err(SoloError::InvalidChoice(
-            "dice count must be at least 1".to_string(),
+            "list the rolled values after '=', e.g. roll 2d6 = 4,5".to_string(),
         ));
     }
+    Ok((head.trim(), values))
+}
 
-    let die_str = format!("d{}", parts[1]);
-    let die = Die::from_str_tag(&die_str)
-        .ok_or_else(|| SoloError::InvalidChoice(format!("invalid die type: {die_str}")))?;
-
-    Ok((count, die))
+/// Commands whose result is random and can be repeated with `reroll`.
+fn is_rerollable(cmd: &str) -> bool {
+    matches!(
+        cmd,
+        "ask" | "reask" | "reaction" | "event" | "check" | "roll"
+    )
 }
 
 /// Capitalize the first letter of a string.
@@ -1228,7 +1919,7 @@ fn parse_scene_end(input: &str) -> (bool, &str) {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use ww_core::{Entity, EntityKind, World, WorldMeta};
+    use ww_core::WorldMeta;
 
     fn test_world() -> World {
         let mut world = World::new(WorldMeta::new("Test World"));
@@ -1273,6 +1964,66 @@ mod tests {
         assert!(output.contains("Very Likely"));
     }
 
+    #[test]
+    fn oracle_complex_adds_meaning_words() {
+        let mut s = test_session();
+        let output = s
+            .process("ask complex likely Does the guard know something?")
+            .unwrap();
+        assert!(output.contains("Likely"));
+        assert!(output.contains("Meaning: "));
+        match &s.journal().entries()[0] {
+            JournalEntry::OracleQuery {
+                question,
+                interpretation,
+                ..
+            } => {
+                assert_eq!(question, "Does the guard know something?");
+                assert!(interpretation.as_deref().is_some_and(|w| w.contains(" + ")));
+            }
+            other => panic!("expected oracle query, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn oracle_plain_ask_has_no_meaning_words() {
+        let mut s = test_session();
+        let output = s.process("ask Is it raining?").unwrap();
+        assert!(!output.contains("Meaning"));
+        assert!(matches!(
+            &s.journal().entries()[0],
+            JournalEntry::OracleQuery {
+                interpretation: None,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn reroll_keeps_complex_questions_complex() {
+        let mut world = test_world();
+        world.meta.properties.insert(
+            "solo.oracle_facts".to_string(),
+            MetadataValue::Boolean(true),
+        );
+        let mut s = SoloSession::new(world, SoloConfig::default()).unwrap();
+        s.process("Ask complex likely Does the guard know something?")
+            .unwrap();
+
+        let output = s.process("reroll").unwrap();
+        assert!(
+            output.starts_with("Reroll: reask complex likely Does the guard know something?\n"),
+            "{output}"
+        );
+        assert!(output.contains("Meaning: "), "{output}");
+    }
+
+    #[test]
+    fn oracle_complex_requires_question() {
+        let mut s = test_session();
+        assert!(s.process("ask complex").is_err());
+    }
+
     #[test]
     fn npc_reaction() {
         let mut s = test_session();
@@ -1305,6 +2056,61 @@ mod tests {
         assert!(s.chaos().value() <= 5); // decreased or stayed same
     }
 
+    #[test]
+    fn scene_advances_clock() {
+        let mut world = test_world();
+        world.meta.properties.insert(
+            "fiction.minutes_per_scene".to_string(),
+            MetadataValue::Integer(120),
+        );
+        let mut s = SoloSession::new(world, SoloConfig::default()).unwrap();
+        assert!(s.process("status").unwrap().contains("Time: Day 1, 08:00"));
+
+        let output = s.process("scene Sneak into the vault").unwrap();
+        assert!(output.contains("Time: Day 1, 10:00 (morning)"));
+        assert!(s.process("time").unwrap().contains("10:00"));
+    }
+
+    #[test]
+    fn scene_limit_prompts_wrap_up() {
+        let mut world = test_world();
+        world.meta.properties.insert(
+            "solo.session_scene_limit".to_string(),
+            MetadataValue::Integer(4),
+        );
+        let mut s = SoloSession::new(world, SoloConfig::default()).unwrap();
+
+        let mut prompts = Vec::new();
+        for n in 1..=5 {
+            let output = s.process(&format!("scene Scene number {n}")).unwrap();
+            s.process("end scene well done").unwrap();
+            prompts.push(
+                output
+                    .lines()
+                    .find(|l| l.contains("threads") || l.contains("limit"))
+                    .map(str::to_string),
+            );
+        }
+        assert_eq!(prompts[0], None);
+        assert_eq!(
+            prompts[1].as_deref(),
+            Some("Consider resolving your threads; 2 scenes remain after this one.")
+        );
+        assert_eq!(
+            prompts[2].as_deref(),
+            Some("Consider resolving your threads; 1 scene remains after this one.")
+        );
+        assert!(prompts[3].as_deref().unwrap().starts_with("Final scene"));
+        assert!(prompts[4].as_deref().unwrap().contains("4-scene limit"));
+
+        // Without a limit there is no prompt
+        let mut s = test_session();
+        for _ in 0..5 {
+            let output = s.process("scene Wander").unwrap();
+            assert!(!output.contains("resolving your threads"));
+        }
+    }
+
     #[test]
     fn scene_end_badly_increases_chaos() {
         let mut s = test_session();
@@ -1328,6 +2134,39 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn scene_end_suggests_next_scene_when_enabled() {
+        let mut s = test_session();
+        s.process("thread add Find the artifact").unwrap();
+        s.process("scene Enter the tavern").unwrap();
+        let output = s.process("end scene well Made an ally").unwrap();
+        assert!(!output.contains("Consider a scene"));
+
+        let mut world = test_world();
+        world.meta.properties.insert(
+            "solo.scene_prompt".to_string(),
+            MetadataValue::Boolean(true),
+        );
+        let mut s = SoloSession::new(world, SoloConfig::default()).unwrap();
+        s.process("scene Enter the tavern").unwrap();
+        let output = s.process("end scene well Quiet night").unwrap();
+        assert!(!output.contains("Consider a scene"));
+
+        s.process("thread add Find the artifact").unwrap();
+        s.process("thread add Escape the city").unwrap();
+        assert_eq!(
+            s.process("thread priority Escape the city 0").unwrap(),
+            "Thread priority set: Escape the city (0)"
+        );
+        s.process("npc add Old Tom").unwrap();
+        s.process("scene Search the ruins").unwrap();
+        let output = s.process("end scene badly Found nothing").unwrap();
+        assert!(
+            output.ends_with("Consider a scene involving: Find the artifact / Old Tom."),
+            "{output}"
+        );
+    }
+
     #[test]
     fn thread_management() {
         let mut s = test_session();
@@ -1344,6 +2183,26 @@ mod tests {
         assert_eq!(s.threads().active_count(), 0);
     }
 
+    #[test]
+    fn tracker_lists_follow_list_style() {
+        let mut s = test_session();
+        s.process("thread add Find the artifact").unwrap();
+        s.process("thread add Escape the city").unwrap();
+        assert_eq!(
+            s.process("threads").unwrap(),
+            "Active threads (2):\n  1. Find the artifact\n  2. Escape the city"
+        );
+
+        let mut world = test_world();
+        world.meta.properties.insert(
+            "fiction.list_style".to_string(),
+            MetadataValue::String("bulleted".to_string()),
+        );
+        let mut s = SoloSession::new(world, SoloConfig::default()).unwrap();
+        s.process("npc add Old Tom").unwrap();
+        assert_eq!(s.process("npcs").unwrap(), "Tracked NPCs (1):\n  - Old Tom");
+    }
+
     #[test]
     fn npc_management() {
         let mut s = test_session();
@@ -1357,6 +2216,24 @@ mod tests {
         assert_eq!(s.npcs().count(), 0);
     }
 
+    #[test]
+    fn npc_add_without_name_draws_from_genre_pack() {
+        let mut s = test_session();
+        assert!(s.process("npc add").is_err());
+
+        let mut world = test_world();
+        world.meta.properties.insert(
+            "solo.genre_pack".to_string(),
+            MetadataValue::String("noir".to_string()),
+        );
+        let mut s = SoloSession::new(world, SoloConfig::default()).unwrap();
+        let out = s.process("npc add").unwrap();
+        let name = out.strip_prefix("NPC added: ").unwrap();
+        let noir = crate::oracle::tables::genre_pack("noir").unwrap();
+        assert!(noir.names.contains(&name));
+        assert_eq!(s.npcs().count(), 1);
+    }
+
     #[test]
     fn note_and_journal() {
         let mut s = test_session();
@@ -1379,6 +2256,228 @@ mod tests {
         assert!(txt.contains("Solo Session Journal"));
     }
 
+    #[test]
+    fn journal_export_handout() {
+        let mut s = test_session();
+        s.process("thread add Find the missing heir").unwrap();
+        s.process("roll 2d6").unwrap();
+
+        let handout = s.process("export handout").unwrap();
+        assert!(handout.starts_with("# Session Recap"));
+        assert!(handout.contains("- Find the missing heir"));
+        assert!(!handout.contains("2d6"));
+    }
+
+    #[test]
+    fn journal_export_wraps_to_configured_width() {
+        let mut world = test_world();
+        world
+            .meta
+            .properties
+            .insert("solo.wrap_width".to_string(), MetadataValue::Integer(30));
+        let mut s = SoloSession::new(world, SoloConfig::default()).unwrap();
+        s.process("note The guard at the gate seemed nervous and kept glancing at the tower")
+            .unwrap();
+
+        let md = s.process("export markdown").unwrap();
+        assert!(md.lines().all(|l| l.chars().count() <= 30), "{md}");
+        assert!(md.contains("nervous"));
+    }
+
+    #[test]
+    fn configured_wrap_width_wraps_output_and_overrides_the_world() {
+        let mut world = test_world();
+        world
+            .meta
+            .properties
+            .insert("solo.wrap_width".to_string(), MetadataValue::Integer(30));
+        let config = SoloConfig::default().with_wrap_width(50);
+        let mut s = SoloSession::new(world, config).unwrap();
+        let note = "The guard at the gate seemed nervous and kept glancing at the tower";
+        s.process(&format!("note {note}")).unwrap();
+
+        let md = s.process("export markdown").unwrap();
+        assert!(md.lines().all(|l| l.chars().count() <= 50), "{md}");
+        assert!(md.lines().any(|l| l.chars().count() > 30), "{md}");
+
+        let help = s.process("help").unwrap();
+        assert!(help.lines().all(|l| l.chars().count() <= 50), "{help}");
+    }
+
+    #[test]
+    fn bookmark_and_restore() {
+        let mut s = test_session();
+        s.process("thread add Find the artifact").unwrap();
+        assert_eq!(
+            s.process("bookmark before the heist").unwrap(),
+            "Bookmark saved: before the heist"
+        );
+        assert_eq!(s.journal().len(), 1);
+
+        s.process("scene Break into the vault").unwrap();
+        s.process("end scene badly Caught").unwrap();
+        s.process("thread close Find the artifact").unwrap();
+        assert_eq!(s.chaos().value(), 6);
+
+        let prompt = s.process("restore Before the Heist").unwrap();
+        assert!(prompt.contains("2 journal entries"));
+        assert!(prompt.contains("confirm"));
+        // Nothing changes until confirmed
+        assert_eq!(s.chaos().value(), 6);
+
+        let output = s.process("confirm").unwrap();
+        assert_eq!(output, "Restored bookmark: before the heist");
+        assert_eq!(s.chaos().value(), 5);
+        assert_eq!(s.threads().active_count(), 1);
+        assert_eq!(s.journal().len(), 1);
+        assert_eq!(s.bookmark_names(), vec!["before the heist"]);
+    }
+
+    #[test]
+    fn save_and_load_resume_session() {
+        let mut s = test_session();
+        s.process("thread add Find the artifact").unwrap();
+        s.process("scene Break into the vault").unwrap();
+        s.process("end scene badly Caught").unwrap();
+        s.process("ask likely Is the vault guarded?").unwrap();
+
+        let save = SoloSave::from_json(&s.save().to_json()).unwrap();
+        let mut loaded = SoloSession::load(test_world(), SoloConfig::default(), save).unwrap();
+        assert_eq!(loaded.chaos().value(), 6);
+        assert_eq!(loaded.threads().active_count(), 1);
+        assert_eq!(loaded.journal().len(), s.journal().len());
+        assert_eq!(
+            loaded.fiction.player().location,
+            loaded
+                .fiction
+                .world()
+                .find_id_by_name("the Tavern")
+                .unwrap()
+        );
+
+        // Loading the same save replays the same rolls
+        let mut again = SoloSession::load(test_world(), SoloConfig::default(), s.save()).unwrap();
+        assert_eq!(
+            loaded.process("ask 50/50 Is it raining?").unwrap(),
+            again.process("ask 50/50 Is it raining?").unwrap()
+        );
+    }
+
+    #[test]
+    fn reroll_repeats_last_roll() {
+        let mut s = test_session();
+        assert!(s.process("reroll").is_err());
+
+        s.process("ask likely Is the door locked?").unwrap();
+        s.process("thread add Open the door").unwrap();
+        let output = s.process("reroll").unwrap();
+        assert!(output.starts_with("Reroll: ask likely Is the door locked?\n"));
+
+        let entries = s.journal().entries();
+        assert_eq!(entries.len(), 3);
+        assert!(matches!(&entries[0], JournalEntry::OracleQuery { .. }));
+        assert!(
+            matches!(&entries[1], JournalEntry::Reroll { command, .. } if command == "ask likely Is the door locked?")
+        );
+        assert!(
+            matches!(&entries[2], JournalEntry::OracleQuery { question, .. } if question == "Is the door locked?")
+        );
+
+        // Rerolls chain onto the same command
+        s.process("reroll").unwrap();
+        assert_eq!(s.journal().len(), 5);
+        assert!(
+            s.journal().export_text().contains(
+                "Reroll (ask likely Is the door locked?): the result above is superseded"
+            )
+        );
+    }
+
+    #[test]
+    fn oracle_facts_repeat_established_answers() {
+        let mut world = test_world();
+        world.meta.properties.insert(
+            "solo.oracle_facts".to_string(),
+            MetadataValue::Boolean(true),
+        );
+        let mut s = SoloSession::new(world, SoloConfig::default()).unwrap();
+
+        let first = s.process("ask Is the door locked?").unwrap();
+        let answer = first.lines().next().unwrap().to_string();
+        let again = s.process("ask likely is the door LOCKED").unwrap();
+        assert_eq!(
+            again,
+            format!("{answer}\n[established earlier; 'reask' to roll again]")
+        );
+        assert_eq!(s.journal().len(), 1);
+
+        // Bookmarks carry facts; an override rolls anew
+        s.process("bookmark before").unwrap();
+        assert!(
+            s.process("reask Is the door locked?")
+                .unwrap()
+                .contains("d100")
+        );
+        assert_eq!(s.journal().len(), 3);
+        assert!(
+            s.process("reroll")
+                .unwrap()
+                .starts_with("Reroll: reask Is the door locked?")
+        );
+
+        s.process("restore before").unwrap();
+        s.process("confirm").unwrap();
+        assert_eq!(s.process("ask Is the door locked?").unwrap(), again);
+
+        // Without the toggle every ask rolls
+        let mut s = test_session();
+        s.process("ask Is the door locked?").unwrap();
+        assert!(
+            s.process("ask Is the door locked?")
+                .unwrap()
+                .contains("d100")
+        );
+    }
+
+    #[test]
+    fn reroll_ignores_failed_commands() {
+        let mut s = test_session();
+        s.process("reaction Guard").unwrap();
+        assert!(s.process("roll nonsense").is_err());
+        let output = s.process("reroll").unwrap();
+        assert!(output.starts_with("Reroll: reaction Guard"));
+    }
+
+    #[test]
+    fn restore_cancelled_by_other_command() {
+        let mut s = test_session();
+        s.process("bookmark start").unwrap();
+        s.process("note Something happened").unwrap();
+        s.process("restore start").unwrap();
+        s.process("status").unwrap();
+        // The pending restore was dropped, so confirm is not a command anymore
+        let output = s.process("confirm");
+        assert!(output.is_err() || !output.unwrap().starts_with("Restored"));
+        assert_eq!(s.journal().len(), 2);
+    }
+
+    #[test]
+    fn restore_unknown_bookmark() {
+        let mut s = test_session();
+        assert!(s.process("restore nowhere").is_err());
+        assert!(s.process("bookmark").is_err());
+    }
+
+    #[test]
+    fn bookmark_list_and_completions() {
+        let mut s = test_session();
+        assert_eq!(s.process("bookmarks").unwrap(), "No bookmarks.");
+        s.process("bookmark Camp").unwrap();
+        let list = s.process("bookmarks").unwrap();
+        assert!(list.contains("1. Camp"));
+        assert_eq!(s.completions("restore ca"), vec!["restore Camp"]);
+    }
+
     #[test]
     fn status() {
         let mut s = test_session();
@@ -1581,6 +2680,69 @@ mod tests {
         assert_eq!(s.journal().len(), 1);
     }
 
+    #[test]
+    fn explain_breaks_down_last_check() {
+        let mut s = mechanics_session();
+        assert!(s.process("explain").is_err());
+
+        s.process("check strength").unwrap();
+        let output = s.process("explain").unwrap();
+        assert!(output.starts_with("Check Strength: rolled "), "{output}");
+        assert!(output.contains("at or under succeeds →"));
+        assert!(output.contains("Strength sets the target"));
+    }
+
+    #[test]
+    fn stats_summarize_checks_and_rolls() {
+        let mut s = mechanics_session();
+        assert_eq!(s.process("stats").unwrap(), "No rolls yet.");
+
+        s.process("check strength").unwrap();
+        s.process("check strength").unwrap();
+        s.process("roll 2d6").unwrap();
+        assert!(s.process("roll banana").is_err());
+
+        let stats = s.roll_stats();
+        assert_eq!(stats.checks, 2);
+        assert_eq!(stats.rolls, 1);
+        assert!(stats.successes <= 2);
+        let output = s.process("stats").unwrap();
+        assert!(output.starts_with("2 checks, "), "{output}");
+        assert!(output.contains("d6: 2 rolled"), "{output}");
+    }
+
+    #[test]
+    fn check_opposed_by_entity_stat() {
+        let mut world = mechanics_world();
+        let mut guard = Entity::new(EntityKind::Custom("creature".to_string()), "Dock Guard");
+        guard
+            .properties
+            .insert("mechanics.combat".to_string(), MetadataValue::Integer(50));
+        world.add_entity(guard).unwrap();
+        let mut s = SoloSession::new(world, SoloConfig::default()).unwrap();
+
+        let output = s.process("check speed vs Dock Guard.combat").unwrap();
+        assert!(output.contains("Check Speed vs Dock Guard.combat (50)"));
+        assert_eq!(s.journal().len(), 1);
+
+        assert!(s.process("check speed vs Nobody.combat").is_err());
+        assert!(s.process("check speed vs Dock Guard").is_err());
+    }
+
+    #[test]
+    fn split_opposed_parses_target() {
+        assert_eq!(split_opposed("strength -1").unwrap(), ("strength -1", None));
+        assert_eq!(
+            split_opposed("stealth VS the Guard.perception").unwrap(),
+            ("stealth", Some(("the Guard", "perception")))
+        );
+        // Lowercasing "İ" changes its length; the split must not shift
+        assert_eq!(
+            split_opposed("İnsight vs the Guard.perception").unwrap(),
+            ("İnsight", Some(("the Guard", "perception")))
+        );
+    }
+
     #[test]
     fn check_without_ruleset() {
         let mut s = test_session();
@@ -1617,6 +2779,157 @@ mod tests {
         assert!(output.contains("Roll 2d6"));
     }
 
+    #[test]
+    fn roll_with_entered_values() {
+        let mut s = test_session();
+        assert_eq!(
+            s.process("roll 2d6 = 4,5").unwrap(),
+            "Roll 2d6: [4, 5] = 9 (entered)"
+        );
+        assert!(matches!(
+            &s.journal().entries()[0],
+            JournalEntry::DiceRoll { values, total: 9, .. } if values == &[4, 5]
+        ));
+        assert_eq!(s.roll_stats().rolls, 1);
+        // Entered results are not rerollable
+        assert!(s.process("reroll").is_err());
+
+        assert!(s.process("roll 2d6 = 4,7").is_err());
+        assert!(s.process("roll 2d6 = 4").is_err());
+        assert!(s.process("roll 2d6 = four").is_err());
+        assert!(s.process("roll 2d6 =").is_err());
+        assert_eq!(s.journal().len(), 1);
+    }
+
+    #[test]
+    fn check_with_entered_values() {
+        let mut s = mechanics_session();
+        let output = s.process("check strength = 12").unwrap();
+        assert!(
+            output.starts_with("Check Strength: 1xd100 = [12] (entered) — Success"),
+            "{output}"
+        );
+        assert_eq!(s.journal().len(), 1);
+        assert!(s.process("check strength = 101").is_err());
+        assert!(s.process("check strength = 12, 13").is_err());
+    }
+
+    #[test]
+    fn fudge_dice_roll_and_check() {
+        let mut world = mechanics_world();
+        let rules = world.find_id_by_name("Game Rules").unwrap();
+        let props = &mut world.get_entity_mut(rules).unwrap().properties;
+        for (key, value) in [
+            (
+                "mechanics.system",
+                MetadataValue::String("fate".to_string()),
+            ),
+            (
+                "mechanics.check_die",
+                MetadataValue::String("dF".to_string()),
+            ),
+            (
+                "mechanics.resolution",
+                MetadataValue::String("sum_pool".to_string()),
+            ),
+            ("mechanics.pool_size", MetadataValue::Integer(4)),
+            ("mechanics.target_number", MetadataValue::Integer(2)),
+        ] {
+            props.insert(key.to_string(), value);
+        }
+        let mut s = SoloSession::new(world, SoloConfig::default()).unwrap();
+
+        let output = s.process("roll 4dF").unwrap();
+        assert!(output.starts_with("Roll 4dF: ["), "{output}");
+        let JournalEntry::DiceRoll { values, total, .. } = &s.journal().entries()[0] else {
+            panic!("expected a dice roll entry");
+        };
+        assert!(values.iter().all(|v| (-1..=1).contains(v)));
+        assert!(output.ends_with(&format!("= {total:+} ({})", ladder_rung(*total))));
+
+        let output = s.process("check strength").unwrap();
+        assert!(output.starts_with("Check Strength: 4xdF = ["), "{output}");
+        assert!(s.process("explain").unwrap().contains("vs Fair (+2)"));
+    }
+
+    #[test]
+    fn pbta_check_reads_bands() {
+        let mut world = mechanics_world();
+        let rules = world.find_id_by_name("Game Rules").unwrap();
+        let props = &mut world.get_entity_mut(rules).unwrap().properties;
+        for (key, value) in [
+            (
+                "mechanics.system",
+                MetadataValue::String("pbta".to_string()),
+            ),
+            (
+                "mechanics.resolution",
+                MetadataValue::String("roll_plus".to_string()),
+            ),
+            (
+                "mechanics.check_die",
+                MetadataValue::String("d6".to_string()),
+            ),
+            ("mechanics.pool_size", MetadataValue::Integer(2)),
+        ] {
+            props.insert(key.to_string(), value);
+        }
+        let hero = world.find_id_by_name("Lamplighter").unwrap();
+        world
+            .get_entity_mut(hero)
+            .unwrap()
+            .properties
+            .insert("mechanics.strength".to_string(), MetadataValue::Integer(1));
+        let mut s = SoloSession::new(world, SoloConfig::default()).unwrap();
+
+        let output = s.process("check strength +1 = 3, 3").unwrap();
+        assert!(output.ends_with("— Partial Success"), "{output}");
+        let output = s.process("check strength = 2, 3").unwrap();
+        assert!(output.ends_with("— Failure"), "{output}");
+        assert!(s.process("explain").unwrap().contains("total 5 +1 = 6"));
+    }
+
+    #[test]
+    fn generate_location_is_journaled_and_deterministic() {
+        let mut a = test_session();
+        let mut b = test_session();
+        let output = a.process("generate location").unwrap();
+        assert_eq!(output, b.process("generate location").unwrap());
+        assert!(output.starts_with("Location: the "), "{output}");
+        assert!(output.contains("Encounter: "));
+        assert!(matches!(
+            a.journal().entries()[0],
+            JournalEntry::LocationGenerated { .. }
+        ));
+        // Without a direction nothing is added to the world
+        assert_eq!(a.fiction.world().entity_count(), 1);
+
+        assert!(a.process("generate").is_err());
+        assert!(a.process("generate location sideways").is_err());
+    }
+
+    #[test]
+    fn generate_location_with_direction_adds_a_room() {
+        let mut s = test_session();
+        let output = s.process("generate location north").unwrap();
+        assert!(output.ends_with("now lies to the north."), "{output}");
+        assert!(
+            output.contains("Exits: south\n"),
+            "the way back is the only exit: {output}"
+        );
+
+        let moved = s.process("go north").unwrap();
+        let JournalEntry::LocationGenerated { name, .. } = &s.journal().entries()[0] else {
+            panic!("expected a generated location entry");
+        };
+        let room = s.fiction.world().find_by_name(name).unwrap().id;
+        assert_eq!(s.fiction.player().location, room, "{moved}");
+        s.process("go south").unwrap();
+
+        let err = s.process("generate location north").unwrap_err();
+        assert!(err.to_string().contains("already an exit north"));
+    }
+
     #[test]
     fn roll_invalid() {
         let mut s = test_session();
@@ -1634,6 +2947,21 @@ mod tests {
         assert!(output.contains("Health: 10/10"));
     }
 
+    #[test]
+    fn sheet_export_formats() {
+        let mut s = mechanics_session();
+        let markdown = s.process("sheet export").unwrap();
+        assert!(markdown.starts_with("# Lamplighter"), "{markdown}");
+        assert!(markdown.contains("| Strength | 30 |"));
+        assert!(markdown.contains("- **Health**: `[##########] 10/10`"));
+
+        let json = s.process("sheet export json").unwrap();
+        assert!(json.contains("\"Strength\": 30"), "{json}");
+
+        assert!(s.process("sheet export pdf").is_err());
+        assert!(s.process("sheet print").is_err());
+    }
+
     #[test]
     fn sheet_without_ruleset() {
         let mut s = test_session();
@@ -1669,37 +2997,65 @@ mod tests {
 
     #[test]
     fn parse_check_input_basic() {
-        let (attr, modifier) = parse_check_input("strength").unwrap();
+        let (attr, modifier, advantage) = parse_check_input("strength").unwrap();
         assert_eq!(attr, "Strength");
         assert_eq!(modifier, 0);
+        assert_eq!(advantage, 0);
     }
 
     #[test]
     fn parse_check_input_with_modifier() {
-        let (attr, modifier) = parse_check_input("combat -2").unwrap();
+        let (attr, modifier, _) = parse_check_input("combat -2").unwrap();
         assert_eq!(attr, "Combat");
         assert_eq!(modifier, -2);
     }
 
     #[test]
-    fn parse_dice_expression_d100() {
-        let (count, die) = parse_dice_expression("d100").unwrap();
-        assert_eq!(count, 1);
-        assert_eq!(die, Die::D100);
+    fn parse_check_input_with_advantage() {
+        assert_eq!(
+            parse_check_input("strength adv").unwrap(),
+            ("Strength".to_string(), 0, 1)
+        );
+        assert_eq!(
+            parse_check_input("combat -1 dis").unwrap(),
+            ("Combat".to_string(), -1, -1)
+        );
     }
 
     #[test]
-    fn parse_dice_expression_2d6() {
-        let (count, die) = parse_dice_expression("2d6").unwrap();
-        assert_eq!(count, 2);
-        assert_eq!(die, Die::D6);
+    fn check_with_advantage_drops_a_die() {
+        let mut s = mechanics_session();
+        let output = s.process("check strength adv = 72, 12").unwrap();
+        assert!(
+            output.starts_with("Check Strength: 1xd100 = [12] (dropped 72) (entered) — Success"),
+            "{output}"
+        );
+        let output = s.process("check strength dis = 72, 12").unwrap();
+        assert!(output.contains("= [72] (dropped 12)"), "{output}");
+        assert!(
+            s.process("explain")
+                .unwrap()
+                .contains("disadvantage dropped [12]")
+        );
     }
 
     #[test]
-    fn parse_dice_expression_invalid() {
-        assert!(parse_dice_expression("").is_err());
-        assert!(parse_dice_expression("xyz").is_err());
-        assert!(parse_dice_expression("0d6").is_err());
+    fn roll_expression_with_modifier_and_groups() {
+        let mut s = test_session();
+        assert_eq!(
+            s.process("roll 1d8 + 2d6 + 1 = 5,3,4").unwrap(),
+            "Roll 1d8+2d6+1: [5] + [3, 4] + 1 = 13 (entered)"
+        );
+        assert!(matches!(
+            &s.journal().entries()[0],
+            JournalEntry::DiceRoll { expression, values, total: 13, .. }
+                if expression == "1d8 + 2d6 + 1" && values == &[5, 3, 4]
+        ));
+        assert_eq!(
+            s.process("roll d20-1 = 12").unwrap(),
+            "Roll 1d20-1: [12] - 1 = 11 (entered)"
+        );
+        assert!(s.process("roll 2d6-1d4").is_err());
     }
 
     #[test]
@@ -1875,6 +3231,17 @@ mod tests {
         assert!(status.contains("Pressure: 5/9"));
     }
 
+    #[test]
+    fn chaos_bounds_from_config() {
+        let config = SoloConfig::default().with_chaos_bounds(3, 6).with_chaos(6);
+        let mut s = SoloSession::new(test_world(), config).unwrap();
+        assert!(s.do_status().unwrap().contains("Chaos Factor: 6/6"));
+        s.process("scene Into the dark").unwrap();
+        s.process("end scene badly Lost the map").unwrap();
+        assert_eq!(s.chaos().value(), 6);
+        assert_eq!(s.chaos().chart_value(), 9);
+    }
+
     #[test]
     fn event_prefix_custom() {
         let mut s = full_config_session();
@@ -1912,6 +3279,7 @@ mod tests {
         assert!(output.contains("vs Stress 0"));
         assert!(output.contains("No effect"));
         assert!(output.contains("Stress: 0 -> 1"));
+        assert!(!output.contains("BREAKDOWN!"));
     }
 
     #[test]
@@ -1922,6 +3290,9 @@ mod tests {
         assert!(output.contains("PANIC check: d20 ->"));
         assert!(output.contains("vs Stress 20"));
         assert!(output.contains("PANIC!"));
+        // Stress is already at its maximum, so the +1 overflows.
+        assert!(output.contains("Stress: 20 -> 20"));
+        assert!(output.contains("BREAKDOWN!"));
     }
 
     #[test]
@@ -2040,6 +3411,26 @@ mod tests {
         assert!(c.contains(&"check Strength".to_string()));
     }
 
+    #[test]
+    fn completions_ask_complex() {
+        let s = test_session();
+        assert!(
+            s.completions("ask co")
+                .contains(&"ask complex ".to_string())
+        );
+        assert!(s.completions("ask ").contains(&"ask complex ".to_string()));
+        assert!(
+            s.completions("reask ")
+                .contains(&"reask complex ".to_string())
+        );
+        assert!(
+            !s.completions("ask complex ")
+                .contains(&"ask complex complex ".to_string())
+        );
+        let c = s.completions("ask complex lik");
+        assert!(c.contains(&"ask complex likely ".to_string()));
+    }
+
     #[test]
     fn completions_ask_empty_lists_likelihoods() {
         let s = test_session();
@@ -1,7 +1,9 @@
-//! Tracker for plot threads and NPCs.
+//! Tracker for plot threads, NPCs, and recent rolls.
 
 pub mod npcs;
+pub mod rolls;
 pub mod threads;
 
 pub use npcs::NpcList;
+pub use rolls::{RollLog, RollStats};
 pub use threads::ThreadList;
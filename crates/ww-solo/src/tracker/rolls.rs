@@ -0,0 +1,240 @@
+//! Recent roll history and session statistics.
+
+use std::collections::VecDeque;
+use std::fmt;
+
+use ww_core::grammar::Grammar;
+use ww_mechanics::{CheckResult, Die, Outcome, RollResult};
+
+/// How many recent rolls a [`RollLog`] keeps by default.
+pub const DEFAULT_CAPACITY: usize = 200;
+
+/// One recorded roll: the dice that came up and, for checks, whether it
+/// succeeded.
+#[derive(Debug, Clone)]
+struct RecordedRoll {
+    dice: Vec<(Die, u32)>,
+    success: Option<bool>,
+}
+
+/// Ring buffer of the session's most recent dice rolls and checks.
+///
+/// Once full, the oldest roll is dropped for each new one.
+#[derive(Debug, Clone)]
+pub struct RollLog {
+    rolls: VecDeque<RecordedRoll>,
+    capacity: usize,
+}
+
+impl Default for RollLog {
+    fn default() -> Self {
+        Self::with_capacity(DEFAULT_CAPACITY)
+    }
+}
+
+impl RollLog {
+    /// Create an empty log holding [`DEFAULT_CAPACITY`] rolls.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create an empty log holding at most `capacity` rolls (at least one).
+    pub fn with_capacity(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            rolls: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Record a plain dice roll.
+    pub fn record_roll(&mut self, roll: &RollResult) {
+        self.push(RecordedRoll {
+            dice: roll.dice.iter().map(|d| (d.die, d.value)).collect(),
+            success: None,
+        });
+    }
+
+    /// Record a resolved check.
+    pub fn record_check(&mut self, check: &CheckResult) {
+        self.push(RecordedRoll {
+            dice: check.roll.dice.iter().map(|d| (d.die, d.value)).collect(),
+            success: Some(matches!(
+                check.outcome,
+                Outcome::Success { .. } | Outcome::CriticalSuccess { .. }
+            )),
+        });
+    }
+
+    /// Number of rolls currently held.
+    pub fn len(&self) -> usize {
+        self.rolls.len()
+    }
+
+    /// Whether no rolls have been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.rolls.is_empty()
+    }
+
+    /// Summarize the held rolls.
+    pub fn stats(&self) -> RollStats {
+        let mut stats = RollStats::default();
+        for roll in &self.rolls {
+            match roll.success {
+                Some(success) => {
+                    stats.checks += 1;
+                    stats.successes += u32::from(success);
+                }
+                None => stats.rolls += 1,
+            }
+            for &(die, value) in &roll.dice {
+                match stats.dice.iter_mut().find(|d| d.die == die) {
+                    Some(d) => {
+                        d.count += 1;
+                        d.total += u64::from(value);
+                    }
+                    None => stats.dice.push(DieStats {
+                        die,
+                        count: 1,
+                        total: u64::from(value),
+                    }),
+                }
+            }
+        }
+        stats.dice.sort_by_key(|d| d.die.sides());
+        stats
+    }
+
+    fn push(&mut self, roll: RecordedRoll) {
+        if self.rolls.len() == self.capacity {
+            self.rolls.pop_front();
+        }
+        self.rolls.push_back(roll);
+    }
+}
+
+/// Per-die totals within [`RollStats`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct DieStats {
+    /// The die type.
+    pub die: Die,
+    /// How many of this die were rolled.
+    pub count: u32,
+    /// Sum of all values rolled on this die.
+    pub total: u64,
+}
+
+impl DieStats {
+    /// Mean value rolled on this die.
+    pub fn average(&self) -> f64 {
+        self.total as f64 / f64::from(self.count.max(1))
+    }
+}
+
+/// Summary of recent rolls, from [`RollLog::stats`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RollStats {
+    /// Checks made.
+    pub checks: u32,
+    /// Checks that succeeded (including critical successes).
+    pub successes: u32,
+    /// Plain dice rolls made.
+    pub rolls: u32,
+    /// Per-die averages, ordered by number of sides.
+    pub dice: Vec<DieStats>,
+}
+
+impl fmt::Display for RollStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.checks == 0 && self.rolls == 0 {
+            return write!(f, "No rolls yet.");
+        }
+        let grammar = Grammar::default();
+        write!(
+            f,
+            "{}, {}; {}",
+            grammar.count(self.checks as usize, "check"),
+            grammar.count(self.successes as usize, "success"),
+            grammar.count(self.rolls as usize, "dice roll")
+        )?;
+        for d in &self.dice {
+            write!(
+                f,
+                "\n  {}: {} rolled, average {:.1}",
+                d.die,
+                d.count,
+                d.average()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ww_mechanics::{DiceTag, DieResult};
+
+    fn roll(die: Die, values: &[u32]) -> RollResult {
+        RollResult {
+            dice: values
+                .iter()
+                .map(|&value| DieResult {
+                    die,
+                    tag: DiceTag::default(),
+                    value,
+                    exploded: false,
+                    rerolled: false,
+                    slot: 0,
+                })
+                .collect(),
+            dropped: Vec::new(),
+        }
+    }
+
+    fn check(die: Die, value: u32, outcome: Outcome) -> CheckResult {
+        CheckResult {
+            roll: roll(die, &[value]),
+            outcome,
+            effects: Vec::new(),
+            pool_limit: None,
+            explanation: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn stats_count_checks_successes_and_averages() {
+        let mut log = RollLog::new();
+        log.record_check(&check(Die::D100, 30, Outcome::Success { margin: 10 }));
+        log.record_check(&check(Die::D100, 66, Outcome::Failure));
+        log.record_roll(&roll(Die::D6, &[2, 5]));
+
+        let stats = log.stats();
+        assert_eq!(stats.checks, 2);
+        assert_eq!(stats.successes, 1);
+        assert_eq!(stats.rolls, 1);
+        assert_eq!(stats.dice[0].die, Die::D6);
+        assert_eq!(stats.dice[0].average(), 3.5);
+        assert_eq!(stats.dice[1].die, Die::D100);
+        assert_eq!(stats.dice[1].average(), 48.0);
+        assert_eq!(
+            stats.to_string(),
+            "2 checks, 1 success; 1 dice roll\n  d6: 2 rolled, average 3.5\n  d100: 2 rolled, average 48.0"
+        );
+    }
+
+    #[test]
+    fn ring_buffer_drops_oldest() {
+        let mut log = RollLog::with_capacity(2);
+        log.record_roll(&roll(Die::D20, &[20]));
+        log.record_roll(&roll(Die::D20, &[1]));
+        log.record_roll(&roll(Die::D20, &[3]));
+        assert_eq!(log.len(), 2);
+        assert_eq!(log.stats().dice[0].total, 4);
+    }
+
+    #[test]
+    fn empty_stats() {
+        assert_eq!(RollLog::new().stats().to_string(), "No rolls yet.");
+    }
+}
@@ -11,6 +11,13 @@ pub struct Thread {
     pub name: String,
     /// Whether this thread is still active.
     pub active: bool,
+    /// Relative weight when a thread is drawn at random (default: 1).
+    #[serde(default = "default_priority")]
+    pub priority: u32,
+}
+
+fn default_priority() -> u32 {
+    1
 }
 
 /// List of tracked plot threads.
@@ -30,9 +37,22 @@ impl ThreadList {
         self.threads.push(Thread {
             name: name.into(),
             active: true,
+            priority: default_priority(),
         });
     }
 
+    /// Set the priority of an active thread by name. Returns true if found.
+    pub fn set_priority(&mut self, name: &str, priority: u32) -> bool {
+        let name_lower = name.to_lowercase();
+        for t in &mut self.threads {
+            if t.active && t.name.to_lowercase() == name_lower {
+                t.priority = priority;
+                return true;
+            }
+        }
+        false
+    }
+
     /// Close a thread by name. Returns true if found.
     pub fn close(&mut self, name: &str) -> bool {
         let name_lower = name.to_lowercase();
@@ -58,13 +78,29 @@ impl ThreadList {
         self.threads.iter().filter(|t| t.active).collect()
     }
 
-    /// Pick a random active thread.
+    /// Pick a random active thread, weighted by priority.
+    ///
+    /// A thread with priority 0 is only picked if every active thread has
+    /// priority 0.
     pub fn random_active(&self, rng: &mut StdRng) -> Option<&Thread> {
         let active: Vec<_> = self.active();
         if active.is_empty() {
             return None;
         }
-        Some(active[rng.random_range(0..active.len())])
+        // Summed in u64 so many high priorities cannot overflow
+        let total: u64 = active.iter().map(|t| u64::from(t.priority)).sum();
+        if total == 0 {
+            return Some(active[rng.random_range(0..active.len())]);
+        }
+        let mut roll = rng.random_range(0..total);
+        for thread in &active {
+            let priority = u64::from(thread.priority);
+            if roll < priority {
+                return Some(thread);
+            }
+            roll -= priority;
+        }
+        None
     }
 
     /// Get all threads (active and closed).
@@ -136,6 +172,32 @@ mod tests {
         assert!(t.name == "Thread A" || t.name == "Thread B");
     }
 
+    #[test]
+    fn random_active_follows_priority() {
+        let mut tl = ThreadList::new();
+        tl.add("Thread A");
+        tl.add("Thread B");
+        assert!(tl.set_priority("thread b", 0));
+        assert!(!tl.set_priority("Thread C", 2));
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..20 {
+            assert_eq!(tl.random_active(&mut rng).unwrap().name, "Thread A");
+        }
+    }
+
+    #[test]
+    fn random_active_handles_huge_priorities() {
+        let mut tl = ThreadList::new();
+        tl.add("Thread A");
+        tl.add("Thread B");
+        tl.set_priority("Thread A", u32::MAX);
+        tl.set_priority("Thread B", u32::MAX);
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..20 {
+            assert!(tl.random_active(&mut rng).is_some());
+        }
+    }
+
     #[test]
     fn random_active_empty() {
         let tl = ThreadList::new();
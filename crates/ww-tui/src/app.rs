@@ -1,9 +1,12 @@
 //! Top-level application state managing tabs and shared world data.
 
+use std::path::PathBuf;
+
 use ww_core::World;
 
 use crate::tabs::explorer::ExplorerTab;
 use crate::tabs::graph::GraphTab;
+use crate::tabs::search::SearchTab;
 use crate::tabs::timeline::TimelineTab;
 use crate::tabs::{InputMode, Tab, TabId};
 
@@ -33,22 +36,35 @@ pub struct TuiApp {
     pub sheet: crate::tabs::sheet::SheetTab,
     /// Dice tab (always initialized).
     pub dice: crate::tabs::dice::DiceTab,
+    /// Search tab (always initialized).
+    pub search: SearchTab,
 
     // Solo config
     /// RNG seed for solo/dice.
     pub seed: u64,
     /// Initial chaos factor.
     pub chaos: u32,
+    /// Column width play and solo output is word-wrapped to.
+    pub wrap_width: Option<usize>,
 }
 
 impl TuiApp {
     /// Create a new app from a compiled world.
-    pub fn new(world: World, start_tab: TabId, seed: u64, chaos: u32) -> Self {
+    ///
+    /// Without an explicit `seed`, the world's `rng.seed` is used (see
+    /// [`ww_core::WorldMeta::rng_seed`]).
+    pub fn new(world: World, start_tab: TabId, seed: Option<u64>, chaos: u32) -> Self {
+        let seed = world
+            .meta
+            .rng_seed(seed)
+            .unwrap_or(ww_solo::config::DEFAULT_SEED);
         let explorer = ExplorerTab::new(world.clone());
         let graph = GraphTab::new(world.clone());
         let timeline = TimelineTab::new(world.clone());
         let sheet = crate::tabs::sheet::SheetTab::new(world.clone());
-        let dice = crate::tabs::dice::DiceTab::new(seed);
+        let dice = crate::tabs::dice::DiceTab::new(seed)
+            .with_faces(crate::tabs::dice::DiceFaces::from_world(&world));
+        let search = SearchTab::new(world.clone());
 
         Self {
             world,
@@ -62,11 +78,26 @@ impl TuiApp {
             solo: None,
             sheet,
             dice,
+            search,
             seed,
             chaos,
+            wrap_width: None,
         }
     }
 
+    /// Write edits made in the explorer back to the `.ww` files in `dir`,
+    /// the directory the world was compiled from.
+    pub fn with_world_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.explorer = self.explorer.with_source_dir(dir);
+        self
+    }
+
+    /// Word-wrap play and solo output to `width` columns.
+    pub fn with_wrap_width(mut self, width: usize) -> Self {
+        self.wrap_width = Some(width);
+        self
+    }
+
     /// Get the input mode of the currently active tab.
     pub fn active_input_mode(&self) -> InputMode {
         self.active_tab_ref().input_mode()
@@ -90,6 +121,7 @@ impl TuiApp {
                 .unwrap_or(&self.explorer),
             TabId::Sheet => &self.sheet,
             TabId::Dice => &self.dice,
+            TabId::Search => &self.search,
         }
     }
 
@@ -101,15 +133,21 @@ impl TuiApp {
             TabId::Timeline => &mut self.timeline,
             TabId::Play => {
                 if self.play.is_none() {
-                    self.play = Some(crate::tabs::play::PlayTab::new(self.world.clone()));
+                    self.play = Some(crate::tabs::play::PlayTab::with_wrap_width(
+                        self.world.clone(),
+                        self.wrap_width,
+                    ));
                 }
                 self.play.as_mut().unwrap()
             }
             TabId::Solo => {
                 if self.solo.is_none() {
-                    let config = ww_solo::SoloConfig::default()
+                    let mut config = ww_solo::SoloConfig::default()
                         .with_seed(self.seed)
                         .with_chaos(self.chaos);
+                    if let Some(width) = self.wrap_width {
+                        config = config.with_wrap_width(width);
+                    }
                     match crate::tabs::solo::SoloTab::new(self.world.clone(), config) {
                         Ok(tab) => self.solo = Some(tab),
                         Err(_) => return &mut self.explorer,
@@ -119,6 +157,19 @@ impl TuiApp {
             }
             TabId::Sheet => &mut self.sheet,
             TabId::Dice => &mut self.dice,
+            TabId::Search => &mut self.search,
+        }
+    }
+
+    /// Forward a key to the active tab, then follow any entity the search
+    /// tab chose to open.
+    pub fn forward_key(&mut self, key: crossterm::event::KeyEvent) {
+        if self.active_tab_mut().handle_key(key) {
+            self.should_quit = true;
+        }
+        if let Some(id) = self.search.take_opened() {
+            self.explorer.open_entity(id);
+            self.switch_tab(TabId::Explorer);
         }
     }
 
@@ -2,9 +2,10 @@
 //!
 //! Provides a unified ratatui-based interface with tabs for world exploration,
 //! relationship graphs, timelines, interactive fiction, solo TTRPG, character
-//! sheets, and dice rolling.
+//! sheets, dice rolling, and entity search.
 
 pub mod app;
+pub mod map;
 pub mod shared;
 pub mod tabs;
 pub mod terminal;
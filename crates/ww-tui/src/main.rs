@@ -16,17 +16,21 @@ struct Args {
     #[arg(long)]
     world: PathBuf,
 
-    /// Start on a specific tab (explorer, graph, timeline, play, solo, sheet, dice)
+    /// Start on a specific tab (explorer, graph, timeline, play, solo, sheet, dice, search)
     #[arg(long, default_value = "explorer")]
     tab: String,
 
-    /// RNG seed for solo/dice
-    #[arg(long, default_value = "42")]
-    seed: u64,
+    /// RNG seed for solo/dice (default: the world's rng.seed, else 42)
+    #[arg(long)]
+    seed: Option<u64>,
 
     /// Initial chaos factor for solo (1-9)
     #[arg(long, default_value = "5")]
     chaos: u32,
+
+    /// Wrap play and solo output to this many columns
+    #[arg(long)]
+    width: Option<usize>,
 }
 
 fn main() {
@@ -42,7 +46,11 @@ fn main() {
 
     let tab = ww_tui::tabs::TabId::from_name(&args.tab).unwrap_or(ww_tui::tabs::TabId::Explorer);
 
-    let app = ww_tui::app::TuiApp::new(world, tab, args.seed, args.chaos);
+    let mut app =
+        ww_tui::app::TuiApp::new(world, tab, args.seed, args.chaos).with_world_dir(&args.world);
+    if let Some(width) = args.width {
+        app = app.with_wrap_width(width);
+    }
 
     if let Err(e) = ww_tui::terminal::run(app) {
         eprintln!("error: {e}");
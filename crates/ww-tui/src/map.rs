@@ -0,0 +1,300 @@
+//! Room-connection map: lays out locations on a grid by their directional
+//! exits and draws them as boxed names joined by lines.
+
+use std::collections::{HashMap, VecDeque};
+
+use ratatui::prelude::*;
+use ww_core::{EntityId, RelationshipKind, World};
+
+/// Longest room name shown before it is cut short.
+const MAX_NAME: usize = 12;
+/// Columns between two rooms side by side.
+const GAP: usize = 3;
+
+/// A room placed on the map grid.
+#[derive(Debug, Clone)]
+struct Room {
+    id: EntityId,
+    name: String,
+    x: i32,
+    y: i32,
+}
+
+/// Locations around a room, placed in the direction of the exits that lead
+/// to them: a `north` exit puts its target directly above.
+///
+/// Up, down, and unnamed connections have no place on a flat grid and are
+/// left out, as are rooms whose cell is already taken by another room.
+#[derive(Debug, Clone)]
+pub struct RoomMap {
+    rooms: Vec<Room>,
+    links: Vec<(usize, usize)>,
+}
+
+impl RoomMap {
+    /// Map the rooms reachable from `center` in at most `depth` exits,
+    /// keeping only those `known` accepts.
+    pub fn build(
+        world: &World,
+        center: EntityId,
+        depth: usize,
+        known: impl Fn(EntityId) -> bool,
+    ) -> Self {
+        let mut map = Self {
+            rooms: vec![Room {
+                id: center,
+                name: world.entity_name(center).to_string(),
+                x: 0,
+                y: 0,
+            }],
+            links: Vec::new(),
+        };
+        let mut index: HashMap<EntityId, usize> = HashMap::from([(center, 0)]);
+        let mut cells: HashMap<(i32, i32), usize> = HashMap::from([((0, 0), 0)]);
+        let mut queue = VecDeque::from([(0, 0)]);
+
+        while let Some((from, distance)) = queue.pop_front() {
+            if distance == depth {
+                continue;
+            }
+            let Room { id, x, y, .. } = map.rooms[from];
+            for rel in world.relationships_of(id) {
+                if rel.kind != RelationshipKind::ConnectedTo {
+                    continue;
+                }
+                let Some((dx, dy)) = rel.label.as_deref().and_then(offset) else {
+                    continue;
+                };
+                let (other, dx, dy) = if rel.source == id {
+                    (rel.target, dx, dy)
+                } else {
+                    (rel.source, -dx, -dy)
+                };
+                if other == id || !known(other) {
+                    continue;
+                }
+                let cell = (x + dx, y + dy);
+                let to = match index.get(&other) {
+                    Some(&to) if (map.rooms[to].x, map.rooms[to].y) == cell => to,
+                    Some(_) => continue,
+                    None if cells.contains_key(&cell) => continue,
+                    None => {
+                        let to = map.rooms.len();
+                        map.rooms.push(Room {
+                            id: other,
+                            name: world.entity_name(other).to_string(),
+                            x: cell.0,
+                            y: cell.1,
+                        });
+                        index.insert(other, to);
+                        cells.insert(cell, to);
+                        queue.push_back((to, distance + 1));
+                        to
+                    }
+                };
+                let link = (from.min(to), from.max(to));
+                if !map.links.contains(&link) {
+                    map.links.push(link);
+                }
+            }
+        }
+        map
+    }
+
+    /// Number of rooms on the map, including the center.
+    pub fn len(&self) -> usize {
+        self.rooms.len()
+    }
+
+    /// True if the map holds no rooms.
+    pub fn is_empty(&self) -> bool {
+        self.rooms.is_empty()
+    }
+
+    /// Grid position of a room relative to the center, if it is on the map.
+    pub fn position(&self, id: EntityId) -> Option<(i32, i32)> {
+        self.rooms
+            .iter()
+            .find(|room| room.id == id)
+            .map(|room| (room.x, room.y))
+    }
+
+    /// Draw the map. The center room is marked with `*` and highlighted.
+    pub fn lines(&self) -> Vec<Line<'static>> {
+        let min_x = self.rooms.iter().map(|r| r.x).min().unwrap_or(0);
+        let max_x = self.rooms.iter().map(|r| r.x).max().unwrap_or(0);
+        let min_y = self.rooms.iter().map(|r| r.y).min().unwrap_or(0);
+        let max_y = self.rooms.iter().map(|r| r.y).max().unwrap_or(0);
+        let cell = self
+            .rooms
+            .iter()
+            .map(|r| r.name.chars().count().min(MAX_NAME))
+            .max()
+            .unwrap_or(0)
+            + 4;
+        let stride = cell + GAP;
+        let cols = (max_x - min_x) as usize + 1;
+        let rows = (max_y - min_y) as usize + 1;
+        let column = |x: i32| (x - min_x) as usize * stride;
+        let row = |y: i32| (y - min_y) as usize * 2;
+
+        let link_style = Style::default().fg(Color::DarkGray);
+        let mut canvas = vec![vec![(' ', Style::default()); cols * stride - GAP]; rows * 2 - 1];
+
+        for &(a, b) in &self.links {
+            let (a, b) = (&self.rooms[a], &self.rooms[b]);
+            let (left, right) = if a.x <= b.x { (a, b) } else { (b, a) };
+            if left.y == right.y {
+                canvas[row(left.y)][column(left.x) + cell..column(right.x)]
+                    .fill(('\u{2500}', link_style));
+            } else if left.x == right.x {
+                canvas[row(left.y.min(right.y)) + 1][column(left.x) + cell / 2] =
+                    ('\u{2502}', link_style);
+            } else {
+                let glyph = if left.y < right.y {
+                    '\u{2572}'
+                } else {
+                    '\u{2571}'
+                };
+                canvas[row(left.y.min(right.y)) + 1][column(left.x) + cell + GAP / 2] =
+                    (glyph, link_style);
+            }
+        }
+
+        for (i, room) in self.rooms.iter().enumerate() {
+            let (label, style) = if i == 0 {
+                (
+                    format!("[*{}*]", shorten(&room.name)),
+                    Style::default().fg(Color::Yellow).bold(),
+                )
+            } else {
+                (
+                    format!("[{}]", shorten(&room.name)),
+                    Style::default().fg(Color::White),
+                )
+            };
+            let pad = (cell - label.chars().count()) / 2;
+            let (r, c) = (row(room.y), column(room.x) + pad);
+            for (k, ch) in label.chars().enumerate() {
+                canvas[r][c + k] = (ch, style);
+            }
+        }
+
+        canvas
+            .into_iter()
+            .map(|chars| {
+                let mut spans: Vec<Span<'static>> = Vec::new();
+                let mut text = String::new();
+                let mut style = Style::default();
+                for (ch, ch_style) in chars {
+                    if ch_style != style && !text.is_empty() {
+                        spans.push(Span::styled(std::mem::take(&mut text), style));
+                    }
+                    style = ch_style;
+                    text.push(ch);
+                }
+                spans.push(Span::styled(text.trim_end().to_string(), style));
+                Line::from(spans)
+            })
+            .collect()
+    }
+}
+
+/// Grid step for a compass exit direction; y grows southward.
+fn offset(direction: &str) -> Option<(i32, i32)> {
+    Some(match direction.to_lowercase().as_str() {
+        "n" | "north" => (0, -1),
+        "s" | "south" => (0, 1),
+        "e" | "east" => (1, 0),
+        "w" | "west" => (-1, 0),
+        "ne" | "northeast" => (1, -1),
+        "nw" | "northwest" => (-1, -1),
+        "se" | "southeast" => (1, 1),
+        "sw" | "southwest" => (-1, 1),
+        _ => return None,
+    })
+}
+
+/// Cut a room name to [`MAX_NAME`] characters.
+fn shorten(name: &str) -> String {
+    if name.chars().count() <= MAX_NAME {
+        name.to_string()
+    } else {
+        let mut short: String = name.chars().take(MAX_NAME - 1).collect();
+        short.push('\u{2026}');
+        short
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ww_core::{Entity, EntityKind, Relationship, WorldMeta};
+
+    fn exit(world: &mut World, from: EntityId, direction: &str, to: EntityId) {
+        world
+            .add_relationship(
+                Relationship::new(from, RelationshipKind::ConnectedTo, to).with_label(direction),
+            )
+            .unwrap();
+    }
+
+    fn dungeon() -> (World, Vec<EntityId>) {
+        let mut world = World::new(WorldMeta::new("Test"));
+        let ids: Vec<EntityId> = ["Hall", "Armory", "Crypt", "Tower", "Vault"]
+            .into_iter()
+            .map(|name| {
+                world
+                    .add_entity(Entity::new(EntityKind::Location, name))
+                    .unwrap()
+            })
+            .collect();
+        exit(&mut world, ids[0], "north", ids[1]);
+        exit(&mut world, ids[2], "west", ids[0]);
+        exit(&mut world, ids[0], "up", ids[3]);
+        exit(&mut world, ids[1], "east", ids[4]);
+        (world, ids)
+    }
+
+    fn text(lines: &[Line]) -> Vec<String> {
+        lines
+            .iter()
+            .map(|line| line.spans.iter().map(|s| s.content.as_ref()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn places_rooms_by_exit_direction_both_ways() {
+        let (world, ids) = dungeon();
+        let map = RoomMap::build(&world, ids[0], 2, |_| true);
+        assert_eq!(map.position(ids[1]), Some((0, -1)));
+        // Crypt's exit leads west into the hall, so the crypt lies east.
+        assert_eq!(map.position(ids[2]), Some((1, 0)));
+        assert_eq!(map.position(ids[3]), None, "up has no grid position");
+        assert_eq!(map.position(ids[4]), Some((1, -1)));
+        assert_eq!(map.len(), 4);
+    }
+
+    #[test]
+    fn depth_and_known_filter_limit_the_map() {
+        let (world, ids) = dungeon();
+        assert_eq!(RoomMap::build(&world, ids[0], 1, |_| true).len(), 3);
+        let map = RoomMap::build(&world, ids[0], 2, |id| id != ids[1]);
+        assert_eq!(map.position(ids[4]), None);
+        assert_eq!(RoomMap::build(&world, ids[3], 2, |_| true).len(), 1);
+    }
+
+    #[test]
+    fn draws_boxes_and_links() {
+        let (world, ids) = dungeon();
+        let map = RoomMap::build(&world, ids[0], 2, |_| true);
+        assert_eq!(
+            text(&map.lines()),
+            [
+                " [Armory] \u{2500}\u{2500}\u{2500} [Vault]",
+                "     \u{2502}",
+                " [*Hall*] \u{2500}\u{2500}\u{2500} [Crypt]",
+            ]
+        );
+    }
+}
@@ -1,12 +1,17 @@
 //! Shared utilities for TUI views: layout helpers, output types, and popups.
 
+use std::collections::HashMap;
+use std::io::{self, Write};
+use std::time::{SystemTime, UNIX_EPOCH};
+
 use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use ww_core::entity::MetadataValue;
 
 /// Visual style for an output line (used by play and solo tabs).
 #[derive(Debug, Clone, Copy)]
 pub enum OutputStyle {
-    /// A command the user entered (yellow, "> " prefix).
+    /// A command the user entered (yellow, "> " prefix by default).
     Command,
     /// Normal output from the session (white).
     Result,
@@ -16,6 +21,19 @@ pub enum OutputStyle {
     System,
 }
 
+impl OutputStyle {
+    /// Terminal style (color and modifiers) for this kind of line.
+    pub fn style(self) -> Style {
+        let (color, modifier) = match self {
+            Self::Command => (Color::Yellow, Modifier::BOLD),
+            Self::Result => (Color::White, Modifier::empty()),
+            Self::Error => (Color::Red, Modifier::empty()),
+            Self::System => (Color::Cyan, Modifier::ITALIC),
+        };
+        Style::default().fg(color).add_modifier(modifier)
+    }
+}
+
 /// A single line of output in a session log.
 #[derive(Debug, Clone)]
 pub struct OutputLine {
@@ -23,6 +41,195 @@ pub struct OutputLine {
     pub style: OutputStyle,
     /// The text content.
     pub text: String,
+    /// Wall-clock time the line was produced.
+    pub time: SystemTime,
+}
+
+impl OutputLine {
+    /// Create a line stamped with the current time.
+    pub fn new(style: OutputStyle, text: impl Into<String>) -> Self {
+        Self {
+            style,
+            text: text.into(),
+            time: SystemTime::now(),
+        }
+    }
+}
+
+/// Previously submitted commands, recalled with Up and Down.
+///
+/// Edits to a recalled command, and the line being typed before browsing
+/// started, are kept while moving through the history and discarded once
+/// a command is submitted.
+#[derive(Debug, Default)]
+pub struct CommandHistory {
+    /// Submitted commands, oldest first.
+    entries: Vec<String>,
+    /// Index of the recalled entry, or `None` while on the new line.
+    position: Option<usize>,
+    /// Unsubmitted edits, by entry index; the new line is at `entries.len()`.
+    edits: HashMap<usize, String>,
+}
+
+impl CommandHistory {
+    /// Submitted commands, oldest first.
+    pub fn entries(&self) -> &[String] {
+        &self.entries
+    }
+
+    /// Record a submitted command and return to a new line.
+    pub fn push(&mut self, command: &str) {
+        if self.entries.last().is_none_or(|last| last != command) {
+            self.entries.push(command.to_string());
+        }
+        self.position = None;
+        self.edits.clear();
+    }
+
+    /// Step back to the previous command, keeping `current` as the edit of
+    /// the line being left. Returns the text to show, if there was one.
+    pub fn prev(&mut self, current: &str) -> Option<String> {
+        let index = match self.position {
+            None => self.entries.len().checked_sub(1)?,
+            Some(0) => return None,
+            Some(i) => i - 1,
+        };
+        Some(self.go_to(Some(index), current))
+    }
+
+    /// Step forward to the next command, or back to the new line.
+    pub fn next(&mut self, current: &str) -> Option<String> {
+        let index = self.position?;
+        let target = (index + 1 < self.entries.len()).then_some(index + 1);
+        Some(self.go_to(target, current))
+    }
+
+    fn go_to(&mut self, target: Option<usize>, current: &str) -> String {
+        let slot = |position: Option<usize>| position.unwrap_or(self.entries.len());
+        self.edits.insert(slot(self.position), current.to_string());
+        self.position = target;
+        let slot = slot(target);
+        self.edits
+            .get(&slot)
+            .or_else(|| self.entries.get(slot))
+            .cloned()
+            .unwrap_or_default()
+    }
+}
+
+/// A `save <file>` or `load <file>` command, which the play and solo tabs
+/// handle themselves before passing input on to their session.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileCommand<'a> {
+    /// Write the session's progress to a file.
+    Save(&'a str),
+    /// Resume the session from a file.
+    Load(&'a str),
+}
+
+impl<'a> FileCommand<'a> {
+    /// Parse a save or load command; anything else is `None`.
+    pub fn parse(input: &'a str) -> Option<Self> {
+        let (cmd, path) = input.trim().split_once(char::is_whitespace)?;
+        let path = path.trim();
+        match cmd.to_lowercase().as_str() {
+            "save" if !path.is_empty() => Some(Self::Save(path)),
+            "load" if !path.is_empty() => Some(Self::Load(path)),
+            _ => None,
+        }
+    }
+
+    /// Read the file a `load` names.
+    pub fn read(path: &str) -> Result<String, String> {
+        std::fs::read_to_string(path).map_err(|e| format!("cannot read {path}: {e}"))
+    }
+
+    /// Write the file a `save` names.
+    pub fn write(path: &str, contents: &str) -> Result<(), String> {
+        std::fs::write(path, contents).map_err(|e| format!("cannot write {path}: {e}"))
+    }
+}
+
+/// Per-style format templates for session output.
+///
+/// Each template may contain `{text}` (the line itself) and `{time}` (the
+/// wall-clock time the line was produced, `HH:MM:SS` UTC). Worlds override
+/// the defaults with `tui.format.command`, `tui.format.result`,
+/// `tui.format.error`, and `tui.format.system` in their metadata, e.g.
+/// `tui.format.result "[{time}] {text}"`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutputFormat {
+    /// Template for commands entered by the user.
+    pub command: String,
+    /// Template for normal session output.
+    pub result: String,
+    /// Template for errors.
+    pub error: String,
+    /// Template for system messages.
+    pub system: String,
+}
+
+impl Default for OutputFormat {
+    fn default() -> Self {
+        Self {
+            command: "> {text}".to_string(),
+            result: "{text}".to_string(),
+            error: "{text}".to_string(),
+            system: "{text}".to_string(),
+        }
+    }
+}
+
+impl OutputFormat {
+    /// Build a format from world metadata, falling back to the defaults.
+    pub fn from_world_meta(properties: &HashMap<String, MetadataValue>) -> Self {
+        let mut format = Self::default();
+        for (key, slot) in [
+            ("tui.format.command", &mut format.command),
+            ("tui.format.result", &mut format.result),
+            ("tui.format.error", &mut format.error),
+            ("tui.format.system", &mut format.system),
+        ] {
+            if let Some(MetadataValue::String(template)) = properties.get(key) {
+                *slot = template.clone();
+            }
+        }
+        format
+    }
+
+    /// Template for a given style.
+    pub fn template(&self, style: OutputStyle) -> &str {
+        match style {
+            OutputStyle::Command => &self.command,
+            OutputStyle::Result => &self.result,
+            OutputStyle::Error => &self.error,
+            OutputStyle::System => &self.system,
+        }
+    }
+
+    /// Apply the matching template to a line, producing its display text.
+    pub fn format(&self, line: &OutputLine) -> String {
+        let template = self.template(line.style);
+        let with_time = if template.contains("{time}") {
+            template.replace("{time}", &clock_time(line.time))
+        } else {
+            template.to_string()
+        };
+        with_time.replace("{text}", &line.text)
+    }
+
+    /// Render a line as a styled terminal line.
+    pub fn render(&self, line: &OutputLine) -> Line<'static> {
+        Line::from(Span::styled(self.format(line), line.style.style()))
+    }
+}
+
+/// Format a wall-clock time as `HH:MM:SS` (UTC).
+fn clock_time(time: SystemTime) -> String {
+    let secs = time
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs() % 86_400);
+    format!("{:02}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60)
 }
 
 /// Create a centered rectangle as a percentage of the given area.
@@ -46,6 +253,41 @@ pub fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
         .split(popup_layout[1])[1]
 }
 
+/// Copy text to the system clipboard through the terminal.
+///
+/// Uses the OSC 52 escape sequence, so it works over SSH and needs no
+/// platform clipboard library; terminals that don't support it ignore it.
+pub fn copy_to_clipboard(text: &str) -> io::Result<()> {
+    let mut stdout = io::stdout();
+    stdout.write_all(osc52(text).as_bytes())?;
+    stdout.flush()
+}
+
+/// Build the OSC 52 "set clipboard" sequence for `text`.
+fn osc52(text: &str) -> String {
+    format!("\x1b]52;c;{}\x07", base64(text.as_bytes()))
+}
+
+/// Standard base64 with padding.
+fn base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let n = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |acc, (i, b)| acc | (u32::from(*b) << (16 - 8 * i)));
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(ALPHABET[((n >> (18 - 6 * i)) & 0x3f) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
 /// Draw a global help popup overlay.
 pub fn draw_help_popup(frame: &mut Frame) {
     let area = centered_rect(60, 70, frame.area());
@@ -54,8 +296,8 @@ pub fn draw_help_popup(frame: &mut Frame) {
         Line::from("Keyboard Shortcuts").style(Style::default().bold()),
         Line::from(""),
         Line::from("Navigation Tabs:"),
-        Line::from("  1-7 / Tab   Switch tab (VimNav mode)"),
-        Line::from("  Ctrl+1..7   Switch tab (TextInput mode)"),
+        Line::from("  1-8 / Tab   Switch tab (VimNav mode)"),
+        Line::from("  Ctrl+1..8   Switch tab (TextInput mode)"),
         Line::from(""),
         Line::from("Explorer / Graph / Timeline / Sheet / Dice:"),
         Line::from("  j / k       Move down / up"),
@@ -63,13 +305,26 @@ pub fn draw_help_popup(frame: &mut Frame) {
         Line::from("  Enter       Select / drill in"),
         Line::from("  Esc         Go back"),
         Line::from("  /           Search (explorer only)"),
+        Line::from("  y           Copy entity as DSL (explorer only)"),
+        Line::from("  Space / t   Mark entity / tag marked entities (explorer)"),
+        Line::from("  j / k       Select legend kind (graph)"),
+        Line::from("  PgUp / PgDn Scroll relationships (graph)"),
+        Line::from("  Space / o   Toggle kind / show only it (graph)"),
+        Line::from("  a           Show all kinds (graph only)"),
+        Line::from("  f           Toggle pip / number faces (dice)"),
         Line::from("  q           Quit"),
         Line::from(""),
         Line::from("Play / Solo:"),
         Line::from("  Enter       Submit command"),
         Line::from("  Tab         Autocomplete"),
         Line::from("  Esc         Clear input"),
-        Line::from("  Arrow keys  Scroll / move cursor"),
+        Line::from("  \u{2191} / \u{2193}       Recall previous commands"),
+        Line::from("  PgUp / PgDn Scroll output"),
+        Line::from(""),
+        Line::from("Search:"),
+        Line::from("  Type        Search names, tags, descriptions"),
+        Line::from("  \u{2191} / \u{2193}       Select result"),
+        Line::from("  Enter       Open result in explorer"),
         Line::from(""),
         Line::from("  ?           Toggle this help"),
         Line::from("  Ctrl+C      Quit"),
@@ -87,3 +342,103 @@ pub fn draw_help_popup(frame: &mut Frame) {
     frame.render_widget(Clear, area);
     frame.render_widget(popup, area);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn line_at(style: OutputStyle, text: &str, secs: u64) -> OutputLine {
+        OutputLine {
+            style,
+            text: text.to_string(),
+            time: UNIX_EPOCH + Duration::from_secs(secs),
+        }
+    }
+
+    #[test]
+    fn osc52_encodes_base64_payload() {
+        assert_eq!(base64(b""), "");
+        assert_eq!(base64(b"f"), "Zg==");
+        assert_eq!(base64(b"fo"), "Zm8=");
+        assert_eq!(base64(b"foobar"), "Zm9vYmFy");
+        assert_eq!(osc52("hi"), "\x1b]52;c;aGk=\x07");
+    }
+
+    #[test]
+    fn history_recalls_commands_and_keeps_edits() {
+        let mut history = CommandHistory::default();
+        assert_eq!(history.prev("draft"), None);
+
+        history.push("ask likely Is it dark?");
+        history.push("look");
+        history.push("look");
+        assert_eq!(history.entries().len(), 2);
+
+        assert_eq!(history.prev("dra").as_deref(), Some("look"));
+        assert_eq!(
+            history.prev("look around").as_deref(),
+            Some("ask likely Is it dark?")
+        );
+        assert_eq!(history.prev("ask likely Is it dark?"), None);
+        assert_eq!(history.next("ask unlikely").as_deref(), Some("look around"));
+        assert_eq!(history.prev("look around").as_deref(), Some("ask unlikely"));
+        assert_eq!(history.next("ask unlikely").as_deref(), Some("look around"));
+        assert_eq!(history.next("look around").as_deref(), Some("dra"));
+        assert_eq!(history.next("dra"), None);
+
+        // Submitting discards the edits
+        history.push("dra");
+        assert_eq!(history.prev("").as_deref(), Some("dra"));
+        assert_eq!(history.prev("dra").as_deref(), Some("look"));
+    }
+
+    #[test]
+    fn file_commands_need_a_path() {
+        assert_eq!(
+            FileCommand::parse("save  run.json "),
+            Some(FileCommand::Save("run.json"))
+        );
+        assert_eq!(
+            FileCommand::parse("LOAD my run.json"),
+            Some(FileCommand::Load("my run.json"))
+        );
+        assert_eq!(FileCommand::parse("save"), None);
+        assert_eq!(FileCommand::parse("look"), None);
+    }
+
+    #[test]
+    fn default_format_prefixes_commands() {
+        let format = OutputFormat::default();
+        assert_eq!(
+            format.format(&line_at(OutputStyle::Command, "look", 0)),
+            "> look"
+        );
+        assert_eq!(
+            format.format(&line_at(OutputStyle::Result, "A hall.", 0)),
+            "A hall."
+        );
+    }
+
+    #[test]
+    fn world_meta_overrides_templates() {
+        let mut props = HashMap::new();
+        props.insert(
+            "tui.format.result".to_string(),
+            MetadataValue::String("[{time}] {text}".to_string()),
+        );
+        props.insert(
+            "tui.format.command".to_string(),
+            MetadataValue::String("$ {text}".to_string()),
+        );
+        let format = OutputFormat::from_world_meta(&props);
+
+        let result = line_at(OutputStyle::Result, "A hall.", 13 * 3600 + 5 * 60 + 9);
+        assert_eq!(format.format(&result), "[13:05:09] A hall.");
+        assert_eq!(
+            format.format(&line_at(OutputStyle::Command, "look", 0)),
+            "$ look"
+        );
+        assert_eq!(format.system, OutputFormat::default().system);
+    }
+}
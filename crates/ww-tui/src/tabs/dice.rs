@@ -7,7 +7,9 @@ use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, Paragraph};
 
-use ww_mechanics::{DicePool, Die, RollResult};
+use ww_core::World;
+use ww_core::entity::MetadataValue;
+use ww_mechanics::{DicePool, Die, DieResult, RollResult};
 
 use super::{InputMode, Tab};
 
@@ -22,6 +24,67 @@ const DIE_TYPES: &[(u32, &str)] = &[
     (100, "d100"),
 ];
 
+/// How rolled dice are drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiceFaces {
+    /// Every die shows its value as a number.
+    Numbers,
+    /// d6 results show pip faces; other dice show their number on a face.
+    Pips,
+}
+
+impl DiceFaces {
+    /// Read the `tui.dice_faces` world setting (`"pips"` or `"numbers"`).
+    pub fn from_world(world: &World) -> Self {
+        match world.meta.properties.get("tui.dice_faces") {
+            Some(MetadataValue::String(s)) if s.eq_ignore_ascii_case("pips") => Self::Pips,
+            _ => Self::Numbers,
+        }
+    }
+}
+
+/// Pip rows for a d6 face, or `None` for values outside 1..=6.
+fn pip_rows(value: u32) -> Option<[&'static str; 3]> {
+    Some(match value {
+        1 => ["     ", "  \u{25cf}  ", "     "],
+        2 => ["\u{25cf}    ", "     ", "    \u{25cf}"],
+        3 => ["\u{25cf}    ", "  \u{25cf}  ", "    \u{25cf}"],
+        4 => ["\u{25cf}   \u{25cf}", "     ", "\u{25cf}   \u{25cf}"],
+        5 => ["\u{25cf}   \u{25cf}", "  \u{25cf}  ", "\u{25cf}   \u{25cf}"],
+        6 => [
+            "\u{25cf}   \u{25cf}",
+            "\u{25cf}   \u{25cf}",
+            "\u{25cf}   \u{25cf}",
+        ],
+        _ => return None,
+    })
+}
+
+/// The three inner rows of a die's face: pips for a d6, else its number.
+fn face_rows(die: &DieResult) -> [String; 3] {
+    match pip_rows(die.value).filter(|_| die.die == Die::D6) {
+        Some(rows) => rows.map(str::to_string),
+        None => [
+            "     ".to_string(),
+            format!("{:^5}", die.value),
+            "     ".to_string(),
+        ],
+    }
+}
+
+/// Draw dice as boxed faces side by side, five lines tall.
+fn pip_lines(dice: &[DieResult]) -> Vec<String> {
+    let mut lines = vec![String::new(); 5];
+    for die in dice {
+        lines[0].push_str(" \u{250c}\u{2500}\u{2500}\u{2500}\u{2500}\u{2500}\u{2510}");
+        for (row, face) in face_rows(die).iter().enumerate() {
+            lines[row + 1].push_str(&format!(" \u{2502}{face}\u{2502}"));
+        }
+        lines[4].push_str(" \u{2514}\u{2500}\u{2500}\u{2500}\u{2500}\u{2500}\u{2518}");
+    }
+    lines
+}
+
 /// Dice roller tab state.
 pub struct DiceTab {
     /// Number of dice in the pool.
@@ -32,6 +95,8 @@ pub struct DiceTab {
     result: Option<RollResult>,
     /// RNG used for rolling.
     rng: StdRng,
+    /// Face style for rolled dice.
+    faces: DiceFaces,
 }
 
 impl DiceTab {
@@ -42,9 +107,16 @@ impl DiceTab {
             die_index: 5, // d20
             result: None,
             rng: StdRng::seed_from_u64(seed),
+            faces: DiceFaces::Numbers,
         }
     }
 
+    /// Use the given face style for rolled dice.
+    pub fn with_faces(mut self, faces: DiceFaces) -> Self {
+        self.faces = faces;
+        self
+    }
+
     fn die_sides(&self) -> u32 {
         DIE_TYPES[self.die_index].0
     }
@@ -81,15 +153,11 @@ impl Tab for DiceTab {
 
     fn handle_key(&mut self, key: KeyEvent) -> bool {
         match key.code {
-            KeyCode::Left | KeyCode::Char('h') => {
-                if self.die_index > 0 {
-                    self.die_index -= 1;
-                }
+            KeyCode::Left | KeyCode::Char('h') if self.die_index > 0 => {
+                self.die_index -= 1;
             }
-            KeyCode::Right | KeyCode::Char('l') => {
-                if self.die_index + 1 < DIE_TYPES.len() {
-                    self.die_index += 1;
-                }
+            KeyCode::Right | KeyCode::Char('l') if self.die_index + 1 < DIE_TYPES.len() => {
+                self.die_index += 1;
             }
             KeyCode::Up | KeyCode::Char('k') => {
                 self.pool_size = (self.pool_size + 1).min(20);
@@ -100,6 +168,12 @@ impl Tab for DiceTab {
             KeyCode::Enter | KeyCode::Char(' ') => {
                 self.roll();
             }
+            KeyCode::Char('f') => {
+                self.faces = match self.faces {
+                    DiceFaces::Numbers => DiceFaces::Pips,
+                    DiceFaces::Pips => DiceFaces::Numbers,
+                };
+            }
             _ => {}
         }
         false
@@ -178,17 +252,26 @@ impl Tab for DiceTab {
             )));
 
             // Individual dice
-            let dice_spans: Vec<Span<'static>> = result
-                .dice
-                .iter()
-                .flat_map(|d| {
-                    vec![Span::styled(
-                        format!(" [{}] ", d.value),
+            if self.faces == DiceFaces::Pips {
+                for line in pip_lines(&result.dice) {
+                    lines.push(Line::from(Span::styled(
+                        line,
                         Style::default().fg(Color::Yellow).bold(),
-                    )]
-                })
-                .collect();
-            lines.push(Line::from(dice_spans));
+                    )));
+                }
+            } else {
+                let dice_spans: Vec<Span<'static>> = result
+                    .dice
+                    .iter()
+                    .map(|d| {
+                        Span::styled(
+                            format!(" [{}] ", d.value),
+                            Style::default().fg(Color::Yellow).bold(),
+                        )
+                    })
+                    .collect();
+                lines.push(Line::from(dice_spans));
+            }
             lines.push(Line::from(""));
 
             // Total
@@ -206,6 +289,66 @@ impl Tab for DiceTab {
     }
 
     fn status_hint(&self) -> &str {
-        "\u{2190}/\u{2192}:die type  \u{2191}/\u{2193}:count  Enter/Space:roll  Tab:view  ?:help  q:quit"
+        "\u{2190}/\u{2192}:die type  \u{2191}/\u{2193}:count  Enter/Space:roll  f:faces  Tab:view  ?:help  q:quit"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pip_faces_render_side_by_side() {
+        let d6 = |value| DieResult {
+            value,
+            ..DicePool::new()
+                .add(Die::D6, 1)
+                .roll(&mut StdRng::seed_from_u64(1))
+                .dice[0]
+                .clone()
+        };
+        let lines = pip_lines(&[d6(5), d6(1)]);
+        assert_eq!(lines.len(), 5);
+        assert_eq!(
+            lines[2],
+            " \u{2502}  \u{25cf}  \u{2502} \u{2502}  \u{25cf}  \u{2502}"
+        );
+        assert_eq!(lines[1].matches('\u{25cf}').count(), 2);
+    }
+
+    #[test]
+    fn each_die_picks_its_own_face() {
+        let result = DicePool::new()
+            .add(Die::D6, 1)
+            .add(Die::D20, 1)
+            .roll(&mut StdRng::seed_from_u64(3));
+        let lines = pip_lines(&result.dice);
+        let d20 = &result.dice[1];
+        assert_eq!(d20.die, Die::D20);
+        // Each face is eight columns wide: the d6 shows pips, the d20 its number
+        let (d6_face, d20_face): (Vec<_>, Vec<_>) = lines
+            .iter()
+            .map(|line| line.split_at(line.char_indices().nth(8).unwrap().0))
+            .unzip();
+        assert!(d6_face.iter().any(|row| row.contains('\u{25cf}')));
+        assert!(!d20_face.iter().any(|row| row.contains('\u{25cf}')));
+        assert_eq!(d20_face[2], format!(" \u{2502}{:^5}\u{2502}", d20.value));
+    }
+
+    #[test]
+    fn faces_come_from_world_and_toggle() {
+        let mut world = World::new(ww_core::WorldMeta::new("Test"));
+        assert_eq!(DiceFaces::from_world(&world), DiceFaces::Numbers);
+        world.meta.properties.insert(
+            "tui.dice_faces".to_string(),
+            MetadataValue::String("pips".to_string()),
+        );
+        let mut tab = DiceTab::new(1).with_faces(DiceFaces::from_world(&world));
+        assert_eq!(tab.faces, DiceFaces::Pips);
+        tab.handle_key(KeyEvent::new(
+            KeyCode::Char('f'),
+            crossterm::event::KeyModifiers::empty(),
+        ));
+        assert_eq!(tab.faces, DiceFaces::Numbers);
     }
 }
@@ -1,13 +1,22 @@
 //! Explorer tab: entity list with search and entity detail view.
 
+use std::collections::HashSet;
+use std::path::PathBuf;
+
 use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::prelude::*;
-use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap};
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap};
 
 use ww_core::World;
+use ww_core::display::DetailTemplate;
 use ww_core::entity::{Entity, EntityId, EntityKind};
+use ww_core::grammar::Grammar;
 
 use super::{InputMode, Tab};
+use crate::map::RoomMap;
+
+/// How many exits away from a location its map panel reaches.
+const MAP_DEPTH: usize = 2;
 
 /// Sub-view within the explorer tab.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -25,12 +34,17 @@ enum ExplorerInput {
     Normal,
     /// Search/filter mode.
     Search,
+    /// Tag prompt for the selected entities.
+    Tag,
 }
 
 /// Explorer tab state.
 pub struct ExplorerTab {
     /// The world data.
     world: World,
+    /// Directory the world was compiled from; tag edits are written back
+    /// to its files. `None` keeps them in the loaded world only.
+    source_dir: Option<PathBuf>,
     /// Current sub-view.
     sub_view: SubView,
     /// Input mode (normal or search).
@@ -45,6 +59,10 @@ pub struct ExplorerTab {
     search_query: String,
     /// Filtered entity IDs.
     filtered_ids: Vec<EntityId>,
+    /// Entities marked for bulk actions (toggled with Space).
+    selected_ids: HashSet<EntityId>,
+    /// Tag prompt input (`name` or `+name` adds, `-name` removes).
+    tag_input: String,
 
     // Detail state
     /// Entity ID being viewed in detail.
@@ -55,6 +73,8 @@ pub struct ExplorerTab {
     // Navigation
     /// View stack for back navigation.
     view_stack: Vec<SubView>,
+    /// One-shot message shown in the status bar (e.g. after copying).
+    notice: Option<String>,
 }
 
 impl ExplorerTab {
@@ -62,20 +82,30 @@ impl ExplorerTab {
     pub fn new(world: World) -> Self {
         let mut tab = Self {
             world,
+            source_dir: None,
             sub_view: SubView::List,
             explorer_input: ExplorerInput::Normal,
             list_cursor: 0,
             list_filter: None,
             search_query: String::new(),
             filtered_ids: Vec::new(),
+            selected_ids: HashSet::new(),
+            tag_input: String::new(),
             detail_entity_id: None,
             detail_scroll: 0,
             view_stack: Vec::new(),
+            notice: None,
         };
         tab.update_filtered_list();
         tab
     }
 
+    /// Write tag edits back to the `.ww` files in `dir`.
+    pub fn with_source_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.source_dir = Some(dir.into());
+        self
+    }
+
     fn update_filtered_list(&mut self) {
         let mut query = self.world.query();
         if let Some(ref kind) = self.list_filter {
@@ -144,19 +174,133 @@ impl ExplorerTab {
         }
     }
 
+    /// Show an entity's detail view, on top of the list.
+    pub fn open_entity(&mut self, id: EntityId) {
+        if self.world.get_entity(id).is_none() {
+            return;
+        }
+        self.detail_entity_id = Some(id);
+        self.detail_scroll = 0;
+        self.view_stack = vec![SubView::List];
+        self.sub_view = SubView::Detail;
+    }
+
     fn go_back(&mut self) {
         if let Some(prev) = self.view_stack.pop() {
             self.sub_view = prev;
         }
     }
+
+    /// Render the focused entity (detail view or list cursor) as DSL source.
+    fn copy_selected(&mut self) -> Option<String> {
+        let entity = match self.sub_view {
+            SubView::List => self.selected_entity(),
+            SubView::Detail => self
+                .detail_entity_id
+                .and_then(|id| self.world.get_entity(id)),
+        }?;
+        let dsl = ww_dsl::render::entity_to_dsl(entity, &self.world);
+        self.notice = Some(format!("Copied {} as DSL", entity.name));
+        Some(dsl)
+    }
+
+    /// Toggle the entity under the list cursor in the bulk selection.
+    fn toggle_selected(&mut self) {
+        if self.sub_view != SubView::List {
+            return;
+        }
+        if let Some(&id) = self.filtered_ids.get(self.list_cursor)
+            && !self.selected_ids.remove(&id)
+        {
+            self.selected_ids.insert(id);
+        }
+    }
+
+    /// Entities a bulk action applies to: the selection, or else the
+    /// focused entity.
+    fn action_targets(&self) -> Vec<EntityId> {
+        if !self.selected_ids.is_empty() {
+            return self.selected_ids.iter().copied().collect();
+        }
+        match self.sub_view {
+            SubView::List => self.selected_entity().map(|e| e.id).into_iter().collect(),
+            SubView::Detail => self.detail_entity_id.into_iter().collect(),
+        }
+    }
+
+    /// Apply the tag prompt to every target entity.
+    ///
+    /// `name` and `+name` add the tag, `-name` removes it. With a source
+    /// directory, the changed entities' `tags` statements are rewritten in
+    /// their files too. Returns how many entities changed.
+    fn apply_tag_input(&mut self) -> usize {
+        let input = self.tag_input.trim();
+        let (remove, tag) = match input.strip_prefix('-') {
+            Some(tag) => (true, tag.trim()),
+            None => (false, input.trim_start_matches('+').trim()),
+        };
+        if tag.is_empty() {
+            return 0;
+        }
+        let tag = tag.to_string();
+        let mut changed = Vec::new();
+        for id in self.action_targets() {
+            if let Some(entity) = self.world.get_entity_mut(id)
+                && (if remove {
+                    entity.remove_tag(&tag)
+                } else {
+                    entity.add_tag(tag.clone())
+                })
+            {
+                changed.push(id);
+            }
+        }
+        let verb = if remove { "Removed" } else { "Added" };
+        let grammar = Grammar::from_meta(&self.world.meta);
+        let entities = grammar.count(changed.len(), "entity");
+        let saved = match self.write_tags(&changed) {
+            None => " for this session".to_string(),
+            Some(Ok(files)) => format!(", saved {}", grammar.count(files, "file")),
+            Some(Err(e)) => format!(", but saving failed: {e}"),
+        };
+        self.notice = Some(format!("{verb} tag \"{tag}\" on {entities}{saved}"));
+        changed.len()
+    }
+
+    /// Rewrite the `tags` statements of `ids` in the source directory.
+    ///
+    /// Returns how many files were written, or `None` without a source
+    /// directory.
+    fn write_tags(&self, ids: &[EntityId]) -> Option<Result<usize, String>> {
+        let dir = self.source_dir.as_ref()?;
+        let write = || {
+            let files = ww_dsl::read_dir_files(dir).map_err(|d| d.message)?;
+            let entities: Vec<&Entity> = ids
+                .iter()
+                .filter_map(|&id| self.world.get_entity(id))
+                .collect();
+            let fixes = ww_dsl::fix::write_tags(dir, &files, &entities)
+                .map_err(|_| "the world's files have errors".to_string())?;
+            for fix in &fixes {
+                std::fs::write(dir.join(&fix.name), &fix.text)
+                    .map_err(|e| format!("cannot write {}: {e}", fix.name))?;
+            }
+            Ok(fixes.len())
+        };
+        Some(write())
+    }
 }
 
 impl Tab for ExplorerTab {
     fn input_mode(&self) -> InputMode {
-        InputMode::VimNav
+        match self.explorer_input {
+            ExplorerInput::Normal => InputMode::VimNav,
+            ExplorerInput::Search | ExplorerInput::Tag => InputMode::TextInput,
+        }
     }
 
     fn handle_key(&mut self, key: KeyEvent) -> bool {
+        self.notice = None;
         match self.explorer_input {
             ExplorerInput::Normal => match key.code {
                 KeyCode::Char('j') | KeyCode::Down => self.move_down(),
@@ -164,7 +308,22 @@ impl Tab for ExplorerTab {
                 KeyCode::Char('g') => self.move_to_top(),
                 KeyCode::Char('G') => self.move_to_bottom(),
                 KeyCode::Enter => self.select(),
+                KeyCode::Esc if self.sub_view == SubView::List && !self.selected_ids.is_empty() => {
+                    self.selected_ids.clear();
+                }
                 KeyCode::Esc => self.go_back(),
+                KeyCode::Char(' ') => self.toggle_selected(),
+                KeyCode::Char('t') => {
+                    self.explorer_input = ExplorerInput::Tag;
+                    self.tag_input.clear();
+                }
+                KeyCode::Char('y') => {
+                    if let Some(dsl) = self.copy_selected()
+                        && let Err(e) = crate::shared::copy_to_clipboard(&dsl)
+                    {
+                        self.notice = Some(format!("Copy failed: {e}"));
+                    }
+                }
                 KeyCode::Char('/') => {
                     self.explorer_input = ExplorerInput::Search;
                     self.search_query.clear();
@@ -194,6 +353,22 @@ impl Tab for ExplorerTab {
                 }
                 _ => {}
             },
+            ExplorerInput::Tag => match key.code {
+                KeyCode::Esc => {
+                    self.explorer_input = ExplorerInput::Normal;
+                    self.tag_input.clear();
+                }
+                KeyCode::Enter => {
+                    self.explorer_input = ExplorerInput::Normal;
+                    self.apply_tag_input();
+                    self.tag_input.clear();
+                }
+                KeyCode::Backspace => {
+                    self.tag_input.pop();
+                }
+                KeyCode::Char(c) => self.tag_input.push(c),
+                _ => {}
+            },
         }
         false
     }
@@ -216,27 +391,25 @@ impl Tab for ExplorerTab {
                     self.detail_scroll = self.detail_scroll.saturating_add(1);
                 }
             },
-            MouseEventKind::Down(MouseButton::Left) => {
-                // Handle clicks in list view to select entities
-                if self.sub_view == SubView::List {
-                    // Row 0 is tab bar, row 1 is block border with title, row 2+ is list content
-                    if mouse.row >= 2 {
-                        let clicked_row = mouse.row - 2;
-                        let target_idx = clicked_row as usize;
-                        if target_idx < self.filtered_ids.len() {
-                            if target_idx == self.list_cursor {
-                                // Double-click effect: open detail view
-                                if let Some(&id) = self.filtered_ids.get(self.list_cursor) {
-                                    self.detail_entity_id = Some(id);
-                                    self.detail_scroll = 0;
-                                    self.view_stack.push(self.sub_view);
-                                    self.sub_view = SubView::Detail;
-                                }
-                            } else {
-                                // Single click: select entity
-                                self.list_cursor = target_idx;
-                            }
+            // Handle clicks in list view to select entities.
+            // Row 0 is tab bar, row 1 is block border with title, row 2+ is list content.
+            MouseEventKind::Down(MouseButton::Left)
+                if self.sub_view == SubView::List && mouse.row >= 2 =>
+            {
+                let clicked_row = mouse.row - 2;
+                let target_idx = clicked_row as usize;
+                if target_idx < self.filtered_ids.len() {
+                    if target_idx == self.list_cursor {
+                        // Double-click effect: open detail view
+                        if let Some(&id) = self.filtered_ids.get(self.list_cursor) {
+                            self.detail_entity_id = Some(id);
+                            self.detail_scroll = 0;
+                            self.view_stack.push(self.sub_view);
+                            self.sub_view = SubView::Detail;
                         }
+                    } else {
+                        // Single click: select entity
+                        self.list_cursor = target_idx;
                     }
                 }
             }
@@ -252,11 +425,20 @@ impl Tab for ExplorerTab {
     }
 
     fn status_hint(&self) -> &str {
+        if let Some(notice) = &self.notice {
+            return notice;
+        }
         match self.explorer_input {
             ExplorerInput::Search => "Enter:confirm  Esc:cancel",
+            ExplorerInput::Tag => "tag / +tag:add  -tag:remove  Enter:apply  Esc:cancel",
             ExplorerInput::Normal => match self.sub_view {
-                SubView::List => "j/k:navigate  Enter:select  /:search  Tab:view  ?:help  q:quit",
-                SubView::Detail => "j/k:scroll  Esc:back  ?:help  q:quit",
+                SubView::List if !self.selected_ids.is_empty() => {
+                    "Space:toggle  t:tag selection  Esc:clear selection  ?:help  q:quit"
+                }
+                SubView::List => {
+                    "j/k:navigate  Enter:select  /:search  Space:mark  t:tag  y:copy  ?:help  q:quit"
+                }
+                SubView::Detail => "j/k:scroll  t:tag  y:copy  Esc:back  ?:help  q:quit",
             },
         }
     }
@@ -276,7 +458,13 @@ fn draw_entity_list(frame: &mut Frame, tab: &ExplorerTab, area: Rect) {
                     } else {
                         e.kind.to_string()
                     };
+                    let mark = if tab.selected_ids.contains(id) {
+                        Span::styled("\u{25cf} ", Style::default().fg(Color::Yellow))
+                    } else {
+                        Span::raw("  ")
+                    };
                     let line = Line::from(vec![
+                        mark,
                         Span::styled(&e.name, Style::default().fg(Color::White).bold()),
                         Span::raw("  "),
                         Span::styled(kind_str, Style::default().fg(Color::DarkGray)),
@@ -288,7 +476,7 @@ fn draw_entity_list(frame: &mut Frame, tab: &ExplorerTab, area: Rect) {
         })
         .collect();
 
-    let title = if tab.search_query.is_empty() {
+    let mut title = if tab.search_query.is_empty() {
         format!(" Entities ({}) ", tab.filtered_ids.len())
     } else {
         format!(
@@ -297,6 +485,12 @@ fn draw_entity_list(frame: &mut Frame, tab: &ExplorerTab, area: Rect) {
             tab.search_query
         )
     };
+    if !tab.selected_ids.is_empty() {
+        title.push_str(&format!("\u{2014} {} selected ", tab.selected_ids.len()));
+    }
+    if tab.explorer_input == ExplorerInput::Tag {
+        title.push_str(&format!("\u{2014} tag: {}_ ", tab.tag_input));
+    }
 
     let list = List::new(items)
         .block(
@@ -343,6 +537,17 @@ fn draw_entity_detail(frame: &mut Frame, tab: &ExplorerTab, area: Rect) {
             Style::default().fg(Color::DarkGray),
         ),
     ]));
+    if !entity.tags.is_empty() {
+        lines.push(Line::from(Span::styled(
+            entity
+                .tags
+                .iter()
+                .map(|t| format!("#{t}"))
+                .collect::<Vec<_>>()
+                .join(" "),
+            Style::default().fg(Color::Yellow),
+        )));
+    }
     lines.push(Line::from(""));
 
     // Description
@@ -356,6 +561,35 @@ fn draw_entity_detail(frame: &mut Frame, tab: &ExplorerTab, area: Rect) {
         lines.push(Line::from(""));
     }
 
+    // GM notes
+    if !entity.gm_notes.is_empty() {
+        let note_style = Style::default().fg(Color::Magenta).italic();
+        lines.push(Line::from(Span::styled(
+            "GM Notes",
+            Style::default().fg(Color::Magenta).bold(),
+        )));
+        for note in &entity.gm_notes {
+            lines.push(Line::from(Span::styled(
+                format!("  {}", note.trim()),
+                note_style,
+            )));
+        }
+        lines.push(Line::from(""));
+    }
+
+    // Authoring doc comment
+    if let Some(doc) = &entity.doc {
+        let doc_style = Style::default().fg(Color::DarkGray).italic();
+        lines.push(Line::from(Span::styled(
+            "Doc",
+            Style::default().fg(Color::DarkGray).bold(),
+        )));
+        for doc_line in doc.lines() {
+            lines.push(Line::from(Span::styled(format!("  {doc_line}"), doc_style)));
+        }
+        lines.push(Line::from(""));
+    }
+
     // Component-specific fields
     if let Some(char_comp) = &entity.components.character {
         lines.push(Line::from(Span::styled(
@@ -454,16 +688,25 @@ fn draw_entity_detail(frame: &mut Frame, tab: &ExplorerTab, area: Rect) {
         lines.push(Line::from(""));
     }
 
-    // Properties
-    if !entity.properties.is_empty() {
+    // Properties, laid out by the world's detail template for this kind
+    let template = DetailTemplate::for_entity(&tab.world.meta, entity).unwrap_or_default();
+    let layout = template.layout(entity);
+    let other_heading = if template.fields.is_empty() {
+        "Properties"
+    } else {
+        "Other"
+    };
+    let sections = [("Details", layout.fields), (other_heading, layout.other)];
+    for (heading, props) in sections {
+        if props.is_empty() {
+            continue;
+        }
         lines.push(Line::from(Span::styled(
-            "Properties",
+            heading,
             Style::default().fg(Color::Yellow).bold(),
         )));
-        let mut props: Vec<_> = entity.properties.iter().collect();
-        props.sort_by_key(|(k, _)| (*k).clone());
-        for (key, value) in props {
-            lines.push(field_line(&format!("  {key}"), &value.to_string()));
+        for (label, value) in props {
+            lines.push(field_line(&format!("  {label}"), &value.to_string()));
         }
         lines.push(Line::from(""));
     }
@@ -495,7 +738,11 @@ fn draw_entity_detail(frame: &mut Frame, tab: &ExplorerTab, area: Rect) {
         }
     }
 
-    let title = format!(" {} ", entity.name);
+    let title = if tab.explorer_input == ExplorerInput::Tag {
+        format!(" {} \u{2014} tag: {}_ ", entity.name, tab.tag_input)
+    } else {
+        format!(" {} ", entity.name)
+    };
     let paragraph = Paragraph::new(lines)
         .block(
             Block::default()
@@ -507,6 +754,42 @@ fn draw_entity_detail(frame: &mut Frame, tab: &ExplorerTab, area: Rect) {
         .scroll((tab.detail_scroll, 0));
 
     frame.render_widget(paragraph, area);
+
+    if entity.kind == EntityKind::Location {
+        draw_room_map(frame, tab, entity.id, area);
+    }
+}
+
+/// Overlay a map of the rooms around a location in the detail view's
+/// top-right corner, if it has any mapped exits.
+fn draw_room_map(frame: &mut Frame, tab: &ExplorerTab, location: EntityId, area: Rect) {
+    let map = RoomMap::build(&tab.world, location, MAP_DEPTH, |_| true);
+    if map.len() < 2 {
+        return;
+    }
+    let lines = map.lines();
+    let width = lines.iter().map(Line::width).max().unwrap_or(0) as u16 + 2;
+    let height = lines.len() as u16 + 2;
+    // Leave the left half of the detail view and its borders readable.
+    if width > area.width / 2 || height + 2 > area.height {
+        return;
+    }
+    let panel = Rect {
+        x: area.right() - width - 1,
+        y: area.y + 1,
+        width,
+        height,
+    };
+    frame.render_widget(Clear, panel);
+    frame.render_widget(
+        Paragraph::new(lines).block(
+            Block::default()
+                .title(" Map ")
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::DarkGray)),
+        ),
+        panel,
+    );
 }
 
 /// Format a labeled field line.
@@ -549,6 +832,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn open_entity_shows_detail_with_list_behind() {
+        let world = create_test_world();
+        let bob = world.find_id_by_name("Bob").unwrap();
+        let mut tab = ExplorerTab::new(world);
+
+        tab.open_entity(bob);
+        assert_eq!(tab.sub_view, SubView::Detail);
+        assert_eq!(tab.detail_entity_id, Some(bob));
+        tab.go_back();
+        assert_eq!(tab.sub_view, SubView::List);
+    }
+
     #[test]
     fn mouse_scroll_up_decrements_list_cursor() {
         let world = create_test_world();
@@ -1056,4 +1352,117 @@ mod tests {
             "Filtered list should be restored"
         );
     }
+
+    fn press(tab: &mut ExplorerTab, code: KeyCode) {
+        tab.handle_key(KeyEvent::new(code, crossterm::event::KeyModifiers::empty()));
+    }
+
+    #[test]
+    fn bulk_tag_applies_to_marked_entities() {
+        let world = create_test_world();
+        let mut tab = ExplorerTab::new(world);
+        let first = tab.filtered_ids[0];
+        let third = tab.filtered_ids[2];
+
+        press(&mut tab, KeyCode::Char(' '));
+        press(&mut tab, KeyCode::Char('j'));
+        press(&mut tab, KeyCode::Char('j'));
+        press(&mut tab, KeyCode::Char(' '));
+        assert_eq!(tab.selected_ids.len(), 2);
+
+        press(&mut tab, KeyCode::Char('t'));
+        assert_eq!(tab.input_mode(), InputMode::TextInput);
+        for c in "arc-1".chars() {
+            press(&mut tab, KeyCode::Char(c));
+        }
+        press(&mut tab, KeyCode::Enter);
+        assert_eq!(
+            tab.status_hint(),
+            "Added tag \"arc-1\" on 2 entities for this session"
+        );
+        assert!(tab.world.get_entity(first).unwrap().has_tag("arc-1"));
+        assert!(tab.world.get_entity(third).unwrap().has_tag("arc-1"));
+        assert!(
+            !tab.world
+                .get_entity(tab.filtered_ids[1])
+                .unwrap()
+                .has_tag("arc-1")
+        );
+
+        press(&mut tab, KeyCode::Char('t'));
+        for c in "-arc-1".chars() {
+            press(&mut tab, KeyCode::Char(c));
+        }
+        press(&mut tab, KeyCode::Enter);
+        assert!(!tab.world.get_entity(first).unwrap().has_tag("arc-1"));
+
+        // Esc clears the selection before it navigates back
+        press(&mut tab, KeyCode::Esc);
+        assert!(tab.selected_ids.is_empty());
+    }
+
+    #[test]
+    fn tag_without_selection_targets_cursor_entity() {
+        let world = create_test_world();
+        let mut tab = ExplorerTab::new(world);
+        let focused = tab.filtered_ids[0];
+
+        press(&mut tab, KeyCode::Char('t'));
+        for c in "+region".chars() {
+            press(&mut tab, KeyCode::Char(c));
+        }
+        press(&mut tab, KeyCode::Enter);
+        assert_eq!(tab.world.get_entity(focused).unwrap().tags, vec!["region"]);
+        assert_eq!(tab.input_mode(), InputMode::VimNav);
+    }
+
+    #[test]
+    fn bulk_tag_writes_back_to_source_files() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(
+            dir.path().join("people.ww"),
+            "Alice is a character {\n    -- the heir\n    tags [heir]\n}\n\nBob is a character {}\n",
+        )
+        .unwrap();
+        let world = ww_dsl::compile_dir(dir.path()).world;
+        let mut tab = ExplorerTab::new(world).with_source_dir(dir.path());
+
+        press(&mut tab, KeyCode::Char(' '));
+        press(&mut tab, KeyCode::Char('j'));
+        press(&mut tab, KeyCode::Char(' '));
+        press(&mut tab, KeyCode::Char('t'));
+        for c in "north arc".chars() {
+            press(&mut tab, KeyCode::Char(c));
+        }
+        press(&mut tab, KeyCode::Enter);
+        assert_eq!(
+            tab.status_hint(),
+            "Added tag \"north arc\" on 2 entities, saved 1 file"
+        );
+        assert_eq!(
+            std::fs::read_to_string(dir.path().join("people.ww")).unwrap(),
+            "Alice is a character {\n    -- the heir\n    tags [heir, \"north arc\"]\n}\n\n\
+             Bob is a character {\n    tags [\"north arc\"]\n}\n"
+        );
+        let reloaded = ww_dsl::compile_dir(dir.path()).world;
+        assert!(reloaded.find_by_name("Bob").unwrap().has_tag("north arc"));
+    }
+
+    #[test]
+    fn copy_selected_renders_focused_entity() {
+        let world = create_test_world();
+        let mut tab = ExplorerTab::new(world);
+        let name = tab.selected_entity().unwrap().name.clone();
+
+        let dsl = tab.copy_selected().expect("an entity is selected");
+        assert!(dsl.starts_with(&format!("{name} is a")), "{dsl}");
+        assert_eq!(tab.status_hint(), format!("Copied {name} as DSL"));
+
+        // Any further key clears the notice
+        tab.handle_key(KeyEvent::new(
+            KeyCode::Char('j'),
+            crossterm::event::KeyModifiers::empty(),
+        ));
+        assert!(tab.status_hint().contains("y:copy"));
+    }
 }
@@ -8,21 +8,154 @@ use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
 
 use ww_core::World;
 use ww_core::entity::EntityId;
+use ww_core::grammar::Grammar;
+use ww_core::relationship::{Relationship, RelationshipKind};
 
 use super::{InputMode, Tab};
 
+/// Width of the legend panel, including borders.
+const LEGEND_WIDTH: u16 = 28;
+
 /// Graph tab state.
 pub struct GraphTab {
     /// The world data.
     world: World,
     /// Scroll offset.
     scroll: u16,
+    /// Relationship kinds present in the world, in legend order.
+    kinds: Vec<RelationshipKind>,
+    /// Kinds whose edges are currently hidden.
+    hidden: HashSet<RelationshipKind>,
+    /// Selected row in the legend.
+    legend_cursor: usize,
 }
 
 impl GraphTab {
     /// Create a new graph tab for the given world.
     pub fn new(world: World) -> Self {
-        Self { world, scroll: 0 }
+        let mut kinds: Vec<RelationshipKind> = Vec::new();
+        for rel in world.all_relationships() {
+            if !kinds.contains(&rel.kind) {
+                kinds.push(rel.kind.clone());
+            }
+        }
+        kinds.sort_by_key(|k| (category(k), k.as_phrase().to_string()));
+
+        Self {
+            world,
+            scroll: 0,
+            kinds,
+            hidden: HashSet::new(),
+            legend_cursor: 0,
+        }
+    }
+
+    /// Whether edges of the given kind are drawn.
+    pub fn is_visible(&self, kind: &RelationshipKind) -> bool {
+        !self.hidden.contains(kind)
+    }
+
+    /// Show or hide the kind under the legend cursor.
+    fn toggle_selected(&mut self) {
+        if let Some(kind) = self.kinds.get(self.legend_cursor)
+            && !self.hidden.remove(kind)
+        {
+            self.hidden.insert(kind.clone());
+        }
+    }
+
+    /// Hide every kind except the one under the legend cursor.
+    fn isolate_selected(&mut self) {
+        if let Some(selected) = self.kinds.get(self.legend_cursor) {
+            self.hidden = self
+                .kinds
+                .iter()
+                .filter(|k| *k != selected)
+                .cloned()
+                .collect();
+        }
+    }
+
+    fn draw_legend(&self, frame: &mut Frame, area: Rect) {
+        let mut lines: Vec<Line<'static>> = Vec::new();
+        for (i, kind) in self.kinds.iter().enumerate() {
+            let (color, dash) = kind_style(kind);
+            let visible = self.is_visible(kind);
+            let check = if visible { "[x] " } else { "[ ] " };
+            let mut style = if visible {
+                Style::default().fg(color)
+            } else {
+                Style::default().fg(Color::DarkGray)
+            };
+            if i == self.legend_cursor {
+                style = style.bg(Color::DarkGray).bold();
+                if !visible {
+                    style = style.fg(Color::Gray);
+                }
+            }
+            lines.push(Line::from(vec![
+                Span::styled(check, style),
+                Span::styled(dash.to_string().repeat(3), style),
+                Span::styled(format!(" {}", kind.as_phrase()), style),
+            ]));
+        }
+        if lines.is_empty() {
+            lines.push(Line::from(Span::styled(
+                "No kinds.",
+                Style::default().fg(Color::DarkGray),
+            )));
+        }
+
+        let legend = Paragraph::new(lines).block(
+            Block::default()
+                .title(" Legend ")
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Magenta)),
+        );
+        frame.render_widget(legend, area);
+    }
+}
+
+/// Legend grouping of a relationship kind: spatial, social, ownership,
+/// events, lore, then custom kinds.
+fn category(kind: &RelationshipKind) -> u8 {
+    match kind {
+        RelationshipKind::ContainedIn
+        | RelationshipKind::ConnectedTo
+        | RelationshipKind::LocatedAt
+        | RelationshipKind::BasedAt => 0,
+        RelationshipKind::MemberOf
+        | RelationshipKind::LeaderOf
+        | RelationshipKind::AlliedWith
+        | RelationshipKind::RivalOf
+        | RelationshipKind::RelatedTo => 1,
+        RelationshipKind::OwnedBy => 2,
+        RelationshipKind::ParticipatedIn | RelationshipKind::CausedBy => 3,
+        RelationshipKind::References => 4,
+        RelationshipKind::Custom(_) => 5,
+    }
+}
+
+/// Color and line character used to draw edges of a relationship kind.
+///
+/// Each category gets its own line character so kinds stay distinguishable
+/// without color; within a category, kinds differ by color.
+fn kind_style(kind: &RelationshipKind) -> (Color, char) {
+    match kind {
+        RelationshipKind::ContainedIn => (Color::Blue, '-'),
+        RelationshipKind::ConnectedTo => (Color::LightBlue, '-'),
+        RelationshipKind::LocatedAt => (Color::Cyan, '-'),
+        RelationshipKind::BasedAt => (Color::LightCyan, '-'),
+        RelationshipKind::MemberOf => (Color::Yellow, '='),
+        RelationshipKind::LeaderOf => (Color::LightYellow, '='),
+        RelationshipKind::AlliedWith => (Color::Green, '='),
+        RelationshipKind::RivalOf => (Color::Red, '='),
+        RelationshipKind::RelatedTo => (Color::LightMagenta, '='),
+        RelationshipKind::OwnedBy => (Color::LightGreen, '.'),
+        RelationshipKind::ParticipatedIn => (Color::LightRed, '~'),
+        RelationshipKind::CausedBy => (Color::Magenta, '~'),
+        RelationshipKind::References => (Color::Gray, ':'),
+        RelationshipKind::Custom(_) => (Color::White, '.'),
     }
 }
 
@@ -33,13 +166,18 @@ impl Tab for GraphTab {
 
     fn handle_key(&mut self, key: KeyEvent) -> bool {
         match key.code {
-            KeyCode::Char('j') | KeyCode::Down => {
-                self.scroll = self.scroll.saturating_add(1);
+            KeyCode::PageDown => self.scroll = self.scroll.saturating_add(1),
+            KeyCode::PageUp => self.scroll = self.scroll.saturating_sub(1),
+            KeyCode::Char('g') => self.scroll = 0,
+            KeyCode::Char('j') | KeyCode::Down if self.legend_cursor + 1 < self.kinds.len() => {
+                self.legend_cursor += 1;
             }
             KeyCode::Char('k') | KeyCode::Up => {
-                self.scroll = self.scroll.saturating_sub(1);
+                self.legend_cursor = self.legend_cursor.saturating_sub(1);
             }
-            KeyCode::Char('g') => self.scroll = 0,
+            KeyCode::Char(' ') | KeyCode::Enter => self.toggle_selected(),
+            KeyCode::Char('o') => self.isolate_selected(),
+            KeyCode::Char('a') => self.hidden.clear(),
             _ => {}
         }
         false
@@ -56,8 +194,12 @@ impl Tab for GraphTab {
     fn draw(&self, frame: &mut Frame, area: Rect) {
         let mut lines: Vec<Line<'static>> = Vec::new();
         let mut seen_pairs: HashSet<(EntityId, EntityId)> = HashSet::new();
+        let mut shown = 0;
 
         for rel in self.world.all_relationships() {
+            if !self.is_visible(&rel.kind) {
+                continue;
+            }
             let (a, b) = (rel.source.0, rel.target.0);
             let pair = if a < b {
                 (rel.source, rel.target)
@@ -72,33 +214,46 @@ impl Tab for GraphTab {
             let source_name = self.world.entity_name(rel.source);
             let target_name = self.world.entity_name(rel.target);
 
+            let (color, dash) = kind_style(&rel.kind);
+            let line = dash.to_string().repeat(2);
             let arrow = if rel.bidirectional {
-                " <--> "
+                format!(" <{line}> ")
             } else {
-                " ---> "
+                format!(" {line}-> ")
             };
 
-            let label = if let Some(ref l) = rel.label {
+            let mut label = if let Some(ref l) = rel.label {
                 format!("{} ({})", rel.kind.as_phrase(), l)
             } else {
                 rel.kind.as_phrase().to_string()
             };
+            for key in Relationship::ATTRIBUTES {
+                if let Some(value) = rel.attribute(key) {
+                    label.push_str(&format!(", {key} {value}"));
+                }
+            }
 
             lines.push(Line::from(vec![
                 Span::styled(format!("[{source_name}]"), Style::default().fg(Color::Cyan)),
-                Span::styled(arrow.to_string(), Style::default().fg(Color::DarkGray)),
-                Span::styled(label, Style::default().fg(Color::Yellow)),
-                Span::styled(arrow.to_string(), Style::default().fg(Color::DarkGray)),
+                Span::styled(arrow.clone(), Style::default().fg(color)),
+                Span::styled(label, Style::default().fg(color)),
+                Span::styled(arrow, Style::default().fg(color)),
                 Span::styled(
                     format!("[{target_name}]"),
                     Style::default().fg(Color::Green),
                 ),
             ]));
+            shown += 1;
         }
 
         if lines.is_empty() {
+            let msg = if self.hidden.is_empty() {
+                "No relationships."
+            } else {
+                "All relationship kinds are hidden (a: show all)."
+            };
             lines.push(Line::from(Span::styled(
-                "No relationships.",
+                msg,
                 Style::default().fg(Color::DarkGray),
             )));
         }
@@ -108,14 +263,26 @@ impl Tab for GraphTab {
         let stats = self.world.entity_counts_by_kind();
         let mut sorted: Vec<_> = stats.iter().collect();
         sorted.sort_by_key(|(k, _)| format!("{k}"));
-        let summary: Vec<String> = sorted.iter().map(|(k, v)| format!("{v} {k}")).collect();
+        let grammar = Grammar::from_meta(&self.world.meta);
+        let summary: Vec<String> = sorted
+            .iter()
+            .map(|(k, v)| grammar.count(**v, &k.to_string()))
+            .collect();
 
         lines.push(Line::from(vec![Span::styled(
-            format!(
-                "{} entities, {} relationships",
-                self.world.entity_count(),
-                self.world.relationship_count()
-            ),
+            if self.hidden.is_empty() {
+                format!(
+                    "{}, {}",
+                    grammar.count(self.world.entity_count(), "entity"),
+                    grammar.count(self.world.relationship_count(), "relationship")
+                )
+            } else {
+                format!(
+                    "{}, {shown} of {} shown",
+                    grammar.count(self.world.entity_count(), "entity"),
+                    grammar.count(self.world.relationship_count(), "relationship")
+                )
+            },
             Style::default().fg(Color::DarkGray),
         )]));
         if !summary.is_empty() {
@@ -135,10 +302,93 @@ impl Tab for GraphTab {
             .wrap(Wrap { trim: false })
             .scroll((self.scroll, 0));
 
-        frame.render_widget(paragraph, area);
+        let chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Min(20), Constraint::Length(LEGEND_WIDTH)])
+            .split(area);
+
+        frame.render_widget(paragraph, chunks[0]);
+        self.draw_legend(frame, chunks[1]);
     }
 
     fn status_hint(&self) -> &str {
-        "j/k:scroll  Tab:view  ?:help  q:quit"
+        "j/k:legend  PgUp/PgDn:scroll  Space:toggle  o:only  a:all  Tab:view  ?:help  q:quit"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+    use ww_core::WorldMeta;
+    use ww_core::entity::{Entity, EntityKind};
+    use ww_core::relationship::Relationship;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    fn test_tab() -> GraphTab {
+        let mut world = World::new(WorldMeta::new("Test"));
+        let kael = world
+            .add_entity(Entity::new(EntityKind::Character, "Kael"))
+            .unwrap();
+        let order = world
+            .add_entity(Entity::new(EntityKind::Faction, "the Order"))
+            .unwrap();
+        let keep = world
+            .add_entity(Entity::new(EntityKind::Location, "the Keep"))
+            .unwrap();
+        world
+            .add_relationship(Relationship::new(kael, RelationshipKind::MemberOf, order))
+            .unwrap();
+        world
+            .add_relationship(Relationship::new(order, RelationshipKind::BasedAt, keep))
+            .unwrap();
+        GraphTab::new(world)
+    }
+
+    #[test]
+    fn legend_lists_kinds_by_category() {
+        let tab = test_tab();
+        assert_eq!(
+            tab.kinds,
+            vec![RelationshipKind::BasedAt, RelationshipKind::MemberOf]
+        );
+    }
+
+    #[test]
+    fn legend_moves_vertically_and_pages_scroll() {
+        let mut tab = test_tab();
+        tab.handle_key(key(KeyCode::Down));
+        tab.handle_key(key(KeyCode::Char('j')));
+        assert_eq!(tab.legend_cursor, 1);
+        tab.handle_key(key(KeyCode::Up));
+        assert_eq!(tab.legend_cursor, 0);
+        tab.handle_key(key(KeyCode::Char('k')));
+        assert_eq!(tab.legend_cursor, 0);
+        assert_eq!(tab.scroll, 0);
+
+        tab.handle_key(key(KeyCode::PageDown));
+        assert_eq!((tab.legend_cursor, tab.scroll), (0, 1));
+        tab.handle_key(key(KeyCode::PageUp));
+        assert_eq!(tab.scroll, 0);
+    }
+
+    #[test]
+    fn toggle_isolate_and_show_all() {
+        let mut tab = test_tab();
+        tab.handle_key(key(KeyCode::Char(' ')));
+        assert!(!tab.is_visible(&RelationshipKind::BasedAt));
+        tab.handle_key(key(KeyCode::Char(' ')));
+        assert!(tab.is_visible(&RelationshipKind::BasedAt));
+
+        tab.handle_key(key(KeyCode::Char('j')));
+        tab.handle_key(key(KeyCode::Char('o')));
+        assert!(tab.is_visible(&RelationshipKind::MemberOf));
+        assert!(!tab.is_visible(&RelationshipKind::BasedAt));
+
+        tab.handle_key(key(KeyCode::Char('a')));
+        assert!(tab.is_visible(&RelationshipKind::BasedAt));
     }
 }
@@ -4,6 +4,7 @@ pub mod dice;
 pub mod explorer;
 pub mod graph;
 pub mod play;
+pub mod search;
 pub mod sheet;
 pub mod solo;
 pub mod timeline;
@@ -28,11 +29,13 @@ pub enum TabId {
     Sheet,
     /// Dice roller.
     Dice,
+    /// Ranked search across all entities.
+    Search,
 }
 
 impl TabId {
     /// All tab IDs in display order.
-    pub const ALL: [TabId; 7] = [
+    pub const ALL: [TabId; 8] = [
         TabId::Explorer,
         TabId::Graph,
         TabId::Timeline,
@@ -40,6 +43,7 @@ impl TabId {
         TabId::Solo,
         TabId::Sheet,
         TabId::Dice,
+        TabId::Search,
     ];
 
     /// Parse a tab name from a string.
@@ -52,6 +56,7 @@ impl TabId {
             "solo" => Some(TabId::Solo),
             "sheet" => Some(TabId::Sheet),
             "dice" => Some(TabId::Dice),
+            "search" => Some(TabId::Search),
             _ => None,
         }
     }
@@ -118,6 +123,7 @@ pub fn draw_tab_bar(frame: &mut Frame, active: TabId, area: Rect) {
         "[5]Solo",
         "[6]Sheet",
         "[7]Dice",
+        "[8]Search",
     ];
 
     // Create spans with proper styling for each tab
@@ -191,6 +197,7 @@ mod rendering_tests {
         assert!(line.contains("[5]Solo"), "Solo tab missing");
         assert!(line.contains("[6]Sheet"), "Sheet tab missing");
         assert!(line.contains("[7]Dice"), "Dice tab missing");
+        assert!(line.contains("[8]Search"), "Search tab missing");
     }
 
     #[test]
@@ -213,7 +220,7 @@ mod rendering_tests {
 
         // Verify dividers are present between tabs
         let divider_count = line.matches(" | ").count();
-        assert_eq!(divider_count, 6, "Should have 6 dividers between 7 tabs");
+        assert_eq!(divider_count, 7, "Should have 7 dividers between 8 tabs");
     }
 
     #[test]
@@ -242,6 +249,7 @@ mod rendering_tests {
         let solo_pos = line.find("[5]Solo").unwrap();
         let sheet_pos = line.find("[6]Sheet").unwrap();
         let dice_pos = line.find("[7]Dice").unwrap();
+        let search_pos = line.find("[8]Search").unwrap();
 
         // Verify order
         assert!(
@@ -256,6 +264,7 @@ mod rendering_tests {
         assert!(play_pos < solo_pos, "Play should come before Solo");
         assert!(solo_pos < sheet_pos, "Solo should come before Sheet");
         assert!(sheet_pos < dice_pos, "Sheet should come before Dice");
+        assert!(dice_pos < search_pos, "Dice should come before Search");
     }
 
     #[test]
@@ -5,37 +5,55 @@ use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
 
 use ww_core::World;
-use ww_fiction::FictionSession;
+use ww_fiction::{FictionSession, SessionSave};
 
-use crate::shared::{OutputLine, OutputStyle};
+use crate::shared::{CommandHistory, FileCommand, OutputFormat, OutputLine, OutputStyle};
 use crate::tabs::{InputMode, Tab};
 
 /// Interactive fiction play tab state.
 pub struct PlayTab {
+    /// The world as loaded, for resuming saved sessions.
+    world: World,
     /// The fiction session (lazily initialized).
     session: Option<FictionSession>,
     /// Styled output log.
     output_lines: Vec<OutputLine>,
+    /// Per-style output templates (from world metadata).
+    output_format: OutputFormat,
     /// Scroll offset from the bottom.
     output_scroll: u16,
     /// Current input text.
     input_text: String,
     /// Cursor position within input text.
     input_cursor: usize,
+    /// Submitted commands, for Up/Down recall.
+    history: CommandHistory,
     /// Initialization error.
     error: Option<String>,
+    /// Column width narration is word-wrapped to.
+    wrap_width: Option<usize>,
 }
 
 impl PlayTab {
     /// Create a new play tab for the given world.
     pub fn new(world: World) -> Self {
+        Self::with_wrap_width(world, None)
+    }
+
+    /// Create a new play tab whose narration is word-wrapped to `width`
+    /// columns, or left unwrapped if `None`.
+    pub fn with_wrap_width(world: World, width: Option<usize>) -> Self {
         let mut tab = Self {
+            world: world.clone(),
             session: None,
             output_lines: Vec::new(),
+            output_format: OutputFormat::from_world_meta(&world.meta.properties),
             output_scroll: 0,
             input_text: String::new(),
             input_cursor: 0,
+            history: CommandHistory::default(),
             error: None,
+            wrap_width: width,
         };
         tab.initialize(world);
         tab
@@ -44,11 +62,13 @@ impl PlayTab {
     fn initialize(&mut self, world: World) {
         match FictionSession::new(world) {
             Ok(mut session) => {
+                session.set_wrap_width(self.wrap_width);
                 self.push_output(
                     OutputStyle::System,
                     "Interactive Fiction\n\
                      Explore the world, talk to characters,\n\
-                     pick up items. Type 'help' for commands.",
+                     pick up items. Type 'help' for commands,\n\
+                     'save <file>' and 'load <file>' to keep your progress.",
                 );
                 match session.process("look") {
                     Ok(output) => self.push_output(OutputStyle::Result, &output),
@@ -69,29 +89,50 @@ impl PlayTab {
         }
         self.input_text.clear();
         self.input_cursor = 0;
+        self.history.push(&input);
 
         self.push_output(OutputStyle::Command, &input);
 
-        if let Some(session) = &mut self.session {
-            match session.process(&input) {
-                Ok(output) => {
-                    if !output.is_empty() {
-                        self.push_output(OutputStyle::Result, &output);
-                    }
-                }
-                Err(e) => {
-                    self.push_output(OutputStyle::Error, &e.to_string());
+        match self.process(&input) {
+            Ok(output) => {
+                if !output.is_empty() {
+                    self.push_output(OutputStyle::Result, &output);
                 }
             }
+            Err(e) => {
+                self.push_output(OutputStyle::Error, &e);
+            }
+        }
+    }
+
+    /// Run a command, handling `save` and `load` here and passing anything
+    /// else to the session.
+    fn process(&mut self, input: &str) -> Result<String, String> {
+        let Some(session) = &mut self.session else {
+            return Ok(String::new());
+        };
+        match FileCommand::parse(input) {
+            Some(FileCommand::Save(path)) => {
+                FileCommand::write(path, &session.save().to_json())?;
+                Ok(format!("Saved session to {path}"))
+            }
+            Some(FileCommand::Load(path)) => {
+                let save =
+                    SessionSave::from_json(&FileCommand::read(path)?).map_err(|e| e.to_string())?;
+                let mut loaded =
+                    FictionSession::load(self.world.clone(), save).map_err(|e| e.to_string())?;
+                loaded.set_wrap_width(self.wrap_width);
+                let look = loaded.process("look").map_err(|e| e.to_string())?;
+                *session = loaded;
+                Ok(format!("Loaded session from {path}\n\n{look}"))
+            }
+            None => session.process(input).map_err(|e| e.to_string()),
         }
     }
 
     fn push_output(&mut self, style: OutputStyle, text: &str) {
         for line in text.lines() {
-            self.output_lines.push(OutputLine {
-                style,
-                text: line.to_string(),
-            });
+            self.output_lines.push(OutputLine::new(style, line));
         }
         self.output_scroll = 0;
     }
@@ -105,47 +146,51 @@ impl Tab for PlayTab {
     fn handle_key(&mut self, key: KeyEvent) -> bool {
         match key.code {
             KeyCode::Enter => self.submit_input(),
-            KeyCode::Esc => {
-                if !self.input_text.is_empty() {
-                    self.input_text.clear();
-                    self.input_cursor = 0;
-                }
+            KeyCode::Esc if !self.input_text.is_empty() => {
+                self.input_text.clear();
+                self.input_cursor = 0;
             }
-            KeyCode::Backspace => {
-                if self.input_cursor > 0 {
-                    let prev = self.input_text[..self.input_cursor]
-                        .char_indices()
-                        .next_back()
-                        .map(|(i, _)| i)
-                        .unwrap_or(0);
-                    self.input_text.remove(prev);
-                    self.input_cursor = prev;
-                }
+            KeyCode::Backspace if self.input_cursor > 0 => {
+                let prev = self.input_text[..self.input_cursor]
+                    .char_indices()
+                    .next_back()
+                    .map(|(i, _)| i)
+                    .unwrap_or(0);
+                self.input_text.remove(prev);
+                self.input_cursor = prev;
             }
-            KeyCode::Left => {
-                if self.input_cursor > 0 {
-                    let prev = self.input_text[..self.input_cursor]
-                        .char_indices()
-                        .next_back()
-                        .map(|(i, _)| i)
-                        .unwrap_or(0);
-                    self.input_cursor = prev;
-                }
+            KeyCode::Left if self.input_cursor > 0 => {
+                let prev = self.input_text[..self.input_cursor]
+                    .char_indices()
+                    .next_back()
+                    .map(|(i, _)| i)
+                    .unwrap_or(0);
+                self.input_cursor = prev;
             }
-            KeyCode::Right => {
-                if self.input_cursor < self.input_text.len() {
-                    let next = self.input_text[self.input_cursor..]
-                        .char_indices()
-                        .nth(1)
-                        .map(|(i, _)| self.input_cursor + i)
-                        .unwrap_or(self.input_text.len());
-                    self.input_cursor = next;
-                }
+            KeyCode::Right if self.input_cursor < self.input_text.len() => {
+                let next = self.input_text[self.input_cursor..]
+                    .char_indices()
+                    .nth(1)
+                    .map(|(i, _)| self.input_cursor + i)
+                    .unwrap_or(self.input_text.len());
+                self.input_cursor = next;
             }
             KeyCode::Home => self.input_cursor = 0,
             KeyCode::End => self.input_cursor = self.input_text.len(),
-            KeyCode::Up => self.output_scroll = self.output_scroll.saturating_add(1),
-            KeyCode::Down => self.output_scroll = self.output_scroll.saturating_sub(1),
+            KeyCode::Up => {
+                if let Some(text) = self.history.prev(&self.input_text) {
+                    self.input_text = text;
+                    self.input_cursor = self.input_text.len();
+                }
+            }
+            KeyCode::Down => {
+                if let Some(text) = self.history.next(&self.input_text) {
+                    self.input_text = text;
+                    self.input_cursor = self.input_text.len();
+                }
+            }
+            KeyCode::PageUp => self.output_scroll = self.output_scroll.saturating_add(1),
+            KeyCode::PageDown => self.output_scroll = self.output_scroll.saturating_sub(1),
             KeyCode::Char(c) => {
                 self.input_text.insert(self.input_cursor, c);
                 self.input_cursor += c.len_utf8();
@@ -190,18 +235,7 @@ impl Tab for PlayTab {
             let lines: Vec<Line> = self
                 .output_lines
                 .iter()
-                .map(|ol| {
-                    let (prefix, color, modifier) = match ol.style {
-                        OutputStyle::Command => ("> ", Color::Yellow, Modifier::BOLD),
-                        OutputStyle::Result => ("", Color::White, Modifier::empty()),
-                        OutputStyle::Error => ("", Color::Red, Modifier::empty()),
-                        OutputStyle::System => ("", Color::Cyan, Modifier::ITALIC),
-                    };
-                    Line::from(Span::styled(
-                        format!("{prefix}{}", ol.text),
-                        Style::default().fg(color).add_modifier(modifier),
-                    ))
-                })
+                .map(|ol| self.output_format.render(ol))
                 .collect();
 
             let inner_width = chunks[0].width.saturating_sub(2) as usize;
@@ -252,6 +286,6 @@ impl Tab for PlayTab {
     }
 
     fn status_hint(&self) -> &str {
-        "Enter:send  Esc:clear  \u{2191}\u{2193}:scroll  Ctrl+C:quit"
+        "Enter:send  Esc:clear  \u{2191}\u{2193}:history  PgUp/PgDn:scroll  Ctrl+C:quit"
     }
 }
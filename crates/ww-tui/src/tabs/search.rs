@@ -0,0 +1,204 @@
+//! Search tab: ranked full-text search across every entity.
+
+use crossterm::event::{KeyCode, KeyEvent, MouseEvent, MouseEventKind};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph};
+
+use ww_core::World;
+use ww_core::entity::EntityId;
+use ww_core::query::MatchField;
+
+use super::{InputMode, Tab};
+
+/// One ranked result, resolved to what the list shows.
+struct SearchResult {
+    id: EntityId,
+    name: String,
+    kind: String,
+    field: MatchField,
+}
+
+/// Search tab state.
+pub struct SearchTab {
+    /// The world data.
+    world: World,
+    /// Current query text.
+    query: String,
+    /// Results for the current query, best first.
+    results: Vec<SearchResult>,
+    /// Cursor position in the results.
+    cursor: usize,
+    /// Entity chosen with Enter, waiting to be opened in the explorer.
+    opened: Option<EntityId>,
+}
+
+impl SearchTab {
+    /// Create a new search tab for the given world.
+    pub fn new(world: World) -> Self {
+        Self {
+            world,
+            query: String::new(),
+            results: Vec::new(),
+            cursor: 0,
+            opened: None,
+        }
+    }
+
+    /// Take the entity the user chose to open, if any.
+    pub fn take_opened(&mut self) -> Option<EntityId> {
+        self.opened.take()
+    }
+
+    fn update_results(&mut self) {
+        self.results = self
+            .world
+            .query()
+            .ranked(&self.query)
+            .into_iter()
+            .map(|hit| SearchResult {
+                id: hit.entity.id,
+                name: hit.entity.name.clone(),
+                kind: hit.entity.kind.to_string(),
+                field: hit.field,
+            })
+            .collect();
+        self.cursor = 0;
+    }
+
+    fn move_down(&mut self) {
+        if self.cursor + 1 < self.results.len() {
+            self.cursor += 1;
+        }
+    }
+
+    fn move_up(&mut self) {
+        self.cursor = self.cursor.saturating_sub(1);
+    }
+}
+
+impl Tab for SearchTab {
+    fn input_mode(&self) -> InputMode {
+        InputMode::TextInput
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        match key.code {
+            KeyCode::Enter => self.opened = self.results.get(self.cursor).map(|r| r.id),
+            KeyCode::Esc if !self.query.is_empty() => {
+                self.query.clear();
+                self.update_results();
+            }
+            KeyCode::Backspace if !self.query.is_empty() => {
+                self.query.pop();
+                self.update_results();
+            }
+            KeyCode::Down => self.move_down(),
+            KeyCode::Up => self.move_up(),
+            KeyCode::Char(c) => {
+                self.query.push(c);
+                self.update_results();
+            }
+            _ => {}
+        }
+        false
+    }
+
+    fn handle_mouse(&mut self, mouse: MouseEvent) {
+        match mouse.kind {
+            MouseEventKind::ScrollUp => self.move_up(),
+            MouseEventKind::ScrollDown => self.move_down(),
+            _ => {}
+        }
+    }
+
+    fn draw(&self, frame: &mut Frame, area: Rect) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(3), Constraint::Min(0)])
+            .split(area);
+
+        let input = Paragraph::new(format!("{}_", self.query)).block(
+            Block::default()
+                .title(" Search ")
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Yellow)),
+        );
+        frame.render_widget(input, chunks[0]);
+
+        let items: Vec<ListItem> = self
+            .results
+            .iter()
+            .map(|r| {
+                let field = match r.field {
+                    MatchField::Name => "name",
+                    MatchField::Tag => "tag",
+                    MatchField::Description => "description",
+                };
+                ListItem::new(Line::from(vec![
+                    Span::styled(&r.name, Style::default().fg(Color::White).bold()),
+                    Span::raw("  "),
+                    Span::styled(&r.kind, Style::default().fg(Color::DarkGray)),
+                    Span::raw("  "),
+                    Span::styled(
+                        format!("({field})"),
+                        Style::default().fg(Color::DarkGray).italic(),
+                    ),
+                ]))
+            })
+            .collect();
+
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .title(format!(" Results ({}) ", self.results.len()))
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Blue)),
+            )
+            .highlight_style(Style::default().bg(Color::DarkGray).fg(Color::White).bold())
+            .highlight_symbol("\u{25b6} ");
+
+        let mut state = ListState::default();
+        state.select((!self.results.is_empty()).then_some(self.cursor));
+        frame.render_stateful_widget(list, chunks[1], &mut state);
+    }
+
+    fn status_hint(&self) -> &str {
+        "type to search  \u{2191}\u{2193}:select  Enter:open in explorer  Esc:clear  Ctrl+C:quit"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+    use ww_core::{Entity, EntityKind, WorldMeta};
+
+    fn type_query(tab: &mut SearchTab, text: &str) {
+        for c in text.chars() {
+            tab.handle_key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+        }
+    }
+
+    #[test]
+    fn enter_opens_best_match() {
+        let mut world = World::new(WorldMeta::new("test-world"));
+        let mut hermit = Entity::new(EntityKind::Character, "Hermit");
+        hermit.description = "Lives beside the old mill.".to_string();
+        world.add_entity(hermit).unwrap();
+        let mill = world
+            .add_entity(Entity::new(EntityKind::Location, "Mill"))
+            .unwrap();
+
+        let mut tab = SearchTab::new(world);
+        type_query(&mut tab, "mill");
+        assert_eq!(tab.results.len(), 2);
+        assert_eq!(tab.results[1].field, MatchField::Description);
+
+        tab.handle_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+        assert_eq!(tab.take_opened(), Some(mill));
+        assert_eq!(tab.take_opened(), None);
+
+        tab.handle_key(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+        assert!(tab.results.is_empty());
+    }
+}
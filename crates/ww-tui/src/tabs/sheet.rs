@@ -64,19 +64,17 @@ impl Tab for SheetTab {
     fn handle_key(&mut self, key: KeyEvent) -> bool {
         match self.sub_view {
             SubView::List => match key.code {
-                KeyCode::Char('j') | KeyCode::Down => {
-                    if self.list_cursor + 1 < self.character_ids.len() {
-                        self.list_cursor += 1;
-                    }
+                KeyCode::Char('j') | KeyCode::Down
+                    if self.list_cursor + 1 < self.character_ids.len() =>
+                {
+                    self.list_cursor += 1;
                 }
                 KeyCode::Char('k') | KeyCode::Up => {
                     self.list_cursor = self.list_cursor.saturating_sub(1);
                 }
                 KeyCode::Char('g') => self.list_cursor = 0,
-                KeyCode::Char('G') => {
-                    if !self.character_ids.is_empty() {
-                        self.list_cursor = self.character_ids.len() - 1;
-                    }
+                KeyCode::Char('G') if !self.character_ids.is_empty() => {
+                    self.list_cursor = self.character_ids.len() - 1;
                 }
                 KeyCode::Enter => {
                     if let Some(&id) = self.character_ids.get(self.list_cursor) {
@@ -288,6 +286,22 @@ fn draw_sheet_detail(frame: &mut Frame, tab: &SheetTab, area: Rect) {
         lines.push(Line::from(""));
     }
 
+    // Derived stats
+    if !sheet.derived.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "Derived",
+            Style::default().fg(Color::Yellow).bold(),
+        )));
+        for (name, value) in sheet.derived_values() {
+            let (text, color) = match value {
+                Ok(v) => (format!("  {name}: {v}"), Color::White),
+                Err(e) => (format!("  {name}: ? ({e})"), Color::Red),
+            };
+            lines.push(Line::from(Span::styled(text, Style::default().fg(color))));
+        }
+        lines.push(Line::from(""));
+    }
+
     // Focuses
     if !sheet.focuses.is_empty() {
         lines.push(Line::from(Span::styled(
@@ -6,9 +6,9 @@ use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent,
 use ratatui::prelude::*;
 
 use ww_core::World;
-use ww_solo::{SoloConfig, SoloSession};
+use ww_solo::{SoloConfig, SoloSave, SoloSession};
 
-use crate::shared::{OutputLine, OutputStyle};
+use crate::shared::{CommandHistory, FileCommand, OutputFormat, OutputLine, OutputStyle};
 use crate::tabs::{InputMode, Tab};
 
 /// Tab-completion state.
@@ -28,8 +28,12 @@ pub struct CompletionState {
 pub struct SoloTab {
     /// The solo session engine.
     pub session: SoloSession,
+    /// The world and config the session started from, for loading saves.
+    origin: (World, SoloConfig),
     /// Styled output log.
     pub output_lines: Vec<OutputLine>,
+    /// Per-style output templates (from world metadata).
+    pub output_format: OutputFormat,
     /// Scroll offset from the bottom (0 = fully scrolled down).
     pub output_scroll: u16,
     /// Current input text.
@@ -38,6 +42,8 @@ pub struct SoloTab {
     pub input_cursor: usize,
     /// Tab-completion state.
     pub completion: CompletionState,
+    /// Submitted commands, for Up/Down recall.
+    pub history: CommandHistory,
     /// Whether the help popup is visible.
     pub show_help: bool,
 }
@@ -45,16 +51,21 @@ pub struct SoloTab {
 impl SoloTab {
     /// Create a new solo tab from a world and config.
     pub fn new(world: World, config: SoloConfig) -> Result<Self, String> {
+        let output_format = OutputFormat::from_world_meta(&world.meta.properties);
+        let origin = (world.clone(), config.clone());
         let session =
             SoloSession::new(world, config).map_err(|e| format!("failed to start session: {e}"))?;
         let intro = session.intro();
         let mut tab = Self {
             session,
+            origin,
             output_lines: Vec::new(),
+            output_format,
             output_scroll: 0,
             input_text: String::new(),
             input_cursor: 0,
             completion: CompletionState::default(),
+            history: CommandHistory::default(),
             show_help: false,
         };
         tab.push_output(OutputStyle::System, &intro);
@@ -68,6 +79,7 @@ impl SoloTab {
             return;
         }
         self.clear_completion();
+        self.history.push(&input);
         self.execute_command(&input);
         self.input_text.clear();
         self.input_cursor = 0;
@@ -77,18 +89,37 @@ impl SoloTab {
     pub fn execute_command(&mut self, cmd: &str) {
         self.push_output(OutputStyle::Command, cmd);
 
-        match self.session.process(cmd) {
+        match self.process(cmd) {
             Ok(output) => {
                 if !output.is_empty() {
                     self.push_output(OutputStyle::Result, &output);
                 }
             }
             Err(e) => {
-                self.push_output(OutputStyle::Error, &e.to_string());
+                self.push_output(OutputStyle::Error, &e);
             }
         }
     }
 
+    /// Run a command, handling `save` and `load` here and passing anything
+    /// else to the session.
+    pub fn process(&mut self, cmd: &str) -> Result<String, String> {
+        match FileCommand::parse(cmd) {
+            Some(FileCommand::Save(path)) => {
+                FileCommand::write(path, &self.session.save().to_json())?;
+                Ok(format!("Saved session to {path}"))
+            }
+            Some(FileCommand::Load(path)) => {
+                let save =
+                    SoloSave::from_json(&FileCommand::read(path)?).map_err(|e| e.to_string())?;
+                let (world, config) = self.origin.clone();
+                self.session = SoloSession::load(world, config, save).map_err(|e| e.to_string())?;
+                Ok(format!("Loaded session from {path}"))
+            }
+            None => self.session.process(cmd).map_err(|e| e.to_string()),
+        }
+    }
+
     /// Prefill the input with a command prefix (from button click).
     pub fn prefill_input(&mut self, text: &str) {
         self.input_text = text.to_string();
@@ -237,6 +268,22 @@ impl SoloTab {
         self.input_cursor = self.input_text.len();
     }
 
+    /// Replace the input with the previous command in the history.
+    pub fn history_prev(&mut self) {
+        if let Some(text) = self.history.prev(&self.input_text) {
+            self.input_text = text;
+            self.input_cursor = self.input_text.len();
+        }
+    }
+
+    /// Replace the input with the next command in the history.
+    pub fn history_next(&mut self) {
+        if let Some(text) = self.history.next(&self.input_text) {
+            self.input_text = text;
+            self.input_cursor = self.input_text.len();
+        }
+    }
+
     /// Scroll output up.
     pub fn scroll_up(&mut self) {
         self.output_scroll = self.output_scroll.saturating_add(1);
@@ -250,10 +297,7 @@ impl SoloTab {
     /// Append styled text to the output log.
     fn push_output(&mut self, style: OutputStyle, text: &str) {
         for line in text.lines() {
-            self.output_lines.push(OutputLine {
-                style,
-                text: line.to_string(),
-            });
+            self.output_lines.push(OutputLine::new(style, line));
         }
         self.output_scroll = 0;
     }
@@ -303,16 +347,18 @@ impl Tab for SoloTab {
                 if self.completion.active {
                     self.tab_complete_prev();
                 } else {
-                    self.scroll_up();
+                    self.history_prev();
                 }
             }
             KeyCode::Down => {
                 if self.completion.active {
                     self.tab_complete();
                 } else {
-                    self.scroll_down();
+                    self.history_next();
                 }
             }
+            KeyCode::PageUp => self.scroll_up(),
+            KeyCode::PageDown => self.scroll_down(),
             KeyCode::Char('?') if self.input_text.is_empty() && !self.completion.active => {
                 self.show_help = !self.show_help;
             }
@@ -384,7 +430,7 @@ impl Tab for SoloTab {
         if self.completion.active {
             "Tab:cycle  Enter:accept  Esc:cancel"
         } else {
-            "Tab:complete  Enter:send  \u{2191}\u{2193}:scroll  ?:help  Ctrl+C:quit"
+            "Tab:complete  Enter:send  \u{2191}\u{2193}:history  PgUp/PgDn:scroll  ?:help  Ctrl+C:quit"
         }
     }
 }
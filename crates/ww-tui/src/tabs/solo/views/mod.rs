@@ -21,7 +21,8 @@ pub fn draw_help_popup(frame: &mut Frame) {
         Line::from("  Tab         Autocomplete (cycle forward)"),
         Line::from("  Shift+Tab   Cycle backward"),
         Line::from("  Esc         Clear completion / input"),
-        Line::from("  \u{2191} / \u{2193}       Scroll output"),
+        Line::from("  \u{2191} / \u{2193}       Recall previous commands"),
+        Line::from("  PgUp / PgDn Scroll output"),
         Line::from("  \u{2190} / \u{2192}       Move cursor in input"),
         Line::from("  Home / End  Jump to start / end"),
         Line::from("  Ctrl+C      Quit"),
@@ -3,7 +3,6 @@
 use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
 
-use crate::shared::OutputStyle;
 use crate::tabs::solo::SoloTab;
 
 /// Draw the scrollable output panel.
@@ -11,18 +10,7 @@ pub fn draw(frame: &mut Frame, tab: &SoloTab, area: Rect) {
     let lines: Vec<Line> = tab
         .output_lines
         .iter()
-        .map(|ol| {
-            let (prefix, color, modifier) = match ol.style {
-                OutputStyle::Command => ("> ", Color::Yellow, Modifier::BOLD),
-                OutputStyle::Result => ("", Color::White, Modifier::empty()),
-                OutputStyle::Error => ("", Color::Red, Modifier::empty()),
-                OutputStyle::System => ("", Color::Cyan, Modifier::ITALIC),
-            };
-            Line::from(Span::styled(
-                format!("{prefix}{}", ol.text),
-                Style::default().fg(color).add_modifier(modifier),
-            ))
-        })
+        .map(|ol| tab.output_format.render(ol))
         .collect();
 
     // Calculate scroll: show the bottom by default.
@@ -1,10 +1,15 @@
-//! Sidebar panel: chaos, scene, tracks, threads, NPCs.
+//! Sidebar panel: chaos, scene, tracks, roll stats, threads, oracle log, NPCs.
 
 use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, Gauge, List, ListItem, Paragraph};
 
+use ww_solo::journal::entry::JournalEntry;
+
 use crate::tabs::solo::SoloTab;
 
+/// How many recent oracle answers the sidebar lists.
+const ORACLE_LOG_LEN: usize = 5;
+
 /// Draw the sidebar status panel.
 pub fn draw(frame: &mut Frame, tab: &SoloTab, area: Rect) {
     let block = Block::default()
@@ -31,31 +36,35 @@ pub fn draw(frame: &mut Frame, tab: &SoloTab, area: Rect) {
             .constraints([
                 Constraint::Length(2),                  // Chaos + Scene
                 Constraint::Length(track_count.max(1)), // Tracks
+                Constraint::Length(1),                  // Roll stats
                 Constraint::Min(2),                     // Threads + NPCs
             ])
             .split(inner);
 
         draw_info(frame, tab, chunks[0]);
         draw_tracks(frame, tab, chunks[1]);
-        draw_lists(frame, tab, chunks[2]);
+        draw_roll_stats(frame, tab, chunks[2]);
+        draw_lists(frame, tab, chunks[3]);
     } else {
         // Chaos disabled: skip chaos/scene section
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
                 Constraint::Length(track_count.max(1)), // Tracks
+                Constraint::Length(1),                  // Roll stats
                 Constraint::Min(2),                     // Threads + NPCs
             ])
             .split(inner);
 
         draw_tracks(frame, tab, chunks[0]);
-        draw_lists(frame, tab, chunks[1]);
+        draw_roll_stats(frame, tab, chunks[1]);
+        draw_lists(frame, tab, chunks[2]);
     }
 }
 
 /// Chaos factor and scene info.
 fn draw_info(frame: &mut Frame, tab: &SoloTab, area: Rect) {
-    let chaos = tab.session.chaos().value();
+    let chaos = tab.session.chaos();
     let chaos_label = tab
         .session
         .world_config()
@@ -63,9 +72,9 @@ fn draw_info(frame: &mut Frame, tab: &SoloTab, area: Rect) {
         .as_deref()
         .unwrap_or("Chaos");
 
-    let chaos_color = if chaos >= 7 {
+    let chaos_color = if chaos.chart_value() >= 7 {
         Color::Red
-    } else if chaos >= 4 {
+    } else if chaos.chart_value() >= 4 {
         Color::Yellow
     } else {
         Color::Green
@@ -74,7 +83,7 @@ fn draw_info(frame: &mut Frame, tab: &SoloTab, area: Rect) {
     let mut lines = vec![Line::from(vec![
         Span::raw(format!("{chaos_label}: ")),
         Span::styled(
-            format!("{chaos}/9"),
+            format!("{}/{}", chaos.value(), chaos.max()),
             Style::default().fg(chaos_color).bold(),
         ),
     ])];
@@ -138,15 +147,49 @@ fn draw_tracks(frame: &mut Frame, tab: &SoloTab, area: Rect) {
     }
 }
 
-/// Thread and NPC lists.
+/// One-line summary of recent checks and rolls.
+fn draw_roll_stats(frame: &mut Frame, tab: &SoloTab, area: Rect) {
+    let stats = tab.session.roll_stats();
+    let line = if stats.checks == 0 && stats.rolls == 0 {
+        Line::from(Span::styled(
+            "No rolls yet",
+            Style::default().fg(Color::DarkGray),
+        ))
+    } else {
+        Line::from(vec![
+            Span::raw("Checks "),
+            Span::styled(
+                format!("{}/{}", stats.successes, stats.checks),
+                Style::default().fg(Color::Green).bold(),
+            ),
+            Span::styled(
+                format!("  {} rolls", stats.rolls),
+                Style::default().fg(Color::DarkGray),
+            ),
+        ])
+    };
+    frame.render_widget(Paragraph::new(line), area);
+}
+
+/// Thread list, recent oracle answers, and NPC list.
 fn draw_lists(frame: &mut Frame, tab: &SoloTab, area: Rect) {
     let threads = tab.session.threads().active();
+    let oracle = tab.session.journal().recent_oracle(ORACLE_LOG_LEN);
     let npcs = tab.session.npcs().list();
 
-    let thread_height = (threads.len() as u16 + 1).min(area.height / 2).max(1);
+    let thread_height = (threads.len() as u16 + 1).min(area.height / 3).max(1);
+    let oracle_height = if oracle.is_empty() {
+        0
+    } else {
+        (oracle.len() as u16 + 1).min(area.height / 3)
+    };
     let chunks = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([Constraint::Length(thread_height), Constraint::Min(1)])
+        .constraints([
+            Constraint::Length(thread_height),
+            Constraint::Length(oracle_height),
+            Constraint::Min(1),
+        ])
         .split(area);
 
     // Threads
@@ -168,6 +211,42 @@ fn draw_lists(frame: &mut Frame, tab: &SoloTab, area: Rect) {
     );
     frame.render_widget(thread_list, chunks[0]);
 
+    // Oracle log
+    let oracle_items: Vec<ListItem> = oracle
+        .iter()
+        .filter_map(|entry| match entry {
+            JournalEntry::OracleQuery {
+                question,
+                likelihood,
+                result,
+                ..
+            } => Some(Line::from(vec![
+                Span::styled(
+                    format!(" {result}"),
+                    Style::default().fg(Color::Yellow).bold(),
+                ),
+                Span::styled(
+                    format!(" ({likelihood}) {question}"),
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ])),
+            JournalEntry::RandomEvent { description, .. } => Some(Line::from(Span::styled(
+                format!(" ! {description}"),
+                Style::default().fg(Color::Red),
+            ))),
+            _ => None,
+        })
+        .map(ListItem::new)
+        .collect();
+
+    let oracle_list = List::new(oracle_items).block(
+        Block::default()
+            .title("Oracle")
+            .borders(Borders::NONE)
+            .title_style(Style::default().fg(Color::Yellow).bold()),
+    );
+    frame.render_widget(oracle_list, chunks[1]);
+
     // NPCs
     let npc_items: Vec<ListItem> = npcs
         .iter()
@@ -185,5 +264,5 @@ fn draw_lists(frame: &mut Frame, tab: &SoloTab, area: Rect) {
             .borders(Borders::NONE)
             .title_style(Style::default().fg(Color::Magenta).bold()),
     );
-    frame.render_widget(npc_list, chunks[1]);
+    frame.render_widget(npc_list, chunks[2]);
 }
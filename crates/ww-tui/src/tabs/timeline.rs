@@ -5,6 +5,7 @@ use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, List, ListItem, ListState};
 
 use ww_core::World;
+use ww_core::grammar::Grammar;
 use ww_core::timeline::Timeline;
 
 use super::{InputMode, Tab};
@@ -84,6 +85,8 @@ impl Tab for TimelineTab {
             return;
         }
 
+        let debuts = timeline.introductions_by_event(&self.world);
+
         let items: Vec<ListItem> = entries
             .iter()
             .map(|entry| {
@@ -102,7 +105,7 @@ impl Tab for TimelineTab {
                     format!(" [{}]", event_type)
                 };
 
-                let line = Line::from(vec![
+                let mut spans = vec![
                     Span::styled(
                         format!("{date_str:>30}"),
                         Style::default().fg(Color::DarkGray),
@@ -110,7 +113,21 @@ impl Tab for TimelineTab {
                     Span::raw("  "),
                     Span::styled(&entry.entity.name, Style::default().fg(Color::White).bold()),
                     Span::styled(type_tag, Style::default().fg(Color::Yellow)),
-                ]);
+                ];
+
+                if let Some(cast) = debuts.get(&entry.entity.id) {
+                    let names: Vec<&str> = cast
+                        .iter()
+                        .filter_map(|id| self.world.get_entity(*id))
+                        .map(|e| e.name.as_str())
+                        .collect();
+                    spans.push(Span::styled(
+                        format!("  \u{2605} {} debuts", names.join(", ")),
+                        Style::default().fg(Color::Green),
+                    ));
+                }
+
+                let line = Line::from(spans);
 
                 ListItem::new(line)
             })
@@ -119,7 +136,10 @@ impl Tab for TimelineTab {
         let list = List::new(items)
             .block(
                 Block::default()
-                    .title(format!(" Timeline ({} events) ", entries.len()))
+                    .title(format!(
+                        " Timeline ({}) ",
+                        Grammar::from_meta(&self.world.meta).count(entries.len(), "event")
+                    ))
                     .borders(Borders::ALL)
                     .border_style(Style::default().fg(Color::Yellow)),
             )
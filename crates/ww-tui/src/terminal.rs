@@ -115,18 +115,16 @@ fn handle_key(app: &mut TuiApp, key: crossterm::event::KeyEvent) {
                 }
                 _ => {}
             }
-            // Number keys 1-7 switch tabs
+            // Number keys 1-8 switch tabs
             if let KeyCode::Char(c) = key.code
                 && let Some(idx) = c.to_digit(10)
-                && (1..=7).contains(&idx)
+                && (1..=8).contains(&idx)
             {
                 app.switch_tab(TabId::ALL[idx as usize - 1]);
                 return;
             }
             // Forward to active tab
-            if app.active_tab_mut().handle_key(key) {
-                app.should_quit = true;
-            }
+            app.forward_key(key);
         }
         InputMode::TextInput => {
             // In text-input mode, only Ctrl+N and Esc toggle help
@@ -138,9 +136,7 @@ fn handle_key(app: &mut TuiApp, key: crossterm::event::KeyEvent) {
                 return;
             }
             // Forward to active tab
-            if app.active_tab_mut().handle_key(key) {
-                app.should_quit = true;
-            }
+            app.forward_key(key);
         }
     }
 }
@@ -155,6 +151,7 @@ fn ctrl_number_to_tab(code: KeyCode) -> Option<TabId> {
         KeyCode::Char('5') => Some(TabId::Solo),
         KeyCode::Char('6') => Some(TabId::Sheet),
         KeyCode::Char('7') => Some(TabId::Dice),
+        KeyCode::Char('8') => Some(TabId::Search),
         _ => None,
     }
 }
@@ -182,7 +179,7 @@ fn handle_mouse(app: &mut TuiApp, mouse: crossterm::event::MouseEvent) {
 
 /// Hit-test the tab bar for mouse clicks.
 fn tab_bar_hit_test(col: u16) -> Option<TabId> {
-    // Tab labels with dividers: "[1]Explorer | [2]Graph | [3]Timeline | [4]Play | [5]Solo | [6]Sheet | [7]Dice"
+    // Tab labels with dividers: "[1]Explorer | [2]Graph | [3]Timeline | [4]Play | [5]Solo | [6]Sheet | [7]Dice | [8]Search"
     // For better UX, make each tab's clickable area include the divider space after it.
     // This makes clicking more forgiving and prevents dead zones.
 
@@ -194,6 +191,7 @@ fn tab_bar_hit_test(col: u16) -> Option<TabId> {
         "[5]Solo",
         "[6]Sheet",
         "[7]Dice",
+        "[8]Search",
     ];
 
     let divider_len = 3u16; // " | " is 3 chars
@@ -255,8 +253,8 @@ mod tests {
 
     #[test]
     fn tab_bar_hit_test_boundaries() {
-        // Tab layout: "[1]Explorer | [2]Graph | [3]Timeline | [4]Play | [5]Solo | [6]Sheet | [7]Dice"
-        // Lengths: 11, 8, 11, 7, 7, 8, 7
+        // Tab layout: "[1]Explorer | [2]Graph | [3]Timeline | [4]Play | [5]Solo | [6]Sheet | [7]Dice | [8]Search"
+        // Lengths: 11, 8, 11, 7, 7, 8, 7, 9
         // Divider: " | " (3 chars) - included in each tab's clickable area
         // Clickable areas: Tab includes label + divider (except last tab)
 
@@ -297,10 +295,16 @@ mod tests {
         assert_eq!(tab_bar_hit_test(66), Some(TabId::Sheet));
         assert_eq!(tab_bar_hit_test(69), Some(TabId::Sheet)); // divider is part of Sheet
 
-        // Tab 6: Dice (cols 70-76, 7 chars, no divider after)
+        // Tab 6: Dice (cols 70-79, 7 chars + 3 divider)
         assert_eq!(tab_bar_hit_test(70), Some(TabId::Dice));
         assert_eq!(tab_bar_hit_test(73), Some(TabId::Dice));
         assert_eq!(tab_bar_hit_test(76), Some(TabId::Dice));
+        assert_eq!(tab_bar_hit_test(79), Some(TabId::Dice)); // divider is part of Dice
+
+        // Tab 7: Search (cols 80-88, 9 chars, no divider after)
+        assert_eq!(tab_bar_hit_test(80), Some(TabId::Search));
+        assert_eq!(tab_bar_hit_test(84), Some(TabId::Search));
+        assert_eq!(tab_bar_hit_test(88), Some(TabId::Search));
 
         // Beyond all tabs
         assert_eq!(tab_bar_hit_test(100), None);
@@ -316,6 +320,7 @@ mod tests {
         assert_eq!(tab_bar_hit_test(52), Some(TabId::Solo)); // middle of Solo
         assert_eq!(tab_bar_hit_test(63), Some(TabId::Sheet)); // middle of Sheet
         assert_eq!(tab_bar_hit_test(73), Some(TabId::Dice)); // middle of Dice
+        assert_eq!(tab_bar_hit_test(84), Some(TabId::Search)); // middle of Search
     }
 
     #[test]
@@ -327,6 +332,7 @@ mod tests {
         assert_eq!(tab_bar_hit_test(46), Some(TabId::Play)); // divider after Play
         assert_eq!(tab_bar_hit_test(56), Some(TabId::Solo)); // divider after Solo
         assert_eq!(tab_bar_hit_test(67), Some(TabId::Sheet)); // divider after Sheet
+        assert_eq!(tab_bar_hit_test(78), Some(TabId::Dice)); // divider after Dice
     }
 
     #[test]
@@ -339,6 +345,7 @@ mod tests {
         assert_eq!("[5]Solo".len(), 7);
         assert_eq!("[6]Sheet".len(), 8);
         assert_eq!("[7]Dice".len(), 7);
+        assert_eq!("[8]Search".len(), 9);
     }
 
     #[test]
@@ -351,6 +358,7 @@ mod tests {
         assert_eq!(tab_bar_hit_test(49), Some(TabId::Solo)); // '[' of Solo
         assert_eq!(tab_bar_hit_test(59), Some(TabId::Sheet)); // '[' of Sheet
         assert_eq!(tab_bar_hit_test(70), Some(TabId::Dice)); // '[' of Dice
+        assert_eq!(tab_bar_hit_test(80), Some(TabId::Search)); // '[' of Search
     }
 
     #[test]
@@ -363,6 +371,7 @@ mod tests {
         assert_eq!(tab_bar_hit_test(55), Some(TabId::Solo)); // 'o' of Solo
         assert_eq!(tab_bar_hit_test(66), Some(TabId::Sheet)); // 't' of Sheet
         assert_eq!(tab_bar_hit_test(76), Some(TabId::Dice)); // 'e' of Dice
+        assert_eq!(tab_bar_hit_test(88), Some(TabId::Search)); // 'h' of Search
     }
 
     #[test]
@@ -398,10 +407,15 @@ mod tests {
         assert_eq!(tab_bar_hit_test(69), Some(TabId::Sheet));
         assert_eq!(tab_bar_hit_test(70), Some(TabId::Dice)); // boundary
 
-        // Dice: [70, 77)
+        // Dice: [70, 80)
         assert_eq!(tab_bar_hit_test(70), Some(TabId::Dice));
-        assert_eq!(tab_bar_hit_test(76), Some(TabId::Dice));
-        assert_eq!(tab_bar_hit_test(77), None); // beyond
+        assert_eq!(tab_bar_hit_test(79), Some(TabId::Dice));
+        assert_eq!(tab_bar_hit_test(80), Some(TabId::Search)); // boundary
+
+        // Search: [80, 89)
+        assert_eq!(tab_bar_hit_test(80), Some(TabId::Search));
+        assert_eq!(tab_bar_hit_test(88), Some(TabId::Search));
+        assert_eq!(tab_bar_hit_test(89), None); // beyond
     }
 
     #[test]
@@ -414,12 +428,13 @@ mod tests {
         assert_eq!(tab_bar_hit_test(50), Some(TabId::Solo)); // '5'
         assert_eq!(tab_bar_hit_test(60), Some(TabId::Sheet)); // '6'
         assert_eq!(tab_bar_hit_test(71), Some(TabId::Dice)); // '7'
+        assert_eq!(tab_bar_hit_test(81), Some(TabId::Search)); // '8'
     }
 
     #[test]
     fn tab_bar_hit_test_no_gaps() {
-        // Verify there are no gaps - every column from 0 to 76 should hit a tab
-        for col in 0..=76 {
+        // Verify there are no gaps - every column from 0 to 88 should hit a tab
+        for col in 0..=88 {
             assert!(
                 tab_bar_hit_test(col).is_some(),
                 "Column {col} should hit a tab, but returned None"
@@ -431,7 +446,7 @@ mod tests {
     fn tab_bar_hit_test_sequential() {
         // Verify tabs are selected in correct order as we scan left to right
         let mut last_tab_index = None;
-        for col in 0..=76 {
+        for col in 0..=88 {
             if let Some(tab) = tab_bar_hit_test(col) {
                 let tab_index = tab.index();
                 if let Some(last_index) = last_tab_index {
@@ -457,5 +472,6 @@ mod tests {
         assert_eq!(tab_bar_hit_test(53), Some(TabId::Solo)); // "lo"
         assert_eq!(tab_bar_hit_test(64), Some(TabId::Sheet)); // "heet"
         assert_eq!(tab_bar_hit_test(74), Some(TabId::Dice)); // "ce"
+        assert_eq!(tab_bar_hit_test(85), Some(TabId::Search)); // "arch"
     }
 }